@@ -1,5 +1,6 @@
 use std::collections::HashMap;
 use std::convert::TryFrom;
+use std::sync::Arc;
 use tokio::runtime::Runtime;
 use warpgrapher::engine::config::Configuration;
 use warpgrapher::engine::database::neo4j::Neo4jEndpoint;
@@ -45,7 +46,7 @@ fn main() {
     let mut resolvers = Resolvers::<()>::new();
     resolvers.insert(
         "resolve_project_points".to_string(),
-        Box::new(resolve_project_points),
+        Arc::new(resolve_project_points),
     );
 
     // create warpgrapher engine
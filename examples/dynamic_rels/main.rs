@@ -1,5 +1,6 @@
 use std::collections::HashMap;
 use std::convert::TryFrom;
+use std::sync::Arc;
 use tokio::runtime::Runtime;
 use uuid::Uuid;
 use warpgrapher::engine::config::Configuration;
@@ -35,7 +36,7 @@ fn resolve_project_top_contributor(facade: ResolverFacade<()>) -> ExecutionResul
         Value::from(Uuid::new_v4().to_hyphenated().to_string()),
     );
     top_contributor_props.insert("name".to_string(), Value::from("user0".to_string()));
-    let top_contributor = facade.create_node("User", top_contributor_props);
+    let top_contributor = facade.node("User", top_contributor_props);
 
     // create dynamic rel
     let rel_id = "1234567890".to_string();
@@ -63,7 +64,7 @@ fn main() {
     let mut resolvers = Resolvers::<()>::new();
     resolvers.insert(
         "resolve_project_top_contributor".to_string(),
-        Box::new(resolve_project_top_contributor),
+        Arc::new(resolve_project_top_contributor),
     );
 
     // create warpgrapher engine
@@ -1,6 +1,7 @@
 use maplit::hashmap;
 use std::collections::HashMap;
 use std::convert::TryFrom;
+use std::sync::Arc;
 use tokio::runtime::Runtime;
 use warpgrapher::engine::config::Configuration;
 use warpgrapher::engine::database::neo4j::Neo4jEndpoint;
@@ -29,7 +30,7 @@ endpoints:
 
 // endpoint returning a list of `Issue` nodes
 fn resolve_top_issue(facade: ResolverFacade<()>) -> ExecutionResult {
-    let top_issue = facade.create_node(
+    let top_issue = facade.node(
         "Issue",
         hashmap! {
             "name".to_string() => Value::from("Learn more rust".to_string()),
@@ -56,7 +57,7 @@ fn main() {
 
     // define resolvers
     let mut resolvers = Resolvers::<()>::new();
-    resolvers.insert("TopIssue".to_string(), Box::new(resolve_top_issue));
+    resolvers.insert("TopIssue".to_string(), Arc::new(resolve_top_issue));
 
     // create warpgrapher engine
     let engine: Engine<()> = Engine::new(config, db)
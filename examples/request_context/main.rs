@@ -1,5 +1,6 @@
 use std::collections::HashMap;
 use std::convert::TryFrom;
+use std::sync::Arc;
 use tokio::runtime::Runtime;
 use warpgrapher::engine::config::Configuration;
 use warpgrapher::engine::context::RequestContext;
@@ -59,7 +60,7 @@ fn main() {
 
     // define resolvers
     let mut resolvers = Resolvers::<AppRequestContext>::new();
-    resolvers.insert("EchoRequest".to_string(), Box::new(resolve_echo_request));
+    resolvers.insert("EchoRequest".to_string(), Arc::new(resolve_echo_request));
 
     // create warpgrapher engine
     let engine: Engine<AppRequestContext> = Engine::new(config, db)
@@ -0,0 +1,766 @@
+//! This module provides caching of the responses to read queries. Responses are stored for the
+//! time to live configured for the types they read, and invalidated when the generated create,
+//! update, and delete mutations, or the operations of custom resolvers, write to those types.
+
+use crate::engine::config::{Configuration, TriggerEvent};
+use crate::engine::limits::{Document, Fragment, Parser, Selection};
+use crate::engine::persisted::sha256_hash;
+use crate::engine::schema::{NodeType, PropertyKind, TypeKind};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt::Debug;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// A store of cached responses, given to the engine with [`with_cache_store`]. Each response is
+/// stored under a key computed from the query, the operation name, the variables, and the
+/// [`cache_key`] of the request context, and is tagged with the names of the types that it reads,
+/// so that it can be invalidated when any of those types are written.
+///
+/// A store may be shared between engines, so methods take `&self`. A store that cannot reach its
+/// back-end should treat the request as a miss, rather than fail it.
+///
+/// A read query may run while a mutation writes to the types that it reads, so that its response
+/// holds data that is already out of date when the read ends. To keep such responses out of the
+/// cache, the engine takes the [`generation`] of the store before it runs a query, and the store
+/// discards the response if any of the types it reads have been invalidated since.
+///
+/// [`generation`]: #tymethod.generation
+///
+/// [`cache_key`]: ../context/trait.RequestContext.html#method.cache_key
+/// [`with_cache_store`]: ../struct.EngineBuilder.html#method.with_cache_store
+pub trait CacheStore: Debug + Send + Sync {
+    /// Returns the response stored under `key`, if there is one that has not expired
+    fn get(&self, key: &str) -> Option<serde_json::Value>;
+
+    /// Returns the generation of the store, which advances each time responses are invalidated or
+    /// the store is cleared
+    fn generation(&self) -> u64;
+
+    /// Stores the response `value` under `key` for the time to live `ttl`, tagged with the names
+    /// of the types that it reads, unless any of those types have been invalidated, or the store
+    /// cleared, after the generation `generation` at which the query began
+    fn insert(
+        &self,
+        key: String,
+        value: serde_json::Value,
+        ttl: Duration,
+        type_names: HashSet<String>,
+        generation: u64,
+    );
+
+    /// Removes the responses tagged with any of `type_names`, and advances the generation
+    fn invalidate(&self, type_names: &HashSet<String>);
+
+    /// Removes all responses, and advances the generation
+    fn clear(&self);
+}
+
+/// A [`CacheStore`] that keeps responses in memory, up to its capacity, which is 1,000 responses
+/// by default. Once the store is full, the least recently used response is evicted to make room
+/// for a new one.
+///
+/// [`CacheStore`]: ./trait.CacheStore.html
+///
+/// # Examples
+///
+/// ```rust
+/// # use std::sync::Arc;
+/// # use warpgrapher::{Configuration, DatabasePool, Engine};
+/// # use warpgrapher::engine::cache::MemoryCacheStore;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let config = Configuration::default();
+///
+/// let engine = Engine::<()>::new(config, DatabasePool::NoDatabase)
+///     .with_cache_store(Arc::new(MemoryCacheStore::new().with_capacity(500)))
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct MemoryCacheStore {
+    capacity: usize,
+    entries: Mutex<Entries>,
+}
+
+impl MemoryCacheStore {
+    /// Returns a new, empty [`MemoryCacheStore`]
+    ///
+    /// [`MemoryCacheStore`]: ./struct.MemoryCacheStore.html
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use warpgrapher::engine::cache::MemoryCacheStore;
+    ///
+    /// let store = MemoryCacheStore::new();
+    /// ```
+    pub fn new() -> MemoryCacheStore {
+        MemoryCacheStore::default()
+    }
+
+    /// Sets the greatest number of responses kept in the store
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use warpgrapher::engine::cache::MemoryCacheStore;
+    ///
+    /// let store = MemoryCacheStore::new().with_capacity(10);
+    /// ```
+    pub fn with_capacity(mut self, capacity: usize) -> Self {
+        self.capacity = capacity;
+        self
+    }
+
+    /// Returns the number of responses in the store, including any that have expired, but have
+    /// not yet been removed
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use warpgrapher::engine::cache::MemoryCacheStore;
+    ///
+    /// let store = MemoryCacheStore::new();
+    ///
+    /// assert_eq!(0, store.len());
+    /// ```
+    pub fn len(&self) -> usize {
+        self.entries.lock().map(|e| e.values.len()).unwrap_or(0)
+    }
+
+    /// Returns true if there are no responses in the store
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use warpgrapher::engine::cache::MemoryCacheStore;
+    ///
+    /// let store = MemoryCacheStore::new();
+    ///
+    /// assert!(store.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl Default for MemoryCacheStore {
+    fn default() -> Self {
+        MemoryCacheStore {
+            capacity: 1000,
+            entries: Mutex::new(Entries::default()),
+        }
+    }
+}
+
+impl CacheStore for MemoryCacheStore {
+    fn generation(&self) -> u64 {
+        self.entries.lock().map(|e| e.generation).unwrap_or(0)
+    }
+
+    fn get(&self, key: &str) -> Option<serde_json::Value> {
+        let mut entries = self.entries.lock().ok()?;
+        let expired = entries.values.get(key)?.expires <= Instant::now();
+        if expired {
+            entries.remove(key);
+            return None;
+        }
+
+        entries.touch(key);
+        entries.values.get(key).map(|e| e.value.clone())
+    }
+
+    fn insert(
+        &self,
+        key: String,
+        value: serde_json::Value,
+        ttl: Duration,
+        type_names: HashSet<String>,
+        generation: u64,
+    ) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        if let Ok(mut entries) = self.entries.lock() {
+            if entries.stale(&type_names, generation) {
+                return;
+            }
+
+            entries.remove(&key);
+            while entries.values.len() >= self.capacity {
+                let oldest = match entries.order.values().next() {
+                    Some(oldest) => oldest.clone(),
+                    None => break,
+                };
+                entries.remove(&oldest);
+            }
+
+            entries.tick += 1;
+            let tick = entries.tick;
+            entries.order.insert(tick, key.clone());
+            entries.values.insert(
+                key,
+                Entry {
+                    value,
+                    expires: Instant::now() + ttl,
+                    type_names,
+                    tick,
+                },
+            );
+        }
+    }
+
+    fn invalidate(&self, type_names: &HashSet<String>) {
+        if let Ok(mut entries) = self.entries.lock() {
+            entries.generation += 1;
+            let generation = entries.generation;
+            for t in type_names {
+                entries.invalidated.insert(t.clone(), generation);
+            }
+
+            let keys: Vec<String> = entries
+                .values
+                .iter()
+                .filter(|(_, e)| !e.type_names.is_disjoint(type_names))
+                .map(|(k, _)| k.clone())
+                .collect();
+            keys.iter().for_each(|k| entries.remove(k));
+        }
+    }
+
+    fn clear(&self) {
+        if let Ok(mut entries) = self.entries.lock() {
+            entries.generation += 1;
+            entries.cleared = entries.generation;
+            entries.values.clear();
+            entries.order.clear();
+        }
+    }
+}
+
+/// The responses held by a [`MemoryCacheStore`], with the order in which they were last used, and
+/// the generations at which each type was last invalidated and the store last cleared
+#[derive(Debug, Default)]
+struct Entries {
+    values: HashMap<String, Entry>,
+    order: BTreeMap<u64, String>,
+    tick: u64,
+    generation: u64,
+    invalidated: HashMap<String, u64>,
+    cleared: u64,
+}
+
+impl Entries {
+    /// Returns true if any of the types `type_names` have been invalidated, or the store cleared,
+    /// after the generation `generation`
+    fn stale(&self, type_names: &HashSet<String>, generation: u64) -> bool {
+        self.cleared > generation
+            || type_names
+                .iter()
+                .any(|t| matches!(self.invalidated.get(t), Some(g) if *g > generation))
+    }
+
+    fn remove(&mut self, key: &str) {
+        if let Some(entry) = self.values.remove(key) {
+            self.order.remove(&entry.tick);
+        }
+    }
+
+    fn touch(&mut self, key: &str) {
+        self.tick += 1;
+        let tick = self.tick;
+        if let Some(entry) = self.values.get_mut(key) {
+            self.order.remove(&entry.tick);
+            self.order.insert(tick, key.to_string());
+            entry.tick = tick;
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Entry {
+    value: serde_json::Value,
+    expires: Instant,
+    type_names: HashSet<String>,
+    tick: u64,
+}
+
+/// How a request uses the cache
+#[derive(Debug, PartialEq)]
+pub(crate) enum CachePlan {
+    /// The request neither reads from nor writes to the cache
+    Bypass,
+    /// The response is read from the cache, or else stored for the time to live, tagged with the
+    /// names of the types read
+    Read {
+        ttl: Duration,
+        type_names: HashSet<String>,
+    },
+    /// The responses that read any of the types are invalidated after the mutation runs
+    Invalidate(HashSet<String>),
+    /// All responses are invalidated after the mutation runs
+    Clear,
+}
+
+impl CachePlan {
+    /// Returns the plan for the operation `operation_name` of the GraphQL document `query`.
+    ///
+    /// A query is cached only if every type that it reads has a time to live, and it reads no
+    /// fields resolved by custom resolvers. The time to live of the response is the least of the
+    /// times to live of the types read.
+    ///
+    /// A mutation run by the generated endpoints for a type may write, through nested inputs and
+    /// the relationships removed along with deleted nodes, to any type connected to it. So the
+    /// responses reading any of those types are invalidated. Custom mutations, and mutations that
+    /// touch types with triggers, may write anywhere, so they clear the cache.
+    pub(crate) fn new(
+        query: &str,
+        operation_name: Option<&str>,
+        config: &Configuration,
+        type_defs: &HashMap<String, NodeType>,
+    ) -> CachePlan {
+        let document = match Parser::parse(query, None) {
+            Ok(document) => document,
+            Err(_) => return CachePlan::Bypass,
+        };
+        let operation = match document.operation(operation_name) {
+            Some(operation) => operation,
+            None => return CachePlan::Bypass,
+        };
+
+        match operation.type_name.as_str() {
+            "Query" => CachePlan::read(&document, &operation.selections, config, type_defs),
+            "Mutation" => CachePlan::write(&operation.selections, type_defs),
+            _ => CachePlan::Bypass,
+        }
+    }
+
+    /// Removes the responses in `cache_store` that a write made to this plan may have put out of
+    /// date
+    pub(crate) fn invalidate(&self, cache_store: &dyn CacheStore) {
+        match self {
+            CachePlan::Invalidate(type_names) => cache_store.invalidate(type_names),
+            CachePlan::Clear => cache_store.clear(),
+            CachePlan::Bypass | CachePlan::Read { .. } => (),
+        }
+    }
+
+    fn read(
+        document: &Document,
+        selections: &[Selection],
+        config: &Configuration,
+        type_defs: &HashMap<String, NodeType>,
+    ) -> CachePlan {
+        let mut reads = Reads {
+            type_defs,
+            fragments: &document.fragments,
+            type_names: HashSet::new(),
+            spread: HashSet::new(),
+        };
+        if !reads.selections(selections, Some("Query")) {
+            return CachePlan::Bypass;
+        }
+
+        let mut ttl: Option<u64> = None;
+        for t in config.types() {
+            if reads.type_names.contains(t.name()) {
+                match t.cache_ttl() {
+                    Some(type_ttl) => ttl = Some(ttl.map_or(type_ttl, |ttl| ttl.min(type_ttl))),
+                    None => return CachePlan::Bypass,
+                }
+            }
+        }
+
+        match ttl {
+            Some(ttl) if ttl > 0 => CachePlan::Read {
+                ttl: Duration::from_secs(ttl),
+                type_names: reads.type_names,
+            },
+            _ => CachePlan::Bypass,
+        }
+    }
+
+    fn write(selections: &[Selection], type_defs: &HashMap<String, NodeType>) -> CachePlan {
+        let mutation = match type_defs.get("Mutation") {
+            Some(mutation) => mutation,
+            None => return CachePlan::Clear,
+        };
+
+        let mut written = Vec::new();
+        for s in selections {
+            let name = match s {
+                Selection::Field { name, .. } => name,
+                _ => return CachePlan::Clear,
+            };
+            if name == "__typename" {
+                continue;
+            }
+            let prop = match mutation.property(name) {
+                Ok(prop) => prop,
+                Err(_) => return CachePlan::Clear,
+            };
+            match prop.kind() {
+                PropertyKind::NodeCreateMutation | PropertyKind::NodeUpdateMutation => {
+                    written.push(prop.type_name().to_string())
+                }
+                PropertyKind::NodeDeleteMutation { label } => written.push(label.clone()),
+                PropertyKind::RelCreateMutation { src_label, .. }
+                | PropertyKind::RelUpdateMutation { src_label, .. }
+                | PropertyKind::RelDeleteMutation { src_label, .. } => {
+                    written.push(src_label.clone())
+                }
+                _ => return CachePlan::Clear,
+            }
+        }
+
+        CachePlan::written(written, type_defs)
+    }
+
+    /// Returns the plan for a write to the types `written`, which invalidates the responses
+    /// reading any type connected to them, or clears the cache if any of those types have triggers
+    pub(crate) fn written(
+        written: Vec<String>,
+        type_defs: &HashMap<String, NodeType>,
+    ) -> CachePlan {
+        let type_names = connected_types(written, type_defs);
+        let events = [
+            TriggerEvent::BeforeCreate,
+            TriggerEvent::AfterCreate,
+            TriggerEvent::BeforeUpdate,
+            TriggerEvent::AfterUpdate,
+            TriggerEvent::BeforeDelete,
+            TriggerEvent::AfterDelete,
+        ];
+        let triggered = type_names
+            .iter()
+            .filter_map(|t| type_defs.get(t))
+            .any(|td| events.iter().any(|e| td.triggers(*e).next().is_some()));
+
+        if triggered {
+            CachePlan::Clear
+        } else {
+            CachePlan::Invalidate(type_names)
+        }
+    }
+}
+
+/// Returns the key under which the response to a request is cached
+pub(crate) fn cache_key(
+    query: &str,
+    operation_name: Option<&str>,
+    variables: &serde_json::Value,
+    context_key: &str,
+) -> String {
+    sha256_hash(&serde_json::json!([query, operation_name, variables, context_key]).to_string())
+}
+
+/// Returns the names of the types connected to the types `seeds` by the fields of the schema,
+/// whichever way the fields run, along with the seeds themselves. Input types and the root types
+/// are not followed.
+fn connected_types(seeds: Vec<String>, type_defs: &HashMap<String, NodeType>) -> HashSet<String> {
+    let followed = |name: &str| -> bool {
+        name != "Query"
+            && name != "Mutation"
+            && matches!(type_defs.get(name), Some(td) if td.type_kind() != &TypeKind::Input)
+    };
+
+    let mut edges: HashMap<&str, Vec<&str>> = HashMap::new();
+    for (name, td) in type_defs.iter().filter(|(name, _)| followed(name)) {
+        let targets = td
+            .props()
+            .map(|p| p.type_name())
+            .chain(td.union_types().into_iter().flatten().map(|u| u.as_str()))
+            .filter(|target| followed(target));
+        for target in targets {
+            edges.entry(name.as_str()).or_default().push(target);
+            edges.entry(target).or_default().push(name.as_str());
+        }
+    }
+
+    let mut type_names: HashSet<String> = HashSet::new();
+    let mut stack = seeds;
+    while let Some(name) = stack.pop() {
+        if let Some(targets) = edges.get(name.as_str()) {
+            stack.extend(
+                targets
+                    .iter()
+                    .filter(|t| !type_names.contains(**t) && **t != name)
+                    .map(|t| t.to_string()),
+            );
+        }
+        type_names.insert(name);
+    }
+    type_names
+}
+
+/// Collects the names of the types read by the selection sets of a query
+struct Reads<'r> {
+    type_defs: &'r HashMap<String, NodeType>,
+    fragments: &'r HashMap<String, Fragment>,
+    type_names: HashSet<String>,
+    spread: HashSet<(String, Option<String>)>,
+}
+
+impl<'r> Reads<'r> {
+    /// Adds the types read by a selection set on the type `type_name` to the names of the types
+    /// read, returning false if the selection set reads a field that cannot be cached
+    fn selections(&mut self, selections: &[Selection], type_name: Option<&str>) -> bool {
+        selections.iter().all(|s| self.selection(s, type_name))
+    }
+
+    fn selection(&mut self, selection: &Selection, type_name: Option<&str>) -> bool {
+        match selection {
+            Selection::Field { name, selections } => {
+                if name == "__typename" {
+                    return true;
+                }
+                let prop = match type_name
+                    .and_then(|t| self.type_defs.get(t))
+                    .and_then(|td| td.property(name).ok())
+                {
+                    Some(prop) => prop,
+                    None => return false,
+                };
+                match prop.kind() {
+                    PropertyKind::CustomResolver
+                    | PropertyKind::DynamicScalar
+                    | PropertyKind::DynamicRel { .. } => return false,
+                    _ if prop.resolver().is_some() => return false,
+                    _ => (),
+                }
+
+                if self.type_defs.contains_key(prop.type_name()) {
+                    self.type_names.insert(prop.type_name().to_string());
+                }
+                self.selections(selections, Some(prop.type_name()))
+            }
+            Selection::InlineFragment {
+                type_name: fragment_type,
+                selections,
+            } => {
+                if let Some(t) = fragment_type {
+                    if self.type_defs.contains_key(t) {
+                        self.type_names.insert(t.clone());
+                    }
+                }
+                self.selections(selections, fragment_type.as_deref().or(type_name))
+            }
+            Selection::FragmentSpread(name) => {
+                let fragments = self.fragments;
+                let fragment = match fragments.get(name) {
+                    Some(fragment) => fragment,
+                    None => return false,
+                };
+                let t = if self.type_defs.contains_key(&fragment.type_name) {
+                    self.type_names.insert(fragment.type_name.clone());
+                    Some(fragment.type_name.as_str())
+                } else {
+                    type_name
+                };
+
+                if !self.spread.insert((name.clone(), t.map(|t| t.to_string()))) {
+                    return true;
+                }
+                self.selections(&fragment.selections, t)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CachePlan, CacheStore, MemoryCacheStore};
+    use crate::engine::config::Configuration;
+    use crate::engine::schema::Info;
+    use std::collections::HashSet;
+    use std::convert::TryFrom;
+    use std::thread::sleep;
+    use std::time::Duration;
+
+    fn mock_config() -> Configuration {
+        Configuration::try_from(
+            "
+version: 1
+model:
+  - name: Project
+    cacheTtl: 60
+    props:
+      - name: name
+        type: String
+    rels:
+      - name: issues
+        list: true
+        nodes: [Issue]
+  - name: Issue
+    cacheTtl: 30
+    props:
+      - name: title
+        type: String
+  - name: Note
+    props:
+      - name: text
+        type: String
+",
+        )
+        .unwrap()
+    }
+
+    fn types(names: &[&str]) -> HashSet<String> {
+        names.iter().map(|n| n.to_string()).collect()
+    }
+
+    fn plan(query: &str) -> CachePlan {
+        let config = mock_config();
+        let info = Info::from(&config);
+        CachePlan::new(query, None, &config, &info.type_defs())
+    }
+
+    /// Passes if the least recently used response is evicted once the store is full, and
+    /// responses expire after their time to live
+    #[test]
+    fn memory_store_eviction() {
+        let store = MemoryCacheStore::new().with_capacity(2);
+        let ttl = Duration::from_secs(60);
+        store.insert("a".to_string(), "a".into(), ttl, types(&["Project"]), 0);
+        store.insert("b".to_string(), "b".into(), ttl, types(&["Project"]), 0);
+        assert_eq!(store.get("a"), Some("a".into()));
+
+        store.insert("c".to_string(), "c".into(), ttl, types(&["Project"]), 0);
+        assert_eq!(store.len(), 2);
+        assert_eq!(store.get("b"), None);
+        assert_eq!(store.get("a"), Some("a".into()));
+        assert_eq!(store.get("c"), Some("c".into()));
+
+        store.insert(
+            "d".to_string(),
+            "d".into(),
+            Duration::from_millis(10),
+            types(&["Issue"]),
+            0,
+        );
+        sleep(Duration::from_millis(20));
+        assert_eq!(store.get("d"), None);
+        assert_eq!(store.len(), 1);
+    }
+
+    /// Passes if responses tagged with an invalidated type are removed, and others are kept
+    #[test]
+    fn memory_store_invalidation() {
+        let store = MemoryCacheStore::new();
+        let ttl = Duration::from_secs(60);
+        store.insert(
+            "a".to_string(),
+            "a".into(),
+            ttl,
+            types(&["Project", "Issue"]),
+            0,
+        );
+        store.insert("b".to_string(), "b".into(), ttl, types(&["Note"]), 0);
+
+        store.invalidate(&types(&["Issue"]));
+        assert_eq!(store.get("a"), None);
+        assert_eq!(store.get("b"), Some("b".into()));
+
+        store.clear();
+        assert!(store.is_empty());
+    }
+
+    /// Passes if a response read before its types were invalidated, or the store cleared, is not
+    /// stored, and a response read afterwards is
+    #[test]
+    fn memory_store_stale_insert() {
+        let store = MemoryCacheStore::new();
+        let ttl = Duration::from_secs(60);
+        let generation = store.generation();
+
+        store.invalidate(&types(&["Issue"]));
+        store.insert(
+            "a".to_string(),
+            "a".into(),
+            ttl,
+            types(&["Project", "Issue"]),
+            generation,
+        );
+        store.insert(
+            "b".to_string(),
+            "b".into(),
+            ttl,
+            types(&["Note"]),
+            generation,
+        );
+        assert_eq!(store.get("a"), None);
+        assert_eq!(store.get("b"), Some("b".into()));
+
+        let generation = store.generation();
+        store.insert(
+            "a".to_string(),
+            "a".into(),
+            ttl,
+            types(&["Project", "Issue"]),
+            generation,
+        );
+        assert_eq!(store.get("a"), Some("a".into()));
+
+        store.clear();
+        store.insert(
+            "b".to_string(),
+            "b".into(),
+            ttl,
+            types(&["Note"]),
+            generation,
+        );
+        assert!(store.is_empty());
+    }
+
+    /// Passes if a query is cached for the least time to live of the types it reads, and not
+    /// cached if any type read has no time to live
+    #[test]
+    fn plan_reads() {
+        assert_eq!(
+            plan("query { Project { name } }"),
+            CachePlan::Read {
+                ttl: Duration::from_secs(60),
+                type_names: types(&["Project"])
+            }
+        );
+
+        match plan(
+            "query { Project { issues { dst { ...issue } } } } fragment issue on Issue { title }",
+        ) {
+            CachePlan::Read { ttl, type_names } => {
+                assert_eq!(ttl, Duration::from_secs(30));
+                assert!(type_names.contains("Project"));
+                assert!(type_names.contains("Issue"));
+            }
+            p => panic!("{:?}", p),
+        }
+
+        assert_eq!(plan("query { Note { text } }"), CachePlan::Bypass);
+        assert_eq!(plan("query { _version }"), CachePlan::Bypass);
+        assert_eq!(
+            plan("query { __schema { types { name } } }"),
+            CachePlan::Bypass
+        );
+    }
+
+    /// Passes if a mutation invalidates the types connected to the type written, and a mutation
+    /// that cannot be found clears the cache
+    #[test]
+    fn plan_writes() {
+        match plan("mutation { IssueDelete(input: {}) }") {
+            CachePlan::Invalidate(type_names) => {
+                assert!(type_names.contains("Issue"));
+                assert!(type_names.contains("Project"));
+                assert!(!type_names.contains("Note"));
+            }
+            p => panic!("{:?}", p),
+        }
+        match plan("mutation { NoteCreate(input: {}) { id } }") {
+            CachePlan::Invalidate(type_names) => assert_eq!(type_names, types(&["Note"])),
+            p => panic!("{:?}", p),
+        }
+        assert_eq!(plan("mutation { Unknown }"), CachePlan::Clear);
+    }
+}
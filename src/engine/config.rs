@@ -4,10 +4,16 @@ use crate::engine::schema::{rel_name_variants, type_name_variants};
 use crate::Error;
 use log::trace;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::convert::TryFrom;
+use std::env::var_os;
 use std::fs::File;
 use std::io::BufReader;
+use std::path::{Path, PathBuf};
 use std::slice::Iter;
+use yaml_rust::parser::{Event as YamlEvent, MarkedEventReceiver, Parser as YamlParser};
+use yaml_rust::scanner::{Marker, TScalarStyle, TokenType};
+use yaml_rust::Yaml;
 
 const LATEST_CONFIG_VERSION: i32 = 2;
 
@@ -123,15 +129,22 @@ impl Configuration {
     /// # Errors
     ///
     /// Returns an [`Error`] variant [`ConfigItemDuplicated`] if there is more than one type or
-    /// more than one endpoint that use the same name.
+    /// more than one endpoint that use the same name, or if types, properties, or relationships
+    /// would be stored in the database under the same label, key, or relationship type, or if a
+    /// field declares two arguments with the same name.
     ///
     /// Returns an [`Error`] variant [`ConfigItemReserved`] if a named configuration item, such as
     /// an endpoint or type, has a name that is a reserved word, such as "ID" or the name of a
-    /// GraphQL scalar type.
+    /// GraphQL scalar type, or if a property would be stored in the database under the "id" key.
+    /// Arguments may not be named "input" or "partitionKey".
+    ///
+    /// Returns an [`Error`] variant [`TypeConversionFailed`] if the default value of an argument
+    /// cannot be converted to the type of the argument.
     ///
     /// [`ConfigItemDuplicated`]: ../../error/enum.Error.html#variant.ConfigItemDuplicated
     /// [`ConfigItemReserved`]: ../../error/enum.Error.html#variant.ConfigItemReserved
     /// [`Error`]: ../../error/enum.Error.html
+    /// [`TypeConversionFailed`]: ../../error/enum.Error.html#variant.TypeConversionFailed
     ///
     /// # Example
     /// ```rust
@@ -186,6 +199,75 @@ impl Configuration {
                     });
                 }
 
+                // Check for db names that collide with one another or with the id property
+                let db_label = t.db_label().unwrap_or(&t.name);
+                if self
+                    .model
+                    .iter()
+                    .filter(|t2| t2.db_label().unwrap_or(&t2.name) == db_label)
+                    .count()
+                    > 1
+                {
+                    return Err(Error::ConfigItemDuplicated {
+                        type_name: db_label.to_string(),
+                    });
+                }
+
+                if t.props
+                    .iter()
+                    .chain(t.rels.iter().flat_map(|r| r.props.iter()))
+                    .any(|p| p.db_name().map_or(false, |n| n.to_uppercase() == "ID"))
+                {
+                    return Err(Error::ConfigItemReserved {
+                        type_name: "ID".to_string(),
+                    });
+                }
+
+                std::iter::once(&t.props)
+                    .chain(t.rels.iter().map(|r| &r.props))
+                    .try_for_each(|props| {
+                        props.iter().try_for_each(|p| {
+                            let db_name = p.db_name().unwrap_or(&p.name);
+                            if props
+                                .iter()
+                                .filter(|p2| p2.db_name().unwrap_or(&p2.name) == db_name)
+                                .count()
+                                > 1
+                            {
+                                Err(Error::ConfigItemDuplicated {
+                                    type_name: db_name.to_string(),
+                                })
+                            } else {
+                                Ok(())
+                            }
+                        })
+                    })?;
+
+                t.rels.iter().try_for_each(|r| {
+                    let db_type = r.db_type().unwrap_or(&r.name);
+                    if t.rels
+                        .iter()
+                        .filter(|r2| r2.db_type().unwrap_or(&r2.name) == db_type)
+                        .count()
+                        > 1
+                    {
+                        Err(Error::ConfigItemDuplicated {
+                            type_name: db_type.to_string(),
+                        })
+                    } else {
+                        Ok(())
+                    }
+                })?;
+
+                t.props
+                    .iter()
+                    .chain(t.rels.iter().flat_map(|r| r.props.iter()))
+                    .try_for_each(|p| validate_arguments(&p.arguments))?;
+
+                t.rels
+                    .iter()
+                    .try_for_each(|r| validate_arguments(&r.arguments))?;
+
                 t.rels.iter().try_for_each(|r| {
                     let rel_name_variants = rel_name_variants(t, r);
 
@@ -234,6 +316,8 @@ impl Configuration {
                     }
                 }
 
+                validate_arguments(&ep.arguments)?;
+
                 Ok(())
             })
             .collect::<Result<Vec<_>, Error>>()?;
@@ -267,6 +351,8 @@ impl Default for Configuration {
     }
 }
 
+/// Reads a configuration from a file, without expanding environment variable references or
+/// `!include` directives
 impl TryFrom<File> for Configuration {
     type Error = Error;
 
@@ -276,6 +362,47 @@ impl TryFrom<File> for Configuration {
     }
 }
 
+/// Reads a configuration from the file at the given path. A value tagged `!include <path>`,
+/// either the value of a mapping key or an item in a list, is replaced by the contents of the
+/// named file. Included files may themselves include other files. Included paths are resolved
+/// relative to the directory of the including file.
+///
+/// Environment variable references are not expanded. Use [`from_file_with_env`] to expand them.
+///
+/// [`from_file_with_env`]: ./struct.Configuration.html#method.from_file_with_env
+///
+/// # Errors
+///
+/// Returns an [`Error`] variant [`ConfigOpenFailed`] if the file cannot be read.
+///
+/// Returns an [`Error`] variant [`ConfigIncludeFailed`] if an included file cannot be read, or if
+/// a file includes itself.
+///
+/// Returns an [`Error`] variant [`YamlDeserializationFailed`] if a file is not valid YAML, or the
+/// result is not a valid configuration.
+///
+/// [`ConfigIncludeFailed`]: ../../error/enum.Error.html#variant.ConfigIncludeFailed
+/// [`ConfigOpenFailed`]: ../../error/enum.Error.html#variant.ConfigOpenFailed
+/// [`Error`]: ../../error/enum.Error.html
+/// [`YamlDeserializationFailed`]: ../../error/enum.Error.html#variant.YamlDeserializationFailed
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use std::convert::TryFrom;
+/// # use std::path::Path;
+/// # use warpgrapher::Configuration;
+///
+/// let config = Configuration::try_from(Path::new("./config.yml")).unwrap();
+/// ```
+impl TryFrom<&Path> for Configuration {
+    type Error = Error;
+
+    fn try_from(path: &Path) -> Result<Configuration, Error> {
+        Configuration::from_file_with(path, None)
+    }
+}
+
 impl TryFrom<String> for Configuration {
     type Error = Error;
 
@@ -292,6 +419,565 @@ impl TryFrom<&str> for Configuration {
     }
 }
 
+impl Configuration {
+    /// Reads a configuration from the file at the given path, expanding `!include` directives as
+    /// described for `TryFrom<&Path>`, and expanding references to environment variables.
+    ///
+    /// A reference of the form `${VAR}` is replaced with the value of the variable, and a
+    /// reference of the form `${VAR:-default}` is replaced with the value of the variable or, if
+    /// it is unset or empty, with the default. A literal `${` may be written as `$${`. References
+    /// are expanded within the values of the file, and in included files, after the YAML is
+    /// parsed, so references in comments are left alone. An unquoted value takes the type of the
+    /// text it expands to, so that `${PORT:-7687}` is read as a number. Within a flow sequence or
+    /// mapping, such as `[${TYPE}]`, a reference must be quoted.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`] variant [`EnvironmentVariableNotFound`] if a referenced environment
+    /// variable is not set and no default is given, along with the errors described for
+    /// `TryFrom<&Path>`.
+    ///
+    /// [`EnvironmentVariableNotFound`]: ../../error/enum.Error.html#variant.EnvironmentVariableNotFound
+    /// [`Error`]: ../../error/enum.Error.html
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use std::path::Path;
+    /// # use warpgrapher::Configuration;
+    ///
+    /// let config = Configuration::from_file_with_env(Path::new("./config.yml")).unwrap();
+    /// ```
+    pub fn from_file_with_env(path: &Path) -> Result<Configuration, Error> {
+        Configuration::from_file_with(path, Some(&process_env))
+    }
+
+    /// Reads a configuration from a string slice, expanding `!include` directives and references
+    /// to environment variables as described for [`from_file_with_env`]. Included paths are
+    /// resolved relative to the current working directory.
+    ///
+    /// [`from_file_with_env`]: ./struct.Configuration.html#method.from_file_with_env
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors described for [`from_file_with_env`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use warpgrapher::Configuration;
+    ///
+    /// let config = Configuration::from_str_with_env("
+    /// version: ${WG_EXAMPLE_VERSION:-1}
+    /// model: []
+    /// ").unwrap();
+    ///
+    /// assert_eq!(1, config.version());
+    /// ```
+    pub fn from_str_with_env(s: &str) -> Result<Configuration, Error> {
+        Configuration::from_str_with(s, Path::new("."), Some(&process_env))
+    }
+
+    fn from_file_with(path: &Path, env: Option<&Env>) -> Result<Configuration, Error> {
+        let path = path.canonicalize()?;
+        let text = std::fs::read_to_string(&path)?;
+        let base_dir = path.parent().unwrap_or_else(|| Path::new("."));
+        let value = preprocess(&text, base_dir, env, &mut vec![path.clone()])?;
+        Ok(serde_yaml::from_value(value)?)
+    }
+
+    fn from_str_with(s: &str, base_dir: &Path, env: Option<&Env>) -> Result<Configuration, Error> {
+        let value = preprocess(s, base_dir, env, &mut Vec::new())?;
+        Ok(serde_yaml::from_value(value)?)
+    }
+}
+
+/// Looks up the value of an environment variable
+type Env = dyn Fn(&str) -> Option<String>;
+
+fn process_env(name: &str) -> Option<String> {
+    var_os(name).map(|v| v.to_string_lossy().into_owned())
+}
+
+/// A node of a parsed YAML document, keeping the style and tag of each scalar
+#[derive(Clone, Debug)]
+enum YamlNode {
+    Scalar {
+        value: String,
+        style: TScalarStyle,
+        tag: Option<(String, String)>,
+    },
+    Sequence(Vec<YamlNode>),
+    Mapping(Vec<(YamlNode, YamlNode)>),
+}
+
+/// Builds the tree of [`YamlNode`]s of the first document in a YAML stream from parser events
+#[derive(Default)]
+struct YamlBuilder {
+    stack: Vec<(YamlNode, usize)>,
+    keys: Vec<Option<YamlNode>>,
+    anchors: HashMap<usize, YamlNode>,
+    root: Option<YamlNode>,
+}
+
+impl YamlBuilder {
+    fn push(&mut self, node: YamlNode, anchor: usize) {
+        if anchor > 0 {
+            self.anchors.insert(anchor, node.clone());
+        }
+
+        match self.stack.last_mut() {
+            Some((YamlNode::Sequence(items), _)) => items.push(node),
+            Some((YamlNode::Mapping(entries), _)) => {
+                if let Some(key) = self.keys.last_mut() {
+                    match key.take() {
+                        Some(k) => entries.push((k, node)),
+                        None => *key = Some(node),
+                    }
+                }
+            }
+            _ => {
+                if self.root.is_none() {
+                    self.root = Some(node)
+                }
+            }
+        }
+    }
+}
+
+impl MarkedEventReceiver for YamlBuilder {
+    fn on_event(&mut self, event: YamlEvent, _mark: Marker) {
+        match event {
+            YamlEvent::Scalar(value, style, anchor, tag) => {
+                let tag = match tag {
+                    Some(TokenType::Tag(handle, suffix)) => Some((handle, suffix)),
+                    _ => None,
+                };
+                self.push(YamlNode::Scalar { value, style, tag }, anchor)
+            }
+            YamlEvent::SequenceStart(anchor) => {
+                self.stack.push((YamlNode::Sequence(Vec::new()), anchor))
+            }
+            YamlEvent::MappingStart(anchor) => {
+                self.stack.push((YamlNode::Mapping(Vec::new()), anchor));
+                self.keys.push(None);
+            }
+            YamlEvent::SequenceEnd | YamlEvent::MappingEnd => {
+                if let YamlEvent::MappingEnd = event {
+                    self.keys.pop();
+                }
+                if let Some((node, anchor)) = self.stack.pop() {
+                    self.push(node, anchor)
+                }
+            }
+            YamlEvent::Alias(anchor) => {
+                if let Some(node) = self.anchors.get(&anchor).cloned() {
+                    self.push(node, 0)
+                }
+            }
+            _ => (),
+        }
+    }
+}
+
+/// Parses the text of a configuration file, replacing values tagged `!include` with the
+/// contents of the included files and, if `env` is given, expanding environment variable
+/// references within values. The stack holds the canonical paths of the files currently being
+/// expanded, so that include cycles can be detected.
+fn preprocess(
+    text: &str,
+    base_dir: &Path,
+    env: Option<&Env>,
+    stack: &mut Vec<PathBuf>,
+) -> Result<serde_yaml::Value, Error> {
+    trace!("preprocess called -- base_dir: {:#?}", base_dir);
+
+    let mut builder = YamlBuilder::default();
+    if YamlParser::new(text.chars())
+        .load(&mut builder, false)
+        .is_err()
+    {
+        // serde_yaml uses the same parser, and reports the error with its position
+        serde_yaml::from_str::<serde_yaml::Value>(text)?;
+    }
+
+    match builder.root {
+        Some(root) => resolve(root, base_dir, env, stack),
+        None => Ok(serde_yaml::Value::Null),
+    }
+}
+
+/// Converts a [`YamlNode`] to a YAML value, expanding includes and environment variables
+fn resolve(
+    node: YamlNode,
+    base_dir: &Path,
+    env: Option<&Env>,
+    stack: &mut Vec<PathBuf>,
+) -> Result<serde_yaml::Value, Error> {
+    match node {
+        YamlNode::Scalar { value, style, tag } => {
+            let value = match env {
+                Some(env) => interpolate(&value, env)?,
+                None => value,
+            };
+            match tag {
+                Some((handle, suffix)) if handle == "!" && suffix == "include" => {
+                    read_include(&base_dir.join(value.trim()), env, stack)
+                }
+                Some((handle, suffix)) if handle == "!!" && suffix != "str" => {
+                    Ok(scalar_value(&value))
+                }
+                Some(_) => Ok(serde_yaml::Value::String(value)),
+                None if style == TScalarStyle::Plain => Ok(scalar_value(&value)),
+                None => Ok(serde_yaml::Value::String(value)),
+            }
+        }
+        YamlNode::Sequence(items) => Ok(serde_yaml::Value::Sequence(
+            items
+                .into_iter()
+                .map(|item| resolve(item, base_dir, env, stack))
+                .collect::<Result<Vec<_>, Error>>()?,
+        )),
+        YamlNode::Mapping(entries) => {
+            let mut mapping = serde_yaml::Mapping::new();
+            for (k, v) in entries {
+                mapping.insert(
+                    resolve(k, base_dir, env, stack)?,
+                    resolve(v, base_dir, env, stack)?,
+                );
+            }
+            Ok(serde_yaml::Value::Mapping(mapping))
+        }
+    }
+}
+
+/// Returns the YAML value of the text of an unquoted scalar, such as a number or a boolean
+fn scalar_value(text: &str) -> serde_yaml::Value {
+    match Yaml::from_str(text) {
+        Yaml::Null => serde_yaml::Value::Null,
+        Yaml::Boolean(b) => serde_yaml::Value::Bool(b),
+        Yaml::Integer(i) => serde_yaml::Value::Number(i.into()),
+        Yaml::Real(r) => match r.parse::<f64>() {
+            Ok(f) => serde_yaml::Value::Number(f.into()),
+            Err(_) => serde_yaml::Value::String(r),
+        },
+        _ => serde_yaml::Value::String(text.to_string()),
+    }
+}
+
+/// Replaces ${VAR} and ${VAR:-default} references with values from the environment
+fn interpolate(text: &str, env: &Env) -> Result<String, Error> {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(i) = rest.find('$') {
+        out.push_str(&rest[..i]);
+        rest = &rest[i..];
+        if rest.starts_with("$${") {
+            out.push_str("${");
+            rest = &rest[3..];
+        } else if let (true, Some(end)) = (rest.starts_with("${"), rest.find('}')) {
+            let expr = &rest[2..end];
+            let (name, default) = match expr.find(":-") {
+                Some(j) => (&expr[..j], Some(&expr[j + 2..])),
+                None => (expr, None),
+            };
+            match (env(name), default) {
+                (Some(v), Some(d)) if v.is_empty() => out.push_str(d),
+                (Some(v), _) => out.push_str(&v),
+                (None, Some(d)) => out.push_str(d),
+                (None, None) => {
+                    return Err(Error::EnvironmentVariableNotFound {
+                        name: name.to_string(),
+                    })
+                }
+            }
+            rest = &rest[end + 1..];
+        } else {
+            out.push('$');
+            rest = &rest[1..];
+        }
+    }
+    out.push_str(rest);
+
+    Ok(out)
+}
+
+/// Reads and expands an included file, resolving its own includes relative to its directory
+fn read_include(
+    path: &Path,
+    env: Option<&Env>,
+    stack: &mut Vec<PathBuf>,
+) -> Result<serde_yaml::Value, Error> {
+    let include_failed = |source| Error::ConfigIncludeFailed {
+        path: path.to_string_lossy().into_owned(),
+        source,
+    };
+
+    let canonical = path.canonicalize().map_err(include_failed)?;
+    if stack.contains(&canonical) {
+        return Err(include_failed(std::io::Error::new(
+            std::io::ErrorKind::InvalidData,
+            "file includes itself",
+        )));
+    }
+    let text = std::fs::read_to_string(&canonical).map_err(include_failed)?;
+
+    stack.push(canonical.clone());
+    let base_dir = canonical.parent().unwrap_or_else(|| Path::new("."));
+    let expanded = preprocess(&text, base_dir, env, stack);
+    stack.pop();
+    expanded
+}
+
+/// Checks a list of named arguments for duplicate or reserved names, and for default values that
+/// cannot be converted to the type of the argument
+fn validate_arguments(arguments: &[Argument]) -> Result<(), Error> {
+    arguments.iter().try_for_each(|a| {
+        if arguments.iter().filter(|a2| a2.name == a.name).count() > 1 {
+            return Err(Error::ConfigItemDuplicated {
+                type_name: a.name.to_string(),
+            });
+        }
+
+        if a.name == "input" || a.name == "partitionKey" {
+            return Err(Error::ConfigItemReserved {
+                type_name: a.name.to_string(),
+            });
+        }
+
+        if let Some(default) = &a.default {
+            let parsed = match (a.type_name.as_str(), a.list) {
+                ("Boolean", false) => default.parse::<bool>().is_ok(),
+                ("Float", false) => default.parse::<f64>().is_ok(),
+                ("Int", false) => default.parse::<i32>().is_ok(),
+                ("ID", false) | ("String", false) => true,
+                (_, _) => false,
+            };
+
+            if !parsed {
+                return Err(Error::TypeConversionFailed {
+                    src: default.to_string(),
+                    dst: if a.list {
+                        "[".to_string() + &a.type_name + "]"
+                    } else {
+                        a.type_name.to_string()
+                    },
+                });
+            }
+        }
+
+        Ok(())
+    })
+}
+
+/// Configuration item for a named argument to a custom endpoint or to a property or relationship
+/// with a custom resolver
+///
+/// # Examples
+///
+/// ```rust
+/// # use warpgrapher::engine::config::Argument;
+///
+/// let a = Argument::new("limit".to_string(), "Int".to_string(), false, false)
+///     .with_default("20".to_string());
+/// ```
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Argument {
+    /// Name of the argument
+    name: String,
+
+    /// The name of the type of the argument, either a GraphQL scalar (e.g. String) or the name
+    /// of an input type in the schema
+    #[serde(rename = "type")]
+    type_name: String,
+
+    /// True if the argument must be provided by the client
+    #[serde(default = "get_false")]
+    required: bool,
+
+    /// True if the argument is a list
+    #[serde(default = "get_false")]
+    list: bool,
+
+    /// Optional default value of the argument, used if the client does not provide one
+    #[serde(default = "get_none")]
+    default: Option<String>,
+
+    /// Optional description of the argument, included in the GraphQL schema
+    #[serde(default = "get_none")]
+    description: Option<String>,
+}
+
+impl Argument {
+    /// Creates a new Argument struct.
+    ///
+    /// # Arguments
+    ///
+    /// * a String for the name of the argument
+    /// * a String for the type of the argument
+    /// * a boolean that, if true, indicates that the argument is mandatory
+    /// * a boolean that, if true, indicates that the argument is a list
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use warpgrapher::engine::config::Argument;
+    ///
+    /// let a = Argument::new("text".to_string(), "String".to_string(), true, false);
+    /// ```
+    pub fn new(name: String, type_name: String, required: bool, list: bool) -> Argument {
+        Argument {
+            name,
+            type_name,
+            required,
+            list,
+            default: None,
+            description: None,
+        }
+    }
+
+    /// Returns the optional default value of the argument, which is used if the client omits
+    /// the argument. Defaults are supported for arguments of scalar type that are not lists.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use warpgrapher::engine::config::Argument;
+    /// # use std::convert::TryFrom;
+    ///
+    /// let a = Argument::try_from("
+    /// name: limit
+    /// type: Int
+    /// default: 20
+    /// ").unwrap();
+    ///
+    /// assert_eq!(Some("20"), a.default());
+    /// ```
+    pub fn default(&self) -> Option<&str> {
+        self.default.as_deref()
+    }
+
+    /// Returns the optional description of the argument, which is included in the GraphQL
+    /// schema.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use warpgrapher::engine::config::Argument;
+    ///
+    /// let a = Argument::new("text".to_string(), "String".to_string(), true, false)
+    ///     .with_description("Text to search for".to_string());
+    ///
+    /// assert_eq!(Some("Text to search for"), a.description());
+    /// ```
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    /// Returns true if the argument is a list
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use warpgrapher::engine::config::Argument;
+    ///
+    /// let a = Argument::new("labels".to_string(), "String".to_string(), false, true);
+    ///
+    /// assert!(a.list());
+    /// ```
+    pub fn list(&self) -> bool {
+        self.list
+    }
+
+    /// Returns the name of the argument
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use warpgrapher::engine::config::Argument;
+    ///
+    /// let a = Argument::new("text".to_string(), "String".to_string(), true, false);
+    ///
+    /// assert_eq!("text", a.name());
+    /// ```
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns true if the argument must be provided by the client
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use warpgrapher::engine::config::Argument;
+    ///
+    /// let a = Argument::new("text".to_string(), "String".to_string(), true, false);
+    ///
+    /// assert!(a.required());
+    /// ```
+    pub fn required(&self) -> bool {
+        self.required
+    }
+
+    /// Returns the name of the type of the argument
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use warpgrapher::engine::config::Argument;
+    ///
+    /// let a = Argument::new("text".to_string(), "String".to_string(), true, false);
+    ///
+    /// assert_eq!("String", a.type_name());
+    /// ```
+    pub fn type_name(&self) -> &str {
+        &self.type_name
+    }
+
+    /// Sets the default value of the argument, used if the client omits the argument
+    pub fn with_default(mut self, default: String) -> Self {
+        self.default = Some(default);
+        self
+    }
+
+    /// Sets the description of the argument, which is included in the GraphQL schema.
+    pub fn with_description(mut self, description: String) -> Self {
+        self.description = Some(description);
+        self
+    }
+}
+
+impl TryFrom<&str> for Argument {
+    type Error = Error;
+
+    /// Creates a new Argument struct from a yaml-formatted string.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`] variant [`YamlDeserializationFailed`] if the yaml-formatted
+    /// string is improperly formatted.
+    ///
+    /// [`Error`]: ../../error/enum.Error.html
+    /// [`YamlDeserializationFailed`]: ../../error/enum.Error.html#variant.YamlDeserializationFailed
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use warpgrapher::engine::config::Argument;
+    /// # use std::convert::TryFrom;
+    ///
+    /// let a = Argument::try_from("
+    /// name: text
+    /// type: String
+    /// required: true
+    /// ").unwrap();
+    /// ```
+    fn try_from(yaml: &str) -> Result<Argument, Error> {
+        serde_yaml::from_str(yaml).map_err(|e| Error::YamlDeserializationFailed { source: e })
+    }
+}
+
 /// Configuration item for custom endpoints
 ///
 /// # Examples
@@ -315,8 +1001,24 @@ pub struct Endpoint {
     /// Defines the input of the endpoint
     input: Option<EndpointType>,
 
+    /// Named arguments to the endpoint, in addition to the optional input
+    #[serde(default)]
+    arguments: Vec<Argument>,
+
     /// Defines the type returned by the endpoint
     output: EndpointType,
+
+    /// Optional description of the endpoint, included in the GraphQL schema
+    #[serde(default = "get_none")]
+    description: Option<String>,
+
+    /// True if the endpoint is deprecated
+    #[serde(default = "get_false")]
+    deprecated: bool,
+
+    /// Optional reason that the endpoint is deprecated, included in the GraphQL schema
+    #[serde(default = "get_none")]
+    deprecation_reason: Option<String>,
 }
 
 impl Endpoint {
@@ -341,7 +1043,11 @@ impl Endpoint {
             name,
             class,
             input,
+            arguments: Vec::new(),
             output,
+            description: None,
+            deprecated: false,
+            deprecation_reason: None,
         }
     }
 
@@ -400,6 +1106,35 @@ impl Endpoint {
         self.input.as_ref()
     }
 
+    /// Returns an iterator over the named [`Argument`] definitions of a custom endpoint.
+    ///
+    /// [`Argument`]: ./struct.Argument.html
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use warpgrapher::engine::config::{Argument, Endpoint, EndpointClass, EndpointType,
+    /// #   TypeDef, GraphqlType};
+    ///
+    /// let e = Endpoint::new("SearchIssues".to_string(), EndpointClass::Query, None,
+    ///     EndpointType::new(TypeDef::Existing("Issue".to_string()), true, true))
+    ///     .with_arguments(vec![
+    ///         Argument::new("text".to_string(), "String".to_string(), true, false),
+    ///         Argument::new("limit".to_string(), "Int".to_string(), false, false)
+    ///             .with_default("20".to_string())]);
+    ///
+    /// assert_eq!(2, e.arguments().count());
+    /// ```
+    pub fn arguments(&self) -> Iter<'_, Argument> {
+        self.arguments.iter()
+    }
+
+    /// Sets the named arguments of the endpoint.
+    pub fn with_arguments(mut self, arguments: Vec<Argument>) -> Self {
+        self.arguments = arguments;
+        self
+    }
+
     /// Returns the type definition of the output for a custom endpoint
     ///
     /// # Examples
@@ -417,6 +1152,75 @@ impl Endpoint {
     pub fn output(&self) -> &EndpointType {
         &self.output
     }
+
+    /// Returns the optional description of the endpoint, which is included in the GraphQL
+    /// schema.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use warpgrapher::engine::config::{Endpoint, EndpointClass, EndpointType, TypeDef,
+    /// #   GraphqlType};
+    ///
+    /// let e = Endpoint::new("CountItems".to_string(), EndpointClass::Query, None,
+    ///     EndpointType::new(TypeDef::Scalar(GraphqlType::Int), false, true))
+    ///     .with_description("Counts the items".to_string());
+    ///
+    /// assert_eq!(Some("Counts the items"), e.description());
+    /// ```
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    /// Returns true if the endpoint is deprecated.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use warpgrapher::engine::config::{Endpoint, EndpointClass, EndpointType, TypeDef,
+    /// #   GraphqlType};
+    ///
+    /// let e = Endpoint::new("CountItems".to_string(), EndpointClass::Query, None,
+    ///     EndpointType::new(TypeDef::Scalar(GraphqlType::Int), false, true));
+    ///
+    /// assert!(!e.deprecated());
+    /// ```
+    pub fn deprecated(&self) -> bool {
+        self.deprecated
+    }
+
+    /// Returns the optional reason that the endpoint is deprecated.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use warpgrapher::engine::config::{Endpoint, EndpointClass, EndpointType, TypeDef,
+    /// #   GraphqlType};
+    ///
+    /// let e = Endpoint::new("CountItems".to_string(), EndpointClass::Query, None,
+    ///     EndpointType::new(TypeDef::Scalar(GraphqlType::Int), false, true))
+    ///     .with_deprecation(Some("No longer supported".to_string()));
+    ///
+    /// assert!(e.deprecated());
+    /// assert_eq!(Some("No longer supported"), e.deprecation_reason());
+    /// ```
+    pub fn deprecation_reason(&self) -> Option<&str> {
+        self.deprecation_reason.as_deref()
+    }
+
+    /// Sets the description of the endpoint, which is included in the GraphQL schema.
+    pub fn with_description(mut self, description: String) -> Self {
+        self.description = Some(description);
+        self
+    }
+
+    /// Marks the endpoint as deprecated, with an optional reason that is included in the GraphQL
+    /// schema.
+    pub fn with_deprecation(mut self, reason: Option<String>) -> Self {
+        self.deprecated = true;
+        self.deprecation_reason = reason;
+        self
+    }
 }
 
 impl TryFrom<&str> for Endpoint {
@@ -680,71 +1484,378 @@ impl EndpointType {
     /// # Examples
     ///
     /// ```rust
-    /// # use warpgrapher::engine::config::{EndpointType, GraphqlType, TypeDef};
+    /// # use warpgrapher::engine::config::{EndpointType, GraphqlType, TypeDef};
+    ///
+    /// let et = EndpointType::new(TypeDef::Scalar(GraphqlType::Int), false, true);
+    ///
+    /// assert_eq!(false, et.list());
+    /// ```
+    pub fn list(&self) -> bool {
+        self.list
+    }
+
+    /// True if the type associated with a custom GraphQL endpoint is required; false if it is
+    /// optional
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use warpgrapher::engine::config::{EndpointType, GraphqlType, TypeDef};
+    ///
+    /// let et = EndpointType::new(TypeDef::Scalar(GraphqlType::Int), false, true);
+    ///
+    /// assert_eq!(true, et.required());
+    /// ```
+    pub fn required(&self) -> bool {
+        self.required
+    }
+
+    /// Returns a [`TypeDef`] enumeration, describing whether the type is a GraphQL scalar type,
+    /// an existing type defined elsewhere in the configuration, or a new custom type.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use warpgrapher::engine::config::{EndpointType, GraphqlType, TypeDef};
+    ///
+    /// let et = EndpointType::new(TypeDef::Scalar(GraphqlType::Int), false, true);
+    ///
+    /// assert_eq!(&TypeDef::Scalar(GraphqlType::Int), et.type_def());
+    /// ```
+    pub fn type_def(&self) -> &TypeDef {
+        &self.type_def
+    }
+}
+
+/// Enumeration representing Graphql scalar types
+///
+/// # Examples
+///
+/// ```rust
+/// # use warpgrapher::engine::config::GraphqlType;
+///
+/// let gt = GraphqlType::Int;
+/// ```
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub enum GraphqlType {
+    /// GraphQL integer
+    Int,
+
+    /// GraphQL floating point number
+    Float,
+
+    /// GraphQL string value
+    String,
+
+    /// GraphQL boolean value
+    Boolean,
+}
+
+fn get_partition_key_property() -> String {
+    "partitionKey".to_string()
+}
+
+/// Configuration item for the partition key of a type, used by database back-ends that partition
+/// the graph, such as Cosmos DB. Other back-ends ignore it.
+///
+/// When resolving a query or mutation, the partition key is computed from the request context if
+/// `request_context` is true, in which case the endpoints of the type take no `partitionKey`
+/// argument. Otherwise, it is taken from the `partitionKey` argument, if one is given. Failing
+/// that, it is read from the parent node, or, when creating a node, taken from the value of the
+/// partition key property in the input.
+///
+/// # Examples
+///
+/// ```rust
+/// # use warpgrapher::engine::config::PartitionKey;
+///
+/// let pk = PartitionKey::new("tenant".to_string())
+///     .with_request_context(true)
+///     .with_cross_partition(true);
+/// ```
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PartitionKey {
+    /// Name of the property that holds the partition key. Defaults to `partitionKey`.
+    #[serde(default = "get_partition_key_property")]
+    property: String,
+
+    /// True if the partition key is computed from the request context, using
+    /// [`RequestContext::partition_key`]
+    ///
+    /// [`RequestContext::partition_key`]: ../context/trait.RequestContext.html#method.partition_key
+    #[serde(default = "get_false")]
+    request_context: bool,
+
+    /// True if queries may run across all partitions when no partition key is given
+    #[serde(default = "get_false")]
+    cross_partition: bool,
+}
+
+impl PartitionKey {
+    /// Creates a new PartitionKey struct, naming the property that holds the partition key
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use warpgrapher::engine::config::PartitionKey;
+    ///
+    /// let pk = PartitionKey::new("tenant".to_string());
+    ///
+    /// assert_eq!("tenant", pk.property());
+    /// assert!(!pk.request_context());
+    /// assert!(!pk.cross_partition());
+    /// ```
+    pub fn new(property: String) -> PartitionKey {
+        PartitionKey {
+            property,
+            request_context: false,
+            cross_partition: false,
+        }
+    }
+
+    /// Returns the name of the property that holds the partition key
+    pub fn property(&self) -> &str {
+        &self.property
+    }
+
+    /// Returns true if the partition key is computed from the request context
+    pub fn request_context(&self) -> bool {
+        self.request_context
+    }
+
+    /// Returns true if queries may run across all partitions when no partition key is given
+    pub fn cross_partition(&self) -> bool {
+        self.cross_partition
+    }
+
+    /// Sets whether the partition key is computed from the request context, using
+    /// [`RequestContext::partition_key`]
+    ///
+    /// [`RequestContext::partition_key`]: ../context/trait.RequestContext.html#method.partition_key
+    pub fn with_request_context(mut self, request_context: bool) -> Self {
+        self.request_context = request_context;
+        self
+    }
+
+    /// Sets whether queries may run across all partitions when no partition key is given
+    pub fn with_cross_partition(mut self, cross_partition: bool) -> Self {
+        self.cross_partition = cross_partition;
+        self
+    }
+}
+
+impl Default for PartitionKey {
+    fn default() -> Self {
+        PartitionKey::new(get_partition_key_property())
+    }
+}
+
+/// Strategy used to generate the ids of new nodes of a type
+///
+/// # Examples
+///
+/// ```rust
+/// # use warpgrapher::engine::config::IdStrategy;
+///
+/// let strategy = IdStrategy::Ulid;
+/// ```
+#[derive(Clone, Debug, Default, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub enum IdStrategy {
+    /// The id is left to the database back-end:
+    ///
+    /// * Neo4J - a random UUID v4, generated by the Cypher `randomUUID()` function as the node is
+    ///   created
+    /// * Cosmos DB and Gremlin-based DBs - the id assigned by the database, whose form depends on
+    ///   the database and its configuration
+    /// * In-memory, PostgreSQL, and SQLite - a random UUID v4, generated as the node is stored
+    #[default]
+    Native,
+
+    /// A random UUID v4
+    UuidV4,
+
+    /// A UUID v7, which begins with a timestamp, followed by a counter of the ids generated
+    /// within the same millisecond, so that ids sort in the order of their creation
+    UuidV7,
+
+    /// A ULID, which begins with a timestamp, followed by a counter of the ids generated within
+    /// the same millisecond, so that ids sort in the order of their creation
+    Ulid,
+
+    /// The id is generated by a custom [`IdGeneratorFunc`], registered under the name given in
+    /// the `generator` field of the [`Identifier`], or under the name of the type if no generator
+    /// is named
+    ///
+    /// [`IdGeneratorFunc`]: ../id_generators/type.IdGeneratorFunc.html
+    /// [`Identifier`]: ./struct.Identifier.html
+    Custom,
+}
+
+/// Configuration item for the ids of a type, setting how the ids of new nodes are generated and
+/// whether clients may supply their own.
+///
+/// # Examples
+///
+/// ```rust
+/// # use warpgrapher::engine::config::{Identifier, IdStrategy};
+///
+/// let id = Identifier::new(IdStrategy::Custom)
+///     .with_generator("OrderNumber".to_string())
+///     .with_client_supplied(true);
+/// ```
+#[derive(Clone, Debug, Default, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Identifier {
+    /// Strategy used to generate the ids of new nodes. Defaults to `Native`.
+    #[serde(default)]
+    strategy: IdStrategy,
+
+    /// Name of the custom id generator function, used with the `Custom` strategy. Defaults to the
+    /// name of the type.
+    #[serde(default = "get_none")]
+    generator: Option<String>,
+
+    /// True if clients may give the id of a new node in the input to a create mutation, in place
+    /// of a generated id
+    #[serde(default = "get_false")]
+    client_supplied: bool,
+}
+
+impl Identifier {
+    /// Creates a new Identifier struct with the given id generation strategy
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use warpgrapher::engine::config::{Identifier, IdStrategy};
     ///
-    /// let et = EndpointType::new(TypeDef::Scalar(GraphqlType::Int), false, true);
+    /// let id = Identifier::new(IdStrategy::Ulid);
     ///
-    /// assert_eq!(false, et.list());
+    /// assert_eq!(&IdStrategy::Ulid, id.strategy());
+    /// assert_eq!(None, id.generator());
+    /// assert!(!id.client_supplied());
     /// ```
-    pub fn list(&self) -> bool {
-        self.list
+    pub fn new(strategy: IdStrategy) -> Identifier {
+        Identifier {
+            strategy,
+            generator: None,
+            client_supplied: false,
+        }
     }
 
-    /// True if the type associated with a custom GraphQL endpoint is required; false if it is
-    /// optional
-    ///
-    /// # Examples
-    ///
-    /// ```rust
-    /// # use warpgrapher::engine::config::{EndpointType, GraphqlType, TypeDef};
-    ///
-    /// let et = EndpointType::new(TypeDef::Scalar(GraphqlType::Int), false, true);
-    ///
-    /// assert_eq!(true, et.required());
-    /// ```
-    pub fn required(&self) -> bool {
-        self.required
+    /// Returns the strategy used to generate the ids of new nodes
+    pub fn strategy(&self) -> &IdStrategy {
+        &self.strategy
     }
 
-    /// Returns a [`TypeDef`] enumeration, describing whether the type is a GraphQL scalar type,
-    /// an existing type defined elsewhere in the configuration, or a new custom type.
-    ///
-    /// # Examples
-    ///
-    /// ```rust
-    /// # use warpgrapher::engine::config::{EndpointType, GraphqlType, TypeDef};
-    ///
-    /// let et = EndpointType::new(TypeDef::Scalar(GraphqlType::Int), false, true);
-    ///
-    /// assert_eq!(&TypeDef::Scalar(GraphqlType::Int), et.type_def());
-    /// ```
-    pub fn type_def(&self) -> &TypeDef {
-        &self.type_def
+    /// Returns the name of the custom id generator function, if there is one
+    pub fn generator(&self) -> Option<&str> {
+        self.generator.as_deref()
+    }
+
+    /// Returns true if clients may supply the ids of new nodes
+    pub fn client_supplied(&self) -> bool {
+        self.client_supplied
+    }
+
+    /// Sets the name of the custom id generator function, used with the `Custom` strategy
+    pub fn with_generator(mut self, generator: String) -> Self {
+        self.generator = Some(generator);
+        self
+    }
+
+    /// Sets whether clients may supply the ids of new nodes
+    pub fn with_client_supplied(mut self, client_supplied: bool) -> Self {
+        self.client_supplied = client_supplied;
+        self
     }
 }
 
-/// Enumeration representing Graphql scalar types
+/// The point in the lifecycle of a node or relationship at which a [`Trigger`] runs
+///
+/// [`Trigger`]: ./struct.Trigger.html
 ///
 /// # Examples
 ///
 /// ```rust
-/// # use warpgrapher::engine::config::GraphqlType;
+/// # use warpgrapher::engine::config::TriggerEvent;
 ///
-/// let gt = GraphqlType::Int;
+/// let event = TriggerEvent::AfterCreate;
+/// ```
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TriggerEvent {
+    /// Before a node or relationship is created, once for each create mutation
+    BeforeCreate,
+
+    /// After a node or relationship is created, once for each one created
+    AfterCreate,
+
+    /// Before nodes or relationships are updated, once for each one matched by the update
+    BeforeUpdate,
+
+    /// After nodes or relationships are updated, once for each one updated
+    AfterUpdate,
+
+    /// Before nodes or relationships are deleted, once for each one matched by the delete
+    BeforeDelete,
+
+    /// After nodes or relationships are deleted, once for each one deleted
+    AfterDelete,
+}
+
+/// Configuration item for a lifecycle trigger on a type or relationship, naming a
+/// [`TriggerFunc`] to be run within the transaction of a mutation, before or after the node or
+/// relationship is created, updated, or deleted. Triggers run only for writes made by the top
+/// level of a mutation, not for nodes and relationships written through nested input.
+///
+/// [`TriggerFunc`]: ../triggers/type.TriggerFunc.html
+///
+/// # Examples
+///
+/// ```rust
+/// # use warpgrapher::engine::config::{Trigger, TriggerEvent};
+///
+/// let t = Trigger::new(TriggerEvent::AfterCreate, "CountIssues".to_string());
 /// ```
 #[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
-pub enum GraphqlType {
-    /// GraphQL integer
-    Int,
+#[serde(rename_all = "camelCase")]
+pub struct Trigger {
+    /// The point in the lifecycle at which the trigger runs
+    event: TriggerEvent,
 
-    /// GraphQL floating point number
-    Float,
+    /// The name of the trigger function to be run
+    handler: String,
+}
 
-    /// GraphQL string value
-    String,
+impl Trigger {
+    /// Creates a new Trigger struct, running the trigger function named `handler` at the given
+    /// lifecycle `event`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use warpgrapher::engine::config::{Trigger, TriggerEvent};
+    ///
+    /// let t = Trigger::new(TriggerEvent::BeforeDelete, "ArchiveIssue".to_string());
+    ///
+    /// assert_eq!(TriggerEvent::BeforeDelete, t.event());
+    /// assert_eq!("ArchiveIssue", t.handler());
+    /// ```
+    pub fn new(event: TriggerEvent, handler: String) -> Trigger {
+        Trigger { event, handler }
+    }
 
-    /// GraphQL boolean value
-    Boolean,
+    /// Returns the point in the lifecycle at which the trigger runs
+    pub fn event(&self) -> TriggerEvent {
+        self.event
+    }
+
+    /// Returns the name of the trigger function to be run
+    pub fn handler(&self) -> &str {
+        &self.handler
+    }
 }
 
 /// Configuration item for a property on a GraphQL type, modeled as properties on a graph node.
@@ -780,10 +1891,31 @@ pub struct Property {
     #[serde(default = "get_none")]
     resolver: Option<String>,
 
+    /// Named arguments passed to the custom resolver of this prop
+    #[serde(default)]
+    arguments: Vec<Argument>,
+
     /// The name of the validator function to be called when creating or modifying the value of
     /// this prop. If this field is None, the prop resolves the scalar value from the database.
     #[serde(default = "get_none")]
     validator: Option<String>,
+
+    /// Optional description of the property, included in the GraphQL schema
+    #[serde(default = "get_none")]
+    description: Option<String>,
+
+    /// True if the property is deprecated
+    #[serde(default = "get_false")]
+    deprecated: bool,
+
+    /// Optional reason that the property is deprecated, included in the GraphQL schema
+    #[serde(default = "get_none")]
+    deprecation_reason: Option<String>,
+
+    /// Optional key under which the property is stored in the database, if it differs from the
+    /// GraphQL name of the property
+    #[serde(default = "get_none")]
+    db_name: Option<String>,
 }
 
 impl Property {
@@ -822,7 +1954,12 @@ impl Property {
             required,
             list,
             resolver,
+            arguments: Vec::new(),
             validator,
+            description: None,
+            deprecated: false,
+            deprecation_reason: None,
+            db_name: None,
         }
     }
 
@@ -869,6 +2006,33 @@ impl Property {
         self.resolver.as_ref()
     }
 
+    /// Returns an iterator over the named [`Argument`] definitions passed to the custom resolver
+    /// of this property. Arguments are only added to the schema if the property has a resolver.
+    ///
+    /// [`Argument`]: ./struct.Argument.html
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use warpgrapher::engine::config::{Argument, Property};
+    ///
+    /// let p = Property::new("points".to_string(), "Int".to_string(), false, false,
+    ///     Some("CountPoints".to_string()), None)
+    ///     .with_arguments(vec![Argument::new("since".to_string(), "String".to_string(), false,
+    ///         false)]);
+    ///
+    /// assert_eq!("since", p.arguments().next().unwrap().name());
+    /// ```
+    pub fn arguments(&self) -> Iter<'_, Argument> {
+        self.arguments.iter()
+    }
+
+    /// Sets the named arguments passed to the custom resolver of this property.
+    pub fn with_arguments(mut self, arguments: Vec<Argument>) -> Self {
+        self.arguments = arguments;
+        self
+    }
+
     /// Returns a boolean that if true, indicates that this property is mandatory, and if false,
     /// that the property is not required, and may be absent.
     ///
@@ -910,6 +2074,93 @@ impl Property {
     pub fn validator(&self) -> Option<&String> {
         self.validator.as_ref()
     }
+
+    /// Returns the optional description of the property, which is included in the GraphQL
+    /// schema.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use warpgrapher::engine::config::Property;
+    ///
+    /// let p = Property::new("name".to_string(), "String".to_string(), true, false, None, None)
+    ///     .with_description("Name of the user".to_string());
+    ///
+    /// assert_eq!(Some("Name of the user"), p.description());
+    /// ```
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    /// Returns true if the property is deprecated.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use warpgrapher::engine::config::Property;
+    ///
+    /// let p = Property::new("name".to_string(), "String".to_string(), true, false, None, None);
+    ///
+    /// assert!(!p.deprecated());
+    /// ```
+    pub fn deprecated(&self) -> bool {
+        self.deprecated
+    }
+
+    /// Returns the optional reason that the property is deprecated.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use warpgrapher::engine::config::Property;
+    ///
+    /// let p = Property::new("name".to_string(), "String".to_string(), true, false, None, None)
+    ///     .with_deprecation(Some("No longer supported".to_string()));
+    ///
+    /// assert!(p.deprecated());
+    /// assert_eq!(Some("No longer supported"), p.deprecation_reason());
+    /// ```
+    pub fn deprecation_reason(&self) -> Option<&str> {
+        self.deprecation_reason.as_deref()
+    }
+
+    /// Sets the description of the property, which is included in the GraphQL schema.
+    pub fn with_description(mut self, description: String) -> Self {
+        self.description = Some(description);
+        self
+    }
+
+    /// Marks the property as deprecated, with an optional reason that is included in the GraphQL
+    /// schema.
+    pub fn with_deprecation(mut self, reason: Option<String>) -> Self {
+        self.deprecated = true;
+        self.deprecation_reason = reason;
+        self
+    }
+
+    /// Returns the optional key under which the property is stored in the database. If None, the
+    /// property is stored under its GraphQL name.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use warpgrapher::engine::config::Property;
+    ///
+    /// let p = Property::new("email".to_string(), "String".to_string(), true, false, None, None)
+    ///     .with_db_name("email_address".to_string());
+    ///
+    /// assert_eq!(Some("email_address"), p.db_name());
+    /// ```
+    pub fn db_name(&self) -> Option<&str> {
+        self.db_name.as_deref()
+    }
+
+    /// Sets the key under which the property is stored in the database, allowing the property
+    /// to be exposed in the GraphQL schema under a different name.
+    pub fn with_db_name(mut self, db_name: String) -> Self {
+        self.db_name = Some(db_name);
+        self
+    }
 }
 
 /// Configuration item for a relationship on a GraphQL type
@@ -956,6 +2207,37 @@ pub struct Relationship {
     /// If this field is None, the prop resolves the scalar value from the database.
     #[serde(default = "get_none")]
     resolver: Option<String>,
+
+    /// Named arguments passed to the custom resolver of this relationship
+    #[serde(default)]
+    arguments: Vec<Argument>,
+
+    /// Optional description of the relationship, included in the GraphQL schema
+    #[serde(default = "get_none")]
+    description: Option<String>,
+
+    /// True if the relationship is deprecated
+    #[serde(default = "get_false")]
+    deprecated: bool,
+
+    /// Optional reason that the relationship is deprecated, included in the GraphQL schema
+    #[serde(default = "get_none")]
+    deprecation_reason: Option<String>,
+
+    /// Optional relationship type (or edge label) under which the relationship is stored in the
+    /// database, if it differs from the GraphQL name of the relationship
+    #[serde(default = "get_none")]
+    db_type: Option<String>,
+
+    /// The name of the context-aware validator function to be called when creating or updating
+    /// the relationship
+    #[serde(default = "get_none")]
+    validator: Option<String>,
+
+    /// Lifecycle triggers run when the relationship is created, updated, or deleted by the top
+    /// level of a mutation
+    #[serde(default)]
+    triggers: Vec<Trigger>,
 }
 
 impl Relationship {
@@ -999,6 +2281,13 @@ impl Relationship {
             props,
             endpoints,
             resolver,
+            arguments: Vec::new(),
+            description: None,
+            deprecated: false,
+            deprecation_reason: None,
+            db_type: None,
+            validator: None,
+            triggers: Vec::new(),
         }
     }
 
@@ -1039,73 +2328,262 @@ impl Relationship {
         self.list
     }
 
-    /// Returns the name of the relationship
+    /// Returns the name of the relationship
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use warpgrapher::engine::config::{EndpointsFilter, Relationship};
+    ///
+    /// let r = Relationship::new("RelName".to_string(), true, vec!["User".to_string()], vec![],
+    ///     EndpointsFilter::all(), None);
+    ///
+    /// assert_eq!("RelName", r.name());
+    /// ```
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns an iterator over the names of the Warpgrapher [`Type`] definitions that are
+    /// possible destination nodes for this relationship.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use warpgrapher::engine::config::{EndpointsFilter, Relationship};
+    ///
+    /// let r = Relationship::new("RelName".to_string(), true, vec!["User".to_string()], vec![],
+    ///     EndpointsFilter::all(), None);
+    ///
+    /// assert_eq!(1, r.nodes().count());
+    /// assert_eq!("User", r.nodes().next().expect("Expected an element"));
+    /// ```
+    pub fn nodes(&self) -> Iter<String> {
+        self.nodes.iter()
+    }
+
+    /// Returns a slice of [`Property`] references for properties associated with the relationship.
+    ///
+    /// [`Property`]: ./struct.Property.html
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use warpgrapher::engine::config::{EndpointsFilter, Relationship};
+    ///
+    /// let r = Relationship::new("RelName".to_string(), true, vec!["User".to_string()], vec![],
+    ///     EndpointsFilter::all(), None);
+    ///
+    /// assert_eq!(0, r.props_as_slice().len())
+    /// ```
+    pub fn props_as_slice(&self) -> &[Property] {
+        &self.props
+    }
+
+    /// Returns an option for a string containing the name of a custom resolver, if this
+    /// relationship is resolved by a custom resolver instead of an auto-generated read resolver.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use warpgrapher::engine::config::{EndpointsFilter, Relationship};
+    ///
+    /// let r = Relationship::new("RelName".to_string(), true, vec!["User".to_string()], vec![],
+    ///     EndpointsFilter::all(), None);
+    ///
+    /// assert!(r.resolver().is_none())
+    /// ```
+    pub fn resolver(&self) -> Option<&String> {
+        self.resolver.as_ref()
+    }
+
+    /// Returns the optional description of the relationship, which is included in the GraphQL
+    /// schema.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use warpgrapher::engine::config::{EndpointsFilter, Relationship};
+    ///
+    /// let r = Relationship::new("owner".to_string(), false, vec!["User".to_string()], vec![],
+    ///     EndpointsFilter::all(), None)
+    ///     .with_description("Owner of the project".to_string());
+    ///
+    /// assert_eq!(Some("Owner of the project"), r.description());
+    /// ```
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    /// Returns true if the relationship is deprecated.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use warpgrapher::engine::config::{EndpointsFilter, Relationship};
+    ///
+    /// let r = Relationship::new("owner".to_string(), false, vec!["User".to_string()], vec![],
+    ///     EndpointsFilter::all(), None);
+    ///
+    /// assert!(!r.deprecated());
+    /// ```
+    pub fn deprecated(&self) -> bool {
+        self.deprecated
+    }
+
+    /// Returns the optional reason that the relationship is deprecated.
     ///
     /// # Examples
     ///
     /// ```rust
     /// # use warpgrapher::engine::config::{EndpointsFilter, Relationship};
     ///
-    /// let r = Relationship::new("RelName".to_string(), true, vec!["User".to_string()], vec![],
-    ///     EndpointsFilter::all(), None);
+    /// let r = Relationship::new("owner".to_string(), false, vec!["User".to_string()], vec![],
+    ///     EndpointsFilter::all(), None)
+    ///     .with_deprecation(Some("No longer supported".to_string()));
     ///
-    /// assert_eq!("RelName", r.name());
+    /// assert!(r.deprecated());
+    /// assert_eq!(Some("No longer supported"), r.deprecation_reason());
     /// ```
-    pub fn name(&self) -> &str {
-        &self.name
+    pub fn deprecation_reason(&self) -> Option<&str> {
+        self.deprecation_reason.as_deref()
     }
 
-    /// Returns an iterator over the names of the Warpgrapher [`Type`] definitions that are
-    /// possible destination nodes for this relationship.
+    /// Sets the description of the relationship, which is included in the GraphQL schema.
+    pub fn with_description(mut self, description: String) -> Self {
+        self.description = Some(description);
+        self
+    }
+
+    /// Marks the relationship as deprecated, with an optional reason that is included in the
+    /// GraphQL schema.
+    pub fn with_deprecation(mut self, reason: Option<String>) -> Self {
+        self.deprecated = true;
+        self.deprecation_reason = reason;
+        self
+    }
+
+    /// Returns the optional relationship type under which the relationship is stored in the
+    /// database. If None, the relationship is stored under its GraphQL name.
     ///
     /// # Examples
     ///
     /// ```rust
     /// # use warpgrapher::engine::config::{EndpointsFilter, Relationship};
     ///
-    /// let r = Relationship::new("RelName".to_string(), true, vec!["User".to_string()], vec![],
-    ///     EndpointsFilter::all(), None);
+    /// let r = Relationship::new(
+    ///     "members".to_string(),
+    ///     true,
+    ///     vec!["User".to_string()],
+    ///     vec![],
+    ///     EndpointsFilter::all(),
+    ///     None
+    /// ).with_db_type("HAS_MEMBER".to_string());
     ///
-    /// assert_eq!(1, r.nodes().count());
-    /// assert_eq!("User", r.nodes().next().expect("Expected an element"));
+    /// assert_eq!(Some("HAS_MEMBER"), r.db_type());
     /// ```
-    pub fn nodes(&self) -> Iter<String> {
-        self.nodes.iter()
+    pub fn db_type(&self) -> Option<&str> {
+        self.db_type.as_deref()
     }
 
-    /// Returns a slice of [`Property`] references for properties associated with the relationship.
-    ///
-    /// [`Property`]: ./struct.Property.html
+    /// Sets the relationship type under which the relationship is stored in the database,
+    /// allowing the relationship to be exposed in the GraphQL schema under a different name.
+    pub fn with_db_type(mut self, db_type: String) -> Self {
+        self.db_type = Some(db_type);
+        self
+    }
+
+    /// Returns the optional name of the context-aware validator called when the relationship is
+    /// created or updated
     ///
     /// # Examples
     ///
     /// ```rust
     /// # use warpgrapher::engine::config::{EndpointsFilter, Relationship};
     ///
-    /// let r = Relationship::new("RelName".to_string(), true, vec!["User".to_string()], vec![],
-    ///     EndpointsFilter::all(), None);
+    /// let r = Relationship::new(
+    ///     "members".to_string(),
+    ///     true,
+    ///     vec!["User".to_string()],
+    ///     vec![],
+    ///     EndpointsFilter::all(),
+    ///     None
+    /// ).with_validator("MaxMembers".to_string());
     ///
-    /// assert_eq!(0, r.props_as_slice().len())
+    /// assert_eq!(Some("MaxMembers"), r.validator());
     /// ```
-    pub fn props_as_slice(&self) -> &[Property] {
-        &self.props
+    pub fn validator(&self) -> Option<&str> {
+        self.validator.as_deref()
     }
 
-    /// Returns an option for a string containing the name of a custom resolver, if this
-    /// relationship is resolved by a custom resolver instead of an auto-generated read resolver.
+    /// Sets the name of the context-aware validator called when the relationship is created or
+    /// updated
+    pub fn with_validator(mut self, validator: String) -> Self {
+        self.validator = Some(validator);
+        self
+    }
+
+    /// Returns an iterator over the lifecycle [`Trigger`] definitions of the relationship
+    ///
+    /// [`Trigger`]: ./struct.Trigger.html
     ///
     /// # Examples
     ///
     /// ```rust
-    /// # use warpgrapher::engine::config::{EndpointsFilter, Relationship};
+    /// # use warpgrapher::engine::config::{EndpointsFilter, Relationship, Trigger, TriggerEvent};
+    ///
+    /// let r = Relationship::new(
+    ///     "issues".to_string(),
+    ///     true,
+    ///     vec!["Issue".to_string()],
+    ///     vec![],
+    ///     EndpointsFilter::all(),
+    ///     None
+    /// ).with_triggers(vec![Trigger::new(TriggerEvent::AfterCreate, "CountIssues".to_string())]);
+    ///
+    /// assert_eq!(1, r.triggers().count());
+    /// ```
+    pub fn triggers(&self) -> Iter<'_, Trigger> {
+        self.triggers.iter()
+    }
+
+    /// Sets the lifecycle triggers of the relationship
+    pub fn with_triggers(mut self, triggers: Vec<Trigger>) -> Self {
+        self.triggers = triggers;
+        self
+    }
+
+    /// Returns an iterator over the named [`Argument`] definitions passed to the custom resolver
+    /// of this relationship, in addition to the generated `input` argument. Arguments are only
+    /// added to the schema if the relationship has a resolver.
     ///
-    /// let r = Relationship::new("RelName".to_string(), true, vec!["User".to_string()], vec![],
-    ///     EndpointsFilter::all(), None);
+    /// [`Argument`]: ./struct.Argument.html
     ///
-    /// assert!(r.resolver().is_none())
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use warpgrapher::engine::config::{Argument, EndpointsFilter, Relationship};
+    ///
+    /// let r = Relationship::new(
+    ///     "topIssues".to_string(),
+    ///     true,
+    ///     vec!["Issue".to_string()],
+    ///     vec![],
+    ///     EndpointsFilter::all(),
+    ///     Some("TopIssues".to_string())
+    /// ).with_arguments(vec![Argument::new("limit".to_string(), "Int".to_string(), false,
+    ///     false).with_default("5".to_string())]);
+    ///
+    /// assert_eq!(Some("5"), r.arguments().next().unwrap().default());
     /// ```
-    pub fn resolver(&self) -> Option<&String> {
-        self.resolver.as_ref()
+    pub fn arguments(&self) -> Iter<'_, Argument> {
+        self.arguments.iter()
+    }
+
+    /// Sets the named arguments passed to the custom resolver of this relationship.
+    pub fn with_arguments(mut self, arguments: Vec<Argument>) -> Self {
+        self.arguments = arguments;
+        self
     }
 }
 
@@ -1128,7 +2606,8 @@ impl Relationship {
 #[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Type {
-    /// Name of this GraphQL type, also used as the Neo4J label for nodes
+    /// Name of this GraphQL type, also used as the Neo4J label for nodes unless a `db_label` is
+    /// given
     name: String,
 
     /// Vector of properties on this type
@@ -1142,6 +2621,46 @@ pub struct Type {
     /// auto generated for the relationship
     #[serde(default)]
     endpoints: EndpointsFilter,
+
+    /// Optional description of the type, included in the GraphQL schema
+    #[serde(default = "get_none")]
+    description: Option<String>,
+
+    /// True if the type is deprecated
+    #[serde(default = "get_false")]
+    deprecated: bool,
+
+    /// Optional reason that the type is deprecated, included in the GraphQL schema
+    #[serde(default = "get_none")]
+    deprecation_reason: Option<String>,
+
+    /// Optional label under which nodes of this type are stored in the database, if it differs
+    /// from the GraphQL name of the type
+    #[serde(default = "get_none")]
+    db_label: Option<String>,
+
+    /// Optional partition key configuration, for database back-ends that partition the graph
+    #[serde(default)]
+    partition_key: Option<PartitionKey>,
+
+    /// Optional configuration of how the ids of new nodes of this type are generated
+    #[serde(default)]
+    id: Option<Identifier>,
+
+    /// The name of the context-aware validator function to be called when creating or updating
+    /// nodes of this type
+    #[serde(default = "get_none")]
+    validator: Option<String>,
+
+    /// Lifecycle triggers run when nodes of this type are created, updated, or deleted by the top
+    /// level of a mutation
+    #[serde(default)]
+    triggers: Vec<Trigger>,
+
+    /// Optional time to live, in seconds, of cached responses to queries that read nodes of this
+    /// type
+    #[serde(default)]
+    cache_ttl: Option<u64>,
 }
 
 impl Type {
@@ -1185,11 +2704,23 @@ impl Type {
             props,
             rels,
             endpoints,
+            description: None,
+            deprecated: false,
+            deprecation_reason: None,
+            db_label: None,
+            partition_key: None,
+            id: None,
+            validator: None,
+            triggers: Vec::new(),
+            cache_ttl: None,
         }
     }
 
     /// Returns the name of the type. This type name is used as the label on nodes of this type in
-    /// the graph database storage back-end.
+    /// the graph database storage back-end, unless a different label is set with
+    /// [`with_db_label`].
+    ///
+    /// [`with_db_label`]: ./struct.Type.html#method.with_db_label
     ///
     /// # Examples
     ///
@@ -1302,6 +2833,212 @@ impl Type {
     pub fn rels(&self) -> Iter<Relationship> {
         self.rels.iter()
     }
+
+    /// Returns the optional description of the type, which is included in the GraphQL
+    /// schema.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use warpgrapher::engine::config::{EndpointsFilter, Type};
+    ///
+    /// let t = Type::new("User".to_string(), vec![], vec![], EndpointsFilter::all())
+    ///     .with_description("A user of the system".to_string());
+    ///
+    /// assert_eq!(Some("A user of the system"), t.description());
+    /// ```
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    /// Returns true if the type is deprecated.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use warpgrapher::engine::config::{EndpointsFilter, Type};
+    ///
+    /// let t = Type::new("User".to_string(), vec![], vec![], EndpointsFilter::all());
+    ///
+    /// assert!(!t.deprecated());
+    /// ```
+    pub fn deprecated(&self) -> bool {
+        self.deprecated
+    }
+
+    /// Returns the optional reason that the type is deprecated.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use warpgrapher::engine::config::{EndpointsFilter, Type};
+    ///
+    /// let t = Type::new("User".to_string(), vec![], vec![], EndpointsFilter::all())
+    ///     .with_deprecation(Some("No longer supported".to_string()));
+    ///
+    /// assert!(t.deprecated());
+    /// assert_eq!(Some("No longer supported"), t.deprecation_reason());
+    /// ```
+    pub fn deprecation_reason(&self) -> Option<&str> {
+        self.deprecation_reason.as_deref()
+    }
+
+    /// Sets the description of the type, which is included in the GraphQL schema.
+    pub fn with_description(mut self, description: String) -> Self {
+        self.description = Some(description);
+        self
+    }
+
+    /// Marks the type as deprecated, with an optional reason that is included in the GraphQL
+    /// schema.
+    pub fn with_deprecation(mut self, reason: Option<String>) -> Self {
+        self.deprecated = true;
+        self.deprecation_reason = reason;
+        self
+    }
+
+    /// Returns the optional label under which nodes of this type are stored in the database. If
+    /// None, the name of the type is used as the label.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use warpgrapher::engine::config::{EndpointsFilter, Type};
+    ///
+    /// let t = Type::new("User".to_string(), vec![], vec![], EndpointsFilter::all())
+    ///     .with_db_label("legacy_user".to_string());
+    ///
+    /// assert_eq!(Some("legacy_user"), t.db_label());
+    /// ```
+    pub fn db_label(&self) -> Option<&str> {
+        self.db_label.as_deref()
+    }
+
+    /// Sets the label under which nodes of this type are stored in the database, allowing the
+    /// type to be exposed in the GraphQL schema under a different name.
+    pub fn with_db_label(mut self, db_label: String) -> Self {
+        self.db_label = Some(db_label);
+        self
+    }
+
+    /// Returns the optional partition key configuration of the type. If None, database back-ends
+    /// that partition the graph use a `partitionKey` property that must be given as an argument
+    /// to each query and mutation.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use warpgrapher::engine::config::{EndpointsFilter, PartitionKey, Type};
+    ///
+    /// let t = Type::new("User".to_string(), vec![], vec![], EndpointsFilter::all())
+    ///     .with_partition_key(PartitionKey::new("tenant".to_string()));
+    ///
+    /// assert_eq!(Some("tenant"), t.partition_key().map(|pk| pk.property()));
+    /// ```
+    pub fn partition_key(&self) -> Option<&PartitionKey> {
+        self.partition_key.as_ref()
+    }
+
+    /// Sets the partition key configuration of the type
+    pub fn with_partition_key(mut self, partition_key: PartitionKey) -> Self {
+        self.partition_key = Some(partition_key);
+        self
+    }
+
+    /// Returns the optional id configuration of the type. If None, the ids of new nodes are
+    /// generated by the database back-end.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use warpgrapher::engine::config::{EndpointsFilter, Identifier, IdStrategy, Type};
+    ///
+    /// let t = Type::new("User".to_string(), vec![], vec![], EndpointsFilter::all())
+    ///     .with_id(Identifier::new(IdStrategy::UuidV7));
+    ///
+    /// assert_eq!(Some(&IdStrategy::UuidV7), t.id().map(|id| id.strategy()));
+    /// ```
+    pub fn id(&self) -> Option<&Identifier> {
+        self.id.as_ref()
+    }
+
+    /// Sets the id configuration of the type
+    pub fn with_id(mut self, id: Identifier) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Returns the optional name of the context-aware validator called when nodes of the type are
+    /// created or updated
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use warpgrapher::engine::config::{EndpointsFilter, Type};
+    ///
+    /// let t = Type::new("Project".to_string(), vec![], vec![], EndpointsFilter::all())
+    ///     .with_validator("ProjectDates".to_string());
+    ///
+    /// assert_eq!(Some("ProjectDates"), t.validator());
+    /// ```
+    pub fn validator(&self) -> Option<&str> {
+        self.validator.as_deref()
+    }
+
+    /// Sets the name of the context-aware validator called when nodes of the type are created or
+    /// updated
+    pub fn with_validator(mut self, validator: String) -> Self {
+        self.validator = Some(validator);
+        self
+    }
+
+    /// Returns an iterator over the lifecycle [`Trigger`] definitions of the type
+    ///
+    /// [`Trigger`]: ./struct.Trigger.html
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use warpgrapher::engine::config::{EndpointsFilter, Trigger, TriggerEvent, Type};
+    ///
+    /// let t = Type::new("Issue".to_string(), vec![], vec![], EndpointsFilter::all())
+    ///     .with_triggers(vec![Trigger::new(TriggerEvent::AfterCreate, "LogActivity".to_string())]);
+    ///
+    /// assert_eq!(1, t.triggers().count());
+    /// ```
+    pub fn triggers(&self) -> Iter<'_, Trigger> {
+        self.triggers.iter()
+    }
+
+    /// Sets the lifecycle triggers run when nodes of the type are created, updated, or deleted
+    pub fn with_triggers(mut self, triggers: Vec<Trigger>) -> Self {
+        self.triggers = triggers;
+        self
+    }
+
+    /// Returns the optional time to live, in seconds, of cached responses to queries that read
+    /// nodes of the type
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use warpgrapher::engine::config::{EndpointsFilter, Type};
+    ///
+    /// let t = Type::new("Project".to_string(), vec![], vec![], EndpointsFilter::all())
+    ///     .with_cache_ttl(300);
+    ///
+    /// assert_eq!(Some(300), t.cache_ttl());
+    /// ```
+    pub fn cache_ttl(&self) -> Option<u64> {
+        self.cache_ttl
+    }
+
+    /// Sets the time to live, in seconds, of cached responses to queries that read nodes of the
+    /// type
+    pub fn with_cache_ttl(mut self, cache_ttl: u64) -> Self {
+        self.cache_ttl = Some(cache_ttl);
+        self
+    }
 }
 
 impl TryFrom<&str> for Type {
@@ -1716,12 +3453,13 @@ pub(crate) fn mock_endpoints_filter() -> Configuration {
 #[cfg(test)]
 mod tests {
     use super::{
-        compose, Configuration, Endpoint, EndpointType, EndpointsFilter, Property, Relationship,
-        Type,
+        compose, Configuration, Endpoint, EndpointType, EndpointsFilter, IdStrategy, Identifier,
+        Property, Relationship, Type,
     };
     use crate::Error;
-    use std::convert::TryInto;
+    use std::convert::{TryFrom, TryInto};
     use std::fs::File;
+    use std::path::Path;
 
     /// There's not really much of a "test" per se, in this first unit test.
     /// This is the example used in the book/src/warpgrapher/config.md file, so
@@ -1841,6 +3579,33 @@ mod tests {
         assert!(t.props.get(1).unwrap().name == "role");
     }
 
+    /// Passes if the id configuration of a type is deserialized, with defaults for omitted fields
+    #[test]
+    fn type_id_config() {
+        let t = Type::try_from(
+            "
+name: Order
+id:
+  strategy: Custom
+  generator: OrderNumber
+  clientSupplied: true
+props: []
+",
+        )
+        .unwrap();
+        let id = t.id().unwrap();
+        assert_eq!(&IdStrategy::Custom, id.strategy());
+        assert_eq!(Some("OrderNumber"), id.generator());
+        assert!(id.client_supplied());
+
+        let t = Type::try_from("{name: Event, id: {}, props: []}").unwrap();
+        assert_eq!(Some(&Identifier::default()), t.id());
+        assert_eq!(&IdStrategy::Native, t.id().unwrap().strategy());
+
+        let t = Type::try_from("{name: User, props: []}").unwrap();
+        assert!(t.id().is_none());
+    }
+
     #[allow(clippy::match_wild_err_arm)]
     #[test]
     fn test_validate() {
@@ -1941,6 +3706,252 @@ mod tests {
             Err(Error::ConfigItemDuplicated { type_name: _ }) => (),
             _ => panic!(),
         }
+
+        let duplicate_db_label_config: Configuration =
+            match File::open("tests/fixtures/config-validation/test_config_duplicate_db_label.yml")
+                .expect("Couldn't open file")
+                .try_into()
+            {
+                Err(e) => panic!(e),
+                Ok(wgc) => wgc,
+            };
+
+        match duplicate_db_label_config.validate() {
+            Err(Error::ConfigItemDuplicated { type_name }) => assert_eq!(type_name, "legacy_user"),
+            _ => panic!(),
+        }
+
+        let duplicate_db_name_config: Configuration =
+            match File::open("tests/fixtures/config-validation/test_config_duplicate_db_name.yml")
+                .expect("Couldn't open file")
+                .try_into()
+            {
+                Err(e) => panic!(e),
+                Ok(wgc) => wgc,
+            };
+
+        match duplicate_db_name_config.validate() {
+            Err(Error::ConfigItemDuplicated { type_name }) => assert_eq!(type_name, "name"),
+            _ => panic!(),
+        }
+
+        let prop_db_name_id_config: Configuration =
+            match File::open("tests/fixtures/config-validation/test_config_prop_db_name_id.yml")
+                .expect("Couldn't open file")
+                .try_into()
+            {
+                Err(e) => panic!(e),
+                Ok(wgc) => wgc,
+            };
+
+        match prop_db_name_id_config.validate() {
+            Err(Error::ConfigItemReserved { type_name: _ }) => (),
+            _ => panic!(),
+        }
+    }
+
+    /// Passes if endpoint and resolver arguments with duplicate or reserved names, or with
+    /// default values that do not match their type, fail validation
+    #[test]
+    fn test_config_validate_arguments() {
+        let config = |arguments: &str| {
+            Configuration::try_from(
+                "
+version: 1
+model:
+  - name: Issue
+    props:
+      - name: title
+        type: String
+endpoints:
+  - name: SearchIssues
+    class: Query
+    input: null
+    output:
+      type: Issue
+      list: true
+    arguments:
+"
+                .to_string()
+                    + arguments,
+            )
+            .unwrap()
+        };
+
+        assert!(config(
+            "
+      - name: text
+        type: String
+        required: true
+      - name: limit
+        type: Int
+        default: 20
+      - name: labels
+        type: String
+        list: true
+"
+        )
+        .validate()
+        .is_ok());
+
+        match config(
+            "
+      - name: text
+        type: String
+      - name: text
+        type: Int
+",
+        )
+        .validate()
+        {
+            Err(Error::ConfigItemDuplicated { type_name }) => assert_eq!(type_name, "text"),
+            _ => panic!(),
+        }
+
+        match config(
+            "
+      - name: input
+        type: String
+",
+        )
+        .validate()
+        {
+            Err(Error::ConfigItemReserved { type_name }) => assert_eq!(type_name, "input"),
+            _ => panic!(),
+        }
+
+        match config(
+            "
+      - name: limit
+        type: Int
+        default: twenty
+",
+        )
+        .validate()
+        {
+            Err(Error::TypeConversionFailed { src, dst }) => {
+                assert_eq!(src, "twenty");
+                assert_eq!(dst, "Int");
+            }
+            _ => panic!(),
+        }
+    }
+
+    fn mock_env(name: &str) -> Option<String> {
+        match name {
+            "WG_TEST_INTERPOLATE_NAME" => Some("User".to_string()),
+            "WG_TEST_INTERPOLATE_EMPTY" => Some(String::new()),
+            "WG_TEST_INCLUDE_USER_CREATE" => Some("false".to_string()),
+            _ => None,
+        }
+    }
+
+    /// Passes if environment variable references are replaced by values or defaults, and an
+    /// unset variable without a default is reported by name
+    #[test]
+    fn interpolate_env_vars() {
+        let c = Configuration::from_str_with(
+            "version: ${WG_TEST_INTERPOLATE_UNSET:-2}
+# ${WG_TEST_INTERPOLATE_UNSET} is ignored in comments
+model:
+  - name: ${WG_TEST_INTERPOLATE_NAME} # ${WG_TEST_INTERPOLATE_UNSET} is ignored here too
+    props:
+      - name: ${WG_TEST_INTERPOLATE_EMPTY:-email}
+        type: String
+        description: costs $$5 or $${WG_TEST_INTERPOLATE_NAME}",
+            Path::new("."),
+            Some(&mock_env),
+        )
+        .unwrap();
+
+        assert_eq!(2, c.version());
+        let t = c.types().next().unwrap();
+        assert_eq!("User", t.name());
+        let p = t.props().next().unwrap();
+        assert_eq!("email", p.name());
+        assert_eq!(
+            Some("costs $$5 or ${WG_TEST_INTERPOLATE_NAME}"),
+            p.description()
+        );
+
+        match Configuration::from_str_with(
+            "version: ${WG_TEST_INTERPOLATE_UNSET}\nmodel: []",
+            Path::new("."),
+            Some(&mock_env),
+        ) {
+            Err(Error::EnvironmentVariableNotFound { name }) => {
+                assert_eq!("WG_TEST_INTERPOLATE_UNSET", name)
+            }
+            _ => panic!(),
+        }
+    }
+
+    /// Passes if configurations read without environment expansion keep references as written
+    #[test]
+    fn interpolate_opt_in() {
+        let c = Configuration::try_from(
+            "version: 2
+model:
+  - name: User
+    props:
+      - name: email
+        type: String
+        description: ${WG_TEST_INTERPOLATE_UNSET}",
+        )
+        .unwrap();
+        let p = c.types().next().unwrap().props().next().unwrap();
+        assert_eq!(Some("${WG_TEST_INTERPOLATE_UNSET}"), p.description());
+    }
+
+    /// Passes if included files are nested as list items and mapping values, relative to the
+    /// including file, with environment variables interpolated in the included files
+    #[test]
+    fn include_files() {
+        let c = Configuration::from_file_with(
+            Path::new("tests/fixtures/config-include/test_config_include.yml"),
+            Some(&mock_env),
+        )
+        .unwrap();
+
+        assert_eq!(2, c.version());
+        assert!(c.validate().is_ok());
+
+        let mut types = c.types();
+        let user = types.next().unwrap();
+        assert_eq!("User", user.name());
+        assert_eq!("email", user.props().next().unwrap().name());
+        assert!(user.endpoints().read());
+        assert!(!user.endpoints().create());
+
+        let project = types.next().unwrap();
+        assert_eq!("Project", project.name());
+        let owner = project.rels().next().unwrap();
+        assert_eq!("owner", owner.name());
+        assert_eq!("User", owner.nodes().next().unwrap());
+    }
+
+    /// Passes if missing and circular includes are reported with the path of the included file
+    #[test]
+    fn include_failures() {
+        match Configuration::from_str_with(
+            "version: 2\nmodel:\n  - !include does_not_exist.yml",
+            Path::new("."),
+            None,
+        ) {
+            Err(Error::ConfigIncludeFailed { path, source: _ }) => {
+                assert!(path.ends_with("does_not_exist.yml"))
+            }
+            _ => panic!(),
+        }
+
+        match Configuration::try_from(Path::new(
+            "tests/fixtures/config-include/test_config_include_cycle.yml",
+        )) {
+            Err(Error::ConfigIncludeFailed { path, source: _ }) => {
+                assert!(path.ends_with("test_config_include_cycle.yml"))
+            }
+            _ => panic!(),
+        }
     }
 
     #[allow(clippy::match_wild_err_arm)]
@@ -1,15 +1,22 @@
 //! This module provides a Juniper Context for Warpgrapher GraphQL queries. The
 //! context contains a connection pool for the Neo4J database.
+use crate::engine::cache::CacheStore;
+use crate::engine::database::retry::RetryPolicy;
 use crate::engine::database::DatabasePool;
 use crate::engine::extensions::{Extension, Extensions};
+use crate::engine::id_generators::IdGenerators;
+use crate::engine::limits::QueryLimits;
 use crate::engine::resolvers::{ResolverFunc, Resolvers};
-use crate::engine::validators::Validators;
+use crate::engine::triggers::Triggers;
+use crate::engine::validators::{ContextValidators, Validators};
+use crate::engine::value::Value;
 use crate::Error;
 use juniper::Context;
 use std::collections::HashMap;
 use std::fmt::Debug;
 use std::fmt::Formatter;
 use std::slice::Iter;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
 
 /// Juniper Context for Warpgrapher's GraphQL queries. The ['GraphQLContext'] is
@@ -56,12 +63,19 @@ where
     RequestCtx: RequestContext,
 {
     pool: DatabasePool,
-    resolvers: Resolvers<RequestCtx>,
-    validators: Validators,
+    resolvers: Arc<Resolvers<RequestCtx>>,
+    validators: Arc<Validators>,
     extensions: Extensions<RequestCtx>,
     request_ctx: Option<RequestCtx>,
     version: Option<String>,
     metadata: HashMap<String, String>,
+    retry_policy: RetryPolicy,
+    id_generators: IdGenerators,
+    context_validators: Arc<ContextValidators<RequestCtx>>,
+    triggers: Arc<Triggers<RequestCtx>>,
+    query_limits: QueryLimits,
+    nodes_read: AtomicUsize,
+    cache_store: Option<Arc<dyn CacheStore>>,
 }
 
 impl<RequestCtx> GraphQLContext<RequestCtx>
@@ -128,8 +142,8 @@ where
     #[allow(clippy::too_many_arguments)]
     pub fn new(
         pool: DatabasePool,
-        resolvers: Resolvers<RequestCtx>,
-        validators: Validators,
+        resolvers: impl Into<Arc<Resolvers<RequestCtx>>>,
+        validators: impl Into<Arc<Validators>>,
         extensions: Extensions<RequestCtx>,
         request_ctx: Option<RequestCtx>,
         version: Option<String>,
@@ -137,15 +151,188 @@ where
     ) -> GraphQLContext<RequestCtx> {
         GraphQLContext {
             pool,
-            resolvers,
-            validators,
+            resolvers: resolvers.into(),
+            validators: validators.into(),
             extensions,
             request_ctx,
             version,
             metadata,
+            retry_policy: RetryPolicy::default(),
+            id_generators: IdGenerators::new(),
+            context_validators: Arc::new(ContextValidators::new()),
+            triggers: Arc::new(Triggers::new()),
+            query_limits: QueryLimits::default(),
+            nodes_read: AtomicUsize::new(0),
+            cache_store: None,
         }
     }
 
+    /// Sets the [`RetryPolicy`] for the database operations of the generated resolvers. If not
+    /// set, the default [`RetryPolicy`] is used.
+    ///
+    /// [`RetryPolicy`]: ../database/retry/struct.RetryPolicy.html
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::collections::HashMap;
+    /// # use warpgrapher::engine::context::GraphQLContext;
+    /// # use warpgrapher::engine::database::DatabasePool;
+    /// # use warpgrapher::engine::database::retry::RetryPolicy;
+    /// # use warpgrapher::engine::resolvers::Resolvers;
+    /// # use warpgrapher::engine::validators::Validators;
+    ///
+    /// let gqlctx: GraphQLContext<()> = GraphQLContext::new(
+    ///     DatabasePool::NoDatabase,
+    ///     Resolvers::new(),
+    ///     Validators::new(),
+    ///     vec![],
+    ///     Some(()),
+    ///     None,
+    ///     HashMap::new()
+    /// ).with_retry_policy(RetryPolicy::none());
+    /// ```
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> GraphQLContext<RequestCtx> {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Sets the [`QueryLimits`] for the request. The generated resolvers count the nodes and
+    /// relationships read, and fail once the count is greater than the maximum.
+    ///
+    /// [`QueryLimits`]: ../limits/struct.QueryLimits.html
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::collections::HashMap;
+    /// # use warpgrapher::engine::context::GraphQLContext;
+    /// # use warpgrapher::engine::database::DatabasePool;
+    /// # use warpgrapher::engine::limits::QueryLimits;
+    /// # use warpgrapher::engine::resolvers::Resolvers;
+    /// # use warpgrapher::engine::validators::Validators;
+    ///
+    /// let gqlctx: GraphQLContext<()> = GraphQLContext::new(
+    ///     DatabasePool::NoDatabase,
+    ///     Resolvers::new(),
+    ///     Validators::new(),
+    ///     vec![],
+    ///     Some(()),
+    ///     None,
+    ///     HashMap::new()
+    /// ).with_query_limits(QueryLimits::new().with_max_nodes(1000));
+    /// ```
+    pub fn with_query_limits(mut self, query_limits: QueryLimits) -> GraphQLContext<RequestCtx> {
+        self.query_limits = query_limits;
+        self
+    }
+
+    /// Sets the [`IdGenerators`] used to generate the ids of new nodes of types that use the
+    /// `Custom` id strategy
+    ///
+    /// [`IdGenerators`]: ../id_generators/type.IdGenerators.html
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::collections::HashMap;
+    /// # use warpgrapher::engine::context::GraphQLContext;
+    /// # use warpgrapher::engine::database::DatabasePool;
+    /// # use warpgrapher::engine::id_generators::IdGenerators;
+    /// # use warpgrapher::engine::resolvers::Resolvers;
+    /// # use warpgrapher::engine::validators::Validators;
+    ///
+    /// let gqlctx: GraphQLContext<()> = GraphQLContext::new(
+    ///     DatabasePool::NoDatabase,
+    ///     Resolvers::new(),
+    ///     Validators::new(),
+    ///     vec![],
+    ///     Some(()),
+    ///     None,
+    ///     HashMap::new()
+    /// ).with_id_generators(IdGenerators::new());
+    /// ```
+    pub fn with_id_generators(mut self, id_generators: IdGenerators) -> GraphQLContext<RequestCtx> {
+        self.id_generators = id_generators;
+        self
+    }
+
+    /// Sets the [`ContextValidators`] called for types and relationships that name a `validator`
+    /// in the configuration
+    ///
+    /// [`ContextValidators`]: ../validators/type.ContextValidators.html
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::collections::HashMap;
+    /// # use warpgrapher::engine::context::GraphQLContext;
+    /// # use warpgrapher::engine::database::DatabasePool;
+    /// # use warpgrapher::engine::resolvers::Resolvers;
+    /// # use warpgrapher::engine::validators::{ContextValidators, Validators};
+    ///
+    /// let gqlctx: GraphQLContext<()> = GraphQLContext::new(
+    ///     DatabasePool::NoDatabase,
+    ///     Resolvers::new(),
+    ///     Validators::new(),
+    ///     vec![],
+    ///     Some(()),
+    ///     None,
+    ///     HashMap::new()
+    /// ).with_context_validators(ContextValidators::new());
+    /// ```
+    pub fn with_context_validators(
+        mut self,
+        context_validators: impl Into<Arc<ContextValidators<RequestCtx>>>,
+    ) -> GraphQLContext<RequestCtx> {
+        self.context_validators = context_validators.into();
+        self
+    }
+
+    /// Sets the [`Triggers`] run for types and relationships that name `triggers` in the
+    /// configuration
+    ///
+    /// [`Triggers`]: ../triggers/type.Triggers.html
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::collections::HashMap;
+    /// # use warpgrapher::engine::context::GraphQLContext;
+    /// # use warpgrapher::engine::database::DatabasePool;
+    /// # use warpgrapher::engine::resolvers::Resolvers;
+    /// # use warpgrapher::engine::triggers::Triggers;
+    /// # use warpgrapher::engine::validators::Validators;
+    ///
+    /// let gqlctx: GraphQLContext<()> = GraphQLContext::new(
+    ///     DatabasePool::NoDatabase,
+    ///     Resolvers::new(),
+    ///     Validators::new(),
+    ///     vec![],
+    ///     Some(()),
+    ///     None,
+    ///     HashMap::new()
+    /// ).with_triggers(Triggers::new());
+    /// ```
+    pub fn with_triggers(
+        mut self,
+        triggers: impl Into<Arc<Triggers<RequestCtx>>>,
+    ) -> GraphQLContext<RequestCtx> {
+        self.triggers = triggers.into();
+        self
+    }
+
+    /// Sets the [`CacheStore`] whose responses are invalidated by the writes of the request
+    ///
+    /// [`CacheStore`]: ../cache/trait.CacheStore.html
+    pub(crate) fn with_cache_store(
+        mut self,
+        cache_store: Option<Arc<dyn CacheStore>>,
+    ) -> GraphQLContext<RequestCtx> {
+        self.cache_store = cache_store;
+        self
+    }
+
     /// Returns a pool of database connections
     ///
     /// # Examples
@@ -203,6 +390,7 @@ where
     /// use bolt_proto::Message;
     /// use std::collections::HashMap;
     /// use std::iter::FromIterator;
+    /// use std::sync::Arc;
     /// # #[cfg(feature = "neo4j")]
     /// # use tokio::runtime::Runtime;
     /// # #[cfg(feature = "neo4j")]
@@ -240,7 +428,7 @@ where
     /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
     /// let mut resolvers: Resolvers<()> = Resolvers::new();
     /// # #[cfg(feature = "neo4j")]
-    /// resolvers.insert("ProjectCount".to_string(), Box::new(project_count));
+    /// resolvers.insert("ProjectCount".to_string(), Arc::new(project_count));
     ///
     /// # #[cfg(feature = "neo4j")]
     /// let mut runtime = Runtime::new()?;
@@ -461,6 +649,74 @@ where
     pub fn metadata(&self) -> &HashMap<String, String> {
         &self.metadata
     }
+
+    /// Returns the [`RetryPolicy`] for the database operations of the generated resolvers
+    ///
+    /// [`RetryPolicy`]: ../database/retry/struct.RetryPolicy.html
+    pub fn retry_policy(&self) -> &RetryPolicy {
+        &self.retry_policy
+    }
+
+    /// Returns the [`IdGenerators`] used to generate the ids of new nodes
+    ///
+    /// [`IdGenerators`]: ../id_generators/type.IdGenerators.html
+    pub fn id_generators(&self) -> &IdGenerators {
+        &self.id_generators
+    }
+
+    /// Returns the [`ContextValidators`] called for types and relationships that name a
+    /// `validator` in the configuration
+    ///
+    /// [`ContextValidators`]: ../validators/type.ContextValidators.html
+    pub fn context_validators(&self) -> &ContextValidators<RequestCtx> {
+        &self.context_validators
+    }
+
+    /// Returns the [`Triggers`] run for types and relationships that name `triggers` in the
+    /// configuration
+    ///
+    /// [`Triggers`]: ../triggers/type.Triggers.html
+    pub fn triggers(&self) -> &Triggers<RequestCtx> {
+        &self.triggers
+    }
+
+    /// Returns the [`QueryLimits`] for the request
+    ///
+    /// [`QueryLimits`]: ../limits/struct.QueryLimits.html
+    pub fn query_limits(&self) -> &QueryLimits {
+        &self.query_limits
+    }
+
+    /// Returns the greatest number of results that the next read of the request needs from the
+    /// database, or None if the request is not limited. This is one more than the number of
+    /// nodes and relationships that the request may still read, so that a read that goes past
+    /// the limit is detected without reading every result.
+    pub(crate) fn read_limit(&self) -> Option<usize> {
+        self.query_limits.max_nodes().map(|max| {
+            max.saturating_sub(self.nodes_read.load(Ordering::SeqCst))
+                .saturating_add(1)
+        })
+    }
+
+    /// Returns the [`CacheStore`] whose responses are invalidated by the writes of the request
+    ///
+    /// [`CacheStore`]: ../cache/trait.CacheStore.html
+    pub(crate) fn cache_store(&self) -> Option<&dyn CacheStore> {
+        self.cache_store.as_deref()
+    }
+
+    /// Adds `count` to the number of nodes and relationships read by the request, returning an
+    /// [`Error`] variant [`ResultSizeExceeded`] if the total is greater than the maximum
+    ///
+    /// [`Error`]: ../../error/enum.Error.html
+    /// [`ResultSizeExceeded`]: ../../error/enum.Error.html#variant.ResultSizeExceeded
+    pub(crate) fn count_nodes_read(&self, count: usize) -> Result<(), Error> {
+        let total = self.nodes_read.fetch_add(count, Ordering::SeqCst) + count;
+        match self.query_limits.max_nodes() {
+            Some(max) if total > max => Err(Error::ResultSizeExceeded { max }),
+            _ => Ok(()),
+        }
+    }
 }
 
 impl<RequestCtx> Context for GraphQLContext<RequestCtx> where RequestCtx: RequestContext {}
@@ -475,6 +731,9 @@ where
             .field("extensions", &self.extensions)
             .field("request_ctx", &self.request_ctx)
             .field("version", &self.version)
+            .field("retry_policy", &self.retry_policy)
+            .field("query_limits", &self.query_limits)
+            .field("cache_store", &self.cache_store)
             .finish()
     }
 }
@@ -502,6 +761,69 @@ where
 /// ```
 pub trait RequestContext: 'static + Clone + Debug + Send + Sync {
     fn new() -> Self;
+
+    /// Returns the partition key for nodes of the given type, for types whose [`PartitionKey`]
+    /// configuration computes the partition key from the request context. The default
+    /// implementation returns None.
+    ///
+    /// [`PartitionKey`]: ../config/struct.PartitionKey.html
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use warpgrapher::engine::context::RequestContext;
+    /// # use warpgrapher::engine::value::Value;
+    ///
+    /// #[derive(Clone, Debug)]
+    /// struct AppRequestContext {
+    ///     tenant: String
+    /// }
+    ///
+    /// impl RequestContext for AppRequestContext {
+    ///     fn new() -> Self {
+    ///         AppRequestContext { tenant: "".to_string() }
+    ///     }
+    ///
+    ///     fn partition_key(&self, _type_name: &str) -> Option<Value> {
+    ///         Some(Value::String(self.tenant.clone()))
+    ///     }
+    /// }
+    /// ```
+    fn partition_key(&self, _type_name: &str) -> Option<Value> {
+        None
+    }
+
+    /// Returns the fields of the request context that affect the response to a query, for
+    /// responses stored in a [`CacheStore`]. Requests are served from the cache only to requests
+    /// with the same cache key. Returning None prevents the response to the request from being
+    /// cached. The default implementation returns an empty key, so that all requests share cached
+    /// responses.
+    ///
+    /// [`CacheStore`]: ../cache/trait.CacheStore.html
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use warpgrapher::engine::context::RequestContext;
+    ///
+    /// #[derive(Clone, Debug)]
+    /// struct AppRequestContext {
+    ///     user: Option<String>
+    /// }
+    ///
+    /// impl RequestContext for AppRequestContext {
+    ///     fn new() -> Self {
+    ///         AppRequestContext { user: None }
+    ///     }
+    ///
+    ///     fn cache_key(&self) -> Option<String> {
+    ///         self.user.clone()
+    ///     }
+    /// }
+    /// ```
+    fn cache_key(&self) -> Option<String> {
+        Some(String::new())
+    }
 }
 
 impl RequestContext for () {
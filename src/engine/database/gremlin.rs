@@ -1,21 +1,23 @@
 //! Provides database interface types and functions for Cosmos DB and other Gremlin-based DBs
 
-use crate::engine::context::RequestContext;
 #[cfg(feature = "gremlin")]
 use crate::engine::database::env_bool;
+use crate::engine::database::inference::ModelInference;
 use crate::engine::database::{
-    env_string, env_u16, ClauseType, DatabaseEndpoint, DatabasePool, NodeQueryVar, RelQueryVar,
-    SuffixGenerator, Transaction,
+    env_string, env_u16, ClauseType, DatabaseEndpoint, DatabasePool, DbNames, NodeQueryVar,
+    NodeRecord, PoolOptions, RelQueryVar, RelRecord, SuffixGenerator, Transaction,
 };
-use crate::engine::objects::{Node, NodeRef, Rel};
 use crate::engine::schema::{Info, NodeType};
 use crate::engine::value::Value;
 use crate::Error;
 use async_trait::async_trait;
+use gremlin_client::process::traversal::{Bytecode, GraphTraversal, Terminator, TraversalBuilder};
+use gremlin_client::structure::T;
 #[cfg(feature = "gremlin")]
 use gremlin_client::TlsOptions;
 use gremlin_client::{
-    ConnectionOptions, GKey, GValue, GraphSON, GremlinClient, Map, ToGValue, VertexProperty,
+    Cardinality, ConnectionOptions, GKey, GValue, GraphSON, GremlinClient, Map, ToGValue,
+    VertexProperty,
 };
 use log::trace;
 use serde::{Deserialize, Serialize};
@@ -26,11 +28,6 @@ use std::env::var_os;
 use std::fmt::Debug;
 use uuid::Uuid;
 
-static NODE_RETURN_FRAGMENT: &str =
-    ".project('nID', 'nLabel', 'nProps').by(id()).by(label()).by(valueMap())";
-
-static REL_RETURN_FRAGMENT: &str = ".project('rID', 'rProps', 'srcID', 'srcLabel', 'dstID', 'dstLabel').by(id()).by(valueMap()).by(outV().id()).by(outV().label()).by(inV().id()).by(inV().label())";
-
 /// A Cosmos DB endpoint collects the information necessary to generate a connection string and
 /// build a database connection pool.
 ///
@@ -52,6 +49,8 @@ pub struct CosmosEndpoint {
     port: u16,
     user: String,
     pass: String,
+    #[serde(skip)]
+    pool_options: PoolOptions,
 }
 
 #[cfg(feature = "cosmos")]
@@ -66,6 +65,10 @@ impl CosmosEndpoint {
     /// /dbs/*my-db-name*/colls/*my-collection-name*
     /// * WG_COSMOS_PASS - the read/write key for the Cosmos DB.
     ///
+    /// The pool size and health check are read from the WG_POOL_* variables described in
+    /// [`PoolOptions::from_env`].
+    ///
+    /// [`PoolOptions::from_env`]: ../struct.PoolOptions.html#method.from_env
     /// [`CosmosEndpoint`]: ./struct.CosmosEndpoint.html
     ///
     /// # Errors
@@ -94,25 +97,53 @@ impl CosmosEndpoint {
             port: env_u16("WG_COSMOS_PORT")?,
             user: env_string("WG_COSMOS_USER")?,
             pass: env_string("WG_COSMOS_PASS")?,
+            pool_options: PoolOptions::from_env()?,
         })
     }
+
+    /// Sets the options for the connection pool. Only the maximum size and the health check are
+    /// supported for Cosmos DB.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use warpgrapher::engine::database::PoolOptions;
+    /// # use warpgrapher::engine::database::gremlin::CosmosEndpoint;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let ce = CosmosEndpoint::from_env()?
+    ///         .with_pool_options(PoolOptions::new().with_max_size(16));
+    /// #    Ok(())
+    /// # }
+    /// ```
+    pub fn with_pool_options(mut self, pool_options: PoolOptions) -> CosmosEndpoint {
+        self.pool_options = pool_options;
+        self
+    }
 }
 
 #[cfg(feature = "cosmos")]
 #[async_trait]
 impl DatabaseEndpoint for CosmosEndpoint {
     async fn pool(&self) -> Result<DatabasePool, Error> {
-        Ok(DatabasePool::Cosmos(GremlinClient::connect(
+        let pool = DatabasePool::Cosmos(GremlinClient::connect(
             ConnectionOptions::builder()
                 .host(&self.host)
                 .port(self.port)
-                .pool_size(num_cpus::get().try_into().unwrap_or(8))
+                .pool_size(
+                    self.pool_options
+                        .max_size_or(num_cpus::get().try_into().unwrap_or(8))?,
+                )
                 .ssl(true)
                 .serializer(GraphSON::V1)
                 .deserializer(GraphSON::V1)
                 .credentials(&self.user, &self.pass)
                 .build(),
-        )?))
+        )?);
+        if self.pool_options.health_check {
+            pool.health().await?;
+        }
+        Ok(pool)
     }
 }
 
@@ -140,6 +171,8 @@ pub struct GremlinEndpoint {
     accept_invalid_certs: bool,
     uuid: bool,
     use_tls: bool,
+    #[serde(skip)]
+    pool_options: PoolOptions,
 }
 
 #[cfg(feature = "gremlin")]
@@ -157,6 +190,11 @@ impl GremlinEndpoint {
     /// * WG_GREMLIN_UUID - true if the GREMLIN database uses a UUID type for node and vertex ids,
     /// false if the UUIDs for node and vertex ids are represented as string types
     ///
+    /// The pool size and health check are read from the WG_POOL_* variables described in
+    /// [`PoolOptions::from_env`].
+    ///
+    /// [`PoolOptions::from_env`]: ../struct.PoolOptions.html#method.from_env
+    ///
     /// The accept_invalid_certs option may be set to true in a test environment, where a test
     /// Gremlin server is running with an invalid cert. It should be set to false in production
     /// environments.
@@ -192,8 +230,29 @@ impl GremlinEndpoint {
             accept_invalid_certs: env_bool("WG_GREMLIN_CERT")?,
             uuid: env_bool("WG_GREMLIN_UUID")?,
             use_tls: env_bool("WG_GREMLIN_USE_TLS").unwrap_or(true),
+            pool_options: PoolOptions::from_env()?,
         })
     }
+
+    /// Sets the options for the connection pool. Only the maximum size and the health check are
+    /// supported for Gremlin-based DBs.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use warpgrapher::engine::database::PoolOptions;
+    /// # use warpgrapher::engine::database::gremlin::GremlinEndpoint;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let ge = GremlinEndpoint::from_env()?
+    ///         .with_pool_options(PoolOptions::new().with_health_check(true));
+    /// #    Ok(())
+    /// # }
+    /// ```
+    pub fn with_pool_options(mut self, pool_options: PoolOptions) -> GremlinEndpoint {
+        self.pool_options = pool_options;
+        self
+    }
 }
 
 #[cfg(feature = "gremlin")]
@@ -203,7 +262,10 @@ impl DatabaseEndpoint for GremlinEndpoint {
         let mut options_builder = ConnectionOptions::builder()
             .host(&self.host)
             .port(self.port)
-            .pool_size(num_cpus::get().try_into().unwrap_or(8))
+            .pool_size(
+                self.pool_options
+                    .max_size_or(num_cpus::get().try_into().unwrap_or(8))?,
+            )
             .serializer(GraphSON::V3)
             .deserializer(GraphSON::V3);
         if let (Some(user), Some(pass)) = (self.user.as_ref(), self.pass.as_ref()) {
@@ -215,73 +277,576 @@ impl DatabaseEndpoint for GremlinEndpoint {
             });
         }
         let options = options_builder.build();
-        Ok(DatabasePool::Gremlin((
-            GremlinClient::connect(options)?,
-            self.uuid,
-        )))
+        let pool = DatabasePool::Gremlin((GremlinClient::connect(options)?, self.uuid));
+        if self.pool_options.health_check {
+            pool.health().await?;
+        }
+        Ok(pool)
+    }
+}
+
+/// Runs a trivial traversal, returning an error if the database cannot be reached
+pub(crate) fn health(client: &GremlinClient) -> Result<(), Error> {
+    client
+        .execute("g.V().limit(0).count()", &[])?
+        .collect::<Result<Vec<GValue>, _>>()?;
+    Ok(())
+}
+
+/// Samples up to `sample_size` vertices with each label in a Cosmos DB or Gremlin-based DB, along
+/// with their outgoing edges, and records their properties and relationships for inference of a
+/// [`Configuration`].
+///
+/// [`Configuration`]: ../../config/struct.Configuration.html
+pub(crate) fn infer_model(
+    client: &GremlinClient,
+    sample_size: usize,
+) -> Result<ModelInference, Error> {
+    trace!(
+        "gremlin::infer_model called -- sample_size: {}",
+        sample_size
+    );
+
+    let labels = client
+        .execute("g.V().label().dedup()", &[])?
+        .map(|r| match r? {
+            GValue::String(label) => Ok(label),
+            _ => Err(Error::TypeNotExpected),
+        })
+        .collect::<Result<Vec<String>, Error>>()?;
+
+    let mut mi = ModelInference::new(vec!["id", "partitionKey"]);
+    for label in labels {
+        let limit = sample_size as i64;
+        let results = client
+            .execute(
+                "g.V().hasLabel(label).limit(limit)".to_string()
+                    + ".project('nProps', 'rels').by(valueMap())"
+                    + ".by(outE().project('rLabel', 'dstLabel', 'rProps')"
+                    + ".by(label()).by(inV().label()).by(valueMap()).fold())",
+                &[("label", &label), ("limit", &limit)],
+            )?
+            .map(|r| Ok(r?))
+            .collect::<Result<Vec<GValue>, Error>>()?;
+
+        for result in results {
+            let mut hm = GremlinTransaction::gmap_to_hashmap(result)?;
+            if let (Some(GValue::Map(props)), Some(GValue::List(rels))) =
+                (hm.remove("nProps"), hm.remove("rels"))
+            {
+                mi.observe_node(&label, &GremlinTransaction::sample_props(props));
+
+                let rels = rels
+                    .into_iter()
+                    .map(|rel| {
+                        let mut rhm = GremlinTransaction::gmap_to_hashmap(rel)?;
+                        if let (
+                            Some(GValue::String(rel_label)),
+                            Some(GValue::String(dst_label)),
+                            Some(GValue::Map(rel_props)),
+                        ) = (
+                            rhm.remove("rLabel"),
+                            rhm.remove("dstLabel"),
+                            rhm.remove("rProps"),
+                        ) {
+                            Ok((
+                                rel_label,
+                                dst_label,
+                                GremlinTransaction::sample_props(rel_props),
+                            ))
+                        } else {
+                            Err(Error::ResponseItemNotFound {
+                                name: "rLabel, dstLabel, or rProps".to_string(),
+                            })
+                        }
+                    })
+                    .collect::<Result<Vec<(String, String, HashMap<String, Value>)>, Error>>()?;
+                mi.observe_rels(&label, &rels);
+            } else {
+                return Err(Error::ResponseItemNotFound {
+                    name: "nProps or rels".to_string(),
+                });
+            }
+        }
+    }
+
+    Ok(mi)
+}
+
+/// An argument to a step of a [`Traversal`]. Property values never appear in a traversal. They
+/// are referred to by parameter name, and bound when the traversal is run.
+#[derive(Clone, Debug, PartialEq)]
+enum Arg {
+    /// A vertex or edge label, a property key, or a step label
+    Name(String),
+    /// The name of a query parameter
+    Param(String),
+    /// The `id` token
+    Id,
+    /// The `list` cardinality of a vertex property
+    List,
+    /// An anonymous child traversal
+    Traversal(Traversal),
+}
+
+impl Arg {
+    fn name(name: &str) -> Arg {
+        Arg::Name(name.to_string())
+    }
+
+    fn param(name: &str) -> Arg {
+        Arg::Param(name.to_string())
+    }
+
+    /// Returns an anonymous traversal of steps that take no arguments, such as `outV().id()`
+    fn steps(ops: &[&str]) -> Arg {
+        let mut traversal = Traversal::default();
+        for op in ops {
+            traversal.push(op, Vec::new());
+        }
+        Arg::Traversal(traversal)
+    }
+}
+
+/// A single step of a [`Traversal`], such as `has('name', name_1)`
+#[derive(Clone, Debug, PartialEq)]
+struct Step {
+    op: String,
+    args: Vec<Arg>,
+    /// True if the step is a partition key filter that is dropped when the traversal is run
+    /// without a partition key, making it a cross-partition query
+    cross_partition: bool,
+}
+
+/// A Gremlin traversal, assembled step by step from the query fragments requested by the
+/// resolvers. Gremlin servers are sent the traversal as bytecode. Cosmos DB does not accept
+/// bytecode, so it is sent a script in which names are quoted literals and every value is a bound
+/// parameter.
+#[derive(Clone, Debug, Default, PartialEq)]
+struct Traversal {
+    steps: Vec<Step>,
+}
+
+impl Traversal {
+    fn push(&mut self, op: &str, args: Vec<Arg>) {
+        self.steps.push(Step {
+            op: op.to_string(),
+            args,
+            cross_partition: false,
+        });
+    }
+
+    /// Adds a filter on the partition key, held in the property `key`. If `cross_partition` is
+    /// true, the filter is dropped when the traversal is run without a partition key.
+    fn push_partition_filter(&mut self, key: &str, cross_partition: bool) {
+        self.steps.push(Step {
+            op: "has".to_string(),
+            args: vec![Arg::name(key), Arg::param("partitionKey")],
+            cross_partition,
+        });
+    }
+
+    fn extend(&mut self, other: Traversal) {
+        self.steps.extend(other.steps);
+    }
+
+    /// Returns the traversal without the partition key filters that allow cross-partition
+    /// queries, for running without a partition key
+    fn cross_partition(&self) -> Traversal {
+        Traversal {
+            steps: self
+                .steps
+                .iter()
+                .filter(|step| !step.cross_partition)
+                .map(|step| Step {
+                    op: step.op.to_string(),
+                    args: step
+                        .args
+                        .iter()
+                        .map(|arg| match arg {
+                            Arg::Traversal(t) => Arg::Traversal(t.cross_partition()),
+                            _ => arg.clone(),
+                        })
+                        .collect(),
+                    cross_partition: false,
+                })
+                .collect(),
+        }
+    }
+
+    /// Limits the number of results of the traversal to `limit`, held in the `limit` parameter
+    fn limit(&mut self, limit: usize, params: &mut HashMap<String, Value>) -> Result<(), Error> {
+        params.insert("limit".to_string(), Value::Int64(i64::try_from(limit)?));
+        self.push("limit", vec![Arg::param("limit")]);
+        Ok(())
+    }
+
+    fn node_return(&mut self) {
+        self.push(
+            "project",
+            vec![Arg::name("nID"), Arg::name("nLabel"), Arg::name("nProps")],
+        );
+        self.push("by", vec![Arg::steps(&["id"])]);
+        self.push("by", vec![Arg::steps(&["label"])]);
+        self.push("by", vec![Arg::steps(&["valueMap"])]);
+    }
+
+    fn rel_return(&mut self) {
+        self.push(
+            "project",
+            ["rID", "rProps", "srcID", "srcLabel", "dstID", "dstLabel"]
+                .iter()
+                .map(|n| Arg::name(n))
+                .collect(),
+        );
+        self.push("by", vec![Arg::steps(&["id"])]);
+        self.push("by", vec![Arg::steps(&["valueMap"])]);
+        self.push("by", vec![Arg::steps(&["outV", "id"])]);
+        self.push("by", vec![Arg::steps(&["outV", "label"])]);
+        self.push("by", vec![Arg::steps(&["inV", "id"])]);
+        self.push("by", vec![Arg::steps(&["inV", "label"])]);
+    }
+
+    /// Converts the traversal to bytecode, substituting parameter values for their names
+    fn bytecode(&self, values: &HashMap<&str, &Value>) -> Result<Bytecode, Error> {
+        let mut bytecode = Bytecode::new();
+        for step in &self.steps {
+            let args = step
+                .args
+                .iter()
+                .map(|arg| match arg {
+                    Arg::Name(name) => Ok(GValue::String(name.to_string())),
+                    Arg::Param(name) => values
+                        .get(name.as_str())
+                        .map(|v| v.to_gvalue())
+                        .ok_or_else(|| param_not_found(name)),
+                    Arg::Id => Ok(GValue::T(T::Id)),
+                    Arg::List => Ok(GValue::Cardinality(Cardinality::List)),
+                    Arg::Traversal(t) => Ok(GValue::Bytecode(t.bytecode(values)?)),
+                })
+                .collect::<Result<Vec<GValue>, Error>>()?;
+            bytecode.add_step(step.op.to_string(), args);
+        }
+        Ok(bytecode)
+    }
+
+    /// Renders the traversal as a script, without the leading traversal source. Each parameter
+    /// is bound as `p` followed by its position in `bindings`, so neither names nor values are
+    /// ever evaluated as script.
+    fn script(&self, bindings: &mut Vec<String>) -> String {
+        self.steps
+            .iter()
+            .map(|step| {
+                let args = step
+                    .args
+                    .iter()
+                    .map(|arg| match arg {
+                        Arg::Name(name) => {
+                            "'".to_string() + &name.replace('\\', "\\\\").replace('\'', "\\'") + "'"
+                        }
+                        Arg::Param(name) => {
+                            let i = bindings.iter().position(|b| b == name).unwrap_or_else(|| {
+                                bindings.push(name.to_string());
+                                bindings.len() - 1
+                            });
+                            "p".to_string() + &i.to_string()
+                        }
+                        Arg::Id => "id".to_string(),
+                        Arg::List => "list".to_string(),
+                        Arg::Traversal(t) => t.script(bindings),
+                    })
+                    .collect::<Vec<String>>()
+                    .join(", ");
+                step.op.to_string() + "(" + &args + ")"
+            })
+            .collect::<Vec<String>>()
+            .join(".")
     }
 }
 
+/// Returns the error for a parameter that has no value
+fn param_not_found(name: &str) -> Error {
+    if name == "partitionKey" {
+        Error::PartitionKeyNotFound
+    } else {
+        Error::InputItemNotFound {
+            name: name.to_string(),
+        }
+    }
+}
+
+/// Stands in for a terminator while a [`GraphTraversal`] is assembled from bytecode. The
+/// traversal is bound to a client with [`GraphTraversal::change_remote`] before it is run.
+#[derive(Clone)]
+struct Unbound;
+
+impl Terminator<GValue> for Unbound {
+    type List = ();
+    type Next = ();
+    type HasNext = ();
+    type Iter = ();
+
+    fn to_list<S, E>(&self, _traversal: &GraphTraversal<S, GValue, E>) -> Self::List
+    where
+        E: Terminator<GValue>,
+    {
+    }
+
+    fn next<S, E>(&self, _traversal: &GraphTraversal<S, GValue, E>) -> Self::Next
+    where
+        E: Terminator<GValue>,
+    {
+    }
+
+    fn has_next<S, E>(&self, _traversal: &GraphTraversal<S, GValue, E>) -> Self::HasNext
+    where
+        E: Terminator<GValue>,
+    {
+    }
+
+    fn iter<S, E>(&self, _traversal: &GraphTraversal<S, GValue, E>) -> Self::Iter
+    where
+        E: Terminator<GValue>,
+    {
+    }
+}
+
+/// A [`Transaction`] for Gremlin-based databases. The query strings passed through the
+/// [`Transaction`] interface are handles to [`Traversal`]s held by the transaction.
+///
+/// Gremlin-based databases, such as Cosmos DB, have no transactions, so each traversal is kept
+/// once it has run. The transaction records whether any write has run, so that an operation that
+/// fails after one is not retried.
 #[derive(Debug)]
 pub(crate) struct GremlinTransaction {
     client: GremlinClient,
+    db_names: DbNames,
     partition: bool,
     uuid: bool,
+    bytecode: bool,
+    traversals: Vec<Traversal>,
+    written: bool,
 }
 
 impl GremlinTransaction {
-    pub fn new(client: GremlinClient, partition: bool, uuid: bool) -> GremlinTransaction {
+    pub fn new(
+        client: GremlinClient,
+        db_names: DbNames,
+        partition: bool,
+        uuid: bool,
+        bytecode: bool,
+    ) -> GremlinTransaction {
         GremlinTransaction {
             client,
+            db_names,
             partition,
             uuid,
+            bytecode,
+            traversals: Vec::new(),
+            written: false,
+        }
+    }
+
+    fn add_traversal(&mut self, traversal: Traversal) -> String {
+        self.traversals.push(traversal);
+        "#".to_string() + &(self.traversals.len() - 1).to_string()
+    }
+
+    /// Returns the traversal for a handle. An empty handle is an empty traversal.
+    fn traversal(&self, handle: &str) -> Result<Traversal, Error> {
+        if handle.is_empty() {
+            return Ok(Traversal::default());
+        }
+
+        handle
+            .strip_prefix('#')
+            .and_then(|i| i.parse::<usize>().ok())
+            .and_then(|i| self.traversals.get(i))
+            .cloned()
+            .ok_or_else(|| Error::InputItemNotFound {
+                name: handle.to_string(),
+            })
+    }
+
+    /// Runs the traversal for a handle, binding its parameters and the partition key, if any
+    fn run(
+        &self,
+        query: &str,
+        params: &HashMap<String, Value>,
+        partition_key_opt: Option<&Value>,
+    ) -> Result<Vec<GValue>, Error> {
+        let mut traversal = self.traversal(query)?;
+
+        let mut values: HashMap<&str, &Value> =
+            params.iter().map(|(k, v)| (k.as_str(), v)).collect();
+
+        if self.partition {
+            match partition_key_opt {
+                Some(pk) => {
+                    values.insert("partitionKey", pk);
+                }
+                // Without a partition key, the filters of types that allow cross-partition
+                // queries are dropped. Any other use of the partition key is an error.
+                None => traversal = traversal.cross_partition(),
+            }
+        }
+
+        if self.bytecode {
+            let bytecode = traversal.bytecode(&values)?;
+            trace!("GremlinTransaction::run -- bytecode: {:#?}", bytecode);
+
+            Ok(GraphTraversal::<GValue, GValue, Unbound>::new(
+                Unbound,
+                TraversalBuilder::new(bytecode),
+            )
+            .change_remote(self.client.clone())
+            .to_list()?)
+        } else {
+            let mut bindings = Vec::new();
+            let script = "g.".to_string() + &traversal.script(&mut bindings);
+            let bound = bindings
+                .iter()
+                .enumerate()
+                .map(|(i, name)| {
+                    values
+                        .get(name.as_str())
+                        .map(|v| ("p".to_string() + &i.to_string(), *v))
+                        .ok_or_else(|| param_not_found(name))
+                })
+                .collect::<Result<Vec<(String, &Value)>, Error>>()?;
+            trace!(
+                "GremlinTransaction::run -- script: {}, bound: {:#?}",
+                script,
+                bound
+            );
+
+            let param_list: Vec<(&str, &dyn ToGValue)> = bound
+                .iter()
+                .map(|(k, v)| (k.as_str(), *v as &dyn ToGValue))
+                .collect();
+
+            self.client
+                .execute(script, param_list.as_slice())?
+                .map(|r| Ok(r?))
+                .collect()
         }
     }
 
+    /// Runs a traversal that writes to the database, recording that a write has been kept if it
+    /// succeeds
+    fn run_write(
+        &mut self,
+        query: &str,
+        params: &HashMap<String, Value>,
+        partition_key_opt: Option<&Value>,
+    ) -> Result<Vec<GValue>, Error> {
+        let results = self.run(query, params, partition_key_opt)?;
+        self.written = true;
+        Ok(results)
+    }
+
     fn add_properties(
-        query: String,
-        params: HashMap<String, Value>,
+        mut traversal: Traversal,
+        mut params: HashMap<String, Value>,
         props: HashMap<String, Value>,
         sg: &mut SuffixGenerator,
-    ) -> (String, HashMap<String, Value>) {
-        let (ret_query, ret_params): (String, HashMap<String, Value>) =
-            props
-                .into_iter()
-                .fold((query, params), |(mut outer_q, mut outer_p), (k, v)| {
-                    if let Value::Array(a) = v {
-                        a.into_iter()
-                            .fold((outer_q, outer_p), |(mut inner_q, mut inner_p), val| {
-                                let suffix = sg.suffix();
-                                inner_q.push_str(
-                                    &(".property(list, '".to_string()
-                                        + &k
-                                        + "', "
-                                        + &k
-                                        + &suffix
-                                        + ")"),
-                                );
-                                inner_p.insert(k.to_string() + &suffix, val);
-                                (inner_q, inner_p)
-                            })
-                    } else {
-                        let suffix = sg.suffix();
-                        outer_q.push_str(
-                            &(".property('".to_string() + &k + "', " + &k + &suffix + ")"),
-                        );
-                        outer_p.insert(k + &suffix, v);
-                        (outer_q, outer_p)
-                    }
-                });
+    ) -> (Traversal, HashMap<String, Value>) {
+        for (k, v) in props {
+            if let Value::Array(a) = v {
+                for val in a {
+                    let param = k.to_string() + &sg.suffix();
+                    traversal.push(
+                        "property",
+                        vec![Arg::List, Arg::name(&k), Arg::param(&param)],
+                    );
+                    params.insert(param, val);
+                }
+            } else {
+                let param = k.to_string() + &sg.suffix();
+                traversal.push("property", vec![Arg::name(&k), Arg::param(&param)]);
+                params.insert(param, v);
+            }
+        }
+
+        (traversal, params)
+    }
+
+    /// Adds `has` steps matching the given properties, inserting their values into `params`
+    fn add_has(
+        &self,
+        traversal: &mut Traversal,
+        params: &mut HashMap<String, Value>,
+        type_name: Option<&str>,
+        props: HashMap<String, Value>,
+        param_suffix: &str,
+    ) -> Result<(), Error> {
+        if self.partition {
+            traversal.push_partition_filter(
+                self.db_names.partition_key(type_name),
+                self.db_names.cross_partition(type_name),
+            );
+        }
+
+        for (k, v) in props {
+            let param = k.to_string() + param_suffix;
+
+            if k == "id" {
+                // For id, we match on the id token, because it's a "system" property, not just a
+                // user defined property.
+                traversal.push("has", vec![Arg::Id, Arg::param(&param)]);
+            } else {
+                traversal.push("has", vec![Arg::name(&k), Arg::param(&param)]);
+            }
+
+            if k == "id" {
+                params.insert(param, self.id_value(v)?);
+            } else {
+                params.insert(param, v);
+            }
+        }
 
-        (ret_query, ret_params)
+        Ok(())
     }
 
-    fn add_rel_return(query: String) -> String {
-        query
-            + ".project('rID', 'rProps', 'srcID', 'srcLabel', 'dstID', 'dstLabel')"
-            + ".by(id()).by(valueMap())"
-            + ".by(outV().id()).by(outV().label())"
-            + ".by(inV().id()).by(inV().label())"
+    /// Converts an id to a UUID value, if the database uses UUID ids
+    fn id_value(&self, id: Value) -> Result<Value, Error> {
+        if self.uuid {
+            if let Value::String(s) = id {
+                Ok(Value::Uuid(Uuid::parse_str(&s)?))
+            } else {
+                Err(Error::TypeConversionFailed {
+                    src: format!("{:#?}", id),
+                    dst: "String".to_string(),
+                })
+            }
+        } else {
+            Ok(id)
+        }
+    }
+
+    /// Returns an [`Error::IdDuplicated`] if a node already has the id given for a new node. The
+    /// check runs across all partitions. Concurrent transactions that create nodes with the same
+    /// id may both pass the check, in which case the database rejects the second node, if it
+    /// enforces unique ids.
+    ///
+    /// [`Error::IdDuplicated`]: ../../../enum.Error.html#variant.IdDuplicated
+    fn check_id_unused(&mut self, id: Value, id_value: &Value) -> Result<(), Error> {
+        let mut traversal = Traversal::default();
+        traversal.push("V", Vec::new());
+        traversal.push("hasId", vec![Arg::param("id")]);
+        traversal.push("count", Vec::new());
+        let handle = self.add_traversal(traversal);
+
+        let mut params = HashMap::new();
+        params.insert("id".to_string(), id_value.clone());
+        let results = self.run(&handle, &params, None)?;
+        if GremlinTransaction::extract_count(results)? > 0 {
+            Err(Error::IdDuplicated {
+                id: String::try_from(id)?,
+            })
+        } else {
+            Ok(())
+        }
     }
 
     fn extract_count(results: Vec<GValue>) -> Result<i32, Error> {
@@ -297,13 +862,19 @@ impl GremlinTransaction {
     fn extract_node_properties(
         props: Map,
         type_def: &NodeType,
+        db_names: &DbNames,
     ) -> Result<HashMap<String, Value>, Error> {
         trace!("GremlinTransaction::extract_node_properties called");
         props
             .into_iter()
             .map(|(key, property_list)| {
-                if let (GKey::String(k), GValue::List(plist)) = (key, property_list) {
-                    let v = if k == "partitionKey" || !type_def.property(&k)?.list() {
+                if let (GKey::String(db_key), GValue::List(plist)) = (key, property_list) {
+                    let k = db_names
+                        .field_name(type_def.type_name(), &db_key)
+                        .to_string();
+                    let v = if k == type_def.partition_key_property()
+                        || !type_def.property(&k)?.list()
+                    {
                         plist
                             .into_iter()
                             .next()
@@ -343,10 +914,35 @@ impl GremlinTransaction {
         }
     }
 
-    fn nodes<RequestCtx: RequestContext>(
+    /// Converts sampled vertex or edge properties, skipping any whose values cannot be
+    /// represented in the GraphQL schema. Vertex properties with several values are treated as
+    /// lists.
+    fn sample_props(props: Map) -> HashMap<String, Value> {
+        props
+            .into_iter()
+            .filter_map(|(key, val)| match (key, val) {
+                (GKey::String(k), GValue::List(plist)) => {
+                    let mut values = plist
+                        .into_iter()
+                        .filter_map(|v| v.try_into().ok())
+                        .collect::<Vec<Value>>();
+                    if values.len() > 1 {
+                        Some((k, Value::Array(values)))
+                    } else {
+                        values.pop().map(|v| (k, v))
+                    }
+                }
+                (GKey::String(k), v) => v.try_into().ok().map(|v| (k, v)),
+                (_, _) => None,
+            })
+            .collect()
+    }
+
+    fn nodes(
         results: Vec<GValue>,
         info: &Info,
-    ) -> Result<Vec<Node<RequestCtx>>, Error> {
+        db_names: &DbNames,
+    ) -> Result<Vec<NodeRecord>, Error> {
         trace!(
             "GremlinTransaction::nodes called -- info.name: {}, results: {:#?}",
             info.name(),
@@ -364,10 +960,11 @@ impl GremlinTransaction {
                     Some(GValue::Map(props)),
                 ) = (hm.remove("nID"), hm.remove("nLabel"), hm.remove("nProps"))
                 {
-                    let type_def = info.type_def_by_name(&label)?;
-                    let mut fields = GremlinTransaction::extract_node_properties(props, type_def)?;
+                    let type_def = info.type_def_by_name(db_names.type_name(&label))?;
+                    let mut fields =
+                        GremlinTransaction::extract_node_properties(props, type_def, db_names)?;
                     fields.insert("id".to_string(), Value::String(id));
-                    Ok(Node::new(label, fields))
+                    Ok(NodeRecord::new(type_def.type_name().to_string(), fields))
                 } else {
                     Err(Error::ResponseItemNotFound {
                         name: "ID, label, or props".to_string(),
@@ -377,11 +974,12 @@ impl GremlinTransaction {
             .collect()
     }
 
-    fn rels<RequestCtx: RequestContext>(
+    fn rels(
         results: Vec<GValue>,
         props_type_name: Option<&str>,
         partition_key_opt: Option<&Value>,
-    ) -> Result<Vec<Rel<RequestCtx>>, Error> {
+        db_names: &DbNames,
+    ) -> Result<Vec<RelRecord>, Error> {
         trace!("GremlinTransaction::rels called -- results: {:#?}, props_type_name: {:#?}, partition_key_opt: {:#?}",
         results, props_type_name, partition_key_opt);
         results
@@ -414,18 +1012,20 @@ impl GremlinTransaction {
                         })
                         .collect::<Result<HashMap<String, Value>, Error>>()?;
 
-                    Ok(Rel::new(
+                    Ok(RelRecord::new(
                         Value::String(rel_id),
                         partition_key_opt.cloned(),
-                        props_type_name.map(|ptn| Node::new(ptn.to_string(), rel_fields)),
-                        NodeRef::Identifier {
-                            id: Value::String(src_id),
-                            label: src_label,
-                        },
-                        NodeRef::Identifier {
-                            id: Value::String(dst_id),
-                            label: dst_label,
-                        },
+                        props_type_name.map(|ptn| {
+                            NodeRecord::new(ptn.to_string(), db_names.fields(ptn, rel_fields))
+                        }),
+                        (
+                            Value::String(src_id),
+                            db_names.type_name(&src_label).to_string(),
+                        ),
+                        (
+                            Value::String(dst_id),
+                            db_names.type_name(&dst_label).to_string(),
+                        ),
                     ))
                 } else {
                     Err(Error::ResponseItemNotFound {
@@ -442,91 +1042,81 @@ impl Transaction for GremlinTransaction {
         Ok(())
     }
 
-    fn node_create_query<RequestCtx: RequestContext>(
+    fn node_create_query(
         &mut self,
         rel_create_fragments: Vec<String>,
-        params: HashMap<String, Value>,
+        mut params: HashMap<String, Value>,
         node_var: &NodeQueryVar,
         props: HashMap<String, Value>,
         clause: ClauseType,
         sg: &mut SuffixGenerator,
     ) -> Result<(String, HashMap<String, Value>), Error> {
-        trace!("GremlinTransaction::node_create_query called -- rel_create_fragments: {:#?}, params: {:#?}, node_var: {:#?}, props: {:#?}, clause: {:#?}", 
+        trace!("GremlinTransaction::node_create_query called -- rel_create_fragments: {:#?}, params: {:#?}, node_var: {:#?}, props: {:#?}, clause: {:#?}",
         rel_create_fragments, params, node_var, props, clause);
-        let mut first = match clause {
-            ClauseType::Parameter => "addV('".to_string(),
-            ClauseType::FirstSubQuery | ClauseType::SubQuery => ".addV('".to_string(),
-            ClauseType::Query => "g.addV('".to_string(),
-        } + node_var.label()?
-            + "')";
+        let mut first = Traversal::default();
+        first.push(
+            "addV",
+            vec![Arg::name(self.db_names.label(node_var.label()?))],
+        );
+
+        let mut props = self.db_names.props(node_var.label().ok(), props);
+
+        // A generated or client-supplied id is set on the id token, in place of an id assigned by
+        // the database, as long as no other node already has it.
+        if let Some(id) = props.remove("id") {
+            let id_value = self.id_value(id.clone())?;
+            self.check_id_unused(id, &id_value)?;
+
+            let param = "id".to_string() + &sg.suffix();
+            first.push("property", vec![Arg::Id, Arg::param(&param)]);
+            params.insert(param, id_value);
+        }
 
         if self.partition {
-            first.push_str(".property('partitionKey', partitionKey)");
+            // The partition key property is set from the partition key, in place of any value
+            // given for it in the input.
+            let key = self.db_names.partition_key(node_var.label().ok());
+            props.remove(key);
+            first.push("property", vec![Arg::name(key), Arg::param("partitionKey")]);
         }
 
-        let (mut query, params) = GremlinTransaction::add_properties(first, params, props, sg);
+        let (mut traversal, params) = GremlinTransaction::add_properties(first, params, props, sg);
 
-        query.push_str(&(".as('".to_string() + node_var.name() + "')"));
+        traversal.push("as", vec![Arg::name(node_var.name())]);
 
         if !rel_create_fragments.is_empty() {
-            query.push_str(&rel_create_fragments.into_iter().fold(
-                String::new(),
-                |mut acc, fragment| {
-                    acc.push_str(&fragment);
-                    acc
-                },
-            ));
-            query.push_str(&(".select('".to_string() + node_var.name() + "')"));
+            for fragment in rel_create_fragments.iter() {
+                traversal.extend(self.traversal(fragment)?);
+            }
+            traversal.push("select", vec![Arg::name(node_var.name())]);
         }
 
         if let ClauseType::Query = clause {
-            query.push_str(NODE_RETURN_FRAGMENT);
+            traversal.node_return();
         }
 
-        Ok((query, params))
+        Ok((self.add_traversal(traversal), params))
     }
 
-    fn create_node<RequestCtx: RequestContext>(
+    fn create_node(
         &mut self,
         query: String,
         params: HashMap<String, Value>,
         partition_key_opt: Option<&Value>,
         info: &Info,
-    ) -> Result<Node<RequestCtx>, Error> {
+    ) -> Result<NodeRecord, Error> {
         trace!("GremlinTransaction::create_node called -- query: {}, params: {:#?}, partition_key_opt: {:#?}", query, params, partition_key_opt);
 
-        let mut param_list: Vec<(&str, &dyn ToGValue)> =
-            params.iter().fold(Vec::new(), |mut pl, (k, v)| {
-                pl.push((k.as_str(), v));
-                pl
-            });
-
-        if self.partition {
-            if let Some(pk) = partition_key_opt {
-                param_list.push(("partitionKey", pk));
-            } else {
-                return Err(Error::PartitionKeyNotFound);
-            }
-        }
-
-        let r0 = self.client.execute(query, param_list.as_slice());
-        trace!("GremlinTransaction::create_node -- r0: {:#?}", r0);
-        let raw_results = r0?;
-        trace!(
-            "GremlinTransaction::create_node -- raw_results: {:#?}",
-            raw_results
-        );
-        let results = raw_results
-            .map(|r| Ok(r?))
-            .collect::<Result<Vec<GValue>, Error>>()?;
+        let results = self.run_write(&query, &params, partition_key_opt)?;
+        trace!("GremlinTransaction::create_node -- results: {:#?}", results);
 
-        GremlinTransaction::nodes(results, info)?
+        GremlinTransaction::nodes(results, info, &self.db_names)?
             .into_iter()
             .next()
             .ok_or_else(|| Error::ResponseSetNotFound)
     }
 
-    fn rel_create_fragment<RequestCtx: RequestContext>(
+    fn rel_create_fragment(
         &mut self,
         dst_query: &str,
         params: HashMap<String, Value>,
@@ -535,30 +1125,39 @@ impl Transaction for GremlinTransaction {
         clause: ClauseType,
         sg: &mut SuffixGenerator,
     ) -> Result<(String, HashMap<String, Value>), Error> {
-        trace!("GremlinTransaction::rel_create_fragment called -- dst_query: {}, params: {:#?}, rel_var: {:#?}, props: {:#?}, clause: {:#?}", 
+        trace!("GremlinTransaction::rel_create_fragment called -- dst_query: {}, params: {:#?}, rel_var: {:#?}, props: {:#?}, clause: {:#?}",
         dst_query, params, rel_var, props, clause);
 
-        let query = dst_query.to_string()
-            + ".addE('"
-            + rel_var.label()
-            + "').from('"
-            + rel_var.src().name()
-            + "').to('"
-            + rel_var.dst().name()
-            + "')";
-        let (mut q, p) = GremlinTransaction::add_properties(query, params, props, sg);
+        let mut traversal = self.traversal(dst_query)?;
+        traversal.push(
+            "addE",
+            vec![Arg::name(
+                self.db_names
+                    .rel_type(rel_var.src().label().ok(), rel_var.label()),
+            )],
+        );
+        traversal.push("from", vec![Arg::name(rel_var.src().name())]);
+        traversal.push("to", vec![Arg::name(rel_var.dst().name())]);
+
+        let (mut t, p) = GremlinTransaction::add_properties(
+            traversal,
+            params,
+            self.db_names
+                .rel_props(rel_var.src().label().ok(), rel_var.label(), props),
+            sg,
+        );
 
         match clause {
             ClauseType::Parameter | ClauseType::FirstSubQuery | ClauseType::SubQuery => {
-                q.push_str(&(".as('".to_string() + rel_var.name() + "')"))
+                t.push("as", vec![Arg::name(rel_var.name())])
             }
-            ClauseType::Query => q.push_str(REL_RETURN_FRAGMENT),
+            ClauseType::Query => t.rel_return(),
         };
 
-        Ok((q, p))
+        Ok((self.add_traversal(t), p))
     }
 
-    fn rel_create_query<RequestCtx: RequestContext>(
+    fn rel_create_query(
         &mut self,
         src_query_opt: Option<String>,
         rel_create_fragments: Vec<String>,
@@ -568,71 +1167,48 @@ impl Transaction for GremlinTransaction {
     ) -> Result<(String, HashMap<String, Value>), Error> {
         trace!("GremlinTransaction::rel_create_query called -- src_query_opt: {:#?}, rel_create_fragments: {:#?}, params: {:#?}, rel_vars: {:#?}, clause: {:#?}",
         src_query_opt, rel_create_fragments, params, rel_vars, clause);
-        let mut query = if let ClauseType::Query = clause {
-            "g".to_string()
-        } else {
-            String::new()
-        };
+        let mut traversal = Traversal::default();
 
         if let Some(src_query) = src_query_opt {
-            query.push_str(&src_query)
+            traversal.extend(self.traversal(&src_query)?);
         }
 
-        rel_create_fragments
-            .iter()
-            .for_each(|rcf| query.push_str(rcf));
-
-        if rel_vars.len() > 1 {
-            query.push_str(".union(");
-        } else {
-            query.push_str(".")
+        for rcf in rel_create_fragments.iter() {
+            traversal.extend(self.traversal(rcf)?);
         }
 
-        rel_vars.iter().enumerate().for_each(|(i, return_var)| {
-            if i > 0 {
-                query.push_str(", ");
-            }
-            query.push_str(&("select('".to_string() + return_var.name() + "')"));
-            query.push_str(REL_RETURN_FRAGMENT);
-        });
+        let mut returns = rel_vars
+            .iter()
+            .map(|return_var| {
+                let mut t = Traversal::default();
+                t.push("select", vec![Arg::name(return_var.name())]);
+                t.rel_return();
+                t
+            })
+            .collect::<Vec<Traversal>>();
 
-        if rel_create_fragments.len() > 1 {
-            query.push_str(")");
+        if returns.len() > 1 {
+            traversal.push("union", returns.into_iter().map(Arg::Traversal).collect());
+        } else if let Some(t) = returns.pop() {
+            traversal.extend(t);
         }
 
-        Ok((query, params))
+        Ok((self.add_traversal(traversal), params))
     }
 
-    fn create_rels<RequestCtx: RequestContext>(
+    fn create_rels(
         &mut self,
         query: String,
         params: HashMap<String, Value>,
         props_type_name: Option<&str>,
         partition_key_opt: Option<&Value>,
-    ) -> Result<Vec<Rel<RequestCtx>>, Error> {
+    ) -> Result<Vec<RelRecord>, Error> {
         trace!("GremlinTransaction::create_rels called -- query: {}, params: {:#?}, props_type_name: {:#?}, partition_key_opt: {:#?}",
         query, params, props_type_name, partition_key_opt);
 
-        let mut param_list: Vec<(&str, &dyn ToGValue)> =
-            params.iter().fold(Vec::new(), |mut pl, (k, v)| {
-                pl.push((k.as_str(), v));
-                pl
-            });
-
-        if self.partition {
-            if let Some(pk) = partition_key_opt {
-                param_list.push(("partitionKey", pk));
-            } else {
-                return Err(Error::PartitionKeyNotFound);
-            }
-        }
-
-        let raw_results = self.client.execute(query, param_list.as_slice())?;
-        let results = raw_results
-            .map(|r| Ok(r?))
-            .collect::<Result<Vec<GValue>, Error>>()?;
+        let results = self.run_write(&query, &params, partition_key_opt)?;
 
-        GremlinTransaction::rels(results, props_type_name, partition_key_opt)
+        GremlinTransaction::rels(results, props_type_name, partition_key_opt, &self.db_names)
     }
 
     fn node_read_fragment(
@@ -648,126 +1224,90 @@ impl Transaction for GremlinTransaction {
         rel_query_fragments, params, node_var, props, clause);
 
         let param_suffix = sg.suffix();
-        let mut query = if node_var.label().is_ok() {
-            ".hasLabel('".to_string() + node_var.label()? + "')"
-        } else {
-            String::new()
-        };
-
-        if self.partition {
-            query.push_str(".has('partitionKey', partitionKey)");
+        let mut traversal = Traversal::default();
+        if node_var.label().is_ok() {
+            traversal.push(
+                "hasLabel",
+                vec![Arg::name(self.db_names.label(node_var.label()?))],
+            );
         }
 
-        for (k, v) in props.into_iter() {
-            if k == "id" {
-                // For id, we omit the single quotes, because it's a "system" property, not just a
-                // user defined property.
-                query.push_str(&(".has(".to_string() + &k + ", " + &k + &param_suffix + ")"));
-            } else {
-                // For all user-defined properties, we single-quote the property name
-                query.push_str(&(".has('".to_string() + &k + "', " + &k + &param_suffix + ")"));
-            }
-
-            if self.uuid && k == "id" {
-                if let Value::String(s) = v {
-                    params.insert(k + &param_suffix, Value::Uuid(Uuid::parse_str(&s)?));
-                } else {
-                    return Err(Error::TypeConversionFailed {
-                        src: format!("{:#?}", v),
-                        dst: "String".to_string(),
-                    });
-                }
-            } else {
-                params.insert(k + &param_suffix, v);
-            }
-        }
+        self.add_has(
+            &mut traversal,
+            &mut params,
+            node_var.label().ok(),
+            self.db_names.props(node_var.label().ok(), props),
+            &param_suffix,
+        )?;
 
         if !rel_query_fragments.is_empty() {
-            query.push_str(".where(");
-
-            if rel_query_fragments.len() > 1 {
-                query.push_str("and(");
-            }
-
-            rel_query_fragments.iter().enumerate().for_each(|(i, rqf)| {
-                if i == 0 {
-                    query.push_str(&("outE()".to_string() + &rqf.1));
-                } else {
-                    query.push_str(&(", outE()".to_string() + &rqf.1));
-                }
-            });
+            let rel_matches = rel_query_fragments
+                .iter()
+                .map(|rqf| {
+                    let mut t = Traversal::default();
+                    t.push("outE", Vec::new());
+                    t.extend(self.traversal(&rqf.1)?);
+                    Ok(Arg::Traversal(t))
+                })
+                .collect::<Result<Vec<Arg>, Error>>()?;
 
-            if rel_query_fragments.len() > 1 {
-                query.push_str(")");
+            if rel_matches.len() > 1 {
+                let mut and = Traversal::default();
+                and.push("and", rel_matches);
+                traversal.push("where", vec![Arg::Traversal(and)]);
+            } else {
+                traversal.push("where", rel_matches);
             }
-
-            query.push_str(")");
         }
 
         if let ClauseType::SubQuery = clause {
-            query.push_str(&(".as('".to_string() + node_var.name() + "')"));
+            traversal.push("as", vec![Arg::name(node_var.name())]);
         }
 
-        Ok(("".to_string(), query, params))
+        Ok(("".to_string(), self.add_traversal(traversal), params))
     }
 
     fn node_read_query(
         &mut self,
         match_fragment: &str,
         where_fragment: &str,
-        params: HashMap<String, Value>,
+        mut params: HashMap<String, Value>,
         _node_var: &NodeQueryVar,
         clause: ClauseType,
+        limit: Option<usize>,
     ) -> Result<(String, HashMap<String, Value>), Error> {
-        trace!("GremlinTransaction::node_read_query called -- match_fragment: {}, where_fragment: {}, params: {:#?}, clause: {:#?}",
-        match_fragment, where_fragment, params, clause);
-
-        let mut query = if let ClauseType::Query = clause {
-            "g".to_string()
-        } else {
-            String::new()
-        };
+        trace!("GremlinTransaction::node_read_query called -- match_fragment: {}, where_fragment: {}, params: {:#?}, clause: {:#?}, limit: {:#?}",
+        match_fragment, where_fragment, params, clause, limit);
 
-        query.push_str(&(".V()".to_string() + match_fragment + where_fragment));
+        let mut traversal = Traversal::default();
+        traversal.push("V", Vec::new());
+        traversal.extend(self.traversal(match_fragment)?);
+        traversal.extend(self.traversal(where_fragment)?);
 
         if let ClauseType::Query = clause {
-            query.push_str(NODE_RETURN_FRAGMENT);
+            if let Some(limit) = limit {
+                traversal.limit(limit, &mut params)?;
+            }
+            traversal.node_return();
         }
 
-        Ok((query, params))
+        Ok((self.add_traversal(traversal), params))
     }
 
-    fn read_nodes<RequestCtx: RequestContext>(
+    fn read_nodes(
         &mut self,
         query: String,
         params_opt: Option<HashMap<String, Value>>,
         partition_key_opt: Option<&Value>,
         info: &Info,
-    ) -> Result<Vec<Node<RequestCtx>>, Error> {
-        trace!("GremlinTransaction::read_nodes called -- query: {}, partition_key_opt: {:#?}, params_opt: {:#?}, info.name: {}", 
+    ) -> Result<Vec<NodeRecord>, Error> {
+        trace!("GremlinTransaction::read_nodes called -- query: {}, partition_key_opt: {:#?}, params_opt: {:#?}, info.name: {}",
         query, partition_key_opt, params_opt, info.name());
 
         let params = params_opt.unwrap_or_else(HashMap::new);
-        let mut param_list: Vec<(&str, &dyn ToGValue)> =
-            params.iter().fold(Vec::new(), |mut pl, (k, v)| {
-                pl.push((k, v));
-                pl
-            });
-
-        if self.partition {
-            if let Some(pk) = partition_key_opt {
-                param_list.push(("partitionKey", pk));
-            } else {
-                return Err(Error::PartitionKeyNotFound);
-            }
-        }
-
-        let raw_results = self.client.execute(query, param_list.as_slice())?;
-        let results = raw_results
-            .map(|r| Ok(r?))
-            .collect::<Result<Vec<GValue>, Error>>()?;
+        let results = self.run(&query, &params, partition_key_opt)?;
 
-        GremlinTransaction::nodes(results, info)
+        GremlinTransaction::nodes(results, info, &self.db_names)
     }
 
     fn rel_read_fragment(
@@ -783,137 +1323,106 @@ impl Transaction for GremlinTransaction {
         src_query_opt, dst_query_opt, params, rel_var, props);
 
         let param_suffix = sg.suffix();
-        let mut query = ".hasLabel('".to_string() + rel_var.label() + "')";
+        let mut traversal = Traversal::default();
+        traversal.push(
+            "hasLabel",
+            vec![Arg::name(
+                self.db_names
+                    .rel_type(rel_var.src().label().ok(), rel_var.label()),
+            )],
+        );
 
-        if self.partition {
-            query.push_str(".has('partitionKey', partitionKey)");
-        }
+        self.add_has(
+            &mut traversal,
+            &mut params,
+            rel_var.src().label().ok(),
+            self.db_names
+                .rel_props(rel_var.src().label().ok(), rel_var.label(), props),
+            &param_suffix,
+        )?;
 
-        for (k, v) in props.into_iter() {
-            if k == "id" {
-                // For id, we omit the single quotes, because it's a "system" property, not just a
-                // user defined property.
-                query.push_str(&(".has(".to_string() + &k + ", " + &k + &param_suffix + ")"));
-            } else {
-                // For all other user defined properties, we sinlge quote the property name.
-                query.push_str(&(".has('".to_string() + &k + "', " + &k + &param_suffix + ")"));
-            }
+        let mut node_matches = Vec::new();
 
-            if self.uuid && k == "id" {
-                if let Value::String(s) = v {
-                    params.insert(k + &param_suffix, Value::Uuid(Uuid::parse_str(&s)?));
-                } else {
-                    return Err(Error::TypeConversionFailed {
-                        src: format!("{:#?}", v),
-                        dst: "String".to_string(),
-                    });
-                }
-            } else {
-                params.insert(k + &param_suffix, v);
-            }
+        if let Some(src_query) = src_query_opt {
+            let mut t = Traversal::default();
+            t.push("outV", Vec::new());
+            t.extend(self.traversal(&src_query.1)?);
+            node_matches.push(Arg::Traversal(t));
         }
 
-        if src_query_opt.is_some() || dst_query_opt.is_some() {
-            query.push_str(".where(");
-
-            let both = src_query_opt.is_some() && dst_query_opt.is_some();
-
-            if both {
-                query.push_str("and(");
-            }
-
-            if let Some(src_query) = src_query_opt {
-                query.push_str(&("outV()".to_string() + &src_query.1));
-            }
-
-            if both {
-                query.push_str(", ");
-            }
-
-            if let Some(dst_query) = dst_query_opt {
-                query.push_str(&("inV()".to_string() + &dst_query.1));
-            }
+        if let Some(dst_query) = dst_query_opt {
+            let mut t = Traversal::default();
+            t.push("inV", Vec::new());
+            t.extend(self.traversal(&dst_query.1)?);
+            node_matches.push(Arg::Traversal(t));
+        }
 
-            if both {
-                query.push_str(")");
-            }
-            query.push_str(")");
+        if node_matches.len() > 1 {
+            let mut and = Traversal::default();
+            and.push("and", node_matches);
+            traversal.push("where", vec![Arg::Traversal(and)]);
+        } else if !node_matches.is_empty() {
+            traversal.push("where", node_matches);
         }
 
-        Ok(("".to_string(), query, params))
+        Ok(("".to_string(), self.add_traversal(traversal), params))
     }
 
     fn rel_read_query(
         &mut self,
         match_fragment: &str,
         where_fragment: &str,
-        params: HashMap<String, Value>,
+        mut params: HashMap<String, Value>,
         rel_var: &RelQueryVar,
         clause: ClauseType,
+        limit: Option<usize>,
     ) -> Result<(String, HashMap<String, Value>), Error> {
-        trace!("GremlinTransaction::rel_read_query called -- match_fragment: {}, where_fragment: {}, params: {:#?}, rel_var: {:#?}, clause: {:#?}",
-        match_fragment, where_fragment, params, rel_var, clause);
-
-        let mut q = match clause {
-            // ClauseType::Parameter => "outE('".to_string() + rel_var.label() + "')",
-            ClauseType::Parameter => "outE()".to_string(),
-            ClauseType::FirstSubQuery => ".E()".to_string(),
-            // ClauseType::SubQuery => ".outE('".to_string() + rel_var.label() + "')",
-            ClauseType::SubQuery => ".outE()".to_string(),
-            ClauseType::Query => "g.E()".to_string(),
+        trace!("GremlinTransaction::rel_read_query called -- match_fragment: {}, where_fragment: {}, params: {:#?}, rel_var: {:#?}, clause: {:#?}, limit: {:#?}",
+        match_fragment, where_fragment, params, rel_var, clause, limit);
+
+        let mut traversal = Traversal::default();
+        match clause {
+            ClauseType::Parameter | ClauseType::SubQuery => traversal.push("outE", Vec::new()),
+            ClauseType::FirstSubQuery | ClauseType::Query => traversal.push("E", Vec::new()),
         };
 
-        q.push_str(match_fragment);
-        q.push_str(where_fragment);
+        traversal.extend(self.traversal(match_fragment)?);
+        traversal.extend(self.traversal(where_fragment)?);
 
-        let query = match clause {
-            ClauseType::Parameter => q,
-            ClauseType::FirstSubQuery => q + ".as('" + rel_var.name() + "')",
-            ClauseType::SubQuery => q + ".as('" + rel_var.name() + "')",
-            ClauseType::Query => GremlinTransaction::add_rel_return(q),
+        match clause {
+            ClauseType::Parameter => (),
+            ClauseType::FirstSubQuery | ClauseType::SubQuery => {
+                traversal.push("as", vec![Arg::name(rel_var.name())])
+            }
+            ClauseType::Query => {
+                if let Some(limit) = limit {
+                    traversal.limit(limit, &mut params)?;
+                }
+                traversal.rel_return()
+            }
         };
 
-        Ok((query, params))
+        Ok((self.add_traversal(traversal), params))
     }
 
-    fn read_rels<RequestCtx: RequestContext>(
+    fn read_rels(
         &mut self,
         query: String,
         params_opt: Option<HashMap<String, Value>>,
         props_type_name: Option<&str>,
         partition_key_opt: Option<&Value>,
-    ) -> Result<Vec<Rel<RequestCtx>>, Error> {
-        trace!("GremlinTransaction::read_rels called -- query: {}, props_type_name: {:#?}, partition_key_opt: {:#?}, params_opt: {:#?}", 
+    ) -> Result<Vec<RelRecord>, Error> {
+        trace!("GremlinTransaction::read_rels called -- query: {}, props_type_name: {:#?}, partition_key_opt: {:#?}, params_opt: {:#?}",
         query, props_type_name, partition_key_opt, params_opt);
 
         let params = params_opt.unwrap_or_else(HashMap::new);
-        let mut param_list: Vec<(&str, &dyn ToGValue)> =
-            params.iter().fold(Vec::new(), |mut pl, (k, v)| {
-                pl.push((k, v));
-                pl
-            });
-
-        if self.partition {
-            if let Some(pk) = partition_key_opt {
-                param_list.push(("partitionKey", pk));
-            } else {
-                return Err(Error::PartitionKeyNotFound);
-            }
-        }
-
-        let raw_results = self.client.execute(query, param_list.as_slice())?;
-        trace!(
-            "GremlinTransaction::read_rels -- raw_results: {:#?}",
-            raw_results
-        );
-        let results = raw_results
-            .map(|r| Ok(r?))
-            .collect::<Result<Vec<GValue>, Error>>()?;
+        let results = self.run(&query, &params, partition_key_opt)?;
+        trace!("GremlinTransaction::read_rels -- results: {:#?}", results);
 
-        GremlinTransaction::rels(results, props_type_name, partition_key_opt)
+        GremlinTransaction::rels(results, props_type_name, partition_key_opt, &self.db_names)
     }
 
-    fn node_update_query<RequestCtx: RequestContext>(
+    fn node_update_query(
         &mut self,
         match_query: String,
         change_queries: Vec<String>,
@@ -925,61 +1434,45 @@ impl Transaction for GremlinTransaction {
     ) -> Result<(String, HashMap<String, Value>), Error> {
         trace!("GremlinTransaction::node_update_query called: match_query: {}, change_queries: {:#?}, params: {:#?}, node_var: {:#?}, props: {:#?}, clause: {:#?}",
         match_query, change_queries, params, node_var, props, clause);
-        let mut query = if let ClauseType::Query = clause {
-            "g".to_string() + &match_query
-        } else {
-            match_query
-        };
+        let mut traversal = self.traversal(&match_query)?;
 
         if !change_queries.is_empty() {
             for cq in change_queries.iter() {
-                query.push_str(cq);
+                traversal.extend(self.traversal(cq)?);
             }
-            query.push_str(&(".select('".to_string() + node_var.name() + "')"));
+            traversal.push("select", vec![Arg::name(node_var.name())]);
         }
-        let (mut query, params) = GremlinTransaction::add_properties(query, params, props, sg);
-        query.push_str(NODE_RETURN_FRAGMENT);
+        let (mut traversal, params) = GremlinTransaction::add_properties(
+            traversal,
+            params,
+            self.db_names.props(node_var.label().ok(), props),
+            sg,
+        );
+        traversal.node_return();
 
-        Ok((query, params))
+        Ok((self.add_traversal(traversal), params))
     }
 
-    fn update_nodes<RequestCtx: RequestContext>(
+    fn update_nodes(
         &mut self,
         query: String,
         params: HashMap<String, Value>,
         partition_key_opt: Option<&Value>,
         info: &Info,
-    ) -> Result<Vec<Node<RequestCtx>>, Error> {
+    ) -> Result<Vec<NodeRecord>, Error> {
         trace!("GremlinTransaction::update_nodes called: query: {}, params: {:#?}, partition_key_opt: {:#?}",
         query, params, partition_key_opt);
 
-        let mut param_list: Vec<(&str, &dyn ToGValue)> =
-            params.iter().fold(Vec::new(), |mut pl, (k, v)| {
-                pl.push((k.as_str(), v));
-                pl
-            });
-
-        if self.partition {
-            if let Some(pk) = partition_key_opt {
-                param_list.push(("partitionKey", pk));
-            } else {
-                return Err(Error::PartitionKeyNotFound);
-            }
-        }
-
-        let raw_results = self.client.execute(query, param_list.as_slice())?;
+        let results = self.run_write(&query, &params, partition_key_opt)?;
         trace!(
-            "GremlinTransaction::update_nodes -- raw_results: {:#?}",
-            raw_results
+            "GremlinTransaction::update_nodes -- results: {:#?}",
+            results
         );
-        let results = raw_results
-            .map(|r| Ok(r?))
-            .collect::<Result<Vec<GValue>, Error>>()?;
 
-        GremlinTransaction::nodes(results, info)
+        GremlinTransaction::nodes(results, info, &self.db_names)
     }
 
-    fn rel_update_query<RequestCtx: RequestContext>(
+    fn rel_update_query(
         &mut self,
         match_query: String,
         params: HashMap<String, Value>,
@@ -991,51 +1484,36 @@ impl Transaction for GremlinTransaction {
         trace!("GremlinTransaction::rel_update_query called -- match_query: {}, params: {:#?}, rel_var: {:#?}, props: {:#?}, clause: {:#?}",
         match_query, params, rel_var, props, clause);
 
-        let mut fragment = if let ClauseType::Query = clause {
-            "g".to_string() + &match_query
-        } else {
-            match_query
-        };
-
-        fragment.push_str(&(".select('".to_string() + rel_var.name() + "')"));
-        let (q, p) = GremlinTransaction::add_properties(fragment, params, props, sg);
+        let mut fragment = self.traversal(&match_query)?;
+        fragment.push("select", vec![Arg::name(rel_var.name())]);
+        let (mut t, p) = GremlinTransaction::add_properties(
+            fragment,
+            params,
+            self.db_names
+                .rel_props(rel_var.src().label().ok(), rel_var.label(), props),
+            sg,
+        );
 
-        match clause {
-            ClauseType::Parameter | ClauseType::FirstSubQuery | ClauseType::SubQuery => Ok((q, p)),
-            ClauseType::Query => Ok((GremlinTransaction::add_rel_return(q), p)),
+        if let ClauseType::Query = clause {
+            t.rel_return();
         }
+
+        Ok((self.add_traversal(t), p))
     }
 
-    fn update_rels<RequestCtx: RequestContext>(
+    fn update_rels(
         &mut self,
         query: String,
         params: HashMap<String, Value>,
         props_type_name: Option<&str>,
         partition_key_opt: Option<&Value>,
-    ) -> Result<Vec<Rel<RequestCtx>>, Error> {
+    ) -> Result<Vec<RelRecord>, Error> {
         trace!("GremlinTransaction::update_rels called -- query: {}, params: {:#?}, props_type_name: {:#?}, partition_key_opt: {:#?}",
         query, params, props_type_name, partition_key_opt);
 
-        let mut param_list: Vec<(&str, &dyn ToGValue)> =
-            params.iter().fold(Vec::new(), |mut pl, (k, v)| {
-                pl.push((k.as_str(), v));
-                pl
-            });
-
-        if self.partition {
-            if let Some(pk) = partition_key_opt {
-                param_list.push(("partitionKey", pk));
-            } else {
-                return Err(Error::PartitionKeyNotFound);
-            }
-        }
+        let results = self.run_write(&query, &params, partition_key_opt)?;
 
-        let raw_results = self.client.execute(query, param_list.as_slice())?;
-        let results = raw_results
-            .map(|r| Ok(r?))
-            .collect::<Result<Vec<GValue>, Error>>()?;
-
-        GremlinTransaction::rels(results, props_type_name, partition_key_opt)
+        GremlinTransaction::rels(results, props_type_name, partition_key_opt, &self.db_names)
     }
 
     fn node_delete_query(
@@ -1050,24 +1528,20 @@ impl Transaction for GremlinTransaction {
         trace!("GremlinTransaction::node_delete_query -- match_query: {}, rel_delete_fragments: {:#?}, params: {:#?}, node_var: {:#?}, clause: {:#?}",
         match_query, rel_delete_fragments, params, node_var, clause);
 
-        let mut query = if let ClauseType::Query = clause {
-            "g".to_string() + &match_query
-        } else {
-            match_query
-        };
+        let mut traversal = self.traversal(&match_query)?;
 
         if !rel_delete_fragments.is_empty() {
-            query.push_str(&(".as('".to_string() + node_var.name() + "')"));
+            traversal.push("as", vec![Arg::name(node_var.name())]);
             for q in rel_delete_fragments.iter() {
-                query.push_str(q)
+                traversal.extend(self.traversal(q)?);
             }
-            query.push_str(&(".select('".to_string() + node_var.name() + "')"));
+            traversal.push("select", vec![Arg::name(node_var.name())]);
         }
-        query.push_str(&(".sideEffect(drop())"));
+        traversal.push("sideEffect", vec![Arg::steps(&["drop"])]);
         if let ClauseType::Query = clause {
-            query.push_str(".count()");
+            traversal.push("count", Vec::new());
         }
-        Ok((query, params))
+        Ok((self.add_traversal(traversal), params))
     }
 
     fn delete_nodes(
@@ -1076,27 +1550,10 @@ impl Transaction for GremlinTransaction {
         params: HashMap<String, Value>,
         partition_key_opt: Option<&Value>,
     ) -> Result<i32, Error> {
-        trace!("GremlinTransaction::delete_nodes called -- query: {}, params: {:#?}, partition_key_opt: {:#?}", 
+        trace!("GremlinTransaction::delete_nodes called -- query: {}, params: {:#?}, partition_key_opt: {:#?}",
         query, params, partition_key_opt);
 
-        let mut param_list: Vec<(&str, &dyn ToGValue)> =
-            params.iter().fold(Vec::new(), |mut pl, (k, v)| {
-                pl.push((k.as_str(), v));
-                pl
-            });
-
-        if self.partition {
-            if let Some(pk) = partition_key_opt {
-                param_list.push(("partitionKey", pk));
-            } else {
-                return Err(Error::PartitionKeyNotFound);
-            }
-        }
-
-        let raw_results = self.client.execute(query, param_list.as_slice())?;
-        let results = raw_results
-            .map(|r| Ok(r?))
-            .collect::<Result<Vec<GValue>, Error>>()?;
+        let results = self.run_write(&query, &params, partition_key_opt)?;
 
         GremlinTransaction::extract_count(results)
     }
@@ -1113,30 +1570,26 @@ impl Transaction for GremlinTransaction {
     ) -> Result<(String, HashMap<String, Value>), Error> {
         trace!("GremlinTransaction::rel_delete_query called -- query: {}, src_delete_query_opt: {:#?}, dst_delete_query_opt: {:#?}, params: {:#?}, rel_var: {:#?}, clause: {:#?}",
         query, src_delete_query_opt, dst_delete_query_opt, params, rel_var, clause);
-        let mut q = if let ClauseType::Query = clause {
-            "g".to_string() + &query
-        } else {
-            query
-        };
+        let mut traversal = self.traversal(&query)?;
 
         if src_delete_query_opt.is_some() || dst_delete_query_opt.is_some() {
             if let Some(sdq) = src_delete_query_opt {
-                q.push_str(&sdq);
+                traversal.extend(self.traversal(&sdq)?);
             }
             if let Some(ddq) = dst_delete_query_opt {
-                q.push_str(&ddq);
+                traversal.extend(self.traversal(&ddq)?);
             }
 
-            q.push_str(&(".select('".to_string() + rel_var.name() + "')"));
+            traversal.push("select", vec![Arg::name(rel_var.name())]);
         }
 
-        q.push_str(".sideEffect(drop())");
+        traversal.push("sideEffect", vec![Arg::steps(&["drop"])]);
 
         if let ClauseType::Query = clause {
-            q.push_str(".count()");
+            traversal.push("count", Vec::new());
         }
 
-        Ok((q, params))
+        Ok((self.add_traversal(traversal), params))
     }
 
     fn delete_rels(
@@ -1148,24 +1601,7 @@ impl Transaction for GremlinTransaction {
         trace!("GremlinTransaction::delete_rels called -- query: {}, params: {:#?}, partition_key_opt: {:#?}",
         query, params, partition_key_opt);
 
-        let mut param_list: Vec<(&str, &dyn ToGValue)> =
-            params.iter().fold(Vec::new(), |mut pl, (k, v)| {
-                pl.push((k.as_str(), v));
-                pl
-            });
-
-        if self.partition {
-            if let Some(pk) = partition_key_opt {
-                param_list.push(("partitionKey", pk));
-            } else {
-                return Err(Error::PartitionKeyNotFound);
-            }
-        }
-
-        let raw_results = self.client.execute(query, param_list.as_slice())?;
-        let results = raw_results
-            .map(|r| Ok(r?))
-            .collect::<Result<Vec<GValue>, Error>>()?;
+        let results = self.run_write(&query, &params, partition_key_opt)?;
 
         GremlinTransaction::extract_count(results)
     }
@@ -1177,6 +1613,10 @@ impl Transaction for GremlinTransaction {
     fn rollback(&mut self) -> Result<(), Error> {
         Ok(())
     }
+
+    fn irreversible_writes(&self) -> bool {
+        self.written
+    }
 }
 
 impl ToGValue for Value {
@@ -1330,7 +1770,129 @@ mod tests {
     use super::CosmosEndpoint;
     #[cfg(feature = "gremlin")]
     use super::GremlinEndpoint;
-    use super::GremlinTransaction;
+    use super::{Arg, GremlinTransaction, Traversal};
+    use crate::engine::value::Value;
+    use crate::Error;
+    use gremlin_client::process::traversal::Bytecode;
+    use gremlin_client::structure::T;
+    use gremlin_client::GValue;
+    use std::collections::HashMap;
+
+    fn traversal() -> Traversal {
+        let mut t = Traversal::default();
+        t.push("V", Vec::new());
+        t.push("hasLabel", vec![Arg::name("User")]);
+        t.push("has", vec![Arg::Id, Arg::param("id_1")]);
+        t.push(
+            "has",
+            vec![Arg::name("name').drop(); g.V('"), Arg::param("name_1")],
+        );
+        t.push("has", vec![Arg::name("alias"), Arg::param("name_1")]);
+        t.push("sideEffect", vec![Arg::steps(&["drop"])]);
+        t
+    }
+
+    /// Passes if a traversal converts to bytecode with its parameter values substituted
+    #[test]
+    fn traversal_bytecode() {
+        let id = Value::String("1234".to_string());
+        let name = Value::String("Joe".to_string());
+        let mut values: HashMap<&str, &Value> = HashMap::new();
+        values.insert("id_1", &id);
+        values.insert("name_1", &name);
+
+        let mut drop = Bytecode::new();
+        drop.add_step("drop".to_string(), Vec::new());
+
+        let mut expected = Bytecode::new();
+        expected.add_step("V".to_string(), Vec::new());
+        expected.add_step(
+            "hasLabel".to_string(),
+            vec![GValue::String("User".to_string())],
+        );
+        expected.add_step(
+            "has".to_string(),
+            vec![GValue::T(T::Id), GValue::String("1234".to_string())],
+        );
+        expected.add_step(
+            "has".to_string(),
+            vec![
+                GValue::String("name').drop(); g.V('".to_string()),
+                GValue::String("Joe".to_string()),
+            ],
+        );
+        expected.add_step(
+            "has".to_string(),
+            vec![
+                GValue::String("alias".to_string()),
+                GValue::String("Joe".to_string()),
+            ],
+        );
+        expected.add_step("sideEffect".to_string(), vec![GValue::Bytecode(drop)]);
+
+        assert_eq!(traversal().bytecode(&values).unwrap(), expected);
+    }
+
+    /// Passes if converting a traversal to bytecode fails when a parameter has no value
+    #[test]
+    fn traversal_bytecode_param_not_found() {
+        let id = Value::String("1234".to_string());
+        let mut values: HashMap<&str, &Value> = HashMap::new();
+        values.insert("id_1", &id);
+
+        match traversal().bytecode(&values) {
+            Err(Error::InputItemNotFound { name }) => assert_eq!(name, "name_1"),
+            _ => panic!("Expected InputItemNotFound"),
+        }
+    }
+
+    /// Passes if a traversal renders as a script with quoted names and numbered bindings, with
+    /// each parameter bound once
+    #[test]
+    fn traversal_script() {
+        let mut bindings = Vec::new();
+        let script = traversal().script(&mut bindings);
+
+        assert_eq!(
+            script,
+            "V().hasLabel('User').has(id, p0).has('name\\').drop(); g.V(\\'', p1)\
+            .has('alias', p1).sideEffect(drop())"
+        );
+        assert_eq!(bindings, vec!["id_1".to_string(), "name_1".to_string()]);
+    }
+
+    /// Passes if only the partition key filters that allow cross-partition queries are dropped
+    /// from a traversal, including those of nested traversals
+    #[test]
+    fn traversal_cross_partition() {
+        let mut nested = Traversal::default();
+        nested.push("inV", Vec::new());
+        nested.push_partition_filter("partitionKey", true);
+
+        let mut t = Traversal::default();
+        t.push("E", Vec::new());
+        t.push_partition_filter("tenant", true);
+        t.push_partition_filter("partitionKey", false);
+        t.push("where", vec![Arg::Traversal(nested)]);
+
+        let mut bindings = Vec::new();
+        assert_eq!(
+            t.script(&mut bindings),
+            "E().has('tenant', p0).has('partitionKey', p0).where(inV().has('partitionKey', p0))"
+        );
+
+        let mut bindings = Vec::new();
+        assert_eq!(
+            t.cross_partition().script(&mut bindings),
+            "E().has('partitionKey', p0).where(inV())"
+        );
+
+        let values: HashMap<&str, &Value> = HashMap::new();
+        match t.cross_partition().bytecode(&values) {
+            Err(Error::PartitionKeyNotFound) => (),
+            _ => panic!("Expected PartitionKeyNotFound"),
+        }
+    }
 
     #[cfg(feature = "cosmos")]
     #[test]
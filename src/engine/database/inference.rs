@@ -0,0 +1,357 @@
+//! Provides a backend-agnostic accumulator that turns samples of nodes and relationships read
+//! from an existing graph into a Warpgrapher [`Configuration`].
+//!
+//! [`Configuration`]: ../../config/struct.Configuration.html
+
+use crate::engine::config::{Configuration, EndpointsFilter, Property, Relationship, Type};
+use crate::engine::value::Value;
+use log::trace;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+/// GraphQL scalar type observed for a sampled property value
+#[derive(Copy, Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+enum ScalarKind {
+    Boolean,
+    Int,
+    Float,
+    String,
+}
+
+#[derive(Debug, Default)]
+struct PropObservations {
+    count: usize,
+    list: bool,
+    kinds: BTreeSet<ScalarKind>,
+}
+
+impl PropObservations {
+    fn observe(&mut self, value: &Value) {
+        match value {
+            Value::Null => return,
+            Value::Array(a) => {
+                self.list = true;
+                a.iter().for_each(|v| self.observe_scalar(v));
+            }
+            v => self.observe_scalar(v),
+        }
+        self.count += 1;
+    }
+
+    fn observe_scalar(&mut self, value: &Value) {
+        match value {
+            Value::Bool(_) => {
+                self.kinds.insert(ScalarKind::Boolean);
+            }
+            Value::Int64(_) | Value::UInt64(_) => {
+                self.kinds.insert(ScalarKind::Int);
+            }
+            Value::Float64(_) => {
+                self.kinds.insert(ScalarKind::Float);
+            }
+            Value::String(_) | Value::Uuid(_) => {
+                self.kinds.insert(ScalarKind::String);
+            }
+            Value::Array(_) | Value::Map(_) | Value::Null => (),
+        }
+    }
+
+    /// Returns the GraphQL type name that fits every observed value. Integers widen to floats,
+    /// and any other mix of types falls back to strings.
+    fn type_name(&self) -> &'static str {
+        let kinds: Vec<&ScalarKind> = self.kinds.iter().collect();
+        match kinds.as_slice() {
+            [ScalarKind::Boolean] => "Boolean",
+            [ScalarKind::Int] => "Int",
+            [ScalarKind::Float] | [ScalarKind::Int, ScalarKind::Float] => "Float",
+            _ => "String",
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct PropsObservations {
+    count: usize,
+    props: BTreeMap<String, PropObservations>,
+}
+
+impl PropsObservations {
+    fn observe(&mut self, props: &HashMap<String, Value>, skip: &[&str]) {
+        self.count += 1;
+        props
+            .iter()
+            .filter(|(k, _)| !skip.contains(&k.as_str()))
+            .for_each(|(k, v)| self.props.entry(k.to_string()).or_default().observe(v));
+    }
+
+    fn props(&self) -> Vec<Property> {
+        self.props
+            .iter()
+            .map(|(name, po)| {
+                Property::new(
+                    name.to_string(),
+                    po.type_name().to_string(),
+                    po.count == self.count,
+                    po.list,
+                    None,
+                    None,
+                )
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug, Default)]
+struct RelObservations {
+    list: bool,
+    nodes: BTreeSet<String>,
+    props: PropsObservations,
+}
+
+#[derive(Debug, Default)]
+struct TypeObservations {
+    props: PropsObservations,
+    rels: BTreeMap<String, RelObservations>,
+}
+
+/// Accumulates the labels, properties, and relationships observed in a sample of the nodes in a
+/// graph, and produces a [`Configuration`] describing them.
+///
+/// A property is inferred to be required if it is present in every sampled node with a given
+/// label, and to be a list if any sampled value is a list. A relationship is inferred to be a
+/// list if any sampled source node has more than one outgoing relationship of that name, and the
+/// relationship's `nodes` are the labels of all destination nodes sampled.
+///
+/// [`Configuration`]: ../../config/struct.Configuration.html
+#[derive(Debug, Default)]
+pub(crate) struct ModelInference {
+    skip_props: Vec<&'static str>,
+    types: BTreeMap<String, TypeObservations>,
+}
+
+impl ModelInference {
+    /// Creates an accumulator that ignores the named properties, such as the identifier and
+    /// partition key properties that Warpgrapher manages itself.
+    pub(crate) fn new(skip_props: Vec<&'static str>) -> ModelInference {
+        ModelInference {
+            skip_props,
+            types: BTreeMap::new(),
+        }
+    }
+
+    /// Records a sampled node with the given label and properties
+    pub(crate) fn observe_node(&mut self, label: &str, props: &HashMap<String, Value>) {
+        trace!(
+            "ModelInference::observe_node called -- label: {}, props: {:#?}",
+            label,
+            props
+        );
+
+        self.types
+            .entry(label.to_string())
+            .or_default()
+            .props
+            .observe(props, &self.skip_props);
+    }
+
+    /// Records all of the outgoing relationships from a single sampled source node. Each
+    /// relationship is given as its name, the label of its destination node, and its properties.
+    pub(crate) fn observe_rels(
+        &mut self,
+        src_label: &str,
+        rels: &[(String, String, HashMap<String, Value>)],
+    ) {
+        trace!(
+            "ModelInference::observe_rels called -- src_label: {}, rels: {:#?}",
+            src_label,
+            rels
+        );
+
+        let skip_props = &self.skip_props;
+        let to = self.types.entry(src_label.to_string()).or_default();
+
+        let mut counts: HashMap<&str, usize> = HashMap::new();
+        rels.iter().for_each(|(rel_name, dst_label, props)| {
+            *counts.entry(rel_name).or_default() += 1;
+            let ro = to.rels.entry(rel_name.to_string()).or_default();
+            ro.nodes.insert(dst_label.to_string());
+            ro.props.observe(props, skip_props);
+        });
+        counts
+            .into_iter()
+            .filter(|(_, count)| *count > 1)
+            .for_each(|(rel_name, _)| {
+                if let Some(ro) = to.rels.get_mut(rel_name) {
+                    ro.list = true;
+                }
+            });
+    }
+
+    /// Returns a [`Configuration`] describing the types observed so far
+    ///
+    /// [`Configuration`]: ../../config/struct.Configuration.html
+    pub(crate) fn configuration(&self) -> Configuration {
+        Configuration::new(
+            1,
+            self.types
+                .iter()
+                .map(|(label, to)| {
+                    Type::new(
+                        label.to_string(),
+                        to.props.props(),
+                        to.rels
+                            .iter()
+                            .map(|(rel_name, ro)| {
+                                Relationship::new(
+                                    rel_name.to_string(),
+                                    ro.list,
+                                    ro.nodes.iter().cloned().collect(),
+                                    ro.props.props(),
+                                    EndpointsFilter::all(),
+                                    None,
+                                )
+                            })
+                            .collect(),
+                        EndpointsFilter::all(),
+                    )
+                })
+                .collect(),
+            Vec::new(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ModelInference;
+    use crate::engine::value::Value;
+    use maplit::hashmap;
+    use std::collections::HashMap;
+
+    /// Passes if properties present on every sampled node are required, and others are optional
+    #[test]
+    fn infer_required_props() {
+        let mut mi = ModelInference::new(vec!["id"]);
+        mi.observe_node(
+            "User",
+            &hashmap! {
+                "id".to_string() => Value::String("1".to_string()),
+                "name".to_string() => Value::String("Alice".to_string()),
+                "age".to_string() => Value::Int64(30),
+            },
+        );
+        mi.observe_node(
+            "User",
+            &hashmap! {
+                "id".to_string() => Value::String("2".to_string()),
+                "name".to_string() => Value::String("Bob".to_string()),
+            },
+        );
+
+        let config = mi.configuration();
+        let user = config.types().next().unwrap();
+        assert_eq!(user.name(), "User");
+
+        let props: Vec<_> = user.props().collect();
+        assert_eq!(props.len(), 2);
+        assert_eq!(props[0].name(), "age");
+        assert_eq!(props[0].type_name(), "Int");
+        assert!(!props[0].required());
+        assert_eq!(props[1].name(), "name");
+        assert_eq!(props[1].type_name(), "String");
+        assert!(props[1].required());
+    }
+
+    /// Passes if value types are widened to fit every sampled value, and lists are detected
+    #[test]
+    fn infer_prop_types() {
+        let mut mi = ModelInference::new(vec![]);
+        mi.observe_node(
+            "Sample",
+            &hashmap! {
+                "flag".to_string() => Value::Bool(true),
+                "mixed".to_string() => Value::Int64(1),
+                "number".to_string() => Value::Int64(1),
+                "tags".to_string() => Value::Array(vec![Value::String("a".to_string())]),
+                "unknown".to_string() => Value::Null,
+            },
+        );
+        mi.observe_node(
+            "Sample",
+            &hashmap! {
+                "flag".to_string() => Value::Bool(false),
+                "mixed".to_string() => Value::Bool(true),
+                "number".to_string() => Value::Float64(1.5),
+                "tags".to_string() => Value::Array(vec![]),
+            },
+        );
+
+        let config = mi.configuration();
+        let sample = config.types().next().unwrap();
+        let types: HashMap<&str, (&str, bool, bool)> = sample
+            .props()
+            .map(|p| (p.name(), (p.type_name(), p.required(), p.list())))
+            .collect();
+
+        assert_eq!(types["flag"], ("Boolean", true, false));
+        assert_eq!(types["mixed"], ("String", true, false));
+        assert_eq!(types["number"], ("Float", true, false));
+        assert_eq!(types["tags"], ("String", true, true));
+        assert_eq!(types["unknown"], ("String", false, false));
+    }
+
+    /// Passes if relationships collect all destination labels and detect multiple destinations
+    #[test]
+    fn infer_rels() {
+        let mut mi = ModelInference::new(vec!["id"]);
+        mi.observe_node("Project", &HashMap::new());
+        mi.observe_rels(
+            "Project",
+            &[(
+                "owner".to_string(),
+                "User".to_string(),
+                hashmap! {"since".to_string() => Value::Int64(2020)},
+            )],
+        );
+        mi.observe_node("Project", &HashMap::new());
+        mi.observe_rels(
+            "Project",
+            &[
+                ("issues".to_string(), "Bug".to_string(), HashMap::new()),
+                ("issues".to_string(), "Feature".to_string(), HashMap::new()),
+                ("owner".to_string(), "User".to_string(), HashMap::new()),
+            ],
+        );
+
+        let config = mi.configuration();
+        let project = config.types().next().unwrap();
+        let rels: Vec<_> = project.rels().collect();
+        assert_eq!(rels.len(), 2);
+
+        assert_eq!(rels[0].name(), "issues");
+        assert!(rels[0].list());
+        assert_eq!(rels[0].nodes().collect::<Vec<_>>(), vec!["Bug", "Feature"]);
+
+        assert_eq!(rels[1].name(), "owner");
+        assert!(!rels[1].list());
+        assert_eq!(rels[1].nodes().collect::<Vec<_>>(), vec!["User"]);
+        let since = &rels[1].props_as_slice()[0];
+        assert_eq!(since.name(), "since");
+        assert!(!since.required());
+    }
+
+    /// Passes if an inferred configuration passes Warpgrapher's configuration validation
+    #[test]
+    fn inferred_config_validates() {
+        let mut mi = ModelInference::new(vec!["id"]);
+        mi.observe_node(
+            "User",
+            &hashmap! {"name".to_string() => Value::String("Alice".to_string())},
+        );
+        mi.observe_rels(
+            "User",
+            &[("friends".to_string(), "User".to_string(), HashMap::new())],
+        );
+
+        assert!(mi.configuration().validate().is_ok());
+    }
+}
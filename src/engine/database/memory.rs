@@ -0,0 +1,486 @@
+//! Provides an in-memory graph database back-end, for tests and embedded use.
+
+use crate::engine::database::inference::ModelInference;
+use crate::engine::database::plan::{
+    self, merge_props, GraphSession, GraphStore, NodeFilter, PlanTransaction, RelFilter,
+    StoredNode, StoredRel,
+};
+use crate::engine::database::{DatabaseEndpoint, DatabasePool};
+use crate::engine::value::Value;
+use crate::Error;
+use async_trait::async_trait;
+use log::{debug, trace};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use uuid::Uuid;
+
+/// A memory endpoint builds a database pool holding a new, empty graph in process memory. No
+/// database server is needed, which makes it suitable for tests, demos, and embedded use. The
+/// graph is lost when the last clone of the pool is dropped.
+///
+/// # Examples
+///
+/// ```rust
+/// # use warpgrapher::engine::database::memory::MemoryEndpoint;
+///
+/// let me = MemoryEndpoint::new();
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct MemoryEndpoint {}
+
+impl MemoryEndpoint {
+    /// Returns a new [`MemoryEndpoint`].
+    ///
+    /// [`MemoryEndpoint`]: ./struct.MemoryEndpoint.html
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use warpgrapher::engine::database::memory::MemoryEndpoint;
+    ///
+    /// let me = MemoryEndpoint::new();
+    /// ```
+    pub fn new() -> MemoryEndpoint {
+        MemoryEndpoint {}
+    }
+}
+
+#[async_trait]
+impl DatabaseEndpoint for MemoryEndpoint {
+    async fn pool(&self) -> Result<DatabasePool, Error> {
+        let pool = DatabasePool::Memory(MemoryGraph::new());
+
+        trace!("MemoryEndpoint::pool -- pool: {:#?}", pool);
+        Ok(pool)
+    }
+}
+
+/// A graph of nodes and relationships held in process memory. Clones of a `MemoryGraph` share
+/// the same underlying graph.
+///
+/// Transactions that write to the graph are serialized. Each holds the graph exclusively from
+/// `begin` until it is committed or rolled back, and a rollback restores the graph as it was when
+/// the transaction began.
+#[derive(Clone, Debug, Default)]
+pub struct MemoryGraph {
+    store: Arc<Mutex<Graph>>,
+}
+
+impl MemoryGraph {
+    /// Returns a new, empty [`MemoryGraph`].
+    ///
+    /// [`MemoryGraph`]: ./struct.MemoryGraph.html
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use warpgrapher::engine::database::memory::MemoryGraph;
+    /// # use warpgrapher::engine::database::DatabasePool;
+    ///
+    /// let pool = DatabasePool::Memory(MemoryGraph::new());
+    /// ```
+    pub fn new() -> MemoryGraph {
+        MemoryGraph::default()
+    }
+
+    // A panic while the graph is locked cannot leave it partially modified by a transaction, as
+    // transactions restore their snapshot when dropped, so a poisoned lock is safe to reuse.
+    fn lock(&self) -> MutexGuard<'_, Graph> {
+        self.store.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+struct Graph {
+    next_seq: u64,
+    nodes: HashMap<String, (u64, StoredNode)>,
+    rels: Vec<StoredRel>,
+}
+
+impl GraphStore for Graph {
+    fn labels(&self) -> Result<Vec<String>, Error> {
+        let mut labels = Vec::new();
+        for (_, n) in self.nodes.values() {
+            if !labels.contains(&n.label) {
+                labels.push(n.label.to_string());
+            }
+        }
+        Ok(labels)
+    }
+
+    fn node(&self, id: &str) -> Result<Option<StoredNode>, Error> {
+        Ok(self.nodes.get(id).map(|(_, n)| n.clone()))
+    }
+
+    fn nodes(&self, filter: &NodeFilter) -> Result<Vec<StoredNode>, Error> {
+        let mut nodes: Vec<&(u64, StoredNode)> = self
+            .nodes
+            .values()
+            .filter(|(_, n)| filter.matches(n))
+            .collect();
+        nodes.sort_by_key(|(seq, _)| *seq);
+        Ok(nodes
+            .into_iter()
+            .take(filter.limit.unwrap_or(usize::MAX))
+            .map(|(_, n)| n.clone())
+            .collect())
+    }
+
+    fn rel(&self, id: &str) -> Result<Option<StoredRel>, Error> {
+        Ok(self.rels.iter().find(|r| r.id == id).cloned())
+    }
+
+    fn rels(&self, filter: &RelFilter) -> Result<Vec<StoredRel>, Error> {
+        Ok(self
+            .rels
+            .iter()
+            .filter(|r| {
+                filter.matches(r)
+                    && filter.src_label.map_or(true, |l| {
+                        self.nodes
+                            .get(&r.src_id)
+                            .map_or(false, |(_, n)| n.label == l)
+                    })
+            })
+            .cloned()
+            .collect())
+    }
+
+    fn add_node(
+        &mut self,
+        id: &str,
+        label: &str,
+        props: HashMap<String, Value>,
+    ) -> Result<(), Error> {
+        self.next_seq += 1;
+        self.nodes.insert(
+            id.to_string(),
+            (
+                self.next_seq,
+                StoredNode {
+                    id: id.to_string(),
+                    label: label.to_string(),
+                    props: HashMap::new(),
+                },
+            ),
+        );
+        self.set_node_props(id, props)
+    }
+
+    fn add_rel(
+        &mut self,
+        rel_type: &str,
+        src_id: &str,
+        dst_id: &str,
+        props: HashMap<String, Value>,
+    ) -> Result<String, Error> {
+        let id = Uuid::new_v4().to_string();
+        self.rels.push(StoredRel {
+            id: id.clone(),
+            rel_type: rel_type.to_string(),
+            src_id: src_id.to_string(),
+            dst_id: dst_id.to_string(),
+            props: HashMap::new(),
+        });
+        self.set_rel_props(&id, props)?;
+        Ok(id)
+    }
+
+    fn set_node_props(&mut self, id: &str, props: HashMap<String, Value>) -> Result<(), Error> {
+        if let Some((_, n)) = self.nodes.get_mut(id) {
+            merge_props(&mut n.props, props);
+        }
+        Ok(())
+    }
+
+    fn set_rel_props(&mut self, id: &str, props: HashMap<String, Value>) -> Result<(), Error> {
+        if let Some(r) = self.rels.iter_mut().find(|r| r.id == id) {
+            merge_props(&mut r.props, props);
+        }
+        Ok(())
+    }
+
+    fn remove_node(&mut self, id: &str) -> Result<(), Error> {
+        self.nodes.remove(id);
+        self.rels.retain(|r| r.src_id != id && r.dst_id != id);
+        Ok(())
+    }
+
+    fn remove_rel(&mut self, id: &str) -> Result<(), Error> {
+        self.rels.retain(|r| r.id != id);
+        Ok(())
+    }
+}
+
+/// Samples up to `sample_size` nodes with each label in a memory graph, along with their
+/// outgoing relationships, and records their properties and relationships for inference of a
+/// [`Configuration`].
+///
+/// [`Configuration`]: ../../config/struct.Configuration.html
+pub(crate) fn infer_model(
+    graph: &MemoryGraph,
+    sample_size: usize,
+) -> Result<ModelInference, Error> {
+    trace!("memory::infer_model called -- sample_size: {}", sample_size);
+
+    plan::infer_model(&*graph.lock(), sample_size)
+}
+
+pub(crate) type MemoryTransaction<'t> = PlanTransaction<MemorySession<'t>>;
+
+/// A session on a memory graph. Outside of a transaction, each query locks the graph while it
+/// runs. A transaction holds the graph exclusively from `begin` until it is committed or rolled
+/// back, and restores a snapshot of the graph if it is rolled back.
+pub(crate) struct MemorySession<'t> {
+    graph: &'t MemoryGraph,
+    guard: Option<MutexGuard<'t, Graph>>,
+    snapshot: Option<Graph>,
+}
+
+impl<'t> MemorySession<'t> {
+    pub(crate) fn new(graph: &'t MemoryGraph) -> MemorySession<'t> {
+        MemorySession {
+            graph,
+            guard: None,
+            snapshot: None,
+        }
+    }
+}
+
+impl Drop for MemorySession<'_> {
+    /// Rolls back a transaction that is neither committed nor rolled back, for example because
+    /// an error was returned part way through a mutation
+    fn drop(&mut self) {
+        if let (Some(g), Some(s)) = (&mut self.guard, self.snapshot.take()) {
+            **g = s;
+        }
+    }
+}
+
+impl GraphSession for MemorySession<'_> {
+    fn begin(&mut self) -> Result<(), Error> {
+        debug!("MemorySession::begin called");
+
+        let graph = self.graph;
+        let guard = graph.lock();
+        self.snapshot = Some((*guard).clone());
+        self.guard = Some(guard);
+        Ok(())
+    }
+
+    fn commit(&mut self) -> Result<(), Error> {
+        debug!("MemorySession::commit called");
+
+        self.snapshot = None;
+        self.guard = None;
+        Ok(())
+    }
+
+    fn rollback(&mut self) -> Result<(), Error> {
+        debug!("MemorySession::rollback called");
+
+        if let (Some(g), Some(s)) = (&mut self.guard, self.snapshot.take()) {
+            **g = s;
+        }
+        self.guard = None;
+        Ok(())
+    }
+
+    fn with_store<T, F>(&mut self, f: F) -> Result<T, Error>
+    where
+        F: FnOnce(&mut dyn GraphStore) -> Result<T, Error>,
+    {
+        match &mut self.guard {
+            Some(g) => f(&mut **g),
+            None => f(&mut *self.graph.lock()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{MemoryEndpoint, MemoryGraph, MemorySession, MemoryTransaction};
+    use crate::engine::config::Configuration;
+    use crate::engine::database::plan::GraphStore;
+    use crate::engine::database::{
+        ClauseType, DatabasePool, DbNames, NodeQueryVar, SuffixGenerator, Transaction,
+    };
+    use crate::engine::schema::Info;
+    use crate::engine::value::Value;
+    use crate::Engine;
+    use juniper::http::GraphQLRequest;
+    use maplit::hashmap;
+    use serde_json::json;
+    use std::collections::HashMap;
+    use std::convert::TryFrom;
+
+    fn mock_config() -> Configuration {
+        Configuration::try_from(
+            "
+version: 1
+model:
+  - name: User
+    dbLabel: legacy_user
+    props:
+      - name: name
+        type: String
+        dbName: user_name
+      - name: role
+        type: String
+    rels:
+      - name: friends
+        nodes: [User]
+        list: true
+        dbType: FRIEND_OF
+",
+        )
+        .unwrap()
+    }
+
+    fn create_user(txn: &mut MemoryTransaction, info: &Info, name: &str) {
+        let (query, params) = txn
+            .node_create_query(
+                Vec::new(),
+                HashMap::new(),
+                &NodeQueryVar::new(Some("User".to_string()), "node".to_string(), String::new()),
+                hashmap! {"name".to_string() => Value::String(name.to_string())},
+                ClauseType::Query,
+                &mut SuffixGenerator::new(),
+            )
+            .unwrap();
+        txn.create_node(query, params, None, info).unwrap();
+    }
+
+    fn execute(engine: &Engine<()>, query: &str, input: serde_json::Value) -> serde_json::Value {
+        let req: GraphQLRequest =
+            serde_json::from_value(json!({ "query": query, "variables": { "input": input } }))
+                .unwrap();
+        engine.execute(&req, &HashMap::new()).unwrap()
+    }
+
+    #[test]
+    fn test_memory_endpoint_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<MemoryEndpoint>();
+    }
+
+    #[test]
+    fn test_memory_endpoint_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<MemoryEndpoint>();
+    }
+
+    /// Passes if nested creates, filtered reads, and deletes through the engine are stored under
+    /// the database names of the types, properties, and relationships
+    #[test]
+    fn engine_crud() {
+        let graph = MemoryGraph::new();
+        let engine = Engine::<()>::new(mock_config(), DatabasePool::Memory(graph.clone()))
+            .build()
+            .unwrap();
+
+        let created = execute(
+            &engine,
+            "mutation Create($input: UserCreateMutationInput!) {
+                UserCreate(input: $input) { id name friends { dst { ... on User { name } } } }
+            }",
+            json!({
+                "name": "alice",
+                "role": "admin",
+                "friends": [{"dst": {"User": {"$NEW": {"name": "bob", "role": "user"}}}}]
+            }),
+        );
+        assert_eq!(created["data"]["UserCreate"]["name"], "alice");
+        assert_eq!(
+            created["data"]["UserCreate"]["friends"][0]["dst"]["name"],
+            "bob"
+        );
+
+        {
+            let g = graph.lock();
+            assert_eq!(g.nodes.len(), 2);
+            assert!(g.nodes.values().all(|(_, n)| n.label == "legacy_user"));
+            assert!(g
+                .nodes
+                .values()
+                .all(|(_, n)| n.props.contains_key("user_name")));
+            assert_eq!(g.rels.len(), 1);
+            assert_eq!(g.rels[0].rel_type, "FRIEND_OF");
+        }
+
+        let read = execute(
+            &engine,
+            "query Read($input: UserQueryInput) { User(input: $input) { name } }",
+            json!({"friends": {"dst": {"User": {"role": "user"}}}}),
+        );
+        assert_eq!(read["data"]["User"].as_array().unwrap().len(), 1);
+        assert_eq!(read["data"]["User"][0]["name"], "alice");
+
+        let deleted = execute(
+            &engine,
+            "mutation Delete($input: UserDeleteInput!) { UserDelete(input: $input) }",
+            json!({"$MATCH": {"name": "bob"}}),
+        );
+        assert_eq!(deleted["data"]["UserDelete"], 1);
+
+        let g = graph.lock();
+        assert_eq!(g.nodes.len(), 1);
+        assert!(g.rels.is_empty());
+    }
+
+    /// Passes if writes are kept on commit, and discarded on rollback or when a transaction is
+    /// dropped while still open
+    #[test]
+    fn transaction_rollback() {
+        let graph = MemoryGraph::new();
+        let info = Info::from(&mock_config());
+
+        let mut txn = MemoryTransaction::new(MemorySession::new(&graph), DbNames::from(&info));
+        txn.begin().unwrap();
+        create_user(&mut txn, &info, "alice");
+        txn.commit().unwrap();
+        assert_eq!(graph.lock().nodes.len(), 1);
+
+        txn.begin().unwrap();
+        create_user(&mut txn, &info, "bob");
+        txn.rollback().unwrap();
+        assert_eq!(graph.lock().nodes.len(), 1);
+
+        {
+            let mut txn = MemoryTransaction::new(MemorySession::new(&graph), DbNames::from(&info));
+            txn.begin().unwrap();
+            create_user(&mut txn, &info, "carol");
+        }
+        assert_eq!(graph.lock().nodes.len(), 1);
+    }
+
+    /// Passes if a configuration is inferred from the nodes and relationships in the graph
+    #[tokio::test]
+    async fn infer_configuration() {
+        let graph = MemoryGraph::new();
+        {
+            let mut g = graph.lock();
+            g.add_node(
+                "alice",
+                "Person",
+                hashmap! {"name".to_string() => Value::String("alice".to_string())},
+            )
+            .unwrap();
+            g.add_node(
+                "bob",
+                "Person",
+                hashmap! {"name".to_string() => Value::String("bob".to_string())},
+            )
+            .unwrap();
+            g.add_rel("KNOWS", "alice", "bob", HashMap::new()).unwrap();
+        }
+
+        let config = DatabasePool::Memory(graph)
+            .infer_configuration(10)
+            .await
+            .unwrap();
+        let person = config.types().find(|t| t.name() == "Person").unwrap();
+        assert!(person.props().any(|p| p.name() == "name"));
+        assert!(person.rels().any(|r| r.name() == "KNOWS"));
+    }
+}
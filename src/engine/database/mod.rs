@@ -2,32 +2,97 @@
 
 #[cfg(any(feature = "cosmos", feature = "gremlin"))]
 pub mod gremlin;
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+mod inference;
+#[cfg(feature = "in-memory")]
+pub mod memory;
 #[cfg(feature = "neo4j")]
 pub mod neo4j;
+#[cfg(any(feature = "in-memory", feature = "postgres", feature = "sqlite"))]
+mod plan;
+#[cfg(feature = "postgres")]
+pub mod postgres;
+pub mod retry;
+#[cfg(feature = "sqlite")]
+pub mod sqlite;
 
-use crate::engine::context::RequestContext;
-use crate::engine::objects::{Node, Rel};
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+use crate::engine::config::Configuration;
 use crate::engine::schema::Info;
+use crate::engine::schema::{NodeType, PropertyKind, TypeKind};
 use crate::engine::value::Value;
 use crate::error::Error;
 use async_trait::async_trait;
-#[cfg(feature = "neo4j")]
-use bb8::Pool;
-#[cfg(feature = "neo4j")]
-use bb8_bolt::BoltConnectionManager;
+#[cfg(any(feature = "cosmos", feature = "gremlin"))]
+use gremlin::GremlinTransaction;
 #[cfg(any(feature = "cosmos", feature = "gremlin"))]
 use gremlin_client::GremlinClient;
+#[cfg(feature = "in-memory")]
+use memory::{MemoryGraph, MemorySession, MemoryTransaction};
+#[cfg(feature = "neo4j")]
+use neo4j::{Neo4jPool, Neo4jTransaction};
+#[cfg(feature = "postgres")]
+use postgres::{PostgresGraph, PostgresSession, PostgresTransaction};
+#[cfg(feature = "sqlite")]
+use r2d2::Pool as SqlitePool;
+#[cfg(feature = "sqlite")]
+use r2d2_sqlite::SqliteConnectionManager;
+#[cfg(feature = "sqlite")]
+use sqlite::{SqliteSession, SqliteTransaction};
 use std::collections::HashMap;
-#[cfg(any(feature = "cosmos", feature = "gremlin", feature = "neo4j"))]
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
 use std::env::var_os;
 use std::fmt::Debug;
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+use std::str::FromStr;
+use std::sync::Arc;
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+use std::time::Duration;
 
 #[cfg(feature = "gremlin")]
 fn env_bool(var_name: &str) -> Result<bool, Error> {
     Ok(env_string(var_name)?.parse::<bool>()?)
 }
 
-#[cfg(any(feature = "cosmos", feature = "gremlin", feature = "neo4j"))]
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
 fn env_string(var_name: &str) -> Result<String, Error> {
     var_os(var_name)
         .map(|osstr| osstr.to_string_lossy().into_owned())
@@ -36,18 +101,257 @@ fn env_string(var_name: &str) -> Result<String, Error> {
         })
 }
 
-#[cfg(any(feature = "cosmos", feature = "gremlin", feature = "neo4j"))]
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "neo4j",
+    feature = "postgres"
+))]
 fn env_u16(var_name: &str) -> Result<u16, Error> {
     Ok(env_string(var_name)?.parse::<u16>()?)
 }
 
-/// Contains a pool of database connections, or an enumeration variant indicating that there is no
-/// back-end database
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+fn env_opt<T>(var_name: &str) -> Result<Option<T>, Error>
+where
+    T: FromStr,
+    Error: From<T::Err>,
+{
+    var_os(var_name)
+        .map(|osstr| Ok(osstr.to_string_lossy().parse::<T>()?))
+        .transpose()
+}
+
+/// Options for the pool of connections built by a [`DatabaseEndpoint`]. Options that are not set
+/// keep the defaults of the back-end's connection pool. Cosmos DB and Gremlin-based DB pools only
+/// support the maximum size and the health check.
+///
+/// [`DatabaseEndpoint`]: ./trait.DatabaseEndpoint.html
+///
+/// # Examples
+///
+/// ```rust
+/// # use std::time::Duration;
+/// # use warpgrapher::engine::database::PoolOptions;
+///
+/// let po = PoolOptions::new()
+///     .with_max_size(16)
+///     .with_connection_timeout(Duration::from_secs(5))
+///     .with_query_timeout(Duration::from_secs(30))
+///     .with_health_check(true);
+/// ```
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct PoolOptions {
+    max_size: Option<u32>,
+    min_idle: Option<u32>,
+    connection_timeout: Option<Duration>,
+    idle_timeout: Option<Duration>,
+    max_lifetime: Option<Duration>,
+    query_timeout: Option<Duration>,
+    health_check: bool,
+}
+
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+impl PoolOptions {
+    /// Returns a new [`PoolOptions`], with no options set and no health check
+    ///
+    /// [`PoolOptions`]: ./struct.PoolOptions.html
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use warpgrapher::engine::database::PoolOptions;
+    ///
+    /// let po = PoolOptions::new();
+    /// ```
+    pub fn new() -> PoolOptions {
+        PoolOptions::default()
+    }
+
+    /// Reads environment variables to construct a [`PoolOptions`]. Each environment variable is
+    /// optional, and durations are given in whole seconds. The environment variables are
+    ///
+    /// * WG_POOL_MAX_SIZE - the maximum number of connections in the pool. For example, `16`.
+    /// * WG_POOL_MIN_IDLE - the number of idle connections that the pool tries to keep open.
+    /// * WG_POOL_CONNECTION_TIMEOUT - how long to wait for a connection from the pool.
+    /// * WG_POOL_IDLE_TIMEOUT - how long an idle connection is kept open.
+    /// * WG_POOL_MAX_LIFETIME - how long a connection is kept open before it is replaced.
+    /// * WG_POOL_QUERY_TIMEOUT - how long a query may run before it is cancelled.
+    /// * WG_POOL_HEALTH_CHECK - true if the database should be checked when the pool is built.
+    ///
+    /// The endpoints' `from_env` functions read these same variables.
+    ///
+    /// [`PoolOptions`]: ./struct.PoolOptions.html
+    ///
+    /// # Errors
+    ///
+    /// * [`EnvironmentVariableIntNotParsed`] - if a number cannot be parsed from a variable
+    /// * [`EnvironmentVariableBoolNotParsed`] - if WG_POOL_HEALTH_CHECK is not `true` or `false`
+    ///
+    /// [`EnvironmentVariableBoolNotParsed`]: ../../enum.Error.html#variant.EnvironmentVariableBoolNotParsed
+    /// [`EnvironmentVariableIntNotParsed`]: ../../enum.Error.html#variant.EnvironmentVariableIntNotParsed
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use warpgrapher::engine::database::PoolOptions;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let po = PoolOptions::from_env()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_env() -> Result<PoolOptions, Error> {
+        let secs = |var_name| -> Result<Option<Duration>, Error> {
+            Ok(env_opt::<u64>(var_name)?.map(Duration::from_secs))
+        };
+
+        Ok(PoolOptions {
+            max_size: env_opt("WG_POOL_MAX_SIZE")?,
+            min_idle: env_opt("WG_POOL_MIN_IDLE")?,
+            connection_timeout: secs("WG_POOL_CONNECTION_TIMEOUT")?,
+            idle_timeout: secs("WG_POOL_IDLE_TIMEOUT")?,
+            max_lifetime: secs("WG_POOL_MAX_LIFETIME")?,
+            query_timeout: secs("WG_POOL_QUERY_TIMEOUT")?,
+            health_check: env_opt("WG_POOL_HEALTH_CHECK")?.unwrap_or(false),
+        })
+    }
+
+    /// Sets the maximum number of connections in the pool, which must be greater than zero
+    pub fn with_max_size(mut self, max_size: u32) -> PoolOptions {
+        self.max_size = Some(max_size);
+        self
+    }
+
+    /// Sets the number of idle connections that the pool tries to keep open, which must be no
+    /// greater than the maximum size. Not supported by Cosmos DB and Gremlin-based DB pools.
+    pub fn with_min_idle(mut self, min_idle: u32) -> PoolOptions {
+        self.min_idle = Some(min_idle);
+        self
+    }
+
+    /// Sets how long to wait for a connection from the pool, for example while all connections
+    /// are in use, before returning an error. Not supported by Cosmos DB and Gremlin-based DB
+    /// pools.
+    pub fn with_connection_timeout(mut self, connection_timeout: Duration) -> PoolOptions {
+        self.connection_timeout = Some(connection_timeout);
+        self
+    }
+
+    /// Sets how long an idle connection is kept open. Not supported by Cosmos DB and
+    /// Gremlin-based DB pools.
+    pub fn with_idle_timeout(mut self, idle_timeout: Duration) -> PoolOptions {
+        self.idle_timeout = Some(idle_timeout);
+        self
+    }
+
+    /// Sets how long a connection is kept open before it is replaced. Not supported by Cosmos DB
+    /// and Gremlin-based DB pools.
+    pub fn with_max_lifetime(mut self, max_lifetime: Duration) -> PoolOptions {
+        self.max_lifetime = Some(max_lifetime);
+        self
+    }
+
+    /// Sets how long a query may run before it is cancelled. For Neo4J, this is the timeout of
+    /// each transaction. For SQLite, it is how long to wait for another writer's transaction to
+    /// finish. Not supported by Cosmos DB and Gremlin-based DB pools.
+    pub fn with_query_timeout(mut self, query_timeout: Duration) -> PoolOptions {
+        self.query_timeout = Some(query_timeout);
+        self
+    }
+
+    /// Sets whether the database is checked, with [`DatabasePool::health`], when the pool is
+    /// built, so that a misconfigured database is reported at startup
+    ///
+    /// [`DatabasePool::health`]: ./enum.DatabasePool.html#method.health
+    pub fn with_health_check(mut self, health_check: bool) -> PoolOptions {
+        self.health_check = health_check;
+        self
+    }
+
+    /// Returns the maximum pool size, or `default` if it is not set
+    fn max_size_or(&self, default: u32) -> Result<u32, Error> {
+        let max_size = self.max_size.unwrap_or(default);
+        if max_size == 0 {
+            return Err(Error::PoolOptionsInvalid {
+                message: "max_size must be greater than zero".to_string(),
+            });
+        }
+        if self.min_idle.map_or(false, |min_idle| min_idle > max_size) {
+            return Err(Error::PoolOptionsInvalid {
+                message: "min_idle must be no greater than max_size".to_string(),
+            });
+        }
+        Ok(max_size)
+    }
+
+    #[cfg(feature = "neo4j")]
+    fn bb8_builder<M: bb8::ManageConnection>(
+        &self,
+        default_max_size: u32,
+    ) -> Result<bb8::Builder<M>, Error> {
+        let mut builder = bb8::Pool::builder()
+            .max_size(self.max_size_or(default_max_size)?)
+            .min_idle(self.min_idle);
+        if let Some(connection_timeout) = self.connection_timeout {
+            builder = builder.connection_timeout(connection_timeout);
+        }
+        if let Some(idle_timeout) = self.idle_timeout {
+            builder = builder.idle_timeout(Some(idle_timeout));
+        }
+        if let Some(max_lifetime) = self.max_lifetime {
+            builder = builder.max_lifetime(Some(max_lifetime));
+        }
+        Ok(builder)
+    }
+
+    #[cfg(any(feature = "postgres", feature = "sqlite"))]
+    fn r2d2_builder<M: r2d2::ManageConnection>(
+        &self,
+        default_max_size: u32,
+    ) -> Result<r2d2::Builder<M>, Error> {
+        let mut builder = r2d2::Pool::builder()
+            .max_size(self.max_size_or(default_max_size)?)
+            .min_idle(self.min_idle);
+        if let Some(connection_timeout) = self.connection_timeout {
+            builder = builder.connection_timeout(connection_timeout);
+        }
+        if let Some(idle_timeout) = self.idle_timeout {
+            builder = builder.idle_timeout(Some(idle_timeout));
+        }
+        if let Some(max_lifetime) = self.max_lifetime {
+            builder = builder.max_lifetime(Some(max_lifetime));
+        }
+        Ok(builder)
+    }
+}
+
+/// Contains a pool of database connections, a custom database back-end, or an enumeration variant
+/// indicating that there is no back-end database
 #[derive(Clone, Debug)]
 pub enum DatabasePool {
     /// Contians a pool of Neo4J database clients
     #[cfg(feature = "neo4j")]
-    Neo4j(Pool<BoltConnectionManager>),
+    Neo4j(Neo4jPool),
 
     /// Contains a pool of Cosmos DB database clients
     #[cfg(feature = "cosmos")]
@@ -59,13 +363,30 @@ pub enum DatabasePool {
     #[cfg(feature = "gremlin")]
     Gremlin((GremlinClient, bool)),
 
+    /// Contains a graph held in process memory, shared by all clones of the pool
+    #[cfg(feature = "in-memory")]
+    Memory(MemoryGraph),
+
+    /// Contains a pool of connections to a PostgreSQL database, and the names of the tables in
+    /// which the graph is stored
+    #[cfg(feature = "postgres")]
+    Postgres(PostgresGraph),
+
+    /// Contains a pool of connections to a local SQLite database file
+    #[cfg(feature = "sqlite")]
+    Sqlite(SqlitePool<SqliteConnectionManager>),
+
+    /// Contains a database back-end provided outside of Warpgrapher, for a graph store that
+    /// Warpgrapher does not support directly
+    Custom(Arc<dyn DatabaseBackend>),
+
     /// Used to serve the schema without a database backend
     NoDatabase,
 }
 
 impl DatabasePool {
     #[cfg(feature = "neo4j")]
-    pub fn neo4j(&self) -> Result<&bb8::Pool<bb8_bolt::BoltConnectionManager>, Error> {
+    pub fn neo4j(&self) -> Result<&Neo4jPool, Error> {
         match self {
             DatabasePool::Neo4j(pool) => Ok(pool),
             _ => Err(Error::DatabaseNotFound {}),
@@ -87,6 +408,228 @@ impl DatabasePool {
             _ => Err(Error::DatabaseNotFound {}),
         }
     }
+
+    #[cfg(feature = "in-memory")]
+    pub fn memory(&self) -> Result<&MemoryGraph, Error> {
+        match self {
+            DatabasePool::Memory(graph) => Ok(graph),
+            _ => Err(Error::DatabaseNotFound {}),
+        }
+    }
+
+    #[cfg(feature = "postgres")]
+    pub fn postgres(&self) -> Result<&PostgresGraph, Error> {
+        match self {
+            DatabasePool::Postgres(pool) => Ok(pool),
+            _ => Err(Error::DatabaseNotFound {}),
+        }
+    }
+
+    #[cfg(feature = "sqlite")]
+    pub fn sqlite(&self) -> Result<&SqlitePool<SqliteConnectionManager>, Error> {
+        match self {
+            DatabasePool::Sqlite(pool) => Ok(pool),
+            _ => Err(Error::DatabaseNotFound {}),
+        }
+    }
+
+    /// Returns the pool with which to execute a request. For Neo4J, the session shares the
+    /// connections of the pool, but chains the transactions of the request with bookmarks of
+    /// its own. Other back-ends share the pool as it is.
+    pub(crate) fn session(&self) -> DatabasePool {
+        match self {
+            #[cfg(feature = "neo4j")]
+            DatabasePool::Neo4j(pool) => DatabasePool::Neo4j(pool.session()),
+            _ => self.clone(),
+        }
+    }
+
+    /// Infers a [`Configuration`] from the data already stored in the database. Up to
+    /// `sample_size` nodes with each label are sampled, along with their outgoing relationships.
+    /// The resulting configuration has a type for each label, with properties whose types,
+    /// `required`, and `list` flags fit every sampled value, and relationships whose `nodes` are
+    /// the labels of all the destination nodes sampled. Properties with values that cannot be
+    /// represented as GraphQL scalars, such as dates, are omitted.
+    ///
+    /// Because the configuration is inferred from a sample, it should be reviewed before use. For
+    /// example, a property that is absent on nodes outside the sample may be wrongly inferred to
+    /// be required.
+    ///
+    /// [`Configuration`]: ../config/struct.Configuration.html
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`] variant [`DatabaseNotFound`] if the pool is a
+    /// [`DatabasePool::Custom`] or a [`DatabasePool::NoDatabase`].
+    ///
+    /// Returns an [`Error`] if the sampling queries fail. The specific [`Error`] variant depends on
+    /// the database back-end.
+    ///
+    /// [`DatabaseNotFound`]: ../../enum.Error.html#variant.DatabaseNotFound
+    /// [`DatabasePool::Custom`]: ./enum.DatabasePool.html#variant.Custom
+    /// [`DatabasePool::NoDatabase`]: ./enum.DatabasePool.html#variant.NoDatabase
+    /// [`Error`]: ../../enum.Error.html
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # #[cfg(feature = "neo4j")]
+    /// # use tokio::runtime::Runtime;
+    /// # use warpgrapher::engine::database::DatabaseEndpoint;
+    /// # #[cfg(feature = "neo4j")]
+    /// # use warpgrapher::engine::database::neo4j::Neo4jEndpoint;
+    ///
+    /// # #[cfg(feature = "neo4j")]
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut runtime = Runtime::new()?;
+    /// let pool = runtime.block_on(Neo4jEndpoint::from_env()?.pool())?;
+    /// let config = runtime.block_on(pool.infer_configuration(100))?;
+    ///
+    /// println!("{}", serde_yaml::to_string(&config)?);
+    /// # Ok(())
+    /// # }
+    /// # #[cfg(not(feature = "neo4j"))]
+    /// # fn main() {}
+    /// ```
+    #[cfg(any(
+        feature = "cosmos",
+        feature = "gremlin",
+        feature = "in-memory",
+        feature = "neo4j",
+        feature = "postgres",
+        feature = "sqlite"
+    ))]
+    pub async fn infer_configuration(&self, sample_size: usize) -> Result<Configuration, Error> {
+        let mi = match self {
+            #[cfg(feature = "neo4j")]
+            DatabasePool::Neo4j(pool) => neo4j::infer_model(pool, sample_size).await?,
+            #[cfg(feature = "cosmos")]
+            DatabasePool::Cosmos(client) => gremlin::infer_model(client, sample_size)?,
+            #[cfg(feature = "gremlin")]
+            DatabasePool::Gremlin((client, _)) => gremlin::infer_model(client, sample_size)?,
+            #[cfg(feature = "in-memory")]
+            DatabasePool::Memory(graph) => memory::infer_model(graph, sample_size)?,
+            #[cfg(feature = "postgres")]
+            DatabasePool::Postgres(graph) => postgres::infer_model(graph, sample_size)?,
+            #[cfg(feature = "sqlite")]
+            DatabasePool::Sqlite(pool) => sqlite::infer_model(pool, sample_size)?,
+            DatabasePool::Custom(_) | DatabasePool::NoDatabase => {
+                return Err(Error::DatabaseNotFound {})
+            }
+        };
+
+        Ok(mi.configuration())
+    }
+
+    /// Checks that the database can be reached, by running a trivial query on a connection from
+    /// the pool. For a Neo4J cluster, every server in the pool is checked. The check is
+    /// intended for readiness probes, and is run when a pool is built if the health check is
+    /// set in the endpoint's [`PoolOptions`]. A [`DatabasePool::Custom`] back-end is checked by
+    /// calling [`DatabaseBackend::health`].
+    ///
+    /// [`DatabaseBackend::health`]: ./trait.DatabaseBackend.html#method.health
+    /// [`DatabasePool::Custom`]: ./enum.DatabasePool.html#variant.Custom
+    /// [`PoolOptions`]: ./struct.PoolOptions.html
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`] variant [`DatabaseNotFound`] if the pool is a
+    /// [`DatabasePool::NoDatabase`].
+    ///
+    /// Returns an [`Error`] if a connection cannot be obtained from the pool, for example because
+    /// all connections are in use until the connection timeout passes, or if the query fails. The
+    /// specific [`Error`] variant depends on the database back-end.
+    ///
+    /// [`DatabaseNotFound`]: ../../enum.Error.html#variant.DatabaseNotFound
+    /// [`DatabasePool::NoDatabase`]: ./enum.DatabasePool.html#variant.NoDatabase
+    /// [`Error`]: ../../enum.Error.html
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # #[cfg(feature = "neo4j")]
+    /// # use tokio::runtime::Runtime;
+    /// # use warpgrapher::engine::database::DatabaseEndpoint;
+    /// # #[cfg(feature = "neo4j")]
+    /// # use warpgrapher::engine::database::neo4j::Neo4jEndpoint;
+    ///
+    /// # #[cfg(feature = "neo4j")]
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut runtime = Runtime::new()?;
+    /// let pool = runtime.block_on(Neo4jEndpoint::from_env()?.pool())?;
+    /// let ready = runtime.block_on(pool.health()).is_ok();
+    /// # Ok(())
+    /// # }
+    /// # #[cfg(not(feature = "neo4j"))]
+    /// # fn main() {}
+    /// ```
+    pub async fn health(&self) -> Result<(), Error> {
+        match self {
+            #[cfg(feature = "neo4j")]
+            DatabasePool::Neo4j(pool) => pool.health().await,
+            #[cfg(feature = "cosmos")]
+            DatabasePool::Cosmos(client) => gremlin::health(client),
+            #[cfg(feature = "gremlin")]
+            DatabasePool::Gremlin((client, _)) => gremlin::health(client),
+            #[cfg(feature = "in-memory")]
+            DatabasePool::Memory(_) => Ok(()),
+            #[cfg(feature = "postgres")]
+            DatabasePool::Postgres(graph) => graph.health().await,
+            #[cfg(feature = "sqlite")]
+            DatabasePool::Sqlite(pool) => {
+                pool.get()?.execute_batch("SELECT 1")?;
+                Ok(())
+            }
+            DatabasePool::Custom(backend) => backend.health(),
+            DatabasePool::NoDatabase => Err(Error::DatabaseNotFound),
+        }
+    }
+}
+
+impl DatabaseBackend for DatabasePool {
+    fn transaction(&self, info: &Info) -> Result<Box<dyn Transaction + '_>, Error> {
+        match self {
+            #[cfg(feature = "neo4j")]
+            DatabasePool::Neo4j(pool) => Ok(Box::new(Neo4jTransaction::new(
+                pool,
+                DbNames::from(info),
+                info.name() == "Mutation",
+            )?)),
+            #[cfg(feature = "cosmos")]
+            DatabasePool::Cosmos(client) => Ok(Box::new(GremlinTransaction::new(
+                client.clone(),
+                DbNames::from(info),
+                true,
+                false,
+                false,
+            ))),
+            #[cfg(feature = "gremlin")]
+            DatabasePool::Gremlin((client, uuid)) => Ok(Box::new(GremlinTransaction::new(
+                client.clone(),
+                DbNames::from(info),
+                false,
+                *uuid,
+                true,
+            ))),
+            #[cfg(feature = "in-memory")]
+            DatabasePool::Memory(graph) => Ok(Box::new(MemoryTransaction::new(
+                MemorySession::new(graph),
+                DbNames::from(info),
+            ))),
+            #[cfg(feature = "postgres")]
+            DatabasePool::Postgres(graph) => Ok(Box::new(PostgresTransaction::new(
+                PostgresSession::new(graph)?,
+                DbNames::from(info),
+            ))),
+            #[cfg(feature = "sqlite")]
+            DatabasePool::Sqlite(pool) => Ok(Box::new(SqliteTransaction::new(
+                SqliteSession::new(pool)?,
+                DbNames::from(info),
+            ))),
+            DatabasePool::Custom(backend) => backend.transaction(info),
+            DatabasePool::NoDatabase => Err(Error::DatabaseNotFound),
+        }
+    }
 }
 
 impl Default for DatabasePool {
@@ -134,10 +677,93 @@ pub trait DatabaseEndpoint {
     async fn pool(&self) -> Result<DatabasePool, Error>;
 }
 
-pub(crate) trait Transaction {
+/// Trait for a database back-end. A back-end begins [`Transaction`]s against a graph store, and
+/// the generated resolvers run every query and mutation through the transactions it returns.
+///
+/// [`DatabasePool`] implements this trait for the back-ends built into Warpgrapher. To use
+/// another graph store, implement this trait and [`Transaction`] for it, and wrap the back-end in
+/// a [`DatabasePool::Custom`] variant.
+///
+/// [`DatabasePool`]: ./enum.DatabasePool.html
+/// [`DatabasePool::Custom`]: ./enum.DatabasePool.html#variant.Custom
+/// [`Transaction`]: ./trait.Transaction.html
+///
+/// # Examples
+///
+/// ```rust
+/// # use std::sync::Arc;
+/// # use warpgrapher::Error;
+/// # use warpgrapher::engine::database::{DatabaseBackend, DatabasePool, Transaction};
+/// # use warpgrapher::engine::schema::Info;
+///
+/// #[derive(Debug)]
+/// struct MyGraphStore {}
+///
+/// impl DatabaseBackend for MyGraphStore {
+///     fn transaction(&self, info: &Info) -> Result<Box<dyn Transaction + '_>, Error> {
+///         // Return a transaction against the graph store here
+///         Err(Error::DatabaseNotFound)
+///     }
+/// }
+///
+/// let pool = DatabasePool::Custom(Arc::new(MyGraphStore {}));
+/// ```
+pub trait DatabaseBackend: Debug + Send + Sync {
+    /// Returns a new [`Transaction`] against the database. The transaction is used to resolve a
+    /// single GraphQL field, described by `info`. [`DbNames::from`] can be used with `info` to
+    /// translate GraphQL names into the names used in the database.
+    ///
+    /// [`DbNames::from`]: ./struct.DbNames.html
+    /// [`Transaction`]: ./trait.Transaction.html
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`] if a transaction cannot be created, for example if no connection to
+    /// the database is available. The specific [`Error`] variant depends on the database
+    /// back-end.
+    ///
+    /// [`Error`]: ../../enum.Error.html
+    fn transaction(&self, info: &Info) -> Result<Box<dyn Transaction + '_>, Error>;
+
+    /// Checks that the graph store can be reached, for [`DatabasePool::health`]. The default
+    /// implementation always succeeds.
+    ///
+    /// [`DatabasePool::health`]: ./enum.DatabasePool.html#method.health
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`] if the graph store cannot be reached
+    ///
+    /// [`Error`]: ../../enum.Error.html
+    fn health(&self) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+/// Trait for a transaction against a database back-end.
+///
+/// Queries are built up from the leaves of a GraphQL input towards its root. The methods ending in
+/// `_fragment` or `_query` return a query, or fragment of a query, as a `String`, along with the
+/// parameters that the query needs. The query strings are opaque to Warpgrapher. They are only
+/// passed back into later calls on the same transaction, either as fragments nested in an
+/// enclosing query, or to one of the methods that execute a query, such as [`read_nodes`]. Query
+/// variables, such as the [`NodeQueryVar`] for a node, name the nodes and relationships matched
+/// by a fragment, so that nested fragments can refer to them.
+///
+/// The names of types, properties, and relationships passed to a transaction are GraphQL names.
+/// [`DbNames`] translates them to and from the names under which they are stored in the
+/// database.
+///
+/// [`DbNames`]: ./struct.DbNames.html
+/// [`NodeQueryVar`]: ./struct.NodeQueryVar.html
+/// [`read_nodes`]: #tymethod.read_nodes
+pub trait Transaction {
+    /// Begins the transaction
     fn begin(&mut self) -> Result<(), Error>;
 
-    fn node_create_query<RequestCtx: RequestContext>(
+    /// Returns a query creating a node labeled with the type of `node_var`, with properties
+    /// `props`, and with the outgoing relationships created by the `rel_create_fragments`
+    fn node_create_query(
         &mut self,
         rel_create_fragments: Vec<String>,
         params: HashMap<String, Value>,
@@ -147,15 +773,18 @@ pub(crate) trait Transaction {
         sg: &mut SuffixGenerator,
     ) -> Result<(String, HashMap<String, Value>), Error>;
 
-    fn create_node<RequestCtx: RequestContext>(
+    /// Executes a node creation query, returning the node created
+    fn create_node(
         &mut self,
         query: String,
         params: HashMap<String, Value>,
         partition_key_opt: Option<&Value>,
         info: &Info,
-    ) -> Result<Node<RequestCtx>, Error>;
+    ) -> Result<NodeRecord, Error>;
 
-    fn rel_create_fragment<RequestCtx: RequestContext>(
+    /// Returns a fragment creating a relationship from the source node of `rel_var` to each of
+    /// the nodes returned by `dst_query`
+    fn rel_create_fragment(
         &mut self,
         dst_query: &str,
         params: HashMap<String, Value>,
@@ -165,7 +794,9 @@ pub(crate) trait Transaction {
         sg: &mut SuffixGenerator,
     ) -> Result<(String, HashMap<String, Value>), Error>;
 
-    fn rel_create_query<RequestCtx: RequestContext>(
+    /// Returns a query running the `rel_create_fragments` for each source node returned by
+    /// `src_query_opt`, or for the source node already bound if there is no source query
+    fn rel_create_query(
         &mut self,
         src_query_opt: Option<String>,
         rel_create_fragments: Vec<String>,
@@ -174,14 +805,17 @@ pub(crate) trait Transaction {
         clause: ClauseType,
     ) -> Result<(String, HashMap<String, Value>), Error>;
 
-    fn create_rels<RequestCtx: RequestContext>(
+    /// Executes a relationship creation query, returning the relationships created
+    fn create_rels(
         &mut self,
         query: String,
         params: HashMap<String, Value>,
         props_type_name: Option<&str>,
         partition_key_opt: Option<&Value>,
-    ) -> Result<Vec<Rel<RequestCtx>>, Error>;
+    ) -> Result<Vec<RelRecord>, Error>;
 
+    /// Returns match and where fragments for nodes with the label of `node_var`, if it has one,
+    /// the properties in `props`, and a match for each of the `rel_query_fragments`
     fn node_read_fragment(
         &mut self,
         rel_query_fragments: Vec<(String, String)>,
@@ -192,6 +826,8 @@ pub(crate) trait Transaction {
         sg: &mut SuffixGenerator,
     ) -> Result<(String, String, HashMap<String, Value>), Error>;
 
+    /// Returns a query returning the nodes matched by a node read fragment. For a query clause,
+    /// no more than `limit` nodes are returned, if it is set.
     fn node_read_query(
         &mut self,
         match_fragment: &str,
@@ -199,16 +835,21 @@ pub(crate) trait Transaction {
         params: HashMap<String, Value>,
         node_var: &NodeQueryVar,
         clause: ClauseType,
+        limit: Option<usize>,
     ) -> Result<(String, HashMap<String, Value>), Error>;
 
-    fn read_nodes<RequestCtx: RequestContext>(
+    /// Executes a node read query, returning the nodes read
+    fn read_nodes(
         &mut self,
         query: String,
         params: Option<HashMap<String, Value>>,
         partition_key_opt: Option<&Value>,
         info: &Info,
-    ) -> Result<Vec<Node<RequestCtx>>, Error>;
+    ) -> Result<Vec<NodeRecord>, Error>;
 
+    /// Returns match and where fragments for relationships of the type of `rel_var`, with the
+    /// properties in `props`, and with source and destination nodes matched by the source and
+    /// destination fragments, if present
     fn rel_read_fragment(
         &mut self,
         src_query_opt: Option<(String, String)>,
@@ -219,6 +860,8 @@ pub(crate) trait Transaction {
         sg: &mut SuffixGenerator,
     ) -> Result<(String, String, HashMap<String, Value>), Error>;
 
+    /// Returns a query returning the relationships matched by a relationship read fragment. For a
+    /// query clause, no more than `limit` relationships are returned, if it is set.
     fn rel_read_query(
         &mut self,
         match_fragment: &str,
@@ -226,18 +869,22 @@ pub(crate) trait Transaction {
         params: HashMap<String, Value>,
         rel_var: &RelQueryVar,
         clause: ClauseType,
+        limit: Option<usize>,
     ) -> Result<(String, HashMap<String, Value>), Error>;
 
-    fn read_rels<RequestCtx: RequestContext>(
+    /// Executes a relationship read query, returning the relationships read
+    fn read_rels(
         &mut self,
         query: String,
         params: Option<HashMap<String, Value>>,
         props_type_name: Option<&str>,
         partition_key_opt: Option<&Value>,
-    ) -> Result<Vec<Rel<RequestCtx>>, Error>;
+    ) -> Result<Vec<RelRecord>, Error>;
 
+    /// Returns a query setting the properties in `props` on each node returned by `match_query`,
+    /// and running the `change_queries` for each of them
     #[allow(clippy::too_many_arguments)]
-    fn node_update_query<RequestCtx: RequestContext>(
+    fn node_update_query(
         &mut self,
         match_query: String,
         change_queries: Vec<String>,
@@ -248,15 +895,18 @@ pub(crate) trait Transaction {
         sg: &mut SuffixGenerator,
     ) -> Result<(String, HashMap<String, Value>), Error>;
 
-    fn update_nodes<RequestCtx: RequestContext>(
+    /// Executes a node update query, returning the nodes updated
+    fn update_nodes(
         &mut self,
         query: String,
         params: HashMap<String, Value>,
         partition_key_opt: Option<&Value>,
         info: &Info,
-    ) -> Result<Vec<Node<RequestCtx>>, Error>;
+    ) -> Result<Vec<NodeRecord>, Error>;
 
-    fn rel_update_query<RequestCtx: RequestContext>(
+    /// Returns a query setting the properties in `props` on each relationship returned by
+    /// `match_query`
+    fn rel_update_query(
         &mut self,
         match_query: String,
         params: HashMap<String, Value>,
@@ -266,14 +916,17 @@ pub(crate) trait Transaction {
         sg: &mut SuffixGenerator,
     ) -> Result<(String, HashMap<String, Value>), Error>;
 
-    fn update_rels<RequestCtx: RequestContext>(
+    /// Executes a relationship update query, returning the relationships updated
+    fn update_rels(
         &mut self,
         query: String,
         params: HashMap<String, Value>,
         props_type_name: Option<&str>,
         partition_key_opt: Option<&Value>,
-    ) -> Result<Vec<Rel<RequestCtx>>, Error>;
+    ) -> Result<Vec<RelRecord>, Error>;
 
+    /// Returns a query deleting each node returned by `match_query`, along with its
+    /// relationships, after running the `rel_delete_fragments` for each of them
     fn node_delete_query(
         &mut self,
         match_query: String,
@@ -284,6 +937,7 @@ pub(crate) trait Transaction {
         sg: &mut SuffixGenerator,
     ) -> Result<(String, HashMap<String, Value>), Error>;
 
+    /// Executes a node deletion query, returning the number of nodes deleted
     fn delete_nodes(
         &mut self,
         query: String,
@@ -291,6 +945,8 @@ pub(crate) trait Transaction {
         partition_key_opt: Option<&Value>,
     ) -> Result<i32, Error>;
 
+    /// Returns a query deleting each relationship returned by `match_query`, after running the
+    /// source and destination node deletion queries, if present, for each of them
     #[allow(clippy::too_many_arguments)]
     fn rel_delete_query(
         &mut self,
@@ -303,6 +959,7 @@ pub(crate) trait Transaction {
         sg: &mut SuffixGenerator,
     ) -> Result<(String, HashMap<String, Value>), Error>;
 
+    /// Executes a relationship deletion query, returning the number of relationships deleted
     fn delete_rels(
         &mut self,
         query: String,
@@ -310,13 +967,156 @@ pub(crate) trait Transaction {
         partition_key_opt: Option<&Value>,
     ) -> Result<i32, Error>;
 
+    /// Commits the transaction
     fn commit(&mut self) -> Result<(), Error>;
 
+    /// Rolls back the transaction
     fn rollback(&mut self) -> Result<(), Error>;
+
+    /// Returns true if the transaction has made writes that [`rollback`] does not undo, because
+    /// the back-end does not support transactions. An operation that fails after such a write is
+    /// not retried, as running it again would repeat the write. The default implementation
+    /// returns false, for back-ends that roll back every write of the transaction.
+    ///
+    /// [`rollback`]: #tymethod.rollback
+    fn irreversible_writes(&self) -> bool {
+        false
+    }
+}
+
+/// A node returned by a [`Transaction`], with the GraphQL type name of the node and its fields
+/// keyed by GraphQL field name
+///
+/// [`Transaction`]: ./trait.Transaction.html
+///
+/// # Examples
+///
+/// ```rust
+/// # use std::collections::HashMap;
+/// # use warpgrapher::engine::database::NodeRecord;
+/// # use warpgrapher::engine::value::Value;
+///
+/// let mut fields = HashMap::new();
+/// fields.insert("id".to_string(), Value::String("1234".to_string()));
+/// let node = NodeRecord::new("User".to_string(), fields);
+/// ```
+#[derive(Clone, Debug)]
+pub struct NodeRecord {
+    type_name: String,
+    fields: HashMap<String, Value>,
+}
+
+impl NodeRecord {
+    /// Returns a new [`NodeRecord`]
+    ///
+    /// [`NodeRecord`]: ./struct.NodeRecord.html
+    pub fn new(type_name: String, fields: HashMap<String, Value>) -> NodeRecord {
+        NodeRecord { type_name, fields }
+    }
+
+    /// Returns the GraphQL type name of the node
+    pub fn type_name(&self) -> &str {
+        &self.type_name
+    }
+
+    /// Returns the fields of the node
+    pub fn fields(&self) -> &HashMap<String, Value> {
+        &self.fields
+    }
+
+    pub(crate) fn into_parts(self) -> (String, HashMap<String, Value>) {
+        (self.type_name, self.fields)
+    }
 }
 
+/// A relationship returned by a [`Transaction`], with the ids and GraphQL type names of the
+/// source and destination nodes
+///
+/// [`Transaction`]: ./trait.Transaction.html
 #[derive(Clone, Debug)]
-pub(crate) struct NodeQueryVar {
+pub struct RelRecord {
+    id: Value,
+    partition_key: Option<Value>,
+    props: Option<NodeRecord>,
+    src: (Value, String),
+    dst: (Value, String),
+}
+
+impl RelRecord {
+    /// Returns a new [`RelRecord`]. The `src` and `dst` tuples contain the id and the GraphQL
+    /// type name of the source and destination nodes. The `props` node record, if present, has
+    /// the relationship properties, with the type name of the relationship's property type.
+    ///
+    /// [`RelRecord`]: ./struct.RelRecord.html
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use warpgrapher::engine::database::RelRecord;
+    /// # use warpgrapher::engine::value::Value;
+    ///
+    /// let rel = RelRecord::new(
+    ///     Value::String("5678".to_string()),
+    ///     None,
+    ///     None,
+    ///     (Value::String("1234".to_string()), "Project".to_string()),
+    ///     (Value::String("4321".to_string()), "User".to_string()),
+    /// );
+    /// ```
+    pub fn new(
+        id: Value,
+        partition_key: Option<Value>,
+        props: Option<NodeRecord>,
+        src: (Value, String),
+        dst: (Value, String),
+    ) -> RelRecord {
+        RelRecord {
+            id,
+            partition_key,
+            props,
+            src,
+            dst,
+        }
+    }
+
+    /// Returns the id of the relationship
+    pub fn id(&self) -> &Value {
+        &self.id
+    }
+
+    /// Returns the properties of the relationship, if any
+    pub fn props(&self) -> Option<&NodeRecord> {
+        self.props.as_ref()
+    }
+
+    /// Returns the id and GraphQL type name of the source node
+    pub fn src(&self) -> (&Value, &str) {
+        (&self.src.0, &self.src.1)
+    }
+
+    /// Returns the id and GraphQL type name of the destination node
+    pub fn dst(&self) -> (&Value, &str) {
+        (&self.dst.0, &self.dst.1)
+    }
+
+    #[allow(clippy::type_complexity)]
+    pub(crate) fn into_parts(
+        self,
+    ) -> (
+        Value,
+        Option<Value>,
+        Option<NodeRecord>,
+        (Value, String),
+        (Value, String),
+    ) {
+        (self.id, self.partition_key, self.props, self.src, self.dst)
+    }
+}
+
+/// A query variable naming the nodes matched by a query fragment. Fragments nested within the
+/// fragment use the name to refer to the same nodes.
+#[derive(Clone, Debug)]
+pub struct NodeQueryVar {
     base: String,
     suffix: String,
     label: Option<String>,
@@ -324,7 +1124,6 @@ pub(crate) struct NodeQueryVar {
 }
 
 impl NodeQueryVar {
-    #[cfg(any(feature = "cosmos", feature = "gremlin", feature = "neo4j"))]
     pub(crate) fn new(label: Option<String>, base: String, suffix: String) -> NodeQueryVar {
         NodeQueryVar {
             base: base.clone(),
@@ -334,29 +1133,39 @@ impl NodeQueryVar {
         }
     }
 
-    #[cfg(any(feature = "cosmos", feature = "gremlin", feature = "neo4j"))]
-    pub(crate) fn base(&self) -> &str {
+    /// Returns the base of the variable name, such as `node`, `src`, or `dst`
+    pub fn base(&self) -> &str {
         &self.base
     }
 
-    #[cfg(any(feature = "cosmos", feature = "gremlin", feature = "neo4j"))]
-    pub(crate) fn label(&self) -> Result<&str, Error> {
+    /// Returns the GraphQL type name of the nodes
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`] variant [`LabelNotFound`] if the variable may match nodes of any
+    /// type, as for the destination of a relationship with several destination node types.
+    ///
+    /// [`Error`]: ../../enum.Error.html
+    /// [`LabelNotFound`]: ../../enum.Error.html#variant.LabelNotFound
+    pub fn label(&self) -> Result<&str, Error> {
         self.label.as_deref().ok_or_else(|| Error::LabelNotFound)
     }
 
-    #[cfg(any(feature = "cosmos", feature = "gremlin", feature = "neo4j"))]
-    pub(crate) fn suffix(&self) -> &str {
+    /// Returns the suffix that makes the variable name unique within a query
+    pub fn suffix(&self) -> &str {
         &self.suffix
     }
 
-    #[cfg(any(feature = "cosmos", feature = "gremlin", feature = "neo4j"))]
-    pub(crate) fn name(&self) -> &str {
+    /// Returns the variable name
+    pub fn name(&self) -> &str {
         &self.name
     }
 }
 
+/// A query variable naming the relationships matched by a query fragment, along with their
+/// source and destination nodes
 #[derive(Clone, Debug)]
-pub(crate) struct RelQueryVar {
+pub struct RelQueryVar {
     label: String,
     suffix: String,
     name: String,
@@ -365,7 +1174,6 @@ pub(crate) struct RelQueryVar {
 }
 
 impl RelQueryVar {
-    #[cfg(any(feature = "cosmos", feature = "gremlin", feature = "neo4j"))]
     pub(crate) fn new(
         label: String,
         suffix: String,
@@ -381,54 +1189,421 @@ impl RelQueryVar {
         }
     }
 
-    #[cfg(any(feature = "cosmos", feature = "gremlin", feature = "neo4j"))]
-    pub(crate) fn label(&self) -> &str {
+    /// Returns the GraphQL name of the relationship
+    pub fn label(&self) -> &str {
         &self.label
     }
 
-    #[cfg(any(feature = "cosmos", feature = "gremlin", feature = "neo4j"))]
-    pub(crate) fn name(&self) -> &str {
+    /// Returns the suffix that makes the variable name unique within a query
+    pub fn suffix(&self) -> &str {
+        &self.suffix
+    }
+
+    /// Returns the variable name
+    pub fn name(&self) -> &str {
         &self.name
     }
 
-    #[cfg(any(feature = "cosmos", feature = "gremlin", feature = "neo4j"))]
-    pub(crate) fn src(&self) -> &NodeQueryVar {
+    /// Returns the query variable for the source nodes of the relationships
+    pub fn src(&self) -> &NodeQueryVar {
         &self.src
     }
 
-    #[cfg(any(feature = "cosmos", feature = "gremlin", feature = "neo4j"))]
-    pub(crate) fn dst(&self) -> &NodeQueryVar {
+    /// Returns the query variable for the destination nodes of the relationships
+    pub fn dst(&self) -> &NodeQueryVar {
         &self.dst
     }
 }
 
+/// The position of a query or fragment within the query being built
 #[derive(Clone, Copy, Debug)]
-pub(crate) enum ClauseType {
-    #[cfg(any(feature = "cosmos", feature = "gremlin", feature = "neo4j"))]
+pub enum ClauseType {
+    /// A fragment used as a parameter of an enclosing query, such as a match on destination nodes
     Parameter,
-    #[cfg(any(feature = "cosmos", feature = "gremlin", feature = "neo4j"))]
+    /// The first subquery of an enclosing query
     FirstSubQuery,
-    #[cfg(any(feature = "cosmos", feature = "gremlin", feature = "neo4j"))]
+    /// A subquery of an enclosing query
     SubQuery,
-    #[cfg(any(feature = "cosmos", feature = "gremlin", feature = "neo4j"))]
+    /// A top-level query
     Query,
 }
 
+/// Generates suffixes that make query variable and parameter names unique within a query
 #[derive(Default)]
-pub(crate) struct SuffixGenerator {
-    #[cfg(any(feature = "cosmos", feature = "gremlin", feature = "neo4j"))]
+pub struct SuffixGenerator {
     seed: i32,
 }
 
 impl SuffixGenerator {
-    #[cfg(any(feature = "cosmos", feature = "gremlin", feature = "neo4j"))]
     pub(crate) fn new() -> SuffixGenerator {
         SuffixGenerator { seed: -1 }
     }
 
-    #[cfg(any(feature = "cosmos", feature = "gremlin", feature = "neo4j"))]
-    pub(crate) fn suffix(&mut self) -> String {
+    /// Returns a new suffix, unique within the query
+    pub fn suffix(&mut self) -> String {
         self.seed += 1;
         "_".to_string() + &self.seed.to_string()
     }
 }
+
+/// Translates between the GraphQL names of types, fields, and relationships, and the labels,
+/// property keys, and relationship types under which they are stored in the database, as set by
+/// the `dbLabel`, `dbName`, and `dbType` configuration items. A `DbNames` is created from the
+/// [`Info`] passed to [`DatabaseBackend::transaction`].
+///
+/// [`DatabaseBackend::transaction`]: ./trait.DatabaseBackend.html#tymethod.transaction
+/// [`Info`]: ../schema/struct.Info.html
+#[derive(Clone, Debug)]
+pub struct DbNames {
+    type_defs: Arc<HashMap<String, NodeType>>,
+}
+
+impl DbNames {
+    /// Returns the database label for nodes of the given GraphQL type
+    pub fn label<'a>(&'a self, type_name: &'a str) -> &'a str {
+        self.type_defs
+            .get(type_name)
+            .map_or(type_name, |nt| nt.db_label())
+    }
+
+    /// Returns the GraphQL type name for nodes stored under the given database label
+    pub fn type_name<'a>(&'a self, label: &'a str) -> &'a str {
+        match self.type_defs.get(label) {
+            Some(nt) if nt.db_label() == label => label,
+            _ => self
+                .type_defs
+                .values()
+                .find(|nt| nt.type_kind() == &TypeKind::Object && nt.db_label() == label)
+                .map_or(label, |nt| nt.type_name()),
+        }
+    }
+
+    /// Returns the database relationship type for a relationship on the given GraphQL type
+    pub fn rel_type<'a>(&'a self, src_type_name: Option<&str>, rel_name: &'a str) -> &'a str {
+        src_type_name
+            .and_then(|tn| self.type_defs.get(tn))
+            .and_then(|nt| nt.property(rel_name).ok())
+            .map_or(rel_name, |p| p.db_name())
+    }
+
+    /// Returns the GraphQL field name for a database property key of the given GraphQL type
+    pub fn field_name<'a>(&'a self, type_name: &str, key: &'a str) -> &'a str {
+        self.type_defs
+            .get(type_name)
+            .and_then(|nt| {
+                nt.props()
+                    .find(|p| p.kind() == &PropertyKind::Scalar && p.db_name() == key)
+            })
+            .map_or(key, |p| p.name())
+    }
+
+    /// Re-keys properties read from the database by their GraphQL field names
+    pub fn fields<V>(&self, type_name: &str, props: HashMap<String, V>) -> HashMap<String, V> {
+        props
+            .into_iter()
+            .map(|(k, v)| (self.field_name(type_name, &k).to_string(), v))
+            .collect()
+    }
+
+    /// Re-keys properties of the given GraphQL type by their database property keys
+    pub fn props(
+        &self,
+        type_name: Option<&str>,
+        props: HashMap<String, Value>,
+    ) -> HashMap<String, Value> {
+        match type_name.and_then(|tn| self.type_defs.get(tn)) {
+            Some(nt) => props
+                .into_iter()
+                .map(|(k, v)| match nt.property(&k) {
+                    Ok(p) => (p.db_name().to_string(), v),
+                    Err(_) => (k, v),
+                })
+                .collect(),
+            None => props,
+        }
+    }
+
+    /// Re-keys properties of a relationship on the given GraphQL type by their database property
+    /// keys
+    pub fn rel_props(
+        &self,
+        src_type_name: Option<&str>,
+        rel_name: &str,
+        props: HashMap<String, Value>,
+    ) -> HashMap<String, Value> {
+        self.props(self.rel_props_type_name(src_type_name, rel_name), props)
+    }
+
+    /// Returns the database property key that holds the partition key of nodes of the given
+    /// GraphQL type, as set by the `partitionKey` configuration item
+    pub fn partition_key<'a>(&'a self, type_name: Option<&str>) -> &'a str {
+        match type_name.and_then(|tn| self.type_defs.get(tn)) {
+            Some(nt) => nt
+                .property(nt.partition_key_property())
+                .map_or(nt.partition_key_property(), |p| p.db_name()),
+            None => "partitionKey",
+        }
+    }
+
+    /// Returns true if queries for nodes of the given GraphQL type may run across all partitions
+    /// when no partition key is given
+    pub fn cross_partition(&self, type_name: Option<&str>) -> bool {
+        type_name
+            .and_then(|tn| self.type_defs.get(tn))
+            .and_then(|nt| nt.partition_key())
+            .map_or(false, |pk| pk.cross_partition())
+    }
+
+    fn rel_props_type_name(&self, src_type_name: Option<&str>, rel_name: &str) -> Option<&str> {
+        let src_type = self.type_defs.get(src_type_name?)?;
+        let rel_type = self
+            .type_defs
+            .get(src_type.property(rel_name).ok()?.type_name())?;
+        Some(rel_type.property("props").ok()?.type_name())
+    }
+}
+
+impl From<&Info> for DbNames {
+    fn from(info: &Info) -> Self {
+        DbNames {
+            type_defs: info.type_defs(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(any(
+        feature = "cosmos",
+        feature = "gremlin",
+        feature = "neo4j",
+        feature = "postgres",
+        feature = "sqlite"
+    ))]
+    use super::{env_opt, PoolOptions};
+    use super::{DatabaseBackend, DatabasePool, DbNames, Transaction};
+    use crate::engine::config::Configuration;
+    use crate::engine::schema::Info;
+    use crate::engine::value::Value;
+    use crate::Error;
+    use maplit::hashmap;
+    use std::convert::TryFrom;
+    use std::sync::Arc;
+    #[cfg(any(
+        feature = "cosmos",
+        feature = "gremlin",
+        feature = "neo4j",
+        feature = "postgres",
+        feature = "sqlite"
+    ))]
+    use std::time::Duration;
+
+    #[derive(Debug)]
+    struct MockBackend {}
+
+    impl DatabaseBackend for MockBackend {
+        fn transaction(&self, _info: &Info) -> Result<Box<dyn Transaction + '_>, Error> {
+            Err(Error::DatabaseNotFound)
+        }
+    }
+
+    fn mock_db_names() -> DbNames {
+        let config = Configuration::try_from(
+            "
+version: 1
+model:
+  - name: User
+    dbLabel: legacy_user
+    props:
+      - name: firstName
+        type: String
+        dbName: first_name
+      - name: email
+        type: String
+    rels:
+      - name: manager
+        nodes: [User]
+        dbType: REPORTS_TO
+        props:
+          - name: startDate
+            type: String
+            dbName: start_date
+  - name: Project
+    partitionKey:
+      property: tenant
+      requestContext: true
+      crossPartition: true
+    props:
+      - name: tenant
+        type: String
+        dbName: tenant_id
+",
+        )
+        .unwrap();
+
+        DbNames::from(&Info::from(&config))
+    }
+
+    /// Passes if GraphQL names are translated to the labels, keys, and relationship types used
+    /// in the database, and unmapped names pass through unchanged
+    #[test]
+    fn db_names_to_db() {
+        let dbn = mock_db_names();
+
+        assert_eq!(dbn.label("User"), "legacy_user");
+        assert_eq!(dbn.label("NotAType"), "NotAType");
+        assert_eq!(dbn.rel_type(Some("User"), "manager"), "REPORTS_TO");
+        assert_eq!(dbn.rel_type(None, "manager"), "manager");
+
+        let props = dbn.props(
+            Some("User"),
+            hashmap! {
+                "id".to_string() => Value::String("1".to_string()),
+                "firstName".to_string() => Value::String("Alice".to_string()),
+                "email".to_string() => Value::String("alice@example.com".to_string()),
+            },
+        );
+        assert!(props.contains_key("id"));
+        assert!(props.contains_key("first_name"));
+        assert!(props.contains_key("email"));
+        assert!(!props.contains_key("firstName"));
+
+        let rel_props = dbn.rel_props(
+            Some("User"),
+            "manager",
+            hashmap! {"startDate".to_string() => Value::String("2020-01-01".to_string())},
+        );
+        assert!(rel_props.contains_key("start_date"));
+    }
+
+    /// Passes if labels and keys read from the database are translated back to GraphQL names
+    #[test]
+    fn db_names_from_db() {
+        let dbn = mock_db_names();
+
+        assert_eq!(dbn.type_name("legacy_user"), "User");
+        assert_eq!(dbn.type_name("Unknown"), "Unknown");
+        assert_eq!(dbn.field_name("User", "first_name"), "firstName");
+        assert_eq!(dbn.field_name("User", "email"), "email");
+
+        let fields = dbn.fields(
+            "UserManagerProps",
+            hashmap! {"start_date".to_string() => Value::String("2020-01-01".to_string())},
+        );
+        assert!(fields.contains_key("startDate"));
+    }
+
+    /// Passes if the partition key property and cross-partition setting are taken from the
+    /// `partitionKey` configuration, defaulting to a `partitionKey` property
+    #[test]
+    fn db_names_partition_key() {
+        let dbn = mock_db_names();
+
+        assert_eq!(dbn.partition_key(Some("Project")), "tenant_id");
+        assert!(dbn.cross_partition(Some("Project")));
+        assert_eq!(dbn.partition_key(Some("User")), "partitionKey");
+        assert!(!dbn.cross_partition(Some("User")));
+        assert_eq!(dbn.partition_key(None), "partitionKey");
+        assert!(!dbn.cross_partition(None));
+    }
+
+    /// Passes if the builder methods set each pool option, and unset options are left to the
+    /// back-end's defaults
+    #[cfg(any(
+        feature = "cosmos",
+        feature = "gremlin",
+        feature = "neo4j",
+        feature = "postgres",
+        feature = "sqlite"
+    ))]
+    #[test]
+    fn pool_options_builders() {
+        let po = PoolOptions::new();
+        assert_eq!(po, PoolOptions::default());
+        assert!(!po.health_check);
+        assert_eq!(po.max_size_or(8).unwrap(), 8);
+
+        let po = PoolOptions::new()
+            .with_max_size(16)
+            .with_min_idle(2)
+            .with_connection_timeout(Duration::from_secs(5))
+            .with_idle_timeout(Duration::from_secs(60))
+            .with_max_lifetime(Duration::from_secs(600))
+            .with_query_timeout(Duration::from_secs(30))
+            .with_health_check(true);
+        assert_eq!(po.max_size, Some(16));
+        assert_eq!(po.min_idle, Some(2));
+        assert_eq!(po.connection_timeout, Some(Duration::from_secs(5)));
+        assert_eq!(po.idle_timeout, Some(Duration::from_secs(60)));
+        assert_eq!(po.max_lifetime, Some(Duration::from_secs(600)));
+        assert_eq!(po.query_timeout, Some(Duration::from_secs(30)));
+        assert!(po.health_check);
+        assert_eq!(po.max_size_or(8).unwrap(), 16);
+    }
+
+    /// Passes if a maximum size of zero, or a minimum idle count above the maximum size, is
+    /// rejected rather than left for the pool to panic on
+    #[cfg(any(
+        feature = "cosmos",
+        feature = "gremlin",
+        feature = "neo4j",
+        feature = "postgres",
+        feature = "sqlite"
+    ))]
+    #[test]
+    fn pool_options_invalid() {
+        assert!(matches!(
+            PoolOptions::new().with_max_size(0).max_size_or(8),
+            Err(Error::PoolOptionsInvalid { .. })
+        ));
+        assert!(matches!(
+            PoolOptions::new().with_min_idle(9).max_size_or(8),
+            Err(Error::PoolOptionsInvalid { .. })
+        ));
+        assert_eq!(
+            PoolOptions::new()
+                .with_max_size(4)
+                .with_min_idle(4)
+                .max_size_or(8)
+                .unwrap(),
+            4
+        );
+    }
+
+    /// Passes if optional environment variables are parsed when set, and are None when unset
+    #[cfg(any(
+        feature = "cosmos",
+        feature = "gremlin",
+        feature = "neo4j",
+        feature = "postgres",
+        feature = "sqlite"
+    ))]
+    #[test]
+    fn env_opt_parse() {
+        std::env::set_var("WG_TEST_ENV_OPT_NUMBER", "42");
+        std::env::set_var("WG_TEST_ENV_OPT_BOOL", "true");
+        std::env::set_var("WG_TEST_ENV_OPT_INVALID", "forty-two");
+
+        assert_eq!(env_opt::<u32>("WG_TEST_ENV_OPT_NUMBER").unwrap(), Some(42));
+        assert_eq!(env_opt::<bool>("WG_TEST_ENV_OPT_BOOL").unwrap(), Some(true));
+        assert_eq!(env_opt::<u32>("WG_TEST_ENV_OPT_UNSET").unwrap(), None);
+        assert!(matches!(
+            env_opt::<u32>("WG_TEST_ENV_OPT_INVALID"),
+            Err(Error::EnvironmentVariableIntNotParsed { .. })
+        ));
+    }
+
+    /// Passes if a pool without a database fails the health check, and a custom back-end is
+    /// checked with its own health method
+    #[tokio::test]
+    async fn health() {
+        assert!(matches!(
+            DatabasePool::NoDatabase.health().await,
+            Err(Error::DatabaseNotFound)
+        ));
+        assert!(DatabasePool::Custom(Arc::new(MockBackend {}))
+            .health()
+            .await
+            .is_ok());
+    }
+}
@@ -1,28 +1,36 @@
 //! Provides database interface types and functions for Neo4J databases.
 
 use crate::engine::context::RequestContext;
+use crate::engine::database::inference::ModelInference;
 use crate::engine::database::{
-    env_string, env_u16, ClauseType, DatabaseEndpoint, DatabasePool, NodeQueryVar, RelQueryVar,
-    SuffixGenerator, Transaction,
+    env_string, env_u16, ClauseType, DatabaseEndpoint, DatabasePool, DbNames, NodeQueryVar,
+    NodeRecord, PoolOptions, RelQueryVar, RelRecord, SuffixGenerator, Transaction,
 };
-use crate::engine::objects::{Node, NodeRef, Rel};
+use crate::engine::objects::Node;
 use crate::engine::schema::Info;
 use crate::engine::schema::NodeType;
 use crate::engine::value::Value;
 use crate::Error;
 use async_trait::async_trait;
-use bb8::Pool;
-use bb8::PooledConnection;
+use bb8::{ManageConnection, Pool, PooledConnection, RunError};
 use bb8_bolt::BoltConnectionManager;
-use bolt_client::{Metadata, Params};
+use bolt_client::{Client, Metadata, Params};
 use bolt_proto::error::ConversionError;
-use bolt_proto::message::{Message, Record};
+use bolt_proto::message::{Message, Record, Success};
 use log::{debug, trace};
 use std::collections::HashMap;
 use std::convert::{TryFrom, TryInto};
+use std::fmt::{Debug, Formatter};
 use std::iter::FromIterator;
+use std::ops::{Deref, DerefMut};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::{Duration, Instant};
 use tokio::runtime::Runtime;
 
+/// The port on which Neo4J listens for Bolt connections, if a URI does not give one
+const DEFAULT_PORT: u16 = 7687;
+
 /// A Neo4J endpoint collects the information necessary to generate a connection string and
 /// build a database connection pool.
 ///
@@ -42,15 +50,22 @@ use tokio::runtime::Runtime;
 /// #    Ok(())
 /// # }
 /// ```
+#[derive(Clone)]
 pub struct Neo4jEndpoint {
     host: String,
     port: u16,
     user: String,
     pass: String,
+    tls: bool,
+    routing: bool,
+    routing_context: HashMap<String, String>,
+    database: Option<String>,
+    pool_options: PoolOptions,
 }
 
 impl Neo4jEndpoint {
-    /// Returns a new [`Neo4jEndpoint`] from the provided values.
+    /// Returns a new [`Neo4jEndpoint`] from the provided values. The endpoint connects directly
+    /// to a single server, without TLS, and uses the server's default database.
     ///
     /// # Examples
     ///
@@ -74,23 +89,133 @@ impl Neo4jEndpoint {
             port,
             user,
             pass,
+            tls: false,
+            routing: false,
+            routing_context: HashMap::new(),
+            database: None,
+            pool_options: PoolOptions::default(),
+        }
+    }
+
+    /// Returns a new [`Neo4jEndpoint`] for a connection URI, such as
+    /// `neo4j+s://xxxxxxxx.databases.neo4j.io`. The scheme of the URI selects TLS and routing:
+    ///
+    /// * `bolt` - a direct connection to a single server
+    /// * `bolt+s` - a direct connection to a single server, secured with TLS
+    /// * `neo4j` - a routed connection to a cluster
+    /// * `neo4j+s` - a routed connection to a cluster, secured with TLS
+    ///
+    /// The port defaults to 7687. For routed connections, the query string of the URI, if any, is
+    /// passed to the cluster as the routing context.
+    ///
+    /// [`Neo4jEndpoint`]: ./struct.Neo4jEndpoint.html
+    ///
+    /// # Errors
+    ///
+    /// * [`Neo4jUriNotParsed`] - if the URI cannot be parsed, or has a scheme other than those
+    /// above
+    ///
+    /// [`Neo4jUriNotParsed`]: ../../enum.Error.html#variant.Neo4jUriNotParsed
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use warpgrapher::engine::database::neo4j::Neo4jEndpoint;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let ne = Neo4jEndpoint::from_uri(
+    ///         "neo4j+s://db.example.com",
+    ///         "neo4j".to_string(),
+    ///         "password".to_string()
+    ///     )?;
+    /// #    Ok(())
+    /// # }
+    /// ```
+    pub fn from_uri(uri: &str, user: String, pass: String) -> Result<Neo4jEndpoint, Error> {
+        let not_parsed = || Error::Neo4jUriNotParsed {
+            uri: uri.to_string(),
+        };
+
+        let (scheme, rest) = split_once(uri, "://").ok_or_else(not_parsed)?;
+        let (tls, routing) = match scheme {
+            "bolt" => (false, false),
+            "bolt+s" => (true, false),
+            "neo4j" => (false, true),
+            "neo4j+s" => (true, true),
+            _ => return Err(not_parsed()),
+        };
+
+        let (rest, query) = split_once(rest, "?").unwrap_or((rest, ""));
+        let authority = rest.trim_end_matches('/');
+        if authority.contains('/') || authority.contains('@') {
+            return Err(not_parsed());
+        }
+
+        let (host, port) = match authority.rfind(':') {
+            Some(i) if !authority[i..].contains(']') => (
+                &authority[..i],
+                authority[i + 1..]
+                    .parse::<u16>()
+                    .map_err(|_| not_parsed())?,
+            ),
+            _ => (authority, DEFAULT_PORT),
+        };
+        let host = host.trim_start_matches('[').trim_end_matches(']');
+        if host.is_empty() {
+            return Err(not_parsed());
         }
+
+        let routing_context = query
+            .split('&')
+            .filter(|kv| !kv.is_empty())
+            .map(|kv| {
+                split_once(kv, "=")
+                    .map(|(k, v)| (k.to_string(), v.to_string()))
+                    .ok_or_else(not_parsed)
+            })
+            .collect::<Result<HashMap<String, String>, Error>>()?;
+
+        Ok(Neo4jEndpoint {
+            host: host.to_string(),
+            port,
+            user,
+            pass,
+            tls,
+            routing,
+            routing_context,
+            database: None,
+            pool_options: PoolOptions::default(),
+        })
     }
 
-    /// Reads an variable to construct a [`Neo4jEndpoint`]. The environment variable is
+    /// Reads environment variables to construct a [`Neo4jEndpoint`]. The environment variables
+    /// are
     ///
-    /// * WG_NEO4J_ADDR - the address for the Neo4J DB. For example, `127.0.0.1`.
+    /// * WG_NEO4J_URI - optional, the connection URI for the Neo4J DB, as taken by [`from_uri`].
+    /// For example, `neo4j+s://db.example.com`. If set, WG_NEO4J_HOST and WG_NEO4J_PORT are not
+    /// used.
+    /// * WG_NEO4J_HOST - the address for the Neo4J DB. For example, `127.0.0.1`.
     /// * WG_NEO4J_PORT - the port number for the Neo4J DB.  For example, `7687`.
     /// * WG_NEO4J_USER - the username for the Neo4J DB. For example, `neo4j`.
     /// * WG_NEO4J_PASS - the password for the Neo4J DB. For example, `my-db-pass`.
+    /// * WG_NEO4J_DATABASE - optional, the name of the database to use. For example, `movies`.
+    /// If not set, the server's default database is used.
     ///
+    /// Pool options are read from the WG_POOL_* variables described in [`PoolOptions::from_env`].
+    ///
+    /// [`from_uri`]: ./struct.Neo4jEndpoint.html#method.from_uri
     /// [`Neo4jEndpoint`]: ./struct.Neo4jEndpoint.html
+    /// [`PoolOptions::from_env`]: ../struct.PoolOptions.html#method.from_env
     ///
     /// # Errors
     ///
     /// * [`EnvironmentVariableNotFound`] - if an environment variable does not exist
+    /// * [`EnvironmentVariableIntNotParsed`] - if a WG_POOL_* variable cannot be parsed
+    /// * [`Neo4jUriNotParsed`] - if WG_NEO4J_URI cannot be parsed
     ///
     /// [`EnvironmentVariableNotFound`]: ../../enum.ErrorKind.html
+    /// [`EnvironmentVariableIntNotParsed`]: ../../enum.Error.html#variant.EnvironmentVariableIntNotParsed
+    /// [`Neo4jUriNotParsed`]: ../../enum.Error.html#variant.Neo4jUriNotParsed
     ///
     /// # Examples
     ///
@@ -103,21 +228,120 @@ impl Neo4jEndpoint {
     /// # }
     /// ```
     pub fn from_env() -> Result<Neo4jEndpoint, Error> {
-        Ok(Neo4jEndpoint {
-            host: env_string("WG_NEO4J_HOST")?,
-            port: env_u16("WG_NEO4J_PORT")?,
-            user: env_string("WG_NEO4J_USER")?,
-            pass: env_string("WG_NEO4J_PASS")?,
+        let user = env_string("WG_NEO4J_USER")?;
+        let pass = env_string("WG_NEO4J_PASS")?;
+
+        let ne = match env_string("WG_NEO4J_URI") {
+            Ok(uri) => Neo4jEndpoint::from_uri(&uri, user, pass)?,
+            Err(_) => Neo4jEndpoint::new(
+                env_string("WG_NEO4J_HOST")?,
+                env_u16("WG_NEO4J_PORT")?,
+                user,
+                pass,
+            ),
+        };
+
+        let ne = ne.with_pool_options(PoolOptions::from_env()?);
+        Ok(match env_string("WG_NEO4J_DATABASE") {
+            Ok(database) => ne.with_database(database),
+            Err(_) => ne,
         })
     }
-}
 
-#[async_trait]
-impl DatabaseEndpoint for Neo4jEndpoint {
-    async fn pool(&self) -> Result<DatabasePool, Error> {
-        let manager = BoltConnectionManager::new(
-            self.host.to_string() + ":" + &self.port.to_string(),
-            None,
+    /// Selects the database in which the graph is stored, for servers that host more than one.
+    /// By default, the server's default database is used.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use warpgrapher::engine::database::neo4j::Neo4jEndpoint;
+    ///
+    /// let ne = Neo4jEndpoint::new(
+    ///     "127.0.0.1".to_string(),
+    ///     7687,
+    ///     "neo4j".to_string(),
+    ///     "password".to_string()
+    /// ).with_database("movies".to_string());
+    /// ```
+    pub fn with_database(mut self, database: String) -> Neo4jEndpoint {
+        self.database = Some(database);
+        self
+    }
+
+    /// Sets whether connections are secured with TLS. The server's certificate must be signed by
+    /// a publicly trusted certificate authority, and match the host name of the server.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use warpgrapher::engine::database::neo4j::Neo4jEndpoint;
+    ///
+    /// let ne = Neo4jEndpoint::new(
+    ///     "db.example.com".to_string(),
+    ///     7687,
+    ///     "neo4j".to_string(),
+    ///     "password".to_string()
+    /// ).with_tls(true);
+    /// ```
+    pub fn with_tls(mut self, tls: bool) -> Neo4jEndpoint {
+        self.tls = tls;
+        self
+    }
+
+    /// Sets whether the endpoint is the address of a cluster member from which a routing table
+    /// is read, rather than a single server. With routing, mutations are sent to the leader of
+    /// the cluster, and queries are spread across its followers and read replicas.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use warpgrapher::engine::database::neo4j::Neo4jEndpoint;
+    ///
+    /// let ne = Neo4jEndpoint::new(
+    ///     "core-1.example.com".to_string(),
+    ///     7687,
+    ///     "neo4j".to_string(),
+    ///     "password".to_string()
+    /// ).with_routing(true);
+    /// ```
+    pub fn with_routing(mut self, routing: bool) -> Neo4jEndpoint {
+        self.routing = routing;
+        self
+    }
+
+    /// Sets the options for the connection pools. With routing, the options apply to the pool
+    /// for each server in the cluster. The query timeout is sent to the server as the timeout of
+    /// each transaction.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::time::Duration;
+    /// # use warpgrapher::engine::database::PoolOptions;
+    /// # use warpgrapher::engine::database::neo4j::Neo4jEndpoint;
+    ///
+    /// let ne = Neo4jEndpoint::new(
+    ///     "127.0.0.1".to_string(),
+    ///     7687,
+    ///     "neo4j".to_string(),
+    ///     "password".to_string()
+    /// ).with_pool_options(PoolOptions::new().with_connection_timeout(Duration::from_secs(5)));
+    /// ```
+    pub fn with_pool_options(mut self, pool_options: PoolOptions) -> Neo4jEndpoint {
+        self.pool_options = pool_options;
+        self
+    }
+
+    fn manager(&self, address: &str) -> Result<BoltConnectionManager, Error> {
+        let domain = if self.tls {
+            Some(host_of(address).to_string())
+        } else {
+            None
+        };
+
+        Ok(BoltConnectionManager::new(
+            address,
+            domain,
             [4, 0, 0, 0],
             HashMap::from_iter(vec![
                 ("user_agent", "warpgrapher/0.2.0"),
@@ -125,32 +349,782 @@ impl DatabaseEndpoint for Neo4jEndpoint {
                 ("principal", &self.user),
                 ("credentials", &self.pass),
             ]),
-        )?;
+        )?)
+    }
 
-        let pool = DatabasePool::Neo4j(
-            Pool::builder()
-                .max_size(num_cpus::get().try_into().unwrap_or(8))
-                .build(manager)
-                .await?,
-        );
+    async fn build_pool(
+        &self,
+        router: &Arc<Router>,
+        role: Role,
+    ) -> Result<Pool<Neo4jConnectionManager>, Error> {
+        Ok(self
+            .pool_options
+            .bb8_builder(num_cpus::get().try_into().unwrap_or(8))?
+            .build(Neo4jConnectionManager {
+                router: router.clone(),
+                role,
+            })
+            .await?)
+    }
+
+    /// Reads the routing table of the cluster from the server at the given address, and returns
+    /// the addresses of the servers that accept writes and reads, in that order, and the time
+    /// for which the table may be used before it must be read again
+    async fn routing_table(
+        &self,
+        seed: &str,
+    ) -> Result<(Vec<String>, Vec<String>, Duration), Error> {
+        let mut client = self.manager(seed)?.connect().await?;
+
+        let mut context = self.routing_context.clone();
+        context.insert("address".to_string(), seed.to_string());
+        let params = Params::from(HashMap::from_iter(vec![
+            ("context", bolt_proto::value::Value::from(context)),
+            (
+                "database",
+                self.database.clone().map_or(
+                    bolt_proto::value::Value::Null,
+                    bolt_proto::value::Value::from,
+                ),
+            ),
+        ]));
+
+        client
+            .run_with_metadata(
+                "CALL dbms.routing.getRoutingTable($context, $database)",
+                Some(params),
+                None,
+            )
+            .await?;
+        let (response, records) = client
+            .pull(Some(Metadata::from_iter(vec![("n", -1)])))
+            .await?;
+        match response {
+            Message::Success(_) => (),
+            message => return Err(Error::Neo4jQueryFailed { message }),
+        }
+
+        let record = records
+            .into_iter()
+            .next()
+            .ok_or_else(|| Error::ResponseSetNotFound)?;
+        let ttl: i64 = record
+            .fields()
+            .first()
+            .cloned()
+            .ok_or_else(|| Error::ResponseItemNotFound {
+                name: "ttl".to_string(),
+            })?
+            .try_into()?;
+        let servers =
+            record
+                .fields()
+                .get(1)
+                .cloned()
+                .ok_or_else(|| Error::ResponseItemNotFound {
+                    name: "servers".to_string(),
+                })?;
+        let (writers, readers) = routing_servers(servers)?;
+        Ok((writers, readers, Duration::from_secs(ttl.max(0) as u64)))
+    }
+}
+
+#[async_trait]
+impl DatabaseEndpoint for Neo4jEndpoint {
+    async fn pool(&self) -> Result<DatabasePool, Error> {
+        let seed = self.host.to_string() + ":" + &self.port.to_string();
+        let router = Arc::new(Router::new(self.clone(), seed));
+
+        let readers = if self.routing {
+            router.refresh().await?;
+            Some(self.build_pool(&router, Role::Read).await?)
+        } else {
+            None
+        };
+
+        let pool = DatabasePool::Neo4j(Neo4jPool {
+            writer: self.build_pool(&router, Role::Write).await?,
+            readers,
+            router,
+            database: self.database.clone(),
+            bookmark: Arc::new(Mutex::new(None)),
+            query_timeout: self.pool_options.query_timeout,
+        });
+        if self.pool_options.health_check {
+            pool.health().await?;
+        }
 
         trace!("Neo4jEndpoint::pool -- pool: {:#?}", pool);
         Ok(pool)
     }
 }
 
+/// Splits a string at the first occurrence of a delimiter
+fn split_once<'a>(s: &'a str, delimiter: &str) -> Option<(&'a str, &'a str)> {
+    s.find(delimiter)
+        .map(|i| (&s[..i], &s[i + delimiter.len()..]))
+}
+
+/// Returns the host name of a `host:port` address, to be checked against the server's
+/// certificate
+fn host_of(address: &str) -> &str {
+    match address.rfind(':') {
+        Some(i) if !address[i..].contains(']') => &address[..i],
+        _ => address,
+    }
+    .trim_start_matches('[')
+    .trim_end_matches(']')
+}
+
+/// Returns the addresses of the servers with the `WRITE` role, and those with the `READ` role,
+/// from the `servers` field of a routing table
+fn routing_servers(servers: bolt_proto::value::Value) -> Result<(Vec<String>, Vec<String>), Error> {
+    let mut writers = Vec::new();
+    let mut readers = Vec::new();
+
+    let servers: Vec<HashMap<String, bolt_proto::value::Value>> = servers.try_into()?;
+    for mut server in servers {
+        let role: String = server
+            .remove("role")
+            .ok_or_else(|| Error::ResponseItemNotFound {
+                name: "role".to_string(),
+            })?
+            .try_into()?;
+        let addresses: Vec<String> = server
+            .remove("addresses")
+            .ok_or_else(|| Error::ResponseItemNotFound {
+                name: "addresses".to_string(),
+            })?
+            .try_into()?;
+
+        match role.as_str() {
+            "WRITE" => writers.extend(addresses),
+            "READ" => readers.extend(addresses),
+            _ => (),
+        }
+    }
+
+    Ok((writers, readers))
+}
+
+/// The role of the servers to which the connections of a pool are made
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Role {
+    Write,
+    Read,
+}
+
+impl Role {
+    fn name(self) -> &'static str {
+        match self {
+            Role::Write => "WRITE",
+            Role::Read => "READ",
+        }
+    }
+}
+
+/// The servers of a cluster that accept writes and reads, from its routing table, and the time
+/// after which the table must be read again
+#[derive(Clone, Debug)]
+struct RoutingTable {
+    writers: Vec<String>,
+    readers: Vec<String>,
+    expires: Instant,
+}
+
+impl RoutingTable {
+    /// Returns the addresses of the servers with a role. Reads go to the leader if the cluster
+    /// has no followers or read replicas.
+    fn addresses(&self, role: Role) -> &[String] {
+        match role {
+            Role::Read if !self.readers.is_empty() => &self.readers,
+            _ => &self.writers,
+        }
+    }
+
+    fn expired(&self, now: Instant) -> bool {
+        now >= self.expires
+    }
+}
+
+/// Tracks the routing table of a cluster, from which the connection managers of a
+/// [`Neo4jPool`] choose the server to connect to. The table is read again once its time to
+/// live has passed, or once a failure shows it to be out of date. Without routing, the table
+/// holds the endpoint's address, and is never read again.
+///
+/// [`Neo4jPool`]: ./struct.Neo4jPool.html
+struct Router {
+    endpoint: Neo4jEndpoint,
+    seed: String,
+    table: RwLock<RoutingTable>,
+    refreshing: AtomicBool,
+    next_reader: AtomicUsize,
+}
+
+impl Router {
+    fn new(endpoint: Neo4jEndpoint, seed: String) -> Router {
+        Router {
+            table: RwLock::new(RoutingTable {
+                writers: vec![seed.clone()],
+                readers: Vec::new(),
+                expires: Instant::now(),
+            }),
+            endpoint,
+            seed,
+            refreshing: AtomicBool::new(false),
+            next_reader: AtomicUsize::new(0),
+        }
+    }
+
+    fn snapshot(&self) -> RoutingTable {
+        self.table
+            .read()
+            .map(|table| table.clone())
+            .unwrap_or_else(|e| e.into_inner().clone())
+    }
+
+    /// Reads the routing table from the endpoint's address, or, if that server cannot be
+    /// reached, from any other member of the cluster in the current table
+    async fn refresh(&self) -> Result<(), Error> {
+        let mut candidates = vec![self.seed.clone()];
+        let table = self.snapshot();
+        for address in table.writers.iter().chain(table.readers.iter()) {
+            if !candidates.contains(address) {
+                candidates.push(address.clone());
+            }
+        }
+
+        let mut error = Error::ResponseSetNotFound;
+        for address in candidates {
+            match self.endpoint.routing_table(&address).await {
+                Ok((writers, readers, ttl)) => {
+                    debug!(
+                        "Router::refresh -- writers: {:?}, readers: {:?}, ttl: {:?}",
+                        writers, readers, ttl
+                    );
+                    let table = RoutingTable {
+                        writers,
+                        readers,
+                        expires: Instant::now() + ttl,
+                    };
+                    match self.table.write() {
+                        Ok(mut t) => *t = table,
+                        Err(e) => *e.into_inner() = table,
+                    }
+                    return Ok(());
+                }
+                Err(e) => error = e,
+            }
+        }
+        Err(error)
+    }
+
+    /// Returns the routing table, reading it again first if it has expired. While another task
+    /// reads the table, the expired table is used in the meantime.
+    async fn table(&self) -> Result<RoutingTable, Error> {
+        let table = self.snapshot();
+        if self.endpoint.routing
+            && table.expired(Instant::now())
+            && !self.refreshing.swap(true, Ordering::SeqCst)
+        {
+            let result = self.refresh().await;
+            self.refreshing.store(false, Ordering::SeqCst);
+            result?;
+            Ok(self.snapshot())
+        } else {
+            Ok(table)
+        }
+    }
+
+    /// Returns the address of the server to which to make a new connection for a role. New
+    /// connections for reads take turns among the followers and read replicas of a cluster.
+    async fn address(&self, role: Role) -> Result<String, Error> {
+        let table = self.table().await?;
+        let addresses = table.addresses(role);
+        if addresses.is_empty() {
+            return Err(Error::ResponseItemNotFound {
+                name: role.name().to_string(),
+            });
+        }
+
+        let i = match role {
+            Role::Write => 0,
+            Role::Read => self.next_reader.fetch_add(1, Ordering::Relaxed) % addresses.len(),
+        };
+        Ok(addresses[i].clone())
+    }
+
+    /// Returns true if a server still has a role in the current routing table
+    fn current(&self, role: Role, address: &str) -> bool {
+        !self.endpoint.routing || self.snapshot().addresses(role).iter().any(|a| a == address)
+    }
+
+    /// Marks the routing table as out of date, so that it is read again before the next
+    /// connection is checked out or made
+    fn invalidate(&self) {
+        if self.endpoint.routing {
+            match self.table.write() {
+                Ok(mut t) => t.expires = Instant::now(),
+                Err(e) => e.into_inner().expires = Instant::now(),
+            }
+        }
+    }
+}
+
+impl Debug for Router {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Router")
+            .field("seed", &self.seed)
+            .field("routing", &self.endpoint.routing)
+            .field("table", &self.snapshot())
+            .finish()
+    }
+}
+
+/// Returns true if an error shows the routing table of a cluster to be out of date, because a
+/// write was sent to a server that is no longer the leader, or a connection was lost
+pub(crate) fn stale_route(e: &Error) -> bool {
+    match e {
+        Error::Neo4jQueryFailed {
+            message: Message::Failure(failure),
+        } => match failure
+            .metadata()
+            .get("code")
+            .cloned()
+            .map(String::try_from)
+        {
+            Some(Ok(code)) => {
+                code == "Neo.ClientError.Cluster.NotALeader"
+                    || code == "Neo.ClientError.General.ForbiddenOnReadOnlyDatabase"
+            }
+            _ => false,
+        },
+        Error::BoltClientFailed {
+            source: bolt_client::error::Error::IOError(_),
+        } => true,
+        _ => false,
+    }
+}
+
+/// Manages the connections of a [`Neo4jPool`] to the servers of a role. New connections are
+/// made to the servers named in the current routing table of a cluster, and connections to
+/// servers that no longer have the role are discarded when they are checked out or returned.
+///
+/// [`Neo4jPool`]: ./struct.Neo4jPool.html
+#[derive(Debug)]
+pub struct Neo4jConnectionManager {
+    router: Arc<Router>,
+    role: Role,
+}
+
+impl Neo4jConnectionManager {
+    fn init_failed(e: Error) -> bb8_bolt::Error {
+        bb8_bolt::Error::ClientInitFailed(e.to_string())
+    }
+}
+
+#[async_trait]
+impl ManageConnection for Neo4jConnectionManager {
+    type Connection = Neo4jConnection;
+    type Error = bb8_bolt::Error;
+
+    async fn connect(&self) -> Result<Neo4jConnection, bb8_bolt::Error> {
+        let address = self
+            .router
+            .address(self.role)
+            .await
+            .map_err(Neo4jConnectionManager::init_failed)?;
+        let manager = self
+            .router
+            .endpoint
+            .manager(&address)
+            .map_err(Neo4jConnectionManager::init_failed)?;
+
+        match manager.connect().await {
+            Ok(client) => Ok(Neo4jConnection {
+                client,
+                address,
+                broken: false,
+            }),
+            Err(e) => {
+                self.router.invalidate();
+                Err(e)
+            }
+        }
+    }
+
+    async fn is_valid(
+        &self,
+        mut conn: Neo4jConnection,
+    ) -> Result<Neo4jConnection, bb8_bolt::Error> {
+        let table = self
+            .router
+            .table()
+            .await
+            .map_err(Neo4jConnectionManager::init_failed)?;
+        if self.router.endpoint.routing && !table.addresses(self.role).contains(&conn.address) {
+            return Err(bb8_bolt::Error::ClientInitFailed(
+                conn.address + " no longer has the role " + self.role.name(),
+            ));
+        }
+
+        let response = conn.client.run("RETURN 1;".to_string(), None).await?;
+        Success::try_from(response)?;
+        let (response, _records) = conn.client.pull_all().await?;
+        Success::try_from(response)?;
+        Ok(conn)
+    }
+
+    fn has_broken(&self, conn: &mut Neo4jConnection) -> bool {
+        conn.broken || !self.router.current(self.role, &conn.address)
+    }
+}
+
+/// A connection to a server of a Neo4J database, from a [`Neo4jPool`]. It dereferences to a
+/// [`bolt_client::Client`].
+///
+/// [`Neo4jPool`]: ./struct.Neo4jPool.html
+/// [`bolt_client::Client`]: https://docs.rs/bolt-client/0.7.0/bolt_client/struct.Client.html
+#[derive(Debug)]
+pub struct Neo4jConnection {
+    client: Client,
+    address: String,
+    broken: bool,
+}
+
+impl Neo4jConnection {
+    /// Returns the `host:port` address of the server to which the connection is made
+    pub fn address(&self) -> &str {
+        &self.address
+    }
+}
+
+impl Deref for Neo4jConnection {
+    type Target = Client;
+
+    fn deref(&self) -> &Client {
+        &self.client
+    }
+}
+
+impl DerefMut for Neo4jConnection {
+    fn deref_mut(&mut self) -> &mut Client {
+        &mut self.client
+    }
+}
+
+/// A pool of connections to a Neo4J database. If the pool was built for a cluster, with routing,
+/// it holds a pool of connections to the leader of the cluster, to which mutations are sent, and
+/// a pool of connections to its followers and read replicas, across which queries are spread.
+/// Otherwise, all queries are sent to the one server.
+///
+/// The routing table of a cluster is read again once its time to live has passed, and after a
+/// write is sent to a server that is no longer the leader, or a connection is lost. Connections
+/// to servers that have left their role are then discarded, rather than reused.
+///
+/// Writes and the reads that follow them in the same session are causally chained with
+/// bookmarks, so that a query sent to a follower sees the results of earlier mutations. Each
+/// GraphQL request executed by the engine is a session of its own; see [`session`].
+///
+/// [`session`]: ./struct.Neo4jPool.html#method.session
+#[derive(Clone, Debug)]
+pub struct Neo4jPool {
+    writer: Pool<Neo4jConnectionManager>,
+    readers: Option<Pool<Neo4jConnectionManager>>,
+    router: Arc<Router>,
+    database: Option<String>,
+    bookmark: Arc<Mutex<Option<String>>>,
+    query_timeout: Option<Duration>,
+}
+
+impl Neo4jPool {
+    /// Returns a connection to the server that accepts writes. Custom resolvers that use the
+    /// connection should pass the name of the database, from [`database`], in the metadata of
+    /// the queries they run.
+    ///
+    /// [`database`]: ./struct.Neo4jPool.html#method.database
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`bb8::RunError`] if a connection cannot be obtained from the pool
+    ///
+    /// [`bb8::RunError`]: https://docs.rs/bb8/0.4.2/bb8/enum.RunError.html
+    pub async fn get(
+        &self,
+    ) -> Result<PooledConnection<'_, Neo4jConnectionManager>, RunError<bb8_bolt::Error>> {
+        self.writer.get().await
+    }
+
+    /// Returns a connection to a server that accepts reads. New connections take turns among
+    /// the followers and read replicas of a cluster. Without routing, the connection is to the
+    /// one server.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`bb8::RunError`] if a connection cannot be obtained from the pool
+    ///
+    /// [`bb8::RunError`]: https://docs.rs/bb8/0.4.2/bb8/enum.RunError.html
+    pub async fn get_reader(
+        &self,
+    ) -> Result<PooledConnection<'_, Neo4jConnectionManager>, RunError<bb8_bolt::Error>> {
+        match &self.readers {
+            Some(readers) => readers.get().await,
+            None => self.writer.get().await,
+        }
+    }
+
+    /// Returns a pool that shares the connections of this pool, but chains its transactions
+    /// with bookmarks of its own, so that its reads wait only for the mutations committed
+    /// through it. The engine executes each GraphQL request with a session of its pool.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use tokio::runtime::Runtime;
+    /// # use warpgrapher::engine::database::DatabaseEndpoint;
+    /// # use warpgrapher::engine::database::neo4j::Neo4jEndpoint;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut runtime = Runtime::new()?;
+    /// let pool = runtime.block_on(Neo4jEndpoint::from_env()?.pool())?;
+    /// let session = pool.neo4j()?.session();
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn session(&self) -> Neo4jPool {
+        Neo4jPool {
+            bookmark: Arc::new(Mutex::new(None)),
+            ..self.clone()
+        }
+    }
+
+    /// Returns the name of the database in which the graph is stored, or [`None`] if the
+    /// server's default database is used
+    pub fn database(&self) -> Option<&str> {
+        self.database.as_deref()
+    }
+
+    /// Runs a trivial query on the server that accepts writes, and on a server that accepts
+    /// reads, returning an error if either of them cannot be reached
+    pub(crate) async fn health(&self) -> Result<(), Error> {
+        for pool in std::iter::once(&self.writer).chain(self.readers.iter()) {
+            let mut client = pool.get().await?;
+            let metadata = Metadata::from(self.transaction_metadata(false));
+            client
+                .run_with_metadata("RETURN 1", None, Some(metadata))
+                .await?;
+            match client.pull(None).await? {
+                (Message::Success(_), _) => (),
+                (message, _) => return Err(Error::Neo4jQueryFailed { message }),
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the metadata for a transaction, naming the database, the access mode, the
+    /// timeout, and the bookmark of the last mutation committed in the session
+    fn transaction_metadata(&self, write: bool) -> HashMap<String, bolt_proto::value::Value> {
+        let mut metadata = HashMap::new();
+        if let Some(database) = &self.database {
+            metadata.insert("db".to_string(), database.clone().into());
+        }
+        if let Some(query_timeout) = self.query_timeout {
+            metadata.insert(
+                "tx_timeout".to_string(),
+                (query_timeout.as_millis() as i64).into(),
+            );
+        }
+        if !write {
+            metadata.insert("mode".to_string(), "r".into());
+        }
+        if let Ok(bookmark) = self.bookmark.lock() {
+            if let Some(b) = bookmark.as_ref() {
+                metadata.insert("bookmarks".to_string(), vec![b.clone()].into());
+            }
+        }
+        metadata
+    }
+}
+
+/// Samples up to `sample_size` nodes with each label in a Neo4J database, along with their
+/// outgoing relationships, and records their properties and relationships for inference of a
+/// [`Configuration`].
+///
+/// [`Configuration`]: ../../config/struct.Configuration.html
+pub(crate) async fn infer_model(
+    pool: &Neo4jPool,
+    sample_size: usize,
+) -> Result<ModelInference, Error> {
+    trace!("neo4j::infer_model called -- sample_size: {}", sample_size);
+
+    let mut client = pool.get_reader().await?;
+    let pull_meta = || Metadata::from_iter(vec![("n", -1)]);
+    let run_meta = || Metadata::from(pool.transaction_metadata(false));
+
+    client
+        .run_with_metadata(
+            "CALL db.labels() YIELD label RETURN label",
+            None,
+            Some(run_meta()),
+        )
+        .await?;
+    let (response, records) = client.pull(Some(pull_meta())).await?;
+    match response {
+        Message::Success(_) => (),
+        message => return Err(Error::Neo4jQueryFailed { message }),
+    }
+    let labels = records
+        .into_iter()
+        .map(|r| r.fields()[0].clone().try_into())
+        .collect::<Result<Vec<String>, bolt_proto::error::Error>>()?;
+
+    let mut mi = ModelInference::new(vec!["id"]);
+    for label in labels {
+        let query = "MATCH (n:`".to_string()
+            + &label.replace('`', "``")
+            + "`)\n"
+            + "WITH n LIMIT $limit\n"
+            + "OPTIONAL MATCH (n)-[r]->(m)\n"
+            + "RETURN n, collect(r) AS rels, collect(labels(m)) AS dst_labels\n";
+        let params = Params::from_iter(vec![("limit", sample_size as i64)]);
+        client
+            .run_with_metadata(query, Some(params), Some(run_meta()))
+            .await?;
+        let (response, records) = client.pull(Some(pull_meta())).await?;
+        match response {
+            Message::Success(_) => (),
+            message => return Err(Error::Neo4jQueryFailed { message }),
+        }
+
+        for record in records {
+            if let bolt_proto::value::Value::Node(n) = &record.fields()[0] {
+                mi.observe_node(&label, &Neo4jTransaction::sample_props(n.properties()));
+            } else {
+                return Err(Error::ResponseItemNotFound {
+                    name: "node".to_string(),
+                });
+            }
+
+            let rels: Vec<bolt_proto::value::Value> = record.fields()[1].clone().try_into()?;
+            let dst_labels: Vec<Vec<String>> = record.fields()[2].clone().try_into()?;
+            let rels = rels
+                .into_iter()
+                .zip(dst_labels)
+                .filter_map(
+                    |(rel, dst_label)| match (rel, dst_label.into_iter().next()) {
+                        (bolt_proto::value::Value::Relationship(r), Some(dl)) => Some((
+                            r.rel_type().to_string(),
+                            dl,
+                            Neo4jTransaction::sample_props(r.properties()),
+                        )),
+                        _ => None,
+                    },
+                )
+                .collect::<Vec<(String, String, HashMap<String, Value>)>>();
+            mi.observe_rels(&label, &rels);
+        }
+    }
+
+    Ok(mi)
+}
+
 #[derive(Debug)]
 pub(crate) struct Neo4jTransaction<'t> {
-    client: PooledConnection<'t, BoltConnectionManager>,
-    runtime: &'t mut Runtime,
+    // Declared ahead of the runtime, so that the connection is returned to the pool before the
+    // runtime on which it was checked out is dropped
+    client: PooledConnection<'t, Neo4jConnectionManager>,
+    db_names: DbNames,
+    metadata: HashMap<String, bolt_proto::value::Value>,
+    bookmark: Arc<Mutex<Option<String>>>,
+    router: Arc<Router>,
+    in_transaction: bool,
+    runtime: Runtime,
 }
 
 impl<'t> Neo4jTransaction<'t> {
+    /// Returns a new transaction on a connection to a server that accepts writes if `write` is
+    /// true, or to one that accepts reads otherwise
     pub fn new(
-        client: PooledConnection<'t, BoltConnectionManager>,
-        runtime: &'t mut Runtime,
-    ) -> Neo4jTransaction<'t> {
-        Neo4jTransaction { client, runtime }
+        pool: &'t Neo4jPool,
+        db_names: DbNames,
+        write: bool,
+    ) -> Result<Neo4jTransaction<'t>, Error> {
+        let mut runtime = Runtime::new()?;
+        let client = if write {
+            runtime.block_on(pool.get())?
+        } else {
+            runtime.block_on(pool.get_reader())?
+        };
+
+        Ok(Neo4jTransaction {
+            client,
+            db_names,
+            metadata: pool.transaction_metadata(write),
+            bookmark: pool.bookmark.clone(),
+            router: pool.router.clone(),
+            in_transaction: false,
+            runtime,
+        })
+    }
+
+    /// Runs a query and pulls all of its records. Outside of an explicit transaction, the query
+    /// runs in a transaction of its own, so it carries the transaction metadata itself.
+    fn run(&mut self, query: String, params: Option<Params>) -> Result<Vec<Record>, Error> {
+        let result = self.run_query(query, params);
+        result.map_err(|e| self.failed(e))
+    }
+
+    fn run_query(&mut self, query: String, params: Option<Params>) -> Result<Vec<Record>, Error> {
+        let metadata = if self.in_transaction {
+            None
+        } else {
+            Some(Metadata::from(self.metadata.clone()))
+        };
+        self.runtime
+            .block_on(self.client.run_with_metadata(query, params, metadata))?;
+
+        let pull_meta = Metadata::from_iter(vec![("n", -1)]);
+        let (response, records) = self.runtime.block_on(self.client.pull(Some(pull_meta)))?;
+        match response {
+            Message::Success(_) => Ok(records),
+            message => Err(Error::Neo4jQueryFailed { message }),
+        }
+    }
+
+    /// Returns an [`Error::IdDuplicated`] if a node with the label already has the id given for a
+    /// new node. Without a uniqueness constraint on the `id` property of the label, concurrent
+    /// transactions that create nodes with the same id may both pass the check.
+    ///
+    /// [`Error::IdDuplicated`]: ../../../enum.Error.html#variant.IdDuplicated
+    fn check_id_unused(&mut self, label: String, id: &Value) -> Result<(), Error> {
+        let query = "MATCH (n:".to_string() + &label + " { id: $id })
+RETURN count(n) AS count
+";
+        let params = Params::from(HashMap::from_iter(vec![("id".to_string(), id.clone())]));
+        let records = self.run(query, Some(params))?;
+
+        if Neo4jTransaction::extract_count(records)? > 0 {
+            Err(Error::IdDuplicated {
+                id: String::try_from(id.clone())?,
+            })
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Notes a failure that shows the routing table of a cluster to be out of date. The
+    /// connection is discarded, rather than returned to the pool, and the table is read again
+    /// before the next connection is checked out, so that a retry reaches the new leader.
+    fn failed(&mut self, e: Error) -> Error {
+        if stale_route(&e) {
+            debug!(
+                "Neo4jTransaction::failed -- invalidating routing table: {}",
+                e
+            );
+            self.client.broken = true;
+            self.router.invalidate();
+        }
+        e
     }
 
     fn add_rel_return(query: String, src_var: &str, rel_var: &str, dst_var: &str) -> String {
@@ -203,21 +1177,32 @@ impl<'t> Neo4jTransaction<'t> {
             .collect::<Result<HashMap<String, Value>, Error>>()
     }
 
-    fn nodes<RequestCtx: RequestContext>(
+    /// Converts sampled properties, skipping any whose values cannot be represented in the
+    /// GraphQL schema, such as temporal and spatial values.
+    fn sample_props(props: &HashMap<String, bolt_proto::value::Value>) -> HashMap<String, Value> {
+        props
+            .iter()
+            .filter_map(|(k, v)| Value::try_from(v.clone()).ok().map(|v| (k.to_string(), v)))
+            .collect()
+    }
+
+    fn nodes(
         records: Vec<Record>,
         info: &Info,
-    ) -> Result<Vec<Node<RequestCtx>>, Error> {
+        db_names: &DbNames,
+    ) -> Result<Vec<NodeRecord>, Error> {
         trace!("Neo4jTransaction::nodes called -- records: {:#?}", records);
 
         records
             .into_iter()
             .map(|r| {
                 if let bolt_proto::value::Value::Node(n) = &r.fields()[0] {
-                    Ok(Node::new(
-                        n.labels()[0].to_string(),
+                    let type_name = db_names.type_name(&n.labels()[0]);
+                    Ok(NodeRecord::new(
+                        type_name.to_string(),
                         Neo4jTransaction::extract_node_properties(
-                            n.properties().clone(),
-                            info.type_def_by_name(&n.labels()[0])?,
+                            db_names.fields(type_name, n.properties().clone()),
+                            info.type_def_by_name(type_name)?,
                         )?,
                     ))
                 } else {
@@ -229,11 +1214,12 @@ impl<'t> Neo4jTransaction<'t> {
             .collect()
     }
 
-    fn rels<RequestCtx: RequestContext>(
+    fn rels(
         records: Vec<Record>,
         partition_key_opt: Option<&Value>,
         props_type_name: Option<&str>,
-    ) -> Result<Vec<Rel<RequestCtx>>, Error> {
+        db_names: &DbNames,
+    ) -> Result<Vec<RelRecord>, Error> {
         trace!("Neo4jTransaction::rels called -- records: {:#?}", records);
 
         records
@@ -263,25 +1249,20 @@ impl<'t> Neo4jTransaction<'t> {
                         });
                     };
 
-                Ok(Rel::new(
+                Ok(RelRecord::new(
                     props
                         .remove("id")
                         .ok_or_else(|| Error::ResponseItemNotFound {
                             name: "id".to_string(),
                         })?,
                     partition_key_opt.cloned(),
-                    props_type_name.map(|ptn| Node::new(ptn.to_string(), props)),
-                    NodeRef::Identifier {
-                        id: src_id,
-                        label: src_label,
-                    },
-                    NodeRef::Identifier {
-                        id: dst_id,
-                        label: dst_label,
-                    },
+                    props_type_name
+                        .map(|ptn| NodeRecord::new(ptn.to_string(), db_names.fields(ptn, props))),
+                    (src_id, db_names.type_name(&src_label).to_string()),
+                    (dst_id, db_names.type_name(&dst_label).to_string()),
                 ))
             })
-            .collect::<Result<Vec<Rel<RequestCtx>>, Error>>()
+            .collect::<Result<Vec<RelRecord>, Error>>()
     }
 }
 
@@ -289,15 +1270,19 @@ impl Transaction for Neo4jTransaction<'_> {
     fn begin(&mut self) -> Result<(), Error> {
         debug!("Neo4jTransaction::begin called");
 
-        let response = self.runtime.block_on(self.client.begin(None));
+        let metadata = Metadata::from(self.metadata.clone());
+        let response = self.runtime.block_on(self.client.begin(Some(metadata)));
         match response {
-            Ok(Message::Success(_)) => Ok(()),
-            Ok(message) => Err(Error::Neo4jQueryFailed { message }),
-            Err(e) => Err(Error::from(e)),
+            Ok(Message::Success(_)) => {
+                self.in_transaction = true;
+                Ok(())
+            }
+            Ok(message) => Err(self.failed(Error::Neo4jQueryFailed { message })),
+            Err(e) => Err(self.failed(Error::from(e))),
         }
     }
 
-    fn node_create_query<RequestCtx: RequestContext>(
+    fn node_create_query(
         &mut self,
         rel_create_fragments: Vec<String>,
         mut params: HashMap<String, Value>,
@@ -308,11 +1293,17 @@ impl Transaction for Neo4jTransaction<'_> {
     ) -> Result<(String, HashMap<String, Value>), Error> {
         trace!("Neo4jTransaction::node_create_query called -- rel_create_fragments: {:#?}, params: {:#?}, node_var: {:#?}, props: {:#?}, clause: {:#?}", 
         rel_create_fragments, params, node_var, props, clause);
+        // The random id is replaced by the id in the properties, if there is a generated or
+        // client-supplied one, as long as no other node of the type already has it.
+        if let Some(id) = props.get("id") {
+            self.check_id_unused(self.db_names.label(node_var.label()?).to_string(), id)?;
+        }
+
         let props_suffix = sg.suffix();
         let mut query = "CREATE (".to_string()
             + node_var.name()
             + ":"
-            + node_var.label()?
+            + self.db_names.label(node_var.label()?)
             + " { id: randomUUID() })\n"
             + "SET "
             + node_var.name()
@@ -340,41 +1331,35 @@ impl Transaction for Neo4jTransaction<'_> {
             }
         };
 
-        params.insert("props".to_string() + &props_suffix, props.into());
+        params.insert(
+            "props".to_string() + &props_suffix,
+            self.db_names.props(node_var.label().ok(), props).into(),
+        );
 
         Ok((query, params))
     }
 
-    fn create_node<RequestCtx: RequestContext>(
+    fn create_node(
         &mut self,
         query: String,
         params: HashMap<String, Value>,
         _partition_key_opt: Option<&Value>,
         info: &Info,
-    ) -> Result<Node<RequestCtx>, Error> {
+    ) -> Result<NodeRecord, Error> {
         trace!(
             "Neo4jTransaction::create_node called -- query: {}, params: {:#?}",
             query,
             params
         );
-        let p = Params::from(params);
-        self.runtime
-            .block_on(self.client.run_with_metadata(query, Some(p), None))?;
-
-        let pull_meta = Metadata::from_iter(vec![("n", -1)]);
-        let (response, records) = self.runtime.block_on(self.client.pull(Some(pull_meta)))?;
-        match response {
-            Message::Success(_) => (),
-            message => return Err(Error::Neo4jQueryFailed { message }),
-        }
+        let records = self.run(query, Some(Params::from(params)))?;
 
-        Neo4jTransaction::nodes(records, info)?
+        Neo4jTransaction::nodes(records, info, &self.db_names)?
             .into_iter()
             .next()
             .ok_or_else(|| Error::ResponseSetNotFound)
     }
 
-    fn rel_create_fragment<RequestCtx: RequestContext>(
+    fn rel_create_fragment(
         &mut self,
         dst_query: &str,
         mut params: HashMap<String, Value>,
@@ -399,7 +1384,9 @@ impl Transaction for Neo4jTransaction<'_> {
             + ")-["
             + rel_var.name()
             + ":"
-            + rel_var.label()
+            + self
+                .db_names
+                .rel_type(rel_var.src().label().ok(), rel_var.label())
             + " { id: randomUUID() }]->("
             + rel_var.dst().name()
             + ")\n"
@@ -409,7 +1396,12 @@ impl Transaction for Neo4jTransaction<'_> {
             + &props_var
             + "\n";
 
-        params.insert(props_var, props.into());
+        params.insert(
+            props_var,
+            self.db_names
+                .rel_props(rel_var.src().label().ok(), rel_var.label(), props)
+                .into(),
+        );
 
         let query = match clause {
             ClauseType::Parameter => q,
@@ -427,7 +1419,7 @@ impl Transaction for Neo4jTransaction<'_> {
         Ok((query, params))
     }
 
-    fn rel_create_query<RequestCtx: RequestContext>(
+    fn rel_create_query(
         &mut self,
         src_query_opt: Option<String>,
         rel_create_fragments: Vec<String>,
@@ -453,7 +1445,11 @@ impl Transaction for Neo4jTransaction<'_> {
         });
         query.push_str("\n");
 
-        query.push_str(&("MATCH (src:".to_string() + src_var.label()? + ")-[rel]->(dst)\n"));
+        query.push_str(
+            &("MATCH (src:".to_string()
+                + self.db_names.label(src_var.label()?)
+                + ")-[rel]->(dst)\n"),
+        );
         query.push_str("WHERE src.id IN src_ids AND rel.id IN (");
         rel_vars.iter().enumerate().for_each(|(i, rel_var)| {
             if i > 0 {
@@ -469,28 +1465,19 @@ impl Transaction for Neo4jTransaction<'_> {
         ))
     }
 
-    fn create_rels<RequestCtx: RequestContext>(
+    fn create_rels(
         &mut self,
         query: String,
         params: HashMap<String, Value>,
         props_type_name: Option<&str>,
         partition_key_opt: Option<&Value>,
-    ) -> Result<Vec<Rel<RequestCtx>>, Error> {
+    ) -> Result<Vec<RelRecord>, Error> {
         trace!("Neo4jTransaction::create_rels called -- query: {}, params: {:#?}, props_type_name: {:#?}, partition_key_opt: {:#?}",
         query, params, props_type_name, partition_key_opt);
 
-        let p = Params::from(params);
-        self.runtime
-            .block_on(self.client.run_with_metadata(query, Some(p), None))?;
+        let records = self.run(query, Some(Params::from(params)))?;
 
-        let pull_meta = Metadata::from_iter(vec![("n", -1)]);
-        let (response, records) = self.runtime.block_on(self.client.pull(Some(pull_meta)))?;
-        match response {
-            Message::Success(_) => (),
-            message => return Err(Error::Neo4jQueryFailed { message }),
-        }
-
-        Neo4jTransaction::rels(records, partition_key_opt, props_type_name)
+        Neo4jTransaction::rels(records, partition_key_opt, props_type_name, &self.db_names)
     }
 
     fn node_read_fragment(
@@ -506,6 +1493,7 @@ impl Transaction for Neo4jTransaction<'_> {
         rel_query_fragments, params, node_var, props, clause);
 
         let param_suffix = sg.suffix();
+        let props = self.db_names.props(node_var.label().ok(), props);
         let mut match_fragment = String::new();
         let mut where_fragment = String::new();
 
@@ -518,7 +1506,7 @@ impl Transaction for Neo4jTransaction<'_> {
                             &("MATCH (".to_string()
                                 + node_var.name()
                                 + ":"
-                                + node_var.label()?
+                                + self.db_names.label(node_var.label()?)
                                 + ")\n"),
                         );
                     } else {
@@ -566,9 +1554,10 @@ impl Transaction for Neo4jTransaction<'_> {
         params: HashMap<String, Value>,
         node_var: &NodeQueryVar,
         clause: ClauseType,
+        limit: Option<usize>,
     ) -> Result<(String, HashMap<String, Value>), Error> {
-        trace!("Neo4jTransaction::node_read_query called -- match_fragment: {}, where_fragment: {}, params: {:#?}, clause: {:#?}",
-        match_fragment, where_fragment, params, clause);
+        trace!("Neo4jTransaction::node_read_query called -- match_fragment: {}, where_fragment: {}, params: {:#?}, clause: {:#?}, limit: {:#?}",
+        match_fragment, where_fragment, params, clause, limit);
         let mut query = match_fragment.to_string();
 
         if !where_fragment.is_empty() {
@@ -577,41 +1566,36 @@ impl Transaction for Neo4jTransaction<'_> {
 
         match clause {
             ClauseType::Parameter => (),
-            ClauseType::FirstSubQuery | ClauseType::SubQuery | ClauseType::Query => {
+            ClauseType::FirstSubQuery | ClauseType::SubQuery => {
                 query.push_str(&("RETURN ".to_string() + node_var.name() + "\n"));
             }
+            ClauseType::Query => {
+                query.push_str(&("RETURN ".to_string() + node_var.name() + "\n"));
+                if let Some(limit) = limit {
+                    query.push_str(&format!("LIMIT {}\n", limit));
+                }
+            }
         };
 
         Ok((query, params))
     }
 
-    fn read_nodes<RequestCtx: RequestContext>(
+    fn read_nodes(
         &mut self,
         query: String,
         params_opt: Option<HashMap<String, Value>>,
         _partition_key_opt: Option<&Value>,
         info: &Info,
-    ) -> Result<Vec<Node<RequestCtx>>, Error> {
+    ) -> Result<Vec<NodeRecord>, Error> {
         trace!(
             "Neo4jTransaction::read_nodes called -- query: {}, params_opt: {:#?}, info.name: {}",
             query,
             params_opt,
             info.name()
         );
-        self.runtime.block_on(self.client.run_with_metadata(
-            query,
-            params_opt.map(Params::from),
-            None,
-        ))?;
-
-        let pull_meta = Metadata::from_iter(vec![("n", -1)]);
-        let (response, records) = self.runtime.block_on(self.client.pull(Some(pull_meta)))?;
-        match response {
-            Message::Success(_) => (),
-            message => return Err(Error::Neo4jQueryFailed { message }),
-        }
+        let records = self.run(query, params_opt.map(Params::from))?;
 
-        Neo4jTransaction::nodes(records, info)
+        Neo4jTransaction::nodes(records, info, &self.db_names)
     }
 
     fn rel_read_fragment(
@@ -626,6 +1610,9 @@ impl Transaction for Neo4jTransaction<'_> {
         trace!("Neo4jTransaction::rel_read_fragment called -- src_query_opt: {:#?}, dst_query_opt: {:#?}, params: {:#?}, rel_var: {:#?}, props: {:#?}",
         src_query_opt, dst_query_opt, params, rel_var, props);
 
+        let props = self
+            .db_names
+            .rel_props(rel_var.src().label().ok(), rel_var.label(), props);
         let mut match_fragment = String::new();
         let mut where_fragment = String::new();
 
@@ -647,11 +1634,13 @@ impl Transaction for Neo4jTransaction<'_> {
             &("MATCH (".to_string()
                 + rel_var.src().name()
                 + ":"
-                + rel_var.src().label()?
+                + self.db_names.label(rel_var.src().label()?)
                 + ")-["
                 + rel_var.name()
                 + ":"
-                + rel_var.label()
+                + self
+                    .db_names
+                    .rel_type(rel_var.src().label().ok(), rel_var.label())
                 + "]->("
                 + rel_var.dst().name()
                 + ")\n"),
@@ -682,9 +1671,10 @@ impl Transaction for Neo4jTransaction<'_> {
         params: HashMap<String, Value>,
         rel_var: &RelQueryVar,
         clause: ClauseType,
+        limit: Option<usize>,
     ) -> Result<(String, HashMap<String, Value>), Error> {
-        trace!("Neo4jTransaction::rel_read_query called -- match_fragment: {}, where_fragment: {}, params: {:#?}, rel_var: {:#?}, clause: {:#?}",
-        match_fragment, where_fragment, params, rel_var, clause);
+        trace!("Neo4jTransaction::rel_read_query called -- match_fragment: {}, where_fragment: {}, params: {:#?}, rel_var: {:#?}, clause: {:#?}, limit: {:#?}",
+        match_fragment, where_fragment, params, rel_var, clause, limit);
 
         let mut query = match_fragment.to_string();
 
@@ -693,47 +1683,37 @@ impl Transaction for Neo4jTransaction<'_> {
         }
 
         if let ClauseType::Query = clause {
-            Ok((
-                Neo4jTransaction::add_rel_return(
-                    query,
-                    rel_var.src().name(),
-                    rel_var.name(),
-                    rel_var.dst().name(),
-                ),
-                params,
-            ))
+            let mut query = Neo4jTransaction::add_rel_return(
+                query,
+                rel_var.src().name(),
+                rel_var.name(),
+                rel_var.dst().name(),
+            );
+            if let Some(limit) = limit {
+                query.push_str(&format!("LIMIT {}\n", limit));
+            }
+            Ok((query, params))
         } else {
             Ok((query, params))
         }
     }
 
-    fn read_rels<RequestCtx: RequestContext>(
+    fn read_rels(
         &mut self,
         query: String,
         params_opt: Option<HashMap<String, Value>>,
         props_type_name: Option<&str>,
         partition_key_opt: Option<&Value>,
-    ) -> Result<Vec<Rel<RequestCtx>>, Error> {
+    ) -> Result<Vec<RelRecord>, Error> {
         trace!("Neo4jTransaction::read_rels called -- query: {}, props_type_name: {:#?}, partition_key_opt: {:#?}, params_opt: {:#?}",
         query, props_type_name, partition_key_opt, params_opt);
 
-        self.runtime.block_on(self.client.run_with_metadata(
-            query,
-            params_opt.map(Params::from),
-            None,
-        ))?;
+        let records = self.run(query, params_opt.map(Params::from))?;
 
-        let pull_meta = Metadata::from_iter(vec![("n", -1)]);
-        let (response, records) = self.runtime.block_on(self.client.pull(Some(pull_meta)))?;
-        match response {
-            Message::Success(_) => (),
-            message => return Err(Error::Neo4jQueryFailed { message }),
-        }
-
-        Neo4jTransaction::rels(records, partition_key_opt, props_type_name)
+        Neo4jTransaction::rels(records, partition_key_opt, props_type_name, &self.db_names)
     }
 
-    fn node_update_query<RequestCtx: RequestContext>(
+    fn node_update_query(
         &mut self,
         match_query: String,
         change_queries: Vec<String>,
@@ -752,7 +1732,10 @@ impl Transaction for Neo4jTransaction<'_> {
         query.push_str(
             &("SET ".to_string() + node_var.name() + " += $props" + &props_suffix + "\n"),
         );
-        params.insert("props".to_string() + &props_suffix, props.into());
+        params.insert(
+            "props".to_string() + &props_suffix,
+            self.db_names.props(node_var.label().ok(), props).into(),
+        );
 
         if !change_queries.is_empty() {
             query.push_str(&("WITH ".to_string() + node_var.name() + "\n"));
@@ -766,33 +1749,24 @@ impl Transaction for Neo4jTransaction<'_> {
         Ok((query, params))
     }
 
-    fn update_nodes<RequestCtx: RequestContext>(
+    fn update_nodes(
         &mut self,
         query: String,
         params: HashMap<String, Value>,
         _partition_key_opt: Option<&Value>,
         info: &Info,
-    ) -> Result<Vec<Node<RequestCtx>>, Error> {
+    ) -> Result<Vec<NodeRecord>, Error> {
         trace!(
             "Neo4jTransaction::update_nodes called: query: {}, params: {:#?}",
             query,
             params,
         );
-        let p = Params::from(params);
-        self.runtime
-            .block_on(self.client.run_with_metadata(query, Some(p), None))?;
+        let records = self.run(query, Some(Params::from(params)))?;
 
-        let pull_meta = Metadata::from_iter(vec![("n", -1)]);
-        let (response, records) = self.runtime.block_on(self.client.pull(Some(pull_meta)))?;
-        match response {
-            Message::Success(_) => (),
-            message => return Err(Error::Neo4jQueryFailed { message }),
-        }
-
-        Neo4jTransaction::nodes(records, info)
+        Neo4jTransaction::nodes(records, info, &self.db_names)
     }
 
-    fn rel_update_query<RequestCtx: RequestContext>(
+    fn rel_update_query(
         &mut self,
         match_query: String,
         mut params: HashMap<String, Value>,
@@ -808,7 +1782,12 @@ impl Transaction for Neo4jTransaction<'_> {
 
         let q = match_query + "SET " + rel_var.name() + " += $" + &props_var + "\n";
 
-        params.insert(props_var, props.into());
+        params.insert(
+            props_var,
+            self.db_names
+                .rel_props(rel_var.src().label().ok(), rel_var.label(), props)
+                .into(),
+        );
         Ok((
             Neo4jTransaction::add_rel_return(
                 q,
@@ -820,27 +1799,18 @@ impl Transaction for Neo4jTransaction<'_> {
         ))
     }
 
-    fn update_rels<RequestCtx: RequestContext>(
+    fn update_rels(
         &mut self,
         query: String,
         params: HashMap<String, Value>,
         props_type_name: Option<&str>,
         partition_key_opt: Option<&Value>,
-    ) -> Result<Vec<Rel<RequestCtx>>, Error> {
+    ) -> Result<Vec<RelRecord>, Error> {
         trace!("Neo4jTransaction::update_rels called -- query: {}, params: {:#?}, props_type_name: {:#?}, partition_key_opt: {:#?}",
         query, params, props_type_name, partition_key_opt);
-        let p = Params::from(params);
-        self.runtime
-            .block_on(self.client.run_with_metadata(query, Some(p), None))?;
-
-        let pull_meta = Metadata::from_iter(vec![("n", -1)]);
-        let (response, records) = self.runtime.block_on(self.client.pull(Some(pull_meta)))?;
-        match response {
-            Message::Success(_) => (),
-            message => return Err(Error::Neo4jQueryFailed { message }),
-        }
+        let records = self.run(query, Some(Params::from(params)))?;
 
-        Neo4jTransaction::rels(records, partition_key_opt, props_type_name)
+        Neo4jTransaction::rels(records, partition_key_opt, props_type_name, &self.db_names)
     }
 
     fn node_delete_query(
@@ -883,16 +1853,7 @@ impl Transaction for Neo4jTransaction<'_> {
             query,
             params,
         );
-        let p = Params::from(params);
-        self.runtime
-            .block_on(self.client.run_with_metadata(query, Some(p), None))?;
-
-        let pull_meta = Metadata::from_iter(vec![("n", -1)]);
-        let (response, records) = self.runtime.block_on(self.client.pull(Some(pull_meta)))?;
-        match response {
-            Message::Success(_) => (),
-            message => return Err(Error::Neo4jQueryFailed { message }),
-        }
+        let records = self.run(query, Some(Params::from(params)))?;
 
         Neo4jTransaction::extract_count(records)
     }
@@ -942,16 +1903,7 @@ impl Transaction for Neo4jTransaction<'_> {
             query,
             params
         );
-        let p = Params::from(params);
-        self.runtime
-            .block_on(self.client.run_with_metadata(query, Some(p), None))?;
-
-        let pull_meta = Metadata::from_iter(vec![("n", -1)]);
-        let (response, records) = self.runtime.block_on(self.client.pull(Some(pull_meta)))?;
-        match response {
-            Message::Success(_) => (),
-            message => return Err(Error::Neo4jQueryFailed { message }),
-        }
+        let records = self.run(query, Some(Params::from(params)))?;
 
         Neo4jTransaction::extract_count(records)
     }
@@ -959,11 +1911,27 @@ impl Transaction for Neo4jTransaction<'_> {
     fn commit(&mut self) -> Result<(), Error> {
         debug!("transaction::commit called");
 
-        Ok(self.runtime.block_on(self.client.commit()).map(|_| ())?)
+        self.in_transaction = false;
+        let result = self.runtime.block_on(self.client.commit());
+        match result.map_err(|e| self.failed(e.into()))? {
+            Message::Success(success) => {
+                // Later transactions wait for the commit to reach the server they run on, so
+                // that queries sent to a follower see the results of this transaction
+                if let Some(b) = success.metadata().get("bookmark") {
+                    if let Ok(mut bookmark) = self.bookmark.lock() {
+                        *bookmark = Some(b.clone().try_into()?);
+                    }
+                }
+                Ok(())
+            }
+            message => Err(self.failed(Error::Neo4jQueryFailed { message })),
+        }
     }
 
     fn rollback(&mut self) -> Result<(), Error> {
         debug!("transaction::rollback called");
+
+        self.in_transaction = false;
         Ok(self.runtime.block_on(self.client.rollback()).map(|_| ())?)
     }
 }
@@ -1062,3 +2030,283 @@ impl TryFrom<HashMap<String, bolt_proto::value::Value>> for Value {
         Ok(Value::Map(hmv))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{host_of, routing_servers, stale_route, Neo4jEndpoint, Role, Router, RoutingTable};
+    use crate::Error;
+    use bolt_proto::message::{Failure, Message};
+    use std::collections::HashMap;
+    use std::iter::FromIterator;
+    use std::time::{Duration, Instant};
+    use tokio::runtime::Runtime;
+
+    fn endpoint(routing: bool) -> Neo4jEndpoint {
+        Neo4jEndpoint::new(
+            "core-1".to_string(),
+            7687,
+            "neo4j".to_string(),
+            "password".to_string(),
+        )
+        .with_routing(routing)
+    }
+
+    fn table(writers: Vec<&str>, readers: Vec<&str>, ttl: Duration) -> RoutingTable {
+        RoutingTable {
+            writers: writers.into_iter().map(|a| a.to_string()).collect(),
+            readers: readers.into_iter().map(|a| a.to_string()).collect(),
+            expires: Instant::now() + ttl,
+        }
+    }
+
+    fn failure(code: &str) -> Error {
+        Error::Neo4jQueryFailed {
+            message: Message::Failure(Failure::new(HashMap::from_iter(vec![(
+                "code".to_string(),
+                bolt_proto::value::Value::from(code),
+            )]))),
+        }
+    }
+
+    fn server(role: &str, addresses: Vec<&str>) -> HashMap<String, bolt_proto::value::Value> {
+        HashMap::from_iter(vec![
+            ("role".to_string(), role.into()),
+            ("addresses".to_string(), addresses.into()),
+        ])
+    }
+
+    /// Passes if Neo4jEndpoint implements the Send trait
+    #[test]
+    fn test_neo4j_endpoint_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<Neo4jEndpoint>();
+    }
+
+    /// Passes if Neo4jEndpoint implements the Sync trait
+    #[test]
+    fn test_neo4j_endpoint_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<Neo4jEndpoint>();
+    }
+
+    /// Passes if the scheme of a URI selects TLS and routing, and the port defaults to 7687
+    #[test]
+    fn from_uri_schemes() {
+        let ne =
+            Neo4jEndpoint::from_uri("bolt://localhost", "u".to_string(), "p".to_string()).unwrap();
+        assert_eq!(
+            ("localhost", 7687, false, false),
+            (&*ne.host, ne.port, ne.tls, ne.routing)
+        );
+
+        let ne = Neo4jEndpoint::from_uri(
+            "bolt+s://db.example.com:7688",
+            "u".to_string(),
+            "p".to_string(),
+        )
+        .unwrap();
+        assert_eq!(
+            ("db.example.com", 7688, true, false),
+            (&*ne.host, ne.port, ne.tls, ne.routing)
+        );
+
+        let ne =
+            Neo4jEndpoint::from_uri("neo4j://10.0.0.1/", "u".to_string(), "p".to_string()).unwrap();
+        assert_eq!(
+            ("10.0.0.1", 7687, false, true),
+            (&*ne.host, ne.port, ne.tls, ne.routing)
+        );
+
+        let ne = Neo4jEndpoint::from_uri(
+            "neo4j+s://db.example.com?region=west&policy=fast",
+            "u".to_string(),
+            "p".to_string(),
+        )
+        .unwrap();
+        assert_eq!(
+            ("db.example.com", 7687, true, true),
+            (&*ne.host, ne.port, ne.tls, ne.routing)
+        );
+        assert_eq!(Some(&"west".to_string()), ne.routing_context.get("region"));
+        assert_eq!(Some(&"fast".to_string()), ne.routing_context.get("policy"));
+        assert!(ne.database.is_none());
+    }
+
+    /// Passes if an IPv6 address in brackets is parsed, with or without a port
+    #[test]
+    fn from_uri_ipv6() {
+        let ne =
+            Neo4jEndpoint::from_uri("bolt://[::1]:7690", "u".to_string(), "p".to_string()).unwrap();
+        assert_eq!(("::1", 7690), (&*ne.host, ne.port));
+
+        let ne = Neo4jEndpoint::from_uri("bolt://[::1]", "u".to_string(), "p".to_string()).unwrap();
+        assert_eq!(("::1", 7687), (&*ne.host, ne.port));
+    }
+
+    /// Passes if URIs with unsupported schemes or malformed addresses are rejected
+    #[test]
+    fn from_uri_invalid() {
+        for uri in &[
+            "localhost:7687",
+            "http://localhost",
+            "bolt+ssc://localhost",
+            "bolt://",
+            "bolt://localhost:port",
+            "bolt://localhost:7687/movies",
+            "bolt://user@localhost",
+            "neo4j://localhost?region",
+        ] {
+            match Neo4jEndpoint::from_uri(uri, "u".to_string(), "p".to_string()) {
+                Err(Error::Neo4jUriNotParsed { uri: u }) => assert_eq!(uri, &u),
+                _ => panic!("Expected {} to be rejected", uri),
+            }
+        }
+    }
+
+    /// Passes if the builder methods set the database, TLS, and routing
+    #[test]
+    fn with_options() {
+        let ne = Neo4jEndpoint::new(
+            "127.0.0.1".to_string(),
+            7687,
+            "u".to_string(),
+            "p".to_string(),
+        );
+        assert_eq!(
+            (false, false, None),
+            (ne.tls, ne.routing, ne.database.as_deref())
+        );
+
+        let ne = ne
+            .with_database("movies".to_string())
+            .with_tls(true)
+            .with_routing(true);
+        assert_eq!(
+            (true, true, Some("movies")),
+            (ne.tls, ne.routing, ne.database.as_deref())
+        );
+    }
+
+    /// Passes if the host name is taken from an address for checking a TLS certificate
+    #[test]
+    fn host_of_address() {
+        assert_eq!("db.example.com", host_of("db.example.com:7687"));
+        assert_eq!("db.example.com", host_of("db.example.com"));
+        assert_eq!("::1", host_of("[::1]:7687"));
+    }
+
+    /// Passes if writers and readers are read from a routing table, and routers are ignored
+    #[test]
+    fn routing_table_servers() {
+        let servers = bolt_proto::value::Value::from(vec![
+            server("WRITE", vec!["core-1:7687"]),
+            server("READ", vec!["core-2:7687", "replica-1:7687"]),
+            server("ROUTE", vec!["core-1:7687", "core-2:7687", "core-3:7687"]),
+        ]);
+
+        let (writers, readers) = routing_servers(servers).unwrap();
+        assert_eq!(vec!["core-1:7687".to_string()], writers);
+        assert_eq!(
+            vec!["core-2:7687".to_string(), "replica-1:7687".to_string()],
+            readers
+        );
+    }
+
+    /// Passes if a routing table entry without a role is rejected
+    #[test]
+    fn routing_table_missing_role() {
+        let mut entry = server("WRITE", vec!["core-1:7687"]);
+        entry.remove("role");
+
+        match routing_servers(bolt_proto::value::Value::from(vec![entry])) {
+            Err(Error::ResponseItemNotFound { name }) => assert_eq!("role", name),
+            _ => panic!("Expected a missing role to be rejected"),
+        }
+    }
+
+    /// Passes if reads go to the leader of a cluster without followers, and a table expires
+    /// once its time to live has passed
+    #[test]
+    fn routing_table_roles() {
+        let t = table(vec!["core-1:7687"], vec![], Duration::from_secs(60));
+        assert_eq!(&["core-1:7687".to_string()], t.addresses(Role::Read));
+        assert!(!t.expired(Instant::now()));
+        assert!(t.expired(Instant::now() + Duration::from_secs(61)));
+
+        let t = table(
+            vec!["core-1:7687"],
+            vec!["core-2:7687"],
+            Duration::from_secs(60),
+        );
+        assert_eq!(&["core-1:7687".to_string()], t.addresses(Role::Write));
+        assert_eq!(&["core-2:7687".to_string()], t.addresses(Role::Read));
+    }
+
+    /// Passes if new connections for reads take turns among the readers of a current table,
+    /// connections to servers that left their role are not current, and invalidating the table
+    /// marks it as expired
+    #[test]
+    fn router_refresh_on_invalidate() {
+        let router = Router::new(endpoint(true), "core-1:7687".to_string());
+        *router.table.write().unwrap() = table(
+            vec!["core-1:7687"],
+            vec!["core-2:7687", "core-3:7687"],
+            Duration::from_secs(60),
+        );
+
+        let mut runtime = Runtime::new().unwrap();
+        assert_eq!(
+            "core-1:7687",
+            runtime.block_on(router.address(Role::Write)).unwrap()
+        );
+        let first = runtime.block_on(router.address(Role::Read)).unwrap();
+        let second = runtime.block_on(router.address(Role::Read)).unwrap();
+        assert_ne!(first, second);
+
+        assert!(router.current(Role::Write, "core-1:7687"));
+        assert!(!router.current(Role::Write, "core-2:7687"));
+        assert!(!router.snapshot().expired(Instant::now()));
+
+        router.invalidate();
+        assert!(router.snapshot().expired(Instant::now()));
+    }
+
+    /// Passes if, without routing, connections are made to the endpoint's address, and the
+    /// table is never read or invalidated
+    #[test]
+    fn router_without_routing() {
+        let router = Router::new(endpoint(false), "core-1:7687".to_string());
+
+        let mut runtime = Runtime::new().unwrap();
+        assert_eq!(
+            "core-1:7687",
+            runtime.block_on(router.address(Role::Write)).unwrap()
+        );
+        assert_eq!(
+            "core-1:7687",
+            runtime.block_on(router.address(Role::Read)).unwrap()
+        );
+        router.invalidate();
+        assert!(router.current(Role::Write, "core-2:7687"));
+    }
+
+    /// Passes if writes sent to a server that is no longer the leader, and lost connections,
+    /// show the routing table to be out of date, and other errors do not
+    #[test]
+    fn stale_route_errors() {
+        assert!(stale_route(&failure("Neo.ClientError.Cluster.NotALeader")));
+        assert!(stale_route(&failure(
+            "Neo.ClientError.General.ForbiddenOnReadOnlyDatabase"
+        )));
+        assert!(!stale_route(&failure(
+            "Neo.TransientError.Transaction.DeadlockDetected"
+        )));
+        assert!(stale_route(&Error::BoltClientFailed {
+            source: bolt_client::error::Error::IOError(std::io::Error::new(
+                std::io::ErrorKind::ConnectionReset,
+                "reset"
+            )),
+        }));
+        assert!(!stale_route(&Error::ResponseSetNotFound));
+    }
+}
@@ -0,0 +1,1571 @@
+//! Provides a query plan interpreter for the graph stores that Warpgrapher lays out itself, rather
+//! than passing queries to a graph database. The in-memory, PostgreSQL, and SQLite back-ends share
+//! the interpreter, and differ only in how they store nodes and relationships.
+
+use crate::engine::database::inference::ModelInference;
+use crate::engine::database::{
+    ClauseType, DbNames, NodeQueryVar, NodeRecord, RelQueryVar, RelRecord, SuffixGenerator,
+    Transaction,
+};
+use crate::engine::schema::Info;
+use crate::engine::value::Value;
+use crate::Error;
+use log::{debug, trace};
+use std::collections::HashMap;
+#[cfg(any(feature = "postgres", feature = "sqlite"))]
+use std::convert::TryFrom;
+use uuid::Uuid;
+
+/// A node as held in a graph store, with its properties under their database names
+#[derive(Clone, Debug)]
+pub(crate) struct StoredNode {
+    pub(crate) id: String,
+    pub(crate) label: String,
+    pub(crate) props: HashMap<String, Value>,
+}
+
+/// A relationship as held in a graph store, with its properties under their database names
+#[derive(Clone, Debug)]
+pub(crate) struct StoredRel {
+    pub(crate) id: String,
+    pub(crate) rel_type: String,
+    pub(crate) src_id: String,
+    pub(crate) dst_id: String,
+    pub(crate) props: HashMap<String, Value>,
+}
+
+/// Storage for the nodes and relationships of a graph, on which a [`Plan`] is executed
+pub(crate) trait GraphStore {
+    /// Returns the distinct node labels in the graph
+    fn labels(&self) -> Result<Vec<String>, Error>;
+
+    /// Returns the node with the given id, if there is one
+    fn node(&self, id: &str) -> Result<Option<StoredNode>, Error>;
+
+    /// Returns the nodes that pass a filter, in the order in which they were created
+    fn nodes(&self, filter: &NodeFilter) -> Result<Vec<StoredNode>, Error>;
+
+    /// Returns the relationship with the given id, if there is one
+    fn rel(&self, id: &str) -> Result<Option<StoredRel>, Error>;
+
+    /// Returns the relationships that pass a filter, in the order in which they were created
+    fn rels(&self, filter: &RelFilter) -> Result<Vec<StoredRel>, Error>;
+
+    /// Adds a node with the given id
+    fn add_node(
+        &mut self,
+        id: &str,
+        label: &str,
+        props: HashMap<String, Value>,
+    ) -> Result<(), Error>;
+
+    /// Adds a relationship with a new id, returning the id
+    fn add_rel(
+        &mut self,
+        rel_type: &str,
+        src_id: &str,
+        dst_id: &str,
+        props: HashMap<String, Value>,
+    ) -> Result<String, Error>;
+
+    /// Sets properties of a node, as with [`merge_props`]
+    fn set_node_props(&mut self, id: &str, props: HashMap<String, Value>) -> Result<(), Error>;
+
+    /// Sets properties of a relationship, as with [`merge_props`]
+    fn set_rel_props(&mut self, id: &str, props: HashMap<String, Value>) -> Result<(), Error>;
+
+    /// Deletes a node along with all relationships to or from it
+    fn remove_node(&mut self, id: &str) -> Result<(), Error>;
+
+    /// Deletes a relationship
+    fn remove_rel(&mut self, id: &str) -> Result<(), Error>;
+}
+
+/// Selects the nodes returned by [`GraphStore::nodes`]. Stores should push as much of the filter
+/// as they can into their own queries, rather than reading every node and filtering afterwards.
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct NodeFilter<'a> {
+    /// Label of the nodes, or any label if `None`
+    pub(crate) label: Option<&'a str>,
+    /// Properties that the nodes must have, compared as in [`props_match`]
+    pub(crate) props: Option<&'a HashMap<String, Value>>,
+    /// Greatest number of nodes to return
+    pub(crate) limit: Option<usize>,
+}
+
+impl NodeFilter<'_> {
+    /// Returns true if a node passes the label and property predicates of the filter
+    pub(crate) fn matches(&self, n: &StoredNode) -> bool {
+        self.label.map_or(true, |l| n.label == l)
+            && self.props.map_or(true, |p| props_match(&n.id, &n.props, p))
+    }
+}
+
+/// Selects the relationships returned by [`GraphStore::rels`]. As with a [`NodeFilter`], stores
+/// should push as much of the filter as they can into their own queries.
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct RelFilter<'a> {
+    /// Type of the relationships, or any type if `None`
+    pub(crate) rel_type: Option<&'a str>,
+    /// Id of the source node of the relationships
+    pub(crate) src_id: Option<&'a str>,
+    /// Id of the destination node of the relationships
+    pub(crate) dst_id: Option<&'a str>,
+    /// Label of the source node of the relationships
+    pub(crate) src_label: Option<&'a str>,
+    /// Properties that the relationships must have, compared as in [`props_match`]
+    pub(crate) props: Option<&'a HashMap<String, Value>>,
+}
+
+impl RelFilter<'_> {
+    /// Returns true if a relationship passes the type, node id, and property predicates of the
+    /// filter. The label of the source node is not checked.
+    pub(crate) fn matches(&self, r: &StoredRel) -> bool {
+        self.rel_type.map_or(true, |t| r.rel_type == t)
+            && self.src_id.map_or(true, |id| r.src_id == id)
+            && self.dst_id.map_or(true, |id| r.dst_id == id)
+            && self.props.map_or(true, |p| props_match(&r.id, &r.props, p))
+    }
+}
+
+/// Returns the property predicates that a store can test with equality comparisons on scalar
+/// JSON values, along with whether they are all of the predicates. Floating point values are
+/// compared with a tolerance, and lists and maps by their elements, so those are left for
+/// [`props_match`] to check on the rows returned.
+#[cfg(any(feature = "postgres", feature = "sqlite"))]
+pub(crate) fn scalar_props(props: Option<&HashMap<String, Value>>) -> (Vec<(&str, &Value)>, bool) {
+    let mut scalars = Vec::new();
+    let mut exact = true;
+    for (k, v) in props.into_iter().flatten() {
+        match v {
+            Value::Bool(_) | Value::Int64(_) | Value::String(_) | Value::Uuid(_) => {
+                scalars.push((k.as_str(), v))
+            }
+            Value::UInt64(u) if i64::try_from(*u).is_ok() => scalars.push((k.as_str(), v)),
+            _ => exact = false,
+        }
+    }
+    (scalars, exact)
+}
+
+/// A connection to a graph store, which may have a transaction open
+pub(crate) trait GraphSession {
+    /// Begins a transaction, holding the graph until it is committed or rolled back
+    fn begin(&mut self) -> Result<(), Error>;
+
+    /// Commits the open transaction
+    fn commit(&mut self) -> Result<(), Error>;
+
+    /// Rolls back the open transaction, discarding its writes
+    fn rollback(&mut self) -> Result<(), Error>;
+
+    /// Runs `f` against the graph, within the open transaction if there is one
+    fn with_store<T, F>(&mut self, f: F) -> Result<T, Error>
+    where
+        F: FnOnce(&mut dyn GraphStore) -> Result<T, Error>;
+}
+
+/// Sets properties in the manner of a Cypher `+=`, where a null value removes the property
+pub(crate) fn merge_props(stored: &mut HashMap<String, Value>, props: HashMap<String, Value>) {
+    props.into_iter().for_each(|(k, v)| {
+        if let Value::Null = v {
+            stored.remove(&k);
+        } else {
+            stored.insert(k, v);
+        }
+    });
+}
+
+/// Returns true if every property in the query has an equal value in the stored properties
+pub(crate) fn props_match(
+    id: &str,
+    stored: &HashMap<String, Value>,
+    query: &HashMap<String, Value>,
+) -> bool {
+    query.iter().all(|(k, v)| {
+        if k == "id" {
+            values_match(&Value::String(id.to_string()), v)
+        } else {
+            stored.get(k).map_or(false, |sv| values_match(sv, v))
+        }
+    })
+}
+
+fn values_match(a: &Value, b: &Value) -> bool {
+    match (a, b) {
+        (Value::Array(x), Value::Array(y)) => {
+            x.len() == y.len() && x.iter().zip(y.iter()).all(|(xv, yv)| values_match(xv, yv))
+        }
+        (Value::Bool(x), Value::Bool(y)) => x == y,
+        (Value::Map(x), Value::Map(y)) => {
+            x.len() == y.len()
+                && x.iter()
+                    .all(|(k, xv)| y.get(k).map_or(false, |yv| values_match(xv, yv)))
+        }
+        (Value::Null, Value::Null) => true,
+        (Value::String(x), Value::String(y)) => x == y,
+        (Value::Uuid(x), Value::Uuid(y)) => x == y,
+        (Value::String(s), Value::Uuid(u)) | (Value::Uuid(u), Value::String(s)) => {
+            u.to_hyphenated().to_string() == *s
+        }
+        (Value::Int64(x), Value::Int64(y)) => x == y,
+        (Value::UInt64(x), Value::UInt64(y)) => x == y,
+        (Value::Int64(i), Value::UInt64(u)) | (Value::UInt64(u), Value::Int64(i)) => {
+            i128::from(*i) == i128::from(*u)
+        }
+        (Value::Float64(x), Value::Float64(y)) => (x - y).abs() < f64::EPSILON,
+        (Value::Float64(f), Value::Int64(i)) | (Value::Int64(i), Value::Float64(f)) => {
+            (f - *i as f64).abs() < f64::EPSILON
+        }
+        (Value::Float64(f), Value::UInt64(u)) | (Value::UInt64(u), Value::Float64(f)) => {
+            (f - *u as f64).abs() < f64::EPSILON
+        }
+        _ => false,
+    }
+}
+
+/// Samples up to `sample_size` nodes with each label in a graph store, along with their outgoing
+/// relationships, and records their properties and relationships for inference of a
+/// [`Configuration`].
+///
+/// [`Configuration`]: ../../config/struct.Configuration.html
+pub(crate) fn infer_model(
+    store: &dyn GraphStore,
+    sample_size: usize,
+) -> Result<ModelInference, Error> {
+    trace!("plan::infer_model called -- sample_size: {}", sample_size);
+
+    let mut mi = ModelInference::new(vec!["id"]);
+    for label in store.labels()? {
+        for n in store.nodes(&NodeFilter {
+            label: Some(&label),
+            limit: Some(sample_size),
+            ..NodeFilter::default()
+        })? {
+            mi.observe_node(&n.label, &n.props);
+
+            let mut rels = Vec::new();
+            for r in store.rels(&RelFilter {
+                src_id: Some(&n.id),
+                ..RelFilter::default()
+            })? {
+                if let Some(dst) = store.node(&r.dst_id)? {
+                    rels.push((r.rel_type, dst.label, r.props));
+                }
+            }
+            mi.observe_rels(&n.label, &rels);
+        }
+    }
+
+    Ok(mi)
+}
+
+/// Variable bindings from the names of node query variables to the ids of the nodes to which
+/// they are bound
+type Bindings = HashMap<String, String>;
+
+fn bind(bindings: &Bindings, var: &str, id: &str) -> Bindings {
+    let mut b = bindings.clone();
+    b.insert(var.to_string(), id.to_string());
+    b
+}
+
+/// A step in a query plan. The query strings passed through the [`Transaction`] interface are
+/// handles to fragments, which refer to other fragments by index. As in a Cypher query, node
+/// query variables bound by an enclosing fragment constrain the fragments nested within it.
+#[derive(Clone, Debug)]
+enum Fragment {
+    NodeCreate {
+        var: String,
+        label: String,
+        props: HashMap<String, Value>,
+        rels: Vec<usize>,
+    },
+    NodeMatch {
+        var: String,
+        label: Option<String>,
+        props: HashMap<String, Value>,
+        rels: Vec<usize>,
+        limit: Option<usize>,
+    },
+    NodeUpdate {
+        var: String,
+        matched: usize,
+        props: HashMap<String, Value>,
+        changes: Vec<usize>,
+    },
+    NodeDelete {
+        var: String,
+        matched: usize,
+        rels: Vec<usize>,
+    },
+    RelCreate {
+        src_var: String,
+        rel_type: String,
+        props: HashMap<String, Value>,
+        dst: usize,
+    },
+    RelCreateQuery {
+        src_var: String,
+        src: Option<usize>,
+        rels: Vec<usize>,
+    },
+    RelMatch {
+        src_var: String,
+        dst_var: String,
+        src_label: Option<String>,
+        rel_type: String,
+        props: HashMap<String, Value>,
+        src: Option<usize>,
+        dst: Option<usize>,
+        limit: Option<usize>,
+    },
+    RelUpdate {
+        matched: usize,
+        props: HashMap<String, Value>,
+    },
+    RelDelete {
+        matched: usize,
+        src: Option<usize>,
+        dst: Option<usize>,
+    },
+}
+
+/// Result of executing a fragment
+enum Outcome {
+    Nodes(Vec<String>),
+    Rels(Vec<String>),
+    Count(i32),
+}
+
+#[derive(Debug, Default)]
+struct Plan {
+    fragments: Vec<Fragment>,
+}
+
+impl Plan {
+    fn push(&mut self, fragment: Fragment) -> String {
+        self.fragments.push(fragment);
+        "#".to_string() + &(self.fragments.len() - 1).to_string()
+    }
+
+    fn index(&self, handle: &str) -> Result<usize, Error> {
+        handle
+            .strip_prefix('#')
+            .and_then(|i| i.parse::<usize>().ok())
+            .filter(|i| *i < self.fragments.len())
+            .ok_or_else(|| Error::InputItemNotFound {
+                name: handle.to_string(),
+            })
+    }
+
+    fn indexes<'a, I>(&self, handles: I) -> Result<Vec<usize>, Error>
+    where
+        I: IntoIterator<Item = &'a str>,
+    {
+        handles.into_iter().map(|h| self.index(h)).collect()
+    }
+
+    /// Returns the ids of the nodes bound to `var` by a node or relationship match fragment
+    /// Sets the greatest number of nodes or relationships returned by a match fragment
+    fn set_limit(&mut self, handle: &str, limit: Option<usize>) -> Result<(), Error> {
+        let i = self.index(handle)?;
+        match &mut self.fragments[i] {
+            Fragment::NodeMatch { limit: l, .. } | Fragment::RelMatch { limit: l, .. } => {
+                *l = limit;
+                Ok(())
+            }
+            _ => Err(Error::TypeNotExpected),
+        }
+    }
+
+    fn matched_nodes(
+        &self,
+        g: &dyn GraphStore,
+        i: usize,
+        var: &str,
+        bindings: &Bindings,
+    ) -> Result<Vec<String>, Error> {
+        match &self.fragments[i] {
+            Fragment::NodeMatch { .. } => self.node_matches(g, i, bindings),
+            Fragment::RelMatch { src_var, .. } => {
+                let mut ids = Vec::new();
+                for r in self.rel_matches(g, i, bindings)? {
+                    let id = if var == src_var { r.src_id } else { r.dst_id };
+                    if !ids.contains(&id) {
+                        ids.push(id);
+                    }
+                }
+                Ok(ids)
+            }
+            _ => Err(Error::TypeNotExpected),
+        }
+    }
+
+    fn node_matches(
+        &self,
+        g: &dyn GraphStore,
+        i: usize,
+        bindings: &Bindings,
+    ) -> Result<Vec<String>, Error> {
+        if let Fragment::NodeMatch {
+            var,
+            label,
+            props,
+            rels,
+            limit,
+        } = &self.fragments[i]
+        {
+            let filter = NodeFilter {
+                label: label.as_deref(),
+                props: Some(props),
+                limit: if rels.is_empty() { *limit } else { None },
+            };
+            let candidates = match (bindings.get(var), props.get("id")) {
+                (Some(id), _) | (None, Some(Value::String(id))) => g
+                    .node(id)?
+                    .into_iter()
+                    .filter(|n| filter.matches(n))
+                    .collect(),
+                _ => g.nodes(&filter)?,
+            };
+
+            let mut ids = Vec::new();
+            for n in candidates {
+                if limit.map_or(false, |l| ids.len() >= l) {
+                    break;
+                }
+
+                let b = bind(bindings, var, &n.id);
+                if rels
+                    .iter()
+                    .try_fold(true, |acc, r| -> Result<bool, Error> {
+                        Ok(acc && !self.rel_matches(g, *r, &b)?.is_empty())
+                    })?
+                {
+                    ids.push(n.id);
+                }
+            }
+            Ok(ids)
+        } else {
+            Err(Error::TypeNotExpected)
+        }
+    }
+
+    fn rel_matches(
+        &self,
+        g: &dyn GraphStore,
+        i: usize,
+        bindings: &Bindings,
+    ) -> Result<Vec<StoredRel>, Error> {
+        if let Fragment::RelMatch {
+            src_var,
+            dst_var,
+            src_label,
+            rel_type,
+            props,
+            src,
+            dst,
+            limit,
+        } = &self.fragments[i]
+        {
+            let mut matches = Vec::new();
+            for r in g.rels(&RelFilter {
+                rel_type: Some(rel_type),
+                src_id: bindings.get(src_var).map(String::as_str),
+                dst_id: bindings.get(dst_var).map(String::as_str),
+                src_label: src_label.as_deref(),
+                props: Some(props),
+            })? {
+                if limit.map_or(false, |l| matches.len() >= l) {
+                    break;
+                }
+
+                if let Some(s) = src {
+                    if self
+                        .node_matches(g, *s, &bind(bindings, src_var, &r.src_id))?
+                        .is_empty()
+                    {
+                        continue;
+                    }
+                }
+
+                if let Some(d) = dst {
+                    if self
+                        .node_matches(g, *d, &bind(bindings, dst_var, &r.dst_id))?
+                        .is_empty()
+                    {
+                        continue;
+                    }
+                }
+
+                matches.push(r);
+            }
+            Ok(matches)
+        } else {
+            Err(Error::TypeNotExpected)
+        }
+    }
+
+    fn execute(
+        &self,
+        g: &mut dyn GraphStore,
+        i: usize,
+        bindings: &Bindings,
+    ) -> Result<Outcome, Error> {
+        match &self.fragments[i] {
+            Fragment::NodeCreate {
+                var,
+                label,
+                props,
+                rels,
+            } => {
+                let mut props = props.clone();
+                let id = match props.remove("id") {
+                    Some(Value::String(id)) => {
+                        if g.node(&id)?.is_some() {
+                            return Err(Error::IdDuplicated { id });
+                        }
+                        id
+                    }
+                    Some(v) => {
+                        return Err(Error::TypeConversionFailed {
+                            src: format!("{:#?}", v),
+                            dst: "String".to_string(),
+                        })
+                    }
+                    None => Uuid::new_v4().to_string(),
+                };
+                g.add_node(&id, label, props)?;
+                let b = bind(bindings, var, &id);
+                for r in rels {
+                    self.execute(g, *r, &b)?;
+                }
+                Ok(Outcome::Nodes(vec![id]))
+            }
+            Fragment::NodeMatch { .. } => Ok(Outcome::Nodes(self.node_matches(g, i, bindings)?)),
+            Fragment::NodeUpdate {
+                var,
+                matched,
+                props,
+                changes,
+            } => {
+                let ids = self.matched_nodes(g, *matched, var, bindings)?;
+                for id in &ids {
+                    g.set_node_props(id, props.clone())?;
+                    let b = bind(bindings, var, id);
+                    for c in changes {
+                        self.execute(g, *c, &b)?;
+                    }
+                }
+                Ok(Outcome::Nodes(ids))
+            }
+            Fragment::NodeDelete { var, matched, rels } => {
+                let ids = self.matched_nodes(g, *matched, var, bindings)?;
+                for id in &ids {
+                    let b = bind(bindings, var, id);
+                    for r in rels {
+                        self.execute(g, *r, &b)?;
+                    }
+                    g.remove_node(id)?;
+                }
+                Ok(Outcome::Count(ids.len() as i32))
+            }
+            Fragment::RelCreate {
+                src_var,
+                rel_type,
+                props,
+                dst,
+            } => {
+                let src_id = bindings
+                    .get(src_var)
+                    .ok_or_else(|| Error::InputItemNotFound {
+                        name: src_var.to_string(),
+                    })?
+                    .to_string();
+                let dst_ids = match self.execute(g, *dst, bindings)? {
+                    Outcome::Nodes(ids) => ids,
+                    _ => return Err(Error::TypeNotExpected),
+                };
+
+                Ok(Outcome::Rels(
+                    dst_ids
+                        .iter()
+                        .map(|dst_id| g.add_rel(rel_type, &src_id, dst_id, props.clone()))
+                        .collect::<Result<Vec<String>, Error>>()?,
+                ))
+            }
+            Fragment::RelCreateQuery { src_var, src, rels } => {
+                let src_ids = match src {
+                    Some(s) => self.matched_nodes(g, *s, src_var, bindings)?,
+                    None => bindings.get(src_var).into_iter().cloned().collect(),
+                };
+
+                let mut ids = Vec::new();
+                for src_id in src_ids {
+                    let b = bind(bindings, src_var, &src_id);
+                    for r in rels {
+                        if let Outcome::Rels(mut rel_ids) = self.execute(g, *r, &b)? {
+                            ids.append(&mut rel_ids);
+                        }
+                    }
+                }
+                Ok(Outcome::Rels(ids))
+            }
+            Fragment::RelMatch { .. } => Ok(Outcome::Rels(
+                self.rel_matches(g, i, bindings)?
+                    .into_iter()
+                    .map(|r| r.id)
+                    .collect(),
+            )),
+            Fragment::RelUpdate { matched, props } => {
+                let ids = self
+                    .rel_matches(g, *matched, bindings)?
+                    .into_iter()
+                    .map(|r| r.id)
+                    .collect::<Vec<String>>();
+                for id in &ids {
+                    g.set_rel_props(id, props.clone())?;
+                }
+                Ok(Outcome::Rels(ids))
+            }
+            Fragment::RelDelete { matched, src, dst } => {
+                let (src_var, dst_var) = match &self.fragments[*matched] {
+                    Fragment::RelMatch {
+                        src_var, dst_var, ..
+                    } => (src_var, dst_var),
+                    _ => return Err(Error::TypeNotExpected),
+                };
+
+                let rels = self.rel_matches(g, *matched, bindings)?;
+                for r in &rels {
+                    let b = bind(&bind(bindings, src_var, &r.src_id), dst_var, &r.dst_id);
+                    for d in src.iter().chain(dst.iter()) {
+                        self.execute(g, *d, &b)?;
+                    }
+                    g.remove_rel(&r.id)?;
+                }
+                Ok(Outcome::Count(rels.len() as i32))
+            }
+        }
+    }
+}
+
+/// A [`Transaction`] that builds a [`Plan`] from the query fragments requested by the resolvers,
+/// and executes it against the graph store of a [`GraphSession`]
+pub(crate) struct PlanTransaction<S: GraphSession> {
+    session: S,
+    db_names: DbNames,
+    plan: Plan,
+}
+
+impl<S: GraphSession> PlanTransaction<S> {
+    pub(crate) fn new(session: S, db_names: DbNames) -> PlanTransaction<S> {
+        PlanTransaction {
+            session,
+            db_names,
+            plan: Plan::default(),
+        }
+    }
+
+    /// Executes a query, and converts its outcome to records using the graph as it is after the
+    /// query has run
+    fn execute<T, F>(&mut self, query: &str, f: F) -> Result<T, Error>
+    where
+        F: FnOnce(Outcome, &dyn GraphStore, &DbNames) -> Result<T, Error>,
+    {
+        let i = self.plan.index(query)?;
+        let plan = &self.plan;
+        let db_names = &self.db_names;
+        self.session.with_store(|g| {
+            let outcome = plan.execute(g, i, &Bindings::new())?;
+            f(outcome, g, db_names)
+        })
+    }
+
+    fn read_node_records(&mut self, query: &str, info: &Info) -> Result<Vec<NodeRecord>, Error> {
+        self.execute(query, |outcome, g, db_names| match outcome {
+            Outcome::Nodes(ids) => node_records(ids, g, info, db_names),
+            _ => Err(Error::TypeNotExpected),
+        })
+    }
+
+    fn read_rel_records(
+        &mut self,
+        query: &str,
+        props_type_name: Option<&str>,
+        partition_key_opt: Option<&Value>,
+    ) -> Result<Vec<RelRecord>, Error> {
+        self.execute(query, |outcome, g, db_names| match outcome {
+            Outcome::Rels(ids) => rel_records(ids, g, props_type_name, partition_key_opt, db_names),
+            _ => Err(Error::TypeNotExpected),
+        })
+    }
+
+    fn count(&mut self, query: &str) -> Result<i32, Error> {
+        self.execute(query, |outcome, _, _| match outcome {
+            Outcome::Count(c) => Ok(c),
+            _ => Err(Error::TypeNotExpected),
+        })
+    }
+}
+
+fn node_records(
+    ids: Vec<String>,
+    g: &dyn GraphStore,
+    info: &Info,
+    db_names: &DbNames,
+) -> Result<Vec<NodeRecord>, Error> {
+    ids.iter()
+        .map(|id| {
+            let n = g.node(id)?.ok_or_else(|| Error::ResponseItemNotFound {
+                name: "node".to_string(),
+            })?;
+            let type_name = db_names.type_name(&n.label);
+            let type_def = info.type_def_by_name(type_name)?;
+            let mut fields = db_names
+                .fields(type_name, n.props)
+                .into_iter()
+                .map(|(k, v)| match v {
+                    Value::Array(_) => (k, v),
+                    _ if type_def.property(&k).map_or(false, |p| p.list()) => {
+                        (k, Value::Array(vec![v]))
+                    }
+                    _ => (k, v),
+                })
+                .collect::<HashMap<String, Value>>();
+            fields.insert("id".to_string(), Value::String(n.id));
+            Ok(NodeRecord::new(type_name.to_string(), fields))
+        })
+        .collect()
+}
+
+fn rel_records(
+    ids: Vec<String>,
+    g: &dyn GraphStore,
+    props_type_name: Option<&str>,
+    partition_key_opt: Option<&Value>,
+    db_names: &DbNames,
+) -> Result<Vec<RelRecord>, Error> {
+    ids.iter()
+        .map(|id| {
+            let r = g.rel(id)?.ok_or_else(|| Error::ResponseItemNotFound {
+                name: "rel".to_string(),
+            })?;
+            let label = |node_id: &str| -> Result<String, Error> {
+                g.node(node_id)?
+                    .map(|n| db_names.type_name(&n.label).to_string())
+                    .ok_or_else(|| Error::ResponseItemNotFound {
+                        name: "node".to_string(),
+                    })
+            };
+
+            let src = (Value::String(r.src_id.to_string()), label(&r.src_id)?);
+            let dst = (Value::String(r.dst_id.to_string()), label(&r.dst_id)?);
+
+            let props = r.props;
+            let props = props_type_name
+                .map(|ptn| NodeRecord::new(ptn.to_string(), db_names.fields(ptn, props)));
+
+            Ok(RelRecord::new(
+                Value::String(r.id),
+                partition_key_opt.cloned(),
+                props,
+                src,
+                dst,
+            ))
+        })
+        .collect()
+}
+
+impl<S: GraphSession> Transaction for PlanTransaction<S> {
+    fn begin(&mut self) -> Result<(), Error> {
+        debug!("PlanTransaction::begin called");
+
+        self.session.begin()
+    }
+
+    fn node_create_query(
+        &mut self,
+        rel_create_fragments: Vec<String>,
+        params: HashMap<String, Value>,
+        node_var: &NodeQueryVar,
+        props: HashMap<String, Value>,
+        clause: ClauseType,
+        _sg: &mut SuffixGenerator,
+    ) -> Result<(String, HashMap<String, Value>), Error> {
+        trace!("PlanTransaction::node_create_query called -- rel_create_fragments: {:#?}, node_var: {:#?}, props: {:#?}, clause: {:#?}",
+        rel_create_fragments, node_var, props, clause);
+
+        let fragment = Fragment::NodeCreate {
+            var: node_var.name().to_string(),
+            label: self.db_names.label(node_var.label()?).to_string(),
+            props: self.db_names.props(node_var.label().ok(), props),
+            rels: self
+                .plan
+                .indexes(rel_create_fragments.iter().map(String::as_str))?,
+        };
+
+        Ok((self.plan.push(fragment), params))
+    }
+
+    fn create_node(
+        &mut self,
+        query: String,
+        params: HashMap<String, Value>,
+        _partition_key_opt: Option<&Value>,
+        info: &Info,
+    ) -> Result<NodeRecord, Error> {
+        trace!(
+            "PlanTransaction::create_node called -- query: {}, params: {:#?}",
+            query,
+            params
+        );
+
+        self.read_node_records(&query, info)?
+            .into_iter()
+            .next()
+            .ok_or_else(|| Error::ResponseSetNotFound)
+    }
+
+    fn rel_create_fragment(
+        &mut self,
+        dst_query: &str,
+        params: HashMap<String, Value>,
+        rel_var: &RelQueryVar,
+        props: HashMap<String, Value>,
+        clause: ClauseType,
+        _sg: &mut SuffixGenerator,
+    ) -> Result<(String, HashMap<String, Value>), Error> {
+        trace!("PlanTransaction::rel_create_fragment called -- dst_query: {}, rel_var: {:#?}, props: {:#?}, clause: {:#?}",
+        dst_query, rel_var, props, clause);
+
+        let fragment = Fragment::RelCreate {
+            src_var: rel_var.src().name().to_string(),
+            rel_type: self
+                .db_names
+                .rel_type(rel_var.src().label().ok(), rel_var.label())
+                .to_string(),
+            props: self
+                .db_names
+                .rel_props(rel_var.src().label().ok(), rel_var.label(), props),
+            dst: self.plan.index(dst_query)?,
+        };
+
+        Ok((self.plan.push(fragment), params))
+    }
+
+    fn rel_create_query(
+        &mut self,
+        src_query_opt: Option<String>,
+        rel_create_fragments: Vec<String>,
+        params: HashMap<String, Value>,
+        rel_vars: Vec<RelQueryVar>,
+        _clause: ClauseType,
+    ) -> Result<(String, HashMap<String, Value>), Error> {
+        trace!("PlanTransaction::rel_create_query called -- src_query_opt: {:#?}, rel_create_fragments: {:#?}, rel_vars: {:#?}",
+        src_query_opt, rel_create_fragments, rel_vars);
+
+        let src_var = rel_vars.first().ok_or_else(|| Error::LabelNotFound)?.src();
+        let fragment = Fragment::RelCreateQuery {
+            src_var: src_var.name().to_string(),
+            src: src_query_opt.map(|q| self.plan.index(&q)).transpose()?,
+            rels: self
+                .plan
+                .indexes(rel_create_fragments.iter().map(String::as_str))?,
+        };
+
+        Ok((self.plan.push(fragment), params))
+    }
+
+    fn create_rels(
+        &mut self,
+        query: String,
+        params: HashMap<String, Value>,
+        props_type_name: Option<&str>,
+        partition_key_opt: Option<&Value>,
+    ) -> Result<Vec<RelRecord>, Error> {
+        trace!("PlanTransaction::create_rels called -- query: {}, params: {:#?}, props_type_name: {:#?}, partition_key_opt: {:#?}",
+        query, params, props_type_name, partition_key_opt);
+
+        self.read_rel_records(&query, props_type_name, partition_key_opt)
+    }
+
+    fn node_read_fragment(
+        &mut self,
+        rel_query_fragments: Vec<(String, String)>,
+        params: HashMap<String, Value>,
+        node_var: &NodeQueryVar,
+        props: HashMap<String, Value>,
+        clause: ClauseType,
+        _sg: &mut SuffixGenerator,
+    ) -> Result<(String, String, HashMap<String, Value>), Error> {
+        trace!("PlanTransaction::node_read_fragment called -- rel_query_fragments: {:#?}, node_var: {:#?}, props: {:#?}, clause: {:#?}",
+        rel_query_fragments, node_var, props, clause);
+
+        let fragment = Fragment::NodeMatch {
+            var: node_var.name().to_string(),
+            label: node_var
+                .label()
+                .ok()
+                .map(|l| self.db_names.label(l).to_string()),
+            props: self.db_names.props(node_var.label().ok(), props),
+            rels: self
+                .plan
+                .indexes(rel_query_fragments.iter().map(|rqf| rqf.0.as_str()))?,
+            limit: None,
+        };
+
+        Ok((self.plan.push(fragment), String::new(), params))
+    }
+
+    fn node_read_query(
+        &mut self,
+        match_fragment: &str,
+        where_fragment: &str,
+        params: HashMap<String, Value>,
+        _node_var: &NodeQueryVar,
+        clause: ClauseType,
+        limit: Option<usize>,
+    ) -> Result<(String, HashMap<String, Value>), Error> {
+        trace!("PlanTransaction::node_read_query called -- match_fragment: {}, where_fragment: {}, clause: {:#?}, limit: {:#?}",
+        match_fragment, where_fragment, clause, limit);
+
+        if let ClauseType::Query = clause {
+            self.plan.set_limit(match_fragment, limit)?;
+        }
+        Ok((match_fragment.to_string(), params))
+    }
+
+    fn read_nodes(
+        &mut self,
+        query: String,
+        params_opt: Option<HashMap<String, Value>>,
+        _partition_key_opt: Option<&Value>,
+        info: &Info,
+    ) -> Result<Vec<NodeRecord>, Error> {
+        trace!(
+            "PlanTransaction::read_nodes called -- query: {}, params_opt: {:#?}, info.name: {}",
+            query,
+            params_opt,
+            info.name()
+        );
+
+        self.read_node_records(&query, info)
+    }
+
+    fn rel_read_fragment(
+        &mut self,
+        src_query_opt: Option<(String, String)>,
+        dst_query_opt: Option<(String, String)>,
+        params: HashMap<String, Value>,
+        rel_var: &RelQueryVar,
+        props: HashMap<String, Value>,
+        _sg: &mut SuffixGenerator,
+    ) -> Result<(String, String, HashMap<String, Value>), Error> {
+        trace!("PlanTransaction::rel_read_fragment called -- src_query_opt: {:#?}, dst_query_opt: {:#?}, rel_var: {:#?}, props: {:#?}",
+        src_query_opt, dst_query_opt, rel_var, props);
+
+        let fragment = Fragment::RelMatch {
+            src_var: rel_var.src().name().to_string(),
+            dst_var: rel_var.dst().name().to_string(),
+            src_label: rel_var
+                .src()
+                .label()
+                .ok()
+                .map(|l| self.db_names.label(l).to_string()),
+            rel_type: self
+                .db_names
+                .rel_type(rel_var.src().label().ok(), rel_var.label())
+                .to_string(),
+            props: self
+                .db_names
+                .rel_props(rel_var.src().label().ok(), rel_var.label(), props),
+            src: src_query_opt.map(|q| self.plan.index(&q.0)).transpose()?,
+            dst: dst_query_opt.map(|q| self.plan.index(&q.0)).transpose()?,
+            limit: None,
+        };
+
+        Ok((self.plan.push(fragment), String::new(), params))
+    }
+
+    fn rel_read_query(
+        &mut self,
+        match_fragment: &str,
+        where_fragment: &str,
+        params: HashMap<String, Value>,
+        rel_var: &RelQueryVar,
+        clause: ClauseType,
+        limit: Option<usize>,
+    ) -> Result<(String, HashMap<String, Value>), Error> {
+        trace!("PlanTransaction::rel_read_query called -- match_fragment: {}, where_fragment: {}, rel_var: {:#?}, clause: {:#?}, limit: {:#?}",
+        match_fragment, where_fragment, rel_var, clause, limit);
+
+        if let ClauseType::Query = clause {
+            self.plan.set_limit(match_fragment, limit)?;
+        }
+        Ok((match_fragment.to_string(), params))
+    }
+
+    fn read_rels(
+        &mut self,
+        query: String,
+        params_opt: Option<HashMap<String, Value>>,
+        props_type_name: Option<&str>,
+        partition_key_opt: Option<&Value>,
+    ) -> Result<Vec<RelRecord>, Error> {
+        trace!("PlanTransaction::read_rels called -- query: {}, props_type_name: {:#?}, partition_key_opt: {:#?}, params_opt: {:#?}",
+        query, props_type_name, partition_key_opt, params_opt);
+
+        self.read_rel_records(&query, props_type_name, partition_key_opt)
+    }
+
+    fn node_update_query(
+        &mut self,
+        match_query: String,
+        change_queries: Vec<String>,
+        params: HashMap<String, Value>,
+        node_var: &NodeQueryVar,
+        props: HashMap<String, Value>,
+        _clause: ClauseType,
+        _sg: &mut SuffixGenerator,
+    ) -> Result<(String, HashMap<String, Value>), Error> {
+        trace!("PlanTransaction::node_update_query called -- match_query: {}, change_queries: {:#?}, node_var: {:#?}, props: {:#?}",
+        match_query, change_queries, node_var, props);
+
+        let fragment = Fragment::NodeUpdate {
+            var: node_var.name().to_string(),
+            matched: self.plan.index(&match_query)?,
+            props: self.db_names.props(node_var.label().ok(), props),
+            changes: self
+                .plan
+                .indexes(change_queries.iter().map(String::as_str))?,
+        };
+
+        Ok((self.plan.push(fragment), params))
+    }
+
+    fn update_nodes(
+        &mut self,
+        query: String,
+        params: HashMap<String, Value>,
+        _partition_key_opt: Option<&Value>,
+        info: &Info,
+    ) -> Result<Vec<NodeRecord>, Error> {
+        trace!(
+            "PlanTransaction::update_nodes called -- query: {}, params: {:#?}",
+            query,
+            params
+        );
+
+        self.read_node_records(&query, info)
+    }
+
+    fn rel_update_query(
+        &mut self,
+        match_query: String,
+        params: HashMap<String, Value>,
+        rel_var: &RelQueryVar,
+        props: HashMap<String, Value>,
+        _clause: ClauseType,
+        _sg: &mut SuffixGenerator,
+    ) -> Result<(String, HashMap<String, Value>), Error> {
+        trace!("PlanTransaction::rel_update_query called -- match_query: {}, rel_var: {:#?}, props: {:#?}",
+        match_query, rel_var, props);
+
+        let fragment = Fragment::RelUpdate {
+            matched: self.plan.index(&match_query)?,
+            props: self
+                .db_names
+                .rel_props(rel_var.src().label().ok(), rel_var.label(), props),
+        };
+
+        Ok((self.plan.push(fragment), params))
+    }
+
+    fn update_rels(
+        &mut self,
+        query: String,
+        params: HashMap<String, Value>,
+        props_type_name: Option<&str>,
+        partition_key_opt: Option<&Value>,
+    ) -> Result<Vec<RelRecord>, Error> {
+        trace!("PlanTransaction::update_rels called -- query: {}, params: {:#?}, props_type_name: {:#?}, partition_key_opt: {:#?}",
+        query, params, props_type_name, partition_key_opt);
+
+        self.read_rel_records(&query, props_type_name, partition_key_opt)
+    }
+
+    fn node_delete_query(
+        &mut self,
+        match_query: String,
+        rel_delete_fragments: Vec<String>,
+        params: HashMap<String, Value>,
+        node_var: &NodeQueryVar,
+        _clause: ClauseType,
+        _sg: &mut SuffixGenerator,
+    ) -> Result<(String, HashMap<String, Value>), Error> {
+        trace!("PlanTransaction::node_delete_query called -- match_query: {}, rel_delete_fragments: {:#?}, node_var: {:#?}",
+        match_query, rel_delete_fragments, node_var);
+
+        let fragment = Fragment::NodeDelete {
+            var: node_var.name().to_string(),
+            matched: self.plan.index(&match_query)?,
+            rels: self
+                .plan
+                .indexes(rel_delete_fragments.iter().map(String::as_str))?,
+        };
+
+        Ok((self.plan.push(fragment), params))
+    }
+
+    fn delete_nodes(
+        &mut self,
+        query: String,
+        params: HashMap<String, Value>,
+        _partition_key_opt: Option<&Value>,
+    ) -> Result<i32, Error> {
+        trace!(
+            "PlanTransaction::delete_nodes called -- query: {}, params: {:#?}",
+            query,
+            params
+        );
+
+        self.count(&query)
+    }
+
+    fn rel_delete_query(
+        &mut self,
+        match_query: String,
+        src_delete_query_opt: Option<String>,
+        dst_delete_query_opt: Option<String>,
+        params: HashMap<String, Value>,
+        rel_var: &RelQueryVar,
+        _clause: ClauseType,
+        _sg: &mut SuffixGenerator,
+    ) -> Result<(String, HashMap<String, Value>), Error> {
+        trace!("PlanTransaction::rel_delete_query called -- match_query: {}, src_delete_query_opt: {:#?}, dst_delete_query_opt: {:#?}, rel_var: {:#?}",
+        match_query, src_delete_query_opt, dst_delete_query_opt, rel_var);
+
+        let fragment = Fragment::RelDelete {
+            matched: self.plan.index(&match_query)?,
+            src: src_delete_query_opt
+                .map(|q| self.plan.index(&q))
+                .transpose()?,
+            dst: dst_delete_query_opt
+                .map(|q| self.plan.index(&q))
+                .transpose()?,
+        };
+
+        Ok((self.plan.push(fragment), params))
+    }
+
+    fn delete_rels(
+        &mut self,
+        query: String,
+        params: HashMap<String, Value>,
+        _partition_key_opt: Option<&Value>,
+    ) -> Result<i32, Error> {
+        trace!(
+            "PlanTransaction::delete_rels called -- query: {}, params: {:#?}",
+            query,
+            params
+        );
+
+        self.count(&query)
+    }
+
+    fn commit(&mut self) -> Result<(), Error> {
+        debug!("PlanTransaction::commit called");
+
+        self.session.commit()
+    }
+
+    fn rollback(&mut self) -> Result<(), Error> {
+        debug!("PlanTransaction::rollback called");
+
+        self.session.rollback()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(any(feature = "postgres", feature = "sqlite"))]
+    use super::scalar_props;
+    use super::{
+        merge_props, props_match, Bindings, Fragment, GraphStore, NodeFilter, Outcome, Plan,
+        RelFilter, StoredNode, StoredRel,
+    };
+    use crate::engine::value::Value;
+    use crate::Error;
+    use maplit::hashmap;
+    use std::cell::Cell;
+    use std::collections::HashMap;
+    use uuid::Uuid;
+
+    /// A graph store that counts the queries made of it
+    #[derive(Default)]
+    struct MockStore {
+        nodes: Vec<StoredNode>,
+        rels: Vec<StoredRel>,
+        node_calls: Cell<usize>,
+        nodes_calls: Cell<usize>,
+    }
+
+    impl MockStore {
+        fn with_node(mut self, id: &str, label: &str, props: HashMap<String, Value>) -> Self {
+            self.add_node(id, label, props).unwrap();
+            self
+        }
+
+        fn with_rel(mut self, rel_type: &str, src_id: &str, dst_id: &str) -> Self {
+            self.add_rel(rel_type, src_id, dst_id, HashMap::new())
+                .unwrap();
+            self
+        }
+    }
+
+    impl GraphStore for MockStore {
+        fn labels(&self) -> Result<Vec<String>, Error> {
+            Ok(self.nodes.iter().map(|n| n.label.to_string()).collect())
+        }
+
+        fn node(&self, id: &str) -> Result<Option<StoredNode>, Error> {
+            self.node_calls.set(self.node_calls.get() + 1);
+            Ok(self.nodes.iter().find(|n| n.id == id).cloned())
+        }
+
+        fn nodes(&self, filter: &NodeFilter) -> Result<Vec<StoredNode>, Error> {
+            self.nodes_calls.set(self.nodes_calls.get() + 1);
+            Ok(self
+                .nodes
+                .iter()
+                .filter(|n| filter.matches(n))
+                .take(filter.limit.unwrap_or(usize::MAX))
+                .cloned()
+                .collect())
+        }
+
+        fn rel(&self, id: &str) -> Result<Option<StoredRel>, Error> {
+            Ok(self.rels.iter().find(|r| r.id == id).cloned())
+        }
+
+        fn rels(&self, filter: &RelFilter) -> Result<Vec<StoredRel>, Error> {
+            Ok(self
+                .rels
+                .iter()
+                .filter(|r| {
+                    filter.matches(r)
+                        && filter.src_label.map_or(true, |l| {
+                            self.nodes.iter().any(|n| n.id == r.src_id && n.label == l)
+                        })
+                })
+                .cloned()
+                .collect())
+        }
+
+        fn add_node(
+            &mut self,
+            id: &str,
+            label: &str,
+            props: HashMap<String, Value>,
+        ) -> Result<(), Error> {
+            self.nodes.push(StoredNode {
+                id: id.to_string(),
+                label: label.to_string(),
+                props,
+            });
+            Ok(())
+        }
+
+        fn add_rel(
+            &mut self,
+            rel_type: &str,
+            src_id: &str,
+            dst_id: &str,
+            props: HashMap<String, Value>,
+        ) -> Result<String, Error> {
+            let id = Uuid::new_v4().to_string();
+            self.rels.push(StoredRel {
+                id: id.clone(),
+                rel_type: rel_type.to_string(),
+                src_id: src_id.to_string(),
+                dst_id: dst_id.to_string(),
+                props,
+            });
+            Ok(id)
+        }
+
+        fn set_node_props(&mut self, id: &str, props: HashMap<String, Value>) -> Result<(), Error> {
+            if let Some(n) = self.nodes.iter_mut().find(|n| n.id == id) {
+                merge_props(&mut n.props, props);
+            }
+            Ok(())
+        }
+
+        fn set_rel_props(&mut self, id: &str, props: HashMap<String, Value>) -> Result<(), Error> {
+            if let Some(r) = self.rels.iter_mut().find(|r| r.id == id) {
+                merge_props(&mut r.props, props);
+            }
+            Ok(())
+        }
+
+        fn remove_node(&mut self, id: &str) -> Result<(), Error> {
+            self.nodes.retain(|n| n.id != id);
+            self.rels.retain(|r| r.src_id != id && r.dst_id != id);
+            Ok(())
+        }
+
+        fn remove_rel(&mut self, id: &str) -> Result<(), Error> {
+            self.rels.retain(|r| r.id != id);
+            Ok(())
+        }
+    }
+
+    fn mock_store() -> MockStore {
+        MockStore::default()
+            .with_node(
+                "1",
+                "User",
+                hashmap! {"name".to_string() => Value::String("alice".to_string())},
+            )
+            .with_node(
+                "2",
+                "User",
+                hashmap! {"name".to_string() => Value::String("bob".to_string())},
+            )
+            .with_node(
+                "3",
+                "Team",
+                hashmap! {"name".to_string() => Value::String("alice".to_string())},
+            )
+            .with_rel("MEMBER", "1", "3")
+            .with_rel("MEMBER", "3", "2")
+    }
+
+    fn node_ids(outcome: Outcome) -> Vec<String> {
+        match outcome {
+            Outcome::Nodes(ids) => ids,
+            _ => panic!("Expected nodes"),
+        }
+    }
+
+    /// Passes if numeric values are equal across integer and float types, and uuids equal their
+    /// string forms
+    #[test]
+    fn props_match_across_types() {
+        let u = Uuid::new_v4();
+        let stored = hashmap! {
+            "count".to_string() => Value::Float64(3.0),
+            "size".to_string() => Value::UInt64(5),
+            "key".to_string() => Value::Uuid(u),
+        };
+
+        assert!(props_match(
+            "1",
+            &stored,
+            &hashmap! {
+                "id".to_string() => Value::String("1".to_string()),
+                "count".to_string() => Value::Int64(3),
+                "size".to_string() => Value::Int64(5),
+                "key".to_string() => Value::String(u.to_hyphenated().to_string()),
+            }
+        ));
+        assert!(!props_match(
+            "1",
+            &stored,
+            &hashmap! {"count".to_string() => Value::String("3".to_string())}
+        ));
+        assert!(!props_match(
+            "1",
+            &stored,
+            &hashmap! {"missing".to_string() => Value::Null}
+        ));
+    }
+
+    /// Passes if only scalar predicates that can be compared exactly are offered to stores
+    #[cfg(any(feature = "postgres", feature = "sqlite"))]
+    #[test]
+    fn scalar_props_split() {
+        let props = hashmap! {
+            "name".to_string() => Value::String("alice".to_string()),
+            "active".to_string() => Value::Bool(true),
+        };
+        let (scalars, exact) = scalar_props(Some(&props));
+        assert_eq!(scalars.len(), 2);
+        assert!(exact);
+
+        let props = hashmap! {
+            "name".to_string() => Value::String("alice".to_string()),
+            "score".to_string() => Value::Float64(1.5),
+            "tags".to_string() => Value::Array(vec![Value::String("a".to_string())]),
+            "big".to_string() => Value::UInt64(u64::MAX),
+        };
+        let (scalars, exact) = scalar_props(Some(&props));
+        assert_eq!(scalars, vec![("name", &props["name"])]);
+        assert!(!exact);
+
+        assert_eq!(scalar_props(None), (Vec::new(), true));
+    }
+
+    /// Passes if a node match passes its label and properties to the store, in a single query
+    #[test]
+    fn node_match_filters_in_store() {
+        let mut g = mock_store();
+        let mut plan = Plan::default();
+        plan.push(Fragment::NodeMatch {
+            var: "n".to_string(),
+            label: Some("User".to_string()),
+            props: hashmap! {"name".to_string() => Value::String("alice".to_string())},
+            rels: Vec::new(),
+            limit: None,
+        });
+
+        let ids = node_ids(plan.execute(&mut g, 0, &Bindings::new()).unwrap());
+        assert_eq!(ids, vec!["1".to_string()]);
+        assert_eq!(g.nodes_calls.get(), 1);
+        assert_eq!(g.node_calls.get(), 0);
+    }
+
+    /// Passes if a node match on a bound variable, or on an id, looks up the node by id and
+    /// still checks its label and properties
+    #[test]
+    fn node_match_by_id() {
+        let mut g = mock_store();
+        let mut plan = Plan::default();
+        plan.push(Fragment::NodeMatch {
+            var: "n".to_string(),
+            label: Some("User".to_string()),
+            props: hashmap! {"id".to_string() => Value::String("3".to_string())},
+            rels: Vec::new(),
+            limit: None,
+        });
+
+        assert!(node_ids(plan.execute(&mut g, 0, &Bindings::new()).unwrap()).is_empty());
+        assert_eq!(g.nodes_calls.get(), 0);
+        assert_eq!(g.node_calls.get(), 1);
+    }
+
+    /// Passes if the source label of a relationship match is tested by the store, rather than
+    /// by looking up the source node of each relationship
+    #[test]
+    fn rel_match_src_label_in_store() {
+        let mut g = mock_store();
+        let mut plan = Plan::default();
+        plan.push(Fragment::RelMatch {
+            src_var: "src".to_string(),
+            dst_var: "dst".to_string(),
+            src_label: Some("User".to_string()),
+            rel_type: "MEMBER".to_string(),
+            props: HashMap::new(),
+            src: None,
+            dst: None,
+            limit: None,
+        });
+
+        match plan.execute(&mut g, 0, &Bindings::new()).unwrap() {
+            Outcome::Rels(ids) => assert_eq!(ids, vec![g.rels[0].id.to_string()]),
+            _ => panic!("Expected rels"),
+        }
+        assert_eq!(g.node_calls.get(), 0);
+    }
+
+    /// Passes if a node match nested in a relationship match constrains the relationships to
+    /// those whose destination node matches
+    #[test]
+    fn node_match_nested_rels() {
+        let mut g = mock_store();
+        let mut plan = Plan::default();
+        plan.push(Fragment::NodeMatch {
+            var: "dst".to_string(),
+            label: Some("Team".to_string()),
+            props: HashMap::new(),
+            rels: Vec::new(),
+            limit: None,
+        });
+        plan.push(Fragment::RelMatch {
+            src_var: "n".to_string(),
+            dst_var: "dst".to_string(),
+            src_label: Some("User".to_string()),
+            rel_type: "MEMBER".to_string(),
+            props: HashMap::new(),
+            src: None,
+            dst: Some(0),
+            limit: None,
+        });
+        plan.push(Fragment::NodeMatch {
+            var: "n".to_string(),
+            label: Some("User".to_string()),
+            props: HashMap::new(),
+            rels: vec![1],
+            limit: None,
+        });
+
+        let ids = node_ids(plan.execute(&mut g, 2, &Bindings::new()).unwrap());
+        assert_eq!(ids, vec!["1".to_string()]);
+    }
+
+    /// Passes if the limit of a node match is passed to the store, and the limit of a
+    /// relationship match stops the match once it is reached
+    #[test]
+    fn match_limit() {
+        let mut g = mock_store();
+        let mut plan = Plan::default();
+        let node_query = plan.push(Fragment::NodeMatch {
+            var: "n".to_string(),
+            label: Some("User".to_string()),
+            props: HashMap::new(),
+            rels: Vec::new(),
+            limit: None,
+        });
+        let rel_query = plan.push(Fragment::RelMatch {
+            src_var: "src".to_string(),
+            dst_var: "dst".to_string(),
+            src_label: None,
+            rel_type: "MEMBER".to_string(),
+            props: HashMap::new(),
+            src: None,
+            dst: None,
+            limit: None,
+        });
+        plan.set_limit(&node_query, Some(1)).unwrap();
+        plan.set_limit(&rel_query, Some(1)).unwrap();
+
+        let ids = node_ids(plan.execute(&mut g, 0, &Bindings::new()).unwrap());
+        assert_eq!(ids, vec!["1".to_string()]);
+        assert_eq!(g.nodes_calls.get(), 1);
+
+        match plan.execute(&mut g, 1, &Bindings::new()).unwrap() {
+            Outcome::Rels(ids) => assert_eq!(ids.len(), 1),
+            _ => panic!("Expected rels"),
+        }
+    }
+
+    /// Passes if creating a node with the id of an existing node fails, and a node created
+    /// without an id is given one
+    #[test]
+    fn node_create_ids() {
+        let mut g = mock_store();
+        let mut plan = Plan::default();
+        plan.push(Fragment::NodeCreate {
+            var: "n".to_string(),
+            label: "User".to_string(),
+            props: hashmap! {"id".to_string() => Value::String("1".to_string())},
+            rels: Vec::new(),
+        });
+        plan.push(Fragment::NodeCreate {
+            var: "n".to_string(),
+            label: "User".to_string(),
+            props: HashMap::new(),
+            rels: Vec::new(),
+        });
+
+        assert!(matches!(
+            plan.execute(&mut g, 0, &Bindings::new()),
+            Err(Error::IdDuplicated { .. })
+        ));
+        let ids = node_ids(plan.execute(&mut g, 1, &Bindings::new()).unwrap());
+        assert_eq!(ids.len(), 1);
+        assert_eq!(g.nodes.len(), 4);
+    }
+}
@@ -0,0 +1,877 @@
+//! Provides a PostgreSQL graph database back-end, storing a graph in relational tables.
+
+use crate::engine::database::inference::ModelInference;
+use crate::engine::database::plan::{
+    self, merge_props, GraphSession, GraphStore, NodeFilter, PlanTransaction, RelFilter,
+    StoredNode, StoredRel,
+};
+use crate::engine::database::{
+    env_opt, env_string, env_u16, DatabaseEndpoint, DatabasePool, PoolOptions,
+};
+use crate::engine::value::Value;
+use crate::Error;
+use async_trait::async_trait;
+use log::{debug, trace};
+use native_tls::TlsConnector;
+use postgres_client::config::SslMode;
+use postgres_client::{Client, Config, Row};
+use postgres_native_tls::MakeTlsConnector;
+use r2d2::{Pool, PooledConnection};
+use r2d2_postgres::PostgresConnectionManager;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::fmt::{self, Debug, Formatter};
+use std::panic;
+use std::sync::Arc;
+use tokio::task;
+use uuid::Uuid;
+
+/// Creates the tables in which a graph is stored, if they do not already exist. Each node and
+/// relationship is a row, with its properties held in a JSONB object. The `seq` columns preserve
+/// the order in which nodes and relationships were created, and the GIN index on node properties
+/// serves the containment tests with which reads filter nodes. The advisory lock keeps pools that
+/// are created at the same time from racing to create the tables. The `{nodes}` and `{edges}`
+/// placeholders are replaced by the names of the tables, and `{nodes_index}` and `{edges_index}`
+/// by the prefixes of the names of their indexes.
+const SCHEMA: &str = "
+BEGIN;
+SELECT pg_advisory_xact_lock(hashtext('warpgrapher_schema'));
+CREATE TABLE IF NOT EXISTS {nodes} (
+    seq BIGSERIAL PRIMARY KEY,
+    id TEXT NOT NULL UNIQUE,
+    label TEXT NOT NULL,
+    props JSONB NOT NULL
+);
+CREATE INDEX IF NOT EXISTS {nodes_index}_label ON {nodes} (label);
+CREATE INDEX IF NOT EXISTS {nodes_index}_props ON {nodes} USING GIN (props jsonb_path_ops);
+CREATE TABLE IF NOT EXISTS {edges} (
+    seq BIGSERIAL PRIMARY KEY,
+    id TEXT NOT NULL UNIQUE,
+    rel_type TEXT NOT NULL,
+    src_id TEXT NOT NULL,
+    dst_id TEXT NOT NULL,
+    props JSONB NOT NULL
+);
+CREATE INDEX IF NOT EXISTS {edges_index}_src ON {edges} (src_id, rel_type);
+CREATE INDEX IF NOT EXISTS {edges_index}_dst ON {edges} (dst_id, rel_type);
+COMMIT;
+";
+
+/// The names of the tables in which a graph is stored
+#[derive(Debug)]
+struct Tables {
+    nodes: String,
+    edges: String,
+}
+
+impl Tables {
+    /// Returns the names of the tables, if each is a table name, optionally qualified by the name
+    /// of a schema, made up of ASCII letters, digits, and underscores
+    fn new(nodes: &str, edges: &str) -> Result<Tables, Error> {
+        for name in [nodes, edges].iter() {
+            let parts: Vec<&str> = name.split('.').collect();
+            if parts.len() > 2
+                || !parts.iter().all(|p| {
+                    p.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+                        && p.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+                })
+            {
+                return Err(Error::PostgresTableNameInvalid {
+                    name: name.to_string(),
+                });
+            }
+        }
+
+        Ok(Tables {
+            nodes: nodes.to_string(),
+            edges: edges.to_string(),
+        })
+    }
+
+    /// Returns a statement with the `{nodes}` and `{edges}` placeholders replaced by the names of
+    /// the tables
+    fn sql(&self, statement: &str) -> String {
+        statement
+            .replace("{nodes}", &self.nodes)
+            .replace("{edges}", &self.edges)
+    }
+
+    /// Returns the statements that create the tables
+    fn schema(&self) -> String {
+        // Indexes are created in the schema of their table, so their names are not qualified
+        let index = |table: &str| table.rsplit('.').next().unwrap_or(table).to_string();
+        self.sql(
+            &SCHEMA
+                .replace("{nodes_index}", &index(&self.nodes))
+                .replace("{edges_index}", &index(&self.edges)),
+        )
+    }
+}
+
+/// A pool of connections to a PostgreSQL database, along with the names of the tables in which
+/// the graph is stored
+#[derive(Clone)]
+pub struct PostgresGraph {
+    pool: Pool<PostgresConnectionManager<MakeTlsConnector>>,
+    tables: Arc<Tables>,
+}
+
+impl PostgresGraph {
+    /// Returns the pool of connections to the database
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use warpgrapher::engine::database::DatabaseEndpoint;
+    /// # use warpgrapher::engine::database::postgres::PostgresEndpoint;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let pool = PostgresEndpoint::from_env()?.pool().await?;
+    /// let conn = pool.postgres()?.pool().get()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn pool(&self) -> &Pool<PostgresConnectionManager<MakeTlsConnector>> {
+        &self.pool
+    }
+
+    /// Checks that a connection can be obtained and used to run a statement. The PostgreSQL
+    /// client blocks while it waits for the server, so the check runs on a thread on which
+    /// blocking is expected, rather than on the async executor.
+    pub(crate) async fn health(&self) -> Result<(), Error> {
+        let pool = self.pool.clone();
+        blocking(move || {
+            pool.get()
+                .map_err(|e| Error::PostgresPoolError { source: e })?
+                .batch_execute("SELECT 1")?;
+            Ok(())
+        })
+        .await
+    }
+}
+
+impl Debug for PostgresGraph {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PostgresGraph")
+            .field("pool", &self.pool.state())
+            .field("tables", &self.tables)
+            .finish()
+    }
+}
+
+/// Runs a function that blocks on the PostgreSQL client on the thread pool of the tokio runtime
+/// for blocking operations, and returns its result
+async fn blocking<T, F>(f: F) -> Result<T, Error>
+where
+    T: Send + 'static,
+    F: FnOnce() -> Result<T, Error> + Send + 'static,
+{
+    task::spawn_blocking(f)
+        .await
+        .unwrap_or_else(|e| panic::resume_unwind(e.into_panic()))
+}
+
+/// A PostgreSQL endpoint collects the information necessary to build a pool of connections to a
+/// PostgreSQL database.
+///
+/// # Examples
+///
+/// ```rust
+/// # use warpgrapher::engine::database::postgres::PostgresEndpoint;
+///
+/// let pe = PostgresEndpoint::new(
+///     "127.0.0.1".to_string(),
+///     5432,
+///     "postgres".to_string(),
+///     "password".to_string(),
+///     "warpgrapher".to_string()
+/// );
+/// ```
+pub struct PostgresEndpoint {
+    host: String,
+    port: u16,
+    user: String,
+    pass: String,
+    database: String,
+    tls: bool,
+    nodes_table: String,
+    edges_table: String,
+    pool_options: PoolOptions,
+}
+
+impl PostgresEndpoint {
+    /// Returns a new [`PostgresEndpoint`] from the provided values.
+    ///
+    /// [`PostgresEndpoint`]: ./struct.PostgresEndpoint.html
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use warpgrapher::engine::database::postgres::PostgresEndpoint;
+    ///
+    /// let pe = PostgresEndpoint::new(
+    ///     "127.0.0.1".to_string(),
+    ///     5432,
+    ///     "postgres".to_string(),
+    ///     "password".to_string(),
+    ///     "warpgrapher".to_string()
+    /// );
+    /// ```
+    pub fn new(
+        host: String,
+        port: u16,
+        user: String,
+        pass: String,
+        database: String,
+    ) -> PostgresEndpoint {
+        PostgresEndpoint {
+            host,
+            port,
+            user,
+            pass,
+            database,
+            tls: false,
+            nodes_table: "nodes".to_string(),
+            edges_table: "edges".to_string(),
+            pool_options: PoolOptions::default(),
+        }
+    }
+
+    /// Reads environment variables to construct a [`PostgresEndpoint`]. The environment
+    /// variables are
+    ///
+    /// * WG_POSTGRES_HOST - the address of the PostgreSQL server. For example, `127.0.0.1`.
+    /// * WG_POSTGRES_PORT - the port number of the PostgreSQL server. For example, `5432`.
+    /// * WG_POSTGRES_USER - the username for the PostgreSQL server. For example, `postgres`.
+    /// * WG_POSTGRES_PASS - the password for the PostgreSQL server. For example, `my-db-pass`.
+    /// * WG_POSTGRES_DB - the name of the database holding the graph. For example, `warpgrapher`.
+    ///
+    /// The following environment variables are optional:
+    ///
+    /// * WG_POSTGRES_TLS - `true` to secure connections with TLS, as with [`with_tls`]. Defaults
+    ///   to `false`.
+    /// * WG_POSTGRES_NODES_TABLE - the name of the table holding nodes, as with
+    ///   [`with_table_names`]. Defaults to `nodes`.
+    /// * WG_POSTGRES_EDGES_TABLE - the name of the table holding relationships, as with
+    ///   [`with_table_names`]. Defaults to `edges`.
+    ///
+    /// Pool options are read from the WG_POOL_* variables described in [`PoolOptions::from_env`].
+    ///
+    /// [`PoolOptions::from_env`]: ../struct.PoolOptions.html#method.from_env
+    /// [`PostgresEndpoint`]: ./struct.PostgresEndpoint.html
+    /// [`with_table_names`]: ./struct.PostgresEndpoint.html#method.with_table_names
+    /// [`with_tls`]: ./struct.PostgresEndpoint.html#method.with_tls
+    ///
+    /// # Errors
+    ///
+    /// * [`EnvironmentVariableNotFound`] - if a required environment variable does not exist
+    /// * [`EnvironmentVariableIntNotParsed`] - if the port or a pool option cannot be parsed
+    /// * [`EnvironmentVariableBoolNotParsed`] - if WG_POSTGRES_TLS cannot be parsed
+    ///
+    /// [`EnvironmentVariableNotFound`]: ../../enum.Error.html#variant.EnvironmentVariableNotFound
+    /// [`EnvironmentVariableIntNotParsed`]: ../../enum.Error.html#variant.EnvironmentVariableIntNotParsed
+    /// [`EnvironmentVariableBoolNotParsed`]: ../../enum.Error.html#variant.EnvironmentVariableBoolNotParsed
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use warpgrapher::engine::database::postgres::PostgresEndpoint;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let pe = PostgresEndpoint::from_env()?;
+    /// #    Ok(())
+    /// # }
+    /// ```
+    pub fn from_env() -> Result<PostgresEndpoint, Error> {
+        Ok(PostgresEndpoint {
+            host: env_string("WG_POSTGRES_HOST")?,
+            port: env_u16("WG_POSTGRES_PORT")?,
+            user: env_string("WG_POSTGRES_USER")?,
+            pass: env_string("WG_POSTGRES_PASS")?,
+            database: env_string("WG_POSTGRES_DB")?,
+            tls: env_opt("WG_POSTGRES_TLS")?.unwrap_or(false),
+            nodes_table: env_string("WG_POSTGRES_NODES_TABLE")
+                .unwrap_or_else(|_| "nodes".to_string()),
+            edges_table: env_string("WG_POSTGRES_EDGES_TABLE")
+                .unwrap_or_else(|_| "edges".to_string()),
+            pool_options: PoolOptions::from_env()?,
+        })
+    }
+
+    /// Sets the options for the connection pool. The query timeout is applied to each statement
+    /// as the PostgreSQL `statement_timeout` setting.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::time::Duration;
+    /// # use warpgrapher::engine::database::PoolOptions;
+    /// # use warpgrapher::engine::database::postgres::PostgresEndpoint;
+    ///
+    /// let pe = PostgresEndpoint::new(
+    ///     "127.0.0.1".to_string(),
+    ///     5432,
+    ///     "postgres".to_string(),
+    ///     "password".to_string(),
+    ///     "warpgrapher".to_string()
+    /// )
+    /// .with_pool_options(
+    ///     PoolOptions::new()
+    ///         .with_max_size(32)
+    ///         .with_query_timeout(Duration::from_secs(10))
+    /// );
+    /// ```
+    pub fn with_pool_options(mut self, pool_options: PoolOptions) -> PostgresEndpoint {
+        self.pool_options = pool_options;
+        self
+    }
+
+    /// Sets the names of the tables holding nodes and relationships, which default to `nodes`
+    /// and `edges`. A name may be qualified by the name of a schema, such as `graph.nodes`, and
+    /// is made up of ASCII letters, digits, and underscores. Setting different names lets more
+    /// than one graph be stored in a database.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use warpgrapher::engine::database::postgres::PostgresEndpoint;
+    ///
+    /// let pe = PostgresEndpoint::new(
+    ///     "127.0.0.1".to_string(),
+    ///     5432,
+    ///     "postgres".to_string(),
+    ///     "password".to_string(),
+    ///     "warpgrapher".to_string()
+    /// )
+    /// .with_table_names("graph.nodes".to_string(), "graph.edges".to_string());
+    /// ```
+    pub fn with_table_names(mut self, nodes: String, edges: String) -> PostgresEndpoint {
+        self.nodes_table = nodes;
+        self.edges_table = edges;
+        self
+    }
+
+    /// Sets whether connections are secured with TLS. The server's certificate must be signed by
+    /// a certificate authority trusted by the system, and match the host name of the server.
+    /// Without TLS, which is the default, the database should be reached over a trusted network.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use warpgrapher::engine::database::postgres::PostgresEndpoint;
+    ///
+    /// let pe = PostgresEndpoint::new(
+    ///     "db.example.com".to_string(),
+    ///     5432,
+    ///     "postgres".to_string(),
+    ///     "password".to_string(),
+    ///     "warpgrapher".to_string()
+    /// )
+    /// .with_tls(true);
+    /// ```
+    pub fn with_tls(mut self, tls: bool) -> PostgresEndpoint {
+        self.tls = tls;
+        self
+    }
+}
+
+#[async_trait]
+impl DatabaseEndpoint for PostgresEndpoint {
+    async fn pool(&self) -> Result<DatabasePool, Error> {
+        let tables = Tables::new(&self.nodes_table, &self.edges_table)?;
+
+        let mut config = Config::new();
+        config
+            .host(&self.host)
+            .port(self.port)
+            .user(&self.user)
+            .password(&self.pass)
+            .dbname(&self.database)
+            .ssl_mode(if self.tls {
+                SslMode::Require
+            } else {
+                SslMode::Disable
+            });
+        if let Some(query_timeout) = self.pool_options.query_timeout {
+            config.options(&format!(
+                "-c statement_timeout={}",
+                query_timeout.as_millis()
+            ));
+        }
+        let connector = MakeTlsConnector::new(
+            TlsConnector::new().map_err(|e| Error::PostgresTlsFailed { source: e })?,
+        );
+
+        // Building the pool opens its first connections, which blocks while the client waits
+        // for the server, so it is kept off the async executor
+        let builder = self.pool_options.r2d2_builder(10)?;
+        let schema = tables.schema();
+        let pool = blocking(move || {
+            let pool = builder
+                .build(PostgresConnectionManager::new(config, connector))
+                .map_err(|e| Error::PostgresPoolError { source: e })?;
+            pool.get()
+                .map_err(|e| Error::PostgresPoolError { source: e })?
+                .batch_execute(&schema)?;
+            Ok(pool)
+        })
+        .await?;
+
+        let pool = DatabasePool::Postgres(PostgresGraph {
+            pool,
+            tables: Arc::new(tables),
+        });
+        if self.pool_options.health_check {
+            pool.health().await?;
+        }
+
+        trace!("PostgresEndpoint::pool -- pool: {:#?}", pool);
+        Ok(pool)
+    }
+}
+
+/// Samples up to `sample_size` nodes with each label in a PostgreSQL database, along with their
+/// outgoing relationships, and records their properties and relationships for inference of a
+/// [`Configuration`].
+///
+/// [`Configuration`]: ../../config/struct.Configuration.html
+pub(crate) fn infer_model(
+    graph: &PostgresGraph,
+    sample_size: usize,
+) -> Result<ModelInference, Error> {
+    trace!(
+        "postgres::infer_model called -- sample_size: {}",
+        sample_size
+    );
+
+    let mut conn = graph
+        .pool
+        .get()
+        .map_err(|e| Error::PostgresPoolError { source: e })?;
+    plan::infer_model(&PostgresStore::new(&mut conn, &graph.tables), sample_size)
+}
+
+fn props_to_json(props: HashMap<String, Value>) -> Result<serde_json::Value, Error> {
+    serde_json::Value::try_from(Value::Map(props))
+}
+
+fn props_from_json(json: serde_json::Value) -> Result<HashMap<String, Value>, Error> {
+    match Value::try_from(json)? {
+        Value::Map(m) => Ok(m),
+        v => Err(Error::TypeConversionFailed {
+            src: format!("{:#?}", v),
+            dst: "HashMap<String, Value>".to_string(),
+        }),
+    }
+}
+
+/// Returns the id predicate of a filter, and a JSON object of its scalar property predicates to
+/// be tested by containment, along with whether these test every predicate of the filter
+fn prop_predicates(
+    props: Option<&HashMap<String, Value>>,
+) -> Result<(Option<String>, serde_json::Value, bool), Error> {
+    let (scalars, mut exact) = plan::scalar_props(props);
+    let mut id = None;
+    let mut contained = serde_json::Map::new();
+    for (k, v) in scalars {
+        match (k, v) {
+            ("id", Value::String(s)) => id = Some(s.to_string()),
+            ("id", Value::Uuid(u)) => id = Some(u.to_hyphenated().to_string()),
+            ("id", _) => exact = false,
+            _ => {
+                contained.insert(k.to_string(), serde_json::Value::try_from(v.clone())?);
+            }
+        }
+    }
+    Ok((id, serde_json::Value::Object(contained), exact))
+}
+
+fn stored_node(row: &Row) -> Result<StoredNode, Error> {
+    Ok(StoredNode {
+        id: row.try_get(0)?,
+        label: row.try_get(1)?,
+        props: props_from_json(row.try_get(2)?)?,
+    })
+}
+
+fn stored_rel(row: &Row) -> Result<StoredRel, Error> {
+    Ok(StoredRel {
+        id: row.try_get(0)?,
+        rel_type: row.try_get(1)?,
+        src_id: row.try_get(2)?,
+        dst_id: row.try_get(3)?,
+        props: props_from_json(row.try_get(4)?)?,
+    })
+}
+
+/// Stores a graph through a PostgreSQL client. The client needs mutable access to run even a
+/// read-only statement, so it is held in a [`RefCell`].
+///
+/// [`RefCell`]: https://doc.rust-lang.org/std/cell/struct.RefCell.html
+struct PostgresStore<'c> {
+    client: RefCell<&'c mut Client>,
+    tables: &'c Tables,
+}
+
+impl<'c> PostgresStore<'c> {
+    fn new(client: &'c mut Client, tables: &'c Tables) -> PostgresStore<'c> {
+        PostgresStore {
+            client: RefCell::new(client),
+            tables,
+        }
+    }
+}
+
+impl GraphStore for PostgresStore<'_> {
+    fn labels(&self) -> Result<Vec<String>, Error> {
+        self.client
+            .borrow_mut()
+            .query(&self.tables.sql("SELECT DISTINCT label FROM {nodes}"), &[])?
+            .iter()
+            .map(|row| Ok(row.try_get(0)?))
+            .collect()
+    }
+
+    fn node(&self, id: &str) -> Result<Option<StoredNode>, Error> {
+        self.client
+            .borrow_mut()
+            .query_opt(
+                &self
+                    .tables
+                    .sql("SELECT id, label, props FROM {nodes} WHERE id = $1"),
+                &[&id],
+            )?
+            .as_ref()
+            .map(stored_node)
+            .transpose()
+    }
+
+    fn nodes(&self, filter: &NodeFilter) -> Result<Vec<StoredNode>, Error> {
+        let (id, props, exact) = prop_predicates(filter.props)?;
+
+        // The limit can only be applied by the query if every predicate is tested by it. A null
+        // limit returns all rows.
+        let limit = match (exact, filter.limit) {
+            (true, Some(limit)) => Some(i64::try_from(limit)?),
+            _ => None,
+        };
+        let mut nodes = Vec::new();
+        for row in self.client.borrow_mut().query(
+            &self.tables.sql(
+                "SELECT id, label, props FROM {nodes} \
+             WHERE ($1::TEXT IS NULL OR label = $1) \
+             AND ($2::TEXT IS NULL OR id = $2) \
+             AND props @> $3 \
+             ORDER BY seq LIMIT $4",
+            ),
+            &[&filter.label, &id, &props, &limit],
+        )? {
+            let n = stored_node(&row)?;
+            if filter.matches(&n) {
+                nodes.push(n);
+            }
+        }
+        nodes.truncate(filter.limit.unwrap_or(usize::MAX));
+        Ok(nodes)
+    }
+
+    fn rel(&self, id: &str) -> Result<Option<StoredRel>, Error> {
+        self.client
+            .borrow_mut()
+            .query_opt(
+                &self
+                    .tables
+                    .sql("SELECT id, rel_type, src_id, dst_id, props FROM {edges} WHERE id = $1"),
+                &[&id],
+            )?
+            .as_ref()
+            .map(stored_rel)
+            .transpose()
+    }
+
+    fn rels(&self, filter: &RelFilter) -> Result<Vec<StoredRel>, Error> {
+        let (id, props, _) = prop_predicates(filter.props)?;
+
+        let mut rels = Vec::new();
+        for row in self.client.borrow_mut().query(
+            &self.tables.sql(
+                "SELECT e.id, e.rel_type, e.src_id, e.dst_id, e.props FROM {edges} AS e \
+             WHERE ($1::TEXT IS NULL OR e.rel_type = $1) \
+             AND ($2::TEXT IS NULL OR e.src_id = $2) \
+             AND ($3::TEXT IS NULL OR e.dst_id = $3) \
+             AND ($4::TEXT IS NULL OR EXISTS \
+                 (SELECT 1 FROM {nodes} AS n WHERE n.id = e.src_id AND n.label = $4)) \
+             AND ($5::TEXT IS NULL OR e.id = $5) \
+             AND e.props @> $6 \
+             ORDER BY e.seq",
+            ),
+            &[
+                &filter.rel_type,
+                &filter.src_id,
+                &filter.dst_id,
+                &filter.src_label,
+                &id,
+                &props,
+            ],
+        )? {
+            let r = stored_rel(&row)?;
+            if filter.matches(&r) {
+                rels.push(r);
+            }
+        }
+        Ok(rels)
+    }
+
+    fn add_node(
+        &mut self,
+        id: &str,
+        label: &str,
+        props: HashMap<String, Value>,
+    ) -> Result<(), Error> {
+        let mut stored = HashMap::new();
+        merge_props(&mut stored, props);
+        self.client.get_mut().execute(
+            &self
+                .tables
+                .sql("INSERT INTO {nodes} (id, label, props) VALUES ($1, $2, $3)"),
+            &[&id, &label, &props_to_json(stored)?],
+        )?;
+        Ok(())
+    }
+
+    fn add_rel(
+        &mut self,
+        rel_type: &str,
+        src_id: &str,
+        dst_id: &str,
+        props: HashMap<String, Value>,
+    ) -> Result<String, Error> {
+        let id = Uuid::new_v4().to_string();
+        let mut stored = HashMap::new();
+        merge_props(&mut stored, props);
+        self.client.get_mut().execute(
+            &self.tables.sql("INSERT INTO {edges} (id, rel_type, src_id, dst_id, props) VALUES ($1, $2, $3, $4, $5)"),
+            &[&id, &rel_type, &src_id, &dst_id, &props_to_json(stored)?],
+        )?;
+        Ok(id)
+    }
+
+    fn set_node_props(&mut self, id: &str, props: HashMap<String, Value>) -> Result<(), Error> {
+        if let Some(mut n) = self.node(id)? {
+            merge_props(&mut n.props, props);
+            self.client.get_mut().execute(
+                &self
+                    .tables
+                    .sql("UPDATE {nodes} SET props = $1 WHERE id = $2"),
+                &[&props_to_json(n.props)?, &id],
+            )?;
+        }
+        Ok(())
+    }
+
+    fn set_rel_props(&mut self, id: &str, props: HashMap<String, Value>) -> Result<(), Error> {
+        if let Some(mut r) = self.rel(id)? {
+            merge_props(&mut r.props, props);
+            self.client.get_mut().execute(
+                &self
+                    .tables
+                    .sql("UPDATE {edges} SET props = $1 WHERE id = $2"),
+                &[&props_to_json(r.props)?, &id],
+            )?;
+        }
+        Ok(())
+    }
+
+    fn remove_node(&mut self, id: &str) -> Result<(), Error> {
+        let client = self.client.get_mut();
+        client.execute(
+            &self
+                .tables
+                .sql("DELETE FROM {edges} WHERE src_id = $1 OR dst_id = $1"),
+            &[&id],
+        )?;
+        client.execute(
+            &self.tables.sql("DELETE FROM {nodes} WHERE id = $1"),
+            &[&id],
+        )?;
+        Ok(())
+    }
+
+    fn remove_rel(&mut self, id: &str) -> Result<(), Error> {
+        self.client.get_mut().execute(
+            &self.tables.sql("DELETE FROM {edges} WHERE id = $1"),
+            &[&id],
+        )?;
+        Ok(())
+    }
+}
+
+pub(crate) type PostgresTransaction = PlanTransaction<PostgresSession>;
+
+/// A session on a connection from a PostgreSQL database pool. Outside of a transaction, each
+/// statement is committed as it runs.
+pub(crate) struct PostgresSession {
+    conn: PooledConnection<PostgresConnectionManager<MakeTlsConnector>>,
+    tables: Arc<Tables>,
+    in_transaction: bool,
+}
+
+impl PostgresSession {
+    pub(crate) fn new(graph: &PostgresGraph) -> Result<PostgresSession, Error> {
+        Ok(PostgresSession {
+            conn: graph
+                .pool
+                .get()
+                .map_err(|e| Error::PostgresPoolError { source: e })?,
+            tables: graph.tables.clone(),
+            in_transaction: false,
+        })
+    }
+}
+
+impl Drop for PostgresSession {
+    /// Rolls back a transaction that is neither committed nor rolled back, for example because
+    /// an error was returned part way through a mutation, before the connection is returned to
+    /// the pool
+    fn drop(&mut self) {
+        if self.in_transaction {
+            if let Err(e) = self.conn.batch_execute("ROLLBACK") {
+                debug!("PostgresSession::drop -- rollback failed: {}", e);
+            }
+        }
+    }
+}
+
+impl GraphSession for PostgresSession {
+    fn begin(&mut self) -> Result<(), Error> {
+        debug!("PostgresSession::begin called");
+
+        self.conn.batch_execute("BEGIN")?;
+        self.in_transaction = true;
+        Ok(())
+    }
+
+    fn commit(&mut self) -> Result<(), Error> {
+        debug!("PostgresSession::commit called");
+
+        if !self.in_transaction {
+            return Err(Error::TransactionFinished);
+        }
+        self.in_transaction = false;
+        self.conn.batch_execute("COMMIT")?;
+        Ok(())
+    }
+
+    fn rollback(&mut self) -> Result<(), Error> {
+        debug!("PostgresSession::rollback called");
+
+        if !self.in_transaction {
+            return Err(Error::TransactionFinished);
+        }
+        self.in_transaction = false;
+        self.conn.batch_execute("ROLLBACK")?;
+        Ok(())
+    }
+
+    fn with_store<T, F>(&mut self, f: F) -> Result<T, Error>
+    where
+        F: FnOnce(&mut dyn GraphStore) -> Result<T, Error>,
+    {
+        f(&mut PostgresStore::new(&mut self.conn, &self.tables))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{PostgresEndpoint, Tables};
+    use crate::Error;
+
+    fn mock_endpoint() -> PostgresEndpoint {
+        PostgresEndpoint::new(
+            "127.0.0.1".to_string(),
+            5432,
+            "postgres".to_string(),
+            "password".to_string(),
+            "warpgrapher".to_string(),
+        )
+    }
+
+    /// Passes if PostgresEndpoint implements the Send trait
+    #[test]
+    fn test_postgres_endpoint_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<PostgresEndpoint>();
+    }
+
+    /// Passes if PostgresEndpoint implements the Sync trait
+    #[test]
+    fn test_postgres_endpoint_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<PostgresEndpoint>();
+    }
+
+    /// Passes if the builder methods set TLS and the table names, which default to no TLS and
+    /// the nodes and edges tables
+    #[test]
+    fn endpoint_builder() {
+        let pe = mock_endpoint();
+        assert!(!pe.tls);
+        assert_eq!((&*pe.nodes_table, &*pe.edges_table), ("nodes", "edges"));
+
+        let pe = mock_endpoint()
+            .with_tls(true)
+            .with_table_names("graph.people".to_string(), "graph.links".to_string());
+        assert!(pe.tls);
+        assert_eq!(
+            (&*pe.nodes_table, &*pe.edges_table),
+            ("graph.people", "graph.links")
+        );
+    }
+
+    /// Passes if plain and schema-qualified table names are accepted, and names that would need
+    /// quoting are rejected
+    #[test]
+    fn table_names() {
+        assert!(Tables::new("nodes", "edges").is_ok());
+        assert!(Tables::new("graph.nodes", "_edges_2").is_ok());
+
+        for name in &[
+            "",
+            "1nodes",
+            "nodes;drop",
+            "a.b.c",
+            "graph.",
+            "my nodes",
+            "\"nodes\"",
+        ] {
+            assert!(matches!(
+                Tables::new(name, "edges"),
+                Err(Error::PostgresTableNameInvalid { .. })
+            ));
+            assert!(matches!(
+                Tables::new("nodes", name),
+                Err(Error::PostgresTableNameInvalid { .. })
+            ));
+        }
+    }
+
+    /// Passes if statements and the schema refer to the configured tables, with index names
+    /// that are not qualified by the schema name
+    #[test]
+    fn table_statements() {
+        let tables = Tables::new("graph.people", "links").unwrap();
+        assert_eq!(
+            tables.sql("SELECT id FROM {nodes} JOIN {edges} ON {edges}.src_id = {nodes}.id"),
+            "SELECT id FROM graph.people JOIN links ON links.src_id = graph.people.id"
+        );
+
+        let schema = tables.schema();
+        assert!(schema.contains("CREATE TABLE IF NOT EXISTS graph.people ("));
+        assert!(schema.contains("CREATE TABLE IF NOT EXISTS links ("));
+        assert!(schema.contains("people_label ON graph.people (label)"));
+        assert!(schema.contains("links_src ON links (src_id, rel_type)"));
+        assert!(!schema.contains('{'));
+    }
+}
@@ -0,0 +1,452 @@
+//! Provides a policy for retrying database operations that fail with transient errors, such as
+//! deadlocks, cluster leader switches, and throttling.
+
+use crate::Error;
+use log::debug;
+use rand::Rng;
+#[cfg(feature = "neo4j")]
+use std::convert::TryFrom;
+use std::thread::sleep;
+use std::time::Duration;
+
+/// A policy for retrying the database operations of the generated resolvers. Each query or
+/// mutation runs in a transaction. If the transaction fails with a transient error, it is rolled
+/// back and the whole operation is run again in a new transaction, after waiting for an
+/// exponentially increasing backoff. Other errors are returned immediately.
+///
+/// Only Neo4J, PostgreSQL, SQLite, and the in-memory graph roll back the writes of a failed
+/// transaction. Cosmos DB and Gremlin-based DBs have no transactions, so each query that succeeds
+/// is kept, even if a later query of the same operation fails. An operation on those back-ends
+/// is retried only if it failed before making any write, such as when its first write or one of
+/// its reads is throttled. Otherwise the error is returned, because running the operation again
+/// would repeat the writes already made.
+///
+/// The errors treated as transient depend on the database back-end:
+///
+/// * Cosmos DB and Gremlin-based DBs - throttling (status 429) and temporary errors (status 596)
+/// * Neo4J - `Neo.TransientError` codes, such as deadlocks, and leader switches in a cluster
+/// * PostgreSQL - deadlocks and serialization failures
+/// * SQLite - a busy or locked database
+///
+/// If Cosmos DB gives a retry-after time with a throttling error, it is used in place of the
+/// backoff. When a Neo4J cluster reports that a mutation was sent to a server that is no longer
+/// the leader, the pool reads the cluster's routing table again before the retry checks out a
+/// connection, so that the retry is sent to the new leader.
+///
+/// The resolvers run synchronously, so the wait between attempts blocks the thread on which the
+/// resolver runs, as do the database operations themselves. Keep the backoff short where
+/// requests are executed on the threads of an async runtime, or execute them on a thread pool
+/// for blocking work, such as with `tokio::task::spawn_blocking`.
+///
+/// By default, an operation is attempted up to three times, with a backoff starting at 100ms,
+/// doubling with each retry up to 5s, and a random jitter.
+///
+/// # Examples
+///
+/// ```rust
+/// # use std::time::Duration;
+/// # use warpgrapher::engine::database::retry::RetryPolicy;
+///
+/// let rp = RetryPolicy::new()
+///     .with_max_attempts(5)
+///     .with_initial_backoff(Duration::from_millis(50))
+///     .with_max_backoff(Duration::from_secs(2));
+/// ```
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+    multiplier: u32,
+    jitter: bool,
+}
+
+impl RetryPolicy {
+    /// Returns a new [`RetryPolicy`] with the default settings
+    ///
+    /// [`RetryPolicy`]: ./struct.RetryPolicy.html
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use warpgrapher::engine::database::retry::RetryPolicy;
+    ///
+    /// let rp = RetryPolicy::new();
+    /// ```
+    pub fn new() -> RetryPolicy {
+        RetryPolicy::default()
+    }
+
+    /// Returns a [`RetryPolicy`] that never retries, so that every error is returned immediately
+    ///
+    /// [`RetryPolicy`]: ./struct.RetryPolicy.html
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use warpgrapher::engine::database::retry::RetryPolicy;
+    ///
+    /// let rp = RetryPolicy::none();
+    /// ```
+    pub fn none() -> RetryPolicy {
+        RetryPolicy::default().with_max_attempts(1)
+    }
+
+    /// Sets the maximum number of times an operation is attempted, including the first attempt.
+    /// A value of zero is treated as one.
+    pub fn with_max_attempts(mut self, max_attempts: u32) -> RetryPolicy {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    /// Sets the backoff before the first retry
+    pub fn with_initial_backoff(mut self, initial_backoff: Duration) -> RetryPolicy {
+        self.initial_backoff = initial_backoff;
+        self
+    }
+
+    /// Sets the longest backoff between retries
+    pub fn with_max_backoff(mut self, max_backoff: Duration) -> RetryPolicy {
+        self.max_backoff = max_backoff;
+        self
+    }
+
+    /// Sets the factor by which the backoff grows with each retry
+    pub fn with_multiplier(mut self, multiplier: u32) -> RetryPolicy {
+        self.multiplier = multiplier;
+        self
+    }
+
+    /// Sets whether a random jitter is applied to the backoff, so that clients that failed at the
+    /// same time do not retry at the same time. With jitter, the wait is chosen at random between
+    /// zero and the backoff.
+    pub fn with_jitter(mut self, jitter: bool) -> RetryPolicy {
+        self.jitter = jitter;
+        self
+    }
+
+    /// Runs `f`, running it again after a wait if it fails with a transient error, until it
+    /// succeeds, fails with another error, or the maximum number of attempts is reached. The
+    /// wait blocks the calling thread with `std::thread::sleep`.
+    pub(crate) fn run<T, F>(&self, f: F) -> Result<T, Error>
+    where
+        F: FnMut() -> Result<T, Error>,
+    {
+        self.run_if(|| true, f)
+    }
+
+    /// Runs `f` as [`run`] does, except that a failed attempt is not retried if `retryable`
+    /// returns false once the attempt has failed, such as when the attempt made writes that
+    /// cannot be rolled back
+    ///
+    /// [`run`]: #method.run
+    pub(crate) fn run_if<T, F, R>(&self, retryable: R, mut f: F) -> Result<T, Error>
+    where
+        F: FnMut() -> Result<T, Error>,
+        R: Fn() -> bool,
+    {
+        let mut attempt = 1;
+        loop {
+            match f() {
+                Err(e) if attempt < self.max_attempts && retryable() => match retry_after(&e) {
+                    Some(retry_after) => {
+                        let wait = retry_after.unwrap_or_else(|| self.backoff(attempt));
+                        debug!(
+                            "RetryPolicy::run -- attempt {} failed, retrying in {:?}: {}",
+                            attempt, wait, e
+                        );
+                        sleep(wait);
+                        attempt += 1;
+                    }
+                    None => return Err(e),
+                },
+                result => return result,
+            }
+        }
+    }
+
+    /// Returns the wait before the retry that follows a given attempt
+    fn backoff(&self, attempt: u32) -> Duration {
+        let backoff = self
+            .multiplier
+            .checked_pow(attempt - 1)
+            .and_then(|factor| self.initial_backoff.checked_mul(factor))
+            .map_or(self.max_backoff, |backoff| backoff.min(self.max_backoff));
+
+        if self.jitter {
+            let millis = backoff.as_millis() as u64;
+            Duration::from_millis(rand::thread_rng().gen_range(0, millis + 1))
+        } else {
+            backoff
+        }
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_secs(5),
+            multiplier: 2,
+            jitter: true,
+        }
+    }
+}
+
+/// Returns [`None`] if an error is not transient. Otherwise, returns the time after which the
+/// database asked that the operation be retried, if it gave one.
+fn retry_after(e: &Error) -> Option<Option<Duration>> {
+    match e {
+        #[cfg(any(feature = "cosmos", feature = "gremlin"))]
+        Error::GremlinActionFailed { source } => match source.as_ref() {
+            gremlin_client::GremlinError::Request((code, message))
+                if *code == 429
+                    || *code == 596
+                    || message.contains("RequestRateTooLarge")
+                    || message.contains("Request rate is large") =>
+            {
+                Some(cosmos_retry_after(message))
+            }
+            _ => None,
+        },
+        #[cfg(feature = "neo4j")]
+        Error::Neo4jQueryFailed {
+            message: bolt_proto::Message::Failure(failure),
+        } => match failure
+            .metadata()
+            .get("code")
+            .cloned()
+            .map(String::try_from)
+        {
+            Some(Ok(code)) if neo4j_transient(&code) => Some(None),
+            _ => None,
+        },
+        #[cfg(feature = "postgres")]
+        Error::PostgresQueryFailed { source } => match source.code() {
+            Some(&postgres_client::error::SqlState::T_R_DEADLOCK_DETECTED)
+            | Some(&postgres_client::error::SqlState::T_R_SERIALIZATION_FAILURE) => Some(None),
+            _ => None,
+        },
+        #[cfg(feature = "sqlite")]
+        Error::SqliteQueryFailed {
+            source: rusqlite::Error::SqliteFailure(failure, _),
+        } => match failure.code {
+            rusqlite::ErrorCode::DatabaseBusy | rusqlite::ErrorCode::DatabaseLocked => Some(None),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Returns true if a Neo4J status code is for a failure that may succeed if retried. A mutation
+/// sent to a server that is no longer the leader may succeed once the routing table is read
+/// again, which the failed transaction triggers before it is rolled back.
+#[cfg(feature = "neo4j")]
+fn neo4j_transient(code: &str) -> bool {
+    (code.starts_with("Neo.TransientError.")
+        && code != "Neo.TransientError.Transaction.Terminated"
+        && code != "Neo.TransientError.Transaction.LockClientStopped")
+        || code == "Neo.ClientError.Cluster.NotALeader"
+        || code == "Neo.ClientError.General.ForbiddenOnReadOnlyDatabase"
+}
+
+/// Parses the `x-ms-retry-after-ms` value from the message of a Cosmos DB error, given either as
+/// a number of milliseconds or as a time span, such as `00:00:00.1000000`
+#[cfg(any(feature = "cosmos", feature = "gremlin"))]
+fn cosmos_retry_after(message: &str) -> Option<Duration> {
+    let start = message.find("x-ms-retry-after-ms")? + "x-ms-retry-after-ms".len();
+    let value: String = message[start..]
+        .trim_start_matches(|c: char| c == '"' || c == ':' || c == '=' || c.is_whitespace())
+        .chars()
+        .take_while(|c| c.is_ascii_digit() || *c == '.' || *c == ':')
+        .collect();
+
+    if value.contains(':') {
+        let mut secs = 0.0;
+        for part in value.split(':') {
+            secs = secs * 60.0 + part.parse::<f64>().ok()?;
+        }
+        Some(Duration::from_secs_f64(secs))
+    } else {
+        Some(Duration::from_secs_f64(value.parse::<f64>().ok()? / 1000.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{retry_after, RetryPolicy};
+    use crate::Error;
+    use std::time::Duration;
+
+    /// Passes if the backoff grows by the multiplier with each attempt, up to the maximum, and
+    /// stays within the backoff when jitter is applied
+    #[test]
+    fn backoff() {
+        let rp = RetryPolicy::new()
+            .with_initial_backoff(Duration::from_millis(100))
+            .with_max_backoff(Duration::from_millis(500))
+            .with_jitter(false);
+        assert_eq!(rp.backoff(1), Duration::from_millis(100));
+        assert_eq!(rp.backoff(2), Duration::from_millis(200));
+        assert_eq!(rp.backoff(3), Duration::from_millis(400));
+        assert_eq!(rp.backoff(4), Duration::from_millis(500));
+        assert_eq!(rp.backoff(100), Duration::from_millis(500));
+
+        let rp = rp.with_jitter(true);
+        for attempt in 1..10 {
+            assert!(rp.backoff(attempt) <= Duration::from_millis(500));
+        }
+    }
+
+    /// Passes if an operation is retried until it succeeds, or the maximum number of attempts is
+    /// reached, but only for transient errors, and only while the failed attempt can be retried
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn run() {
+        let busy = || Error::SqliteQueryFailed {
+            source: rusqlite::Error::SqliteFailure(
+                rusqlite::ffi::Error::new(rusqlite::ffi::SQLITE_BUSY),
+                None,
+            ),
+        };
+        let rp = RetryPolicy::new()
+            .with_max_attempts(3)
+            .with_initial_backoff(Duration::from_millis(1));
+
+        let mut attempts = 0;
+        let result = rp.run(|| {
+            attempts += 1;
+            if attempts < 3 {
+                Err(busy())
+            } else {
+                Ok(attempts)
+            }
+        });
+        assert_eq!(result.unwrap(), 3);
+
+        let mut attempts = 0;
+        let result: Result<(), Error> = rp.run(|| {
+            attempts += 1;
+            Err(busy())
+        });
+        assert!(matches!(result, Err(Error::SqliteQueryFailed { .. })));
+        assert_eq!(attempts, 3);
+
+        let mut attempts = 0;
+        let result: Result<(), Error> = rp.run(|| {
+            attempts += 1;
+            Err(Error::DatabaseNotFound)
+        });
+        assert!(matches!(result, Err(Error::DatabaseNotFound)));
+        assert_eq!(attempts, 1);
+
+        let mut attempts = 0;
+        let result: Result<(), Error> = RetryPolicy::none().run(|| {
+            attempts += 1;
+            Err(busy())
+        });
+        assert!(result.is_err());
+        assert_eq!(attempts, 1);
+
+        let mut attempts = 0;
+        let result: Result<(), Error> = rp.run_if(
+            || false,
+            || {
+                attempts += 1;
+                Err(busy())
+            },
+        );
+        assert!(matches!(result, Err(Error::SqliteQueryFailed { .. })));
+        assert_eq!(attempts, 1);
+    }
+
+    /// Passes if Cosmos DB throttling and temporary Gremlin server errors are transient, with the
+    /// retry-after time parsed from the error message, and other request errors are not
+    #[cfg(any(feature = "cosmos", feature = "gremlin"))]
+    #[test]
+    fn retry_after_gremlin() {
+        use gremlin_client::GremlinError;
+
+        let request = |code, message: &str| Error::GremlinActionFailed {
+            source: Box::new(GremlinError::Request((code, message.to_string()))),
+        };
+
+        assert_eq!(retry_after(&request(429, "throttled")), Some(None));
+        assert_eq!(retry_after(&request(596, "try again")), Some(None));
+        assert_eq!(
+            retry_after(&request(
+                500,
+                "ExceptionType : RequestRateTooLargeException\r\nx-ms-retry-after-ms : 00:00:01.5000000"
+            )),
+            Some(Some(Duration::from_millis(1500)))
+        );
+        assert_eq!(
+            retry_after(&request(429, "{\"x-ms-retry-after-ms\": \"250\"}")),
+            Some(Some(Duration::from_millis(250)))
+        );
+        assert_eq!(retry_after(&request(597, "script failed")), None);
+        assert_eq!(retry_after(&request(500, "server error")), None);
+    }
+
+    /// Passes if Neo4J transient and leader switch errors are retried, and terminated
+    /// transactions and other errors are not
+    #[cfg(feature = "neo4j")]
+    #[test]
+    fn retry_after_neo4j() {
+        use bolt_proto::message::Failure;
+        use bolt_proto::Message;
+        use std::collections::HashMap;
+        use std::iter::FromIterator;
+
+        let failure = |code: &str| Error::Neo4jQueryFailed {
+            message: Message::Failure(Failure::new(HashMap::from_iter(vec![(
+                "code".to_string(),
+                bolt_proto::value::Value::from(code),
+            )]))),
+        };
+
+        assert_eq!(
+            retry_after(&failure("Neo.TransientError.Transaction.DeadlockDetected")),
+            Some(None)
+        );
+        assert_eq!(
+            retry_after(&failure("Neo.ClientError.Cluster.NotALeader")),
+            Some(None)
+        );
+        assert_eq!(
+            retry_after(&failure("Neo.TransientError.Transaction.Terminated")),
+            None
+        );
+        assert_eq!(
+            retry_after(&failure("Neo.ClientError.Statement.SyntaxError")),
+            None
+        );
+    }
+
+    /// Passes if the leader switch errors that are retried also mark the routing table as out
+    /// of date, so that the retry is sent to the new leader
+    #[cfg(feature = "neo4j")]
+    #[test]
+    fn leader_switch_refreshes_routing() {
+        use crate::engine::database::neo4j::stale_route;
+        use bolt_proto::message::Failure;
+        use bolt_proto::Message;
+        use std::collections::HashMap;
+        use std::iter::FromIterator;
+
+        for code in &[
+            "Neo.ClientError.Cluster.NotALeader",
+            "Neo.ClientError.General.ForbiddenOnReadOnlyDatabase",
+        ] {
+            let e = Error::Neo4jQueryFailed {
+                message: Message::Failure(Failure::new(HashMap::from_iter(vec![(
+                    "code".to_string(),
+                    bolt_proto::value::Value::from(*code),
+                )]))),
+            };
+            assert_eq!(retry_after(&e), Some(None), "code: {}", code);
+            assert!(stale_route(&e), "code: {}", code);
+        }
+    }
+}
@@ -0,0 +1,815 @@
+//! Provides an embedded SQLite graph database back-end, storing a graph in a local file.
+
+use crate::engine::database::inference::ModelInference;
+use crate::engine::database::plan::{
+    self, merge_props, GraphSession, GraphStore, NodeFilter, PlanTransaction, RelFilter,
+    StoredNode, StoredRel,
+};
+use crate::engine::database::{env_string, DatabaseEndpoint, DatabasePool, PoolOptions};
+use crate::engine::value::Value;
+use crate::Error;
+use async_trait::async_trait;
+use log::{debug, trace};
+use r2d2::{Pool, PooledConnection};
+use r2d2_sqlite::SqliteConnectionManager;
+use rusqlite::types::Value as SqlValue;
+use rusqlite::{params, Connection, OptionalExtension, Row};
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::time::Duration;
+use uuid::Uuid;
+
+/// Creates the tables in which a graph is stored, if they do not already exist. Each node and
+/// relationship is a row, with its properties held in a JSON object. The `seq` columns preserve
+/// the order in which nodes and relationships were created.
+const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS nodes (
+    seq INTEGER PRIMARY KEY AUTOINCREMENT,
+    id TEXT NOT NULL UNIQUE,
+    label TEXT NOT NULL,
+    props TEXT NOT NULL
+);
+CREATE INDEX IF NOT EXISTS nodes_label ON nodes (label);
+CREATE TABLE IF NOT EXISTS edges (
+    seq INTEGER PRIMARY KEY AUTOINCREMENT,
+    id TEXT NOT NULL UNIQUE,
+    rel_type TEXT NOT NULL,
+    src_id TEXT NOT NULL,
+    dst_id TEXT NOT NULL,
+    props TEXT NOT NULL
+);
+CREATE INDEX IF NOT EXISTS edges_src ON edges (src_id, rel_type);
+CREATE INDEX IF NOT EXISTS edges_dst ON edges (dst_id, rel_type);
+";
+
+/// A SQLite endpoint holds the path of a local SQLite database file, which is created if it does
+/// not exist. No database server is needed.
+///
+/// # Examples
+///
+/// ```rust
+/// # use warpgrapher::engine::database::sqlite::SqliteEndpoint;
+///
+/// let se = SqliteEndpoint::new("./graph.db".to_string());
+/// ```
+pub struct SqliteEndpoint {
+    path: String,
+    pool_options: PoolOptions,
+}
+
+impl SqliteEndpoint {
+    /// Returns a new [`SqliteEndpoint`] for the database file at `path`.
+    ///
+    /// [`SqliteEndpoint`]: ./struct.SqliteEndpoint.html
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use warpgrapher::engine::database::sqlite::SqliteEndpoint;
+    ///
+    /// let se = SqliteEndpoint::new("./graph.db".to_string());
+    /// ```
+    pub fn new(path: String) -> SqliteEndpoint {
+        SqliteEndpoint {
+            path,
+            pool_options: PoolOptions::default(),
+        }
+    }
+
+    /// Reads an environment variable to construct a [`SqliteEndpoint`]. The environment variable
+    /// is
+    ///
+    /// * WG_SQLITE_PATH - the path of the SQLite database file. For example, `./graph.db`.
+    ///
+    /// Pool options are read from the WG_POOL_* variables described in [`PoolOptions::from_env`].
+    ///
+    /// [`PoolOptions::from_env`]: ../struct.PoolOptions.html#method.from_env
+    /// [`SqliteEndpoint`]: ./struct.SqliteEndpoint.html
+    ///
+    /// # Errors
+    ///
+    /// * [`EnvironmentVariableNotFound`] - if the environment variable does not exist
+    /// * [`EnvironmentVariableIntNotParsed`] - if a WG_POOL_* variable cannot be parsed
+    ///
+    /// [`EnvironmentVariableNotFound`]: ../../enum.Error.html#variant.EnvironmentVariableNotFound
+    /// [`EnvironmentVariableIntNotParsed`]: ../../enum.Error.html#variant.EnvironmentVariableIntNotParsed
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use warpgrapher::engine::database::sqlite::SqliteEndpoint;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let se = SqliteEndpoint::from_env()?;
+    /// #    Ok(())
+    /// # }
+    /// ```
+    pub fn from_env() -> Result<SqliteEndpoint, Error> {
+        Ok(SqliteEndpoint {
+            path: env_string("WG_SQLITE_PATH")?,
+            pool_options: PoolOptions::from_env()?,
+        })
+    }
+
+    /// Sets the options for the connection pool. The query timeout is how long a writer waits
+    /// for another writer's transaction to finish, which defaults to 30 seconds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::time::Duration;
+    /// # use warpgrapher::engine::database::PoolOptions;
+    /// # use warpgrapher::engine::database::sqlite::SqliteEndpoint;
+    ///
+    /// let se = SqliteEndpoint::new("./graph.db".to_string())
+    ///     .with_pool_options(PoolOptions::new().with_query_timeout(Duration::from_secs(5)));
+    /// ```
+    pub fn with_pool_options(mut self, pool_options: PoolOptions) -> SqliteEndpoint {
+        self.pool_options = pool_options;
+        self
+    }
+}
+
+#[async_trait]
+impl DatabaseEndpoint for SqliteEndpoint {
+    async fn pool(&self) -> Result<DatabasePool, Error> {
+        // Writers wait on each other's transactions, rather than failing immediately
+        let busy_timeout = self
+            .pool_options
+            .query_timeout
+            .unwrap_or_else(|| Duration::from_secs(30));
+        let manager = SqliteConnectionManager::file(&self.path)
+            .with_init(move |c| c.busy_timeout(busy_timeout));
+        let pool = self.pool_options.r2d2_builder(10)?.build(manager)?;
+        pool.get()?.execute_batch(SCHEMA)?;
+
+        let pool = DatabasePool::Sqlite(pool);
+        if self.pool_options.health_check {
+            pool.health().await?;
+        }
+
+        trace!("SqliteEndpoint::pool -- pool: {:#?}", pool);
+        Ok(pool)
+    }
+}
+
+/// Samples up to `sample_size` nodes with each label in a SQLite database, along with their
+/// outgoing relationships, and records their properties and relationships for inference of a
+/// [`Configuration`].
+///
+/// [`Configuration`]: ../../config/struct.Configuration.html
+pub(crate) fn infer_model(
+    pool: &Pool<SqliteConnectionManager>,
+    sample_size: usize,
+) -> Result<ModelInference, Error> {
+    trace!("sqlite::infer_model called -- sample_size: {}", sample_size);
+
+    plan::infer_model(&*pool.get()?, sample_size)
+}
+
+fn props_to_json(props: HashMap<String, Value>) -> Result<String, Error> {
+    Ok(serde_json::to_string(&serde_json::Value::try_from(
+        Value::Map(props),
+    )?)?)
+}
+
+fn props_from_json(json: &str) -> Result<HashMap<String, Value>, Error> {
+    match Value::try_from(serde_json::from_str::<serde_json::Value>(json)?)? {
+        Value::Map(m) => Ok(m),
+        _ => Err(Error::TypeConversionFailed {
+            src: json.to_string(),
+            dst: "HashMap<String, Value>".to_string(),
+        }),
+    }
+}
+
+/// Adds the conditions testing the scalar property predicates of a filter on the rows of a
+/// table, returning true if they test every predicate. Properties are compared with their values
+/// extracted from the JSON object of each row.
+fn prop_conditions(
+    table: &str,
+    props: Option<&HashMap<String, Value>>,
+    conditions: &mut Vec<String>,
+    values: &mut Vec<SqlValue>,
+) -> bool {
+    let (scalars, mut exact) = plan::scalar_props(props);
+    for (k, v) in scalars {
+        let value = match v {
+            Value::Bool(b) => SqlValue::Integer(i64::from(*b)),
+            Value::Int64(i) => SqlValue::Integer(*i),
+            Value::UInt64(u) => SqlValue::Integer(*u as i64),
+            Value::String(s) => SqlValue::Text(s.to_string()),
+            Value::Uuid(u) => SqlValue::Text(u.to_hyphenated().to_string()),
+            _ => {
+                exact = false;
+                continue;
+            }
+        };
+
+        if k == "id" {
+            values.push(value);
+            conditions.push(format!("{}.id = ?{}", table, values.len()));
+        } else if k.contains('"') {
+            // A double quote cannot be escaped within a quoted JSON path label
+            exact = false;
+        } else {
+            values.push(SqlValue::Text(format!("$.\"{}\"", k)));
+            values.push(value);
+            conditions.push(format!(
+                "json_extract({}.props, ?{}) = ?{}",
+                table,
+                values.len() - 1,
+                values.len()
+            ));
+        }
+    }
+    exact
+}
+
+fn clause(conditions: &[String]) -> String {
+    if conditions.is_empty() {
+        String::new()
+    } else {
+        " WHERE ".to_string() + &conditions.join(" AND ")
+    }
+}
+
+fn node_from_row(row: &Row) -> rusqlite::Result<(String, String, String)> {
+    Ok((row.get(0)?, row.get(1)?, row.get(2)?))
+}
+
+fn rel_from_row(row: &Row) -> rusqlite::Result<(String, String, String, String, String)> {
+    Ok((
+        row.get(0)?,
+        row.get(1)?,
+        row.get(2)?,
+        row.get(3)?,
+        row.get(4)?,
+    ))
+}
+
+fn stored_node((id, label, props): (String, String, String)) -> Result<StoredNode, Error> {
+    Ok(StoredNode {
+        id,
+        label,
+        props: props_from_json(&props)?,
+    })
+}
+
+fn stored_rel(
+    (id, rel_type, src_id, dst_id, props): (String, String, String, String, String),
+) -> Result<StoredRel, Error> {
+    Ok(StoredRel {
+        id,
+        rel_type,
+        src_id,
+        dst_id,
+        props: props_from_json(&props)?,
+    })
+}
+
+impl GraphStore for Connection {
+    fn labels(&self) -> Result<Vec<String>, Error> {
+        let mut stmt = self.prepare("SELECT DISTINCT label FROM nodes")?;
+        let labels = stmt
+            .query_map(params![], |row| row.get(0))?
+            .collect::<Result<Vec<String>, _>>()?;
+        Ok(labels)
+    }
+
+    fn node(&self, id: &str) -> Result<Option<StoredNode>, Error> {
+        self.query_row(
+            "SELECT id, label, props FROM nodes WHERE id = ?1",
+            params![id],
+            node_from_row,
+        )
+        .optional()?
+        .map(stored_node)
+        .transpose()
+    }
+
+    fn nodes(&self, filter: &NodeFilter) -> Result<Vec<StoredNode>, Error> {
+        let mut conditions = Vec::new();
+        let mut values = Vec::new();
+        if let Some(l) = filter.label {
+            values.push(SqlValue::Text(l.to_string()));
+            conditions.push(format!("label = ?{}", values.len()));
+        }
+        let exact = prop_conditions("nodes", filter.props, &mut conditions, &mut values);
+
+        // The limit can only be applied by the query if every predicate is tested by it
+        let mut query = "SELECT id, label, props FROM nodes".to_string()
+            + &clause(&conditions)
+            + " ORDER BY seq";
+        if let (true, Some(limit)) = (exact, filter.limit) {
+            values.push(SqlValue::Integer(i64::try_from(limit)?));
+            query += &format!(" LIMIT ?{}", values.len());
+        }
+
+        let rows = self
+            .prepare(&query)?
+            .query_map(values, node_from_row)?
+            .collect::<Result<Vec<_>, _>>()?;
+        let mut nodes = Vec::new();
+        for row in rows {
+            let n = stored_node(row)?;
+            if filter.matches(&n) {
+                nodes.push(n);
+            }
+        }
+        nodes.truncate(filter.limit.unwrap_or(usize::MAX));
+        Ok(nodes)
+    }
+
+    fn rel(&self, id: &str) -> Result<Option<StoredRel>, Error> {
+        self.query_row(
+            "SELECT id, rel_type, src_id, dst_id, props FROM edges WHERE id = ?1",
+            params![id],
+            rel_from_row,
+        )
+        .optional()?
+        .map(stored_rel)
+        .transpose()
+    }
+
+    fn rels(&self, filter: &RelFilter) -> Result<Vec<StoredRel>, Error> {
+        let mut conditions = Vec::new();
+        let mut values = Vec::new();
+        for (column, value) in [
+            ("edges.rel_type", filter.rel_type),
+            ("edges.src_id", filter.src_id),
+            ("edges.dst_id", filter.dst_id),
+            ("src.label", filter.src_label),
+        ]
+        .iter()
+        {
+            if let Some(v) = value {
+                values.push(SqlValue::Text(v.to_string()));
+                conditions.push(format!("{} = ?{}", column, values.len()));
+            }
+        }
+        prop_conditions("edges", filter.props, &mut conditions, &mut values);
+
+        let mut query = "SELECT edges.id, edges.rel_type, edges.src_id, edges.dst_id, edges.props \
+                         FROM edges"
+            .to_string();
+        if filter.src_label.is_some() {
+            query += " JOIN nodes AS src ON src.id = edges.src_id";
+        }
+        query += &(clause(&conditions) + " ORDER BY edges.seq");
+
+        let rows = self
+            .prepare(&query)?
+            .query_map(values, rel_from_row)?
+            .collect::<Result<Vec<_>, _>>()?;
+        let mut rels = Vec::new();
+        for row in rows {
+            let r = stored_rel(row)?;
+            if filter.matches(&r) {
+                rels.push(r);
+            }
+        }
+        Ok(rels)
+    }
+
+    fn add_node(
+        &mut self,
+        id: &str,
+        label: &str,
+        props: HashMap<String, Value>,
+    ) -> Result<(), Error> {
+        let mut stored = HashMap::new();
+        merge_props(&mut stored, props);
+        self.execute(
+            "INSERT INTO nodes (id, label, props) VALUES (?1, ?2, ?3)",
+            params![id, label, props_to_json(stored)?],
+        )?;
+        Ok(())
+    }
+
+    fn add_rel(
+        &mut self,
+        rel_type: &str,
+        src_id: &str,
+        dst_id: &str,
+        props: HashMap<String, Value>,
+    ) -> Result<String, Error> {
+        let id = Uuid::new_v4().to_string();
+        let mut stored = HashMap::new();
+        merge_props(&mut stored, props);
+        self.execute(
+            "INSERT INTO edges (id, rel_type, src_id, dst_id, props) VALUES (?1, ?2, ?3, ?4, ?5)",
+            params![id, rel_type, src_id, dst_id, props_to_json(stored)?],
+        )?;
+        Ok(id)
+    }
+
+    fn set_node_props(&mut self, id: &str, props: HashMap<String, Value>) -> Result<(), Error> {
+        if let Some(mut n) = self.node(id)? {
+            merge_props(&mut n.props, props);
+            self.execute(
+                "UPDATE nodes SET props = ?1 WHERE id = ?2",
+                params![props_to_json(n.props)?, id],
+            )?;
+        }
+        Ok(())
+    }
+
+    fn set_rel_props(&mut self, id: &str, props: HashMap<String, Value>) -> Result<(), Error> {
+        if let Some(mut r) = self.rel(id)? {
+            merge_props(&mut r.props, props);
+            self.execute(
+                "UPDATE edges SET props = ?1 WHERE id = ?2",
+                params![props_to_json(r.props)?, id],
+            )?;
+        }
+        Ok(())
+    }
+
+    fn remove_node(&mut self, id: &str) -> Result<(), Error> {
+        self.execute(
+            "DELETE FROM edges WHERE src_id = ?1 OR dst_id = ?1",
+            params![id],
+        )?;
+        self.execute("DELETE FROM nodes WHERE id = ?1", params![id])?;
+        Ok(())
+    }
+
+    fn remove_rel(&mut self, id: &str) -> Result<(), Error> {
+        self.execute("DELETE FROM edges WHERE id = ?1", params![id])?;
+        Ok(())
+    }
+}
+
+pub(crate) type SqliteTransaction = PlanTransaction<SqliteSession>;
+
+/// A session on a connection from a SQLite database pool. Outside of a transaction, each
+/// statement is committed as it runs.
+pub(crate) struct SqliteSession {
+    conn: PooledConnection<SqliteConnectionManager>,
+}
+
+impl SqliteSession {
+    pub(crate) fn new(pool: &Pool<SqliteConnectionManager>) -> Result<SqliteSession, Error> {
+        Ok(SqliteSession { conn: pool.get()? })
+    }
+}
+
+impl Drop for SqliteSession {
+    /// Rolls back a transaction that is neither committed nor rolled back, for example because
+    /// an error was returned part way through a mutation, before the connection is returned to
+    /// the pool
+    fn drop(&mut self) {
+        if !self.conn.is_autocommit() {
+            if let Err(e) = self.conn.execute_batch("ROLLBACK") {
+                debug!("SqliteSession::drop -- rollback failed: {}", e);
+            }
+        }
+    }
+}
+
+impl GraphSession for SqliteSession {
+    fn begin(&mut self) -> Result<(), Error> {
+        debug!("SqliteSession::begin called");
+
+        // Takes the write lock at the start of the transaction, so that concurrent transactions
+        // wait for it instead of failing when they first write
+        self.conn.execute_batch("BEGIN IMMEDIATE")?;
+        Ok(())
+    }
+
+    fn commit(&mut self) -> Result<(), Error> {
+        debug!("SqliteSession::commit called");
+
+        if self.conn.is_autocommit() {
+            return Err(Error::TransactionFinished);
+        }
+        self.conn.execute_batch("COMMIT")?;
+        Ok(())
+    }
+
+    fn rollback(&mut self) -> Result<(), Error> {
+        debug!("SqliteSession::rollback called");
+
+        if self.conn.is_autocommit() {
+            return Err(Error::TransactionFinished);
+        }
+        self.conn.execute_batch("ROLLBACK")?;
+        Ok(())
+    }
+
+    fn with_store<T, F>(&mut self, f: F) -> Result<T, Error>
+    where
+        F: FnOnce(&mut dyn GraphStore) -> Result<T, Error>,
+    {
+        f(&mut *self.conn)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{SqliteEndpoint, SqliteSession, SqliteTransaction};
+    use crate::engine::config::Configuration;
+    use crate::engine::database::retry::RetryPolicy;
+    use crate::engine::database::{
+        ClauseType, DatabaseEndpoint, DatabasePool, DbNames, NodeQueryVar, PoolOptions,
+        SuffixGenerator, Transaction,
+    };
+    use crate::engine::schema::Info;
+    use crate::engine::value::Value;
+    use crate::{Engine, Error};
+    use juniper::http::GraphQLRequest;
+    use maplit::hashmap;
+    use rusqlite::{params, Connection};
+    use serde_json::json;
+    use std::collections::HashMap;
+    use std::convert::TryFrom;
+    use std::env::temp_dir;
+    use std::thread::{sleep, spawn};
+    use std::time::Duration;
+    use uuid::Uuid;
+
+    fn mock_config() -> Configuration {
+        Configuration::try_from(
+            "
+version: 1
+model:
+  - name: User
+    dbLabel: legacy_user
+    props:
+      - name: name
+        type: String
+        dbName: user_name
+      - name: role
+        type: String
+      - name: tags
+        type: String
+        list: true
+    rels:
+      - name: friends
+        nodes: [User]
+        list: true
+        dbType: FRIEND_OF
+",
+        )
+        .unwrap()
+    }
+
+    async fn mock_pool() -> DatabasePool {
+        SqliteEndpoint::new(mock_path()).pool().await.unwrap()
+    }
+
+    fn mock_path() -> String {
+        temp_dir()
+            .join(Uuid::new_v4().to_string() + ".db")
+            .to_string_lossy()
+            .into_owned()
+    }
+
+    fn count(pool: &DatabasePool, table: &str) -> i64 {
+        pool.sqlite()
+            .unwrap()
+            .get()
+            .unwrap()
+            .query_row(
+                &("SELECT COUNT(*) FROM ".to_string() + table),
+                params![],
+                |row| row.get(0),
+            )
+            .unwrap()
+    }
+
+    fn create_user(txn: &mut SqliteTransaction, info: &Info, name: &str) {
+        let (query, params) = txn
+            .node_create_query(
+                Vec::new(),
+                HashMap::new(),
+                &NodeQueryVar::new(Some("User".to_string()), "node".to_string(), String::new()),
+                hashmap! {"name".to_string() => Value::String(name.to_string())},
+                ClauseType::Query,
+                &mut SuffixGenerator::new(),
+            )
+            .unwrap();
+        txn.create_node(query, params, None, info).unwrap();
+    }
+
+    fn execute(engine: &Engine<()>, query: &str, input: serde_json::Value) -> serde_json::Value {
+        let req: GraphQLRequest =
+            serde_json::from_value(json!({ "query": query, "variables": { "input": input } }))
+                .unwrap();
+        engine.execute(&req, &HashMap::new()).unwrap()
+    }
+
+    #[test]
+    fn test_sqlite_endpoint_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<SqliteEndpoint>();
+    }
+
+    #[test]
+    fn test_sqlite_endpoint_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<SqliteEndpoint>();
+    }
+
+    /// Passes if nested creates, filtered reads, updates, and deletes through the engine are
+    /// stored in the nodes and edges tables, with property values converted to and from JSON
+    #[tokio::test]
+    async fn engine_crud() {
+        let pool = mock_pool().await;
+        let engine = Engine::<()>::new(mock_config(), pool.clone())
+            .build()
+            .unwrap();
+
+        let created = execute(
+            &engine,
+            "mutation Create($input: UserCreateMutationInput!) {
+                UserCreate(input: $input) { id name tags friends { dst { ... on User { name } } } }
+            }",
+            json!({
+                "name": "alice",
+                "role": "admin",
+                "tags": ["a", "b"],
+                "friends": [{"dst": {"User": {"$NEW": {"name": "bob", "role": "user"}}}}]
+            }),
+        );
+        assert_eq!(created["data"]["UserCreate"]["name"], "alice");
+        assert_eq!(created["data"]["UserCreate"]["tags"], json!(["a", "b"]));
+        assert_eq!(
+            created["data"]["UserCreate"]["friends"][0]["dst"]["name"],
+            "bob"
+        );
+        assert_eq!(count(&pool, "nodes"), 2);
+        assert_eq!(count(&pool, "edges"), 1);
+
+        let read = execute(
+            &engine,
+            "query Read($input: UserQueryInput) { User(input: $input) { name } }",
+            json!({"friends": {"dst": {"User": {"role": "user"}}}}),
+        );
+        assert_eq!(read["data"]["User"].as_array().unwrap().len(), 1);
+        assert_eq!(read["data"]["User"][0]["name"], "alice");
+
+        let updated = execute(
+            &engine,
+            "mutation Update($input: UserUpdateInput!) { UserUpdate(input: $input) { name role } }",
+            json!({"$MATCH": {"name": "bob"}, "$SET": {"role": "admin"}}),
+        );
+        assert_eq!(updated["data"]["UserUpdate"][0]["role"], "admin");
+
+        let deleted = execute(
+            &engine,
+            "mutation Delete($input: UserDeleteInput!) { UserDelete(input: $input) }",
+            json!({"$MATCH": {"name": "bob"}}),
+        );
+        assert_eq!(deleted["data"]["UserDelete"], 1);
+        assert_eq!(count(&pool, "nodes"), 1);
+        assert_eq!(count(&pool, "edges"), 0);
+    }
+
+    /// Passes if writes are kept on commit, and discarded on rollback or when a transaction is
+    /// dropped while still open
+    #[tokio::test]
+    async fn transaction_rollback() {
+        let pool = mock_pool().await;
+        let info = Info::from(&mock_config());
+
+        let session = SqliteSession::new(pool.sqlite().unwrap()).unwrap();
+        let mut txn = SqliteTransaction::new(session, DbNames::from(&info));
+        txn.begin().unwrap();
+        create_user(&mut txn, &info, "alice");
+        txn.commit().unwrap();
+        assert_eq!(count(&pool, "nodes"), 1);
+
+        txn.begin().unwrap();
+        create_user(&mut txn, &info, "bob");
+        txn.rollback().unwrap();
+        assert_eq!(count(&pool, "nodes"), 1);
+
+        {
+            let session = SqliteSession::new(pool.sqlite().unwrap()).unwrap();
+            let mut txn = SqliteTransaction::new(session, DbNames::from(&info));
+            txn.begin().unwrap();
+            create_user(&mut txn, &info, "carol");
+        }
+        assert_eq!(count(&pool, "nodes"), 1);
+    }
+
+    /// Passes if a configuration is inferred from the nodes and relationships in the database
+    #[tokio::test]
+    async fn infer_configuration() {
+        let pool = mock_pool().await;
+        let engine = Engine::<()>::new(mock_config(), pool.clone())
+            .build()
+            .unwrap();
+        execute(
+            &engine,
+            "mutation Create($input: UserCreateMutationInput!) { UserCreate(input: $input) { id } }",
+            json!({
+                "name": "alice",
+                "friends": [{"dst": {"User": {"$NEW": {"name": "bob"}}}}]
+            }),
+        );
+
+        let config = pool.infer_configuration(10).await.unwrap();
+        let user = config.types().find(|t| t.name() == "legacy_user").unwrap();
+        assert!(user.props().any(|p| p.name() == "user_name"));
+        assert!(user.rels().any(|r| r.name() == "FRIEND_OF"));
+    }
+
+    /// Passes if the pool is built with the configured options, and passes the health check both
+    /// at startup and when called afterwards
+    #[tokio::test]
+    async fn pool_options_and_health() {
+        let pool = SqliteEndpoint::new(mock_path())
+            .with_pool_options(
+                PoolOptions::new()
+                    .with_max_size(2)
+                    .with_min_idle(1)
+                    .with_connection_timeout(Duration::from_secs(1))
+                    .with_query_timeout(Duration::from_secs(1))
+                    .with_health_check(true),
+            )
+            .pool()
+            .await
+            .unwrap();
+        assert_eq!(pool.sqlite().unwrap().max_size(), 2);
+        assert_eq!(pool.sqlite().unwrap().min_idle(), Some(1));
+        assert!(pool.health().await.is_ok());
+    }
+
+    /// Passes if an exhausted pool returns an error once the connection timeout passes, rather
+    /// than waiting indefinitely
+    #[tokio::test]
+    async fn pool_exhausted() {
+        let pool = SqliteEndpoint::new(mock_path())
+            .with_pool_options(
+                PoolOptions::new()
+                    .with_max_size(1)
+                    .with_connection_timeout(Duration::from_millis(100)),
+            )
+            .pool()
+            .await
+            .unwrap();
+
+        let _conn = pool.sqlite().unwrap().get().unwrap();
+        assert!(matches!(
+            pool.health().await,
+            Err(Error::SqlitePoolError { .. })
+        ));
+    }
+
+    /// Passes if inconsistent pool options are reported as an error when the pool is built
+    #[tokio::test]
+    async fn pool_options_invalid() {
+        let result = SqliteEndpoint::new(mock_path())
+            .with_pool_options(PoolOptions::new().with_max_size(1).with_min_idle(2))
+            .pool()
+            .await;
+        assert!(matches!(result, Err(Error::PoolOptionsInvalid { .. })));
+    }
+
+    /// Passes if a mutation that finds the database locked by another writer is retried, and
+    /// succeeds once the lock is released, but fails without retries
+    #[tokio::test]
+    async fn retry_busy() {
+        let path = mock_path();
+        let pool = SqliteEndpoint::new(path.clone())
+            .with_pool_options(PoolOptions::new().with_query_timeout(Duration::from_millis(10)))
+            .pool()
+            .await
+            .unwrap();
+        let create = "mutation Create($input: UserCreateMutationInput!) {
+            UserCreate(input: $input) { name }
+        }";
+
+        let engine = Engine::<()>::new(mock_config(), pool.clone())
+            .with_retry_policy(RetryPolicy::none())
+            .build()
+            .unwrap();
+        let conn = Connection::open(&path).unwrap();
+        conn.execute_batch("BEGIN IMMEDIATE").unwrap();
+        let failed = execute(&engine, create, json!({"name": "alice"}));
+        assert!(failed["errors"].is_array());
+        conn.execute_batch("COMMIT").unwrap();
+
+        let engine = Engine::<()>::new(mock_config(), pool.clone())
+            .with_retry_policy(
+                RetryPolicy::new()
+                    .with_max_attempts(10)
+                    .with_initial_backoff(Duration::from_millis(50))
+                    .with_jitter(false),
+            )
+            .build()
+            .unwrap();
+        conn.execute_batch("BEGIN IMMEDIATE").unwrap();
+        let writer = spawn(move || {
+            sleep(Duration::from_millis(200));
+            conn.execute_batch("COMMIT").unwrap();
+        });
+        let created = execute(&engine, create, json!({"name": "alice"}));
+        writer.join().unwrap();
+        assert_eq!(created["data"]["UserCreate"]["name"], "alice");
+        assert_eq!(count(&pool, "nodes"), 1);
+    }
+}
@@ -1,7 +1,10 @@
 //! Contains types and functions for application specific extensions to the Warpgrapher framework.
 
 use crate::engine::context::RequestContext;
-use crate::engine::database::DatabasePool;
+use crate::engine::database::{DatabasePool, NodeRecord, RelRecord};
+use crate::engine::value::Value;
+use crate::Error;
+use juniper::ExecutionResult;
 
 use std::collections::hash_map::HashMap;
 use std::fmt::Debug;
@@ -66,6 +69,60 @@ where
     ) -> Result<serde_json::Value, Box<dyn std::error::Error + Sync + Send>> {
         Ok(response)
     }
+
+    /// Called before a create, read, update, or delete operation on a node or relationship type,
+    /// whether the operation comes from a generated endpoint, a nested relationship query, or a
+    /// [`ResolverFacade`] helper. The hook may modify the input, which has the same shape as the
+    /// `input` argument of the corresponding generated endpoint, and may rewrite a delete into an
+    /// update by calling [`OperationContext::rewrite_delete_as_update`]. Returning an error vetoes
+    /// the operation. The hook runs once for each operation, before its transaction is begun, and
+    /// does not run again if the transaction is retried.
+    ///
+    /// [`OperationContext::rewrite_delete_as_update`]: ./struct.OperationContext.html#method.rewrite_delete_as_update
+    /// [`ResolverFacade`]: ../resolvers/struct.ResolverFacade.html
+    fn before_operation(
+        &self,
+        _operation: &mut OperationContext<RequestCtx>,
+        _input: &mut Option<Value>,
+    ) -> Result<(), Box<dyn std::error::Error + Sync + Send>> {
+        Ok(())
+    }
+
+    /// Called after a create, read, update, or delete operation on a node or relationship type,
+    /// once the transaction of the operation is committed, so the hook sees only operations that
+    /// took effect. The hook may modify the result returned to the client. Returning an error
+    /// returns the error to the client, but does not undo the operation. The exception is an
+    /// operation made through a [`ResolverFacade`] helper, for which the hook runs before the
+    /// transaction shared by the helpers is committed, when the custom resolver returns, so that
+    /// an error from the hook rolls back the writes of the resolver.
+    ///
+    /// [`ResolverFacade`]: ../resolvers/struct.ResolverFacade.html
+    fn after_operation(
+        &self,
+        _operation: &OperationContext<RequestCtx>,
+        _result: &mut OperationResult,
+    ) -> Result<(), Box<dyn std::error::Error + Sync + Send>> {
+        Ok(())
+    }
+
+    /// Called before each field of a node or relationship is resolved. Returning an error fails
+    /// the resolution of the field.
+    fn before_field(
+        &self,
+        _field: &FieldContext<RequestCtx>,
+    ) -> Result<(), Box<dyn std::error::Error + Sync + Send>> {
+        Ok(())
+    }
+
+    /// Called after each field of a node or relationship is resolved, with the result of the
+    /// resolution, which the hook may replace.
+    fn after_field(
+        &self,
+        _field: &FieldContext<RequestCtx>,
+        result: ExecutionResult,
+    ) -> ExecutionResult {
+        result
+    }
 }
 
 /// Type alias for a thread-safe Extension vector.
@@ -110,3 +167,265 @@ where
 /// let extensions: Extensions<()> = vec![Arc::new(metadata_extension)];
 /// ```
 pub type Extensions<RequestCtx> = Vec<Arc<dyn Extension<RequestCtx>>>;
+
+/// The kind of operation passed to the [`Extension`] operation hooks
+///
+/// [`Extension`]: ./trait.Extension.html
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum OperationKind {
+    /// Creation of a node or relationship
+    Create,
+
+    /// Read of nodes or relationships
+    Read,
+
+    /// Update of nodes or relationships
+    Update,
+
+    /// Deletion of nodes or relationships
+    Delete,
+}
+
+/// Describes a create, read, update, or delete operation to the [`Extension`] operation hooks
+///
+/// [`Extension`]: ./trait.Extension.html
+#[derive(Debug)]
+pub struct OperationContext<'a, RequestCtx>
+where
+    RequestCtx: RequestContext,
+{
+    kind: OperationKind,
+    type_name: &'a str,
+    rel_name: Option<&'a str>,
+    request_ctx: Option<&'a RequestCtx>,
+}
+
+impl<'a, RequestCtx> OperationContext<'a, RequestCtx>
+where
+    RequestCtx: RequestContext,
+{
+    pub(crate) fn new(
+        kind: OperationKind,
+        type_name: &'a str,
+        rel_name: Option<&'a str>,
+        request_ctx: Option<&'a RequestCtx>,
+    ) -> OperationContext<'a, RequestCtx> {
+        OperationContext {
+            kind,
+            type_name,
+            rel_name,
+            request_ctx,
+        }
+    }
+
+    /// Returns the kind of the operation
+    pub fn kind(&self) -> OperationKind {
+        self.kind
+    }
+
+    /// Rewrites a delete into an update, for example to mark nodes as deleted rather than removing
+    /// them. The hook must also change the input to the input of an update, with `$MATCH` and
+    /// `$SET` keys. The deleted count returned to the client is the number of nodes or
+    /// relationships updated. The update runs through the operation hooks again, as an update.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`] variant [`TypeNotExpected`] if the operation is not a delete
+    ///
+    /// [`Error`]: ../../error/enum.Error.html
+    /// [`TypeNotExpected`]: ../../error/enum.Error.html#variant.TypeNotExpected
+    pub fn rewrite_delete_as_update(&mut self) -> Result<(), Error> {
+        match self.kind {
+            OperationKind::Delete => {
+                self.kind = OperationKind::Update;
+                Ok(())
+            }
+            _ => Err(Error::TypeNotExpected),
+        }
+    }
+
+    /// Returns the name of the node type, or, for a relationship operation, the name of the type
+    /// of the source node
+    pub fn type_name(&self) -> &str {
+        self.type_name
+    }
+
+    /// Returns the name of the relationship for a relationship operation, or None for a node
+    /// operation
+    pub fn rel_name(&self) -> Option<&str> {
+        self.rel_name
+    }
+
+    /// Returns the request context, if one was provided to the engine
+    pub fn request_context(&self) -> Option<&RequestCtx> {
+        self.request_ctx
+    }
+}
+
+/// The result of an operation passed to the [`Extension`] `after_operation` hook
+///
+/// [`Extension`]: ./trait.Extension.html
+#[derive(Clone, Debug)]
+pub enum OperationResult {
+    /// Nodes created, read, or updated
+    Nodes(Vec<NodeRecord>),
+
+    /// Relationships created, read, or updated
+    Rels(Vec<RelRecord>),
+
+    /// The number of nodes or relationships deleted
+    Count(i32),
+}
+
+impl OperationResult {
+    pub(crate) fn into_nodes(self) -> Result<Vec<NodeRecord>, Error> {
+        match self {
+            OperationResult::Nodes(nodes) => Ok(nodes),
+            _ => Err(Error::TypeNotExpected),
+        }
+    }
+
+    pub(crate) fn into_rels(self) -> Result<Vec<RelRecord>, Error> {
+        match self {
+            OperationResult::Rels(rels) => Ok(rels),
+            _ => Err(Error::TypeNotExpected),
+        }
+    }
+
+    pub(crate) fn into_count(self) -> Result<i32, Error> {
+        match self {
+            OperationResult::Count(count) => Ok(count),
+            _ => Err(Error::TypeNotExpected),
+        }
+    }
+}
+
+/// Describes the resolution of a field to the [`Extension`] field hooks
+///
+/// [`Extension`]: ./trait.Extension.html
+#[derive(Debug)]
+pub struct FieldContext<'a, RequestCtx>
+where
+    RequestCtx: RequestContext,
+{
+    type_name: &'a str,
+    field_name: &'a str,
+    request_ctx: Option<&'a RequestCtx>,
+}
+
+impl<'a, RequestCtx> FieldContext<'a, RequestCtx>
+where
+    RequestCtx: RequestContext,
+{
+    pub(crate) fn new(
+        type_name: &'a str,
+        field_name: &'a str,
+        request_ctx: Option<&'a RequestCtx>,
+    ) -> FieldContext<'a, RequestCtx> {
+        FieldContext {
+            type_name,
+            field_name,
+            request_ctx,
+        }
+    }
+
+    /// Returns the name of the GraphQL type on which the field is resolved, such as `Query`,
+    /// `Mutation`, or the name of a node or relationship type
+    pub fn type_name(&self) -> &str {
+        self.type_name
+    }
+
+    /// Returns the name of the field
+    pub fn field_name(&self) -> &str {
+        self.field_name
+    }
+
+    /// Returns the request context, if one was provided to the engine
+    pub fn request_context(&self) -> Option<&RequestCtx> {
+        self.request_ctx
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Extension, FieldContext, OperationContext, OperationKind, OperationResult};
+    use crate::engine::value::Value;
+    use crate::Error;
+
+    #[derive(Debug)]
+    struct DefaultExtension;
+
+    impl Extension<()> for DefaultExtension {}
+
+    /// Passes if a delete can be rewritten into an update, and no other operation can be
+    #[test]
+    fn rewrite_delete_as_update() {
+        let mut delete = OperationContext::<()>::new(OperationKind::Delete, "Project", None, None);
+        assert!(delete.rewrite_delete_as_update().is_ok());
+        assert_eq!(OperationKind::Update, delete.kind());
+
+        let mut read =
+            OperationContext::<()>::new(OperationKind::Read, "Project", Some("owners"), None);
+        assert!(matches!(
+            read.rewrite_delete_as_update(),
+            Err(Error::TypeNotExpected)
+        ));
+        assert_eq!(OperationKind::Read, read.kind());
+        assert_eq!(Some("owners"), read.rel_name());
+
+        assert!(matches!(
+            delete.rewrite_delete_as_update(),
+            Err(Error::TypeNotExpected)
+        ));
+    }
+
+    /// Passes if an operation result converts only into the kind of result it holds
+    #[test]
+    fn operation_result_conversions() {
+        assert_eq!(3, OperationResult::Count(3).into_count().unwrap());
+        assert!(OperationResult::Nodes(Vec::new())
+            .into_nodes()
+            .unwrap()
+            .is_empty());
+        assert!(OperationResult::Rels(Vec::new())
+            .into_rels()
+            .unwrap()
+            .is_empty());
+        assert!(matches!(
+            OperationResult::Count(3).into_nodes(),
+            Err(Error::TypeNotExpected)
+        ));
+        assert!(matches!(
+            OperationResult::Nodes(Vec::new()).into_rels(),
+            Err(Error::TypeNotExpected)
+        ));
+    }
+
+    /// Passes if the default operation and field hooks leave the input and results unchanged
+    #[test]
+    fn default_hooks() {
+        let extension = DefaultExtension;
+
+        let mut operation =
+            OperationContext::<()>::new(OperationKind::Create, "Project", None, None);
+        let mut input = Some(Value::String("warpgrapher".to_string()));
+        assert!(extension
+            .before_operation(&mut operation, &mut input)
+            .is_ok());
+        assert_eq!(Some(Value::String("warpgrapher".to_string())), input);
+        assert_eq!(OperationKind::Create, operation.kind());
+
+        let mut result = OperationResult::Count(1);
+        assert!(extension.after_operation(&operation, &mut result).is_ok());
+        assert_eq!(1, result.into_count().unwrap());
+
+        let field = FieldContext::<()>::new("Project", "name", None);
+        assert!(extension.before_field(&field).is_ok());
+        assert_eq!(
+            juniper::Value::scalar(7),
+            extension
+                .after_field(&field, Ok(juniper::Value::scalar(7)))
+                .unwrap()
+        );
+    }
+}
@@ -0,0 +1,276 @@
+//! This module provides types for custom generation of node ids, along with the time-ordered id
+//! generators used by the `UuidV7` and `Ulid` id strategies
+
+use crate::engine::config::{IdStrategy, Identifier};
+use crate::Error;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
+use uuid::Uuid;
+
+/// Type alias for a custom function used to generate the id of a new node. The function is
+/// passed the name of the type of the node. A generator may be a plain function, or a closure that
+/// captures state, such as a prefix or a client for an id service.
+///
+/// # Examples
+///
+/// ```rust
+/// # use std::sync::Arc;
+/// # use warpgrapher::Error;
+/// # use warpgrapher::engine::id_generators::IdGeneratorFunc;
+///
+/// fn order_number(type_name: &str) -> Result<String, Error> {
+///     Ok(type_name.to_lowercase() + "-" + &rand::random::<u32>().to_string())
+/// }
+///
+/// let f: Arc<IdGeneratorFunc> = Arc::new(order_number);
+///
+/// let prefix = "acme".to_string();
+/// let g: Arc<IdGeneratorFunc> = Arc::new(move |type_name: &str| {
+///     Ok(prefix.clone() + "-" + &type_name.to_lowercase())
+/// });
+/// ```
+pub type IdGeneratorFunc = dyn Fn(&str) -> Result<String, Error> + Send + Sync;
+
+/// Type alias for a collection of custom id generators, keyed by the name used in the
+/// configuration to refer to each one
+///
+/// # Examples
+///
+/// ```rust
+/// # use std::sync::Arc;
+/// # use warpgrapher::Error;
+/// # use warpgrapher::engine::id_generators::{IdGeneratorFunc, IdGenerators};
+///
+/// fn order_number(type_name: &str) -> Result<String, Error> {
+///     Ok(type_name.to_lowercase() + "-" + &rand::random::<u32>().to_string())
+/// }
+///
+/// let mut id_generators = IdGenerators::new();
+/// id_generators.insert("OrderNumber".to_string(), Arc::new(order_number));
+/// ```
+pub type IdGenerators = HashMap<String, Arc<IdGeneratorFunc>>;
+
+/// Crockford's base 32 alphabet, used to encode ULIDs
+const CROCKFORD: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+/// The number of bits of a time-ordered id, following its timestamp, that count the ids generated
+/// within the same millisecond
+const COUNTER_BITS: u32 = 12;
+
+/// The timestamp and counter of the last UUID v7 generated, packed as the milliseconds since the
+/// epoch followed by [`COUNTER_BITS`] bits of counter
+static LAST_UUID_V7: AtomicU64 = AtomicU64::new(0);
+
+/// The timestamp and counter of the last ULID generated, packed as for [`LAST_UUID_V7`]
+static LAST_ULID: AtomicU64 = AtomicU64::new(0);
+
+/// Returns a new id for a node of type `type_name`, following the id configuration of the type,
+/// or None if the id is left for the database back-end to generate
+pub(crate) fn generate_id(
+    id_generators: &IdGenerators,
+    type_name: &str,
+    identifier: &Identifier,
+) -> Result<Option<String>, Error> {
+    match identifier.strategy() {
+        IdStrategy::Native => Ok(None),
+        IdStrategy::UuidV4 => Ok(Some(Uuid::new_v4().to_string())),
+        IdStrategy::UuidV7 => {
+            let (millis, counter) = next_tick(&LAST_UUID_V7, unix_millis()?);
+            let mut random: [u8; 10] = rand::random();
+            random[0] = (counter >> 8) as u8;
+            random[1] = counter as u8;
+            Ok(Some(uuid_v7(millis, random)))
+        }
+        IdStrategy::Ulid => {
+            let (millis, counter) = next_tick(&LAST_ULID, unix_millis()?);
+            let mut random: [u8; 10] = rand::random();
+            random[0] = (counter >> 4) as u8;
+            random[1] = ((counter << 4) as u8) | (random[1] & 0x0f);
+            Ok(Some(ulid(millis, random)))
+        }
+        IdStrategy::Custom => {
+            let name = identifier.generator().unwrap_or(type_name);
+            let func = id_generators
+                .get(name)
+                .ok_or_else(|| Error::IdGeneratorNotFound {
+                    name: name.to_string(),
+                })?;
+            func(type_name).map(Some)
+        }
+    }
+}
+
+fn unix_millis() -> Result<u64, Error> {
+    let millis = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_err(|_| Error::TypeConversionFailed {
+            src: "SystemTime".to_string(),
+            dst: "u64".to_string(),
+        })?
+        .as_millis();
+    Ok(millis as u64)
+}
+
+/// Returns the timestamp and counter for the next time-ordered id, given the current time. Ids
+/// generated within the same millisecond take successive values of the counter, so that they sort
+/// in the order in which they were generated, even across threads. If the counter runs out, or
+/// the clock goes backwards, the timestamp of the last id is carried forward.
+fn next_tick(last: &AtomicU64, millis: u64) -> (u64, u16) {
+    let next = |last: u64| (millis << COUNTER_BITS).max(last + 1);
+    let prev = last
+        .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |last| Some(next(last)))
+        .unwrap_or_else(|last| last);
+    let tick = next(prev);
+    (
+        tick >> COUNTER_BITS,
+        (tick & ((1 << COUNTER_BITS) - 1)) as u16,
+    )
+}
+
+/// Returns a UUID v7, made of a 48 bit timestamp in milliseconds followed by random bits
+fn uuid_v7(millis: u64, random: [u8; 10]) -> String {
+    let mut bytes = [0u8; 16];
+    bytes[..6].copy_from_slice(&millis.to_be_bytes()[2..]);
+    bytes[6..].copy_from_slice(&random);
+    bytes[6] = 0x70 | (bytes[6] & 0x0f);
+    bytes[8] = 0x80 | (bytes[8] & 0x3f);
+    Uuid::from_bytes(bytes).to_string()
+}
+
+/// Returns a ULID, made of a 48 bit timestamp in milliseconds followed by 80 random bits, encoded
+/// as 26 characters of Crockford's base 32
+fn ulid(millis: u64, random: [u8; 10]) -> String {
+    let value = random
+        .iter()
+        .fold(u128::from(millis & 0xffff_ffff_ffff), |acc, b| {
+            (acc << 8) | u128::from(*b)
+        });
+    (0..26)
+        .map(|i| CROCKFORD[((value >> (125 - 5 * i)) & 0x1f) as usize] as char)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{generate_id, next_tick, ulid, uuid_v7, IdGenerators, COUNTER_BITS};
+    use crate::engine::config::{IdStrategy, Identifier};
+    use crate::Error;
+    use std::sync::atomic::AtomicU64;
+    use std::sync::Arc;
+    use uuid::Uuid;
+
+    fn order_number(type_name: &str) -> Result<String, Error> {
+        Ok(type_name.to_string() + "-1")
+    }
+
+    /// Passes if a UUID v7 is well formed, carries its timestamp, and sorts by time
+    #[test]
+    fn uuid_v7_format() {
+        let id = uuid_v7(0x0123_4567_89ab, [0xff; 10]);
+        assert_eq!("01234567-89ab-7fff-bfff-ffffffffffff", id);
+        assert!(Uuid::parse_str(&id).is_ok());
+
+        assert!(uuid_v7(1, [0xff; 10]) < uuid_v7(2, [0; 10]));
+    }
+
+    /// Passes if a ULID is well formed, carries its timestamp, and sorts by time
+    #[test]
+    fn ulid_format() {
+        assert_eq!("00000000000000000000000000", ulid(0, [0; 10]));
+        assert_eq!(
+            "7ZZZZZZZZZZZZZZZZZZZZZZZZZ",
+            ulid(0xffff_ffff_ffff, [0xff; 10])
+        );
+        assert_eq!("01ARYZ6S41", &ulid(1_469_918_176_385, [0; 10])[..10]);
+
+        assert!(ulid(1, [0xff; 10]) < ulid(2, [0; 10]));
+    }
+
+    /// Passes if ids in the same millisecond take successive counter values, and the timestamp is
+    /// carried forward when the clock goes backwards or the counter runs out
+    #[test]
+    fn next_tick_monotonic() {
+        let last = AtomicU64::new(0);
+        assert_eq!((100, 0), next_tick(&last, 100));
+        assert_eq!((100, 1), next_tick(&last, 100));
+        assert_eq!((100, 2), next_tick(&last, 99));
+        assert_eq!((101, 0), next_tick(&last, 101));
+
+        let last = AtomicU64::new((200 << COUNTER_BITS) + (1 << COUNTER_BITS) - 1);
+        assert_eq!((201, 0), next_tick(&last, 200));
+    }
+
+    /// Passes if time-ordered ids generated in quick succession sort in the order in which they
+    /// were generated
+    #[test]
+    fn time_ordered_ids_monotonic() {
+        for strategy in &[IdStrategy::UuidV7, IdStrategy::Ulid] {
+            let identifier = Identifier::new(strategy.clone());
+            let ids = (0..1000)
+                .map(|_| {
+                    generate_id(&IdGenerators::new(), "Event", &identifier)
+                        .unwrap()
+                        .unwrap()
+                })
+                .collect::<Vec<String>>();
+            assert!(
+                ids.windows(2).all(|w| w[0] < w[1]),
+                "strategy: {:?}",
+                strategy
+            );
+        }
+    }
+
+    /// Passes if each id strategy generates the expected kind of id
+    #[test]
+    fn generate_id_strategies() {
+        let mut id_generators = IdGenerators::new();
+        id_generators.insert("OrderNumber".to_string(), Arc::new(order_number));
+
+        let native = Identifier::new(IdStrategy::Native);
+        assert!(generate_id(&id_generators, "Order", &native)
+            .unwrap()
+            .is_none());
+
+        let v4 = generate_id(
+            &id_generators,
+            "Order",
+            &Identifier::new(IdStrategy::UuidV4),
+        )
+        .unwrap()
+        .unwrap();
+        assert!(Uuid::parse_str(&v4).is_ok());
+
+        let v7 = generate_id(
+            &id_generators,
+            "Order",
+            &Identifier::new(IdStrategy::UuidV7),
+        )
+        .unwrap()
+        .unwrap();
+        assert_eq!("7", &v7[14..15]);
+
+        let ulid = generate_id(&id_generators, "Order", &Identifier::new(IdStrategy::Ulid))
+            .unwrap()
+            .unwrap();
+        assert_eq!(26, ulid.len());
+
+        let custom = Identifier::new(IdStrategy::Custom).with_generator("OrderNumber".to_string());
+        assert_eq!(
+            Some("Order-1".to_string()),
+            generate_id(&id_generators, "Order", &custom).unwrap()
+        );
+    }
+
+    /// Passes if the Custom strategy fails when its id generator is not registered
+    #[test]
+    fn generate_id_generator_not_found() {
+        let custom = Identifier::new(IdStrategy::Custom).with_generator("Missing".to_string());
+        assert!(matches!(
+            generate_id(&IdGenerators::new(), "Order", &custom),
+            Err(Error::IdGeneratorNotFound { name }) if name == "Missing"
+        ));
+    }
+}
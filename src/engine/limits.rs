@@ -0,0 +1,788 @@
+//! This module provides limits on the depth, cost, and result size of GraphQL queries, so that a
+//! single query cannot exhaust the resources of the server or the database.
+
+use crate::engine::schema::NodeType;
+use crate::Error;
+use std::collections::HashMap;
+
+/// Limits on the queries run by an engine. The depth and cost of a query are computed from the
+/// request and checked before it is executed. The number of nodes returned is counted as the
+/// query runs, and the query fails once the count goes past the limit.
+///
+/// The depth of a query is the greatest number of nested fields in its selection set, so that
+/// `query { User { name } }` has a depth of two.
+///
+/// The cost of a query is the sum of the costs of its fields. A field costs its weight, which is
+/// one by default. The cost of the fields selected within a field that returns a list is
+/// multiplied by the list multiplier of the field, which is ten by default, as the selection is
+/// resolved once for each item in the list.
+///
+/// No limit is applied unless it is set.
+///
+/// # Examples
+///
+/// ```rust
+/// # use warpgrapher::engine::limits::QueryLimits;
+///
+/// let limits = QueryLimits::new()
+///     .with_max_depth(8)
+///     .with_max_cost(5000)
+///     .with_max_nodes(1000)
+///     .with_field_weight("Project", "owner", 5)
+///     .with_field_list_multiplier("Project", "issues", 50);
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct QueryLimits {
+    max_depth: Option<usize>,
+    max_cost: Option<u64>,
+    max_nodes: Option<usize>,
+    list_multiplier: u64,
+    field_weights: HashMap<String, u64>,
+    field_list_multipliers: HashMap<String, u64>,
+}
+
+impl QueryLimits {
+    /// Returns a new [`QueryLimits`] that does not limit queries
+    ///
+    /// [`QueryLimits`]: ./struct.QueryLimits.html
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use warpgrapher::engine::limits::QueryLimits;
+    ///
+    /// let limits = QueryLimits::new();
+    /// ```
+    pub fn new() -> QueryLimits {
+        QueryLimits::default()
+    }
+
+    /// Sets the greatest depth of the selection set of a query
+    pub fn with_max_depth(mut self, max_depth: usize) -> QueryLimits {
+        self.max_depth = Some(max_depth);
+        self
+    }
+
+    /// Sets the greatest cost of a query
+    pub fn with_max_cost(mut self, max_cost: u64) -> QueryLimits {
+        self.max_cost = Some(max_cost);
+        self
+    }
+
+    /// Sets the greatest number of nodes and relationships that a request may read from the
+    /// database
+    pub fn with_max_nodes(mut self, max_nodes: usize) -> QueryLimits {
+        self.max_nodes = Some(max_nodes);
+        self
+    }
+
+    /// Sets the default factor by which the cost of the selection of a field that returns a list
+    /// is multiplied
+    pub fn with_list_multiplier(mut self, list_multiplier: u64) -> QueryLimits {
+        self.list_multiplier = list_multiplier;
+        self
+    }
+
+    /// Sets the weight of the field `field_name` of the GraphQL type `type_name`. For example,
+    /// the weight of the root query for `User` nodes is set for the type `Query` and the field
+    /// `User`.
+    pub fn with_field_weight(
+        mut self,
+        type_name: &str,
+        field_name: &str,
+        weight: u64,
+    ) -> QueryLimits {
+        self.field_weights
+            .insert(format!("{}.{}", type_name, field_name), weight);
+        self
+    }
+
+    /// Sets the list multiplier of the field `field_name` of the GraphQL type `type_name`, in
+    /// place of the default list multiplier
+    pub fn with_field_list_multiplier(
+        mut self,
+        type_name: &str,
+        field_name: &str,
+        list_multiplier: u64,
+    ) -> QueryLimits {
+        self.field_list_multipliers
+            .insert(format!("{}.{}", type_name, field_name), list_multiplier);
+        self
+    }
+
+    /// Returns the greatest number of nodes and relationships that a request may read
+    pub fn max_nodes(&self) -> Option<usize> {
+        self.max_nodes
+    }
+
+    /// Checks the depth and cost of the operation `operation_name` of the GraphQL document
+    /// `query`, given the types of the schema. A document that cannot be parsed is rejected. An
+    /// operation that cannot be found is passed, so that the error is reported when the query is
+    /// executed.
+    pub(crate) fn check(
+        &self,
+        query: &str,
+        operation_name: Option<&str>,
+        type_defs: &HashMap<String, NodeType>,
+    ) -> Result<(), Error> {
+        if self.max_depth.is_none() && self.max_cost.is_none() {
+            return Ok(());
+        }
+
+        let document = match Parser::parse(query, self.max_depth) {
+            Ok(document) => document,
+            Err(ParseError::DepthExceeded(depth)) => {
+                return Err(Error::QueryDepthExceeded {
+                    depth,
+                    max: self.max_depth.unwrap_or_default(),
+                })
+            }
+            Err(ParseError::Syntax) => return Err(Error::QueryNotParsed),
+        };
+
+        let operation = match document.operation(operation_name) {
+            Some(operation) => operation,
+            None => return Ok(()),
+        };
+
+        let mut measure = Measure {
+            limits: self,
+            type_defs,
+            fragments: &document.fragments,
+            memo: HashMap::new(),
+            stack: Vec::new(),
+        };
+        let (depth, cost) = measure.selections(&operation.selections, Some(&operation.type_name));
+
+        if let Some(max) = self.max_depth {
+            if depth > max {
+                return Err(Error::QueryDepthExceeded { depth, max });
+            }
+        }
+        if let Some(max) = self.max_cost {
+            if cost > max {
+                return Err(Error::QueryCostExceeded { cost, max });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Default for QueryLimits {
+    fn default() -> Self {
+        QueryLimits {
+            max_depth: None,
+            max_cost: None,
+            max_nodes: None,
+            list_multiplier: 10,
+            field_weights: HashMap::new(),
+            field_list_multipliers: HashMap::new(),
+        }
+    }
+}
+
+/// A selection within the selection set of an operation or fragment
+#[derive(Debug)]
+pub(crate) enum Selection {
+    Field {
+        name: String,
+        selections: Vec<Selection>,
+    },
+    FragmentSpread(String),
+    InlineFragment {
+        type_name: Option<String>,
+        selections: Vec<Selection>,
+    },
+}
+
+#[derive(Debug)]
+pub(crate) struct Operation {
+    pub(crate) name: Option<String>,
+    pub(crate) type_name: String,
+    pub(crate) selections: Vec<Selection>,
+}
+
+#[derive(Debug)]
+pub(crate) struct Fragment {
+    pub(crate) type_name: String,
+    pub(crate) selections: Vec<Selection>,
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct Document {
+    pub(crate) operations: Vec<Operation>,
+    pub(crate) fragments: HashMap<String, Fragment>,
+}
+
+impl Document {
+    /// Returns the operation named `operation_name`, or the only operation of the document if no
+    /// name is given
+    pub(crate) fn operation(&self, operation_name: Option<&str>) -> Option<&Operation> {
+        match operation_name {
+            Some(name) => self
+                .operations
+                .iter()
+                .find(|o| o.name.as_deref() == Some(name)),
+            None if self.operations.len() == 1 => self.operations.first(),
+            None => None,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub(crate) enum ParseError {
+    Syntax,
+    DepthExceeded(usize),
+}
+
+#[derive(Debug, PartialEq)]
+enum Token<'a> {
+    Name(&'a str),
+    Punctuator(char),
+    Spread,
+    Value,
+}
+
+/// Parses the operations and fragments of a GraphQL document, keeping only their selection sets.
+/// Arguments, variables, and directives are skipped.
+pub(crate) struct Parser<'a> {
+    tokens: Vec<Token<'a>>,
+    pos: usize,
+    max_depth: Option<usize>,
+}
+
+impl<'a> Parser<'a> {
+    pub(crate) fn parse(source: &'a str, max_depth: Option<usize>) -> Result<Document, ParseError> {
+        let mut parser = Parser {
+            tokens: tokenize(source)?,
+            pos: 0,
+            max_depth,
+        };
+
+        let mut document = Document::default();
+        while let Some(token) = parser.peek() {
+            match token {
+                Token::Punctuator('{') => document.operations.push(Operation {
+                    name: None,
+                    type_name: "Query".to_string(),
+                    selections: parser.selection_set(0)?,
+                }),
+                Token::Name("fragment") => {
+                    parser.pos += 1;
+                    let name = parser.name()?;
+                    if parser.name()? != "on" {
+                        return Err(ParseError::Syntax);
+                    }
+                    let type_name = parser.name()?;
+                    parser.directives()?;
+                    let selections = parser.selection_set(0)?;
+                    document.fragments.insert(
+                        name.to_string(),
+                        Fragment {
+                            type_name: type_name.to_string(),
+                            selections,
+                        },
+                    );
+                }
+                Token::Name(kind) => {
+                    let type_name = match *kind {
+                        "query" => "Query",
+                        "mutation" => "Mutation",
+                        "subscription" => "Subscription",
+                        _ => return Err(ParseError::Syntax),
+                    };
+                    parser.pos += 1;
+                    let name = match parser.peek() {
+                        Some(Token::Name(name)) => {
+                            let name = name.to_string();
+                            parser.pos += 1;
+                            Some(name)
+                        }
+                        _ => None,
+                    };
+                    if parser.peek() == Some(&Token::Punctuator('(')) {
+                        parser.skip_group()?;
+                    }
+                    parser.directives()?;
+                    document.operations.push(Operation {
+                        name,
+                        type_name: type_name.to_string(),
+                        selections: parser.selection_set(0)?,
+                    });
+                }
+                _ => return Err(ParseError::Syntax),
+            }
+        }
+
+        Ok(document)
+    }
+
+    fn peek(&self) -> Option<&Token<'a>> {
+        self.tokens.get(self.pos)
+    }
+
+    fn name(&mut self) -> Result<&'a str, ParseError> {
+        match self.tokens.get(self.pos) {
+            Some(Token::Name(name)) => {
+                self.pos += 1;
+                Ok(name)
+            }
+            _ => Err(ParseError::Syntax),
+        }
+    }
+
+    fn expect(&mut self, c: char) -> Result<(), ParseError> {
+        if self.peek() == Some(&Token::Punctuator(c)) {
+            self.pos += 1;
+            Ok(())
+        } else {
+            Err(ParseError::Syntax)
+        }
+    }
+
+    /// Skips a group of tokens opened by the current token, such as a list of arguments, up to
+    /// and including the token that closes it
+    fn skip_group(&mut self) -> Result<(), ParseError> {
+        let mut open = 0;
+        while let Some(token) = self.tokens.get(self.pos) {
+            self.pos += 1;
+            match token {
+                Token::Punctuator('(') | Token::Punctuator('[') | Token::Punctuator('{') => {
+                    open += 1
+                }
+                Token::Punctuator(')') | Token::Punctuator(']') | Token::Punctuator('}') => {
+                    open -= 1
+                }
+                _ => (),
+            }
+            if open == 0 {
+                return Ok(());
+            }
+        }
+        Err(ParseError::Syntax)
+    }
+
+    fn directives(&mut self) -> Result<(), ParseError> {
+        while self.peek() == Some(&Token::Punctuator('@')) {
+            self.pos += 1;
+            self.name()?;
+            if self.peek() == Some(&Token::Punctuator('(')) {
+                self.skip_group()?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Parses a selection set, within `depth` fields
+    fn selection_set(&mut self, depth: usize) -> Result<Vec<Selection>, ParseError> {
+        self.expect('{')?;
+        let mut selections = Vec::new();
+        loop {
+            match self.peek() {
+                Some(Token::Punctuator('}')) => {
+                    self.pos += 1;
+                    return Ok(selections);
+                }
+                Some(Token::Spread) => {
+                    self.pos += 1;
+                    match self.peek() {
+                        Some(Token::Name("on")) => {
+                            self.pos += 1;
+                            let type_name = self.name()?.to_string();
+                            self.directives()?;
+                            selections.push(Selection::InlineFragment {
+                                type_name: Some(type_name),
+                                selections: self.selection_set(depth)?,
+                            });
+                        }
+                        Some(Token::Name(name)) => {
+                            let name = name.to_string();
+                            self.pos += 1;
+                            self.directives()?;
+                            selections.push(Selection::FragmentSpread(name));
+                        }
+                        _ => {
+                            self.directives()?;
+                            selections.push(Selection::InlineFragment {
+                                type_name: None,
+                                selections: self.selection_set(depth)?,
+                            });
+                        }
+                    }
+                }
+                Some(Token::Name(_)) => {
+                    let mut name = self.name()?;
+                    if self.peek() == Some(&Token::Punctuator(':')) {
+                        self.pos += 1;
+                        name = self.name()?;
+                    }
+                    if self.peek() == Some(&Token::Punctuator('(')) {
+                        self.skip_group()?;
+                    }
+                    self.directives()?;
+
+                    let field_depth = depth + 1;
+                    if let Some(max) = self.max_depth {
+                        if field_depth > max {
+                            return Err(ParseError::DepthExceeded(field_depth));
+                        }
+                    }
+
+                    let selections_inner = if self.peek() == Some(&Token::Punctuator('{')) {
+                        self.selection_set(field_depth)?
+                    } else {
+                        Vec::new()
+                    };
+                    selections.push(Selection::Field {
+                        name: name.to_string(),
+                        selections: selections_inner,
+                    });
+                }
+                _ => return Err(ParseError::Syntax),
+            }
+        }
+    }
+}
+
+/// Splits a GraphQL document into tokens, dropping whitespace, commas, and comments. String and
+/// number values are kept only as placeholders.
+fn tokenize(source: &str) -> Result<Vec<Token<'_>>, ParseError> {
+    let bytes = source.as_bytes();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let c = bytes[i];
+        match c {
+            b' ' | b'\t' | b'\n' | b'\r' | b',' => i += 1,
+            b'#' => {
+                while i < bytes.len() && bytes[i] != b'\n' && bytes[i] != b'\r' {
+                    i += 1;
+                }
+            }
+            b'.' if source[i..].starts_with("...") => {
+                tokens.push(Token::Spread);
+                i += 3;
+            }
+            b'"' => {
+                if source[i..].starts_with("\"\"\"") {
+                    i += 3;
+                    loop {
+                        if i >= bytes.len() {
+                            return Err(ParseError::Syntax);
+                        } else if source[i..].starts_with("\\\"\"\"") {
+                            i += 4;
+                        } else if source[i..].starts_with("\"\"\"") {
+                            i += 3;
+                            break;
+                        } else {
+                            i += 1;
+                        }
+                    }
+                } else {
+                    i += 1;
+                    loop {
+                        match bytes.get(i) {
+                            None | Some(b'\n') | Some(b'\r') => return Err(ParseError::Syntax),
+                            Some(b'\\') => i += 2,
+                            Some(b'"') => {
+                                i += 1;
+                                break;
+                            }
+                            Some(_) => i += 1,
+                        }
+                    }
+                }
+                tokens.push(Token::Value);
+            }
+            b'!' | b'$' | b'(' | b')' | b':' | b'=' | b'@' | b'[' | b']' | b'{' | b'|' | b'}' => {
+                tokens.push(Token::Punctuator(c as char));
+                i += 1;
+            }
+            b'-' | b'0'..=b'9' => {
+                i += 1;
+                while i < bytes.len()
+                    && (bytes[i].is_ascii_alphanumeric()
+                        || bytes[i] == b'.'
+                        || bytes[i] == b'+'
+                        || bytes[i] == b'-')
+                {
+                    i += 1;
+                }
+                tokens.push(Token::Value);
+            }
+            _ if c == b'_' || c.is_ascii_alphabetic() => {
+                let start = i;
+                while i < bytes.len() && (bytes[i] == b'_' || bytes[i].is_ascii_alphanumeric()) {
+                    i += 1;
+                }
+                tokens.push(Token::Name(&source[start..i]));
+            }
+            _ => return Err(ParseError::Syntax),
+        }
+    }
+
+    Ok(tokens)
+}
+
+/// Measures the depth and cost of selection sets, resolving the type of each field from the
+/// schema. The measure of each fragment is kept, so that a fragment spread many times is
+/// measured once for each type on which it is spread.
+struct Measure<'m> {
+    limits: &'m QueryLimits,
+    type_defs: &'m HashMap<String, NodeType>,
+    fragments: &'m HashMap<String, Fragment>,
+    memo: HashMap<(String, Option<String>), (usize, u64)>,
+    stack: Vec<String>,
+}
+
+impl<'m> Measure<'m> {
+    /// Returns the depth and cost of a selection set on the type `type_name`, or on an unknown
+    /// type if None
+    fn selections(&mut self, selections: &[Selection], type_name: Option<&str>) -> (usize, u64) {
+        selections.iter().fold((0, 0), |(depth, cost), s| {
+            let (d, c) = self.selection(s, type_name);
+            (depth.max(d), cost.saturating_add(c))
+        })
+    }
+
+    fn selection(&mut self, selection: &Selection, type_name: Option<&str>) -> (usize, u64) {
+        match selection {
+            Selection::Field { name, selections } => {
+                let key = type_name.map(|t| format!("{}.{}", t, name));
+                let prop = type_name
+                    .and_then(|t| self.type_defs.get(t))
+                    .and_then(|td| td.property(name).ok());
+                let weight = key
+                    .as_ref()
+                    .and_then(|k| self.limits.field_weights.get(k))
+                    .copied()
+                    .unwrap_or(1);
+                let multiplier = match prop {
+                    Some(p) if p.list() => key
+                        .as_ref()
+                        .and_then(|k| self.limits.field_list_multipliers.get(k))
+                        .copied()
+                        .unwrap_or(self.limits.list_multiplier),
+                    _ => 1,
+                };
+                let field_type = prop.map(|p| p.type_name().to_string());
+
+                let (depth, cost) = self.selections(selections, field_type.as_deref());
+                (
+                    depth + 1,
+                    weight.saturating_add(multiplier.saturating_mul(cost)),
+                )
+            }
+            Selection::InlineFragment {
+                type_name: fragment_type,
+                selections,
+            } => {
+                let t = fragment_type.as_deref().or(type_name);
+                self.selections(selections, t)
+            }
+            Selection::FragmentSpread(name) => {
+                let fragments = self.fragments;
+                let fragment = match fragments.get(name) {
+                    Some(fragment) => fragment,
+                    None => return (0, 0),
+                };
+                let t = if self.type_defs.contains_key(&fragment.type_name) {
+                    Some(fragment.type_name.as_str())
+                } else {
+                    type_name
+                };
+
+                let key = (name.clone(), t.map(|t| t.to_string()));
+                if let Some(measure) = self.memo.get(&key) {
+                    return *measure;
+                }
+                if self.stack.contains(name) {
+                    return (0, 0);
+                }
+
+                self.stack.push(name.clone());
+                let measure = self.selections(&fragment.selections, t);
+                self.stack.pop();
+                self.memo.insert(key, measure);
+                measure
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{tokenize, ParseError, Parser, QueryLimits, Token};
+    use crate::engine::config::Configuration;
+    use crate::engine::schema::Info;
+    use crate::Error;
+    use std::convert::TryFrom;
+
+    fn mock_info() -> Info {
+        Info::from(
+            &Configuration::try_from(
+                "
+version: 1
+model:
+  - name: Project
+    props:
+      - name: name
+        type: String
+    rels:
+      - name: issues
+        list: true
+        nodes: [Issue]
+  - name: Issue
+    props:
+      - name: title
+        type: String
+",
+            )
+            .unwrap(),
+        )
+    }
+
+    /// Passes if comments, strings, and spreads are tokenized, and an unterminated string is a
+    /// syntax error
+    #[test]
+    fn tokenize_document() {
+        let tokens = tokenize("{ a(s: \"}\", b: \"\"\"x\"\"\") # }\n ...f }").unwrap();
+        assert_eq!(
+            tokens,
+            vec![
+                Token::Punctuator('{'),
+                Token::Name("a"),
+                Token::Punctuator('('),
+                Token::Name("s"),
+                Token::Punctuator(':'),
+                Token::Value,
+                Token::Name("b"),
+                Token::Punctuator(':'),
+                Token::Value,
+                Token::Punctuator(')'),
+                Token::Spread,
+                Token::Name("f"),
+                Token::Punctuator('}'),
+            ]
+        );
+        assert_eq!(tokenize("{ a(s: \"x) }"), Err(ParseError::Syntax));
+    }
+
+    /// Passes if the operations and fragments of a document are parsed, and parsing stops as
+    /// soon as the maximum depth is exceeded
+    #[test]
+    fn parse_document() {
+        let document = Parser::parse(
+            "query Q($id: ID) @a { p: Project(input: {id: $id}) { ...F } }
+             mutation M { ProjectDelete(input: {}) }
+             fragment F on Project { name }",
+            None,
+        )
+        .unwrap();
+        assert_eq!(document.operations.len(), 2);
+        assert_eq!(document.operations[0].name.as_deref(), Some("Q"));
+        assert_eq!(document.operations[1].type_name, "Mutation");
+        assert!(document.fragments.contains_key("F"));
+
+        assert_eq!(
+            Parser::parse("{ a { b { c { d } } } }", Some(2)).unwrap_err(),
+            ParseError::DepthExceeded(3)
+        );
+    }
+
+    /// Passes if the depth of a query is limited, counting fields within fragments
+    #[test]
+    fn check_depth() {
+        let info = mock_info();
+        let limits = QueryLimits::new().with_max_depth(4);
+        let query = "query { Project { issues { dst { ... on Issue { title } } } } }";
+        assert!(limits.check(query, None, &info.type_defs()).is_ok());
+
+        let query = "query { Project { issues { dst { ...I } } } }
+                     fragment I on Issue { title }";
+        assert!(limits.check(query, None, &info.type_defs()).is_ok());
+
+        let query = "query { Project { issues { dst { ... on Issue { title } } props { id } } } }";
+        assert!(limits.check(query, None, &info.type_defs()).is_ok());
+
+        let limits = QueryLimits::new().with_max_depth(3);
+        let query = "query { Project { issues { dst { ...I } } } }
+                     fragment I on Issue { title }";
+        assert!(matches!(
+            limits.check(query, None, &info.type_defs()),
+            Err(Error::QueryDepthExceeded { depth: 4, max: 3 })
+        ));
+
+        let query = "query { Project { name } ";
+        assert!(matches!(
+            limits.check(query, None, &info.type_defs()),
+            Err(Error::QueryNotParsed)
+        ));
+    }
+
+    /// Passes if the cost of a query counts aliased fields, weights, and list multipliers, and
+    /// if only the named operation is measured
+    #[test]
+    fn check_cost() {
+        let info = mock_info();
+        let cost = |limits: QueryLimits, query: &str, operation_name: Option<&str>| match limits
+            .with_max_cost(0)
+            .check(query, operation_name, &info.type_defs())
+        {
+            Err(Error::QueryCostExceeded { cost, .. }) => cost,
+            r => panic!("{:?}", r),
+        };
+
+        // Project (1) + 10 * (name (1) + issues (1) + 10 * (id (1)))
+        let query = "query { Project { name issues { id } } }";
+        assert_eq!(cost(QueryLimits::new(), query, None), 121);
+
+        let query = "query { a: Project { name } b: Project { name } }";
+        assert_eq!(cost(QueryLimits::new(), query, None), 22);
+
+        let limits = QueryLimits::new()
+            .with_list_multiplier(2)
+            .with_field_weight("Query", "Project", 5)
+            .with_field_list_multiplier("Project", "issues", 3);
+        let query = "query { Project { name issues { id } } }";
+        assert_eq!(cost(limits, query, None), 5 + 2 * (1 + 1 + 3));
+
+        let query = "query A { Project { name } } query B { Issue { title } Project { name } }";
+        assert_eq!(cost(QueryLimits::new(), query, Some("B")), 22);
+
+        let limits = QueryLimits::new().with_max_cost(121);
+        let query = "query { Project { name issues { id } } }";
+        assert!(limits.check(query, None, &info.type_defs()).is_ok());
+    }
+
+    /// Passes if fragments spread many times are measured without walking every spread, and a
+    /// cycle of fragments ends
+    #[test]
+    fn check_fragments() {
+        let info = mock_info();
+        let mut query = "query { Project { ...F0 } } fragment F30 on Project { name }".to_string();
+        for i in 0..30 {
+            query.push_str(&format!(
+                " fragment F{} on Project {{ ...F{} ... {{ ...F{} }} }}",
+                i,
+                i + 1,
+                i + 1
+            ));
+        }
+        assert!(matches!(
+            QueryLimits::new()
+                .with_max_cost(100)
+                .check(&query, None, &info.type_defs()),
+            Err(Error::QueryCostExceeded { .. })
+        ));
+
+        let query = "query { Project { ...A } } fragment A on Project { ...B }
+                     fragment B on Project { name ...A }";
+        assert!(QueryLimits::new()
+            .with_max_cost(100)
+            .check(query, None, &info.type_defs())
+            .is_ok());
+    }
+}
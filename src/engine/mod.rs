@@ -2,26 +2,38 @@
 //! GraphQL schema generation, resolvers, and interface to the database.
 
 use super::error::Error;
-use config::Configuration;
+use cache::{CachePlan, CacheStore};
+use config::{Configuration, IdStrategy};
 use context::{GraphQLContext, RequestContext};
+use database::retry::RetryPolicy;
 use database::DatabasePool;
 use extensions::Extensions;
+use id_generators::IdGenerators;
 use juniper::http::GraphQLRequest;
-use log::debug;
+use limits::QueryLimits;
+use log::{debug, error};
+use persisted::{PersistedQueries, PersistedRequest};
 use resolvers::Resolvers;
-use schema::{create_root_node, RootRef};
+use schema::{create_root_node, Info, RootRef};
 use std::collections::HashMap;
 use std::fmt::{Debug, Display, Formatter};
 use std::option::Option;
-use validators::Validators;
+use std::sync::Arc;
+use triggers::Triggers;
+use validators::{ContextValidators, Validators};
 
+pub mod cache;
 pub mod config;
 pub mod context;
 pub mod database;
 pub mod extensions;
+pub mod id_generators;
+pub mod limits;
 pub mod objects;
+pub mod persisted;
 pub mod resolvers;
 pub mod schema;
+pub mod triggers;
 pub mod validators;
 pub mod value;
 
@@ -48,9 +60,17 @@ where
     config: Configuration,
     db_pool: DatabasePool,
     extensions: Extensions<RequestCtx>,
-    resolvers: Resolvers<RequestCtx>,
-    validators: Validators,
+    resolvers: Arc<Resolvers<RequestCtx>>,
+    validators: Arc<Validators>,
+    context_validators: Arc<ContextValidators<RequestCtx>>,
+    triggers: Arc<Triggers<RequestCtx>>,
+    id_generators: IdGenerators,
     version: Option<String>,
+    retry_policy: RetryPolicy,
+    production_mode: bool,
+    query_limits: QueryLimits,
+    persisted_queries: PersistedQueries,
+    cache_store: Option<Arc<dyn CacheStore>>,
 }
 
 impl<RequestCtx> EngineBuilder<RequestCtx>
@@ -77,7 +97,7 @@ where
     /// # }
     /// ```
     pub fn with_resolvers(mut self, resolvers: Resolvers<RequestCtx>) -> EngineBuilder<RequestCtx> {
-        self.resolvers = resolvers;
+        self.resolvers = Arc::new(resolvers);
         self
     }
 
@@ -101,7 +121,84 @@ where
     /// # }
     /// ```
     pub fn with_validators(mut self, validators: Validators) -> EngineBuilder<RequestCtx> {
-        self.validators = validators;
+        self.validators = Arc::new(validators);
+        self
+    }
+
+    /// Adds context-aware validators to the engine, for types and relationships that name a
+    /// `validator` in the configuration
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use warpgrapher::{Configuration, DatabasePool, Engine};
+    /// # use warpgrapher::engine::validators::ContextValidators;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let context_validators = ContextValidators::new();
+    ///
+    /// let config = Configuration::default();
+    ///
+    /// let mut engine = Engine::<()>::new(config, DatabasePool::NoDatabase)
+    ///     .with_context_validators(context_validators)
+    ///     .build()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_context_validators(
+        mut self,
+        context_validators: ContextValidators<RequestCtx>,
+    ) -> EngineBuilder<RequestCtx> {
+        self.context_validators = Arc::new(context_validators);
+        self
+    }
+
+    /// Adds trigger functions to the engine, for types and relationships that name `triggers` in
+    /// the configuration
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use warpgrapher::{Configuration, DatabasePool, Engine};
+    /// # use warpgrapher::engine::triggers::Triggers;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let triggers = Triggers::new();
+    ///
+    /// let config = Configuration::default();
+    ///
+    /// let mut engine = Engine::<()>::new(config, DatabasePool::NoDatabase)
+    ///     .with_triggers(triggers)
+    ///     .build()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_triggers(mut self, triggers: Triggers<RequestCtx>) -> EngineBuilder<RequestCtx> {
+        self.triggers = Arc::new(triggers);
+        self
+    }
+
+    /// Adds custom id generators to the engine, for types that use the `Custom` id strategy
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use warpgrapher::{Configuration, DatabasePool, Engine};
+    /// # use warpgrapher::engine::id_generators::IdGenerators;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let id_generators = IdGenerators::new();
+    ///
+    /// let config = Configuration::default();
+    ///
+    /// let mut engine = Engine::<()>::new(config, DatabasePool::NoDatabase)
+    ///     .with_id_generators(id_generators)
+    ///     .build()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_id_generators(mut self, id_generators: IdGenerators) -> EngineBuilder<RequestCtx> {
+        self.id_generators = id_generators;
         self
     }
 
@@ -153,6 +250,147 @@ where
         self
     }
 
+    /// Sets the policy for retrying database operations that fail with transient errors, such
+    /// as deadlocks and throttling. If not set, the default [`RetryPolicy`] is used.
+    ///
+    /// [`RetryPolicy`]: ./database/retry/struct.RetryPolicy.html
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::time::Duration;
+    /// # use warpgrapher::{Configuration, DatabasePool, Engine};
+    /// # use warpgrapher::engine::database::retry::RetryPolicy;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let config = Configuration::default();
+    ///
+    /// let mut engine = Engine::<()>::new(config, DatabasePool::NoDatabase)
+    ///     .with_retry_policy(RetryPolicy::new().with_max_attempts(5))
+    ///     .build()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> EngineBuilder<RequestCtx> {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Sets whether the engine runs in production mode. In production mode, the messages of
+    /// errors with the codes `DATABASE_UNAVAILABLE`, `DATABASE_ERROR`, and `INTERNAL_ERROR` are
+    /// replaced with generic messages, and their details removed, so that internal database
+    /// messages are not returned to clients. The original messages are logged. Defaults to false.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use warpgrapher::{Configuration, DatabasePool, Engine};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let config = Configuration::default();
+    ///
+    /// let mut engine = Engine::<()>::new(config, DatabasePool::NoDatabase)
+    ///     .with_production_mode(true)
+    ///     .build()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_production_mode(mut self, production_mode: bool) -> EngineBuilder<RequestCtx> {
+        self.production_mode = production_mode;
+        self
+    }
+
+    /// Sets the [`QueryLimits`] on the depth, cost, and number of nodes read by a query. Queries
+    /// that are too deep or too costly are rejected before they are executed. If not set, queries
+    /// are not limited.
+    ///
+    /// [`QueryLimits`]: ./limits/struct.QueryLimits.html
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use warpgrapher::{Configuration, DatabasePool, Engine};
+    /// # use warpgrapher::engine::limits::QueryLimits;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let config = Configuration::default();
+    ///
+    /// let mut engine = Engine::<()>::new(config, DatabasePool::NoDatabase)
+    ///     .with_query_limits(QueryLimits::new().with_max_depth(10).with_max_cost(1000))
+    ///     .build()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_query_limits(mut self, query_limits: QueryLimits) -> EngineBuilder<RequestCtx> {
+        self.query_limits = query_limits;
+        self
+    }
+
+    /// Sets the store of [`PersistedQueries`], used to run requests that name a persisted query
+    /// in place of sending the query document, with [`execute_persisted`]. If the store is in
+    /// persisted only mode, any query that is not persisted is rejected.
+    ///
+    /// [`execute_persisted`]: ./struct.Engine.html#method.execute_persisted
+    /// [`PersistedQueries`]: ./persisted/struct.PersistedQueries.html
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use warpgrapher::{Configuration, DatabasePool, Engine};
+    /// # use warpgrapher::engine::persisted::PersistedQueries;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let config = Configuration::default();
+    /// let pq = PersistedQueries::new().with_query("Version", "query Version { _version }");
+    ///
+    /// let mut engine = Engine::<()>::new(config, DatabasePool::NoDatabase)
+    ///     .with_persisted_queries(pq)
+    ///     .build()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_persisted_queries(
+        mut self,
+        persisted_queries: PersistedQueries,
+    ) -> EngineBuilder<RequestCtx> {
+        self.persisted_queries = persisted_queries;
+        self
+    }
+
+    /// Sets the [`CacheStore`] in which the responses to read queries are cached. A query is
+    /// cached only if every type it reads has a `cacheTtl` in the configuration, and the response
+    /// is kept for the least of those times to live. Cached responses are invalidated when the
+    /// generated create, update, and delete mutations write to the types they read.
+    ///
+    /// Requests served from the cache do not run resolvers, or the operation and field hooks of
+    /// extensions. The pre request and post request hooks still run.
+    ///
+    /// [`CacheStore`]: ./cache/trait.CacheStore.html
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::sync::Arc;
+    /// # use warpgrapher::{Configuration, DatabasePool, Engine};
+    /// # use warpgrapher::engine::cache::MemoryCacheStore;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let config = Configuration::default();
+    ///
+    /// let mut engine = Engine::<()>::new(config, DatabasePool::NoDatabase)
+    ///     .with_cache_store(Arc::new(MemoryCacheStore::new()))
+    ///     .build()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_cache_store(
+        mut self,
+        cache_store: Arc<dyn CacheStore>,
+    ) -> EngineBuilder<RequestCtx> {
+        self.cache_store = Some(cache_store);
+        self
+    }
+
     /// Builds a configured [`Engine`] including generating the data model, CRUD operations, and
     /// custom endpoints from the [`Configuration`] `c`. Returns the [`Engine`].
     ///
@@ -180,19 +418,33 @@ where
     /// configuration for which no [`ValidatorFunc`] has been added to the [`Validators`] collection
     /// applied to the EngineBuilder with [`with_validators`].
     ///
-    /// Returns an
+    /// Returns an [`Error`] variant [`TriggerNotFound`] if there is a trigger defined in the
+    /// configuration for which no [`TriggerFunc`] has been added to the [`Triggers`] collection
+    /// applied to the EngineBuilder with [`with_triggers`].
+    ///
+    /// Returns an [`Error`] variant [`IdGeneratorNotFound`] if a type in the configuration uses
+    /// the `Custom` id strategy, but no [`IdGeneratorFunc`] has been added to the [`IdGenerators`]
+    /// collection applied to the EngineBuilder with [`with_id_generators`].
     ///
     /// [`ConfigItemDuplicated`]: ../error/enum.Error.html#variant.ConfigItemDuplicated
     /// [`ConfigItemReserved`]: ../error/enum.Error.html#variant.ConfigItemReserved
     /// [`Error`]: ../error/enum.Error.html
+    /// [`IdGeneratorFunc`]: ./id_generators/type.IdGeneratorFunc.html
+    /// [`IdGeneratorNotFound`]: ../error/enum.Error.html#variant.IdGeneratorNotFound
+    /// [`IdGenerators`]: ./id_generators/type.IdGenerators.html
     /// [`ResolverNotFound`]: ../error/enum.Error.html#variant.ResolverNotFound
     /// [`ResolverFunc`]: ./resolvers/type.ResolverFunc.html
     /// [`Resolvers`]: ./resolvers/type.Resolvers.html
     /// [`SchemaItemNotFound`]: ../error/enum.Error.html#variant.SchemaItemNotFound
+    /// [`TriggerFunc`]: ./triggers/type.TriggerFunc.html
+    /// [`TriggerNotFound`]: ../error/enum.Error.html#variant.TriggerNotFound
+    /// [`Triggers`]: ./triggers/type.Triggers.html
     /// [`ValidatorNotFound`]: ../error/enum.Error.html#variant.ValidatorNotFound
     /// [`ValidatorFunc`]: ./validators/type.ValidatorFunc.html
     /// [`Validators`]: ./validators/type.Validators.html
+    /// [`with_id_generators`]: ./struct.EngineBuilder.html#method.with_id_generators
     /// [`with_resolvers`]: ./struct.EngineBuilder.html#method.with_resolvers
+    /// [`with_triggers`]: ./struct.EngineBuilder.html#method.with_triggers
     /// [`with_validators`]: ./struct.EngineBuilder.html#method.with_validators
     ///
     /// # Examples
@@ -217,8 +469,16 @@ where
             db_pool: self.db_pool,
             resolvers: self.resolvers,
             validators: self.validators,
+            context_validators: self.context_validators,
+            triggers: self.triggers,
+            id_generators: self.id_generators,
             extensions: self.extensions,
             version: self.version,
+            retry_policy: self.retry_policy,
+            production_mode: self.production_mode,
+            query_limits: self.query_limits,
+            persisted_queries: self.persisted_queries,
+            cache_store: self.cache_store,
             root_node,
         };
 
@@ -273,6 +533,47 @@ where
                     })
                     .collect::<Result<Vec<_>, Error>>()?;
 
+                // Validate that context-aware validators defined in Configuration exist
+                t.validator()
+                    .into_iter()
+                    .chain(t.rels().filter_map(|r| r.validator()))
+                    .map(|v| {
+                        if !self.context_validators.contains_key(v) {
+                            Err(Error::ValidatorNotFound {
+                                name: v.to_string(),
+                            })
+                        } else {
+                            Ok(())
+                        }
+                    })
+                    .collect::<Result<Vec<_>, Error>>()?;
+
+                // Validate that trigger functions named in Configuration exist
+                t.triggers()
+                    .chain(t.rels().flat_map(|r| r.triggers()))
+                    .map(|tr| {
+                        if !self.triggers.contains_key(tr.handler()) {
+                            Err(Error::TriggerNotFound {
+                                name: tr.handler().to_string(),
+                            })
+                        } else {
+                            Ok(())
+                        }
+                    })
+                    .collect::<Result<Vec<_>, Error>>()?;
+
+                // Validate that a custom id generator named in Configuration exists
+                if let Some(id) = t.id() {
+                    if let IdStrategy::Custom = id.strategy() {
+                        let name = id.generator().unwrap_or_else(|| t.name());
+                        if !self.id_generators.contains_key(name) {
+                            return Err(Error::IdGeneratorNotFound {
+                                name: name.to_string(),
+                            });
+                        }
+                    }
+                }
+
                 Ok(())
             })
             .collect::<Result<Vec<_>, Error>>()?;
@@ -288,6 +589,10 @@ impl Debug for EngineBuilder {
             .field("config", &self.config)
             .field("db_pool", &self.db_pool)
             .field("version", &self.version)
+            .field("retry_policy", &self.retry_policy)
+            .field("production_mode", &self.production_mode)
+            .field("query_limits", &self.query_limits)
+            .field("cache_store", &self.cache_store)
             .finish()
     }
 }
@@ -320,10 +625,18 @@ where
 {
     config: Configuration,
     db_pool: DatabasePool,
-    resolvers: Resolvers<RequestCtx>,
-    validators: Validators,
+    resolvers: Arc<Resolvers<RequestCtx>>,
+    validators: Arc<Validators>,
+    context_validators: Arc<ContextValidators<RequestCtx>>,
+    triggers: Arc<Triggers<RequestCtx>>,
+    id_generators: IdGenerators,
     extensions: Extensions<RequestCtx>,
     version: Option<String>,
+    retry_policy: RetryPolicy,
+    production_mode: bool,
+    query_limits: QueryLimits,
+    persisted_queries: PersistedQueries,
+    cache_store: Option<Arc<dyn CacheStore>>,
     root_node: RootRef<RequestCtx>,
 }
 
@@ -357,13 +670,45 @@ where
         EngineBuilder::<RequestCtx> {
             config,
             db_pool: database_pool,
-            resolvers: HashMap::new(),
-            validators: HashMap::new(),
+            resolvers: Arc::new(Resolvers::new()),
+            validators: Arc::new(Validators::new()),
+            context_validators: Arc::new(ContextValidators::new()),
+            triggers: Arc::new(Triggers::new()),
+            id_generators: HashMap::new(),
             extensions: vec![],
             version: None,
+            retry_policy: RetryPolicy::default(),
+            production_mode: false,
+            query_limits: QueryLimits::default(),
+            persisted_queries: PersistedQueries::default(),
+            cache_store: None,
         }
     }
 
+    /// Returns the [`Info`] describing the root `Query` type of the schema served by the engine,
+    /// from which all other types in the schema can be reached.
+    ///
+    /// [`Info`]: ./schema/struct.Info.html
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use warpgrapher::{Configuration, DatabasePool, Engine};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let config = Configuration::default();
+    /// let engine = Engine::<()>::new(config, DatabasePool::NoDatabase).build()?;
+    ///
+    /// for node_type in engine.info().type_defs().values() {
+    ///     println!("{}", node_type.type_name());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn info(&self) -> Info {
+        self.root_node.query_info.clone()
+    }
+
     /// Executes a [`GraphQLRequest`], returning a serialized JSON response.
     ///
     /// [`GraphQLRequest`]: ../../juniper/http/struct.GraphQLRequest.html
@@ -405,6 +750,10 @@ where
     ) -> Result<serde_json::Value, Error> {
         debug!("Engine::execute called");
 
+        // transactions of the request are causally chained with each other, but not with those
+        // of other requests
+        let db_pool = self.db_pool.session();
+
         // run pre request plugin hooks
         let req_ctx = self
             .extensions
@@ -414,26 +763,59 @@ where
                     req.operation_name().map(|v| v.to_string()),
                     req_ctx,
                     &metadata,
-                    self.db_pool.clone(),
+                    db_pool.clone(),
                 )
             })?;
 
-        // execute graphql query
-        let res = req.execute(
-            &self.root_node,
-            &GraphQLContext::<RequestCtx>::new(
-                self.db_pool.clone(),
-                self.resolvers.clone(),
-                self.validators.clone(),
-                self.extensions.clone(),
-                Some(req_ctx.clone()),
-                self.version.clone(),
-                metadata.clone(),
-            ),
-        );
+        // reject queries that are not persisted, in persisted only mode, or that go past the depth
+        // or cost limits, or else serve the response from the cache, or else execute the query
+        // and convert graphql response (json) to mutable serde_json::Value
+        let req_value = serde_json::to_value(req)?;
+        let query = req_value.get("query").and_then(|q| q.as_str());
+        let (plan, key) = self.cache_plan(query, req, &req_value, &req_ctx);
 
-        // convert graphql response (json) to mutable serde_json::Value
-        let res_value = serde_json::to_value(&res)?;
+        let mut res_value = if let Err(e) = self.check_request(query, req) {
+            error_response(&e)
+        } else if let Some(cached) = key
+            .as_ref()
+            .and_then(|key| self.cache_store.as_ref()?.get(key))
+        {
+            debug!("Engine::execute -- serving response from cache");
+            cached
+        } else {
+            let generation = self.cache_store.as_ref().map_or(0, |c| c.generation());
+            let res = req.execute(
+                &self.root_node,
+                &GraphQLContext::<RequestCtx>::new(
+                    db_pool,
+                    self.resolvers.clone(),
+                    self.validators.clone(),
+                    self.extensions.clone(),
+                    Some(req_ctx.clone()),
+                    self.version.clone(),
+                    metadata.clone(),
+                )
+                .with_retry_policy(self.retry_policy.clone())
+                .with_context_validators(self.context_validators.clone())
+                .with_triggers(self.triggers.clone())
+                .with_query_limits(self.query_limits.clone())
+                .with_id_generators(self.id_generators.clone())
+                .with_cache_store(self.cache_store.clone()),
+            );
+            let res_value = serde_json::to_value(&res)?;
+            self.update_cache(plan, key, generation, &res_value);
+            res_value
+        };
+
+        // add error codes to the extensions of errors, and mask internal errors in production
+        if let Some(errors) = res_value
+            .get_mut("errors")
+            .and_then(|errors| errors.as_array_mut())
+        {
+            errors
+                .iter_mut()
+                .for_each(|err| format_error(err, self.production_mode));
+        }
 
         // run post request plugin hooks
         let ret_value = self.extensions.iter().try_fold(res_value, |res_value, e| {
@@ -443,6 +825,163 @@ where
         debug!("Engine::execute -- ret_value: {:#?}", ret_value);
         Ok(ret_value)
     }
+
+    /// Executes a [`PersistedRequest`], which may name a persisted query in place of sending the
+    /// query document, returning a serialized JSON response. A request that sends the sha256
+    /// hash of its query in the `persistedQuery` extension, as clients that use automatic
+    /// persisted queries do, registers the query with the engine, so that later requests may
+    /// send only the hash.
+    ///
+    /// A service that serves persisted queries deserializes the body of each GraphQL request into
+    /// a [`PersistedRequest`] and calls this method in place of [`execute`]. A request that sends
+    /// only a query document is run as it would be by [`execute`].
+    ///
+    /// If the persisted query cannot be found, the response holds an error with the code
+    /// `PERSISTED_QUERY_NOT_FOUND`.
+    ///
+    /// [`execute`]: #method.execute
+    /// [`PersistedRequest`]: ./persisted/struct.PersistedRequest.html
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`] variant [`ExtensionFailed`] if a pre request hook or post request
+    /// hook extension returns an error.
+    ///
+    /// Returns an [`Error`] variant [`SerializationFailed`] if the variables of the request are
+    /// not valid, or if the engine response cannot be serialized successfully.
+    ///
+    /// [`ExtensionFailed`]: ../error/enum.Error.html#variant.ExtensionFailed
+    /// [`Error`]: ../error/enum.Error.html
+    /// [`SerializationFailed`]: ../error/enum.Error.html#variant.SerializationFailed
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::collections::HashMap;
+    /// # use warpgrapher::{Configuration, DatabasePool, Engine};
+    /// # use warpgrapher::engine::persisted::{PersistedQueries, PersistedRequest};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let config = Configuration::default();
+    /// let pq = PersistedQueries::new().with_query("Version", "query Version { _version }");
+    /// let engine = Engine::<()>::new(config, DatabasePool::NoDatabase)
+    ///     .with_persisted_queries(pq)
+    ///     .with_version("1.0.0".to_string())
+    ///     .build()?;
+    ///
+    /// let result = engine.execute_persisted(&PersistedRequest::with_id("Version"), &HashMap::new())?;
+    /// assert_eq!(result["data"]["_version"], "1.0.0");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn execute_persisted(
+        &self,
+        req: &PersistedRequest,
+        metadata: &HashMap<String, String>,
+    ) -> Result<serde_json::Value, Error> {
+        debug!("Engine::execute_persisted called");
+
+        let query = match self.persisted_queries.resolve(req) {
+            Ok(query) => query,
+            Err(e) => {
+                let mut res_value = error_response(&e);
+                if let Some(errors) = res_value["errors"].as_array_mut() {
+                    errors
+                        .iter_mut()
+                        .for_each(|err| format_error(err, self.production_mode));
+                }
+                return Ok(res_value);
+            }
+        };
+
+        let variables = req
+            .variables()
+            .map(|v| serde_json::from_value(v.clone()))
+            .transpose()?;
+        self.execute(
+            &GraphQLRequest::new(
+                query,
+                req.operation_name().map(|name| name.to_string()),
+                variables,
+            ),
+            metadata,
+        )
+    }
+
+    /// Checks that the query of a request is persisted, if the engine runs only persisted
+    /// queries, and checks the depth and cost of the operation run against the query limits
+    fn check_request(&self, query: Option<&str>, req: &GraphQLRequest) -> Result<(), Error> {
+        match query {
+            Some(query) => {
+                self.persisted_queries.check(query)?;
+                self.query_limits.check(
+                    query,
+                    req.operation_name(),
+                    &self.root_node.query_info.type_defs(),
+                )
+            }
+            None => Ok(()),
+        }
+    }
+
+    /// Returns how a request uses the cache, along with the key of its response for a read
+    /// query. Requests whose request context has no cache key are not read from the cache.
+    fn cache_plan(
+        &self,
+        query: Option<&str>,
+        req: &GraphQLRequest,
+        req_value: &serde_json::Value,
+        req_ctx: &RequestCtx,
+    ) -> (CachePlan, Option<String>) {
+        let query = match (&self.cache_store, query) {
+            (Some(_), Some(query)) => query,
+            _ => return (CachePlan::Bypass, None),
+        };
+
+        let plan = CachePlan::new(
+            query,
+            req.operation_name(),
+            &self.config,
+            &self.root_node.query_info.type_defs(),
+        );
+        let key = match plan {
+            CachePlan::Read { .. } => req_ctx.cache_key().map(|context_key| {
+                cache::cache_key(
+                    query,
+                    req.operation_name(),
+                    &req_value["variables"],
+                    &context_key,
+                )
+            }),
+            _ => None,
+        };
+        (plan, key)
+    }
+
+    /// Stores the response to a read query that has no errors, unless the types it reads were
+    /// invalidated after the generation of the cache store at which the query began, or
+    /// invalidates the responses that read the types written by a mutation
+    fn update_cache(
+        &self,
+        plan: CachePlan,
+        key: Option<String>,
+        generation: u64,
+        res_value: &serde_json::Value,
+    ) {
+        let cache_store = match &self.cache_store {
+            Some(cache_store) => cache_store,
+            None => return,
+        };
+
+        match plan {
+            CachePlan::Read { ttl, type_names } => {
+                if let (Some(key), None) = (key, res_value.get("errors")) {
+                    cache_store.insert(key, res_value.clone(), ttl, type_names, generation);
+                }
+            }
+            plan => plan.invalidate(&**cache_store),
+        }
+    }
 }
 
 impl<RequestCtx> Display for Engine<RequestCtx>
@@ -463,10 +1002,71 @@ where
             .field("config", &self.config)
             .field("db_pool", &self.db_pool)
             .field("version", &self.version)
+            .field("retry_policy", &self.retry_policy)
+            .field("production_mode", &self.production_mode)
+            .field("query_limits", &self.query_limits)
+            .field("cache_store", &self.cache_store)
             .finish()
     }
 }
 
+/// Returns a GraphQL response for a request rejected before it is executed
+fn error_response(e: &Error) -> serde_json::Value {
+    serde_json::json!({ "errors": [{
+        "message": e.to_string(),
+        "extensions": { "code": e.code(), "details": e.details() }
+    }]})
+}
+
+/// Sets a default code in the extensions of a GraphQL error that does not already have one.
+/// Errors raised while resolving a field, which have a path, default to `INTERNAL_ERROR`. Errors
+/// raised while parsing or validating the request, which have no path, default to
+/// `GRAPHQL_VALIDATION_FAILED`. In production mode, replaces the message of internal errors.
+fn format_error(err: &mut serde_json::Value, production_mode: bool) {
+    let default_code = if err.get("path").is_some() {
+        "INTERNAL_ERROR"
+    } else {
+        "GRAPHQL_VALIDATION_FAILED"
+    };
+
+    let obj = match err.as_object_mut() {
+        Some(obj) => obj,
+        None => return,
+    };
+
+    let extensions = obj
+        .entry("extensions")
+        .or_insert_with(|| serde_json::Value::Object(serde_json::Map::new()));
+    if !extensions.is_object() {
+        *extensions = serde_json::Value::Object(serde_json::Map::new());
+    }
+    let ext = match extensions.as_object_mut() {
+        Some(ext) => ext,
+        None => return,
+    };
+    let code = ext
+        .entry("code")
+        .or_insert_with(|| serde_json::Value::String(default_code.to_string()))
+        .as_str()
+        .unwrap_or(default_code)
+        .to_string();
+
+    if production_mode {
+        let message = match code.as_str() {
+            "DATABASE_UNAVAILABLE" => "The database is unavailable.",
+            "DATABASE_ERROR" | "INTERNAL_ERROR" => "Internal server error.",
+            _ => return,
+        };
+        ext.remove("details");
+        if let Some(original) = obj.insert(
+            "message".to_string(),
+            serde_json::Value::String(message.to_string()),
+        ) {
+            error!("format_error -- {}: {}", code, original);
+        }
+    }
+}
+
 /// Notably, the unit tests here likely seem weak. This is because testing most
 /// of the functionality requires a database container to be running and
 /// reachable, so most of the coverage is provided by integration tests.
@@ -474,13 +1074,16 @@ where
 mod tests {
     use super::EngineBuilder;
     use crate::engine::database::DatabasePool;
+    use crate::engine::id_generators::IdGenerators;
     use crate::engine::resolvers::{ResolverFacade, Resolvers};
-    use crate::engine::validators::Validators;
+    use crate::engine::triggers::{TriggerContext, Triggers};
+    use crate::engine::validators::{ContextValidators, ValidationContext, Validators};
     use crate::engine::value::Value;
     use crate::{Configuration, Engine, Error};
     use juniper::ExecutionResult;
     use std::convert::TryInto;
     use std::fs::File;
+    use std::sync::Arc;
 
     /// Passes if the engine can be created.
     #[test]
@@ -521,7 +1124,7 @@ mod tests {
         //No validator in config
         //is_ok
         let mut validators = Validators::new();
-        validators.insert("MyValidator".to_string(), Box::new(my_validator));
+        validators.insert("MyValidator".to_string(), Arc::new(my_validator));
         assert!(Engine::<()>::new(
             File::open("tests/fixtures/minimal.yml")
                 .expect("Couldn't read config")
@@ -537,7 +1140,7 @@ mod tests {
         //Validator in config
         //is_ok
         let mut validators = Validators::new();
-        validators.insert("MyValidator".to_string(), Box::new(my_validator));
+        validators.insert("MyValidator".to_string(), Arc::new(my_validator));
         assert!(Engine::<()>::new(
             File::open("tests/fixtures/config-validation/test_config_with_custom_validator.yml")
                 .expect("Couldn't read config")
@@ -602,7 +1205,7 @@ mod tests {
         //Resolver defined
         //is_ok
         let mut resolvers = Resolvers::<()>::new();
-        resolvers.insert("MyResolver".to_string(), Box::new(my_resolver));
+        resolvers.insert("MyResolver".to_string(), Arc::new(my_resolver));
         assert!(Engine::<()>::new(
             TryInto::<Configuration>::try_into(
                 File::open("tests/fixtures/config-validation/test_config_with_custom_resolver.yml")
@@ -622,7 +1225,7 @@ mod tests {
         //Resolver defined
         //is_ok
         let mut resolvers = Resolvers::<()>::new();
-        resolvers.insert("MyResolver".to_string(), Box::new(my_resolver));
+        resolvers.insert("MyResolver".to_string(), Arc::new(my_resolver));
         assert!(Engine::<()>::new(
             TryInto::<Configuration>::try_into(
                 File::open(
@@ -641,7 +1244,7 @@ mod tests {
         //Resolver defined
         //is_ok
         let mut resolvers = Resolvers::<()>::new();
-        resolvers.insert("MyResolver".to_string(), Box::new(my_resolver));
+        resolvers.insert("MyResolver".to_string(), Arc::new(my_resolver));
         assert!(Engine::<()>::new(
             TryInto::<Configuration>::try_into(
                 File::open("tests/fixtures/minimal.yml").expect("Couldn't read config")
@@ -670,14 +1273,131 @@ mod tests {
         .is_err());
     }
 
+    /// Passes if a build fails when the custom id generator named in the configuration is not
+    /// provided
+    #[test]
+    fn test_engine_validate_custom_id_generator() {
+        let mut id_generators = IdGenerators::new();
+        id_generators.insert("MyIdGenerator".to_string(), Arc::new(my_id_generator));
+        assert!(Engine::<()>::new(
+            TryInto::<Configuration>::try_into(
+                File::open(
+                    "tests/fixtures/config-validation/test_config_with_custom_id_generator.yml"
+                )
+                .expect("Couldn't read config")
+            )
+            .expect("Couldn't convert to config"),
+            DatabasePool::NoDatabase
+        )
+        .with_id_generators(id_generators)
+        .build()
+        .is_ok());
+
+        assert!(matches!(
+            Engine::<()>::new(
+                TryInto::<Configuration>::try_into(
+                    File::open(
+                        "tests/fixtures/config-validation/test_config_with_custom_id_generator.yml"
+                    )
+                    .expect("Couldn't read config")
+                )
+                .expect("Couldn't convert to config"),
+                DatabasePool::NoDatabase
+            )
+            .build(),
+            Err(Error::IdGeneratorNotFound { name }) if name == "MyIdGenerator"
+        ));
+    }
+
+    /// Passes if a build fails when the context-aware validator named by a type or relationship
+    /// in the configuration is not provided
+    #[test]
+    fn test_engine_validate_context_validator() {
+        let mut context_validators = ContextValidators::<()>::new();
+        context_validators.insert(
+            "MyContextValidator".to_string(),
+            Arc::new(my_context_validator),
+        );
+        assert!(Engine::<()>::new(
+            TryInto::<Configuration>::try_into(
+                File::open(
+                    "tests/fixtures/config-validation/test_config_with_context_validator.yml"
+                )
+                .expect("Couldn't read config")
+            )
+            .expect("Couldn't convert to config"),
+            DatabasePool::NoDatabase
+        )
+        .with_context_validators(context_validators)
+        .build()
+        .is_ok());
+
+        assert!(matches!(
+            Engine::<()>::new(
+                TryInto::<Configuration>::try_into(
+                    File::open(
+                        "tests/fixtures/config-validation/test_config_with_context_validator.yml"
+                    )
+                    .expect("Couldn't read config")
+                )
+                .expect("Couldn't convert to config"),
+                DatabasePool::NoDatabase
+            )
+            .build(),
+            Err(Error::ValidatorNotFound { name }) if name == "MyContextValidator"
+        ));
+    }
+
+    /// Passes if a build fails when a trigger function named by a type or relationship in the
+    /// configuration is not provided
+    #[test]
+    fn test_engine_validate_trigger() {
+        let config = || -> Configuration {
+            TryInto::<Configuration>::try_into(
+                File::open("tests/fixtures/config-validation/test_config_with_trigger.yml")
+                    .expect("Couldn't read config"),
+            )
+            .expect("Couldn't convert to config")
+        };
+
+        let mut triggers = Triggers::<()>::new();
+        triggers.insert("MyTrigger".to_string(), Arc::new(my_trigger));
+        triggers.insert("MyRelTrigger".to_string(), Arc::new(my_trigger));
+        assert!(Engine::<()>::new(config(), DatabasePool::NoDatabase)
+            .with_triggers(triggers)
+            .build()
+            .is_ok());
+
+        let mut triggers = Triggers::<()>::new();
+        triggers.insert("MyTrigger".to_string(), Arc::new(my_trigger));
+        assert!(matches!(
+            Engine::<()>::new(config(), DatabasePool::NoDatabase)
+                .with_triggers(triggers)
+                .build(),
+            Err(Error::TriggerNotFound { name }) if name == "MyRelTrigger"
+        ));
+    }
+
     pub fn my_resolver(executor: ResolverFacade<()>) -> ExecutionResult {
         executor.resolve_scalar(1 as i32)
     }
 
+    fn my_context_validator(_context: &mut ValidationContext<()>) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn my_trigger(_context: &mut TriggerContext<()>) -> Result<(), Error> {
+        Ok(())
+    }
+
     fn my_validator(_value: &Value) -> Result<(), Error> {
         Ok(())
     }
 
+    fn my_id_generator(_type_name: &str) -> Result<String, Error> {
+        Ok("1".to_string())
+    }
+
     /// Passes if EngineBuilder implements the Send trait
     #[test]
     fn test_engine_builder_send() {
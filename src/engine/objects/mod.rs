@@ -5,6 +5,8 @@
 use super::context::GraphQLContext;
 use super::schema::{ArgumentKind, Info, NodeType, Property, PropertyKind, TypeKind};
 use crate::engine::context::RequestContext;
+use crate::engine::database::{NodeRecord, RelRecord};
+use crate::engine::extensions::FieldContext;
 use crate::engine::resolvers::Object;
 use crate::engine::value::Value;
 use crate::error::Error;
@@ -15,7 +17,7 @@ use juniper::{
     Selection, ID,
 };
 use log::{error, trace};
-use resolvers::Resolver;
+pub(crate) use resolvers::{OperationTransaction, Resolver};
 use std::collections::HashMap;
 use std::convert::TryInto;
 use std::fmt::Debug;
@@ -24,7 +26,7 @@ use std::marker::PhantomData;
 mod resolvers;
 
 #[derive(Clone, Debug)]
-struct Input<RequestCtx>
+pub(crate) struct Input<RequestCtx>
 where
     RequestCtx: RequestContext,
 {
@@ -36,7 +38,7 @@ impl<RequestCtx> Input<RequestCtx>
 where
     RequestCtx: RequestContext,
 {
-    fn new(value: Value) -> Input<RequestCtx> {
+    pub(crate) fn new(value: Value) -> Input<RequestCtx> {
         Input {
             value,
             _rctx: PhantomData,
@@ -87,49 +89,58 @@ where
 
         let args = props
             .iter()
-            .map(|p| match (p.type_name(), p.required(), p.list()) {
-                ("Boolean", false, false) => registry.arg::<Option<bool>>(p.name(), &()),
-                ("Boolean", false, true) => registry.arg::<Option<Vec<bool>>>(p.name(), &()),
-                ("Boolean", true, false) => registry.arg::<bool>(p.name(), &()),
-                ("Boolean", true, true) => registry.arg::<Vec<bool>>(p.name(), &()),
-                ("Float", false, false) => registry.arg::<Option<f64>>(p.name(), &()),
-                ("Float", false, true) => registry.arg::<Option<Vec<f64>>>(p.name(), &()),
-                ("Float", true, false) => registry.arg::<f64>(p.name(), &()),
-                ("Float", true, true) => registry.arg::<Vec<f64>>(p.name(), &()),
-                ("ID", false, false) => registry.arg::<Option<ID>>(p.name(), &()),
-                ("ID", false, true) => registry.arg::<Option<Vec<ID>>>(p.name(), &()),
-                ("ID", true, false) => registry.arg::<ID>(p.name(), &()),
-                ("ID", true, true) => registry.arg::<Vec<ID>>(p.name(), &()),
-                ("Int", false, false) => registry.arg::<Option<i32>>(p.name(), &()),
-                ("Int", false, true) => registry.arg::<Option<Vec<i32>>>(p.name(), &()),
-                ("Int", true, false) => registry.arg::<i32>(p.name(), &()),
-                ("Int", true, true) => registry.arg::<Vec<i32>>(p.name(), &()),
-                ("String", false, false) => registry.arg::<Option<String>>(p.name(), &()),
-                ("String", false, true) => registry.arg::<Option<Vec<String>>>(p.name(), &()),
-                ("String", true, false) => registry.arg::<String>(p.name(), &()),
-                ("String", true, true) => registry.arg::<Vec<String>>(p.name(), &()),
-                (_, false, false) => registry.arg::<Option<Input<RequestCtx>>>(
-                    p.name(),
-                    &Info::new(p.type_name().to_string(), info.type_defs()),
-                ),
-                (_, false, true) => registry.arg::<Option<Vec<Input<RequestCtx>>>>(
-                    p.name(),
-                    &Info::new(p.type_name().to_string(), info.type_defs()),
-                ),
-                (_, true, false) => registry.arg::<Input<RequestCtx>>(
-                    p.name(),
-                    &Info::new(p.type_name().to_string(), info.type_defs()),
-                ),
-                (_, true, true) => registry.arg::<Vec<Input<RequestCtx>>>(
-                    p.name(),
-                    &Info::new(p.type_name().to_string(), info.type_defs()),
-                ),
+            .map(|p| {
+                let a = match (p.type_name(), p.required(), p.list()) {
+                    ("Boolean", false, false) => registry.arg::<Option<bool>>(p.name(), &()),
+                    ("Boolean", false, true) => registry.arg::<Option<Vec<bool>>>(p.name(), &()),
+                    ("Boolean", true, false) => registry.arg::<bool>(p.name(), &()),
+                    ("Boolean", true, true) => registry.arg::<Vec<bool>>(p.name(), &()),
+                    ("Float", false, false) => registry.arg::<Option<f64>>(p.name(), &()),
+                    ("Float", false, true) => registry.arg::<Option<Vec<f64>>>(p.name(), &()),
+                    ("Float", true, false) => registry.arg::<f64>(p.name(), &()),
+                    ("Float", true, true) => registry.arg::<Vec<f64>>(p.name(), &()),
+                    ("ID", false, false) => registry.arg::<Option<ID>>(p.name(), &()),
+                    ("ID", false, true) => registry.arg::<Option<Vec<ID>>>(p.name(), &()),
+                    ("ID", true, false) => registry.arg::<ID>(p.name(), &()),
+                    ("ID", true, true) => registry.arg::<Vec<ID>>(p.name(), &()),
+                    ("Int", false, false) => registry.arg::<Option<i32>>(p.name(), &()),
+                    ("Int", false, true) => registry.arg::<Option<Vec<i32>>>(p.name(), &()),
+                    ("Int", true, false) => registry.arg::<i32>(p.name(), &()),
+                    ("Int", true, true) => registry.arg::<Vec<i32>>(p.name(), &()),
+                    ("String", false, false) => registry.arg::<Option<String>>(p.name(), &()),
+                    ("String", false, true) => registry.arg::<Option<Vec<String>>>(p.name(), &()),
+                    ("String", true, false) => registry.arg::<String>(p.name(), &()),
+                    ("String", true, true) => registry.arg::<Vec<String>>(p.name(), &()),
+                    (_, false, false) => registry.arg::<Option<Input<RequestCtx>>>(
+                        p.name(),
+                        &Info::new(p.type_name().to_string(), info.type_defs()),
+                    ),
+                    (_, false, true) => registry.arg::<Option<Vec<Input<RequestCtx>>>>(
+                        p.name(),
+                        &Info::new(p.type_name().to_string(), info.type_defs()),
+                    ),
+                    (_, true, false) => registry.arg::<Input<RequestCtx>>(
+                        p.name(),
+                        &Info::new(p.type_name().to_string(), info.type_defs()),
+                    ),
+                    (_, true, true) => registry.arg::<Vec<Input<RequestCtx>>>(
+                        p.name(),
+                        &Info::new(p.type_name().to_string(), info.type_defs()),
+                    ),
+                };
+
+                match p.description() {
+                    Some(d) => a.description(d),
+                    None => a,
+                }
             })
             .collect::<Vec<_>>();
 
-        registry
-            .build_input_object_type::<Input<RequestCtx>>(info, &args)
-            .into_meta()
+        let mut meta = registry.build_input_object_type::<Input<RequestCtx>>(info, &args);
+        if let Some(d) = nt.description() {
+            meta = meta.description(d);
+        }
+        meta.into_meta()
     }
 }
 
@@ -149,7 +160,7 @@ where
 ///     let mut props = HashMap::new();
 ///     props.insert("role".to_string(), Value::String("Admin".to_string()));
 ///
-///     let n = facade.create_node(typename, props);
+///     let n = facade.node(typename, props);
 ///
 ///     facade.resolve_node(&n)
 /// }
@@ -305,62 +316,129 @@ where
                     ),
                 };
 
-                p.arguments().fold(f, |f, arg| {
-                    match (arg.name(), arg.type_name(), arg.kind()) {
-                        (name, "Boolean", ArgumentKind::Optional) => {
-                            f.argument(registry.arg::<Option<bool>>(name, &()))
-                        }
-                        (name, "Boolean", ArgumentKind::Required) => {
-                            f.argument(registry.arg::<bool>(name, &()))
-                        }
-                        (name, "Float", ArgumentKind::Optional) => {
-                            f.argument(registry.arg::<Option<f64>>(name, &()))
-                        }
-                        (name, "Float", ArgumentKind::Required) => {
-                            f.argument(registry.arg::<f64>(name, &()))
-                        }
-                        (name, "ID", ArgumentKind::Optional) => {
-                            f.argument(registry.arg::<Option<ID>>(name, &()))
-                        }
-                        (name, "ID", ArgumentKind::Required) => {
-                            f.argument(registry.arg::<ID>(name, &()))
-                        }
-                        (name, "Int", ArgumentKind::Optional) => {
-                            f.argument(registry.arg::<Option<i32>>(name, &()))
-                        }
-                        (name, "Int", ArgumentKind::Required) => {
-                            f.argument(registry.arg::<i32>(name, &()))
-                        }
-                        ("partitionKey", "String", ArgumentKind::Optional) => {
-                            f.argument(registry.arg::<Option<String>>("partitionKey", &()))
-                        }
-                        (name, "String", ArgumentKind::Optional) => {
-                            f.argument(registry.arg::<Option<String>>(name, &()))
-                        }
-                        (name, "String", ArgumentKind::Required) => {
-                            f.argument(registry.arg::<String>(name, &()))
-                        }
-                        ("input", type_name, ArgumentKind::Optional) => {
-                            f.argument(registry.arg::<Option<Input<RequestCtx>>>(
-                                "input",
-                                &Info::new(type_name.to_string(), info.type_defs()),
-                            ))
-                        }
-                        ("input", type_name, ArgumentKind::Required) => {
-                            f.argument(registry.arg::<Input<RequestCtx>>(
-                                "input",
-                                &Info::new(type_name.to_string(), info.type_defs()),
-                            ))
-                        }
-                        (_, _, _) => panic!(Error::TypeNotExpected),
-                    }
-                })
+                let f = p.arguments().fold(f, |f, arg| {
+                    let a =
+                        match (arg.name(), arg.type_name(), arg.kind(), arg.list()) {
+                            (name, "Boolean", ArgumentKind::Optional, false) => {
+                                registry.arg::<Option<bool>>(name, &())
+                            }
+                            (name, "Boolean", ArgumentKind::Required, false) => {
+                                registry.arg::<bool>(name, &())
+                            }
+                            (name, "Boolean", ArgumentKind::Optional, true) => {
+                                registry.arg::<Option<Vec<bool>>>(name, &())
+                            }
+                            (name, "Boolean", ArgumentKind::Required, true) => {
+                                registry.arg::<Vec<bool>>(name, &())
+                            }
+                            (name, "Float", ArgumentKind::Optional, false) => {
+                                registry.arg::<Option<f64>>(name, &())
+                            }
+                            (name, "Float", ArgumentKind::Required, false) => {
+                                registry.arg::<f64>(name, &())
+                            }
+                            (name, "Float", ArgumentKind::Optional, true) => {
+                                registry.arg::<Option<Vec<f64>>>(name, &())
+                            }
+                            (name, "Float", ArgumentKind::Required, true) => {
+                                registry.arg::<Vec<f64>>(name, &())
+                            }
+                            (name, "ID", ArgumentKind::Optional, false) => {
+                                registry.arg::<Option<ID>>(name, &())
+                            }
+                            (name, "ID", ArgumentKind::Required, false) => {
+                                registry.arg::<ID>(name, &())
+                            }
+                            (name, "ID", ArgumentKind::Optional, true) => {
+                                registry.arg::<Option<Vec<ID>>>(name, &())
+                            }
+                            (name, "ID", ArgumentKind::Required, true) => {
+                                registry.arg::<Vec<ID>>(name, &())
+                            }
+                            (name, "Int", ArgumentKind::Optional, false) => {
+                                registry.arg::<Option<i32>>(name, &())
+                            }
+                            (name, "Int", ArgumentKind::Required, false) => {
+                                registry.arg::<i32>(name, &())
+                            }
+                            (name, "Int", ArgumentKind::Optional, true) => {
+                                registry.arg::<Option<Vec<i32>>>(name, &())
+                            }
+                            (name, "Int", ArgumentKind::Required, true) => {
+                                registry.arg::<Vec<i32>>(name, &())
+                            }
+                            ("partitionKey", "String", ArgumentKind::Optional, false) => {
+                                registry.arg::<Option<String>>("partitionKey", &())
+                            }
+                            (name, "String", ArgumentKind::Optional, false) => {
+                                registry.arg::<Option<String>>(name, &())
+                            }
+                            (name, "String", ArgumentKind::Required, false) => {
+                                registry.arg::<String>(name, &())
+                            }
+                            (name, "String", ArgumentKind::Optional, true) => {
+                                registry.arg::<Option<Vec<String>>>(name, &())
+                            }
+                            (name, "String", ArgumentKind::Required, true) => {
+                                registry.arg::<Vec<String>>(name, &())
+                            }
+                            (name, type_name, ArgumentKind::Optional, false) => registry
+                                .arg::<Option<Input<RequestCtx>>>(
+                                    name,
+                                    &Info::new(type_name.to_string(), info.type_defs()),
+                                ),
+                            (name, type_name, ArgumentKind::Required, false) => registry
+                                .arg::<Input<RequestCtx>>(
+                                    name,
+                                    &Info::new(type_name.to_string(), info.type_defs()),
+                                ),
+                            (name, type_name, ArgumentKind::Optional, true) => registry
+                                .arg::<Option<Vec<Input<RequestCtx>>>>(
+                                    name,
+                                    &Info::new(type_name.to_string(), info.type_defs()),
+                                ),
+                            (name, type_name, ArgumentKind::Required, true) => {
+                                registry.arg::<Vec<Input<RequestCtx>>>(
+                                    name,
+                                    &Info::new(type_name.to_string(), info.type_defs()),
+                                )
+                            }
+                        };
+
+                    let a = match arg.description() {
+                        Some(d) => a.description(d),
+                        None => a,
+                    };
+
+                    let a = match arg.default_value() {
+                        Some(d) => a.default_value(
+                            default_input_value(arg.type_name(), d)
+                                .unwrap_or_else(|e| panic!("{}", e)),
+                        ),
+                        None => a,
+                    };
+
+                    f.argument(a)
+                });
+
+                let f = match p.description() {
+                    Some(d) => f.description(d),
+                    None => f,
+                };
+
+                if p.deprecated() {
+                    f.deprecated(p.deprecation_reason())
+                } else {
+                    f
+                }
             })
             .collect::<Vec<_>>();
 
-        registry
-            .build_object_type::<Node<RequestCtx>>(info, &fields)
-            .into_meta()
+        let mut meta = registry.build_object_type::<Node<RequestCtx>>(info, &fields);
+        if let Some(d) = nt.description() {
+            meta = meta.description(d);
+        }
+        meta.into_meta()
     }
 
     pub(crate) fn typename(&self) -> &str {
@@ -368,6 +446,16 @@ where
     }
 }
 
+impl<RequestCtx> From<NodeRecord> for Node<RequestCtx>
+where
+    RequestCtx: RequestContext,
+{
+    fn from(record: NodeRecord) -> Self {
+        let (concrete_typename, fields) = record.into_parts();
+        Node::new(concrete_typename, fields)
+    }
+}
+
 impl<RequestCtx> GraphQLType for Node<RequestCtx>
 where
     RequestCtx: RequestContext,
@@ -432,125 +520,131 @@ where
             field_name,
         );
 
-        let p = info.type_def()?.property(field_name)?;
-        let input_opt: Option<Input<RequestCtx>> = args.get("input");
-
-        // The partition key is only in the arguments for the outermost query or mutation.
-        // For lower-level field resolution, the partition key is read from the field of the parent.
-        // An alternate design would've been to carry the partitionKey in context, but this way
-        // recursive resolve calls from custom resolvers that execute cross-partition queries will
-        // work correctly, as each node carries its own partition, for any recursion to fill out the
-        // other rels and nodes loaded by the shape.
-        let arg_partition_key = args.get("partitionKey");
-        let partition_key_opt: Option<&Value> = arg_partition_key
-            .as_ref()
-            .or_else(|| self.fields.get("partitionKey"));
+        let result = resolve_field_with_hooks(sn, field_name, executor, || {
+            let p = info.type_def()?.property(field_name)?;
+            let input_opt: Option<Input<RequestCtx>> = args.get("input");
 
-        let mut resolver = Resolver::new(partition_key_opt);
-
-        let result = match p.kind() {
-            PropertyKind::CustomResolver => resolver.resolve_custom_endpoint(
-                info,
-                field_name,
-                Object::Node(self),
-                args,
-                executor,
-            ),
-            PropertyKind::DynamicScalar => resolver.resolve_custom_field(
+            // The partition key is only in the arguments for the outermost query or mutation.
+            // For lower-level field resolution, the partition key is read from the field of the parent.
+            // An alternate design would've been to carry the partitionKey in context, but this way
+            // recursive resolve calls from custom resolvers that execute cross-partition queries will
+            // work correctly, as each node carries its own partition, for any recursion to fill out the
+            // other rels and nodes loaded by the shape.
+            let partition_key = partition_key(
                 info,
-                field_name,
-                p.resolver(),
-                Object::Node(self),
+                p,
                 args,
+                self.fields.get(info.type_def()?.partition_key_property()),
+                input_opt.as_ref(),
                 executor,
-            ),
-            PropertyKind::DynamicRel { rel_name } => resolver.resolve_custom_rel(
-                info,
-                &rel_name,
-                p.resolver(),
-                Object::Node(self),
-                args,
-                executor,
-            ),
-            PropertyKind::Input => Err(Error::TypeNotExpected.into()),
-            PropertyKind::NodeCreateMutation => {
-                let input = input_opt.ok_or_else(|| Error::InputItemNotFound {
-                    name: "input".to_string(),
-                })?;
-                resolver.resolve_node_create_mutation(field_name, info, input, executor)
-            }
-            PropertyKind::NodeDeleteMutation { label } => {
-                let input = input_opt.ok_or_else(|| Error::InputItemNotFound {
-                    name: "input".to_string(),
-                })?;
-                resolver.resolve_node_delete_mutation(field_name, &label, info, input, executor)
-            }
-            PropertyKind::NodeUpdateMutation => {
-                let input = input_opt.ok_or_else(|| Error::InputItemNotFound {
-                    name: "input".to_string(),
-                })?;
-                resolver.resolve_node_update_mutation(field_name, info, input, executor)
-            }
-            PropertyKind::Object => {
-                resolver.resolve_node_read_query(field_name, info, input_opt, executor)
-            }
-            PropertyKind::Rel { rel_name } => {
-                let io = match sn {
-                    "Mutation" | "Query" => input_opt,
-                    _ => {
-                        let mut src_node = HashMap::new();
-                        src_node.insert("id".to_string(), self.id()?.clone());
-                        let mut src = HashMap::new();
-                        src.insert(
-                            info.type_def()?.type_name().to_string(),
-                            Value::Map(src_node),
-                        );
-                        let mut hm = HashMap::new();
-                        hm.insert("src".to_string(), Value::Map(src));
-                        Some(Input::new(Value::Map(hm)))
-                    }
-                };
-                resolver.resolve_rel_read_query(field_name, &rel_name, info, io, executor)
-            }
-            PropertyKind::RelCreateMutation {
-                src_label,
-                rel_name,
-            } => {
-                let input = input_opt.ok_or_else(|| Error::InputItemNotFound {
-                    name: "input".to_string(),
-                })?;
-                resolver.resolve_rel_create_mutation(
-                    field_name, &src_label, &rel_name, info, input, executor,
-                )
-            }
-            PropertyKind::RelDeleteMutation {
-                src_label,
-                rel_name,
-            } => {
-                let input = input_opt.ok_or_else(|| Error::InputItemNotFound {
-                    name: "input".to_string(),
-                })?;
-                resolver.resolve_rel_delete_mutation(
-                    field_name, &src_label, &rel_name, info, input, executor,
-                )
-            }
-            PropertyKind::RelUpdateMutation {
-                src_label,
-                rel_name,
-            } => {
-                let input = input_opt.ok_or_else(|| Error::InputItemNotFound {
-                    name: "input".to_string(),
-                })?;
-                resolver.resolve_rel_update_mutation(
-                    field_name, &src_label, &rel_name, info, input, executor,
-                )
-            }
-            PropertyKind::Scalar => {
-                resolver.resolve_scalar_field(info, field_name, &self.fields, executor)
+            );
+
+            let mut resolver = Resolver::new(partition_key.as_ref());
+
+            match p.kind() {
+                PropertyKind::CustomResolver => resolver.resolve_custom_endpoint(
+                    info,
+                    field_name,
+                    Object::Node(self),
+                    args,
+                    executor,
+                ),
+                PropertyKind::DynamicScalar => resolver.resolve_custom_field(
+                    info,
+                    field_name,
+                    p.resolver(),
+                    Object::Node(self),
+                    args,
+                    executor,
+                ),
+                PropertyKind::DynamicRel { rel_name } => resolver.resolve_custom_rel(
+                    info,
+                    &rel_name,
+                    p.resolver(),
+                    Object::Node(self),
+                    args,
+                    executor,
+                ),
+                PropertyKind::Input => Err(Error::TypeNotExpected.into()),
+                PropertyKind::NodeCreateMutation => {
+                    let input = input_opt.ok_or_else(|| Error::InputItemNotFound {
+                        name: "input".to_string(),
+                    })?;
+                    resolver.resolve_node_create_mutation(field_name, info, input, executor)
+                }
+                PropertyKind::NodeDeleteMutation { label } => {
+                    let input = input_opt.ok_or_else(|| Error::InputItemNotFound {
+                        name: "input".to_string(),
+                    })?;
+                    resolver.resolve_node_delete_mutation(field_name, &label, info, input, executor)
+                }
+                PropertyKind::NodeUpdateMutation => {
+                    let input = input_opt.ok_or_else(|| Error::InputItemNotFound {
+                        name: "input".to_string(),
+                    })?;
+                    resolver.resolve_node_update_mutation(field_name, info, input, executor)
+                }
+                PropertyKind::Object => {
+                    resolver.resolve_node_read_query(field_name, info, input_opt, executor)
+                }
+                PropertyKind::Rel { rel_name } => {
+                    let io = match sn {
+                        "Mutation" | "Query" => input_opt,
+                        _ => {
+                            let mut src_node = HashMap::new();
+                            src_node.insert("id".to_string(), self.id()?.clone());
+                            let mut src = HashMap::new();
+                            src.insert(
+                                info.type_def()?.type_name().to_string(),
+                                Value::Map(src_node),
+                            );
+                            let mut hm = HashMap::new();
+                            hm.insert("src".to_string(), Value::Map(src));
+                            Some(Input::new(Value::Map(hm)))
+                        }
+                    };
+                    resolver.resolve_rel_read_query(field_name, &rel_name, info, io, executor)
+                }
+                PropertyKind::RelCreateMutation {
+                    src_label,
+                    rel_name,
+                } => {
+                    let input = input_opt.ok_or_else(|| Error::InputItemNotFound {
+                        name: "input".to_string(),
+                    })?;
+                    resolver.resolve_rel_create_mutation(
+                        field_name, &src_label, &rel_name, info, input, executor,
+                    )
+                }
+                PropertyKind::RelDeleteMutation {
+                    src_label,
+                    rel_name,
+                } => {
+                    let input = input_opt.ok_or_else(|| Error::InputItemNotFound {
+                        name: "input".to_string(),
+                    })?;
+                    resolver.resolve_rel_delete_mutation(
+                        field_name, &src_label, &rel_name, info, input, executor,
+                    )
+                }
+                PropertyKind::RelUpdateMutation {
+                    src_label,
+                    rel_name,
+                } => {
+                    let input = input_opt.ok_or_else(|| Error::InputItemNotFound {
+                        name: "input".to_string(),
+                    })?;
+                    resolver.resolve_rel_update_mutation(
+                        field_name, &src_label, &rel_name, info, input, executor,
+                    )
+                }
+                PropertyKind::Scalar => {
+                    resolver.resolve_scalar_field(info, field_name, &self.fields, executor)
+                }
+                PropertyKind::Union => Err(Error::TypeNotExpected.into()),
+                PropertyKind::VersionQuery => resolver.resolve_static_version_query(executor),
             }
-            PropertyKind::Union => Err(Error::TypeNotExpected.into()),
-            PropertyKind::VersionQuery => resolver.resolve_static_version_query(executor),
-        };
+        });
 
         trace!("Node::resolve_field -- result: {:#?}", result);
 
@@ -664,6 +758,29 @@ where
     }
 }
 
+impl<RequestCtx> From<RelRecord> for Rel<RequestCtx>
+where
+    RequestCtx: RequestContext,
+{
+    fn from(record: RelRecord) -> Self {
+        let (id, partition_key, props, (src_id, src_label), (dst_id, dst_label)) =
+            record.into_parts();
+        Rel::new(
+            id,
+            partition_key,
+            props.map(Node::from),
+            NodeRef::Identifier {
+                id: src_id,
+                label: src_label,
+            },
+            NodeRef::Identifier {
+                id: dst_id,
+                label: dst_label,
+            },
+        )
+    }
+}
+
 impl<RequestCtx> GraphQLType for Rel<RequestCtx>
 where
     RequestCtx: RequestContext,
@@ -710,33 +827,42 @@ where
 
         let fields = props
             .iter()
-            .map(|p| match (p.type_name(), p.required(), p.list()) {
-                ("ID", false, false) => registry.field::<Option<ID>>(p.name(), &()),
-                ("ID", false, true) => registry.field::<Option<Vec<ID>>>(p.name(), &()),
-                ("ID", true, false) => registry.field::<ID>(p.name(), &()),
-                ("ID", true, true) => registry.field::<Vec<ID>>(p.name(), &()),
-                (_, false, false) => registry.field::<Option<Node<RequestCtx>>>(
-                    p.name(),
-                    &Info::new(p.type_name().to_string(), info.type_defs()),
-                ),
-                (_, false, true) => registry.field::<Option<Vec<&Node<RequestCtx>>>>(
-                    p.name(),
-                    &Info::new(p.type_name().to_string(), info.type_defs()),
-                ),
-                (_, true, false) => registry.field::<Node<RequestCtx>>(
-                    p.name(),
-                    &Info::new(p.type_name().to_string(), info.type_defs()),
-                ),
-                (_, true, true) => registry.field::<Vec<&Node<RequestCtx>>>(
-                    p.name(),
-                    &Info::new(p.type_name().to_string(), info.type_defs()),
-                ),
+            .map(|p| {
+                let f = match (p.type_name(), p.required(), p.list()) {
+                    ("ID", false, false) => registry.field::<Option<ID>>(p.name(), &()),
+                    ("ID", false, true) => registry.field::<Option<Vec<ID>>>(p.name(), &()),
+                    ("ID", true, false) => registry.field::<ID>(p.name(), &()),
+                    ("ID", true, true) => registry.field::<Vec<ID>>(p.name(), &()),
+                    (_, false, false) => registry.field::<Option<Node<RequestCtx>>>(
+                        p.name(),
+                        &Info::new(p.type_name().to_string(), info.type_defs()),
+                    ),
+                    (_, false, true) => registry.field::<Option<Vec<&Node<RequestCtx>>>>(
+                        p.name(),
+                        &Info::new(p.type_name().to_string(), info.type_defs()),
+                    ),
+                    (_, true, false) => registry.field::<Node<RequestCtx>>(
+                        p.name(),
+                        &Info::new(p.type_name().to_string(), info.type_defs()),
+                    ),
+                    (_, true, true) => registry.field::<Vec<&Node<RequestCtx>>>(
+                        p.name(),
+                        &Info::new(p.type_name().to_string(), info.type_defs()),
+                    ),
+                };
+
+                match p.description() {
+                    Some(d) => f.description(d),
+                    None => f,
+                }
             })
             .collect::<Vec<_>>();
 
-        registry
-            .build_object_type::<Rel<RequestCtx>>(info, &fields)
-            .into_meta()
+        let mut meta = registry.build_object_type::<Rel<RequestCtx>>(info, &fields);
+        if let Some(d) = nt.description() {
+            meta = meta.description(d);
+        }
+        meta.into_meta()
     }
 
     fn resolve_field(
@@ -750,58 +876,236 @@ where
             "Rel::resolve_field_with_transaction called -- field_name: {}",
             field_name
         );
-        let p = info.type_def()?.property(field_name)?;
-        let arg_partition_key = args.get("partitionKey");
-        let partition_key_opt: Option<&Value> = arg_partition_key
-            .as_ref()
-            .or_else(|| self.partition_key.as_ref());
-
-        let mut resolver = Resolver::new(partition_key_opt);
-
-        match (p.kind(), &field_name) {
-            (PropertyKind::DynamicScalar, _) => resolver.resolve_custom_field(
+        resolve_field_with_hooks(info.name(), field_name, executor, || {
+            let p = info.type_def()?.property(field_name)?;
+            let partition_key = partition_key::<RequestCtx>(
                 info,
-                field_name,
-                p.resolver(),
-                Object::Rel(self),
+                p,
                 args,
+                self.partition_key.as_ref(),
+                None,
                 executor,
-            ),
-            (PropertyKind::Object, &"props") => match &self.props {
-                Some(p) => resolver.resolve_rel_props(info, field_name, p, executor),
-                None => Err(Error::TypeNotExpected.into()),
-            },
-            (PropertyKind::Object, &"src") => match &self.src_ref {
-                NodeRef::Identifier { id, label: _ } => {
-                    let mut hm = HashMap::new();
-                    hm.insert("id".to_string(), id.clone());
-                    let input = Input::new(Value::Map(hm));
-                    resolver.resolve_node_read_query(field_name, info, Some(input), executor)
-                }
-                NodeRef::Node(n) => {
-                    executor.resolve(&Info::new(n.type_name().clone(), info.type_defs()), &n)
+            );
+
+            let mut resolver = Resolver::new(partition_key.as_ref());
+
+            match (p.kind(), &field_name) {
+                (PropertyKind::DynamicScalar, _) => resolver.resolve_custom_field(
+                    info,
+                    field_name,
+                    p.resolver(),
+                    Object::Rel(self),
+                    args,
+                    executor,
+                ),
+                (PropertyKind::Object, &"props") => match &self.props {
+                    Some(p) => resolver.resolve_rel_props(info, field_name, p, executor),
+                    None => Err(Error::TypeNotExpected.into()),
+                },
+                (PropertyKind::Object, &"src") => match &self.src_ref {
+                    NodeRef::Identifier { id, label: _ } => {
+                        let mut hm = HashMap::new();
+                        hm.insert("id".to_string(), id.clone());
+                        let input = Input::new(Value::Map(hm));
+                        resolver.resolve_node_read_query(field_name, info, Some(input), executor)
+                    }
+                    NodeRef::Node(n) => {
+                        executor.resolve(&Info::new(n.type_name().clone(), info.type_defs()), &n)
+                    }
+                },
+                (PropertyKind::Object, _) => Err(Error::ResponseItemNotFound {
+                    name: field_name.to_string(),
                 }
-            },
-            (PropertyKind::Object, _) => Err(Error::ResponseItemNotFound {
-                name: field_name.to_string(),
-            }
-            .into()),
-            (PropertyKind::Scalar, _) => {
-                if field_name == "id" {
-                    executor.resolve_with_ctx(&(), &TryInto::<String>::try_into(self.id.clone())?)
-                } else {
-                    executor.resolve_with_ctx(&(), &None::<String>)
+                .into()),
+                (PropertyKind::Scalar, _) => {
+                    if field_name == "id" {
+                        executor
+                            .resolve_with_ctx(&(), &TryInto::<String>::try_into(self.id.clone())?)
+                    } else {
+                        executor.resolve_with_ctx(&(), &None::<String>)
+                    }
                 }
+                (PropertyKind::Union, _) => match &self.dst_ref {
+                    NodeRef::Identifier { id, label } => {
+                        resolver.resolve_union_field(info, label, field_name, &id, executor)
+                    }
+                    NodeRef::Node(n) => {
+                        resolver.resolve_union_field_node(info, field_name, &n, executor)
+                    }
+                },
+                (_, _) => Err(Error::TypeNotExpected.into()),
             }
-            (PropertyKind::Union, _) => match &self.dst_ref {
-                NodeRef::Identifier { id, label } => {
-                    resolver.resolve_union_field(info, label, field_name, &id, executor)
-                }
-                NodeRef::Node(n) => {
-                    resolver.resolve_union_field_node(info, field_name, &n, executor)
-                }
-            },
-            (_, _) => Err(Error::TypeNotExpected.into()),
+        })
+    }
+}
+
+/// Resolves a field by calling `resolve`, unless a `before_field` hook of a registered extension
+/// returns an error, and passes the result through the `after_field` hooks
+fn resolve_field_with_hooks<RequestCtx, F>(
+    type_name: &str,
+    field_name: &str,
+    executor: &Executor<GraphQLContext<RequestCtx>>,
+    resolve: F,
+) -> ExecutionResult
+where
+    RequestCtx: RequestContext,
+    F: FnOnce() -> ExecutionResult,
+{
+    let context = executor.context();
+    let field = FieldContext::new(type_name, field_name, context.request_context());
+    context
+        .extensions()
+        .try_for_each(|e| e.before_field(&field))
+        .map_err(|e| Error::from(e).into_field_error())?;
+
+    context
+        .extensions()
+        .fold(resolve(), |result, e| e.after_field(&field, result))
+}
+
+/// Returns the partition key with which to resolve a field, for database back-ends that partition
+/// the graph. If the type of the field computes its partition key from the request context, the
+/// request context is asked for it first, and a `partitionKey` argument is ignored. Otherwise, a
+/// `partitionKey` argument takes precedence. Next comes the partition key of the parent node or
+/// relationship. Lastly, a create mutation takes the partition key from the partition key
+/// property of its input.
+fn partition_key<RequestCtx>(
+    info: &Info,
+    p: &Property,
+    args: &Arguments,
+    parent_opt: Option<&Value>,
+    input_opt: Option<&Input<RequestCtx>>,
+    executor: &Executor<GraphQLContext<RequestCtx>>,
+) -> Option<Value>
+where
+    RequestCtx: RequestContext,
+{
+    let type_name = match p.kind() {
+        PropertyKind::NodeDeleteMutation { label } => label,
+        PropertyKind::RelDeleteMutation { src_label, .. } => src_label,
+        _ => p.type_name(),
+    };
+    let nt = info.type_def_by_name(type_name).ok();
+    let from_context = nt
+        .and_then(|nt| nt.partition_key())
+        .map_or(false, |pk| pk.request_context());
+
+    let context_pk = if from_context {
+        nt.and_then(|nt| match nt.type_kind() {
+            TypeKind::Rel => nt.property("src").ok().map(|p| p.type_name()),
+            _ => Some(nt.type_name()),
+        })
+        .and_then(|name| {
+            executor
+                .context()
+                .request_context()
+                .and_then(|rc| rc.partition_key(name))
+        })
+    } else {
+        None
+    };
+
+    let input_pk = match (p.kind(), input_opt.map(|i| &i.value), nt) {
+        (PropertyKind::NodeCreateMutation, Some(Value::Map(m)), Some(nt)) => {
+            m.get(nt.partition_key_property())
         }
+        _ => None,
+    };
+
+    select_partition_key(
+        from_context,
+        context_pk,
+        args.get::<Value>("partitionKey"),
+        parent_opt,
+        input_pk,
+    )
+}
+
+/// Chooses among the candidate partition keys for a field, in the order of precedence described
+/// for [`partition_key`]. The `partitionKey` argument is ignored if the partition key comes from
+/// the request context.
+///
+/// [`partition_key`]: ./fn.partition_key.html
+fn select_partition_key(
+    from_context: bool,
+    context_pk: Option<Value>,
+    arg_pk: Option<Value>,
+    parent_pk: Option<&Value>,
+    input_pk: Option<&Value>,
+) -> Option<Value> {
+    let first = if from_context { context_pk } else { arg_pk };
+    first
+        .or_else(|| parent_pk.cloned())
+        .or_else(|| input_pk.cloned())
+}
+
+/// Converts the configured default value of a field argument into the GraphQL input value for
+/// the scalar type of the argument.
+fn default_input_value(type_name: &str, default: &str) -> Result<InputValue, Error> {
+    let value = match type_name {
+        "Boolean" => default.parse::<bool>().ok().map(InputValue::scalar),
+        "Float" => default.parse::<f64>().ok().map(InputValue::scalar),
+        "Int" => default.parse::<i32>().ok().map(InputValue::scalar),
+        "ID" | "String" => Some(InputValue::scalar(default.to_string())),
+        _ => None,
+    };
+
+    value.ok_or_else(|| Error::TypeConversionFailed {
+        src: default.to_string(),
+        dst: type_name.to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::select_partition_key;
+    use crate::engine::value::Value;
+
+    /// Passes if a partition key from the request context cannot be overridden by a
+    /// `partitionKey` argument, and the argument is used for other types
+    #[test]
+    fn partition_key_from_context() {
+        let tenant = Value::String("tenant".to_string());
+        let other = Value::String("other".to_string());
+
+        assert_eq!(
+            Some(tenant.clone()),
+            select_partition_key(
+                true,
+                Some(tenant.clone()),
+                Some(other.clone()),
+                None,
+                Some(&other)
+            )
+        );
+        assert_eq!(
+            Some(other.clone()),
+            select_partition_key(
+                false,
+                None,
+                Some(other.clone()),
+                Some(&tenant),
+                Some(&tenant)
+            )
+        );
+    }
+
+    /// Passes if the parent's partition key, and then the partition key property of a created
+    /// node, are used when no other partition key is given
+    #[test]
+    fn partition_key_fallbacks() {
+        let parent = Value::String("parent".to_string());
+        let input = Value::String("input".to_string());
+        let other = Value::String("other".to_string());
+
+        assert_eq!(
+            Some(parent.clone()),
+            select_partition_key(true, None, Some(other), Some(&parent), Some(&input))
+        );
+        assert_eq!(
+            Some(input.clone()),
+            select_partition_key(false, None, None, None, Some(&input))
+        );
+        assert_eq!(None, select_partition_key(true, None, None, None, None));
     }
 }
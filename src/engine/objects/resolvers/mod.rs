@@ -1,41 +1,39 @@
 use super::{Input, Node, Rel};
+use crate::engine::cache::CachePlan;
+use crate::engine::config::TriggerEvent;
 use crate::engine::context::{GraphQLContext, RequestContext};
-#[cfg(any(feature = "cosmos", feature = "gremlin"))]
-use crate::engine::database::gremlin::GremlinTransaction;
-#[cfg(feature = "neo4j")]
-use crate::engine::database::neo4j::Neo4jTransaction;
-use crate::engine::database::DatabasePool;
-#[cfg(any(feature = "cosmos", feature = "gremlin", feature = "neo4j"))]
 use crate::engine::database::{
-    ClauseType, NodeQueryVar, RelQueryVar, SuffixGenerator, Transaction,
+    ClauseType, DatabaseBackend, NodeQueryVar, NodeRecord, RelQueryVar, RelRecord, SuffixGenerator,
+    Transaction,
 };
+use crate::engine::extensions::{OperationContext, OperationKind, OperationResult};
 use crate::engine::resolvers::Object;
-use crate::engine::resolvers::ResolverFacade;
 use crate::engine::resolvers::{Arguments, ExecutionResult, Executor};
+use crate::engine::resolvers::{FacadeTransaction, ResolverFacade};
 use crate::engine::schema::Info;
+use crate::engine::triggers::TriggerRecord;
 use crate::engine::value::Value;
 use crate::error::Error;
 use log::trace;
+use std::cell::Cell;
 use std::collections::HashMap;
 use std::convert::TryInto;
-#[cfg(feature = "neo4j")]
-use tokio::runtime::Runtime;
-#[cfg(any(feature = "cosmos", feature = "gremlin", feature = "neo4j"))]
+use std::rc::Rc;
 use visitors::{
+    has_triggers, read_matched_nodes, read_matched_rels, run_triggers,
     visit_node_create_mutation_input, visit_node_delete_input, visit_node_query_input,
     visit_node_update_input, visit_rel_create_input, visit_rel_delete_input, visit_rel_query_input,
     visit_rel_update_input,
 };
 
-#[cfg(any(feature = "cosmos", feature = "gremlin", feature = "neo4j"))]
 mod visitors;
 
-pub(super) struct Resolver<'r> {
+pub(crate) struct Resolver<'r> {
     partition_key_opt: Option<&'r Value>,
 }
 
 impl<'r> Resolver<'r> {
-    pub(super) fn new(partition_key_opt: Option<&'r Value>) -> Resolver<'r> {
+    pub(crate) fn new(partition_key_opt: Option<&'r Value>) -> Resolver<'r> {
         trace!(
             "Resolver::new called -- partition_key_opt: {:#?}",
             partition_key_opt
@@ -61,14 +59,20 @@ impl<'r> Resolver<'r> {
         let func = executor.context().resolver(field_name)?;
 
         // results
-        func(ResolverFacade::new(
+        let transaction = Rc::new(FacadeTransaction::new(
+            executor.context().pool(),
+            executor.context().cache_store(),
+        ));
+        let result = func(ResolverFacade::new(
             field_name.to_string(),
             info,
             args,
             parent,
             self.partition_key_opt,
             executor,
-        ))
+            transaction.clone(),
+        ));
+        transaction.finish(result)
     }
 
     pub(super) fn resolve_custom_field<RequestCtx: RequestContext>(
@@ -92,14 +96,20 @@ impl<'r> Resolver<'r> {
 
         let func = &executor.context().resolver(resolver_name)?;
 
-        func(ResolverFacade::new(
+        let transaction = Rc::new(FacadeTransaction::new(
+            executor.context().pool(),
+            executor.context().cache_store(),
+        ));
+        let result = func(ResolverFacade::new(
             field_name.to_string(),
             info,
             args,
             parent,
             self.partition_key_opt,
-            &executor,
-        ))
+            executor,
+            transaction.clone(),
+        ));
+        transaction.finish(result)
     }
 
     pub(super) fn resolve_custom_rel<RequestCtx: RequestContext>(
@@ -123,14 +133,20 @@ impl<'r> Resolver<'r> {
 
         let func = &executor.context().resolver(resolver_name)?;
 
-        func(ResolverFacade::new(
+        let transaction = Rc::new(FacadeTransaction::new(
+            executor.context().pool(),
+            executor.context().cache_store(),
+        ));
+        let result = func(ResolverFacade::new(
             rel_name.to_string(),
             info,
             args,
             parent,
             self.partition_key_opt,
             executor,
-        ))
+            transaction.clone(),
+        ));
+        transaction.finish(result)
     }
 
     pub(super) fn resolve_node_create_mutation<RequestCtx: RequestContext>(
@@ -147,40 +163,20 @@ impl<'r> Resolver<'r> {
             input
         );
 
-        #[cfg(feature = "neo4j")]
-        let mut runtime = Runtime::new()?;
         let p = info.type_def()?.property(field_name)?;
 
-        let result: Node<RequestCtx> = match &executor.context().pool() {
-            #[cfg(feature = "cosmos")]
-            DatabasePool::Cosmos(c) => self.resolve_node_create_mutation_with_transaction(
+        // The transaction is dropped before any nested fields are resolved, so that nested
+        // resolvers do not wait on a connection held by their parent
+        let result: Node<RequestCtx> = self
+            .node_create_operation(
                 field_name,
                 info,
-                input,
-                &mut GremlinTransaction::new(c.clone(), true, false),
+                input.value,
+                &RetriedTransaction::new(executor.context()),
                 executor,
-            ),
-            #[cfg(feature = "gremlin")]
-            DatabasePool::Gremlin((c, uuid)) => self.resolve_node_create_mutation_with_transaction(
-                field_name,
-                info,
-                input,
-                &mut GremlinTransaction::new(c.clone(), false, *uuid),
-                executor,
-            ),
-            #[cfg(feature = "neo4j")]
-            DatabasePool::Neo4j(p) => {
-                let c = runtime.block_on(p.get())?;
-                self.resolve_node_create_mutation_with_transaction(
-                    field_name,
-                    info,
-                    input,
-                    &mut Neo4jTransaction::new(c, &mut runtime),
-                    executor,
-                )
-            }
-            DatabasePool::NoDatabase => Err(Error::DatabaseNotFound),
-        }?;
+            )
+            .map(Node::from)
+            .map_err(Error::into_field_error)?;
 
         trace!(
             "Resolver::resolve_node_create_mutation -- result: {:#?}",
@@ -192,24 +188,34 @@ impl<'r> Resolver<'r> {
         )
     }
 
-    #[cfg(any(feature = "cosmos", feature = "gremlin", feature = "neo4j"))]
-    pub(super) fn resolve_node_create_mutation_with_transaction<RequestCtx, T>(
+    fn resolve_node_create_mutation_with_transaction<RequestCtx, T>(
         &mut self,
         field_name: &str,
         info: &Info,
-        input: Input<RequestCtx>,
+        input_value: Value,
         transaction: &mut T,
         executor: &Executor<GraphQLContext<RequestCtx>>,
-    ) -> Result<Node<RequestCtx>, Error>
+    ) -> Result<NodeRecord, Error>
     where
         RequestCtx: RequestContext,
-        T: Transaction,
+        T: Transaction + ?Sized,
     {
         let mut sg = SuffixGenerator::new();
+        let context = executor.context();
         let p = info.type_def()?.property(field_name)?;
         let itd = p.input_type_definition(info)?;
 
-        transaction.begin()?;
+        let names = (p.type_name(), None);
+        run_triggers(
+            context,
+            TriggerEvent::BeforeCreate,
+            names,
+            Some(&input_value),
+            (None, None),
+            info,
+            self.partition_key_opt,
+            transaction,
+        )?;
         let node_var = NodeQueryVar::new(
             Some(p.type_name().to_string()),
             "node".to_string(),
@@ -218,23 +224,29 @@ impl<'r> Resolver<'r> {
         let (query, params) = visit_node_create_mutation_input::<T, RequestCtx>(
             HashMap::new(),
             &node_var,
-            input.value,
+            input_value,
             ClauseType::Query,
             &Info::new(itd.type_name().to_owned(), info.type_defs()),
             self.partition_key_opt,
             &mut sg,
             transaction,
-            &executor.context().validators(),
+            executor.context(),
         )?;
-        let results = transaction.create_node(query, params, self.partition_key_opt, info);
-
-        if results.is_ok() {
-            transaction.commit()?;
-        } else {
-            transaction.rollback()?;
-        }
-
-        results
+        transaction
+            .create_node(query, params, self.partition_key_opt, info)
+            .and_then(|node| {
+                run_triggers(
+                    context,
+                    TriggerEvent::AfterCreate,
+                    names,
+                    None,
+                    (None, Some(TriggerRecord::Node(&node))),
+                    info,
+                    self.partition_key_opt,
+                    transaction,
+                )?;
+                Ok(node)
+            })
     }
 
     #[allow(unused_variables)]
@@ -256,38 +268,16 @@ impl<'r> Resolver<'r> {
             input
         );
 
-        #[cfg(feature = "neo4j")]
-        let mut runtime = Runtime::new()?;
-        let results: i32 = match &executor.context().pool() {
-            #[cfg(feature = "cosmos")]
-            DatabasePool::Cosmos(c) => self.resolve_node_delete_mutation_with_transaction(
-                field_name,
-                label,
-                info,
-                input,
-                &mut GremlinTransaction::new(c.clone(), true, false),
-            ),
-            #[cfg(feature = "gremlin")]
-            DatabasePool::Gremlin((c, uuid)) => self.resolve_node_delete_mutation_with_transaction(
+        let results: i32 = self
+            .node_delete_operation(
                 field_name,
                 label,
                 info,
-                input,
-                &mut GremlinTransaction::new(c.clone(), false, *uuid),
-            ),
-            #[cfg(feature = "neo4j")]
-            DatabasePool::Neo4j(p) => {
-                let c = runtime.block_on(p.get())?;
-                self.resolve_node_delete_mutation_with_transaction(
-                    field_name,
-                    label,
-                    info,
-                    input,
-                    &mut Neo4jTransaction::new(c, &mut runtime),
-                )
-            }
-            DatabasePool::NoDatabase => Err(Error::DatabaseNotFound),
-        }?;
+                input.value,
+                &RetriedTransaction::new(executor.context()),
+                executor,
+            )
+            .map_err(Error::into_field_error)?;
 
         trace!(
             "Resolver::resolve_node_delete_mutation -- results: {:#?}",
@@ -297,45 +287,81 @@ impl<'r> Resolver<'r> {
         executor.resolve_with_ctx(&(), &results)
     }
 
-    #[cfg(any(feature = "cosmos", feature = "gremlin", feature = "neo4j"))]
-    pub(super) fn resolve_node_delete_mutation_with_transaction<RequestCtx, T>(
+    fn resolve_node_delete_mutation_with_transaction<RequestCtx, T>(
         &mut self,
         field_name: &str,
         label: &str,
         info: &Info,
-        input: Input<RequestCtx>,
+        input_value: Value,
         transaction: &mut T,
+        executor: &Executor<GraphQLContext<RequestCtx>>,
     ) -> Result<i32, Error>
     where
         RequestCtx: RequestContext,
-        T: Transaction,
+        T: Transaction + ?Sized,
     {
         let mut sg = SuffixGenerator::new();
+        let context = executor.context();
         let itd = info
             .type_def()?
             .property(field_name)?
             .input_type_definition(info)?;
 
-        transaction.begin()?;
+        let names = (label, None);
+        let old_nodes = if has_triggers(
+            info,
+            names,
+            &[TriggerEvent::BeforeDelete, TriggerEvent::AfterDelete],
+        )? {
+            read_matched_nodes(
+                label,
+                &input_value,
+                info,
+                self.partition_key_opt,
+                transaction,
+            )?
+        } else {
+            Vec::new()
+        };
+        old_nodes.iter().try_for_each(|node| {
+            run_triggers(
+                context,
+                TriggerEvent::BeforeDelete,
+                names,
+                Some(&input_value),
+                (Some(TriggerRecord::Node(node)), None),
+                info,
+                self.partition_key_opt,
+                transaction,
+            )
+        })?;
         let node_var = NodeQueryVar::new(Some(label.to_string()), "node".to_string(), sg.suffix());
         let (query, params) = visit_node_delete_input::<T, RequestCtx>(
             HashMap::new(),
             &node_var,
-            input.value,
+            input_value,
             &Info::new(itd.type_name().to_owned(), info.type_defs()),
             self.partition_key_opt,
             &mut sg,
             transaction,
         )?;
-        let results = transaction.delete_nodes(query, params, self.partition_key_opt);
-
-        if results.is_ok() {
-            transaction.commit()?;
-        } else {
-            transaction.rollback()?;
-        }
-
-        results
+        transaction
+            .delete_nodes(query, params, self.partition_key_opt)
+            .and_then(|count| {
+                old_nodes.iter().try_for_each(|node| {
+                    run_triggers(
+                        context,
+                        TriggerEvent::AfterDelete,
+                        names,
+                        None,
+                        (Some(TriggerRecord::Node(node)), None),
+                        info,
+                        self.partition_key_opt,
+                        transaction,
+                    )
+                })?;
+                Ok(count)
+            })
     }
 
     pub(super) fn resolve_node_read_query<RequestCtx: RequestContext>(
@@ -351,37 +377,18 @@ impl<'r> Resolver<'r> {
             field_name,
             input_opt
         );
-        #[cfg(feature = "neo4j")]
-        let mut runtime = Runtime::new()?;
         let p = info.type_def()?.property(field_name)?;
 
-        let results: Vec<Node<RequestCtx>> = match &executor.context().pool() {
-            #[cfg(feature = "cosmos")]
-            DatabasePool::Cosmos(c) => self.resolve_node_read_query_with_transaction(
+        let results: Vec<Node<RequestCtx>> = self
+            .node_read_operation(
                 field_name,
                 info,
-                input_opt,
-                &mut GremlinTransaction::new(c.clone(), true, false),
-            ),
-            #[cfg(feature = "gremlin")]
-            DatabasePool::Gremlin((c, uuid)) => self.resolve_node_read_query_with_transaction(
-                field_name,
-                info,
-                input_opt,
-                &mut GremlinTransaction::new(c.clone(), false, *uuid),
-            ),
-            #[cfg(feature = "neo4j")]
-            DatabasePool::Neo4j(p) => {
-                let c = runtime.block_on(p.get())?;
-                self.resolve_node_read_query_with_transaction(
-                    field_name,
-                    info,
-                    input_opt,
-                    &mut Neo4jTransaction::new(c, &mut runtime),
-                )
-            }
-            DatabasePool::NoDatabase => Err(Error::DatabaseNotFound),
-        }?;
+                input_opt.map(|i| i.value),
+                &RetriedTransaction::new(executor.context()),
+                executor,
+            )
+            .map(|nodes| nodes.into_iter().map(Node::from).collect())
+            .map_err(Error::into_field_error)?;
 
         trace!(
             "Resolver::resolve_node_read_query -- results: {:#?}",
@@ -401,17 +408,16 @@ impl<'r> Resolver<'r> {
         }
     }
 
-    #[cfg(any(feature = "cosmos", feature = "gremlin", feature = "neo4j"))]
-    pub(super) fn resolve_node_read_query_with_transaction<RequestCtx, T>(
+    fn resolve_node_read_query_with_transaction<T>(
         &mut self,
         field_name: &str,
         info: &Info,
-        input_opt: Option<Input<RequestCtx>>,
+        input_value_opt: Option<Value>,
+        limit: Option<usize>,
         transaction: &mut T,
-    ) -> Result<Vec<Node<RequestCtx>>, Error>
+    ) -> Result<Vec<NodeRecord>, Error>
     where
-        RequestCtx: RequestContext,
-        T: Transaction,
+        T: Transaction + ?Sized,
     {
         let mut sg = SuffixGenerator::new();
 
@@ -429,13 +435,10 @@ impl<'r> Resolver<'r> {
             sg.suffix(),
         );
 
-        if info.name() == "Mutation" || info.name() == "Query" {
-            transaction.begin()?;
-        }
         let (match_fragment, where_fragment, params) = visit_node_query_input(
             HashMap::new(),
             &node_var,
-            input_opt.map(|i| i.value),
+            input_value_opt,
             ClauseType::Query,
             &Info::new(itd.type_name().to_owned(), info.type_defs()),
             self.partition_key_opt,
@@ -448,18 +451,9 @@ impl<'r> Resolver<'r> {
             params,
             &node_var,
             ClauseType::Query,
+            limit,
         )?;
-        let results = transaction.read_nodes(query, Some(params), self.partition_key_opt, info);
-
-        if info.name() == "Mutation" || info.name() == "Query" {
-            if results.is_ok() {
-                transaction.commit()?;
-            } else {
-                transaction.rollback()?;
-            }
-        }
-
-        results
+        transaction.read_nodes(query, Some(params), self.partition_key_opt, info)
     }
 
     pub(super) fn resolve_node_update_mutation<RequestCtx: RequestContext>(
@@ -475,40 +469,18 @@ impl<'r> Resolver<'r> {
             field_name,
             input
         );
-        #[cfg(feature = "neo4j")]
-        let mut runtime = Runtime::new()?;
         let p = info.type_def()?.property(field_name)?;
 
-        let results: Vec<Node<RequestCtx>> = match &executor.context().pool() {
-            #[cfg(feature = "cosmos")]
-            DatabasePool::Cosmos(c) => self.resolve_node_update_mutation_with_transaction(
-                field_name,
-                info,
-                input,
-                &mut GremlinTransaction::new(c.clone(), true, false),
-                executor,
-            ),
-            #[cfg(feature = "gremlin")]
-            DatabasePool::Gremlin((c, uuid)) => self.resolve_node_update_mutation_with_transaction(
+        let results: Vec<Node<RequestCtx>> = self
+            .node_update_operation(
                 field_name,
                 info,
-                input,
-                &mut GremlinTransaction::new(c.clone(), false, *uuid),
+                input.value,
+                &RetriedTransaction::new(executor.context()),
                 executor,
-            ),
-            #[cfg(feature = "neo4j")]
-            DatabasePool::Neo4j(p) => {
-                let c = runtime.block_on(p.get())?;
-                self.resolve_node_update_mutation_with_transaction(
-                    field_name,
-                    info,
-                    input,
-                    &mut Neo4jTransaction::new(c, &mut runtime),
-                    executor,
-                )
-            }
-            DatabasePool::NoDatabase => Err(Error::DatabaseNotFound),
-        }?;
+            )
+            .map(|nodes| nodes.into_iter().map(Node::from).collect())
+            .map_err(Error::into_field_error)?;
 
         trace!(
             "Resolver::resolve_node_update_mutation result: {:#?}",
@@ -521,24 +493,51 @@ impl<'r> Resolver<'r> {
         )
     }
 
-    #[cfg(any(feature = "cosmos", feature = "gremlin", feature = "neo4j"))]
-    pub(super) fn resolve_node_update_mutation_with_transaction<RequestCtx, T>(
+    fn resolve_node_update_mutation_with_transaction<RequestCtx, T>(
         &mut self,
         field_name: &str,
         info: &Info,
-        input: Input<RequestCtx>,
+        input_value: Value,
         transaction: &mut T,
         executor: &Executor<GraphQLContext<RequestCtx>>,
-    ) -> Result<Vec<Node<RequestCtx>>, Error>
+    ) -> Result<Vec<NodeRecord>, Error>
     where
         RequestCtx: RequestContext,
-        T: Transaction,
+        T: Transaction + ?Sized,
     {
         let mut sg = SuffixGenerator::new();
+        let context = executor.context();
         let p = info.type_def()?.property(field_name)?;
         let itd = p.input_type_definition(info)?;
 
-        transaction.begin()?;
+        let names = (p.type_name(), None);
+        let old_nodes = if has_triggers(
+            info,
+            names,
+            &[TriggerEvent::BeforeUpdate, TriggerEvent::AfterUpdate],
+        )? {
+            read_matched_nodes(
+                p.type_name(),
+                &input_value,
+                info,
+                self.partition_key_opt,
+                transaction,
+            )?
+        } else {
+            Vec::new()
+        };
+        old_nodes.iter().try_for_each(|node| {
+            run_triggers(
+                context,
+                TriggerEvent::BeforeUpdate,
+                names,
+                Some(&input_value),
+                (Some(TriggerRecord::Node(node)), None),
+                info,
+                self.partition_key_opt,
+                transaction,
+            )
+        })?;
         let (query, params) = visit_node_update_input::<T, RequestCtx>(
             HashMap::new(),
             &NodeQueryVar::new(
@@ -546,22 +545,30 @@ impl<'r> Resolver<'r> {
                 "node".to_string(),
                 sg.suffix(),
             ),
-            input.value,
+            input_value,
             &Info::new(itd.type_name().to_owned(), info.type_defs()),
             self.partition_key_opt,
             &mut sg,
             transaction,
-            &executor.context().validators(),
+            executor.context(),
         )?;
-        let result = transaction.update_nodes(query, params, self.partition_key_opt, info);
-
-        if result.is_ok() {
-            transaction.commit()?;
-        } else {
-            transaction.rollback()?;
-        }
-
-        result
+        transaction
+            .update_nodes(query, params, self.partition_key_opt, info)
+            .and_then(|nodes| {
+                nodes.iter().try_for_each(|node| {
+                    run_triggers(
+                        context,
+                        TriggerEvent::AfterUpdate,
+                        names,
+                        None,
+                        (old_node(&old_nodes, node), Some(TriggerRecord::Node(node))),
+                        info,
+                        self.partition_key_opt,
+                        transaction,
+                    )
+                })?;
+                Ok(nodes)
+            })
     }
 
     pub(super) fn resolve_rel_create_mutation<RequestCtx: RequestContext>(
@@ -580,46 +587,19 @@ impl<'r> Resolver<'r> {
         src_label,
         rel_name, input
     );
-        #[cfg(feature = "neo4j")]
-        let mut runtime = Runtime::new()?;
         let p = info.type_def()?.property(field_name)?;
 
-        let results: Vec<Rel<RequestCtx>> = match &executor.context().pool() {
-            #[cfg(feature = "cosmos")]
-            DatabasePool::Cosmos(c) => self.resolve_rel_create_mutation_with_transaction(
-                field_name,
-                src_label,
-                rel_name,
-                info,
-                input,
-                &mut GremlinTransaction::new(c.clone(), true, false),
-                executor,
-            ),
-            #[cfg(feature = "gremlin")]
-            DatabasePool::Gremlin((c, uuid)) => self.resolve_rel_create_mutation_with_transaction(
+        let results: Vec<Rel<RequestCtx>> = self
+            .rel_create_operation(
                 field_name,
-                src_label,
-                rel_name,
+                (src_label, rel_name),
                 info,
-                input,
-                &mut GremlinTransaction::new(c.clone(), false, *uuid),
+                input.value,
+                &RetriedTransaction::new(executor.context()),
                 executor,
-            ),
-            #[cfg(feature = "neo4j")]
-            DatabasePool::Neo4j(p) => {
-                let c = runtime.block_on(p.get())?;
-                self.resolve_rel_create_mutation_with_transaction(
-                    field_name,
-                    src_label,
-                    rel_name,
-                    info,
-                    input,
-                    &mut Neo4jTransaction::new(c, &mut runtime),
-                    executor,
-                )
-            }
-            DatabasePool::NoDatabase => Err(Error::DatabaseNotFound),
-        }?;
+            )
+            .map(|rels| rels.into_iter().map(Rel::from).collect())
+            .map_err(Error::into_field_error)?;
 
         executor.resolve(
             &Info::new(p.type_name().to_owned(), info.type_defs()),
@@ -627,24 +607,23 @@ impl<'r> Resolver<'r> {
         )
     }
 
-    #[cfg(any(feature = "cosmos", feature = "gremlin", feature = "neo4j"))]
     #[allow(clippy::too_many_arguments)]
-    pub(super) fn resolve_rel_create_mutation_with_transaction<RequestCtx, T>(
+    fn resolve_rel_create_mutation_with_transaction<RequestCtx, T>(
         &mut self,
         field_name: &str,
         src_label: &str,
         rel_name: &str,
         info: &Info,
-        input: Input<RequestCtx>,
+        input_value: Value,
         transaction: &mut T,
         executor: &Executor<GraphQLContext<RequestCtx>>,
-    ) -> Result<Vec<Rel<RequestCtx>>, Error>
+    ) -> Result<Vec<RelRecord>, Error>
     where
         RequestCtx: RequestContext,
-        T: Transaction,
+        T: Transaction + ?Sized,
     {
         let mut sg = SuffixGenerator::new();
-        let validators = &executor.context().validators();
+        let context = executor.context();
 
         let td = info.type_def()?;
         let p = td.property(field_name)?;
@@ -653,7 +632,17 @@ impl<'r> Resolver<'r> {
         let src_var =
             NodeQueryVar::new(Some(src_label.to_string()), "src".to_string(), sg.suffix());
 
-        transaction.begin()?;
+        let names = (src_label, Some(rel_name));
+        run_triggers(
+            context,
+            TriggerEvent::BeforeCreate,
+            names,
+            Some(&input_value),
+            (None, None),
+            info,
+            self.partition_key_opt,
+            transaction,
+        )?;
         let (query, params) = visit_rel_create_input::<T, RequestCtx>(
             HashMap::new(),
             &src_var,
@@ -662,27 +651,35 @@ impl<'r> Resolver<'r> {
             // as it's expected that some relationship types may not have props defined
             // in their schema, in which case the missing property is fine.
             rtd.property("props").map(|pp| pp.type_name()).ok(),
-            input.value,
+            input_value,
             &Info::new(itd.type_name().to_owned(), info.type_defs()),
             self.partition_key_opt,
             &mut sg,
             transaction,
-            validators,
+            context,
         )?;
-        let result = transaction.create_rels(
-            query,
-            params,
-            rtd.property("props").map(|pp| pp.type_name()).ok(),
-            self.partition_key_opt,
-        );
-
-        if result.is_ok() {
-            transaction.commit()?;
-        } else {
-            transaction.rollback()?;
-        }
-
-        result
+        transaction
+            .create_rels(
+                query,
+                params,
+                rtd.property("props").map(|pp| pp.type_name()).ok(),
+                self.partition_key_opt,
+            )
+            .and_then(|rels| {
+                rels.iter().try_for_each(|rel| {
+                    run_triggers(
+                        context,
+                        TriggerEvent::AfterCreate,
+                        names,
+                        None,
+                        (None, Some(TriggerRecord::Rel(rel))),
+                        info,
+                        self.partition_key_opt,
+                        transaction,
+                    )
+                })?;
+                Ok(rels)
+            })
     }
 
     pub(super) fn resolve_rel_delete_mutation<RequestCtx: RequestContext>(
@@ -700,61 +697,38 @@ impl<'r> Resolver<'r> {
         field_name,
         src_label, rel_name, input
     );
-        #[cfg(feature = "neo4j")]
-        let mut runtime = Runtime::new()?;
 
-        let results: i32 = match executor.context().pool() {
-            #[cfg(feature = "cosmos")]
-            DatabasePool::Cosmos(c) => self.resolve_rel_delete_mutation_with_transaction(
+        let results: i32 = self
+            .rel_delete_operation(
                 field_name,
-                src_label,
-                rel_name,
+                (src_label, rel_name),
                 info,
-                input,
-                &mut GremlinTransaction::new(c.clone(), true, false),
-            ),
-            #[cfg(feature = "gremlin")]
-            DatabasePool::Gremlin((c, uuid)) => self.resolve_rel_delete_mutation_with_transaction(
-                field_name,
-                src_label,
-                rel_name,
-                info,
-                input,
-                &mut GremlinTransaction::new(c.clone(), false, *uuid),
-            ),
-            #[cfg(feature = "neo4j")]
-            DatabasePool::Neo4j(p) => {
-                let c = runtime.block_on(p.get())?;
-                self.resolve_rel_delete_mutation_with_transaction(
-                    field_name,
-                    src_label,
-                    rel_name,
-                    info,
-                    input,
-                    &mut Neo4jTransaction::new(c, &mut runtime),
-                )
-            }
-            DatabasePool::NoDatabase => Err(Error::DatabaseNotFound),
-        }?;
+                input.value,
+                &RetriedTransaction::new(executor.context()),
+                executor,
+            )
+            .map_err(Error::into_field_error)?;
 
         executor.resolve_with_ctx(&(), &results)
     }
 
-    #[cfg(any(feature = "cosmos", feature = "gremlin", feature = "neo4j"))]
-    pub(super) fn resolve_rel_delete_mutation_with_transaction<RequestCtx, T>(
+    #[allow(clippy::too_many_arguments)]
+    fn resolve_rel_delete_mutation_with_transaction<RequestCtx, T>(
         &mut self,
         field_name: &str,
         src_label: &str,
         rel_name: &str,
         info: &Info,
-        input: Input<RequestCtx>,
+        input_value: Value,
         transaction: &mut T,
+        executor: &Executor<GraphQLContext<RequestCtx>>,
     ) -> Result<i32, Error>
     where
         RequestCtx: RequestContext,
-        T: Transaction,
+        T: Transaction + ?Sized,
     {
         let mut sg = SuffixGenerator::new();
+        let context = executor.context();
         let td = info.type_def()?;
         let p = td.property(field_name)?;
         let itd = p.input_type_definition(info)?;
@@ -766,26 +740,61 @@ impl<'r> Resolver<'r> {
             NodeQueryVar::new(None, "dst".to_string(), sg.suffix()),
         );
 
-        transaction.begin()?;
+        let names = (src_label, Some(rel_name));
+        let old_rels = if has_triggers(
+            info,
+            names,
+            &[TriggerEvent::BeforeDelete, TriggerEvent::AfterDelete],
+        )? {
+            read_matched_rels(
+                (src_label, rel_name),
+                &input_value,
+                info,
+                self.partition_key_opt,
+                transaction,
+            )?
+        } else {
+            Vec::new()
+        };
+        old_rels.iter().try_for_each(|rel| {
+            run_triggers(
+                context,
+                TriggerEvent::BeforeDelete,
+                names,
+                Some(&input_value),
+                (Some(TriggerRecord::Rel(rel)), None),
+                info,
+                self.partition_key_opt,
+                transaction,
+            )
+        })?;
         let (query, params) = visit_rel_delete_input::<T, RequestCtx>(
             HashMap::new(),
             &rel_var,
-            input.value,
+            input_value,
             ClauseType::Query,
             &Info::new(itd.type_name().to_owned(), info.type_defs()),
             self.partition_key_opt,
             &mut sg,
             transaction,
         )?;
-        let results = transaction.delete_rels(query, params, self.partition_key_opt);
-
-        if results.is_ok() {
-            transaction.commit()?;
-        } else {
-            transaction.rollback()?;
-        }
-
-        results
+        transaction
+            .delete_rels(query, params, self.partition_key_opt)
+            .and_then(|count| {
+                old_rels.iter().try_for_each(|rel| {
+                    run_triggers(
+                        context,
+                        TriggerEvent::AfterDelete,
+                        names,
+                        None,
+                        (Some(TriggerRecord::Rel(rel)), None),
+                        info,
+                        self.partition_key_opt,
+                        transaction,
+                    )
+                })?;
+                Ok(count)
+            })
     }
 
     pub(super) fn resolve_rel_props<RequestCtx: RequestContext>(
@@ -827,40 +836,19 @@ impl<'r> Resolver<'r> {
         input_opt
     );
 
-        #[cfg(feature = "neo4j")]
-        let mut runtime = Runtime::new()?;
         let p = info.type_def()?.property(field_name)?;
 
-        let results: Vec<Rel<RequestCtx>> = match executor.context().pool() {
-            #[cfg(feature = "cosmos")]
-            DatabasePool::Cosmos(c) => self.resolve_rel_read_query_with_transaction(
+        let results: Vec<Rel<RequestCtx>> = self
+            .rel_read_operation(
                 field_name,
                 rel_name,
                 info,
-                input_opt,
-                &mut GremlinTransaction::new(c.clone(), true, false),
-            ),
-            #[cfg(feature = "gremlin")]
-            DatabasePool::Gremlin((c, uuid)) => self.resolve_rel_read_query_with_transaction(
-                field_name,
-                rel_name,
-                info,
-                input_opt,
-                &mut GremlinTransaction::new(c.clone(), false, *uuid),
-            ),
-            #[cfg(feature = "neo4j")]
-            DatabasePool::Neo4j(p) => {
-                let c = runtime.block_on(p.get())?;
-                self.resolve_rel_read_query_with_transaction(
-                    field_name,
-                    rel_name,
-                    info,
-                    input_opt,
-                    &mut Neo4jTransaction::new(c, &mut runtime),
-                )
-            }
-            DatabasePool::NoDatabase => Err(Error::DatabaseNotFound),
-        }?;
+                input_opt.map(|i| i.value),
+                &RetriedTransaction::new(executor.context()),
+                executor,
+            )
+            .map(|rels| rels.into_iter().map(Rel::from).collect())
+            .map_err(Error::into_field_error)?;
 
         if p.list() {
             executor.resolve(
@@ -893,19 +881,17 @@ impl<'r> Resolver<'r> {
         }
     }
 
-    #[cfg(any(feature = "cosmos", feature = "gremlin", feature = "neo4j"))]
-    #[allow(clippy::too_many_arguments)]
-    pub(super) fn resolve_rel_read_query_with_transaction<RequestCtx, T>(
+    fn resolve_rel_read_query_with_transaction<T>(
         &mut self,
         field_name: &str,
         rel_name: &str,
         info: &Info,
-        input_opt: Option<Input<RequestCtx>>,
+        input_value_opt: Option<Value>,
+        limit: Option<usize>,
         transaction: &mut T,
-    ) -> Result<Vec<Rel<RequestCtx>>, Error>
+    ) -> Result<Vec<RelRecord>, Error>
     where
-        RequestCtx: RequestContext,
-        T: Transaction,
+        T: Transaction + ?Sized,
     {
         let mut sg = SuffixGenerator::new();
         let td = info.type_def()?;
@@ -925,13 +911,10 @@ impl<'r> Resolver<'r> {
         let dst_var = NodeQueryVar::new(None, "dst".to_string(), dst_suffix);
         let rel_var = RelQueryVar::new(rel_name.to_string(), rel_suffix, src_var, dst_var);
 
-        if info.name() == "Mutation" || info.name() == "Query" {
-            transaction.begin()?;
-        }
         let (match_fragment, where_fragment, params) = visit_rel_query_input(
             HashMap::new(),
             &rel_var,
-            input_opt.map(|i| i.value),
+            input_value_opt,
             ClauseType::Query,
             &Info::new(itd.type_name().to_owned(), info.type_defs()),
             self.partition_key_opt,
@@ -944,23 +927,14 @@ impl<'r> Resolver<'r> {
             params,
             &rel_var,
             ClauseType::Query,
+            limit,
         )?;
-        let results = transaction.read_rels(
+        transaction.read_rels(
             query,
             Some(params),
             Some(p.type_name()),
             self.partition_key_opt,
-        );
-
-        if info.name() == "Mutation" || info.name() == "Query" {
-            if results.is_ok() {
-                transaction.commit()?;
-            } else {
-                transaction.rollback()?;
-            }
-        }
-
-        results
+        )
     }
 
     pub(super) fn resolve_rel_update_mutation<RequestCtx: RequestContext>(
@@ -980,46 +954,19 @@ impl<'r> Resolver<'r> {
         input
     );
 
-        #[cfg(feature = "neo4j")]
-        let mut runtime = Runtime::new()?;
         let p = info.type_def()?.property(field_name)?;
 
-        let results: Vec<Rel<RequestCtx>> = match executor.context().pool() {
-            #[cfg(feature = "cosmos")]
-            DatabasePool::Cosmos(c) => self.resolve_rel_update_mutation_with_transaction(
+        let results: Vec<Rel<RequestCtx>> = self
+            .rel_update_operation(
                 field_name,
-                src_label,
-                rel_name,
+                (src_label, rel_name),
                 info,
-                input,
-                &mut GremlinTransaction::new(c.clone(), true, false),
+                input.value,
+                &RetriedTransaction::new(executor.context()),
                 executor,
-            ),
-            #[cfg(feature = "gremlin")]
-            DatabasePool::Gremlin((c, uuid)) => self.resolve_rel_update_mutation_with_transaction(
-                field_name,
-                src_label,
-                rel_name,
-                info,
-                input,
-                &mut GremlinTransaction::new(c.clone(), false, *uuid),
-                executor,
-            ),
-            #[cfg(feature = "neo4j")]
-            DatabasePool::Neo4j(p) => {
-                let c = runtime.block_on(p.get())?;
-                self.resolve_rel_update_mutation_with_transaction(
-                    field_name,
-                    src_label,
-                    rel_name,
-                    info,
-                    input,
-                    &mut Neo4jTransaction::new(c, &mut runtime),
-                    executor,
-                )
-            }
-            DatabasePool::NoDatabase => Err(Error::DatabaseNotFound),
-        }?;
+            )
+            .map(|rels| rels.into_iter().map(Rel::from).collect())
+            .map_err(Error::into_field_error)?;
 
         executor.resolve(
             &Info::new(p.type_name().to_owned(), info.type_defs()),
@@ -1027,24 +974,23 @@ impl<'r> Resolver<'r> {
         )
     }
 
-    #[cfg(any(feature = "cosmos", feature = "gremlin", feature = "neo4j"))]
     #[allow(clippy::too_many_arguments)]
-    pub(super) fn resolve_rel_update_mutation_with_transaction<RequestCtx, T>(
+    fn resolve_rel_update_mutation_with_transaction<RequestCtx, T>(
         &mut self,
         field_name: &str,
         src_label: &str,
         rel_name: &str,
         info: &Info,
-        input: Input<RequestCtx>,
+        input_value: Value,
         transaction: &mut T,
         executor: &Executor<GraphQLContext<RequestCtx>>,
-    ) -> Result<Vec<Rel<RequestCtx>>, Error>
+    ) -> Result<Vec<RelRecord>, Error>
     where
         RequestCtx: RequestContext,
-        T: Transaction,
+        T: Transaction + ?Sized,
     {
         let mut sg = SuffixGenerator::new();
-        let validators = &executor.context().validators();
+        let context = executor.context();
         let td = info.type_def()?;
         let p = td.property(field_name)?;
         let itd = p.input_type_definition(info)?;
@@ -1057,33 +1003,68 @@ impl<'r> Resolver<'r> {
             NodeQueryVar::new(None, "dst".to_string(), sg.suffix()),
         );
 
-        transaction.begin()?;
+        let names = (src_label, Some(rel_name));
+        let old_rels = if has_triggers(
+            info,
+            names,
+            &[TriggerEvent::BeforeUpdate, TriggerEvent::AfterUpdate],
+        )? {
+            read_matched_rels(
+                (src_label, rel_name),
+                &input_value,
+                info,
+                self.partition_key_opt,
+                transaction,
+            )?
+        } else {
+            Vec::new()
+        };
+        old_rels.iter().try_for_each(|rel| {
+            run_triggers(
+                context,
+                TriggerEvent::BeforeUpdate,
+                names,
+                Some(&input_value),
+                (Some(TriggerRecord::Rel(rel)), None),
+                info,
+                self.partition_key_opt,
+                transaction,
+            )
+        })?;
         let (query, params) = visit_rel_update_input::<T, RequestCtx>(
             HashMap::new(),
             &rel_var,
             props_prop.map(|_| p.type_name()).ok(),
-            input.value,
+            input_value,
             ClauseType::Query,
             &Info::new(itd.type_name().to_owned(), info.type_defs()),
             self.partition_key_opt,
             &mut sg,
             transaction,
-            validators,
+            context,
         )?;
-        let results = transaction.update_rels(
-            query,
-            params,
-            rtd.property("props").map(|_| p.type_name()).ok(),
-            self.partition_key_opt,
-        );
-
-        if results.is_ok() {
-            transaction.commit()?;
-        } else {
-            transaction.rollback()?;
-        }
-
-        results
+        transaction
+            .update_rels(
+                query,
+                params,
+                rtd.property("props").map(|_| p.type_name()).ok(),
+                self.partition_key_opt,
+            )
+            .and_then(|rels| {
+                rels.iter().try_for_each(|rel| {
+                    run_triggers(
+                        context,
+                        TriggerEvent::AfterUpdate,
+                        names,
+                        None,
+                        (old_rel(&old_rels, rel), Some(TriggerRecord::Rel(rel))),
+                        info,
+                        self.partition_key_opt,
+                        transaction,
+                    )
+                })?;
+                Ok(rels)
+            })
     }
 
     pub(super) fn resolve_scalar_field<RequestCtx: RequestContext>(
@@ -1176,38 +1157,20 @@ impl<'r> Resolver<'r> {
             dst_id
         );
 
-        #[cfg(feature = "neo4j")]
-        let mut runtime = Runtime::new()?;
-        let results: Vec<Node<RequestCtx>> = match executor.context().pool() {
-            #[cfg(feature = "cosmos")]
-            DatabasePool::Cosmos(c) => self.resolve_union_field_with_transaction(
-                info,
-                dst_label,
-                field_name,
-                dst_id,
-                &mut GremlinTransaction::new(c.clone(), true, false),
-            ),
-            #[cfg(feature = "gremlin")]
-            DatabasePool::Gremlin((c, uuid)) => self.resolve_union_field_with_transaction(
-                info,
-                dst_label,
-                field_name,
-                dst_id,
-                &mut GremlinTransaction::new(c.clone(), false, *uuid),
-            ),
-            #[cfg(feature = "neo4j")]
-            DatabasePool::Neo4j(p) => {
-                let c = runtime.block_on(p.get())?;
+        let results: Vec<Node<RequestCtx>> = executor
+            .context()
+            .retry_policy()
+            .run(|| {
+                let mut transaction = executor.context().pool().transaction(info)?;
                 self.resolve_union_field_with_transaction(
                     info,
                     dst_label,
                     field_name,
                     dst_id,
-                    &mut Neo4jTransaction::new(c, &mut runtime),
+                    &mut *transaction,
                 )
-            }
-            DatabasePool::NoDatabase => Err(Error::DatabaseNotFound),
-        }?;
+            })
+            .map_err(Error::into_field_error)?;
 
         executor.resolve(
             &Info::new(dst_label.to_string(), info.type_defs()),
@@ -1230,7 +1193,6 @@ impl<'r> Resolver<'r> {
         )
     }
 
-    #[cfg(any(feature = "cosmos", feature = "gremlin", feature = "neo4j"))]
     pub(super) fn resolve_union_field_with_transaction<RequestCtx, T>(
         &mut self,
         info: &Info,
@@ -1241,7 +1203,7 @@ impl<'r> Resolver<'r> {
     ) -> Result<Vec<Node<RequestCtx>>, Error>
     where
         RequestCtx: RequestContext,
-        T: Transaction,
+        T: Transaction + ?Sized,
     {
         let mut sg = SuffixGenerator::new();
 
@@ -1265,12 +1227,496 @@ impl<'r> Resolver<'r> {
                     params,
                     &node_var,
                     ClauseType::Query,
+                    None,
                 )?;
-                transaction.read_nodes(query, Some(params), self.partition_key_opt, info)
+                transaction
+                    .read_nodes(query, Some(params), self.partition_key_opt, info)
+                    .map(|nodes| nodes.into_iter().map(Node::from).collect())
             }
             _ => Err(Error::SchemaItemNotFound {
                 name: info.name().to_string() + "::" + field_name,
             }),
         }
     }
+    /// Creates a node, passing the input through the `before_operation` hooks of the extensions
+    /// and the new node through the `after_operation` hooks. The node is created in a transaction
+    /// run by `transaction`, which the hooks run outside of.
+    pub(crate) fn node_create_operation<RequestCtx, O>(
+        &mut self,
+        field_name: &str,
+        info: &Info,
+        input: Value,
+        transaction: &O,
+        executor: &Executor<GraphQLContext<RequestCtx>>,
+    ) -> Result<NodeRecord, Error>
+    where
+        RequestCtx: RequestContext,
+        O: OperationTransaction,
+    {
+        let context = executor.context();
+        let p = info.type_def()?.property(field_name)?;
+
+        let mut operation = OperationContext::new(
+            OperationKind::Create,
+            p.type_name(),
+            None,
+            context.request_context(),
+        );
+        let input_value = required_input(before_operation(context, &mut operation, Some(input))?)?;
+        let node = transaction.run(info, |transaction| {
+            self.resolve_node_create_mutation_with_transaction(
+                field_name,
+                info,
+                input_value.clone(),
+                transaction,
+                executor,
+            )
+        })?;
+        transaction.written(p.type_name(), info);
+        after_operation(context, &operation, OperationResult::Nodes(vec![node]))?
+            .into_nodes()?
+            .into_iter()
+            .next()
+            .ok_or(Error::ResponseSetNotFound)
+    }
+
+    /// Deletes nodes between the operation hooks of the extensions, or updates them instead if a
+    /// `before_operation` hook rewrites the delete into an update
+    pub(crate) fn node_delete_operation<RequestCtx, O>(
+        &mut self,
+        field_name: &str,
+        label: &str,
+        info: &Info,
+        input: Value,
+        transaction: &O,
+        executor: &Executor<GraphQLContext<RequestCtx>>,
+    ) -> Result<i32, Error>
+    where
+        RequestCtx: RequestContext,
+        O: OperationTransaction,
+    {
+        let context = executor.context();
+
+        let mut operation = OperationContext::new(
+            OperationKind::Delete,
+            label,
+            None,
+            context.request_context(),
+        );
+        let input_value = required_input(before_operation(context, &mut operation, Some(input))?)?;
+        if operation.kind() == OperationKind::Update {
+            return self
+                .node_update_operation(
+                    &update_field_name(field_name),
+                    info,
+                    input_value,
+                    transaction,
+                    executor,
+                )
+                .map(|nodes| nodes.len() as i32);
+        }
+
+        let count = transaction.run(info, |transaction| {
+            self.resolve_node_delete_mutation_with_transaction(
+                field_name,
+                label,
+                info,
+                input_value.clone(),
+                transaction,
+                executor,
+            )
+        })?;
+        transaction.written(label, info);
+        after_operation(context, &operation, OperationResult::Count(count))?.into_count()
+    }
+
+    /// Reads nodes between the operation hooks of the extensions. The nodes read are counted
+    /// against the greatest number of nodes that the request may read.
+    pub(crate) fn node_read_operation<RequestCtx, O>(
+        &mut self,
+        field_name: &str,
+        info: &Info,
+        input_opt: Option<Value>,
+        transaction: &O,
+        executor: &Executor<GraphQLContext<RequestCtx>>,
+    ) -> Result<Vec<NodeRecord>, Error>
+    where
+        RequestCtx: RequestContext,
+        O: OperationTransaction,
+    {
+        let context = executor.context();
+        let p = info.type_def()?.property(field_name)?;
+
+        let mut operation = OperationContext::new(
+            OperationKind::Read,
+            p.type_name(),
+            None,
+            context.request_context(),
+        );
+        let input_value_opt = before_operation(context, &mut operation, input_opt)?;
+        let limit = context.read_limit();
+        let nodes = transaction.run(info, |transaction| {
+            self.resolve_node_read_query_with_transaction(
+                field_name,
+                info,
+                input_value_opt.clone(),
+                limit,
+                transaction,
+            )
+        })?;
+        context.count_nodes_read(nodes.len())?;
+        after_operation(context, &operation, OperationResult::Nodes(nodes))?.into_nodes()
+    }
+
+    /// Updates nodes between the operation hooks of the extensions
+    pub(crate) fn node_update_operation<RequestCtx, O>(
+        &mut self,
+        field_name: &str,
+        info: &Info,
+        input: Value,
+        transaction: &O,
+        executor: &Executor<GraphQLContext<RequestCtx>>,
+    ) -> Result<Vec<NodeRecord>, Error>
+    where
+        RequestCtx: RequestContext,
+        O: OperationTransaction,
+    {
+        let context = executor.context();
+        let p = info.type_def()?.property(field_name)?;
+
+        let mut operation = OperationContext::new(
+            OperationKind::Update,
+            p.type_name(),
+            None,
+            context.request_context(),
+        );
+        let input_value = required_input(before_operation(context, &mut operation, Some(input))?)?;
+        let nodes = transaction.run(info, |transaction| {
+            self.resolve_node_update_mutation_with_transaction(
+                field_name,
+                info,
+                input_value.clone(),
+                transaction,
+                executor,
+            )
+        })?;
+        transaction.written(p.type_name(), info);
+        after_operation(context, &operation, OperationResult::Nodes(nodes))?.into_nodes()
+    }
+
+    /// Creates relationships from nodes of the type `names.0` between the operation hooks of the
+    /// extensions
+    pub(crate) fn rel_create_operation<RequestCtx, O>(
+        &mut self,
+        field_name: &str,
+        names: (&str, &str),
+        info: &Info,
+        input: Value,
+        transaction: &O,
+        executor: &Executor<GraphQLContext<RequestCtx>>,
+    ) -> Result<Vec<RelRecord>, Error>
+    where
+        RequestCtx: RequestContext,
+        O: OperationTransaction,
+    {
+        let context = executor.context();
+        let (src_label, rel_name) = names;
+
+        let mut operation = OperationContext::new(
+            OperationKind::Create,
+            src_label,
+            Some(rel_name),
+            context.request_context(),
+        );
+        let input_value = required_input(before_operation(context, &mut operation, Some(input))?)?;
+        let rels = transaction.run(info, |transaction| {
+            self.resolve_rel_create_mutation_with_transaction(
+                field_name,
+                src_label,
+                rel_name,
+                info,
+                input_value.clone(),
+                transaction,
+                executor,
+            )
+        })?;
+        transaction.written(src_label, info);
+        after_operation(context, &operation, OperationResult::Rels(rels))?.into_rels()
+    }
+
+    /// Deletes relationships between the operation hooks of the extensions, or updates them
+    /// instead if a `before_operation` hook rewrites the delete into an update
+    pub(crate) fn rel_delete_operation<RequestCtx, O>(
+        &mut self,
+        field_name: &str,
+        names: (&str, &str),
+        info: &Info,
+        input: Value,
+        transaction: &O,
+        executor: &Executor<GraphQLContext<RequestCtx>>,
+    ) -> Result<i32, Error>
+    where
+        RequestCtx: RequestContext,
+        O: OperationTransaction,
+    {
+        let context = executor.context();
+        let (src_label, rel_name) = names;
+
+        let mut operation = OperationContext::new(
+            OperationKind::Delete,
+            src_label,
+            Some(rel_name),
+            context.request_context(),
+        );
+        let input_value = required_input(before_operation(context, &mut operation, Some(input))?)?;
+        if operation.kind() == OperationKind::Update {
+            return self
+                .rel_update_operation(
+                    &update_field_name(field_name),
+                    names,
+                    info,
+                    input_value,
+                    transaction,
+                    executor,
+                )
+                .map(|rels| rels.len() as i32);
+        }
+
+        let count = transaction.run(info, |transaction| {
+            self.resolve_rel_delete_mutation_with_transaction(
+                field_name,
+                src_label,
+                rel_name,
+                info,
+                input_value.clone(),
+                transaction,
+                executor,
+            )
+        })?;
+        transaction.written(src_label, info);
+        after_operation(context, &operation, OperationResult::Count(count))?.into_count()
+    }
+
+    /// Reads relationships between the operation hooks of the extensions. The relationships read
+    /// are counted against the greatest number of nodes and relationships that the request may
+    /// read.
+    pub(crate) fn rel_read_operation<RequestCtx, O>(
+        &mut self,
+        field_name: &str,
+        rel_name: &str,
+        info: &Info,
+        input_opt: Option<Value>,
+        transaction: &O,
+        executor: &Executor<GraphQLContext<RequestCtx>>,
+    ) -> Result<Vec<RelRecord>, Error>
+    where
+        RequestCtx: RequestContext,
+        O: OperationTransaction,
+    {
+        let context = executor.context();
+        let p = info.type_def()?.property(field_name)?;
+        let src_prop = info.type_def_by_name(p.type_name())?.property("src")?;
+
+        let mut operation = OperationContext::new(
+            OperationKind::Read,
+            src_prop.type_name(),
+            Some(rel_name),
+            context.request_context(),
+        );
+        let input_value_opt = before_operation(context, &mut operation, input_opt)?;
+        let limit = context.read_limit();
+        let rels = transaction.run(info, |transaction| {
+            self.resolve_rel_read_query_with_transaction(
+                field_name,
+                rel_name,
+                info,
+                input_value_opt.clone(),
+                limit,
+                transaction,
+            )
+        })?;
+        context.count_nodes_read(rels.len())?;
+        after_operation(context, &operation, OperationResult::Rels(rels))?.into_rels()
+    }
+
+    /// Updates relationships between the operation hooks of the extensions
+    pub(crate) fn rel_update_operation<RequestCtx, O>(
+        &mut self,
+        field_name: &str,
+        names: (&str, &str),
+        info: &Info,
+        input: Value,
+        transaction: &O,
+        executor: &Executor<GraphQLContext<RequestCtx>>,
+    ) -> Result<Vec<RelRecord>, Error>
+    where
+        RequestCtx: RequestContext,
+        O: OperationTransaction,
+    {
+        let context = executor.context();
+        let (src_label, rel_name) = names;
+
+        let mut operation = OperationContext::new(
+            OperationKind::Update,
+            src_label,
+            Some(rel_name),
+            context.request_context(),
+        );
+        let input_value = required_input(before_operation(context, &mut operation, Some(input))?)?;
+        let rels = transaction.run(info, |transaction| {
+            self.resolve_rel_update_mutation_with_transaction(
+                field_name,
+                src_label,
+                rel_name,
+                info,
+                input_value.clone(),
+                transaction,
+                executor,
+            )
+        })?;
+        transaction.written(src_label, info);
+        after_operation(context, &operation, OperationResult::Rels(rels))?.into_rels()
+    }
+}
+
+/// Runs `f` between a begin and a commit of `transaction`, rolling the transaction back instead if
+/// `f` fails
+fn in_transaction<T, R, F>(transaction: &mut T, f: F) -> Result<R, Error>
+where
+    T: Transaction + ?Sized,
+    F: FnOnce(&mut T) -> Result<R, Error>,
+{
+    transaction.begin()?;
+    let result = f(transaction);
+    if result.is_ok() {
+        transaction.commit()?;
+    } else {
+        transaction.rollback()?;
+    }
+
+    result
+}
+
+/// Runs the database part of an operation in a transaction. The operation hooks of the extensions
+/// run outside of the transaction, so the `before_operation` hooks run once even if the
+/// transaction is retried, and the `after_operation` hooks run once it is committed.
+pub(crate) trait OperationTransaction {
+    /// Runs `f` in a transaction for an operation of the type described by `info`
+    fn run<R, F>(&self, info: &Info, f: F) -> Result<R, Error>
+    where
+        F: FnMut(&mut dyn Transaction) -> Result<R, Error>;
+
+    /// Invalidates the cached responses that may read data written to the type `type_name` by an
+    /// operation, once the writes are committed
+    fn written(&self, type_name: &str, info: &Info);
+}
+
+/// Runs each operation of a generated endpoint in a transaction of its own, retried according to
+/// the retry policy of the engine. Reads nested in the results of another operation are run
+/// without beginning a transaction. An operation is not retried once it has made writes that the
+/// back-end cannot roll back.
+struct RetriedTransaction<'c, RequestCtx: RequestContext> {
+    context: &'c GraphQLContext<RequestCtx>,
+}
+
+impl<'c, RequestCtx: RequestContext> RetriedTransaction<'c, RequestCtx> {
+    fn new(context: &'c GraphQLContext<RequestCtx>) -> RetriedTransaction<'c, RequestCtx> {
+        RetriedTransaction { context }
+    }
+}
+
+impl<RequestCtx: RequestContext> OperationTransaction for RetriedTransaction<'_, RequestCtx> {
+    fn run<R, F>(&self, info: &Info, mut f: F) -> Result<R, Error>
+    where
+        F: FnMut(&mut dyn Transaction) -> Result<R, Error>,
+    {
+        // on back-ends without transactions, the writes of a failed attempt are kept, so the
+        // operation is retried only if the attempt failed before it wrote anything
+        let written = Cell::new(false);
+        self.context.retry_policy().run_if(
+            || !written.get(),
+            || {
+                let mut transaction = self.context.pool().transaction(info)?;
+                let result = if info.name() == "Mutation" || info.name() == "Query" {
+                    in_transaction(&mut *transaction, |transaction| f(transaction))
+                } else {
+                    f(&mut *transaction)
+                };
+                written.set(transaction.irreversible_writes());
+                result
+            },
+        )
+    }
+
+    /// Invalidates the cached responses at once, as the transaction run for the operation has
+    /// already been committed
+    fn written(&self, type_name: &str, info: &Info) {
+        if let Some(cache_store) = self.context.cache_store() {
+            CachePlan::written(vec![type_name.to_string()], &info.type_defs())
+                .invalidate(cache_store);
+        }
+    }
+}
+
+/// Returns the input of a mutation as returned by the `before_operation` hooks, or an error if a
+/// hook removed it
+fn required_input(input_opt: Option<Value>) -> Result<Value, Error> {
+    input_opt.ok_or_else(|| Error::InputItemNotFound {
+        name: "input".to_string(),
+    })
+}
+
+/// Passes the input of an operation through the `before_operation` hooks of the registered
+/// extensions, returning the input as modified by the hooks
+fn before_operation<RequestCtx: RequestContext>(
+    context: &GraphQLContext<RequestCtx>,
+    operation: &mut OperationContext<RequestCtx>,
+    input_opt: Option<Value>,
+) -> Result<Option<Value>, Error> {
+    let mut input_opt = input_opt;
+    context
+        .extensions()
+        .try_for_each(|e| e.before_operation(operation, &mut input_opt))?;
+    Ok(input_opt)
+}
+
+/// Passes the result of an operation through the `after_operation` hooks of the registered
+/// extensions, returning the result as modified by the hooks
+fn after_operation<RequestCtx: RequestContext>(
+    context: &GraphQLContext<RequestCtx>,
+    operation: &OperationContext<RequestCtx>,
+    result: OperationResult,
+) -> Result<OperationResult, Error> {
+    let mut result = result;
+    context
+        .extensions()
+        .try_for_each(|e| e.after_operation(operation, &mut result))?;
+    Ok(result)
+}
+
+/// Returns the node in `old_nodes`, read before an update, with the same id as the updated `node`
+fn old_node<'n>(old_nodes: &'n [NodeRecord], node: &NodeRecord) -> Option<TriggerRecord<'n>> {
+    old_nodes
+        .iter()
+        .find(|n| n.fields().get("id") == node.fields().get("id"))
+        .map(TriggerRecord::Node)
+}
+
+/// Returns the relationship in `old_rels`, read before an update, with the same id as the updated
+/// `rel`
+fn old_rel<'r>(old_rels: &'r [RelRecord], rel: &RelRecord) -> Option<TriggerRecord<'r>> {
+    old_rels
+        .iter()
+        .find(|r| r.id() == rel.id())
+        .map(TriggerRecord::Rel)
+}
+
+/// Takes the name of a generated delete mutation, such as `ProjectOwnersDelete`, and returns the
+/// name of the corresponding update mutation, such as `ProjectOwnersUpdate`
+fn update_field_name(delete_field_name: &str) -> String {
+    delete_field_name
+        .strip_suffix("Delete")
+        .unwrap_or(delete_field_name)
+        .to_string()
+        + "Update"
 }
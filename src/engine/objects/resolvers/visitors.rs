@@ -1,8 +1,13 @@
-use crate::engine::context::RequestContext;
-use crate::engine::database::{ClauseType, NodeQueryVar, RelQueryVar, Transaction};
+use crate::engine::config::TriggerEvent;
+use crate::engine::context::{GraphQLContext, RequestContext};
+use crate::engine::database::{
+    ClauseType, NodeQueryVar, NodeRecord, RelQueryVar, RelRecord, Transaction,
+};
+use crate::engine::id_generators::generate_id;
 use crate::engine::objects::resolvers::SuffixGenerator;
-use crate::engine::schema::{Info, PropertyKind};
-use crate::engine::validators::Validators;
+use crate::engine::schema::{Info, NodeType, PropertyKind};
+use crate::engine::triggers::{TriggerContext, TriggerRecord, TriggerWriter};
+use crate::engine::validators::{Operation, ValidationContext, ValidationReader, Validators};
 use crate::engine::value::Value;
 use crate::error::Error;
 use log::trace;
@@ -18,10 +23,10 @@ pub(super) fn visit_node_create_mutation_input<T, RequestCtx>(
     partition_key_opt: Option<&Value>,
     sg: &mut SuffixGenerator,
     transaction: &mut T,
-    validators: &Validators,
+    context: &GraphQLContext<RequestCtx>,
 ) -> Result<(String, HashMap<String, Value>), Error>
 where
-    T: Transaction,
+    T: Transaction + ?Sized,
     RequestCtx: RequestContext,
 {
     trace!(
@@ -35,16 +40,31 @@ where
         m.keys().try_for_each(|k| {
             let p = itd.property(k)?;
             match p.kind() {
-                PropertyKind::Scalar | PropertyKind::DynamicScalar => p
-                    .validator()
-                    .map_or(Ok(()), |v_name| validate_input(validators, &v_name, &input)),
+                PropertyKind::Scalar | PropertyKind::DynamicScalar => {
+                    p.validator().map_or(Ok(()), |v_name| {
+                        validate_input(context.validators(), v_name, k, &input)
+                    })
+                }
                 _ => Ok(()), // No validation action to take
             }
         })?
     }
 
+    let label = node_var.label()?;
+    if let Some(v) = info.type_def_by_name(label)?.validator() {
+        validate_context(
+            context,
+            v,
+            Operation::Create,
+            (label, None),
+            &input,
+            None,
+            &mut TransactionReader::new(transaction, info, partition_key_opt),
+        )?;
+    }
+
     if let Value::Map(m) = input {
-        let (props, inputs) = m.into_iter().try_fold(
+        let (mut props, inputs) = m.into_iter().try_fold(
             (HashMap::new(), HashMap::new()),
             |(mut props, mut inputs), (k, v)| {
                 match itd.property(&k)?.kind() {
@@ -60,6 +80,16 @@ where
             },
         )?;
 
+        // Generate the id of the new node, unless the client supplied one or the type leaves it to
+        // the database back-end
+        if let Some(identifier) = info.type_def_by_name(label)?.id() {
+            if !props.contains_key("id") {
+                if let Some(id) = generate_id(context.id_generators(), label, identifier)? {
+                    props.insert("id".to_string(), Value::String(id));
+                }
+            }
+        }
+
         let (rel_create_fragments, params) = inputs.into_iter().try_fold(
             (Vec::new(), params),
             |(mut rel_create_fragments, params),
@@ -88,13 +118,14 @@ where
                                             NodeQueryVar::new(None, "dst".to_string(), sg.suffix()),
                                         ),
                                         None,
+                                        None,
                                         val,
                                         ClauseType::SubQuery,
                                         &Info::new(p.type_name().to_owned(), info.type_defs()),
                                         partition_key_opt,
                                         sg,
                                         transaction,
-                                        validators,
+                                        context,
                                     )?;
                                     rel_create_fragments.push(fragment);
                                     Ok((rel_create_fragments, params))
@@ -111,13 +142,14 @@ where
                                         NodeQueryVar::new(None, "dst".to_string(), sg.suffix()),
                                     ),
                                     None,
+                                    None,
                                     v,
                                     ClauseType::SubQuery,
                                     &Info::new(p.type_name().to_owned(), info.type_defs()),
                                     partition_key_opt,
                                     sg,
                                     transaction,
-                                    validators,
+                                    context,
                                 )?;
                             rel_create_fragments.push(fragment);
                             Ok((rel_create_fragments, params))
@@ -130,14 +162,7 @@ where
             },
         )?;
 
-        transaction.node_create_query::<RequestCtx>(
-            rel_create_fragments,
-            params,
-            node_var,
-            props,
-            clause,
-            sg,
-        )
+        transaction.node_create_query(rel_create_fragments, params, node_var, props, clause, sg)
     } else {
         Err(Error::TypeNotExpected)
     }
@@ -153,7 +178,7 @@ pub(super) fn visit_node_delete_input<T, RequestCtx: RequestContext>(
     transaction: &mut T,
 ) -> Result<(String, HashMap<String, Value>), Error>
 where
-    T: Transaction,
+    T: Transaction + ?Sized,
 {
     trace!(
         "visit_node_delete_input called -- params: {:#?}, node_var: {:#?}, input: {:#?}, info.name: {}, partition_key_opt: {:#?}",
@@ -182,6 +207,7 @@ where
             params,
             &node_var,
             ClauseType::Parameter,
+            None,
         )?;
 
         visit_node_delete_mutation_input::<T, RequestCtx>(
@@ -217,7 +243,7 @@ fn visit_node_delete_mutation_input<T, RequestCtx>(
 ) -> Result<(String, HashMap<String, Value>), Error>
 where
     RequestCtx: RequestContext,
-    T: Transaction,
+    T: Transaction + ?Sized,
 {
     let itd = info.type_def()?;
 
@@ -301,10 +327,10 @@ fn visit_node_input<T, RequestCtx>(
     partition_key_opt: Option<&Value>,
     sg: &mut SuffixGenerator,
     transaction: &mut T,
-    validators: &Validators,
+    context: &GraphQLContext<RequestCtx>,
 ) -> Result<(String, HashMap<String, Value>), Error>
 where
-    T: Transaction,
+    T: Transaction + ?Sized,
     RequestCtx: RequestContext,
 {
     trace!(
@@ -334,7 +360,7 @@ where
                 partition_key_opt,
                 sg,
                 transaction,
-                validators,
+                context,
             ),
             "$EXISTING" => {
                 let (match_fragment, where_fragment, params) = visit_node_query_input(
@@ -354,6 +380,7 @@ where
                     params,
                     &node_var,
                     ClauseType::SubQuery,
+                    None,
                 )
             }
             _ => Err(Error::SchemaItemNotFound {
@@ -377,7 +404,7 @@ pub(super) fn visit_node_query_input<T>(
     transaction: &mut T,
 ) -> Result<(String, String, HashMap<String, Value>), Error>
 where
-    T: Transaction,
+    T: Transaction + ?Sized,
 {
     trace!("visit_node_query_input called -- params: {:#?}, node_var: {:#?}, input: {:#?}, clause: {:#?}, info.name: {}, partition_key_opt: {:#?}",
     params, node_var, input, clause, info.name(), partition_key_opt);
@@ -436,10 +463,10 @@ pub(super) fn visit_node_update_input<T, RequestCtx>(
     partition_key_opt: Option<&Value>,
     sg: &mut SuffixGenerator,
     transaction: &mut T,
-    validators: &Validators,
+    context: &GraphQLContext<RequestCtx>,
 ) -> Result<(String, HashMap<String, Value>), Error>
 where
-    T: Transaction,
+    T: Transaction + ?Sized,
     RequestCtx: RequestContext,
 {
     trace!(
@@ -450,6 +477,16 @@ where
     if let Value::Map(mut m) = input {
         let itd = info.type_def()?;
 
+        // Read the nodes to be updated, if a context-aware validator needs them
+        let label = node_var.label()?;
+        let existing = match info.type_def_by_name(label)?.validator() {
+            Some(_) => Some(
+                TransactionReader::new(transaction, info, partition_key_opt)
+                    .read_nodes(label, m.get("$MATCH").cloned())?,
+            ),
+            None => None,
+        };
+
         let (match_fragment, where_fragment, params) = visit_node_query_input(
             params,
             node_var,
@@ -470,12 +507,14 @@ where
             params,
             &node_var,
             ClauseType::Parameter,
+            None,
         )?;
 
         visit_node_update_mutation_input::<T, RequestCtx>(
             match_query,
             params,
             node_var,
+            existing.as_deref(),
             m.remove("$SET").ok_or_else(|| {
                 // remove() used here to take ownership of the "set" value, not borrow it
                 Error::InputItemNotFound {
@@ -490,7 +529,7 @@ where
             partition_key_opt,
             sg,
             transaction,
-            validators,
+            context,
         )
     } else {
         Err(Error::TypeNotExpected)
@@ -502,16 +541,17 @@ fn visit_node_update_mutation_input<T, RequestCtx>(
     match_query: String,
     params: HashMap<String, Value>,
     node_var: &NodeQueryVar,
+    existing_opt: Option<&[NodeRecord]>,
     input: Value,
     clause: ClauseType,
     info: &Info,
     partition_key_opt: Option<&Value>,
     sg: &mut SuffixGenerator,
     transaction: &mut T,
-    validators: &Validators,
+    context: &GraphQLContext<RequestCtx>,
 ) -> Result<(String, HashMap<String, Value>), Error>
 where
-    T: Transaction,
+    T: Transaction + ?Sized,
     RequestCtx: RequestContext,
 {
     trace!(
@@ -526,14 +566,29 @@ where
             let p = itd.property(k)?;
 
             match p.kind() {
-                PropertyKind::Scalar | PropertyKind::DynamicScalar => p
-                    .validator()
-                    .map_or(Ok(()), |v_name| validate_input(validators, &v_name, &input)),
+                PropertyKind::Scalar | PropertyKind::DynamicScalar => {
+                    p.validator().map_or(Ok(()), |v_name| {
+                        validate_input(context.validators(), v_name, k, &input)
+                    })
+                }
                 _ => Ok(()), // No validation action to take
             }
         })?;
     }
 
+    let label = node_var.label()?;
+    if let Some(v) = info.type_def_by_name(label)?.validator() {
+        validate_context(
+            context,
+            v,
+            Operation::Update,
+            (label, None),
+            &input,
+            existing_opt,
+            &mut TransactionReader::new(transaction, info, partition_key_opt),
+        )?;
+    }
+
     if let Value::Map(m) = input {
         let (props, inputs) = m.into_iter().try_fold(
             (HashMap::new(), HashMap::new()),
@@ -590,7 +645,7 @@ where
                                                     partition_key_opt,
                                                     sg,
                                                     transaction,
-                                                    validators,
+                                                    context,
                                                 )?;
 
                                             queries.push(query);
@@ -614,7 +669,7 @@ where
                                 partition_key_opt,
                                 sg,
                                 transaction,
-                                validators,
+                                context,
                             )?;
 
                             queries.push(query);
@@ -626,7 +681,7 @@ where
             },
         )?;
 
-        transaction.node_update_query::<RequestCtx>(
+        transaction.node_update_query(
             match_query,
             change_queries,
             params,
@@ -649,10 +704,10 @@ fn visit_rel_change_input<T, RequestCtx>(
     partition_key_opt: Option<&Value>,
     sg: &mut SuffixGenerator,
     transaction: &mut T,
-    validators: &Validators,
+    context: &GraphQLContext<RequestCtx>,
 ) -> Result<(String, HashMap<String, Value>), Error>
 where
-    T: Transaction,
+    T: Transaction + ?Sized,
     RequestCtx: RequestContext,
 {
     trace!(
@@ -669,6 +724,7 @@ where
                 params,
                 rel_var,
                 None,
+                None,
                 v,
                 ClauseType::SubQuery,
                 &Info::new(
@@ -678,7 +734,7 @@ where
                 partition_key_opt,
                 sg,
                 transaction,
-                validators,
+                context,
             )
         } else if let Some(v) = m.remove("$DELETE") {
             // Using remove to take ownership
@@ -710,7 +766,7 @@ where
                 partition_key_opt,
                 sg,
                 transaction,
-                validators,
+                context,
             )
         } else {
             Err(Error::InputItemNotFound {
@@ -734,10 +790,10 @@ pub(super) fn visit_rel_create_input<T, RequestCtx>(
     partition_key_opt: Option<&Value>,
     sg: &mut SuffixGenerator,
     transaction: &mut T,
-    validators: &Validators,
+    context: &GraphQLContext<RequestCtx>,
 ) -> Result<(String, HashMap<String, Value>), Error>
 where
-    T: Transaction,
+    T: Transaction + ?Sized,
     RequestCtx: RequestContext,
 {
     trace!(
@@ -748,6 +804,20 @@ where
     if let Value::Map(mut m) = input {
         let itd = info.type_def()?;
 
+        // Read the source nodes, if a context-aware validator of the relationship needs them
+        let src_label = src_var.label()?;
+        let rel_type_name = info
+            .type_def_by_name(src_label)?
+            .property(rel_name)?
+            .type_name();
+        let src_nodes = match info.type_def_by_name(rel_type_name)?.validator() {
+            Some(_) => Some(
+                TransactionReader::new(transaction, info, partition_key_opt)
+                    .read_nodes(src_label, m.get("$MATCH").cloned())?,
+            ),
+            None => None,
+        };
+
         let (match_fragment, where_fragment, params) = visit_node_query_input(
             params,
             src_var,
@@ -768,6 +838,7 @@ where
             params,
             src_var,
             ClauseType::Parameter,
+            None,
         )?;
 
         let create_input = m.remove("$CREATE").ok_or_else(|| {
@@ -789,6 +860,7 @@ where
                     params,
                     &rel_var,
                     props_type_name,
+                    src_nodes.as_deref(),
                     create_input,
                     ClauseType::SubQuery,
                     &Info::new(
@@ -798,10 +870,10 @@ where
                     partition_key_opt,
                     sg,
                     transaction,
-                    validators,
+                    context,
                 )?;
 
-                transaction.rel_create_query::<RequestCtx>(
+                transaction.rel_create_query(
                     Some(src_query),
                     vec![cf],
                     params,
@@ -829,6 +901,7 @@ where
                                 params,
                                 &rel_var,
                                 props_type_name,
+                                src_nodes.as_deref(),
                                 create_input_value,
                                 ClauseType::SubQuery,
                                 &Info::new(
@@ -838,7 +911,7 @@ where
                                 partition_key_opt,
                                 sg,
                                 transaction,
-                                validators,
+                                context,
                             )?;
 
                             rcfs.push(query);
@@ -847,7 +920,7 @@ where
                         },
                     )?;
 
-                transaction.rel_create_query::<RequestCtx>(
+                transaction.rel_create_query(
                     Some(src_query),
                     rcfs,
                     params,
@@ -867,22 +940,32 @@ fn visit_rel_create_mutation_input<T, RequestCtx>(
     params: HashMap<String, Value>,
     rel_var: &RelQueryVar,
     props_type_name: Option<&str>,
+    src_opt: Option<&[NodeRecord]>,
     input: Value,
     clause: ClauseType,
     info: &Info,
     partition_key_opt: Option<&Value>,
     sg: &mut SuffixGenerator,
     transaction: &mut T,
-    validators: &Validators,
+    context: &GraphQLContext<RequestCtx>,
 ) -> Result<(String, HashMap<String, Value>), Error>
 where
-    T: Transaction,
+    T: Transaction + ?Sized,
     RequestCtx: RequestContext,
 {
     trace!("visit_rel_create_mutation_input called -- params: {:#?}, rel_var: {:#?}, props_type_name: {:#?}, input: {:#?}, clause: {:#?}, info.name: {}, partition_key_opt: {:#?}",
             params, rel_var, props_type_name, input, clause, info.name(), partition_key_opt,
         );
 
+    validate_rel_context(
+        context,
+        Operation::Create,
+        rel_var,
+        &input,
+        src_opt,
+        &mut TransactionReader::new(transaction, info, partition_key_opt),
+    )?;
+
     if let Value::Map(mut m) = input {
         let dst_prop = info.type_def()?.property("dst")?;
         let dst = m
@@ -899,7 +982,7 @@ where
             partition_key_opt,
             sg,
             transaction,
-            validators,
+            context,
         )?;
 
         let props = match m.remove("props") {
@@ -908,8 +991,7 @@ where
             Some(_) => return Err(Error::TypeNotExpected),
         };
 
-        transaction
-            .rel_create_fragment::<RequestCtx>(&dst_query, params, &rel_var, props, clause, sg)
+        transaction.rel_create_fragment(&dst_query, params, &rel_var, props, clause, sg)
     } else {
         Err(Error::TypeNotExpected)
     }
@@ -928,7 +1010,7 @@ pub(super) fn visit_rel_delete_input<T, RequestCtx>(
 ) -> Result<(String, HashMap<String, Value>), Error>
 where
     RequestCtx: RequestContext,
-    T: Transaction,
+    T: Transaction + ?Sized,
 {
     trace!("visit_rel_delete_input called -- params: {:#?}, rel_var: {:#?}, input: {:#?}, clause: {:#?}, info.name: {}, partition_key_opt: {:#?}",
     params, rel_var, input, clause, info.name(), partition_key_opt);
@@ -960,6 +1042,7 @@ where
             } else {
                 ClauseType::SubQuery
             },
+            None,
         )?;
 
         let (src_delete_query_opt, params) = if let Some(src) = m.remove("src") {
@@ -1029,7 +1112,7 @@ fn visit_rel_dst_delete_mutation_input<T, RequestCtx>(
 ) -> Result<(String, HashMap<String, Value>), Error>
 where
     RequestCtx: RequestContext,
-    T: Transaction,
+    T: Transaction + ?Sized,
 {
     trace!(
         "visit_rel_dst_delete_mutation_input called -- match_query: {}, params: {:#?}, node_var: {:#?}, input: {:#?}, info.name: {}, partition_key_opt, {:#?}",
@@ -1073,7 +1156,7 @@ fn visit_rel_dst_query_input<T>(
     transaction: &mut T,
 ) -> Result<(Option<(String, String)>, HashMap<String, Value>), Error>
 where
-    T: Transaction,
+    T: Transaction + ?Sized,
 {
     trace!("visit_rel_dst_query_input called -- params: {:#?}, node_var: {:#?}, input: {:#?}, info.name: {}, partition_key_opt: {:#?}",
         params, node_var, input, info.name(), partition_key_opt);
@@ -1111,10 +1194,10 @@ fn visit_rel_dst_update_mutation_input<T, RequestCtx>(
     partition_key_opt: Option<&Value>,
     sg: &mut SuffixGenerator,
     transaction: &mut T,
-    validators: &Validators,
+    context: &GraphQLContext<RequestCtx>,
 ) -> Result<(String, HashMap<String, Value>), Error>
 where
-    T: Transaction,
+    T: Transaction + ?Sized,
     RequestCtx: RequestContext,
 {
     trace!("visit_rel_dst_update_mutation_input called -- match_query: {}, params: {:#?}, input: {:#?}, info.name: {}, partition_key_opt: {:#?}",
@@ -1134,13 +1217,14 @@ where
             match_query,
             params,
             &NodeQueryVar::new(Some(k), "dst".to_string(), sg.suffix()),
+            None,
             v,
             ClauseType::SubQuery,
             &Info::new(p.type_name().to_owned(), info.type_defs()),
             partition_key_opt,
             sg,
             transaction,
-            validators,
+            context,
         )
     } else {
         Err(Error::TypeNotExpected)
@@ -1157,10 +1241,10 @@ fn visit_rel_nodes_mutation_input_union<T, RequestCtx>(
     partition_key_opt: Option<&Value>,
     sg: &mut SuffixGenerator,
     transaction: &mut T,
-    validators: &Validators,
+    context: &GraphQLContext<RequestCtx>,
 ) -> Result<(String, HashMap<String, Value>), Error>
 where
-    T: Transaction,
+    T: Transaction + ?Sized,
     RequestCtx: RequestContext,
 {
     trace!("visit_rel_nodes_mutation_input_union called -- params: {:#?}, node_var: {:#?}, input: {:#?}, clause: {:#?}, info.name: {}, partition_key_opt: {:#?}",
@@ -1189,7 +1273,7 @@ where
             partition_key_opt,
             sg,
             transaction,
-            validators,
+            context,
         )
     } else {
         Err(Error::TypeNotExpected)
@@ -1208,7 +1292,7 @@ pub(super) fn visit_rel_query_input<T>(
     transaction: &mut T,
 ) -> Result<(String, String, HashMap<String, Value>), Error>
 where
-    T: Transaction,
+    T: Transaction + ?Sized,
 {
     trace!("visit_rel_query_input called -- params: {:#?}, rel_var: {:#?}, input_opt: {:#?}, clause: {:#?}, info.name(): {}, partition_key_opt: {:#?}",
         params, rel_var, input_opt, clause, info.name(), partition_key_opt);
@@ -1278,7 +1362,7 @@ fn visit_rel_src_delete_mutation_input<T, RequestCtx>(
 ) -> Result<(String, HashMap<String, Value>), Error>
 where
     RequestCtx: RequestContext,
-    T: Transaction,
+    T: Transaction + ?Sized,
 {
     trace!(
         "visit_rel_src_delete_mutation_input called -- match_query: {}, params: {:#?}, node_var: {:#?}, input: {:#?}, info.name: {}, partition_key_opt: {:#?}",
@@ -1320,10 +1404,10 @@ fn visit_rel_src_update_mutation_input<T, RequestCtx>(
     partition_key_opt: Option<&Value>,
     sg: &mut SuffixGenerator,
     transaction: &mut T,
-    validators: &Validators,
+    context: &GraphQLContext<RequestCtx>,
 ) -> Result<(String, HashMap<String, Value>), Error>
 where
-    T: Transaction,
+    T: Transaction + ?Sized,
     RequestCtx: RequestContext,
 {
     trace!(
@@ -1344,13 +1428,14 @@ where
             match_query,
             params,
             node_var,
+            None,
             v,
             ClauseType::SubQuery,
             &Info::new(p.type_name().to_owned(), info.type_defs()),
             partition_key_opt,
             sg,
             transaction,
-            validators,
+            context,
         )
     } else {
         Err(Error::TypeNotExpected)
@@ -1369,7 +1454,7 @@ fn visit_rel_src_query_input<T>(
     transaction: &mut T,
 ) -> Result<(Option<(String, String)>, HashMap<String, Value>), Error>
 where
-    T: Transaction,
+    T: Transaction + ?Sized,
 {
     trace!(
         "visit_rel_src_query_input called -- params: {:#?}, node_var: {:#?}, input: {:#?}, info.name: {}, partition_key_opt: {:#?}",
@@ -1410,10 +1495,10 @@ pub(super) fn visit_rel_update_input<T, RequestCtx>(
     partition_key_opt: Option<&Value>,
     sg: &mut SuffixGenerator,
     transaction: &mut T,
-    validators: &Validators,
+    context: &GraphQLContext<RequestCtx>,
 ) -> Result<(String, HashMap<String, Value>), Error>
 where
-    T: Transaction,
+    T: Transaction + ?Sized,
     RequestCtx: RequestContext,
 {
     trace!(
@@ -1447,6 +1532,7 @@ where
             } else {
                 ClauseType::SubQuery
             },
+            None,
         )?;
 
         trace!(
@@ -1471,7 +1557,7 @@ where
                 partition_key_opt,
                 sg,
                 transaction,
-                validators,
+                context,
             )
         } else {
             Err(Error::InputItemNotFound {
@@ -1495,16 +1581,25 @@ fn visit_rel_update_mutation_input<T, RequestCtx>(
     partition_key_opt: Option<&Value>,
     sg: &mut SuffixGenerator,
     transaction: &mut T,
-    validators: &Validators,
+    context: &GraphQLContext<RequestCtx>,
 ) -> Result<(String, HashMap<String, Value>), Error>
 where
-    T: Transaction,
+    T: Transaction + ?Sized,
     RequestCtx: RequestContext,
 {
     trace!(
          "visit_rel_update_mutation_input called -- match_query: {}, params: {:#?}, rel_var: {:#?}, props_type_name: {:#?}, input: {:#?}, clause: {:#?}, info.name: {}, partition_key_opt: {:#?}",
          match_query, params, rel_var, props_type_name, input, clause, info.name(), partition_key_opt);
 
+    validate_rel_context(
+        context,
+        Operation::Update,
+        rel_var,
+        &input,
+        None,
+        &mut TransactionReader::new(transaction, info, partition_key_opt),
+    )?;
+
     if let Value::Map(mut m) = input {
         let itd = info.type_def()?;
 
@@ -1514,7 +1609,7 @@ where
             HashMap::new()
         };
 
-        let results = transaction.rel_update_query::<RequestCtx>(
+        let results = transaction.rel_update_query(
             match_query.clone(),
             params.clone(),
             rel_var,
@@ -1537,7 +1632,7 @@ where
                 partition_key_opt,
                 sg,
                 transaction,
-                validators,
+                context,
             )?;
         }
 
@@ -1554,7 +1649,7 @@ where
                 partition_key_opt,
                 sg,
                 transaction,
-                validators,
+                context,
             )?;
         }
 
@@ -1564,16 +1659,485 @@ where
     }
 }
 
-fn validate_input(validators: &Validators, v: &str, input: &Value) -> Result<(), Error> {
+/// Calls the input validator named `v` for the property `field`, recording the name of the
+/// property in the error if the validator rejects the input
+fn validate_input(
+    validators: &Validators,
+    v: &str,
+    field: &str,
+    input: &Value,
+) -> Result<(), Error> {
     let func = validators.get(v).ok_or_else(|| Error::ValidatorNotFound {
         name: v.to_string(),
     })?;
 
     trace!(
-        "validate_input Calling input validator function {} for input value {:#?}",
+        "validate_input Calling input validator function {} for field {} of input value {:#?}",
         v,
+        field,
         input
     );
 
-    func(input)
+    func(input).map_err(|e| Error::FieldValidationFailed {
+        field: field.to_string(),
+        source: Box::new(e),
+    })
+}
+
+/// Calls the context-aware validator named `v` for the type, and optional relationship, in
+/// `names`. If the nodes already stored in the database are known, the validator is called once
+/// for each of them.
+fn validate_context<RequestCtx>(
+    context: &GraphQLContext<RequestCtx>,
+    v: &str,
+    operation: Operation,
+    names: (&str, Option<&str>),
+    input: &Value,
+    existing_opt: Option<&[NodeRecord]>,
+    reader: &mut dyn ValidationReader,
+) -> Result<(), Error>
+where
+    RequestCtx: RequestContext,
+{
+    let func = context
+        .context_validators()
+        .get(v)
+        .ok_or_else(|| Error::ValidatorNotFound {
+            name: v.to_string(),
+        })?;
+
+    trace!(
+        "validate_context Calling context validator function {} for {:?} of {:?} with input value {:#?}",
+        v,
+        operation,
+        names,
+        input
+    );
+
+    let (type_name, rel_name) = names;
+    match existing_opt {
+        Some(existing) => existing.iter().try_for_each(|node| {
+            func(&mut ValidationContext::new(
+                operation,
+                type_name,
+                rel_name,
+                input,
+                Some(node),
+                context.request_context(),
+                reader,
+            ))
+        }),
+        None => func(&mut ValidationContext::new(
+            operation,
+            type_name,
+            rel_name,
+            input,
+            None,
+            context.request_context(),
+            reader,
+        )),
+    }
+}
+
+/// Calls the context-aware validator of the relationship type of `rel_var`, if it has one
+fn validate_rel_context<T, RequestCtx>(
+    context: &GraphQLContext<RequestCtx>,
+    operation: Operation,
+    rel_var: &RelQueryVar,
+    input: &Value,
+    src_opt: Option<&[NodeRecord]>,
+    reader: &mut TransactionReader<T>,
+) -> Result<(), Error>
+where
+    T: Transaction + ?Sized,
+    RequestCtx: RequestContext,
+{
+    let src_label = rel_var.src().label()?;
+    let rel_type_name = reader
+        .info
+        .type_def_by_name(src_label)?
+        .property(rel_var.label())?
+        .type_name();
+
+    match reader.info.type_def_by_name(rel_type_name)?.validator() {
+        Some(v) => validate_context(
+            context,
+            v,
+            operation,
+            (src_label, Some(rel_var.label())),
+            input,
+            src_opt,
+            reader,
+        ),
+        None => Ok(()),
+    }
+}
+
+/// Reads nodes and relationships for context-aware validators, within the transaction of the
+/// mutation being validated
+struct TransactionReader<'a, T>
+where
+    T: Transaction + ?Sized,
+{
+    transaction: &'a mut T,
+    info: &'a Info,
+    partition_key_opt: Option<&'a Value>,
+}
+
+impl<'a, T> TransactionReader<'a, T>
+where
+    T: Transaction + ?Sized,
+{
+    fn new(
+        transaction: &'a mut T,
+        info: &'a Info,
+        partition_key_opt: Option<&'a Value>,
+    ) -> TransactionReader<'a, T> {
+        TransactionReader {
+            transaction,
+            info,
+            partition_key_opt,
+        }
+    }
+}
+
+impl<'a, T> ValidationReader for TransactionReader<'a, T>
+where
+    T: Transaction + ?Sized,
+{
+    fn read_nodes(
+        &mut self,
+        type_name: &str,
+        input: Option<Value>,
+    ) -> Result<Vec<NodeRecord>, Error> {
+        let mut sg = SuffixGenerator::new();
+        let info = Info::new("Query".to_string(), self.info.type_defs());
+        let itd = info
+            .type_def()?
+            .property(type_name)?
+            .input_type_definition(&info)?;
+        let node_var =
+            NodeQueryVar::new(Some(type_name.to_string()), "node".to_string(), sg.suffix());
+
+        let (match_fragment, where_fragment, params) = visit_node_query_input(
+            HashMap::new(),
+            &node_var,
+            input,
+            ClauseType::Query,
+            &Info::new(itd.type_name().to_owned(), info.type_defs()),
+            self.partition_key_opt,
+            &mut sg,
+            self.transaction,
+        )?;
+        let (query, params) = self.transaction.node_read_query(
+            &match_fragment,
+            &where_fragment,
+            params,
+            &node_var,
+            ClauseType::Query,
+            None,
+        )?;
+        self.transaction
+            .read_nodes(query, Some(params), self.partition_key_opt, &info)
+    }
+
+    fn read_rels(
+        &mut self,
+        type_name: &str,
+        rel_name: &str,
+        input: Option<Value>,
+    ) -> Result<Vec<RelRecord>, Error> {
+        let mut sg = SuffixGenerator::new();
+        let info = Info::new("Query".to_string(), self.info.type_defs());
+        let rel_type_name = info
+            .type_def_by_name(type_name)?
+            .property(rel_name)?
+            .type_name();
+        let query_name = rel_type_name.strip_suffix("Rel").unwrap_or(rel_type_name);
+        let itd = info
+            .type_def()?
+            .property(query_name)?
+            .input_type_definition(&info)?;
+
+        let dst_suffix = sg.suffix();
+        let rel_suffix = sg.suffix();
+        let src_var =
+            NodeQueryVar::new(Some(type_name.to_string()), "src".to_string(), sg.suffix());
+        let dst_var = NodeQueryVar::new(None, "dst".to_string(), dst_suffix);
+        let rel_var = RelQueryVar::new(rel_name.to_string(), rel_suffix, src_var, dst_var);
+
+        let (match_fragment, where_fragment, params) = visit_rel_query_input(
+            HashMap::new(),
+            &rel_var,
+            input,
+            ClauseType::Query,
+            &Info::new(itd.type_name().to_owned(), info.type_defs()),
+            self.partition_key_opt,
+            &mut sg,
+            self.transaction,
+        )?;
+        let (query, params) = self.transaction.rel_read_query(
+            &match_fragment,
+            &where_fragment,
+            params,
+            &rel_var,
+            ClauseType::Query,
+            None,
+        )?;
+        self.transaction.read_rels(
+            query,
+            Some(params),
+            Some(rel_type_name),
+            self.partition_key_opt,
+        )
+    }
+}
+
+/// Writes nodes for trigger functions, within the transaction of the mutation running the
+/// trigger. Writes made here do not run further triggers or extension hooks.
+struct TransactionWriter<'a, T, RequestCtx>
+where
+    T: Transaction + ?Sized,
+    RequestCtx: RequestContext,
+{
+    reader: TransactionReader<'a, T>,
+    context: &'a GraphQLContext<RequestCtx>,
+}
+
+impl<'a, T, RequestCtx> TransactionWriter<'a, T, RequestCtx>
+where
+    T: Transaction + ?Sized,
+    RequestCtx: RequestContext,
+{
+    fn new(
+        transaction: &'a mut T,
+        info: &'a Info,
+        partition_key_opt: Option<&'a Value>,
+        context: &'a GraphQLContext<RequestCtx>,
+    ) -> TransactionWriter<'a, T, RequestCtx> {
+        TransactionWriter {
+            reader: TransactionReader::new(transaction, info, partition_key_opt),
+            context,
+        }
+    }
+
+    /// Returns the mutation root, along with the input type definition of the generated mutation
+    /// with the given name
+    fn mutation_info(&self, field_name: &str) -> Result<(Info, Info), Error> {
+        let info = Info::new("Mutation".to_string(), self.reader.info.type_defs());
+        let itd = info
+            .type_def()?
+            .property(field_name)?
+            .input_type_definition(&info)?;
+        let itd_info = Info::new(itd.type_name().to_owned(), info.type_defs());
+        Ok((info, itd_info))
+    }
+}
+
+impl<'a, T, RequestCtx> ValidationReader for TransactionWriter<'a, T, RequestCtx>
+where
+    T: Transaction + ?Sized,
+    RequestCtx: RequestContext,
+{
+    fn read_nodes(
+        &mut self,
+        type_name: &str,
+        input: Option<Value>,
+    ) -> Result<Vec<NodeRecord>, Error> {
+        self.reader.read_nodes(type_name, input)
+    }
+
+    fn read_rels(
+        &mut self,
+        type_name: &str,
+        rel_name: &str,
+        input: Option<Value>,
+    ) -> Result<Vec<RelRecord>, Error> {
+        self.reader.read_rels(type_name, rel_name, input)
+    }
+}
+
+impl<'a, T, RequestCtx> TriggerWriter for TransactionWriter<'a, T, RequestCtx>
+where
+    T: Transaction + ?Sized,
+    RequestCtx: RequestContext,
+{
+    fn create_node(&mut self, type_name: &str, input: Value) -> Result<NodeRecord, Error> {
+        let mut sg = SuffixGenerator::new();
+        let (info, itd_info) = self.mutation_info(&(type_name.to_string() + "Create"))?;
+        let node_var =
+            NodeQueryVar::new(Some(type_name.to_string()), "node".to_string(), sg.suffix());
+
+        let (query, params) = visit_node_create_mutation_input(
+            HashMap::new(),
+            &node_var,
+            input,
+            ClauseType::Query,
+            &itd_info,
+            self.reader.partition_key_opt,
+            &mut sg,
+            self.reader.transaction,
+            self.context,
+        )?;
+        self.reader
+            .transaction
+            .create_node(query, params, self.reader.partition_key_opt, &info)
+    }
+
+    fn update_nodes(&mut self, type_name: &str, input: Value) -> Result<Vec<NodeRecord>, Error> {
+        let mut sg = SuffixGenerator::new();
+        let (info, itd_info) = self.mutation_info(&(type_name.to_string() + "Update"))?;
+        let node_var =
+            NodeQueryVar::new(Some(type_name.to_string()), "node".to_string(), sg.suffix());
+
+        let (query, params) = visit_node_update_input(
+            HashMap::new(),
+            &node_var,
+            input,
+            &itd_info,
+            self.reader.partition_key_opt,
+            &mut sg,
+            self.reader.transaction,
+            self.context,
+        )?;
+        self.reader
+            .transaction
+            .update_nodes(query, params, self.reader.partition_key_opt, &info)
+    }
+
+    fn delete_nodes(&mut self, type_name: &str, input: Value) -> Result<i32, Error> {
+        let mut sg = SuffixGenerator::new();
+        let (_info, itd_info) = self.mutation_info(&(type_name.to_string() + "Delete"))?;
+        let node_var =
+            NodeQueryVar::new(Some(type_name.to_string()), "node".to_string(), sg.suffix());
+
+        let (query, params) = visit_node_delete_input::<T, RequestCtx>(
+            HashMap::new(),
+            &node_var,
+            input,
+            &itd_info,
+            self.reader.partition_key_opt,
+            &mut sg,
+            self.reader.transaction,
+        )?;
+        self.reader
+            .transaction
+            .delete_nodes(query, params, self.reader.partition_key_opt)
+    }
+}
+
+/// Returns the type definition that holds the triggers of the type, or of the relationship type
+/// if there is a relationship name in `names`
+fn trigger_type_def<'i>(
+    info: &'i Info,
+    names: (&str, Option<&str>),
+) -> Result<&'i NodeType, Error> {
+    match names {
+        (type_name, Some(rel_name)) => info.type_def_by_name(
+            info.type_def_by_name(type_name)?
+                .property(rel_name)?
+                .type_name(),
+        ),
+        (type_name, None) => info.type_def_by_name(type_name),
+    }
+}
+
+/// Returns true if the type, or relationship, in `names` has a trigger for any of `events`
+pub(super) fn has_triggers(
+    info: &Info,
+    names: (&str, Option<&str>),
+    events: &[TriggerEvent],
+) -> Result<bool, Error> {
+    let td = trigger_type_def(info, names)?;
+    Ok(events.iter().any(|e| td.triggers(*e).next().is_some()))
+}
+
+/// Reads the nodes matched by the `$MATCH` of a node update or delete input, so that triggers
+/// can be passed the state of the nodes before they are changed
+pub(super) fn read_matched_nodes<T>(
+    type_name: &str,
+    input: &Value,
+    info: &Info,
+    partition_key_opt: Option<&Value>,
+    transaction: &mut T,
+) -> Result<Vec<NodeRecord>, Error>
+where
+    T: Transaction + ?Sized,
+{
+    match input {
+        Value::Map(m) => TransactionReader::new(transaction, info, partition_key_opt)
+            .read_nodes(type_name, m.get("$MATCH").cloned()),
+        _ => Err(Error::TypeNotExpected),
+    }
+}
+
+/// Reads the relationships matched by the `$MATCH` of a relationship update or delete input, so
+/// that triggers can be passed the state of the relationships before they are changed
+pub(super) fn read_matched_rels<T>(
+    names: (&str, &str),
+    input: &Value,
+    info: &Info,
+    partition_key_opt: Option<&Value>,
+    transaction: &mut T,
+) -> Result<Vec<RelRecord>, Error>
+where
+    T: Transaction + ?Sized,
+{
+    match input {
+        Value::Map(m) => TransactionReader::new(transaction, info, partition_key_opt).read_rels(
+            names.0,
+            names.1,
+            m.get("$MATCH").cloned(),
+        ),
+        _ => Err(Error::TypeNotExpected),
+    }
+}
+
+/// Runs the trigger functions configured for `event` on the type, or relationship, in `names`,
+/// passing them the `input` of the mutation, and the old and new state in `records`
+#[allow(clippy::too_many_arguments)]
+pub(super) fn run_triggers<T, RequestCtx>(
+    context: &GraphQLContext<RequestCtx>,
+    event: TriggerEvent,
+    names: (&str, Option<&str>),
+    input: Option<&Value>,
+    records: (Option<TriggerRecord>, Option<TriggerRecord>),
+    info: &Info,
+    partition_key_opt: Option<&Value>,
+    transaction: &mut T,
+) -> Result<(), Error>
+where
+    T: Transaction + ?Sized,
+    RequestCtx: RequestContext,
+{
+    let (type_name, rel_name) = names;
+    let (old, new) = records;
+    trigger_type_def(info, names)?
+        .triggers(event)
+        .try_for_each(|name| {
+            let func = context
+                .triggers()
+                .get(name)
+                .ok_or_else(|| Error::TriggerNotFound {
+                    name: name.to_string(),
+                })?;
+
+            trace!(
+                "run_triggers Calling trigger function {} for {:?} of {:?}",
+                name,
+                event,
+                names
+            );
+
+            func(&mut TriggerContext::new(
+                event,
+                type_name,
+                rel_name,
+                input,
+                old,
+                new,
+                context.request_context(),
+                &mut TransactionWriter::new(transaction, info, partition_key_opt, context),
+            ))
+        })
 }
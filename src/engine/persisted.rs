@@ -0,0 +1,488 @@
+//! This module provides persisted queries, which are query documents registered with the engine
+//! ahead of time, and automatic persisted queries (APQ), which clients register by the sha256
+//! hash of the query document.
+
+use crate::Error;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::{BTreeMap, HashMap};
+use std::fs::{read_dir, read_to_string};
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+
+/// A store of persisted queries. A query may be registered under a name, given to the engine
+/// ahead of time, or registered by a client at runtime with automatic persisted queries (APQ).
+/// Either way, a client may then send the name or the sha256 hash of the query in place of the
+/// query document.
+///
+/// In persisted only mode, the engine runs only the queries registered ahead of time, and
+/// rejects any other query.
+///
+/// Queries registered by clients are kept in memory, up to the capacity of the store, which is
+/// 1,000 queries by default. Once the store is full, the least recently used query is evicted to
+/// make room for a new one.
+///
+/// # Examples
+///
+/// ```rust
+/// # use warpgrapher::engine::persisted::PersistedQueries;
+///
+/// let pq = PersistedQueries::new()
+///     .with_query("Projects", "query Projects { Project { id name } }")
+///     .with_persisted_only(true);
+/// ```
+#[derive(Clone, Debug)]
+pub struct PersistedQueries {
+    names: HashMap<String, String>,
+    registered: HashMap<String, String>,
+    cache: Arc<Mutex<Registrations>>,
+    capacity: usize,
+    apq: bool,
+    persisted_only: bool,
+}
+
+impl PersistedQueries {
+    /// Returns a new, empty [`PersistedQueries`] store, with automatic persisted queries enabled
+    ///
+    /// [`PersistedQueries`]: ./struct.PersistedQueries.html
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use warpgrapher::engine::persisted::PersistedQueries;
+    ///
+    /// let pq = PersistedQueries::new();
+    /// ```
+    pub fn new() -> PersistedQueries {
+        PersistedQueries::default()
+    }
+
+    /// Returns a new [`PersistedQueries`] store holding the query documents in the directory
+    /// `path`. Each file with a `.graphql` or `.gql` extension is registered under the name of the
+    /// file, without the extension.
+    ///
+    /// [`PersistedQueries`]: ./struct.PersistedQueries.html
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`] variant [`PersistedQueriesOpenFailed`] if the directory or one of the
+    /// query files cannot be read.
+    ///
+    /// [`Error`]: ../../error/enum.Error.html
+    /// [`PersistedQueriesOpenFailed`]: ../../error/enum.Error.html#variant.PersistedQueriesOpenFailed
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use warpgrapher::engine::persisted::PersistedQueries;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let pq = PersistedQueries::from_dir("./queries")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_dir<P: AsRef<Path>>(path: P) -> Result<PersistedQueries, Error> {
+        let mut pq = PersistedQueries::new();
+        let mut paths = read_dir(path)
+            .map_err(|e| Error::PersistedQueriesOpenFailed { source: e })?
+            .map(|entry| entry.map(|e| e.path()))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| Error::PersistedQueriesOpenFailed { source: e })?;
+        paths.sort();
+
+        for p in paths {
+            let is_query = matches!(
+                p.extension().and_then(|ext| ext.to_str()),
+                Some("graphql") | Some("gql")
+            );
+            if let (true, Some(name)) = (is_query, p.file_stem().and_then(|s| s.to_str())) {
+                let query = read_to_string(&p)
+                    .map_err(|e| Error::PersistedQueriesOpenFailed { source: e })?;
+                pq = pq.with_query(name, &query);
+            }
+        }
+
+        Ok(pq)
+    }
+
+    /// Registers the query document `query` under the name `name`
+    pub fn with_query(mut self, name: &str, query: &str) -> PersistedQueries {
+        let hash = sha256_hash(query);
+        self.names.insert(name.to_string(), hash.clone());
+        self.registered.insert(hash, query.to_string());
+        self
+    }
+
+    /// Sets whether clients may register queries at runtime with automatic persisted queries.
+    /// Defaults to true.
+    pub fn with_apq(mut self, apq: bool) -> PersistedQueries {
+        self.apq = apq;
+        self
+    }
+
+    /// Sets the greatest number of queries registered by clients that are kept
+    pub fn with_capacity(mut self, capacity: usize) -> PersistedQueries {
+        self.capacity = capacity;
+        self
+    }
+
+    /// Sets whether the engine rejects any query that is not registered ahead of time. Defaults
+    /// to false.
+    pub fn with_persisted_only(mut self, persisted_only: bool) -> PersistedQueries {
+        self.persisted_only = persisted_only;
+        self
+    }
+
+    /// Returns the query document registered under the name or sha256 hash `id`, if any
+    pub fn query(&self, id: &str) -> Option<String> {
+        let hash = self.names.get(id).map(|h| h.as_str()).unwrap_or(id);
+        self.registered
+            .get(hash)
+            .cloned()
+            .or_else(|| self.cache.lock().ok().and_then(|mut cache| cache.get(hash)))
+    }
+
+    /// Checks that a query document may be run, returning an [`Error`] variant
+    /// [`QueryNotPersisted`] if the store is in persisted only mode, and the query is not
+    /// registered ahead of time
+    ///
+    /// [`Error`]: ../../error/enum.Error.html
+    /// [`QueryNotPersisted`]: ../../error/enum.Error.html#variant.QueryNotPersisted
+    pub(crate) fn check(&self, query: &str) -> Result<(), Error> {
+        if self.persisted_only && !self.registered.contains_key(&sha256_hash(query)) {
+            Err(Error::QueryNotPersisted)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Returns the query document to run for a request, looking up the persisted query named by
+    /// the request, or registering the query sent by the client with its hash
+    pub(crate) fn resolve(&self, req: &PersistedRequest) -> Result<String, Error> {
+        if let Some(id) = &req.id {
+            return self.query(id).ok_or(Error::PersistedQueryNotFound);
+        }
+
+        let hash = match req.sha256_hash() {
+            Some(hash) => hash,
+            None => {
+                let query = req.query.clone().ok_or_else(|| Error::InputItemNotFound {
+                    name: "query".to_string(),
+                })?;
+                self.check(&query)?;
+                return Ok(query);
+            }
+        };
+
+        if let Some(query) = self.registered.get(&hash) {
+            return Ok(query.clone());
+        }
+        if !self.apq {
+            return Err(Error::PersistedQueryNotSupported);
+        }
+
+        match &req.query {
+            Some(query) => {
+                if sha256_hash(query) != hash {
+                    return Err(Error::PersistedQueryHashMismatched { hash });
+                }
+                self.check(query)?;
+                if let Ok(mut cache) = self.cache.lock() {
+                    cache.insert(hash, query.clone(), self.capacity);
+                }
+                Ok(query.clone())
+            }
+            None => self.query(&hash).ok_or(Error::PersistedQueryNotFound),
+        }
+    }
+}
+
+impl Default for PersistedQueries {
+    fn default() -> Self {
+        PersistedQueries {
+            names: HashMap::new(),
+            registered: HashMap::new(),
+            cache: Arc::new(Mutex::new(Registrations::default())),
+            capacity: 1000,
+            apq: true,
+            persisted_only: false,
+        }
+    }
+}
+
+/// The queries registered by clients with automatic persisted queries, with the order in which
+/// they were last used
+#[derive(Debug, Default)]
+struct Registrations {
+    queries: HashMap<String, (String, u64)>,
+    order: BTreeMap<u64, String>,
+    tick: u64,
+}
+
+impl Registrations {
+    /// Returns the query registered under the hash `hash`, marking it as the most recently used
+    fn get(&mut self, hash: &str) -> Option<String> {
+        self.tick += 1;
+        let tick = self.tick;
+        let (query, last_used) = self.queries.get_mut(hash)?;
+        self.order.remove(last_used);
+        self.order.insert(tick, hash.to_string());
+        *last_used = tick;
+        Some(query.clone())
+    }
+
+    /// Registers `query` under the hash `hash`, evicting the least recently used queries to keep
+    /// no more than `capacity` queries
+    fn insert(&mut self, hash: String, query: String, capacity: usize) {
+        if capacity == 0 {
+            return;
+        }
+
+        if let Some((_, last_used)) = self.queries.remove(&hash) {
+            self.order.remove(&last_used);
+        }
+        while self.queries.len() >= capacity {
+            let oldest = match self.order.values().next() {
+                Some(oldest) => oldest.clone(),
+                None => break,
+            };
+            if let Some((_, last_used)) = self.queries.remove(&oldest) {
+                self.order.remove(&last_used);
+            }
+        }
+
+        self.tick += 1;
+        self.order.insert(self.tick, hash.clone());
+        self.queries.insert(hash, (query, self.tick));
+    }
+}
+
+/// A GraphQL request that may refer to a persisted query in place of a query document. The
+/// persisted query is named either by the `id` of the request, or by the sha256 hash in the
+/// `persistedQuery` extension of the request, as sent by clients that use automatic persisted
+/// queries.
+///
+/// # Examples
+///
+/// ```rust
+/// # use serde_json::json;
+/// # use warpgrapher::engine::persisted::PersistedRequest;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let req: PersistedRequest = serde_json::from_value(json!({
+///     "extensions": {
+///         "persistedQuery": {
+///             "version": 1,
+///             "sha256Hash": "7f56e67dd21ab3f30d1ff8b7bed08893f0a0db86449836189b361dd1e56ddb4b"
+///         }
+///     }
+/// }))?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct PersistedRequest {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    query: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    id: Option<String>,
+    #[serde(
+        default,
+        rename = "operationName",
+        skip_serializing_if = "Option::is_none"
+    )]
+    operation_name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    variables: Option<serde_json::Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    extensions: Option<serde_json::Value>,
+}
+
+impl PersistedRequest {
+    /// Returns a new request for the query document `query`
+    pub fn new(query: &str) -> PersistedRequest {
+        PersistedRequest {
+            query: Some(query.to_string()),
+            ..PersistedRequest::default()
+        }
+    }
+
+    /// Returns a new request for the persisted query registered under the name or hash `id`
+    pub fn with_id(id: &str) -> PersistedRequest {
+        PersistedRequest {
+            id: Some(id.to_string()),
+            ..PersistedRequest::default()
+        }
+    }
+
+    /// Sets the name of the operation to run
+    pub fn with_operation_name(mut self, operation_name: &str) -> PersistedRequest {
+        self.operation_name = Some(operation_name.to_string());
+        self
+    }
+
+    /// Sets the variables of the request
+    pub fn with_variables(mut self, variables: serde_json::Value) -> PersistedRequest {
+        self.variables = Some(variables);
+        self
+    }
+
+    /// Returns the name of the operation to run
+    pub fn operation_name(&self) -> Option<&str> {
+        self.operation_name.as_deref()
+    }
+
+    /// Returns the variables of the request
+    pub fn variables(&self) -> Option<&serde_json::Value> {
+        self.variables.as_ref()
+    }
+
+    /// Returns the sha256 hash of the query in the `persistedQuery` extension of the request
+    fn sha256_hash(&self) -> Option<String> {
+        self.extensions
+            .as_ref()
+            .and_then(|e| e.get("persistedQuery"))
+            .and_then(|pq| pq.get("sha256Hash"))
+            .and_then(|h| h.as_str())
+            .map(|h| h.to_lowercase())
+    }
+}
+
+/// Returns the sha256 hash of a query document, as a lowercase hex string
+pub(crate) fn sha256_hash(query: &str) -> String {
+    Sha256::digest(query.as_bytes())
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{sha256_hash, PersistedQueries, PersistedRequest};
+    use crate::Error;
+    use serde_json::json;
+
+    fn apq_request(query: Option<&str>, hash: &str) -> PersistedRequest {
+        serde_json::from_value(json!({
+            "query": query,
+            "extensions": {"persistedQuery": {"version": 1, "sha256Hash": hash}}
+        }))
+        .unwrap()
+    }
+
+    /// Passes if the sha256 hash of a query is a lowercase hex string
+    #[test]
+    fn hash() {
+        assert_eq!(
+            sha256_hash("{ __typename }"),
+            "7f56e67dd21ab3f30d1ff8b7bed08893f0a0db86449836189b361dd1e56ddb4b"
+        );
+    }
+
+    /// Passes if a registered query is found by name or by hash, and a query sent by a client is
+    /// registered by its hash, once the hash is checked
+    #[test]
+    fn resolve() {
+        let projects = "query { Project { id } }";
+        let pq = PersistedQueries::new().with_query("Projects", projects);
+
+        let by_name = PersistedRequest::with_id("Projects");
+        assert_eq!(pq.resolve(&by_name).unwrap(), projects);
+        let by_hash = PersistedRequest::with_id(&sha256_hash(projects));
+        assert_eq!(pq.resolve(&by_hash).unwrap(), projects);
+        assert!(matches!(
+            pq.resolve(&PersistedRequest::with_id("Missing")),
+            Err(Error::PersistedQueryNotFound)
+        ));
+
+        let users = "query { User { id } }";
+        let hash = sha256_hash(users);
+        assert!(matches!(
+            pq.resolve(&apq_request(None, &hash)),
+            Err(Error::PersistedQueryNotFound)
+        ));
+        assert!(matches!(
+            pq.resolve(&apq_request(
+                Some(users),
+                &sha256_hash(projects).replace('a', "b")
+            )),
+            Err(Error::PersistedQueryHashMismatched { .. })
+        ));
+        assert_eq!(pq.resolve(&apq_request(Some(users), &hash)).unwrap(), users);
+        assert_eq!(
+            pq.clone().resolve(&apq_request(None, &hash)).unwrap(),
+            users
+        );
+
+        assert_eq!(pq.resolve(&PersistedRequest::new(users)).unwrap(), users);
+        assert!(matches!(
+            pq.resolve(&PersistedRequest::default()),
+            Err(Error::InputItemNotFound { .. })
+        ));
+    }
+
+    /// Passes if queries sent by clients are rejected when automatic persisted queries are
+    /// disabled, or in persisted only mode
+    #[test]
+    fn resolve_locked_down() {
+        let projects = "query { Project { id } }";
+        let users = "query { User { id } }";
+
+        let pq = PersistedQueries::new()
+            .with_query("Projects", projects)
+            .with_apq(false);
+        assert!(matches!(
+            pq.resolve(&apq_request(Some(users), &sha256_hash(users))),
+            Err(Error::PersistedQueryNotSupported)
+        ));
+        assert_eq!(
+            pq.resolve(&apq_request(None, &sha256_hash(projects)))
+                .unwrap(),
+            projects
+        );
+
+        let pq = PersistedQueries::new()
+            .with_query("Projects", projects)
+            .with_persisted_only(true);
+        assert!(matches!(
+            pq.resolve(&apq_request(Some(users), &sha256_hash(users))),
+            Err(Error::QueryNotPersisted)
+        ));
+        assert!(matches!(
+            pq.resolve(&PersistedRequest::new(users)),
+            Err(Error::QueryNotPersisted)
+        ));
+        assert!(pq.check(projects).is_ok());
+    }
+
+    /// Passes if the least recently used query sent by a client is evicted once the store is
+    /// full, and a store with no capacity keeps no queries
+    #[test]
+    fn resolve_evicts_least_recently_used() {
+        let projects = "query { Project { id } }";
+        let users = "query { User { id } }";
+        let issues = "query { Issue { id } }";
+        let register = |pq: &PersistedQueries, query: &str| {
+            pq.resolve(&apq_request(Some(query), &sha256_hash(query)))
+                .unwrap()
+        };
+
+        let pq = PersistedQueries::new().with_capacity(2);
+        register(&pq, projects);
+        register(&pq, users);
+        assert!(pq.query(&sha256_hash(projects)).is_some());
+        register(&pq, issues);
+        assert!(pq.query(&sha256_hash(projects)).is_some());
+        assert!(pq.query(&sha256_hash(users)).is_none());
+        assert!(pq.query(&sha256_hash(issues)).is_some());
+
+        register(&pq, issues);
+        register(&pq, users);
+        assert!(pq.query(&sha256_hash(projects)).is_none());
+        assert!(pq.query(&sha256_hash(issues)).is_some());
+
+        let pq = PersistedQueries::new().with_capacity(0);
+        assert_eq!(register(&pq, projects), projects);
+        assert!(pq.query(&sha256_hash(projects)).is_none());
+    }
+}
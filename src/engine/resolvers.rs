@@ -1,15 +1,25 @@
 //! Contains the type aliases, enumerations, and structures to allow for the creation of custom
 //! resolvers.
 
+use crate::engine::cache::{CachePlan, CacheStore};
 use crate::engine::context::GraphQLContext;
 use crate::engine::context::RequestContext;
-use crate::engine::objects::{Node, NodeRef, Rel};
+#[cfg(feature = "neo4j")]
+use crate::engine::database::neo4j::Neo4jConnectionManager;
+use crate::engine::database::{DatabaseBackend, DatabasePool, Transaction};
+use crate::engine::objects::{Node, NodeRef, OperationTransaction, Rel, Resolver};
 use crate::engine::schema::Info;
 use crate::engine::value::Value;
 use crate::Error;
 use inflector::Inflector;
+use std::cell::RefCell;
 use std::collections::HashMap;
 use std::convert::TryFrom;
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::sync::Arc;
+use tokio::runtime::Builder;
 
 pub use juniper::{Arguments, ExecutionResult, Executor, FieldError, FromInputValue};
 
@@ -29,19 +39,217 @@ pub enum Object<'a, RequestCtx: RequestContext> {
 }
 
 /// Type alias for custom resolver functions. Takes a [`ResolverFacade`] and returns an
-/// ExecutionResult.
+/// ExecutionResult. A custom resolver may be a plain function, or a closure that captures state,
+/// such as configuration or a client for another service, to be shared across requests.
 ///
 /// [`ResolverFacade`]: ./struct.ResolverFacade.html
-pub type ResolverFunc<RequestCtx> = fn(ResolverFacade<RequestCtx>) -> ExecutionResult;
+///
+/// # Examples
+///
+/// ```rust
+/// # use std::sync::Arc;
+/// # use warpgrapher::engine::resolvers::{ExecutionResult, ResolverFacade, ResolverFunc};
+///
+/// fn project_count(facade: ResolverFacade<()>) -> ExecutionResult {
+///     facade.resolve_scalar(1)
+/// }
+///
+/// let f: Arc<ResolverFunc<()>> = Arc::new(project_count);
+///
+/// let app_name = Arc::new("warpgrapher".to_string());
+/// let g: Arc<ResolverFunc<()>> =
+///     Arc::new(move |facade: ResolverFacade<()>| facade.resolve_scalar(app_name.to_string()));
+/// ```
+pub type ResolverFunc<RequestCtx> =
+    dyn Fn(ResolverFacade<RequestCtx>) -> ExecutionResult + Send + Sync;
+
+/// Type alias for the future returned by an [`AsyncResolverFunc`]
+///
+/// [`AsyncResolverFunc`]: ./type.AsyncResolverFunc.html
+pub type ResolverFuture<'a> = Pin<Box<dyn Future<Output = ExecutionResult> + 'a>>;
+
+/// Type alias for asynchronous custom resolver functions. Takes a [`ResolverFacade`] and returns
+/// a future of an ExecutionResult. An asynchronous resolver is registered by wrapping it with
+/// [`async_resolver`].
+///
+/// [`async_resolver`]: ./fn.async_resolver.html
+/// [`ResolverFacade`]: ./struct.ResolverFacade.html
+pub type AsyncResolverFunc<RequestCtx> =
+    dyn for<'a> Fn(ResolverFacade<'a, RequestCtx>) -> ResolverFuture<'a> + Send + Sync;
+
+/// Type alias for a mapping from a custom resolver name to a the Rust function or closure that
+/// implements the custom resolver.
+///
+/// # Examples
+///
+/// ```rust
+/// # use std::sync::Arc;
+/// # use warpgrapher::engine::resolvers::{ExecutionResult, ResolverFacade, Resolvers};
+///
+/// fn project_count(facade: ResolverFacade<()>) -> ExecutionResult {
+///     facade.resolve_scalar(1)
+/// }
+///
+/// let mut resolvers = Resolvers::<()>::new();
+/// resolvers.insert("ProjectCount".to_string(), Arc::new(project_count));
+/// ```
+pub type Resolvers<RequestCtx> = HashMap<String, Arc<ResolverFunc<RequestCtx>>>;
+
+/// Wraps an asynchronous custom resolver as a [`ResolverFunc`], so that it can be added to the
+/// [`Resolvers`]. GraphQL fields are resolved synchronously, so the future returned by the
+/// resolver is run to completion on a runtime of its own, in the same way as the queries of the
+/// Neo4j back-end. As with the Neo4j back-end, the engine must therefore not be called from
+/// within an async runtime.
+///
+/// [`ResolverFunc`]: ./type.ResolverFunc.html
+/// [`Resolvers`]: ./type.Resolvers.html
+///
+/// # Examples
+///
+/// ```rust
+/// # use warpgrapher::engine::resolvers::{
+/// #     async_resolver, ExecutionResult, ResolverFacade, Resolvers,
+/// # };
+///
+/// async fn project_count(facade: ResolverFacade<'_, ()>) -> ExecutionResult {
+///     // await a client of another service here
+///     facade.resolve_scalar(1)
+/// }
+///
+/// let mut resolvers = Resolvers::<()>::new();
+/// resolvers.insert(
+///     "ProjectCount".to_string(),
+///     async_resolver(|facade| Box::pin(project_count(facade))),
+/// );
+/// ```
+pub fn async_resolver<RequestCtx, F>(f: F) -> Arc<ResolverFunc<RequestCtx>>
+where
+    RequestCtx: RequestContext,
+    F: for<'a> Fn(ResolverFacade<'a, RequestCtx>) -> ResolverFuture<'a> + Send + Sync + 'static,
+{
+    Arc::new(move |facade: ResolverFacade<RequestCtx>| {
+        block_on(f(facade)).map_err(Error::into_field_error)?
+    })
+}
+
+/// Runs a future to completion on a new single-threaded runtime, for the asynchronous custom
+/// resolvers and validators
+pub(crate) fn block_on<F: Future>(future: F) -> Result<F::Output, Error> {
+    Ok(Builder::new()
+        .basic_scheduler()
+        .enable_all()
+        .build()?
+        .block_on(future))
+}
 
-/// Type alias for a mapping from a custom resolver name to a the Rust function that implements the
-/// custom resolver.
-pub type Resolvers<RequestCtx> = HashMap<String, Box<ResolverFunc<RequestCtx>>>;
+/// Holds the transaction shared by the database helpers of a [`ResolverFacade`], such as
+/// [`insert_node`]. The transaction is begun by the first helper that is called, and committed or
+/// rolled back by [`finish`] once the custom resolver returns. The cached responses put out of
+/// date by the writes of the helpers are invalidated once the writes are committed.
+///
+/// [`insert_node`]: ./struct.ResolverFacade.html#method.insert_node
+/// [`finish`]: #method.finish
+/// [`ResolverFacade`]: ./struct.ResolverFacade.html
+pub(crate) struct FacadeTransaction<'a> {
+    pool: &'a DatabasePool,
+    transaction: RefCell<Option<Box<dyn Transaction + 'a>>>,
+    cache_store: Option<&'a dyn CacheStore>,
+    writes: RefCell<Vec<CachePlan>>,
+}
+
+impl<'a> FacadeTransaction<'a> {
+    pub(crate) fn new(
+        pool: &'a DatabasePool,
+        cache_store: Option<&'a dyn CacheStore>,
+    ) -> FacadeTransaction<'a> {
+        FacadeTransaction {
+            pool,
+            transaction: RefCell::new(None),
+            cache_store,
+            writes: RefCell::new(Vec::new()),
+        }
+    }
+
+    /// Commits the transaction, if one has been begun, and invalidates the cached responses put
+    /// out of date by its writes. A helper called afterwards begins a new transaction.
+    fn commit(&self) -> Result<(), Error> {
+        if let Some(mut transaction) = self.transaction.borrow_mut().take() {
+            transaction.commit()?;
+        }
+        if let Some(cache_store) = self.cache_store {
+            self.writes
+                .borrow_mut()
+                .drain(..)
+                .for_each(|plan| plan.invalidate(cache_store));
+        }
+        Ok(())
+    }
+
+    /// Commits the transaction if the custom resolver succeeded, or rolls it back if the resolver
+    /// returned an error, then returns the result of the resolver
+    pub(crate) fn finish(&self, result: ExecutionResult) -> ExecutionResult {
+        if result.is_ok() {
+            self.commit().map_err(Error::into_field_error)?;
+        } else if let Some(mut transaction) = self.transaction.borrow_mut().take() {
+            self.writes.borrow_mut().clear();
+            transaction.rollback().map_err(Error::into_field_error)?;
+        }
+
+        result
+    }
+}
+
+impl OperationTransaction for FacadeTransaction<'_> {
+    /// Runs `f` in the transaction, beginning the transaction first if no helper has yet been
+    /// called. The transaction is begun as for a mutation, so that on Neo4j it runs against the
+    /// leader, where the resolver may both read and write.
+    fn run<R, F>(&self, info: &Info, mut f: F) -> Result<R, Error>
+    where
+        F: FnMut(&mut dyn Transaction) -> Result<R, Error>,
+    {
+        let mut slot = self.transaction.borrow_mut();
+        let transaction = match slot.take() {
+            Some(transaction) => transaction,
+            None => {
+                let mut transaction = self
+                    .pool
+                    .transaction(&Info::new("Mutation".to_string(), info.type_defs()))?;
+                transaction.begin()?;
+                transaction
+            }
+        };
+        f(&mut **slot.insert(transaction))
+    }
+
+    /// Holds the invalidation of the cached responses reading the types connected to `type_name`
+    /// until the transaction is committed
+    fn written(&self, type_name: &str, info: &Info) {
+        if self.cache_store.is_some() {
+            self.writes.borrow_mut().push(CachePlan::written(
+                vec![type_name.to_string()],
+                &info.type_defs(),
+            ));
+        }
+    }
+}
 
 /// Provides a simplified interface to primitive operations such as Node creation, Rel creation,
 /// resolution of both scalar and complex types. The [`ResolverFacade`] is the primary mechanism
 /// trough which a custom resolver interacts with the rest of the framework.
 ///
+/// The database helpers, such as [`read_nodes`] and [`insert_node`], share one transaction. The
+/// transaction is begun by the first helper called, and is committed when the custom resolver
+/// returns successfully, or rolled back if the resolver returns an error, so the writes of a
+/// resolver are applied together or not at all. The transaction is not retried, as the resolver
+/// may have effects outside the database. [`resolve_node`], [`resolve_rel`], and
+/// [`resolve_rel_list`] commit the transaction before they resolve the fields of their result,
+/// as those fields are read outside of the transaction.
+///
+/// [`insert_node`]: #method.insert_node
+/// [`read_nodes`]: #method.read_nodes
+/// [`resolve_node`]: #method.resolve_node
+/// [`resolve_rel`]: #method.resolve_rel
+/// [`resolve_rel_list`]: #method.resolve_rel_list
 /// [`ResolverFacade`]: ./struct.ResolverFacade.html
 pub struct ResolverFacade<'a, RequestCtx>
 where
@@ -53,6 +261,7 @@ where
     parent: Object<'a, RequestCtx>,
     partition_key_opt: Option<&'a Value>,
     executor: &'a Executor<'a, GraphQLContext<RequestCtx>>,
+    transaction: Rc<FacadeTransaction<'a>>,
 }
 
 impl<'a, RequestCtx> ResolverFacade<'a, RequestCtx>
@@ -66,6 +275,7 @@ where
         parent: Object<'a, RequestCtx>,
         partition_key_opt: Option<&'a Value>,
         executor: &'a Executor<GraphQLContext<RequestCtx>>,
+        transaction: Rc<FacadeTransaction<'a>>,
     ) -> Self {
         ResolverFacade {
             field_name,
@@ -74,6 +284,7 @@ where
             parent,
             partition_key_opt,
             executor,
+            transaction,
         }
     }
 
@@ -109,6 +320,77 @@ where
         Ok(parsed_input)
     }
 
+    /// Returns the value of a named argument to the field, converted into type T. Arguments
+    /// declared in the configuration with a default value resolve to that default if the client
+    /// omits them.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`] variant [`InputItemNotFound`] if the argument was not passed to the
+    /// query and has no default, or was passed as null, and [`TypeConversionFailed`] if the value
+    /// of the argument cannot be converted to type T.
+    ///
+    /// [`Error`]: ../../error/enum.Error.html
+    /// [`InputItemNotFound`]: ../../error/enum.Error.html#variant.InputItemNotFound
+    /// [`TypeConversionFailed`]: ../../error/enum.Error.html#variant.TypeConversionFailed
+    ///
+    /// # Examples
+    ///
+    /// ```rust, no_run
+    /// # use warpgrapher::engine::resolvers::{ResolverFacade, ExecutionResult};
+    ///
+    /// fn search_issues(facade: ResolverFacade<()>) -> ExecutionResult {
+    ///     let text: String = facade.arg("text")?;
+    ///     let limit: i32 = facade.arg("limit")?;
+    ///
+    ///     // search for up to limit issues matching text
+    ///
+    ///     facade.resolve_null()
+    /// }
+    /// ```
+    pub fn arg<T>(&self, name: &str) -> Result<T, Error>
+    where
+        T: TryFrom<Value, Error = Error>,
+    {
+        self.arg_opt(name)?.ok_or_else(|| Error::InputItemNotFound {
+            name: name.to_string(),
+        })
+    }
+
+    /// Returns the value of an optional named argument to the field, converted into type T, or
+    /// None if the argument was omitted without a default value, or was passed as null.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`] variant [`TypeConversionFailed`] if the value of the argument cannot
+    /// be converted to type T.
+    ///
+    /// [`Error`]: ../../error/enum.Error.html
+    /// [`TypeConversionFailed`]: ../../error/enum.Error.html#variant.TypeConversionFailed
+    ///
+    /// # Examples
+    ///
+    /// ```rust, no_run
+    /// # use warpgrapher::engine::resolvers::{ResolverFacade, ExecutionResult};
+    ///
+    /// fn search_issues(facade: ResolverFacade<()>) -> ExecutionResult {
+    ///     let labels: Option<Vec<String>> = facade.arg_opt("labels")?;
+    ///
+    ///     // filter by labels, if provided
+    ///
+    ///     facade.resolve_null()
+    /// }
+    /// ```
+    pub fn arg_opt<T>(&self, name: &str) -> Result<Option<T>, Error>
+    where
+        T: TryFrom<Value, Error = Error>,
+    {
+        match self.args().get::<Value>(name) {
+            None | Some(Value::Null) => Ok(None),
+            Some(v) => T::try_from(v).map(Some),
+        }
+    }
+
     /// Returns the execution metadata that was passed to the engine. If no metadata was
     /// passed to the engine's `execute` method, an empty HashMap is returned.
     ///
@@ -160,9 +442,8 @@ where
     #[cfg(feature = "neo4j")]
     pub async fn db_into_neo4j(
         &self,
-    ) -> Result<bb8::PooledConnection<'_, bb8_bolt::BoltConnectionManager>, Error> {
-        let pool: &bb8::Pool<bb8_bolt::BoltConnectionManager> =
-            self.executor().context().pool().neo4j()?;
+    ) -> Result<bb8::PooledConnection<'_, Neo4jConnectionManager>, Error> {
+        let pool = self.executor().context().pool().neo4j()?;
         let client = pool.get().await?;
         Ok(client)
     }
@@ -246,8 +527,10 @@ where
         self.args
     }
 
-    /// Creates a [`Node`], of a given type, with a set of properites
+    /// Returns a [`Node`], of a given type, with a set of properites, for a custom resolver to
+    /// return. The node is not stored in the database. To store a new node, use [`insert_node`].
     ///
+    /// [`insert_node`]: #method.insert_node
     /// [`Node`]: ../objects/struct.Node.html
     ///
     /// # Examples
@@ -263,15 +546,27 @@ where
     ///     let mut props = HashMap::new();
     ///     props.insert("role".to_string(), Value::String("Admin".to_string()));
     ///
-    ///     let n = facade.create_node(typename, props);
+    ///     let n = facade.node(typename, props);
     ///
     ///     facade.resolve_node(&n)
     /// }
     /// ```
-    pub fn create_node(&self, typename: &str, props: HashMap<String, Value>) -> Node<RequestCtx> {
+    pub fn node(&self, typename: &str, props: HashMap<String, Value>) -> Node<RequestCtx> {
         Node::new(typename.to_string(), props)
     }
 
+    /// Returns a [`Node`], of a given type, with a set of properites, for a custom resolver to
+    /// return. The node is not stored in the database. This method is deprecated in favor of
+    /// [`node`], which does the same. To store a new node, use [`insert_node`].
+    ///
+    /// [`insert_node`]: #method.insert_node
+    /// [`node`]: #method.node
+    /// [`Node`]: ../objects/struct.Node.html
+    #[deprecated(note = "use `node`, or `insert_node` to store the node in the database")]
+    pub fn create_node(&self, typename: &str, props: HashMap<String, Value>) -> Node<RequestCtx> {
+        self.node(typename, props)
+    }
+
     /// Creates a [`Rel`], with a id, properties, and destination node id and label. The src node
     /// of the relationship is the parent node on which the field is being resolved.
     ///
@@ -348,7 +643,7 @@ where
     ///     let typename = "User";
     ///     let mut props = HashMap::new();
     ///     props.insert("role".to_string(), Value::String("Admin".to_string()));
-    ///     let n = facade.create_node(typename, props);
+    ///     let n = facade.node(typename, props);
     ///
     ///     let rel_id = Value::String("1e2ac081-b0a6-4f68-bc88-99bdc4111f00".to_string());
     ///     let mut rel_props = HashMap::new();
@@ -380,6 +675,239 @@ where
         }
     }
 
+    /// Reads the nodes of type `type_name` that match `input`, or all nodes of the type if
+    /// `input` is None. The input takes the same form as the input argument of the generated
+    /// query for the type. The nodes are read in the same way as by the generated query, so the
+    /// helper works with any database back-end, and within the transaction shared by the
+    /// helpers, so the resolver sees its own writes. The nodes read count toward the greatest
+    /// number of nodes that the request may read.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`] variant [`SchemaItemNotFound`] if there is no generated query for the
+    /// type, [`ResultSizeExceeded`] if the request reads more nodes than its query limits allow,
+    /// or any error returned by the database back-end.
+    ///
+    /// [`Error`]: ../../error/enum.Error.html
+    /// [`ResultSizeExceeded`]: ../../error/enum.Error.html#variant.ResultSizeExceeded
+    /// [`SchemaItemNotFound`]: ../../error/enum.Error.html#variant.SchemaItemNotFound
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use serde_json::json;
+    /// # use std::convert::TryFrom;
+    /// # use warpgrapher::engine::resolvers::{ResolverFacade, ExecutionResult};
+    /// # use warpgrapher::engine::value::Value;
+    ///
+    /// fn custom_resolve(facade: ResolverFacade<()>) -> ExecutionResult {
+    ///     let admins = facade.read_nodes("User", Some(Value::try_from(json!({"role": "Admin"}))?))?;
+    ///
+    ///     facade.resolve_scalar(admins.len() as i32)
+    /// }
+    /// ```
+    pub fn read_nodes(
+        &self,
+        type_name: &str,
+        input: Option<Value>,
+    ) -> Result<Vec<Node<RequestCtx>>, Error> {
+        let info = Info::new("Query".to_string(), self.info.type_defs());
+        self.resolver()
+            .node_read_operation(type_name, &info, input, &*self.transaction, self.executor)
+            .map(|nodes| nodes.into_iter().map(Node::from).collect())
+    }
+
+    /// Creates a node of type `type_name` in the database, returning the new node. The input
+    /// takes the same form as the input argument of the generated create mutation for the type,
+    /// including nested relationships, and is checked by the same validators. The node is created
+    /// within the transaction shared by the helpers, which commits when the resolver returns.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`] variant [`SchemaItemNotFound`] if there is no generated create
+    /// mutation for the type, [`FieldValidationFailed`] if a property validator rejects the input,
+    /// the error returned by a context-aware validator, or any error returned by the database
+    /// back-end.
+    ///
+    /// [`Error`]: ../../error/enum.Error.html
+    /// [`FieldValidationFailed`]: ../../error/enum.Error.html#variant.FieldValidationFailed
+    /// [`SchemaItemNotFound`]: ../../error/enum.Error.html#variant.SchemaItemNotFound
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use serde_json::json;
+    /// # use std::convert::TryFrom;
+    /// # use warpgrapher::engine::resolvers::{ResolverFacade, ExecutionResult};
+    /// # use warpgrapher::engine::value::Value;
+    ///
+    /// fn custom_resolve(facade: ResolverFacade<()>) -> ExecutionResult {
+    ///     let user = facade.insert_node("User", Value::try_from(json!({"name": "Joe"}))?)?;
+    ///
+    ///     facade.resolve_node(&user)
+    /// }
+    /// ```
+    pub fn insert_node(&self, type_name: &str, input: Value) -> Result<Node<RequestCtx>, Error> {
+        let info = Info::new("Mutation".to_string(), self.info.type_defs());
+        self.resolver()
+            .node_create_operation(
+                &(type_name.to_string() + "Create"),
+                &info,
+                input,
+                &*self.transaction,
+                self.executor,
+            )
+            .map(Node::from)
+    }
+
+    /// Updates the nodes of type `type_name` in the database, returning the updated nodes. The
+    /// input takes the same form as the input argument of the generated update mutation for the
+    /// type, with a `$MATCH` and a `$SET` field, and is checked by the same validators.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`] variant [`SchemaItemNotFound`] if there is no generated update
+    /// mutation for the type, [`FieldValidationFailed`] if a property validator rejects the input,
+    /// the error returned by a context-aware validator, or any error returned by the database
+    /// back-end.
+    ///
+    /// [`Error`]: ../../error/enum.Error.html
+    /// [`FieldValidationFailed`]: ../../error/enum.Error.html#variant.FieldValidationFailed
+    /// [`SchemaItemNotFound`]: ../../error/enum.Error.html#variant.SchemaItemNotFound
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use serde_json::json;
+    /// # use std::convert::TryFrom;
+    /// # use warpgrapher::engine::resolvers::{ResolverFacade, ExecutionResult};
+    /// # use warpgrapher::engine::value::Value;
+    ///
+    /// fn custom_resolve(facade: ResolverFacade<()>) -> ExecutionResult {
+    ///     let users = facade.update_nodes(
+    ///         "User",
+    ///         Value::try_from(json!({"$MATCH": {"name": "Joe"}, "$SET": {"role": "Admin"}}))?,
+    ///     )?;
+    ///
+    ///     facade.resolve_scalar(users.len() as i32)
+    /// }
+    /// ```
+    pub fn update_nodes(
+        &self,
+        type_name: &str,
+        input: Value,
+    ) -> Result<Vec<Node<RequestCtx>>, Error> {
+        let info = Info::new("Mutation".to_string(), self.info.type_defs());
+        self.resolver()
+            .node_update_operation(
+                &(type_name.to_string() + "Update"),
+                &info,
+                input,
+                &*self.transaction,
+                self.executor,
+            )
+            .map(|nodes| nodes.into_iter().map(Node::from).collect())
+    }
+
+    /// Deletes the nodes of type `type_name` in the database, returning the number of nodes
+    /// deleted. The input takes the same form as the input argument of the generated delete
+    /// mutation for the type, with a `$MATCH` field and an optional `$DELETE` field.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`] variant [`SchemaItemNotFound`] if there is no generated delete
+    /// mutation for the type, or any error returned by the database back-end.
+    ///
+    /// [`Error`]: ../../error/enum.Error.html
+    /// [`SchemaItemNotFound`]: ../../error/enum.Error.html#variant.SchemaItemNotFound
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use serde_json::json;
+    /// # use std::convert::TryFrom;
+    /// # use warpgrapher::engine::resolvers::{ResolverFacade, ExecutionResult};
+    /// # use warpgrapher::engine::value::Value;
+    ///
+    /// fn custom_resolve(facade: ResolverFacade<()>) -> ExecutionResult {
+    ///     let deleted =
+    ///         facade.delete_nodes("User", Value::try_from(json!({"$MATCH": {"name": "Joe"}}))?)?;
+    ///
+    ///     facade.resolve_scalar(deleted)
+    /// }
+    /// ```
+    pub fn delete_nodes(&self, type_name: &str, input: Value) -> Result<i32, Error> {
+        let info = Info::new("Mutation".to_string(), self.info.type_defs());
+        self.resolver().node_delete_operation(
+            &(type_name.to_string() + "Delete"),
+            type_name,
+            &info,
+            input,
+            &*self.transaction,
+            self.executor,
+        )
+    }
+
+    /// Reads the `rel_name` relationships from nodes of type `type_name` that match `input`, or
+    /// all such relationships if `input` is None. The input takes the same form as the input
+    /// argument of the generated query for the relationship. The relationships read count toward
+    /// the greatest number of nodes and relationships that the request may read.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`] variant [`SchemaItemNotFound`] if there is no generated query for the
+    /// relationship, [`ResultSizeExceeded`] if the request reads more relationships than its
+    /// query limits allow, or any error returned by the database back-end.
+    ///
+    /// [`Error`]: ../../error/enum.Error.html
+    /// [`ResultSizeExceeded`]: ../../error/enum.Error.html#variant.ResultSizeExceeded
+    /// [`SchemaItemNotFound`]: ../../error/enum.Error.html#variant.SchemaItemNotFound
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use serde_json::json;
+    /// # use std::convert::TryFrom;
+    /// # use warpgrapher::engine::resolvers::{ResolverFacade, ExecutionResult};
+    /// # use warpgrapher::engine::value::Value;
+    ///
+    /// fn custom_resolve(facade: ResolverFacade<()>) -> ExecutionResult {
+    ///     let input = Value::try_from(json!({"src": {"Project": {"name": "warpgrapher"}}}))?;
+    ///     let owners = facade.read_rels("Project", "owner", Some(input))?;
+    ///
+    ///     facade.resolve_rel_list(owners.iter().collect())
+    /// }
+    /// ```
+    pub fn read_rels(
+        &self,
+        type_name: &str,
+        rel_name: &str,
+        input: Option<Value>,
+    ) -> Result<Vec<Rel<RequestCtx>>, Error> {
+        let info = Info::new("Query".to_string(), self.info.type_defs());
+        let field_name = type_name.to_string()
+            + &rel_name
+                .to_title_case()
+                .split_whitespace()
+                .collect::<String>();
+        self.resolver()
+            .rel_read_operation(
+                &field_name,
+                rel_name,
+                &info,
+                input,
+                &*self.transaction,
+                self.executor,
+            )
+            .map(|rels| rels.into_iter().map(Rel::from).collect())
+    }
+
+    /// Returns a resolver for the operations of the database helpers, which run in the
+    /// transaction shared by the helpers called by the custom resolver
+    fn resolver(&self) -> Resolver<'a> {
+        Resolver::new(self.partition_key_opt)
+    }
+
     /// Returns the [`Info`] struct containing the type schema for the GraphQL model.
     ///
     /// [`Info`]: ../schema/struct.Info.html
@@ -530,10 +1058,11 @@ where
     ///     hm.insert("age".to_string(), Value::Int64(21));
     ///
     ///     // return node
-    ///     facade.resolve_node(&facade.create_node("User", hm))
+    ///     facade.resolve_node(&facade.node("User", hm))
     /// }
     /// ```
     pub fn resolve_node(&self, node: &Node<RequestCtx>) -> ExecutionResult {
+        self.transaction.commit().map_err(Error::into_field_error)?;
         self.executor.resolve(
             &Info::new(node.typename().to_string(), self.info.type_defs()),
             node,
@@ -571,6 +1100,7 @@ where
                 .collect::<String>())
             + "Rel";
 
+        self.transaction.commit().map_err(Error::into_field_error)?;
         self.executor
             .resolve(&Info::new(rel_name, self.info.type_defs()), rel)
     }
@@ -615,6 +1145,7 @@ where
                 .collect::<String>())
             + "Rel";
 
+        self.transaction.commit().map_err(Error::into_field_error)?;
         self.executor
             .resolve(&Info::new(object_name, self.info.type_defs()), &rels)
     }
@@ -638,3 +1169,76 @@ where
         self.executor.context().request_context()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{block_on, FacadeTransaction};
+    #[cfg(feature = "in-memory")]
+    use crate::engine::database::memory::MemoryGraph;
+    use crate::engine::database::DatabasePool;
+    #[cfg(feature = "in-memory")]
+    use crate::engine::objects::OperationTransaction;
+    #[cfg(feature = "in-memory")]
+    use crate::engine::schema::Info;
+    #[cfg(feature = "in-memory")]
+    use crate::Error;
+    use juniper::FieldError;
+    #[cfg(feature = "in-memory")]
+    use std::collections::HashMap;
+    #[cfg(feature = "in-memory")]
+    use std::sync::Arc;
+
+    /// Passes if a future is run to completion on its own runtime
+    #[test]
+    fn block_on_output() {
+        assert_eq!(3, block_on(async { 1 + 2 }).unwrap());
+    }
+
+    /// Passes if the result of a resolver that called no database helpers is returned unchanged
+    #[test]
+    fn finish_without_transaction() {
+        let pool = DatabasePool::NoDatabase;
+        let transaction = FacadeTransaction::new(&pool, None);
+        assert!(transaction.finish(Ok(juniper::Value::null())).is_ok());
+        assert!(transaction
+            .finish(Err(FieldError::from("rejected")))
+            .is_err());
+    }
+
+    /// Passes if the database helpers share one transaction until it is committed, and a helper
+    /// called afterwards begins a new one
+    #[cfg(feature = "in-memory")]
+    #[test]
+    fn facade_transaction_shared() {
+        let pool = DatabasePool::Memory(MemoryGraph::new());
+        let info = Info::new("Query".to_string(), Arc::new(HashMap::new()));
+        let transaction = FacadeTransaction::new(&pool, None);
+
+        let mut calls = 0;
+        for _ in 0..2 {
+            transaction
+                .run(&info, |_| -> Result<(), Error> {
+                    calls += 1;
+                    Ok(())
+                })
+                .unwrap();
+        }
+        assert!(transaction.transaction.borrow().is_some());
+
+        transaction.commit().unwrap();
+        assert!(transaction.transaction.borrow().is_none());
+
+        let result = transaction.run(&info, |_| -> Result<(), Error> {
+            calls += 1;
+            Err(Error::ValidationFailed {
+                message: "rejected".to_string(),
+            })
+        });
+        assert!(result.is_err());
+        assert!(transaction
+            .finish(Err(FieldError::from("rejected")))
+            .is_err());
+        assert!(transaction.transaction.borrow().is_none());
+        assert_eq!(3, calls);
+    }
+}
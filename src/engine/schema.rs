@@ -3,7 +3,8 @@
 //! and resolvers for common create, read, update, and delete operations.
 
 use super::config::{
-    Configuration, Endpoint, EndpointClass, GraphqlType, Relationship, Type, TypeDef,
+    Configuration, Endpoint, EndpointClass, GraphqlType, Identifier, PartitionKey, Relationship,
+    Trigger, TriggerEvent, Type, TypeDef,
 };
 use super::objects::Node;
 use crate::engine::context::RequestContext;
@@ -35,15 +36,76 @@ impl Info {
         Info { name, type_defs }
     }
 
-    pub(crate) fn name(&self) -> &str {
+    /// Returns the name of the GraphQL type that this [`Info`] describes.
+    ///
+    /// [`Info`]: ./struct.Info.html
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use warpgrapher::Configuration;
+    /// # use warpgrapher::engine::schema::Info;
+    ///
+    /// let info = Info::from(&Configuration::default());
+    /// assert_eq!("Query", info.name());
+    /// ```
+    pub fn name(&self) -> &str {
         &self.name
     }
 
-    pub(crate) fn type_def(&self) -> Result<&NodeType, Error> {
+    /// Returns the [`NodeType`] definition of the GraphQL type that this [`Info`] describes.
+    ///
+    /// [`Info`]: ./struct.Info.html
+    /// [`NodeType`]: ./struct.NodeType.html
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`] variant [`SchemaItemNotFound`] if the type is not in the schema.
+    ///
+    /// [`Error`]: ../../enum.Error.html
+    /// [`SchemaItemNotFound`]: ../../enum.Error.html#variant.SchemaItemNotFound
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use warpgrapher::Configuration;
+    /// # use warpgrapher::engine::schema::Info;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let info = Info::from(&Configuration::default());
+    /// assert_eq!("Query", info.type_def()?.type_name());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn type_def(&self) -> Result<&NodeType, Error> {
         self.type_def_by_name(&self.name)
     }
 
-    pub(crate) fn type_def_by_name(&self, name: &str) -> Result<&NodeType, Error> {
+    /// Returns the [`NodeType`] definition of the GraphQL type with the given name.
+    ///
+    /// [`NodeType`]: ./struct.NodeType.html
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`] variant [`SchemaItemNotFound`] if no type with that name is in the
+    /// schema.
+    ///
+    /// [`Error`]: ../../enum.Error.html
+    /// [`SchemaItemNotFound`]: ../../enum.Error.html#variant.SchemaItemNotFound
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use warpgrapher::Configuration;
+    /// # use warpgrapher::engine::schema::Info;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let info = Info::from(&Configuration::default());
+    /// let mutation = info.type_def_by_name("Mutation")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn type_def_by_name(&self, name: &str) -> Result<&NodeType, Error> {
         self.type_defs
             .get(name)
             .ok_or_else(|| Error::SchemaItemNotFound {
@@ -51,52 +113,160 @@ impl Info {
             })
     }
 
-    pub(crate) fn type_defs(&self) -> Arc<HashMap<String, NodeType>> {
+    /// Returns the map of all GraphQL type names to [`NodeType`] definitions in the schema,
+    /// including the root `Query` and `Mutation` types, and all generated input types.
+    ///
+    /// [`NodeType`]: ./struct.NodeType.html
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use warpgrapher::Configuration;
+    /// # use warpgrapher::engine::schema::Info;
+    ///
+    /// let info = Info::from(&Configuration::default());
+    /// for (name, node_type) in info.type_defs().iter() {
+    ///     println!("{}: {:#?}", name, node_type.type_kind());
+    /// }
+    /// ```
+    pub fn type_defs(&self) -> Arc<HashMap<String, NodeType>> {
         self.type_defs.clone()
     }
 }
 
+impl From<&Configuration> for Info {
+    /// Generates the schema model for a [`Configuration`], returning an [`Info`] for the root
+    /// `Query` type. This is the same model that an [`Engine`] built from the configuration
+    /// serves.
+    ///
+    /// [`Configuration`]: ../config/struct.Configuration.html
+    /// [`Engine`]: ../struct.Engine.html
+    /// [`Info`]: ./struct.Info.html
+    fn from(c: &Configuration) -> Info {
+        Info::new("Query".to_string(), Arc::new(generate_schema(c)))
+    }
+}
+
 pub(super) type RootRef<RequestCtx> = Arc<RootNode<'static, Node<RequestCtx>, Node<RequestCtx>>>;
 
+/// Indicates whether an [`Argument`] to a GraphQL field is required or optional.
+///
+/// [`Argument`]: ./struct.Argument.html
 #[derive(Copy, Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
-pub(crate) enum ArgumentKind {
+pub enum ArgumentKind {
+    /// The argument must be provided
     Required,
+    /// The argument may be omitted
     Optional,
 }
 
+/// Identifies the role of a [`Property`] in the schema, which determines how Warpgrapher
+/// resolves the field.
+///
+/// [`Property`]: ./struct.Property.html
 #[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
-pub(crate) enum PropertyKind {
+pub enum PropertyKind {
+    /// A field resolved by a custom resolver function
     CustomResolver,
+    /// A scalar field on a type, resolved by a custom resolver function
     DynamicScalar,
-    DynamicRel { rel_name: String },
+    /// A relationship, resolved by a custom resolver function
+    DynamicRel {
+        /// Name of the relationship
+        rel_name: String,
+    },
+    /// A field of an input type
     Input,
+    /// A root mutation endpoint that creates nodes
     NodeCreateMutation,
+    /// A root mutation endpoint that updates nodes
     NodeUpdateMutation,
-    NodeDeleteMutation { label: String },
+    /// A root mutation endpoint that deletes nodes
+    NodeDeleteMutation {
+        /// Label of the nodes to be deleted
+        label: String,
+    },
+    /// An object-valued field, such as a root query endpoint that reads nodes
     Object,
-    Rel { rel_name: String },
-    RelCreateMutation { src_label: String, rel_name: String },
-    RelUpdateMutation { src_label: String, rel_name: String },
-    RelDeleteMutation { src_label: String, rel_name: String },
+    /// A relationship, read from the database
+    Rel {
+        /// Name of the relationship
+        rel_name: String,
+    },
+    /// A root mutation endpoint that creates relationships
+    RelCreateMutation {
+        /// Label of the source nodes of the relationship
+        src_label: String,
+        /// Name of the relationship
+        rel_name: String,
+    },
+    /// A root mutation endpoint that updates relationships
+    RelUpdateMutation {
+        /// Label of the source nodes of the relationship
+        src_label: String,
+        /// Name of the relationship
+        rel_name: String,
+    },
+    /// A root mutation endpoint that deletes relationships
+    RelDeleteMutation {
+        /// Label of the source nodes of the relationship
+        src_label: String,
+        /// Name of the relationship
+        rel_name: String,
+    },
+    /// A scalar field, read from the database
     Scalar,
+    /// A field whose value is a union of types
     Union,
+    /// The static `_version` query endpoint
     VersionQuery,
 }
 
+/// Identifies the kind of GraphQL type that a [`NodeType`] defines.
+///
+/// [`NodeType`]: ./struct.NodeType.html
 #[derive(Copy, Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
-pub(crate) enum TypeKind {
+pub enum TypeKind {
+    /// A GraphQL input type
     Input,
+    /// A GraphQL object type representing a node, or a root query or mutation type
     Object,
+    /// A GraphQL object type representing a relationship
     Rel,
+    /// A GraphQL union type
     Union,
 }
 
+/// Describes a GraphQL type generated from the [`Configuration`], including its fields.
+///
+/// [`Configuration`]: ../config/struct.Configuration.html
+///
+/// # Examples
+///
+/// ```rust
+/// # use warpgrapher::Configuration;
+/// # use warpgrapher::engine::schema::Info;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let info = Info::from(&Configuration::default());
+/// let query = info.type_def()?;
+/// let version = query.property("_version")?;
+/// assert_eq!("String", version.type_name());
+/// # Ok(())
+/// # }
+/// ```
 #[derive(Debug, Deserialize, PartialEq, Serialize)]
-pub(crate) struct NodeType {
+pub struct NodeType {
     props: HashMap<String, Property>,
     type_kind: TypeKind,
     type_name: String,
     union_types: Option<Vec<String>>,
+    description: Option<String>,
+    db_label: Option<String>,
+    partition_key: Option<PartitionKey>,
+    id: Option<Identifier>,
+    validator: Option<String>,
+    triggers: Vec<Trigger>,
 }
 
 impl NodeType {
@@ -106,10 +276,102 @@ impl NodeType {
             type_kind,
             type_name,
             union_types: None,
+            description: None,
+            db_label: None,
+            partition_key: None,
+            id: None,
+            validator: None,
+            triggers: Vec::new(),
         }
     }
 
-    pub(crate) fn property(&self, property_name: &str) -> Result<&Property, Error> {
+    fn with_db_label(mut self, db_label: Option<&str>) -> Self {
+        self.db_label = db_label.map(|l| l.to_string());
+        self
+    }
+
+    fn with_description(mut self, description: Option<&str>) -> Self {
+        self.description = description.map(|d| d.to_string());
+        self
+    }
+
+    fn with_partition_key(mut self, partition_key: Option<&PartitionKey>) -> Self {
+        self.partition_key = partition_key.cloned();
+        self
+    }
+
+    fn with_id(mut self, id: Option<&Identifier>) -> Self {
+        self.id = id.cloned();
+        self
+    }
+
+    fn with_validator(mut self, validator: Option<&str>) -> Self {
+        self.validator = validator.map(|v| v.to_string());
+        self
+    }
+
+    fn with_triggers(mut self, triggers: Iter<'_, Trigger>) -> Self {
+        self.triggers = triggers.cloned().collect();
+        self
+    }
+
+    /// Returns the label under which nodes of the type are stored in the database, which is the
+    /// name of the type unless the configuration gives a different `dbLabel`.
+    pub fn db_label(&self) -> &str {
+        self.db_label.as_deref().unwrap_or(&self.type_name)
+    }
+
+    /// Returns the optional description of the type.
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    /// Returns the optional partition key configuration of the type. For a relationship type,
+    /// this is the partition key configuration of the source node type.
+    pub fn partition_key(&self) -> Option<&PartitionKey> {
+        self.partition_key.as_ref()
+    }
+
+    /// Returns the name of the property that holds the partition key of nodes of the type, which
+    /// is `partitionKey` unless the configuration names a different property.
+    pub fn partition_key_property(&self) -> &str {
+        self.partition_key
+            .as_ref()
+            .map_or("partitionKey", |pk| pk.property())
+    }
+
+    /// Returns the optional id configuration of the type. If None, the ids of new nodes are
+    /// generated by the database back-end.
+    pub fn id(&self) -> Option<&Identifier> {
+        self.id.as_ref()
+    }
+
+    /// Returns the optional name of the context-aware validator called when nodes of the type, or
+    /// relationships of a relationship type, are created or updated.
+    pub fn validator(&self) -> Option<&str> {
+        self.validator.as_deref()
+    }
+
+    /// Returns the names of the trigger functions run at the given lifecycle event for nodes of
+    /// the type, or relationships of a relationship type, in the order they are configured.
+    pub fn triggers(&self, event: TriggerEvent) -> impl Iterator<Item = &str> {
+        self.triggers
+            .iter()
+            .filter(move |t| t.event() == event)
+            .map(|t| t.handler())
+    }
+
+    /// Returns the [`Property`] definition of the field with the given name.
+    ///
+    /// [`Property`]: ./struct.Property.html
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`] variant [`SchemaItemNotFound`] if the type has no such field.
+    ///
+    /// [`Error`]: ../../enum.Error.html
+    /// [`SchemaItemNotFound`]: ../../enum.Error.html#variant.SchemaItemNotFound
+    pub fn property(&self, property_name: &str) -> Result<&Property, Error> {
         self.props
             .get(property_name)
             .ok_or_else(|| Error::SchemaItemNotFound {
@@ -117,25 +379,35 @@ impl NodeType {
             })
     }
 
-    pub(crate) fn props(&self) -> Values<String, Property> {
+    /// Returns an iterator over the [`Property`] definitions of the type's fields.
+    ///
+    /// [`Property`]: ./struct.Property.html
+    pub fn props(&self) -> Values<String, Property> {
         self.props.values()
     }
 
-    pub(crate) fn type_kind(&self) -> &TypeKind {
+    /// Returns the [`TypeKind`] of the type.
+    ///
+    /// [`TypeKind`]: ./enum.TypeKind.html
+    pub fn type_kind(&self) -> &TypeKind {
         &self.type_kind
     }
 
-    pub(crate) fn type_name(&self) -> &str {
+    /// Returns the GraphQL name of the type.
+    pub fn type_name(&self) -> &str {
         &self.type_name
     }
 
-    pub(crate) fn union_types(&self) -> Option<Iter<String>> {
+    /// Returns an iterator over the names of the member types, if this is a union type.
+    pub fn union_types(&self) -> Option<Iter<String>> {
         self.union_types.as_ref().map(|uts| uts.iter())
     }
 }
 
+/// Describes a field of a GraphQL type, such as a scalar property, a relationship, or a root
+/// query or mutation endpoint.
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
-pub(crate) struct Property {
+pub struct Property {
     name: String,
     kind: PropertyKind,
     type_name: String,
@@ -144,6 +416,10 @@ pub(crate) struct Property {
     arguments: HashMap<String, Argument>,
     resolver: Option<String>,
     validator: Option<String>,
+    description: Option<String>,
+    deprecated: bool,
+    deprecation_reason: Option<String>,
+    db_name: Option<String>,
 }
 
 impl Property {
@@ -157,15 +433,75 @@ impl Property {
             arguments: HashMap::new(),
             resolver: None,
             validator: None,
+            description: None,
+            deprecated: false,
+            deprecation_reason: None,
+            db_name: None,
         }
     }
 
-    pub(crate) fn arguments(&self) -> Values<String, Argument> {
+    /// Returns the named [`Argument`] to the field.
+    ///
+    /// [`Argument`]: ./struct.Argument.html
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`] variant [`SchemaItemNotFound`] if the field takes no such argument.
+    ///
+    /// [`Error`]: ../../enum.Error.html
+    /// [`SchemaItemNotFound`]: ../../enum.Error.html#variant.SchemaItemNotFound
+    pub fn argument(&self, argument_name: &str) -> Result<&Argument, Error> {
+        self.arguments
+            .get(argument_name)
+            .ok_or_else(|| Error::SchemaItemNotFound {
+                name: self.name.to_string() + "::" + argument_name,
+            })
+    }
+
+    /// Returns an iterator over the [`Argument`] definitions of the field.
+    ///
+    /// [`Argument`]: ./struct.Argument.html
+    pub fn arguments(&self) -> Values<String, Argument> {
         self.arguments.values()
     }
 
-    #[cfg(any(feature = "cosmos", feature = "gremlin", feature = "neo4j"))]
-    pub(crate) fn input_type_definition<'i>(&self, info: &'i Info) -> Result<&'i NodeType, Error> {
+    /// Returns the [`NodeType`] definition of the type of the field's `input` argument.
+    ///
+    /// [`NodeType`]: ./struct.NodeType.html
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`] variant [`SchemaItemNotFound`] if the field has no `input` argument,
+    /// or if the type of the argument is not in the schema.
+    ///
+    /// [`Error`]: ../../enum.Error.html
+    /// [`SchemaItemNotFound`]: ../../enum.Error.html#variant.SchemaItemNotFound
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use warpgrapher::Configuration;
+    /// # use warpgrapher::engine::schema::Info;
+    /// # use std::convert::TryFrom;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let config = Configuration::try_from("
+    /// version: 1
+    /// model:
+    ///   - name: User
+    ///     props:
+    ///       - name: email
+    ///         type: String
+    /// ")?;
+    /// let info = Info::from(&config);
+    ///
+    /// let user_create = info.type_def_by_name("Mutation")?.property("UserCreate")?;
+    /// let input_type = user_create.input_type_definition(&info)?;
+    /// assert_eq!("UserCreateMutationInput", input_type.type_name());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn input_type_definition<'i>(&self, info: &'i Info) -> Result<&'i NodeType, Error> {
         self.arguments
             .get("input")
             .ok_or_else(|| Error::SchemaItemNotFound {
@@ -180,35 +516,81 @@ impl Property {
             })
     }
 
-    pub(crate) fn kind(&self) -> &PropertyKind {
+    /// Returns the key under which the field is stored in the database, or for a relationship
+    /// field, the relationship type under which it is stored. This is the name of the field unless
+    /// the configuration gives a different `dbName` or `dbType`.
+    pub fn db_name(&self) -> &str {
+        self.db_name.as_deref().unwrap_or(&self.name)
+    }
+
+    /// Returns true if the field is deprecated.
+    pub fn deprecated(&self) -> bool {
+        self.deprecated
+    }
+
+    /// Returns the optional reason that the field is deprecated.
+    pub fn deprecation_reason(&self) -> Option<&str> {
+        self.deprecation_reason.as_deref()
+    }
+
+    /// Returns the optional description of the field.
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    /// Returns the [`PropertyKind`] of the field.
+    ///
+    /// [`PropertyKind`]: ./enum.PropertyKind.html
+    pub fn kind(&self) -> &PropertyKind {
         &self.kind
     }
 
-    pub(crate) fn list(&self) -> bool {
+    /// Returns true if the field is a list.
+    pub fn list(&self) -> bool {
         self.list
     }
 
-    pub(crate) fn name(&self) -> &str {
+    /// Returns the name of the field.
+    pub fn name(&self) -> &str {
         &self.name
     }
 
-    pub(crate) fn resolver(&self) -> Option<&String> {
+    /// Returns the name of the custom resolver for the field, if there is one.
+    pub fn resolver(&self) -> Option<&String> {
         self.resolver.as_ref()
     }
 
-    pub(crate) fn required(&self) -> bool {
+    /// Returns true if the field is required, meaning it is non-nullable.
+    pub fn required(&self) -> bool {
         self.required
     }
 
-    pub(crate) fn type_name(&self) -> &str {
+    /// Returns the name of the GraphQL type of the field.
+    pub fn type_name(&self) -> &str {
         &self.type_name
     }
 
-    #[cfg(any(feature = "cosmos", feature = "gremlin", feature = "neo4j"))]
-    pub(crate) fn validator(&self) -> Option<&String> {
+    /// Returns the name of the custom validator for the field, if there is one.
+    pub fn validator(&self) -> Option<&String> {
         self.validator.as_ref()
     }
 
+    fn with_db_name(mut self, db_name: Option<&str>) -> Self {
+        self.db_name = db_name.map(|n| n.to_string());
+        self
+    }
+
+    fn with_deprecation(mut self, deprecated: bool, reason: Option<&str>) -> Self {
+        self.deprecated = deprecated;
+        self.deprecation_reason = reason.map(|r| r.to_string());
+        self
+    }
+
+    fn with_description(mut self, description: Option<&str>) -> Self {
+        self.description = description.map(|d| d.to_string());
+        self
+    }
+
     fn with_arguments(mut self, arguments: HashMap<String, Argument>) -> Self {
         self.arguments = arguments;
         self
@@ -235,11 +617,15 @@ impl Property {
     }
 }
 
+/// Describes an argument to a GraphQL field.
 #[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
-pub(crate) struct Argument {
+pub struct Argument {
     name: String,
     kind: ArgumentKind,
     type_name: String,
+    list: bool,
+    default_value: Option<String>,
+    description: Option<String>,
 }
 
 impl Argument {
@@ -248,22 +634,71 @@ impl Argument {
             name,
             kind,
             type_name,
+            list: false,
+            default_value: None,
+            description: None,
         }
     }
 
-    pub(crate) fn kind(&self) -> &ArgumentKind {
+    /// Returns the optional default value of the argument, as configured, used if the client
+    /// omits the argument.
+    pub fn default_value(&self) -> Option<&str> {
+        self.default_value.as_deref()
+    }
+
+    /// Returns the optional description of the argument.
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    /// Returns the [`ArgumentKind`] of the argument, indicating whether it is required.
+    ///
+    /// [`ArgumentKind`]: ./enum.ArgumentKind.html
+    pub fn kind(&self) -> &ArgumentKind {
         &self.kind
     }
 
-    pub(crate) fn name(&self) -> &str {
+    /// Returns the name of the argument.
+    pub fn name(&self) -> &str {
         &self.name
     }
 
-    pub(crate) fn type_name(&self) -> &str {
+    /// Returns true if the argument is a list.
+    pub fn list(&self) -> bool {
+        self.list
+    }
+
+    /// Returns the name of the GraphQL type of the argument.
+    pub fn type_name(&self) -> &str {
         &self.type_name
     }
 }
 
+/// Takes a set of WG Arguments and adds them to a map of Argument structs that represent the
+/// arguments of a field in a graphql schema component
+fn generate_arguments(
+    arguments: &mut HashMap<String, Argument>,
+    config_arguments: Iter<crate::engine::config::Argument>,
+) {
+    config_arguments.for_each(|a| {
+        arguments.insert(
+            a.name().to_string(),
+            Argument {
+                name: a.name().to_string(),
+                kind: if a.required() {
+                    ArgumentKind::Required
+                } else {
+                    ArgumentKind::Optional
+                },
+                type_name: a.type_name().to_string(),
+                list: a.list(),
+                default_value: a.default().map(|d| d.to_string()),
+                description: a.description().map(|d| d.to_string()),
+            },
+        );
+    });
+}
+
 /// Takes a vector of WG Properties and returns a map of Property structs that
 /// represent the property fields in a graphql schema component
 fn generate_props(
@@ -295,10 +730,16 @@ fn generate_props(
                     )
                     .with_required(p.required() && object)
                     .with_list(p.list())
-                    .with_validator(p.validator().cloned()),
+                    .with_validator(p.validator().cloned())
+                    .with_description(p.description())
+                    .with_deprecation(p.deprecated(), p.deprecation_reason())
+                    .with_db_name(p.db_name()),
                 );
             }
             Some(r) => {
+                let mut arguments = HashMap::new();
+                generate_arguments(&mut arguments, p.arguments());
+
                 hm.insert(
                     p.name().to_string(),
                     Property::new(
@@ -308,8 +749,12 @@ fn generate_props(
                     )
                     .with_required(p.required() && object)
                     .with_list(p.list())
+                    .with_arguments(arguments)
                     .with_resolver(r)
-                    .with_validator(p.validator().cloned()),
+                    .with_validator(p.validator().cloned())
+                    .with_description(p.description())
+                    .with_deprecation(p.deprecated(), p.deprecation_reason())
+                    .with_db_name(p.db_name()),
                 );
             }
         };
@@ -355,6 +800,10 @@ fn generate_node_object(t: &Type) -> NodeType {
             ),
         );
 
+        if r.resolver().is_some() {
+            generate_arguments(&mut arguments, r.arguments());
+        }
+
         let mut p = Property::new(
             r.name().to_string(),
             match r.resolver() {
@@ -368,7 +817,10 @@ fn generate_node_object(t: &Type) -> NodeType {
             fmt_rel_object_name(t, &r),
         )
         .with_list(r.list())
-        .with_arguments(arguments);
+        .with_arguments(arguments)
+        .with_description(r.description())
+        .with_deprecation(r.deprecated(), r.deprecation_reason())
+        .with_db_name(r.db_type());
 
         if let Some(resolver) = r.resolver() {
             p = p.with_resolver(resolver);
@@ -378,6 +830,12 @@ fn generate_node_object(t: &Type) -> NodeType {
     });
 
     NodeType::new(t.name().to_string(), TypeKind::Object, props)
+        .with_description(t.description())
+        .with_db_label(t.db_label())
+        .with_partition_key(t.partition_key())
+        .with_id(t.id())
+        .with_validator(t.validator())
+        .with_triggers(t.triggers())
 }
 
 /// Takes a WG type and returns the name of the corresponding GqlNodeQueryInput
@@ -437,7 +895,11 @@ fn fmt_node_create_mutation_input_name(t: &Type) -> String {
 ///     owner: ProjectOwnerMutationInput
 /// }
 fn generate_node_create_mutation_input(t: &Type) -> NodeType {
-    let mut props = generate_props(t.props_as_slice(), false, false);
+    let mut props = generate_props(
+        t.props_as_slice(),
+        t.id().map_or(false, |id| id.client_supplied()),
+        false,
+    );
 
     t.rels().for_each(|r| {
         props.insert(
@@ -658,6 +1120,22 @@ fn fmt_node_read_endpoint_name(t: &Type) -> String {
     t.name().to_string()
 }
 
+/// Inserts the optional `partitionKey` argument of the endpoints of a WG type. Types that take
+/// their partition key from the request context have no such argument, so that a client cannot
+/// choose another partition.
+fn insert_partition_key_argument(arguments: &mut HashMap<String, Argument>, t: &Type) {
+    if !t.partition_key().map_or(false, |pk| pk.request_context()) {
+        arguments.insert(
+            "partitionKey".to_string(),
+            Argument::new(
+                "partitionKey".to_string(),
+                ArgumentKind::Optional,
+                "String".to_string(),
+            ),
+        );
+    }
+}
+
 /// Takes a WG type and returns a NodeType representing a GqlNodeReadEndpoint
 ///
 /// Format:
@@ -675,14 +1153,7 @@ fn generate_node_read_endpoint(t: &Type) -> Property {
             fmt_node_query_input_name(t),
         ),
     );
-    arguments.insert(
-        "partitionKey".to_string(),
-        Argument::new(
-            "partitionKey".to_string(),
-            ArgumentKind::Optional,
-            "String".to_string(),
-        ),
-    );
+    insert_partition_key_argument(&mut arguments, t);
 
     Property::new(
         fmt_node_read_endpoint_name(t),
@@ -691,6 +1162,11 @@ fn generate_node_read_endpoint(t: &Type) -> Property {
     )
     .with_list(true)
     .with_arguments(arguments)
+    .with_description(Some(&format!(
+        "Reads {} nodes that match the input query",
+        t.name()
+    )))
+    .with_deprecation(t.deprecated(), t.deprecation_reason())
 }
 
 /// Takes a WG type and returns the name of the corresponding GqlNodeCreateEndpoint
@@ -715,14 +1191,7 @@ fn generate_node_create_endpoint(t: &Type) -> Property {
             fmt_node_create_mutation_input_name(t),
         ),
     );
-    arguments.insert(
-        "partitionKey".to_string(),
-        Argument::new(
-            "partitionKey".to_string(),
-            ArgumentKind::Optional,
-            "String".to_string(),
-        ),
-    );
+    insert_partition_key_argument(&mut arguments, t);
 
     Property::new(
         fmt_node_create_endpoint_name(t),
@@ -730,6 +1199,8 @@ fn generate_node_create_endpoint(t: &Type) -> Property {
         t.name().to_string(),
     )
     .with_arguments(arguments)
+    .with_description(Some(&format!("Creates a {} node", t.name())))
+    .with_deprecation(t.deprecated(), t.deprecation_reason())
 }
 
 /// Takes a WG type and returns the name of the corresponding GqlNodeCreateEndpoint
@@ -754,14 +1225,7 @@ fn generate_node_update_endpoint(t: &Type) -> Property {
             fmt_node_update_input_name(t),
         ),
     );
-    arguments.insert(
-        "partitionKey".to_string(),
-        Argument::new(
-            "partitionKey".to_string(),
-            ArgumentKind::Optional,
-            "String".to_string(),
-        ),
-    );
+    insert_partition_key_argument(&mut arguments, t);
 
     Property::new(
         fmt_node_update_endpoint_name(t),
@@ -770,6 +1234,11 @@ fn generate_node_update_endpoint(t: &Type) -> Property {
     )
     .with_list(true)
     .with_arguments(arguments)
+    .with_description(Some(&format!(
+        "Updates {} nodes that match the input query",
+        t.name()
+    )))
+    .with_deprecation(t.deprecated(), t.deprecation_reason())
 }
 
 /// Takes a WG type and returns the name of the corresponding GqlNodeDeleteEndpoint
@@ -794,14 +1263,7 @@ fn generate_node_delete_endpoint(t: &Type) -> Property {
             fmt_node_delete_input_name(t),
         ),
     );
-    arguments.insert(
-        "partitionKey".to_string(),
-        Argument::new(
-            "partitionKey".to_string(),
-            ArgumentKind::Optional,
-            "String".to_string(),
-        ),
-    );
+    insert_partition_key_argument(&mut arguments, t);
 
     Property::new(
         fmt_node_delete_endpoint_name(t),
@@ -811,6 +1273,11 @@ fn generate_node_delete_endpoint(t: &Type) -> Property {
         "Int".to_string(),
     )
     .with_arguments(arguments)
+    .with_description(Some(&format!(
+        "Deletes {} nodes that match the input query, returning the number of nodes deleted",
+        t.name()
+    )))
+    .with_deprecation(t.deprecated(), t.deprecation_reason())
 }
 
 /// Takes a WG type and rel and returns the name of the corresponding GqlRelObject
@@ -849,7 +1316,9 @@ fn generate_rel_object(t: &Type, r: &Relationship) -> NodeType {
     let mut props = HashMap::new();
     props.insert(
         "id".to_string(),
-        Property::new("id".to_string(), PropertyKind::Scalar, "ID".to_string()).with_required(true),
+        Property::new("id".to_string(), PropertyKind::Scalar, "ID".to_string())
+            .with_required(true)
+            .with_description(Some("Identifier of the relationship")),
     );
 
     if !r.props_as_slice().is_empty() {
@@ -859,7 +1328,8 @@ fn generate_rel_object(t: &Type, r: &Relationship) -> NodeType {
                 "props".to_string(),
                 PropertyKind::Object,
                 fmt_rel_props_object_name(t, r),
-            ),
+            )
+            .with_description(Some("Properties of the relationship")),
         );
     }
     props.insert(
@@ -869,7 +1339,8 @@ fn generate_rel_object(t: &Type, r: &Relationship) -> NodeType {
             PropertyKind::Object,
             t.name().to_string(),
         )
-        .with_required(true),
+        .with_required(true)
+        .with_description(Some("Source node of the relationship")),
     );
     props.insert(
         "dst".to_string(),
@@ -878,9 +1349,18 @@ fn generate_rel_object(t: &Type, r: &Relationship) -> NodeType {
             PropertyKind::Union,
             fmt_rel_nodes_union_name(t, r),
         )
-        .with_required(true),
+        .with_required(true)
+        .with_description(Some("Destination node of the relationship")),
     );
     NodeType::new(fmt_rel_object_name(t, r), TypeKind::Rel, props)
+        .with_description(Some(r.description().unwrap_or(&format!(
+            "A {} relationship from a {} node",
+            r.name(),
+            t.name()
+        ))))
+        .with_partition_key(t.partition_key())
+        .with_validator(r.validator())
+        .with_triggers(r.triggers())
 }
 
 /// Takes a WG type and rel and returns the name of the corresponding GqlRelPropsObject
@@ -1613,14 +2093,7 @@ fn generate_rel_read_endpoint(t: &Type, r: &Relationship) -> Property {
             fmt_rel_query_input_name(t, r),
         ),
     );
-    arguments.insert(
-        "partitionKey".to_string(),
-        Argument::new(
-            "partitionKey".to_string(),
-            ArgumentKind::Optional,
-            "String".to_string(),
-        ),
-    );
+    insert_partition_key_argument(&mut arguments, t);
 
     Property::new(
         fmt_rel_read_endpoint_name(t, r),
@@ -1631,6 +2104,12 @@ fn generate_rel_read_endpoint(t: &Type, r: &Relationship) -> Property {
     )
     .with_list(true)
     .with_arguments(arguments)
+    .with_description(Some(&format!(
+        "Reads {} relationships from {} nodes that match the input query",
+        r.name(),
+        t.name()
+    )))
+    .with_deprecation(rel_deprecated(t, r), rel_deprecation_reason(t, r))
 }
 
 /// Takes a WG type and rel and returns the name of the corresponding GqlRelCreateEndpoint
@@ -1659,14 +2138,7 @@ fn generate_rel_create_endpoint(t: &Type, r: &Relationship) -> Property {
             fmt_rel_create_input_name(t, r),
         ),
     );
-    arguments.insert(
-        "partitionKey".to_string(),
-        Argument::new(
-            "partitionKey".to_string(),
-            ArgumentKind::Optional,
-            "String".to_string(),
-        ),
-    );
+    insert_partition_key_argument(&mut arguments, t);
 
     Property::new(
         fmt_rel_create_endpoint_name(t, r),
@@ -1678,6 +2150,12 @@ fn generate_rel_create_endpoint(t: &Type, r: &Relationship) -> Property {
     )
     .with_list(true)
     .with_arguments(arguments)
+    .with_description(Some(&format!(
+        "Creates {} relationships from {} nodes that match the input query",
+        r.name(),
+        t.name()
+    )))
+    .with_deprecation(rel_deprecated(t, r), rel_deprecation_reason(t, r))
 }
 
 /// Takes a WG type and rel and returns the name of the corresponding GqlRelUpdateEndpoint
@@ -1706,14 +2184,7 @@ fn generate_rel_update_endpoint(t: &Type, r: &Relationship) -> Property {
             fmt_rel_update_input_name(t, r),
         ),
     );
-    arguments.insert(
-        "partitionKey".to_string(),
-        Argument::new(
-            "partitionKey".to_string(),
-            ArgumentKind::Optional,
-            "String".to_string(),
-        ),
-    );
+    insert_partition_key_argument(&mut arguments, t);
 
     Property::new(
         fmt_rel_update_endpoint_name(t, r),
@@ -1725,6 +2196,12 @@ fn generate_rel_update_endpoint(t: &Type, r: &Relationship) -> Property {
     )
     .with_list(true)
     .with_arguments(arguments)
+    .with_description(Some(&format!(
+        "Updates {} relationships from {} nodes that match the input query",
+        r.name(),
+        t.name()
+    )))
+    .with_deprecation(rel_deprecated(t, r), rel_deprecation_reason(t, r))
 }
 
 /// Takes a WG type and rel and returns the name of the corresponding GqlRelDeleteEndpoint
@@ -1753,14 +2230,7 @@ fn generate_rel_delete_endpoint(t: &Type, r: &Relationship) -> Property {
             fmt_rel_delete_input_name(t, r),
         ),
     );
-    arguments.insert(
-        "partitionKey".to_string(),
-        Argument::new(
-            "partitionKey".to_string(),
-            ArgumentKind::Optional,
-            "String".to_string(),
-        ),
-    );
+    insert_partition_key_argument(&mut arguments, t);
 
     Property::new(
         fmt_rel_delete_endpoint_name(t, r),
@@ -1771,6 +2241,29 @@ fn generate_rel_delete_endpoint(t: &Type, r: &Relationship) -> Property {
         "Int".to_string(),
     )
     .with_arguments(arguments)
+    .with_description(Some(&format!(
+        "Deletes {} relationships from {} nodes that match the input query, returning the \
+        number of relationships deleted",
+        r.name(),
+        t.name()
+    )))
+    .with_deprecation(rel_deprecated(t, r), rel_deprecation_reason(t, r))
+}
+
+/// Takes a WG type and rel and returns true if the endpoints for the rel are deprecated, which
+/// is the case if either the rel or the type is deprecated
+fn rel_deprecated(t: &Type, r: &Relationship) -> bool {
+    r.deprecated() || t.deprecated()
+}
+
+/// Takes a WG type and rel and returns the reason that the endpoints for the rel are deprecated,
+/// preferring the reason given on the rel over the reason given on the type
+fn rel_deprecation_reason<'a>(t: &'a Type, r: &'a Relationship) -> Option<&'a str> {
+    if r.deprecated() {
+        r.deprecation_reason()
+    } else {
+        t.deprecation_reason()
+    }
 }
 
 /// Takes a WG Endpoint and returns a NodeType representing a root endpoint
@@ -1822,6 +2315,8 @@ fn generate_custom_endpoint(e: &Endpoint) -> Property {
         };
     }
 
+    generate_arguments(&mut arguments, e.arguments());
+
     Property::new(
         e.name().to_string(),
         PropertyKind::CustomResolver,
@@ -1839,6 +2334,8 @@ fn generate_custom_endpoint(e: &Endpoint) -> Property {
     .with_required(e.output().required())
     .with_list(e.output().list())
     .with_arguments(arguments)
+    .with_description(e.description())
+    .with_deprecation(e.deprecated(), e.deprecation_reason())
 }
 
 fn generate_custom_endpoint_input(t: &Type) -> NodeType {
@@ -1863,6 +2360,7 @@ fn generate_static_version_query() -> Property {
         PropertyKind::VersionQuery,
         "String".to_string(),
     )
+    .with_description(Some("Returns the version of the GraphQL service"))
 }
 
 /// Takes a WG config and returns a map of graphql schema components for model
@@ -2237,9 +2735,10 @@ mod tests {
     };
     use crate::engine::config::{
         mock_config, mock_endpoint_one, mock_endpoint_three, mock_endpoint_two,
-        mock_endpoints_filter, mock_project_config, mock_project_type,
+        mock_endpoints_filter, mock_project_config, mock_project_type, Configuration, PartitionKey,
     };
     use std::collections::HashMap;
+    use std::convert::TryFrom;
     use std::sync::Arc;
 
     /// Passes if a new Info struct is created
@@ -2277,6 +2776,36 @@ mod tests {
         assert!(p.arguments.is_empty());
     }
 
+    /// Passes if the endpoints of a type that takes its partition key from the request context
+    /// have no `partitionKey` argument, and those of other types do
+    #[test]
+    fn partition_key_argument() {
+        let project_type = mock_project_type();
+        let context_type = mock_project_type()
+            .with_partition_key(PartitionKey::new("tenant".to_string()).with_request_context(true));
+        let owner = project_type.rels().find(|r| r.name() == "owner").unwrap();
+
+        for (t, expected) in &[(&project_type, true), (&context_type, false)] {
+            for p in &[
+                generate_node_read_endpoint(t),
+                generate_node_create_endpoint(t),
+                generate_node_update_endpoint(t),
+                generate_node_delete_endpoint(t),
+                generate_rel_read_endpoint(t, owner),
+                generate_rel_create_endpoint(t, owner),
+                generate_rel_update_endpoint(t, owner),
+                generate_rel_delete_endpoint(t, owner),
+            ] {
+                assert_eq!(
+                    *expected,
+                    p.arguments.contains_key("partitionKey"),
+                    "endpoint: {}",
+                    p.name
+                );
+            }
+        }
+    }
+
     /// Passes if the right schema elements are generated
     #[test]
     fn test_fmt_node_object_name() {
@@ -4264,6 +4793,117 @@ mod tests {
         assert!(root_node.is_err());
     }
 
+    /// Passes if an Info generated from a configuration describes the same schema as
+    /// generate_schema
+    #[test]
+    fn info_from_config() {
+        let config = mock_config();
+        let info = Info::from(&config);
+
+        assert!(info.name() == "Query");
+        assert!(*info.type_defs() == generate_schema(&config));
+        assert!(info.type_def().unwrap().type_kind() == &TypeKind::Object);
+        assert!(info.type_def_by_name("Project").is_ok());
+        assert!(info.type_def_by_name("NotAType").is_err());
+    }
+
+    /// Passes if the input type of a generated endpoint can be looked up through the public
+    /// schema model
+    #[test]
+    fn property_input_type_definition() {
+        let info = Info::from(&mock_config());
+        let project_create = info
+            .type_def_by_name("Mutation")
+            .unwrap()
+            .property(&fmt_node_create_endpoint_name(&mock_project_type()))
+            .unwrap();
+
+        assert!(project_create.kind() == &PropertyKind::NodeCreateMutation);
+        assert!(project_create.argument("input").unwrap().kind() == &ArgumentKind::Required);
+        assert!(project_create.argument("notanarg").is_err());
+        assert!(
+            project_create
+                .input_type_definition(&info)
+                .unwrap()
+                .type_name()
+                == fmt_node_create_mutation_input_name(&mock_project_type())
+        );
+    }
+
+    /// Passes if descriptions and deprecations in the configuration are carried into the
+    /// schema, and generated endpoints are described and deprecated along with their types
+    #[test]
+    fn description_and_deprecation() {
+        let config = Configuration::try_from(
+            "
+version: 1
+model:
+  - name: Project
+    description: A software project
+    props:
+      - name: name
+        type: String
+        description: Name of the project
+      - name: status
+        type: String
+        deprecated: true
+        deprecationReason: Use state instead
+    rels:
+      - name: owner
+        nodes: [User]
+        description: User that owns the project
+  - name: User
+    deprecated: true
+    deprecationReason: Users are going away
+    props: []
+endpoints:
+  - name: ProjectCount
+    class: Query
+    description: Counts projects
+    deprecated: true
+    input: ~
+    output:
+      type: Int
+",
+        )
+        .unwrap();
+        let info = Info::from(&config);
+
+        let project = info.type_def_by_name("Project").unwrap();
+        assert_eq!(project.description(), Some("A software project"));
+        let name = project.property("name").unwrap();
+        assert_eq!(name.description(), Some("Name of the project"));
+        assert!(!name.deprecated());
+        let status = project.property("status").unwrap();
+        assert!(status.deprecated());
+        assert_eq!(status.deprecation_reason(), Some("Use state instead"));
+        assert_eq!(
+            project.property("owner").unwrap().description(),
+            Some("User that owns the project")
+        );
+        assert_eq!(
+            info.type_def_by_name("ProjectOwnerRel")
+                .unwrap()
+                .description(),
+            Some("User that owns the project")
+        );
+
+        let query = info.type_def_by_name("Query").unwrap();
+        let read_project = query.property("Project").unwrap();
+        assert_eq!(
+            read_project.description(),
+            Some("Reads Project nodes that match the input query")
+        );
+        assert!(!read_project.deprecated());
+        let read_user = query.property("User").unwrap();
+        assert!(read_user.deprecated());
+        assert_eq!(read_user.deprecation_reason(), Some("Users are going away"));
+        let count = query.property("ProjectCount").unwrap();
+        assert_eq!(count.description(), Some("Counts projects"));
+        assert!(count.deprecated());
+        assert_eq!(count.deprecation_reason(), None);
+    }
+
     /// Passes if Info implements the Send trait
     #[test]
     fn test_info_send() {
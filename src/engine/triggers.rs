@@ -0,0 +1,466 @@
+//! This module provides types for lifecycle triggers, run within the transaction of a mutation
+//! before or after a node or relationship is created, updated, or deleted. Triggers run only for
+//! the nodes and relationships written by the top level of a mutation, not for those written
+//! through its nested input.
+
+use crate::engine::config::TriggerEvent;
+use crate::engine::context::RequestContext;
+use crate::engine::database::{NodeRecord, RelRecord};
+use crate::engine::validators::ValidationReader;
+use crate::engine::value::Value;
+use crate::Error;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// A node or relationship passed to a trigger as its old or new state
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum TriggerRecord<'a> {
+    Node(&'a NodeRecord),
+    Rel(&'a RelRecord),
+}
+
+/// Reads from and writes to the database within the transaction of the mutation running a
+/// trigger
+pub(crate) trait TriggerWriter: ValidationReader {
+    /// Creates a node of a type, from an input in the form of the input of the generated create
+    /// mutation for the type
+    fn create_node(&mut self, type_name: &str, input: Value) -> Result<NodeRecord, Error>;
+
+    /// Updates the nodes of a type, from an input in the form of the input of the generated
+    /// update mutation for the type
+    fn update_nodes(&mut self, type_name: &str, input: Value) -> Result<Vec<NodeRecord>, Error>;
+
+    /// Deletes the nodes of a type, from an input in the form of the input of the generated
+    /// delete mutation for the type
+    fn delete_nodes(&mut self, type_name: &str, input: Value) -> Result<i32, Error>;
+}
+
+/// Passed to a trigger function, declared with the `triggers` field of a type or relationship in
+/// the configuration. Provides the lifecycle event, the old and new state of the node or
+/// relationship, the input of the mutation, the request context, and read and write access to
+/// the database within the transaction of the mutation.
+///
+/// Writes made by a trigger do not run further triggers or extension hooks.
+pub struct TriggerContext<'a, RequestCtx>
+where
+    RequestCtx: RequestContext,
+{
+    event: TriggerEvent,
+    type_name: &'a str,
+    rel_name: Option<&'a str>,
+    input: Option<&'a Value>,
+    old: Option<TriggerRecord<'a>>,
+    new: Option<TriggerRecord<'a>>,
+    request_ctx: Option<&'a RequestCtx>,
+    writer: &'a mut dyn TriggerWriter,
+}
+
+impl<'a, RequestCtx> TriggerContext<'a, RequestCtx>
+where
+    RequestCtx: RequestContext,
+{
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn new(
+        event: TriggerEvent,
+        type_name: &'a str,
+        rel_name: Option<&'a str>,
+        input: Option<&'a Value>,
+        old: Option<TriggerRecord<'a>>,
+        new: Option<TriggerRecord<'a>>,
+        request_ctx: Option<&'a RequestCtx>,
+        writer: &'a mut dyn TriggerWriter,
+    ) -> TriggerContext<'a, RequestCtx> {
+        TriggerContext {
+            event,
+            type_name,
+            rel_name,
+            input,
+            old,
+            new,
+            request_ctx,
+            writer,
+        }
+    }
+
+    /// Returns the lifecycle event that ran the trigger
+    pub fn event(&self) -> TriggerEvent {
+        self.event
+    }
+
+    /// Returns the name of the type of the node. For a relationship trigger, this is the type of
+    /// the source node of the relationship.
+    pub fn type_name(&self) -> &str {
+        self.type_name
+    }
+
+    /// Returns the name of the relationship, or None for a type trigger
+    pub fn rel_name(&self) -> Option<&str> {
+        self.rel_name
+    }
+
+    /// Returns the input of the mutation, for triggers run before a node or relationship is
+    /// created, updated, or deleted. It is None for triggers run afterwards.
+    pub fn input(&self) -> Option<&Value> {
+        self.input
+    }
+
+    /// Returns the node as stored before an update or delete. It is None for create events, and
+    /// for relationship triggers.
+    pub fn old_node(&self) -> Option<&NodeRecord> {
+        match self.old {
+            Some(TriggerRecord::Node(n)) => Some(n),
+            _ => None,
+        }
+    }
+
+    /// Returns the node as stored after a create or update. It is None for events run before the
+    /// node is written, for delete events, and for relationship triggers.
+    pub fn new_node(&self) -> Option<&NodeRecord> {
+        match self.new {
+            Some(TriggerRecord::Node(n)) => Some(n),
+            _ => None,
+        }
+    }
+
+    /// Returns the relationship as stored before an update or delete. It is None for create
+    /// events, and for type triggers.
+    pub fn old_rel(&self) -> Option<&RelRecord> {
+        match self.old {
+            Some(TriggerRecord::Rel(r)) => Some(r),
+            _ => None,
+        }
+    }
+
+    /// Returns the relationship as stored after a create or update. It is None for events run
+    /// before the relationship is written, for delete events, and for type triggers.
+    pub fn new_rel(&self) -> Option<&RelRecord> {
+        match self.new {
+            Some(TriggerRecord::Rel(r)) => Some(r),
+            _ => None,
+        }
+    }
+
+    /// Returns the request context of the request running the trigger
+    pub fn request_context(&self) -> Option<&RequestCtx> {
+        self.request_ctx
+    }
+
+    /// Reads the nodes of type `type_name` that match `input`, which takes the same form as the
+    /// input argument of the generated query for the type.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`] variant [`SchemaItemNotFound`] if there is no generated query for the
+    /// type, or any error returned by the database back-end.
+    ///
+    /// [`Error`]: ../../error/enum.Error.html
+    /// [`SchemaItemNotFound`]: ../../error/enum.Error.html#variant.SchemaItemNotFound
+    pub fn read_nodes(
+        &mut self,
+        type_name: &str,
+        input: Option<Value>,
+    ) -> Result<Vec<NodeRecord>, Error> {
+        self.writer.read_nodes(type_name, input)
+    }
+
+    /// Reads the `rel_name` relationships from nodes of type `type_name` that match `input`,
+    /// which takes the same form as the input argument of the generated query for the
+    /// relationship.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`] variant [`SchemaItemNotFound`] if there is no generated query for the
+    /// relationship, or any error returned by the database back-end.
+    ///
+    /// [`Error`]: ../../error/enum.Error.html
+    /// [`SchemaItemNotFound`]: ../../error/enum.Error.html#variant.SchemaItemNotFound
+    pub fn read_rels(
+        &mut self,
+        type_name: &str,
+        rel_name: &str,
+        input: Option<Value>,
+    ) -> Result<Vec<RelRecord>, Error> {
+        self.writer.read_rels(type_name, rel_name, input)
+    }
+
+    /// Creates a node of type `type_name` from `input`, which takes the same form as the input
+    /// argument of the generated create mutation for the type.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`] variant [`SchemaItemNotFound`] if there is no generated create
+    /// mutation for the type, or any error returned by the database back-end.
+    ///
+    /// [`Error`]: ../../error/enum.Error.html
+    /// [`SchemaItemNotFound`]: ../../error/enum.Error.html#variant.SchemaItemNotFound
+    pub fn create_node(&mut self, type_name: &str, input: Value) -> Result<NodeRecord, Error> {
+        self.writer.create_node(type_name, input)
+    }
+
+    /// Updates the nodes of type `type_name` from `input`, which takes the same form as the input
+    /// argument of the generated update mutation for the type.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`] variant [`SchemaItemNotFound`] if there is no generated update
+    /// mutation for the type, or any error returned by the database back-end.
+    ///
+    /// [`Error`]: ../../error/enum.Error.html
+    /// [`SchemaItemNotFound`]: ../../error/enum.Error.html#variant.SchemaItemNotFound
+    pub fn update_nodes(
+        &mut self,
+        type_name: &str,
+        input: Value,
+    ) -> Result<Vec<NodeRecord>, Error> {
+        self.writer.update_nodes(type_name, input)
+    }
+
+    /// Deletes the nodes of type `type_name` matching `input`, which takes the same form as the
+    /// input argument of the generated delete mutation for the type, and returns the number of
+    /// nodes deleted.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`] variant [`SchemaItemNotFound`] if there is no generated delete
+    /// mutation for the type, or any error returned by the database back-end.
+    ///
+    /// [`Error`]: ../../error/enum.Error.html
+    /// [`SchemaItemNotFound`]: ../../error/enum.Error.html#variant.SchemaItemNotFound
+    pub fn delete_nodes(&mut self, type_name: &str, input: Value) -> Result<i32, Error> {
+        self.writer.delete_nodes(type_name, input)
+    }
+}
+
+/// Type alias for a trigger function, named in the `triggers` field of a type or relationship in
+/// the configuration. A trigger runs within the transaction of the mutation, so an error returned
+/// by the trigger rolls back the mutation, along with any writes made by the trigger. Cosmos DB
+/// and Gremlin have no transactions, so on those back-ends the writes made before the error are
+/// kept.
+///
+/// A trigger runs only for the nodes and relationships written by the top level of a mutation.
+/// For example, an `Issue` created through the nested input of `ProjectCreate` runs neither the
+/// triggers of the `Issue` type nor those of the `issues` relationship.
+///
+/// # Examples
+///
+/// ```rust
+/// # use serde_json::json;
+/// # use std::convert::TryFrom;
+/// # use std::sync::Arc;
+/// # use warpgrapher::Error;
+/// # use warpgrapher::engine::triggers::{TriggerContext, TriggerFunc};
+/// # use warpgrapher::engine::value::Value;
+///
+/// fn log_activity(context: &mut TriggerContext<()>) -> Result<(), Error> {
+///     let action = format!("{:?}", context.event());
+///     let input = Value::try_from(json!({"action": action, "target": context.type_name()}))?;
+///     context.create_node("Activity", input)?;
+///     Ok(())
+/// }
+///
+/// let f: Arc<TriggerFunc<()>> = Arc::new(log_activity);
+/// ```
+pub type TriggerFunc<RequestCtx> =
+    dyn Fn(&mut TriggerContext<RequestCtx>) -> Result<(), Error> + Send + Sync;
+
+/// Type alias for a collection of trigger functions, keyed by the name used in the configuration
+/// to refer to each one
+///
+/// # Examples
+///
+/// ```rust
+/// # use serde_json::json;
+/// # use std::convert::TryFrom;
+/// # use std::sync::Arc;
+/// # use warpgrapher::Error;
+/// # use warpgrapher::engine::triggers::{TriggerContext, Triggers};
+/// # use warpgrapher::engine::value::Value;
+///
+/// fn count_issues(context: &mut TriggerContext<()>) -> Result<(), Error> {
+///     let id = match context.new_rel().or_else(|| context.old_rel()) {
+///         Some(r) => String::try_from(r.src().0.clone())?,
+///         None => return Ok(()),
+///     };
+///     let input = Value::try_from(json!({"src": {"Project": {"id": id}}}))?;
+///     let count = context.read_rels("Project", "issues", Some(input))?.len();
+///
+///     let update = Value::try_from(json!({
+///         "$MATCH": {"id": id},
+///         "$SET": {"issueCount": count}
+///     }))?;
+///     context.update_nodes("Project", update)?;
+///     Ok(())
+/// }
+///
+/// let mut triggers = Triggers::<()>::new();
+/// triggers.insert("CountIssues".to_string(), Arc::new(count_issues));
+/// ```
+pub type Triggers<RequestCtx> = HashMap<String, Arc<TriggerFunc<RequestCtx>>>;
+
+#[cfg(test)]
+mod tests {
+    use super::{TriggerContext, TriggerFunc, TriggerRecord, TriggerWriter, Triggers};
+    use crate::engine::config::TriggerEvent;
+    use crate::engine::database::{NodeRecord, RelRecord};
+    use crate::engine::validators::ValidationReader;
+    use crate::engine::value::Value;
+    use crate::Error;
+    use maplit::hashmap;
+    use std::sync::Arc;
+
+    /// Records the writes made by a trigger
+    #[derive(Default)]
+    struct MockWriter {
+        writes: Vec<String>,
+    }
+
+    impl ValidationReader for MockWriter {
+        fn read_nodes(
+            &mut self,
+            _type_name: &str,
+            _input: Option<Value>,
+        ) -> Result<Vec<NodeRecord>, Error> {
+            Ok(Vec::new())
+        }
+
+        fn read_rels(
+            &mut self,
+            _type_name: &str,
+            _rel_name: &str,
+            _input: Option<Value>,
+        ) -> Result<Vec<RelRecord>, Error> {
+            Ok(Vec::new())
+        }
+    }
+
+    impl TriggerWriter for MockWriter {
+        fn create_node(&mut self, type_name: &str, _input: Value) -> Result<NodeRecord, Error> {
+            self.writes.push("create ".to_string() + type_name);
+            Ok(issue("created"))
+        }
+
+        fn update_nodes(
+            &mut self,
+            type_name: &str,
+            _input: Value,
+        ) -> Result<Vec<NodeRecord>, Error> {
+            self.writes.push("update ".to_string() + type_name);
+            Ok(Vec::new())
+        }
+
+        fn delete_nodes(&mut self, type_name: &str, _input: Value) -> Result<i32, Error> {
+            self.writes.push("delete ".to_string() + type_name);
+            Ok(0)
+        }
+    }
+
+    fn issue(title: &str) -> NodeRecord {
+        NodeRecord::new(
+            "Issue".to_string(),
+            hashmap! { "title".to_string() => Value::String(title.to_string()) },
+        )
+    }
+
+    fn log_activity(context: &mut TriggerContext<()>) -> Result<(), Error> {
+        let action = format!("{:?}", context.event());
+        context.create_node("Activity", Value::String(action))?;
+        Ok(())
+    }
+
+    /// Passes if a trigger context returns the old and new state of a node, and no relationship
+    #[test]
+    fn node_trigger_context() {
+        let (old, new) = (issue("a"), issue("b"));
+        let mut writer = MockWriter::default();
+        let context = TriggerContext::<()>::new(
+            TriggerEvent::AfterUpdate,
+            "Issue",
+            None,
+            None,
+            Some(TriggerRecord::Node(&old)),
+            Some(TriggerRecord::Node(&new)),
+            None,
+            &mut writer,
+        );
+
+        assert_eq!(TriggerEvent::AfterUpdate, context.event());
+        assert_eq!("Issue", context.type_name());
+        assert!(context.rel_name().is_none());
+        assert!(context.input().is_none());
+        assert_eq!(
+            Some(&Value::String("a".to_string())),
+            context.old_node().and_then(|n| n.fields().get("title"))
+        );
+        assert_eq!(
+            Some(&Value::String("b".to_string())),
+            context.new_node().and_then(|n| n.fields().get("title"))
+        );
+        assert!(context.old_rel().is_none());
+        assert!(context.new_rel().is_none());
+        assert!(context.request_context().is_none());
+    }
+
+    /// Passes if a trigger context returns the new state of a relationship, and no node
+    #[test]
+    fn rel_trigger_context() {
+        let rel = RelRecord::new(
+            Value::String("5678".to_string()),
+            None,
+            None,
+            (Value::String("1234".to_string()), "Project".to_string()),
+            (Value::String("4321".to_string()), "Issue".to_string()),
+        );
+        let input = Value::Null;
+        let mut writer = MockWriter::default();
+        let context = TriggerContext::<()>::new(
+            TriggerEvent::BeforeCreate,
+            "Project",
+            Some("issues"),
+            Some(&input),
+            None,
+            Some(TriggerRecord::Rel(&rel)),
+            None,
+            &mut writer,
+        );
+
+        assert_eq!(Some("issues"), context.rel_name());
+        assert_eq!(Some(&Value::Null), context.input());
+        assert_eq!(
+            &Value::String("5678".to_string()),
+            context.new_rel().unwrap().id()
+        );
+        assert!(context.old_rel().is_none());
+        assert!(context.new_node().is_none());
+    }
+
+    /// Passes if the writes of a trigger held in the collection of triggers are passed to the
+    /// database writer
+    #[test]
+    fn trigger_writes() {
+        let mut triggers = Triggers::<()>::new();
+        triggers.insert("LogActivity".to_string(), Arc::new(log_activity));
+        let trigger: Arc<TriggerFunc<()>> = triggers.get("LogActivity").unwrap().clone();
+
+        let mut writer = MockWriter::default();
+        {
+            let mut context = TriggerContext::<()>::new(
+                TriggerEvent::BeforeDelete,
+                "Issue",
+                None,
+                None,
+                None,
+                None,
+                None,
+                &mut writer,
+            );
+            trigger(&mut context).unwrap();
+            assert!(context.read_nodes("Issue", None).unwrap().is_empty());
+            context.update_nodes("Issue", Value::Null).unwrap();
+            context.delete_nodes("Issue", Value::Null).unwrap();
+        }
+        assert_eq!(
+            vec!["create Activity", "update Issue", "delete Issue"],
+            writer.writes
+        );
+    }
+}
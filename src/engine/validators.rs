@@ -1,14 +1,22 @@
 //! This module provides types for custom input validation
 
+use crate::engine::context::RequestContext;
+use crate::engine::database::{NodeRecord, RelRecord};
+use crate::engine::resolvers::block_on;
 use crate::engine::value::Value;
 use crate::Error;
 use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
 
-/// Type alias for a custom function used to validate the input to a resolver
+/// Type alias for a custom function used to validate the input to a resolver. A validator may be
+/// a plain function, or a closure that captures state, such as a configured limit.
 ///
 /// # Examples
 ///
 /// ```rust
+/// # use std::sync::Arc;
 /// # use warpgrapher::Error;
 /// # use warpgrapher::engine::validators::{ValidatorFunc, Validators};
 /// # use warpgrapher::engine::value::Value;
@@ -34,15 +42,36 @@ use std::collections::HashMap;
 ///     }
 /// }
 ///
-/// let f: Box<ValidatorFunc> = Box::new(name_validator);
+/// let f: Arc<ValidatorFunc> = Arc::new(name_validator);
+///
+/// let max_len = 64;
+/// let g: Arc<ValidatorFunc> = Arc::new(move |value: &Value| match value {
+///     Value::String(s) if s.len() > max_len => Err(Error::ValidationFailed {
+///         message: "Name too long.".to_string(),
+///     }),
+///     _ => Ok(()),
+/// });
 /// ```
-pub type ValidatorFunc = fn(&Value) -> Result<(), Error>;
+pub type ValidatorFunc = dyn Fn(&Value) -> Result<(), Error> + Send + Sync;
+
+/// Type alias for the future returned by an [`AsyncValidatorFunc`]
+///
+/// [`AsyncValidatorFunc`]: ./type.AsyncValidatorFunc.html
+pub type ValidatorFuture<'a> = Pin<Box<dyn Future<Output = Result<(), Error>> + 'a>>;
+
+/// Type alias for asynchronous custom validator functions, for validators that need to await,
+/// for example, a call to another service. An asynchronous validator is registered by wrapping it
+/// with [`async_validator`].
+///
+/// [`async_validator`]: ./fn.async_validator.html
+pub type AsyncValidatorFunc = dyn for<'a> Fn(&'a Value) -> ValidatorFuture<'a> + Send + Sync;
 
 /// Type alias for a custom function used to validate the input to a resolver
 ///
 /// Examples
 ///
 /// ```rust
+/// # use std::sync::Arc;
 /// # use warpgrapher::engine::validators::{ValidatorFunc, Validators};
 /// # use warpgrapher::engine::value::Value;
 /// # use warpgrapher::Error;
@@ -69,6 +98,410 @@ pub type ValidatorFunc = fn(&Value) -> Result<(), Error>;
 /// }
 ///
 /// let mut validators = Validators::new();
-/// validators.insert("name_validator".to_string(), Box::new(name_validator));
+/// validators.insert("name_validator".to_string(), Arc::new(name_validator));
+/// ```
+pub type Validators = HashMap<String, Arc<ValidatorFunc>>;
+
+/// Wraps an asynchronous custom validator as a [`ValidatorFunc`], so that it can be added to the
+/// [`Validators`]. The future returned by the validator is run to completion on a runtime of its
+/// own, as for the asynchronous resolvers wrapped by [`async_resolver`].
+///
+/// [`async_resolver`]: ../resolvers/fn.async_resolver.html
+/// [`ValidatorFunc`]: ./type.ValidatorFunc.html
+/// [`Validators`]: ./type.Validators.html
+///
+/// # Examples
+///
+/// ```rust
+/// # use warpgrapher::engine::validators::{async_validator, Validators};
+/// # use warpgrapher::engine::value::Value;
+/// # use warpgrapher::Error;
+///
+/// async fn name_validator(value: &Value) -> Result<(), Error> {
+///     // await a check against another service here
+///     Ok(())
+/// }
+///
+/// let mut validators = Validators::new();
+/// validators.insert(
+///     "NameValidator".to_string(),
+///     async_validator(|value| Box::pin(name_validator(value))),
+/// );
+/// ```
+pub fn async_validator<F>(f: F) -> Arc<ValidatorFunc>
+where
+    F: for<'a> Fn(&'a Value) -> ValidatorFuture<'a> + Send + Sync + 'static,
+{
+    Arc::new(move |value: &Value| block_on(f(value))?)
+}
+
+/// The kind of operation whose input is passed to a context-aware validator
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Operation {
+    /// A node or relationship is being created
+    Create,
+
+    /// A node or relationship is being updated
+    Update,
+}
+
+/// Reads from the database within the transaction of the mutation being validated
+pub(crate) trait ValidationReader {
+    /// Reads the nodes of a type that match an input, in the form of the input of the generated
+    /// query for the type
+    fn read_nodes(
+        &mut self,
+        type_name: &str,
+        input: Option<Value>,
+    ) -> Result<Vec<NodeRecord>, Error>;
+
+    /// Reads the relationships of a type that match an input, in the form of the input of the
+    /// generated query for the relationship
+    fn read_rels(
+        &mut self,
+        type_name: &str,
+        rel_name: &str,
+        input: Option<Value>,
+    ) -> Result<Vec<RelRecord>, Error>;
+}
+
+/// Passed to a context-aware validator, declared with the `validator` field of a type or
+/// relationship in the configuration. Provides the input being validated, along with the kind of
+/// operation, the node already stored in the database where there is one, the request context,
+/// and read access to the database within the transaction of the mutation.
+pub struct ValidationContext<'a, RequestCtx>
+where
+    RequestCtx: RequestContext,
+{
+    operation: Operation,
+    type_name: &'a str,
+    rel_name: Option<&'a str>,
+    input: &'a Value,
+    existing: Option<&'a NodeRecord>,
+    request_ctx: Option<&'a RequestCtx>,
+    reader: &'a mut dyn ValidationReader,
+}
+
+impl<'a, RequestCtx> ValidationContext<'a, RequestCtx>
+where
+    RequestCtx: RequestContext,
+{
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn new(
+        operation: Operation,
+        type_name: &'a str,
+        rel_name: Option<&'a str>,
+        input: &'a Value,
+        existing: Option<&'a NodeRecord>,
+        request_ctx: Option<&'a RequestCtx>,
+        reader: &'a mut dyn ValidationReader,
+    ) -> ValidationContext<'a, RequestCtx> {
+        ValidationContext {
+            operation,
+            type_name,
+            rel_name,
+            input,
+            existing,
+            request_ctx,
+            reader,
+        }
+    }
+
+    /// Returns whether the input creates or updates the node or relationship
+    pub fn operation(&self) -> Operation {
+        self.operation
+    }
+
+    /// Returns the name of the type being validated. For a relationship validator, this is the
+    /// type of the source node of the relationship.
+    pub fn type_name(&self) -> &str {
+        self.type_name
+    }
+
+    /// Returns the name of the relationship being validated, or None for a type validator
+    pub fn rel_name(&self) -> Option<&str> {
+        self.rel_name
+    }
+
+    /// Returns the input being validated. For a node, this is the create input, or the `$SET`
+    /// input of an update. For a relationship, this is the create or update input of the
+    /// relationship, with its `props` and its `dst` or `src`.
+    pub fn input(&self) -> &Value {
+        self.input
+    }
+
+    /// Returns the node already stored in the database. For a type validator, this is the node
+    /// being updated, and the validator is called once for each node matched by an update
+    /// mutation. For a relationship validator, this is the source node matched by a create
+    /// mutation for the relationship. It is None when the node is being created, and for updates
+    /// nested in the input of a relationship.
+    pub fn existing(&self) -> Option<&NodeRecord> {
+        self.existing
+    }
+
+    /// Returns the request context of the request being validated
+    pub fn request_context(&self) -> Option<&RequestCtx> {
+        self.request_ctx
+    }
+
+    /// Reads the nodes of type `type_name` that match `input`, which takes the same form as the
+    /// input argument of the generated query for the type. The nodes are read within the
+    /// transaction of the mutation being validated.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`] variant [`SchemaItemNotFound`] if there is no generated query for the
+    /// type, or any error returned by the database back-end.
+    ///
+    /// [`Error`]: ../../error/enum.Error.html
+    /// [`SchemaItemNotFound`]: ../../error/enum.Error.html#variant.SchemaItemNotFound
+    pub fn read_nodes(
+        &mut self,
+        type_name: &str,
+        input: Option<Value>,
+    ) -> Result<Vec<NodeRecord>, Error> {
+        self.reader.read_nodes(type_name, input)
+    }
+
+    /// Reads the `rel_name` relationships from nodes of type `type_name` that match `input`,
+    /// which takes the same form as the input argument of the generated query for the
+    /// relationship. The relationships are read within the transaction of the mutation being
+    /// validated.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`] variant [`SchemaItemNotFound`] if there is no generated query for the
+    /// relationship, or any error returned by the database back-end.
+    ///
+    /// [`Error`]: ../../error/enum.Error.html
+    /// [`SchemaItemNotFound`]: ../../error/enum.Error.html#variant.SchemaItemNotFound
+    pub fn read_rels(
+        &mut self,
+        type_name: &str,
+        rel_name: &str,
+        input: Option<Value>,
+    ) -> Result<Vec<RelRecord>, Error> {
+        self.reader.read_rels(type_name, rel_name, input)
+    }
+}
+
+/// Type alias for a context-aware validator, declared with the `validator` field of a type or
+/// relationship in the configuration. Unlike a [`ValidatorFunc`] on a property, it is passed a
+/// [`ValidationContext`], so it can check rules across fields, the stored node, the request
+/// context, and other data in the database.
+///
+/// [`ValidationContext`]: ./struct.ValidationContext.html
+/// [`ValidatorFunc`]: ./type.ValidatorFunc.html
+///
+/// # Examples
+///
+/// ```rust
+/// # use std::sync::Arc;
+/// # use warpgrapher::Error;
+/// # use warpgrapher::engine::validators::{ContextValidatorFunc, ValidationContext};
+/// # use warpgrapher::engine::value::Value;
+///
+/// fn project_dates(context: &mut ValidationContext<()>) -> Result<(), Error> {
+///     let field = |name: &str| match context.input() {
+///         Value::Map(m) => m.get(name).cloned(),
+///         _ => None,
+///     }
+///     .or_else(|| context.existing().and_then(|n| n.fields().get(name).cloned()));
+///
+///     match (field("startDate"), field("endDate")) {
+///         (Some(Value::String(start)), Some(Value::String(end))) if end < start => {
+///             Err(Error::ValidationFailed {
+///                 message: "endDate must be after startDate.".to_string(),
+///             })
+///         }
+///         _ => Ok(()),
+///     }
+/// }
+///
+/// let f: Arc<ContextValidatorFunc<()>> = Arc::new(project_dates);
 /// ```
-pub type Validators = HashMap<String, Box<ValidatorFunc>>;
+pub type ContextValidatorFunc<RequestCtx> =
+    dyn Fn(&mut ValidationContext<RequestCtx>) -> Result<(), Error> + Send + Sync;
+
+/// Type alias for a collection of context-aware validators, keyed by the name used in the
+/// configuration to refer to each one
+///
+/// # Examples
+///
+/// ```rust
+/// # use serde_json::json;
+/// # use std::convert::TryFrom;
+/// # use std::sync::Arc;
+/// # use warpgrapher::Error;
+/// # use warpgrapher::engine::validators::{ContextValidators, ValidationContext};
+/// # use warpgrapher::engine::value::Value;
+///
+/// fn max_owners(context: &mut ValidationContext<()>) -> Result<(), Error> {
+///     let id = match context.existing().and_then(|n| n.fields().get("id")) {
+///         Some(Value::String(id)) => Some(id.clone()),
+///         _ => None,
+///     };
+///     if let Some(id) = id {
+///         let input = Value::try_from(json!({"src": {"Project": {"id": id}}}))?;
+///         let owners = context.read_rels("Project", "owners", Some(input))?;
+///         if owners.len() >= 5 {
+///             return Err(Error::ValidationFailed {
+///                 message: "A project has at most 5 owners.".to_string(),
+///             });
+///         }
+///     }
+///     Ok(())
+/// }
+///
+/// let mut validators = ContextValidators::<()>::new();
+/// validators.insert("MaxOwners".to_string(), Arc::new(max_owners));
+/// ```
+pub type ContextValidators<RequestCtx> = HashMap<String, Arc<ContextValidatorFunc<RequestCtx>>>;
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        async_validator, ContextValidators, Operation, ValidationContext, ValidationReader,
+        Validators,
+    };
+    use crate::engine::database::{NodeRecord, RelRecord};
+    use crate::engine::value::Value;
+    use crate::Error;
+    use maplit::hashmap;
+    use std::sync::Arc;
+
+    /// Records the reads made by a validator and returns the stored node for each one
+    struct MockReader {
+        reads: Vec<String>,
+        stored: NodeRecord,
+    }
+
+    impl ValidationReader for MockReader {
+        fn read_nodes(
+            &mut self,
+            type_name: &str,
+            _input: Option<Value>,
+        ) -> Result<Vec<NodeRecord>, Error> {
+            self.reads.push(type_name.to_string());
+            Ok(vec![self.stored.clone()])
+        }
+
+        fn read_rels(
+            &mut self,
+            type_name: &str,
+            rel_name: &str,
+            _input: Option<Value>,
+        ) -> Result<Vec<RelRecord>, Error> {
+            self.reads.push(type_name.to_string() + "." + rel_name);
+            Ok(Vec::new())
+        }
+    }
+
+    fn mock_reader() -> MockReader {
+        MockReader {
+            reads: Vec::new(),
+            stored: NodeRecord::new(
+                "Project".to_string(),
+                hashmap! { "name".to_string() => Value::String("warpgrapher".to_string()) },
+            ),
+        }
+    }
+
+    fn unique_name(context: &mut ValidationContext<()>) -> Result<(), Error> {
+        if context.read_nodes("Project", None)?.is_empty()
+            && context.operation() == Operation::Create
+        {
+            Ok(())
+        } else {
+            Err(Error::ValidationFailed {
+                message: "Duplicate project.".to_string(),
+            })
+        }
+    }
+
+    /// Passes if a validation context returns what it was built with
+    #[test]
+    fn validation_context_accessors() {
+        let input = Value::Map(hashmap! {});
+        let mut reader = mock_reader();
+        let stored = reader.stored.clone();
+        let context = ValidationContext::<()>::new(
+            Operation::Update,
+            "Project",
+            Some("owners"),
+            &input,
+            Some(&stored),
+            None,
+            &mut reader,
+        );
+
+        assert_eq!(Operation::Update, context.operation());
+        assert_eq!("Project", context.type_name());
+        assert_eq!(Some("owners"), context.rel_name());
+        assert_eq!(&input, context.input());
+        assert_eq!(Some("Project"), context.existing().map(|n| n.type_name()));
+        assert!(context.request_context().is_none());
+    }
+
+    /// Passes if the reads of a context-aware validator are passed to the database reader
+    #[test]
+    fn context_validator_reads() {
+        let mut validators = ContextValidators::<()>::new();
+        validators.insert("UniqueName".to_string(), Arc::new(unique_name));
+        let validator = validators.get("UniqueName").unwrap().clone();
+
+        let input = Value::Map(hashmap! {});
+        let mut reader = mock_reader();
+        let result = validator(&mut ValidationContext::new(
+            Operation::Create,
+            "Project",
+            None,
+            &input,
+            None,
+            None,
+            &mut reader,
+        ));
+        assert!(matches!(result, Err(Error::ValidationFailed { .. })));
+
+        let mut context = ValidationContext::<()>::new(
+            Operation::Create,
+            "Project",
+            Some("owners"),
+            &input,
+            None,
+            None,
+            &mut reader,
+        );
+        assert!(context
+            .read_rels("Project", "owners", None)
+            .unwrap()
+            .is_empty());
+        assert_eq!(vec!["Project", "Project.owners"], reader.reads);
+    }
+
+    /// Passes if an asynchronous validator is run to completion, accepting and rejecting values
+    #[test]
+    fn async_validator_result() {
+        let mut validators = Validators::new();
+        validators.insert(
+            "NotEmpty".to_string(),
+            async_validator(|value| {
+                Box::pin(async move {
+                    match value {
+                        Value::String(s) if !s.is_empty() => Ok(()),
+                        _ => Err(Error::ValidationFailed {
+                            message: "Empty value.".to_string(),
+                        }),
+                    }
+                })
+            }),
+        );
+        let validator = validators.get("NotEmpty").unwrap();
+
+        assert!(validator(&Value::String("warpgrapher".to_string())).is_ok());
+        assert!(matches!(
+            validator(&Value::String(String::new())),
+            Err(Error::ValidationFailed { .. })
+        ));
+    }
+}
@@ -3,6 +3,9 @@
 #[cfg(any(feature = "cosmos", feature = "gremlin"))]
 use gremlin_client::GremlinError;
 use http::header::{InvalidHeaderName, InvalidHeaderValue};
+use juniper::{FieldError, Object};
+use serde_json::json;
+use std::convert::TryFrom;
 use std::fmt::{Display, Formatter};
 use std::num::ParseIntError;
 use std::str::ParseBoolError;
@@ -35,6 +38,14 @@ pub enum Error {
         source: reqwest::Error,
     },
 
+    /// Returned if a file named by an `!include` directive in a configuration file cannot be
+    /// read, or if the file includes itself, directly or indirectly. The `path` field contains the
+    /// path of the included file.
+    ConfigIncludeFailed {
+        path: String,
+        source: std::io::Error,
+    },
+
     /// Returned if two Warpgrapher endpoints or two Warpgrapher types are defined with the same
     /// name. The `type_name` field contains the name of the duplicated type.
     ConfigItemDuplicated {
@@ -96,12 +107,31 @@ pub enum Error {
         source: Box<dyn std::error::Error + Sync + Send>,
     },
 
+    /// Returned if a custom input validator rejects the input of a property. The `field` field
+    /// contains the name of the property, and `source` holds the error returned by the validator.
+    FieldValidationFailed {
+        field: String,
+        source: Box<Error>,
+    },
+
     /// Returned if a client for a Cosmos or Gremlin database pool cannot be built or a query fails.
     #[cfg(any(feature = "cosmos", feature = "gremlin"))]
     GremlinActionFailed {
         source: Box<gremlin_client::GremlinError>,
     },
 
+    /// Returned if a client supplies an id for a new node that is already the id of another node.
+    /// The `id` field holds the id that is already in use.
+    IdDuplicated {
+        id: String,
+    },
+
+    /// Returned if a type uses the `Custom` id strategy, but the corresponding id generator is not
+    /// provided. The `name` field contains the name of the id generator that could not be found.
+    IdGeneratorNotFound {
+        name: String,
+    },
+
     /// Returned if a GraphQL query is missing an expected argument. For example, if a create
     /// mutation call were missing its input argument. Also returned if an input argument is
     /// missing an expected field.
@@ -142,6 +172,13 @@ pub enum Error {
         source: bb8_bolt::Error,
     },
 
+    /// Returned if a Neo4J connection URI cannot be parsed, or has a scheme other than `bolt`,
+    /// `bolt+s`, `neo4j`, or `neo4j+s`. The `uri` field holds the URI.
+    #[cfg(feature = "neo4j")]
+    Neo4jUriNotParsed {
+        uri: String,
+    },
+
     /// Returned if a partition key is [`None`] for a database back-end that requires one, such as
     /// Cosmos DB
     PartitionKeyNotFound,
@@ -156,6 +193,91 @@ pub enum Error {
         response: serde_json::Value,
     },
 
+    /// Returned if the query documents of a [`PersistedQueries`] store cannot be read from disk
+    ///
+    /// [`PersistedQueries`]: ../engine/persisted/struct.PersistedQueries.html
+    PersistedQueriesOpenFailed {
+        source: std::io::Error,
+    },
+
+    /// Returned if a client registers an automatic persisted query with a sha256 hash that does
+    /// not match the query document. The `hash` field holds the hash sent by the client.
+    PersistedQueryHashMismatched {
+        hash: String,
+    },
+
+    /// Returned if a request names a persisted query that is not registered. Clients that use
+    /// automatic persisted queries respond by sending the query document along with its hash.
+    PersistedQueryNotFound,
+
+    /// Returned if a client tries to register an automatic persisted query, but automatic
+    /// persisted queries are disabled
+    PersistedQueryNotSupported,
+
+    /// Returned if the options for a database connection pool are inconsistent, such as a maximum
+    /// size of zero. The `message` field describes the problem.
+    PoolOptionsInvalid {
+        message: String,
+    },
+
+    /// Returned if a connection cannot be obtained from a PostgreSQL connection pool, or the pool
+    /// cannot be built
+    #[cfg(feature = "postgres")]
+    PostgresPoolError {
+        source: r2d2::Error,
+    },
+
+    /// Returned if a PostgreSQL statement fails to execute, for example because the server cannot
+    /// be reached or the credentials are rejected
+    #[cfg(feature = "postgres")]
+    PostgresQueryFailed {
+        source: postgres_client::Error,
+    },
+
+    /// Returned if the name of a table in which a PostgreSQL endpoint stores a graph is not a
+    /// plain table name, optionally qualified by a schema name. The `name` field holds the name.
+    #[cfg(feature = "postgres")]
+    PostgresTableNameInvalid {
+        name: String,
+    },
+
+    /// Returned if the TLS connector for connections to a PostgreSQL database cannot be built
+    #[cfg(feature = "postgres")]
+    PostgresTlsFailed {
+        source: native_tls::Error,
+    },
+
+    /// Returned if the cost of a query, computed from the weights of its fields and the list
+    /// multipliers of the fields that return lists, is greater than the maximum set in the
+    /// [`QueryLimits`] of the engine. The `cost` field holds the cost of the query, and the `max`
+    /// field holds the maximum.
+    ///
+    /// [`QueryLimits`]: ../engine/limits/struct.QueryLimits.html
+    QueryCostExceeded {
+        cost: u64,
+        max: u64,
+    },
+
+    /// Returned if the depth of the selection set of a query is greater than the maximum set in
+    /// the [`QueryLimits`] of the engine. The `depth` field holds the depth of the query, and the
+    /// `max` field holds the maximum.
+    ///
+    /// [`QueryLimits`]: ../engine/limits/struct.QueryLimits.html
+    QueryDepthExceeded {
+        depth: usize,
+        max: usize,
+    },
+
+    /// Returned if the engine is in persisted only mode, and a request sends a query that is not
+    /// one of the persisted queries registered with the engine
+    QueryNotPersisted,
+
+    /// Returned if a query cannot be parsed while its depth and cost are checked against the
+    /// [`QueryLimits`] of the engine
+    ///
+    /// [`QueryLimits`]: ../engine/limits/struct.QueryLimits.html
+    QueryNotParsed,
+
     /// Return if a query tries to read and return a relationship defined in the GraphQL schema as
     /// being a single relationship (one-to-one), for which the back-end database has multiple
     /// outgoing relationship edges (one-to-many or many-to-many).  The `rel_name` field holds the
@@ -166,6 +288,14 @@ pub enum Error {
         ids: String,
     },
 
+    /// Returned if a request reads more nodes and relationships than the maximum set in the
+    /// [`QueryLimits`] of the engine. The `max` field holds the maximum.
+    ///
+    /// [`QueryLimits`]: ../engine/limits/struct.QueryLimits.html
+    ResultSizeExceeded {
+        max: usize,
+    },
+
     /// Returned if a custom endpoint is defined or a resolver is defined for a field, but the
     /// corresponding resolver is not provided. The `name` field contains the name of the resolver
     /// that could not be found.
@@ -201,6 +331,20 @@ pub enum Error {
         name: String,
     },
 
+    /// Returned if a connection cannot be obtained from a SQLite connection pool, or the pool
+    /// cannot be built
+    #[cfg(feature = "sqlite")]
+    SqlitePoolError {
+        source: r2d2::Error,
+    },
+
+    /// Returned if a SQLite statement fails to execute, for example because the database file
+    /// cannot be opened or is locked by another writer for longer than the busy timeout
+    #[cfg(feature = "sqlite")]
+    SqliteQueryFailed {
+        source: rusqlite::Error,
+    },
+
     /// When the Warpgrapher client sends queries to a local instance of a Warpgrapher engine,
     /// it runs the engine in a separate thread, where it can have its own tokio execution context.
     /// This error indicates an error in receiving the query answer from the engine thread.
@@ -211,6 +355,13 @@ pub enum Error {
     /// Returned if a transaction is used after it is committed or rolled back.
     TransactionFinished,
 
+    /// Returned if a lifecycle trigger is defined in the configuration, but the corresponding
+    /// trigger function is not provided. The `name` field contains the name of the trigger
+    /// function that wasn't found.
+    TriggerNotFound {
+        name: String,
+    },
+
     /// Warpgrapher transforms data between different serialization formats in the course of
     /// relaying data between GraphQL and database back-ends. If data fails to convert successfully,
     /// this error is thrown. The `src` field contains the source type name or value that could not
@@ -237,6 +388,15 @@ pub enum Error {
     /// [`Value`]: ./engine/value/enum.Value.html
     TypeNotExpected,
 
+    /// Returned by custom resolvers, validators, triggers, and extensions to report an error with
+    /// an application-defined code, such as `UNAUTHORIZED` or `NOT_FOUND`. The `code` and optional
+    /// `details` are returned to the client in the `extensions` of the GraphQL error.
+    UserDefined {
+        code: String,
+        message: String,
+        details: Option<serde_json::Value>,
+    },
+
     /// Returned if the String argument for an id cannot be parsed into a UUID
     UuidNotParsed {
         source: uuid::Error,
@@ -260,6 +420,239 @@ pub enum Error {
     },
 }
 
+impl Error {
+    /// Returns the stable code of the error, returned to clients in the `extensions` of a GraphQL
+    /// error. The codes are:
+    ///
+    /// * `VALIDATION_FAILED` if a validator rejects the input of a mutation
+    /// * `BAD_USER_INPUT` if the input is missing an item or holds a value of the wrong type
+    /// * `CONFLICT` if a write would duplicate an id or a single relationship
+    /// * `DATABASE_UNAVAILABLE` if a connection to the database cannot be obtained
+    /// * `DATABASE_ERROR` if a database query fails
+    /// * `EXTENSION_FAILED` if an extension returns an error that is not an [`Error`]
+    /// * `QUERY_TOO_DEEP`, `QUERY_TOO_COMPLEX`, or `RESULT_TOO_LARGE` if a query goes past a limit
+    /// * `GRAPHQL_VALIDATION_FAILED` if a query cannot be parsed while its limits are checked
+    /// * `PERSISTED_QUERY_NOT_FOUND` if a request names a persisted query that is not registered
+    /// * `PERSISTED_QUERY_NOT_SUPPORTED` if a client registers a query, but APQ is disabled
+    /// * `PERSISTED_QUERY_REQUIRED` if a query is not persisted, in persisted only mode
+    /// * `INTERNAL_ERROR` for any other error
+    ///
+    /// A [`UserDefined`] error returns its own code. An extension that returns an [`Error`] has
+    /// the code of that error.
+    ///
+    /// [`Error`]: ./enum.Error.html
+    /// [`UserDefined`]: ./enum.Error.html#variant.UserDefined
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use warpgrapher::Error;
+    ///
+    /// let e = Error::ValidationFailed { message: "Name is too long.".to_string() };
+    /// assert_eq!("VALIDATION_FAILED", e.code());
+    ///
+    /// let e = Error::UserDefined {
+    ///     code: "UNAUTHORIZED".to_string(),
+    ///     message: "Login required.".to_string(),
+    ///     details: None,
+    /// };
+    /// assert_eq!("UNAUTHORIZED", e.code());
+    /// ```
+    pub fn code(&self) -> &str {
+        match self {
+            Error::ValidationFailed { message: _ } => "VALIDATION_FAILED",
+            Error::FieldValidationFailed { field: _, source } => source.code(),
+            Error::InputItemNotFound { name: _ }
+            | Error::PartitionKeyNotFound
+            | Error::TypeConversionFailed { src: _, dst: _ }
+            | Error::UuidNotParsed { source: _ } => "BAD_USER_INPUT",
+            Error::IdDuplicated { id: _ }
+            | Error::RelDuplicated {
+                rel_name: _,
+                ids: _,
+            } => "CONFLICT",
+            Error::QueryCostExceeded { cost: _, max: _ } => "QUERY_TOO_COMPLEX",
+            Error::QueryDepthExceeded { depth: _, max: _ } => "QUERY_TOO_DEEP",
+            Error::PersistedQueryHashMismatched { hash: _ } => "BAD_USER_INPUT",
+            Error::PersistedQueryNotFound => "PERSISTED_QUERY_NOT_FOUND",
+            Error::PersistedQueryNotSupported => "PERSISTED_QUERY_NOT_SUPPORTED",
+            Error::QueryNotPersisted => "PERSISTED_QUERY_REQUIRED",
+            Error::QueryNotParsed => "GRAPHQL_VALIDATION_FAILED",
+            Error::ResultSizeExceeded { max: _ } => "RESULT_TOO_LARGE",
+            #[cfg(feature = "neo4j")]
+            Error::BoltClientFailed { source: _ } => "DATABASE_UNAVAILABLE",
+            #[cfg(feature = "neo4j")]
+            Error::Neo4jPoolError { source: _ } => "DATABASE_UNAVAILABLE",
+            #[cfg(feature = "postgres")]
+            Error::PostgresPoolError { source: _ } => "DATABASE_UNAVAILABLE",
+            #[cfg(feature = "sqlite")]
+            Error::SqlitePoolError { source: _ } => "DATABASE_UNAVAILABLE",
+            Error::DatabaseNotFound => "DATABASE_UNAVAILABLE",
+            #[cfg(any(feature = "cosmos", feature = "gremlin"))]
+            Error::GremlinActionFailed { source: _ } => "DATABASE_ERROR",
+            #[cfg(feature = "neo4j")]
+            Error::Neo4jQueryFailed { message: _ } => "DATABASE_ERROR",
+            #[cfg(feature = "postgres")]
+            Error::PostgresQueryFailed { source: _ } => "DATABASE_ERROR",
+            #[cfg(feature = "sqlite")]
+            Error::SqliteQueryFailed { source: _ } => "DATABASE_ERROR",
+            Error::ExtensionFailed { source } => source
+                .downcast_ref::<Error>()
+                .map_or("EXTENSION_FAILED", |e| e.code()),
+            Error::UserDefined {
+                code,
+                message: _,
+                details: _,
+            } => code,
+            Error::ClientRequestFailed { source: _ }
+            | Error::ConfigIncludeFailed { path: _, source: _ }
+            | Error::ConfigItemDuplicated { type_name: _ }
+            | Error::ConfigItemReserved { type_name: _ }
+            | Error::ConfigOpenFailed { source: _ }
+            | Error::ConfigVersionMismatched {
+                expected: _,
+                found: _,
+            }
+            | Error::JsonDeserializationFailed { source: _ }
+            | Error::EnvironmentVariableNotFound { name: _ }
+            | Error::EnvironmentVariableBoolNotParsed { source: _ }
+            | Error::EnvironmentVariableIntNotParsed { source: _ }
+            | Error::IdGeneratorNotFound { name: _ }
+            | Error::InvalidHeaderName { source: _ }
+            | Error::InvalidHeaderValue { source: _ }
+            | Error::LabelNotFound
+            | Error::PayloadNotFound { response: _ }
+            | Error::PersistedQueriesOpenFailed { source: _ }
+            | Error::PoolOptionsInvalid { message: _ }
+            | Error::ResolverNotFound { name: _ }
+            | Error::ResponseSetNotFound
+            | Error::ResponseItemNotFound { name: _ }
+            | Error::SerializationFailed { source: _ }
+            | Error::SchemaItemNotFound { name: _ }
+            | Error::ThreadCommunicationFailed { source: _ }
+            | Error::TransactionFinished
+            | Error::TriggerNotFound { name: _ }
+            | Error::TypeNotExpected
+            | Error::ValidatorNotFound { name: _ }
+            | Error::YamlDeserializationFailed { source: _ } => "INTERNAL_ERROR",
+            #[cfg(feature = "neo4j")]
+            Error::Neo4jPoolNotBuilt { source: _ } => "INTERNAL_ERROR",
+            #[cfg(feature = "neo4j")]
+            Error::Neo4jUriNotParsed { uri: _ } => "INTERNAL_ERROR",
+            #[cfg(feature = "postgres")]
+            Error::PostgresTableNameInvalid { name: _ } => "INTERNAL_ERROR",
+            #[cfg(feature = "postgres")]
+            Error::PostgresTlsFailed { source: _ } => "INTERNAL_ERROR",
+        }
+    }
+
+    /// Returns structured details of the error, returned to clients in the `extensions` of a
+    /// GraphQL error, or None if the error has no details. For example, an error from a property
+    /// validator has the name of the property in its `field` detail.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use serde_json::json;
+    /// # use warpgrapher::Error;
+    ///
+    /// let e = Error::IdDuplicated { id: "1234".to_string() };
+    /// assert_eq!(Some(json!({"id": "1234"})), e.details());
+    /// ```
+    pub fn details(&self) -> Option<serde_json::Value> {
+        match self {
+            Error::FieldValidationFailed { field, source } => match source.details() {
+                Some(serde_json::Value::Object(mut m)) => {
+                    m.insert("field".to_string(), json!(field));
+                    Some(serde_json::Value::Object(m))
+                }
+                _ => Some(json!({ "field": field })),
+            },
+            Error::InputItemNotFound { name } => Some(json!({ "name": name })),
+            Error::TypeConversionFailed { src, dst } => Some(json!({"src": src, "dst": dst})),
+            Error::IdDuplicated { id } => Some(json!({ "id": id })),
+            Error::RelDuplicated { rel_name, ids } => {
+                Some(json!({"relName": rel_name, "ids": ids}))
+            }
+            Error::QueryCostExceeded { cost, max } => Some(json!({"cost": cost, "max": max})),
+            Error::QueryDepthExceeded { depth, max } => Some(json!({"depth": depth, "max": max})),
+            Error::ResultSizeExceeded { max } => Some(json!({ "max": max })),
+            Error::PersistedQueryHashMismatched { hash } => Some(json!({ "hash": hash })),
+            Error::ExtensionFailed { source } => {
+                source.downcast_ref::<Error>().and_then(|e| e.details())
+            }
+            Error::UserDefined {
+                code: _,
+                message: _,
+                details,
+            } => details.clone(),
+            _ => None,
+        }
+    }
+
+    /// Converts the error into a Juniper [`FieldError`], with the [`code`] and [`details`] of the
+    /// error in its extensions. The generated resolvers convert errors this way. A custom
+    /// resolver may do the same, so that its errors reach the client with their codes.
+    ///
+    /// [`code`]: ./enum.Error.html#method.code
+    /// [`details`]: ./enum.Error.html#method.details
+    /// [`FieldError`]: ../engine/resolvers/struct.FieldError.html
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use warpgrapher::Error;
+    /// # use warpgrapher::engine::resolvers::{ExecutionResult, ResolverFacade};
+    ///
+    /// fn project_count(facade: ResolverFacade<()>) -> ExecutionResult {
+    ///     if facade.request_context().is_none() {
+    ///         return Err(Error::UserDefined {
+    ///             code: "UNAUTHORIZED".to_string(),
+    ///             message: "Login required.".to_string(),
+    ///             details: None,
+    ///         }
+    ///         .into_field_error());
+    ///     }
+    ///
+    ///     let projects = facade
+    ///         .read_nodes("Project", None)
+    ///         .map_err(Error::into_field_error)?;
+    ///     facade.resolve_scalar(projects.len() as i32)
+    /// }
+    /// ```
+    pub fn into_field_error(self) -> FieldError {
+        let mut extensions = Object::with_capacity(2);
+        extensions.add_field("code", juniper::Value::scalar(self.code().to_string()));
+        if let Some(details) = self.details() {
+            extensions.add_field("details", graphql_value(details));
+        }
+        FieldError::new(self, juniper::Value::object(extensions))
+    }
+}
+
+/// Converts a JSON value into a GraphQL response value
+fn graphql_value(value: serde_json::Value) -> juniper::Value {
+    match value {
+        serde_json::Value::Null => juniper::Value::null(),
+        serde_json::Value::Bool(b) => juniper::Value::scalar(b),
+        serde_json::Value::Number(n) => match n.as_i64().and_then(|i| i32::try_from(i).ok()) {
+            Some(i) => juniper::Value::scalar(i),
+            None => juniper::Value::scalar(n.as_f64().unwrap_or_default()),
+        },
+        serde_json::Value::String(s) => juniper::Value::scalar(s),
+        serde_json::Value::Array(a) => {
+            juniper::Value::list(a.into_iter().map(graphql_value).collect())
+        }
+        serde_json::Value::Object(m) => {
+            let mut o = Object::with_capacity(m.len());
+            m.into_iter().for_each(|(k, v)| {
+                o.add_field(k, graphql_value(v));
+            });
+            juniper::Value::object(o)
+        }
+    }
+}
+
 impl Display for Error {
     fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
         match self {
@@ -270,6 +663,9 @@ impl Display for Error {
             Error::ClientRequestFailed { source } => {
                 write!(f, "Client request failed. Source error: {}", source)
             }
+            Error::ConfigIncludeFailed { path, source } => {
+                write!(f, "Config include {} could not be read. Source error: {}", path, source)
+            }
             Error::ConfigItemDuplicated { type_name } => {
                 write!(f, "Config model contains duplicate item: {}", type_name)
             }
@@ -297,10 +693,19 @@ impl Display for Error {
             Error::ExtensionFailed { source } => {
                 write!(f, "Extension returned an error: {}", source)
             }
+            Error::FieldValidationFailed { field: _, source } => {
+                write!(f, "{}", source)
+            }
             #[cfg(any(feature = "cosmos", feature = "gremlin"))]
             Error::GremlinActionFailed { source } => {
                 write!(f, "Either building a database connection pool or query failed. Source error: {}", source)
             }
+            Error::IdDuplicated { id } => {
+                write!(f, "Could not create a node with the id {}, which is already in use.", id)
+            }
+            Error::IdGeneratorNotFound { name } => {
+                write!(f, "Could not find a custom id generator named {}", name)
+            }
             Error::InputItemNotFound { name } => {
                 write!(f, "Could not find an expected argument, {}, in the GraphQL query.", name)
             }
@@ -328,15 +733,62 @@ impl Display for Error {
             Error::Neo4jQueryFailed { message } => {
                 write!(f, "Neo4j query execution failed. Error message: {:#?}.", message)
             }
+            #[cfg(feature = "neo4j")]
+            Error::Neo4jUriNotParsed { uri } => {
+                write!(f, "Could not parse Neo4j connection URI: {}", uri)
+            }
             Error::PartitionKeyNotFound => {
                 write!(f, "Partition keys are required when using Cosmos DB.")
             }
             Error::PayloadNotFound { response } => {
                 write!(f, "Required data and/or error fields are missing from the response: {}", response)
             }
+            Error::PoolOptionsInvalid { message } => {
+                write!(f, "Invalid database connection pool options: {}", message)
+            }
+            #[cfg(feature = "postgres")]
+            Error::PostgresPoolError { source } => {
+                write!(f, "Failed to get connection from PostgreSQL pool. Source error: {}", source)
+            }
+            #[cfg(feature = "postgres")]
+            Error::PostgresQueryFailed { source } => {
+                write!(f, "PostgreSQL statement execution failed. Source error: {}", source)
+            }
+            #[cfg(feature = "postgres")]
+            Error::PostgresTableNameInvalid { name } => {
+                write!(f, "Invalid PostgreSQL table name: {}", name)
+            }
+            #[cfg(feature = "postgres")]
+            Error::PostgresTlsFailed { source } => {
+                write!(f, "Failed to build the TLS connector for PostgreSQL. Source error: {}", source)
+            }
+            Error::PersistedQueriesOpenFailed { source } => {
+                write!(f, "Could not read the persisted queries. Source error: {}.", source)
+            }
+            Error::PersistedQueryHashMismatched { hash } => {
+                write!(f, "The sha256 hash {} does not match the query.", hash)
+            }
+            // Clients that use automatic persisted queries look for these exact messages
+            Error::PersistedQueryNotFound => write!(f, "PersistedQueryNotFound"),
+            Error::PersistedQueryNotSupported => write!(f, "PersistedQueryNotSupported"),
+            Error::QueryNotPersisted => {
+                write!(f, "Only persisted queries may be run.")
+            }
+            Error::QueryNotParsed => {
+                write!(f, "The query could not be parsed.")
+            }
+            Error::QueryCostExceeded { cost, max } => {
+                write!(f, "The query has a cost of {}, which is greater than the maximum of {}.", cost, max)
+            }
+            Error::QueryDepthExceeded { depth, max } => {
+                write!(f, "The query has a depth of {}, which is greater than the maximum of {}.", depth, max)
+            }
             Error::RelDuplicated { rel_name, ids } => {
                 write!(f, "Tried to read the single-node (i.e. one-to-one) relationship named {}, but found multipled ids: {}", rel_name, ids)
             }
+            Error::ResultSizeExceeded { max } => {
+                write!(f, "The query read more than the maximum of {} nodes and relationships.", max)
+            }
             Error::ResolverNotFound { name } => {
                 write!(f, "Could not find a custom resolver named {}", name)
             }
@@ -352,18 +804,32 @@ impl Display for Error {
             Error::SchemaItemNotFound { name } => {
                 write!(f, "The following item could not be found in the schema: {}", name)
             }
+            #[cfg(feature = "sqlite")]
+            Error::SqlitePoolError { source } => {
+                write!(f, "Failed to get connection from SQLite pool. Source error: {}", source)
+            }
+            #[cfg(feature = "sqlite")]
+            Error::SqliteQueryFailed { source } => {
+                write!(f, "SQLite statement execution failed. Source error: {}", source)
+            }
             Error::ThreadCommunicationFailed { source } => {
                 write!(f, "Communication from the engine thread failed. Source error: {}", source)
             }
             Error::TransactionFinished => {
                 write!(f, "Cannot use a database transaction already committed or rolled back.")
             }
+            Error::TriggerNotFound { name } => {
+                write!(f, "A trigger function named {} could not be found", name)
+            }
             Error::TypeConversionFailed { src, dst } => {
                 write!(f, "The type or value {} could not be converted to type {}", src, dst)
             }
             Error::TypeNotExpected => {
                 write!(f, "Warpgrapher encountered a type that was not expected, such as a non-string ID")
             },
+            Error::UserDefined { code: _, message, details: _ } => {
+                write!(f, "{}", message)
+            }
             Error::UuidNotParsed { source } => {
                 write!(f, "Failed to parse id attribute value. Source error: {}", source)
             }
@@ -386,6 +852,7 @@ impl std::error::Error for Error {
             #[cfg(feature = "neo4j")]
             Error::BoltClientFailed { source } => Some(source),
             Error::ClientRequestFailed { source } => Some(source),
+            Error::ConfigIncludeFailed { path: _, source } => Some(source),
             Error::ConfigItemDuplicated { type_name: _ } => None,
             Error::ConfigItemReserved { type_name: _ } => None,
             Error::ConfigOpenFailed { source } => Some(source),
@@ -398,8 +865,11 @@ impl std::error::Error for Error {
             Error::EnvironmentVariableBoolNotParsed { source } => Some(source),
             Error::EnvironmentVariableIntNotParsed { source } => Some(source),
             Error::ExtensionFailed { source } => Some(source.as_ref()),
+            Error::FieldValidationFailed { field: _, source } => Some(source.as_ref()),
             #[cfg(any(feature = "cosmos", feature = "gremlin"))]
             Error::GremlinActionFailed { source } => Some(source),
+            Error::IdDuplicated { id: _ } => None,
+            Error::IdGeneratorNotFound { name: _ } => None,
             Error::InputItemNotFound { name: _ } => None,
             Error::InvalidHeaderName { source } => Some(source),
             Error::InvalidHeaderValue { source } => Some(source),
@@ -411,21 +881,51 @@ impl std::error::Error for Error {
             Error::Neo4jPoolError { source } => Some(source),
             #[cfg(feature = "neo4j")]
             Error::Neo4jQueryFailed { message: _ } => None,
+            #[cfg(feature = "neo4j")]
+            Error::Neo4jUriNotParsed { uri: _ } => None,
             Error::PartitionKeyNotFound => None,
             Error::PayloadNotFound { response: _ } => None,
+            Error::PoolOptionsInvalid { message: _ } => None,
+            #[cfg(feature = "postgres")]
+            Error::PostgresPoolError { source } => Some(source),
+            #[cfg(feature = "postgres")]
+            Error::PostgresQueryFailed { source } => Some(source),
+            #[cfg(feature = "postgres")]
+            Error::PostgresTableNameInvalid { name: _ } => None,
+            #[cfg(feature = "postgres")]
+            Error::PostgresTlsFailed { source } => Some(source),
+            Error::PersistedQueriesOpenFailed { source } => Some(source),
+            Error::PersistedQueryHashMismatched { hash: _ } => None,
+            Error::PersistedQueryNotFound => None,
+            Error::PersistedQueryNotSupported => None,
+            Error::QueryNotPersisted => None,
+            Error::QueryNotParsed => None,
+            Error::QueryCostExceeded { cost: _, max: _ } => None,
+            Error::QueryDepthExceeded { depth: _, max: _ } => None,
             Error::RelDuplicated {
                 rel_name: _,
                 ids: _,
             } => None,
+            Error::ResultSizeExceeded { max: _ } => None,
             Error::ResolverNotFound { name: _ } => None,
             Error::ResponseItemNotFound { name: _ } => None,
             Error::ResponseSetNotFound => None,
             Error::SerializationFailed { source } => Some(source),
             Error::SchemaItemNotFound { name: _ } => None,
+            #[cfg(feature = "sqlite")]
+            Error::SqlitePoolError { source } => Some(source),
+            #[cfg(feature = "sqlite")]
+            Error::SqliteQueryFailed { source } => Some(source),
             Error::ThreadCommunicationFailed { source } => Some(source),
             Error::TransactionFinished => None,
+            Error::TriggerNotFound { name: _ } => None,
             Error::TypeConversionFailed { src: _, dst: _ } => None,
             Error::TypeNotExpected => None,
+            Error::UserDefined {
+                code: _,
+                message: _,
+                details: _,
+            } => None,
             Error::UuidNotParsed { source } => Some(source),
             Error::ValidationFailed { message: _ } => None,
             Error::ValidatorNotFound { name: _ } => None,
@@ -497,6 +997,27 @@ impl From<std::io::Error> for Error {
     }
 }
 
+#[cfg(feature = "postgres")]
+impl From<postgres_client::Error> for Error {
+    fn from(e: postgres_client::Error) -> Self {
+        Error::PostgresQueryFailed { source: e }
+    }
+}
+
+#[cfg(feature = "sqlite")]
+impl From<r2d2::Error> for Error {
+    fn from(e: r2d2::Error) -> Self {
+        Error::SqlitePoolError { source: e }
+    }
+}
+
+#[cfg(feature = "sqlite")]
+impl From<rusqlite::Error> for Error {
+    fn from(e: rusqlite::Error) -> Self {
+        Error::SqliteQueryFailed { source: e }
+    }
+}
+
 impl From<std::str::ParseBoolError> for Error {
     fn from(e: std::str::ParseBoolError) -> Self {
         Error::EnvironmentVariableBoolNotParsed { source: e }
@@ -0,0 +1,278 @@
+mod setup;
+
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+use serde_json::json;
+#[cfg(feature = "cosmos")]
+use setup::cosmos_test_pool;
+#[cfg(feature = "gremlin")]
+use setup::gremlin_test_pool;
+#[cfg(feature = "in-memory")]
+use setup::memory_test_pool;
+#[cfg(feature = "neo4j")]
+use setup::neo4j_test_pool;
+#[cfg(feature = "postgres")]
+use setup::postgres_test_pool;
+#[cfg(feature = "sqlite")]
+use setup::sqlite_test_pool;
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+use setup::{clear_db, execute, init};
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+use std::convert::{TryFrom, TryInto};
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+use std::fs::File;
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+use std::sync::Arc;
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+use warpgrapher::engine::validators::{ContextValidators, Operation, ValidationContext};
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+use warpgrapher::engine::value::Value;
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+use warpgrapher::{Configuration, DatabasePool, Engine, Error};
+
+#[cfg(feature = "cosmos")]
+#[tokio::test]
+async fn context_validators_test_cosmos() {
+    init();
+    clear_db().await;
+
+    context_validators_test(cosmos_test_pool().await);
+}
+
+#[cfg(feature = "gremlin")]
+#[tokio::test]
+async fn context_validators_test_gremlin() {
+    init();
+    clear_db().await;
+
+    context_validators_test(gremlin_test_pool().await);
+}
+
+#[cfg(feature = "in-memory")]
+#[tokio::test]
+async fn context_validators_test_memory() {
+    init();
+    clear_db().await;
+
+    context_validators_test(memory_test_pool().await);
+}
+
+#[cfg(feature = "neo4j")]
+#[tokio::test]
+async fn context_validators_test_neo4j() {
+    init();
+    clear_db().await;
+
+    context_validators_test(neo4j_test_pool().await);
+}
+
+#[cfg(feature = "postgres")]
+#[tokio::test]
+async fn context_validators_test_postgres() {
+    init();
+    clear_db().await;
+
+    context_validators_test(postgres_test_pool().await);
+}
+
+#[cfg(feature = "sqlite")]
+#[tokio::test]
+async fn context_validators_test_sqlite() {
+    init();
+    clear_db().await;
+
+    context_validators_test(sqlite_test_pool().await);
+}
+
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+fn project_dates(context: &mut ValidationContext<()>) -> Result<(), Error> {
+    let field = |name: &str| {
+        match context.input() {
+            Value::Map(m) => m.get(name).cloned(),
+            _ => None,
+        }
+        .or_else(|| {
+            context
+                .existing()
+                .and_then(|n| n.fields().get(name).cloned())
+        })
+    };
+
+    if context.operation() == Operation::Update && context.existing().is_none() {
+        return Err(Error::ValidationFailed {
+            message: "Stored project missing.".to_string(),
+        });
+    }
+
+    match (field("startDate"), field("endDate")) {
+        (Some(Value::String(start)), Some(Value::String(end))) if end < start => {
+            Err(Error::ValidationFailed {
+                message: "endDate must be after startDate.".to_string(),
+            })
+        }
+        _ => Ok(()),
+    }
+}
+
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+fn max_owners(context: &mut ValidationContext<()>) -> Result<(), Error> {
+    assert_eq!(Some("owners"), context.rel_name());
+    let id = match context.existing().and_then(|n| n.fields().get("id")) {
+        Some(Value::String(id)) => id.clone(),
+        _ => return Ok(()),
+    };
+    let input = Value::try_from(json!({"src": {"Project": {"id": id}}}))?;
+    if context.read_rels("Project", "owners", Some(input))?.len() >= 2 {
+        return Err(Error::ValidationFailed {
+            message: "A project has at most 2 owners.".to_string(),
+        });
+    }
+    Ok(())
+}
+
+/// Passes if context-aware validators of types and relationships see the operation, the
+/// stored node, and the database, and can reject creates and updates across fields
+#[allow(dead_code)]
+fn context_validators_test(database_pool: DatabasePool) {
+    let config: Configuration = File::open("./tests/fixtures/context_validators.yml")
+        .expect("Failed to load config file")
+        .try_into()
+        .unwrap();
+    let mut context_validators = ContextValidators::<()>::new();
+    context_validators.insert("ProjectDates".to_string(), Arc::new(project_dates));
+    context_validators.insert("MaxOwners".to_string(), Arc::new(max_owners));
+    let engine = Engine::<()>::new(config, database_pool)
+        .with_context_validators(context_validators)
+        .build()
+        .expect("Could not create warpgrapher engine");
+
+    let create = "mutation Create($partitionKey: String, $input: ProjectCreateMutationInput!) {
+        ProjectCreate(partitionKey: $partitionKey, input: $input) { name endDate }
+    }";
+    let created = execute(
+        &engine,
+        create,
+        json!({
+            "name": "warpgrapher",
+            "startDate": "2020-01-01",
+            "endDate": "2020-12-31",
+            "owners": [{"dst": {"User": {"$NEW": {"name": "alice"}}}}]
+        }),
+    );
+    assert_eq!(created["data"]["ProjectCreate"]["name"], "warpgrapher");
+    let invalid = execute(
+        &engine,
+        create,
+        json!({"name": "backwards", "startDate": "2020-12-31", "endDate": "2020-01-01"}),
+    );
+    assert!(invalid["data"]["ProjectCreate"].is_null());
+
+    let update = "mutation Update($partitionKey: String, $input: ProjectUpdateInput!) {
+        ProjectUpdate(partitionKey: $partitionKey, input: $input) { endDate }
+    }";
+    let invalid = execute(
+        &engine,
+        update,
+        json!({"$MATCH": {"name": "warpgrapher"}, "$SET": {"endDate": "2019-01-01"}}),
+    );
+    assert!(invalid["data"]["ProjectUpdate"].is_null());
+    let updated = execute(
+        &engine,
+        update,
+        json!({"$MATCH": {"name": "warpgrapher"}, "$SET": {"endDate": "2021-01-01"}}),
+    );
+    assert_eq!(updated["data"]["ProjectUpdate"][0]["endDate"], "2021-01-01");
+
+    let add_owner = "mutation Add($partitionKey: String, $input: ProjectOwnersCreateInput!) {
+        ProjectOwnersCreate(partitionKey: $partitionKey, input: $input) { id }
+    }";
+    let input = |name: &str| {
+        json!({
+            "$MATCH": {"name": "warpgrapher"},
+            "$CREATE": {"dst": {"User": {"$NEW": {"name": name}}}}
+        })
+    };
+    let added = execute(&engine, add_owner, input("bob"));
+    assert_eq!(
+        added["data"]["ProjectOwnersCreate"]
+            .as_array()
+            .unwrap()
+            .len(),
+        1
+    );
+    let rejected = execute(&engine, add_owner, input("carol"));
+    assert!(rejected["data"]["ProjectOwnersCreate"].is_null());
+    assert!(rejected["errors"][0]["message"]
+        .as_str()
+        .unwrap()
+        .contains("at most 2 owners"));
+}
@@ -0,0 +1,494 @@
+mod setup;
+
+#[cfg(feature = "in-memory")]
+use serde_json::json;
+#[cfg(all(feature = "in-memory", feature = "sqlite"))]
+use setup::execute;
+#[cfg(feature = "in-memory")]
+use setup::{init, AppRequestCtx};
+#[cfg(all(feature = "in-memory", feature = "sqlite"))]
+use std::collections::HashMap;
+#[cfg(all(feature = "in-memory", feature = "sqlite"))]
+use std::convert::TryFrom;
+#[cfg(feature = "in-memory")]
+use std::convert::TryInto;
+#[cfg(feature = "in-memory")]
+use std::fs::File;
+#[cfg(feature = "in-memory")]
+use std::sync::atomic::{AtomicUsize, Ordering};
+#[cfg(feature = "in-memory")]
+use std::sync::Arc;
+#[cfg(all(feature = "in-memory", feature = "sqlite"))]
+use std::time::Duration;
+#[cfg(feature = "in-memory")]
+use warpgrapher::engine::database::memory::MemoryGraph;
+#[cfg(all(feature = "in-memory", feature = "sqlite"))]
+use warpgrapher::engine::database::retry::RetryPolicy;
+#[cfg(all(feature = "in-memory", feature = "sqlite"))]
+use warpgrapher::engine::database::{
+    ClauseType, NodeQueryVar, NodeRecord, RelQueryVar, RelRecord, SuffixGenerator,
+};
+#[cfg(feature = "in-memory")]
+use warpgrapher::engine::database::{DatabaseBackend, DatabasePool, Transaction};
+#[cfg(feature = "in-memory")]
+use warpgrapher::engine::schema::Info;
+#[cfg(all(feature = "in-memory", feature = "sqlite"))]
+use warpgrapher::engine::triggers::{TriggerContext, Triggers};
+#[cfg(all(feature = "in-memory", feature = "sqlite"))]
+use warpgrapher::engine::value::Value;
+#[cfg(feature = "in-memory")]
+use warpgrapher::{Client, Configuration, Engine, Error};
+
+/// A back-end provided outside of the crate, which counts the transactions it begins and
+/// delegates them to an in-memory graph
+#[cfg(feature = "in-memory")]
+#[derive(Debug)]
+struct CountingBackend {
+    pool: DatabasePool,
+    transactions: AtomicUsize,
+}
+
+#[cfg(feature = "in-memory")]
+impl DatabaseBackend for CountingBackend {
+    fn transaction(&self, info: &Info) -> Result<Box<dyn Transaction + '_>, Error> {
+        self.transactions.fetch_add(1, Ordering::SeqCst);
+        self.pool.transaction(info)
+    }
+}
+
+/// Passes if the generated resolvers run queries and mutations through the transactions of a
+/// custom back-end
+#[cfg(feature = "in-memory")]
+#[tokio::test]
+async fn custom_backend_crud() {
+    init();
+
+    let backend = Arc::new(CountingBackend {
+        pool: DatabasePool::Memory(MemoryGraph::new()),
+        transactions: AtomicUsize::new(0),
+    });
+    let config: Configuration = File::open("./tests/fixtures/minimal.yml")
+        .expect("Failed to load config file")
+        .try_into()
+        .unwrap();
+    let engine = Engine::<AppRequestCtx>::new(config, DatabasePool::Custom(backend.clone()))
+        .with_version("1.0".to_string())
+        .build()
+        .expect("Could not create warpgrapher engine");
+    let mut client = Client::new_with_engine(engine, None);
+
+    let p0 = client
+        .create_node(
+            "Project",
+            "id name",
+            Some("1234"),
+            &json!({"name": "MJOLNIR"}),
+        )
+        .await
+        .unwrap();
+    assert_eq!(p0.get("name").unwrap(), "MJOLNIR");
+
+    let projects = client
+        .read_node("Project", "id name", Some("1234"), None)
+        .await
+        .unwrap();
+    let projects_a = projects.as_array().unwrap();
+    assert_eq!(projects_a.len(), 1);
+    assert_eq!(projects_a[0].get("name").unwrap(), "MJOLNIR");
+
+    assert_eq!(backend.transactions.load(Ordering::SeqCst), 2);
+}
+
+/// A back-end without transactions, which delegates to an in-memory graph, but keeps the writes
+/// of a transaction when it is rolled back. The write numbered `fail_at` fails with a transient
+/// error before it is made.
+#[cfg(all(feature = "in-memory", feature = "sqlite"))]
+#[derive(Debug)]
+struct NonTransactionalBackend {
+    pool: DatabasePool,
+    writes: AtomicUsize,
+    fail_at: AtomicUsize,
+}
+
+#[cfg(all(feature = "in-memory", feature = "sqlite"))]
+impl DatabaseBackend for NonTransactionalBackend {
+    fn transaction(&self, info: &Info) -> Result<Box<dyn Transaction + '_>, Error> {
+        Ok(Box::new(NonTransactional {
+            inner: self.pool.transaction(info)?,
+            backend: self,
+            written: false,
+        }))
+    }
+}
+
+#[cfg(all(feature = "in-memory", feature = "sqlite"))]
+struct NonTransactional<'a> {
+    inner: Box<dyn Transaction + 'a>,
+    backend: &'a NonTransactionalBackend,
+    written: bool,
+}
+
+#[cfg(all(feature = "in-memory", feature = "sqlite"))]
+impl NonTransactional<'_> {
+    /// Counts a write, and either fails it with a busy database error, or makes it
+    fn write<T, F>(&mut self, f: F) -> Result<T, Error>
+    where
+        F: FnOnce(&mut dyn Transaction) -> Result<T, Error>,
+    {
+        let write = self.backend.writes.fetch_add(1, Ordering::SeqCst) + 1;
+        if write == self.backend.fail_at.load(Ordering::SeqCst) {
+            return Err(Error::SqliteQueryFailed {
+                source: rusqlite::Error::SqliteFailure(
+                    rusqlite::ffi::Error::new(rusqlite::ffi::SQLITE_BUSY),
+                    None,
+                ),
+            });
+        }
+
+        let result = f(&mut *self.inner)?;
+        self.written = true;
+        Ok(result)
+    }
+}
+
+#[cfg(all(feature = "in-memory", feature = "sqlite"))]
+impl Transaction for NonTransactional<'_> {
+    fn begin(&mut self) -> Result<(), Error> {
+        self.inner.begin()
+    }
+
+    fn node_create_query(
+        &mut self,
+        rel_create_fragments: Vec<String>,
+        params: HashMap<String, Value>,
+        node_var: &NodeQueryVar,
+        props: HashMap<String, Value>,
+        clause: ClauseType,
+        sg: &mut SuffixGenerator,
+    ) -> Result<(String, HashMap<String, Value>), Error> {
+        self.inner
+            .node_create_query(rel_create_fragments, params, node_var, props, clause, sg)
+    }
+
+    fn create_node(
+        &mut self,
+        query: String,
+        params: HashMap<String, Value>,
+        partition_key_opt: Option<&Value>,
+        info: &Info,
+    ) -> Result<NodeRecord, Error> {
+        self.write(|t| t.create_node(query, params, partition_key_opt, info))
+    }
+
+    fn rel_create_fragment(
+        &mut self,
+        dst_query: &str,
+        params: HashMap<String, Value>,
+        rel_var: &RelQueryVar,
+        props: HashMap<String, Value>,
+        clause: ClauseType,
+        sg: &mut SuffixGenerator,
+    ) -> Result<(String, HashMap<String, Value>), Error> {
+        self.inner
+            .rel_create_fragment(dst_query, params, rel_var, props, clause, sg)
+    }
+
+    fn rel_create_query(
+        &mut self,
+        src_query_opt: Option<String>,
+        rel_create_fragments: Vec<String>,
+        params: HashMap<String, Value>,
+        rel_vars: Vec<RelQueryVar>,
+        clause: ClauseType,
+    ) -> Result<(String, HashMap<String, Value>), Error> {
+        self.inner.rel_create_query(
+            src_query_opt,
+            rel_create_fragments,
+            params,
+            rel_vars,
+            clause,
+        )
+    }
+
+    fn create_rels(
+        &mut self,
+        query: String,
+        params: HashMap<String, Value>,
+        props_type_name: Option<&str>,
+        partition_key_opt: Option<&Value>,
+    ) -> Result<Vec<RelRecord>, Error> {
+        self.write(|t| t.create_rels(query, params, props_type_name, partition_key_opt))
+    }
+
+    fn node_read_fragment(
+        &mut self,
+        rel_query_fragments: Vec<(String, String)>,
+        params: HashMap<String, Value>,
+        node_var: &NodeQueryVar,
+        props: HashMap<String, Value>,
+        clause: ClauseType,
+        sg: &mut SuffixGenerator,
+    ) -> Result<(String, String, HashMap<String, Value>), Error> {
+        self.inner
+            .node_read_fragment(rel_query_fragments, params, node_var, props, clause, sg)
+    }
+
+    fn node_read_query(
+        &mut self,
+        match_fragment: &str,
+        where_fragment: &str,
+        params: HashMap<String, Value>,
+        node_var: &NodeQueryVar,
+        clause: ClauseType,
+        limit: Option<usize>,
+    ) -> Result<(String, HashMap<String, Value>), Error> {
+        self.inner.node_read_query(
+            match_fragment,
+            where_fragment,
+            params,
+            node_var,
+            clause,
+            limit,
+        )
+    }
+
+    fn read_nodes(
+        &mut self,
+        query: String,
+        params: Option<HashMap<String, Value>>,
+        partition_key_opt: Option<&Value>,
+        info: &Info,
+    ) -> Result<Vec<NodeRecord>, Error> {
+        self.inner
+            .read_nodes(query, params, partition_key_opt, info)
+    }
+
+    fn rel_read_fragment(
+        &mut self,
+        src_query_opt: Option<(String, String)>,
+        dst_query_opt: Option<(String, String)>,
+        params: HashMap<String, Value>,
+        rel_var: &RelQueryVar,
+        props: HashMap<String, Value>,
+        sg: &mut SuffixGenerator,
+    ) -> Result<(String, String, HashMap<String, Value>), Error> {
+        self.inner
+            .rel_read_fragment(src_query_opt, dst_query_opt, params, rel_var, props, sg)
+    }
+
+    fn rel_read_query(
+        &mut self,
+        match_fragment: &str,
+        where_fragment: &str,
+        params: HashMap<String, Value>,
+        rel_var: &RelQueryVar,
+        clause: ClauseType,
+        limit: Option<usize>,
+    ) -> Result<(String, HashMap<String, Value>), Error> {
+        self.inner.rel_read_query(
+            match_fragment,
+            where_fragment,
+            params,
+            rel_var,
+            clause,
+            limit,
+        )
+    }
+
+    fn read_rels(
+        &mut self,
+        query: String,
+        params: Option<HashMap<String, Value>>,
+        props_type_name: Option<&str>,
+        partition_key_opt: Option<&Value>,
+    ) -> Result<Vec<RelRecord>, Error> {
+        self.inner
+            .read_rels(query, params, props_type_name, partition_key_opt)
+    }
+
+    fn node_update_query(
+        &mut self,
+        match_query: String,
+        change_queries: Vec<String>,
+        params: HashMap<String, Value>,
+        node_var: &NodeQueryVar,
+        props: HashMap<String, Value>,
+        clause: ClauseType,
+        sg: &mut SuffixGenerator,
+    ) -> Result<(String, HashMap<String, Value>), Error> {
+        self.inner.node_update_query(
+            match_query,
+            change_queries,
+            params,
+            node_var,
+            props,
+            clause,
+            sg,
+        )
+    }
+
+    fn update_nodes(
+        &mut self,
+        query: String,
+        params: HashMap<String, Value>,
+        partition_key_opt: Option<&Value>,
+        info: &Info,
+    ) -> Result<Vec<NodeRecord>, Error> {
+        self.write(|t| t.update_nodes(query, params, partition_key_opt, info))
+    }
+
+    fn rel_update_query(
+        &mut self,
+        match_query: String,
+        params: HashMap<String, Value>,
+        rel_var: &RelQueryVar,
+        props: HashMap<String, Value>,
+        clause: ClauseType,
+        sg: &mut SuffixGenerator,
+    ) -> Result<(String, HashMap<String, Value>), Error> {
+        self.inner
+            .rel_update_query(match_query, params, rel_var, props, clause, sg)
+    }
+
+    fn update_rels(
+        &mut self,
+        query: String,
+        params: HashMap<String, Value>,
+        props_type_name: Option<&str>,
+        partition_key_opt: Option<&Value>,
+    ) -> Result<Vec<RelRecord>, Error> {
+        self.write(|t| t.update_rels(query, params, props_type_name, partition_key_opt))
+    }
+
+    fn node_delete_query(
+        &mut self,
+        match_query: String,
+        rel_delete_fragments: Vec<String>,
+        params: HashMap<String, Value>,
+        node_var: &NodeQueryVar,
+        clause: ClauseType,
+        sg: &mut SuffixGenerator,
+    ) -> Result<(String, HashMap<String, Value>), Error> {
+        self.inner.node_delete_query(
+            match_query,
+            rel_delete_fragments,
+            params,
+            node_var,
+            clause,
+            sg,
+        )
+    }
+
+    fn delete_nodes(
+        &mut self,
+        query: String,
+        params: HashMap<String, Value>,
+        partition_key_opt: Option<&Value>,
+    ) -> Result<i32, Error> {
+        self.write(|t| t.delete_nodes(query, params, partition_key_opt))
+    }
+
+    fn rel_delete_query(
+        &mut self,
+        match_query: String,
+        src_delete_query_opt: Option<String>,
+        dst_delete_query_opt: Option<String>,
+        params: HashMap<String, Value>,
+        rel_var: &RelQueryVar,
+        clause: ClauseType,
+        sg: &mut SuffixGenerator,
+    ) -> Result<(String, HashMap<String, Value>), Error> {
+        self.inner.rel_delete_query(
+            match_query,
+            src_delete_query_opt,
+            dst_delete_query_opt,
+            params,
+            rel_var,
+            clause,
+            sg,
+        )
+    }
+
+    fn delete_rels(
+        &mut self,
+        query: String,
+        params: HashMap<String, Value>,
+        partition_key_opt: Option<&Value>,
+    ) -> Result<i32, Error> {
+        self.write(|t| t.delete_rels(query, params, partition_key_opt))
+    }
+
+    fn commit(&mut self) -> Result<(), Error> {
+        self.inner.commit()
+    }
+
+    /// Commits the writes made so far, as a store without transactions keeps them
+    fn rollback(&mut self) -> Result<(), Error> {
+        self.inner.commit()
+    }
+
+    fn irreversible_writes(&self) -> bool {
+        self.written
+    }
+}
+
+#[cfg(all(feature = "in-memory", feature = "sqlite"))]
+fn log_activity(context: &mut TriggerContext<()>) -> Result<(), Error> {
+    let action = format!("{:?}", context.event());
+    context.create_node("Activity", Value::try_from(json!({ "action": action }))?)?;
+    Ok(())
+}
+
+/// Passes if an operation on a back-end without transactions is retried when its first write
+/// fails with a transient error, but not when a later write fails, so that the writes already
+/// made are not repeated
+#[cfg(all(feature = "in-memory", feature = "sqlite"))]
+#[tokio::test]
+async fn custom_backend_no_retry_after_write() {
+    init();
+
+    let backend = Arc::new(NonTransactionalBackend {
+        pool: DatabasePool::Memory(MemoryGraph::new()),
+        writes: AtomicUsize::new(0),
+        fail_at: AtomicUsize::new(1),
+    });
+    let config: Configuration = File::open("./tests/fixtures/non_transactional.yml")
+        .expect("Failed to load config file")
+        .try_into()
+        .unwrap();
+    let mut triggers = Triggers::<()>::new();
+    triggers.insert("LogActivity".to_string(), Arc::new(log_activity));
+    let engine = Engine::<()>::new(config, DatabasePool::Custom(backend.clone()))
+        .with_triggers(triggers)
+        .with_retry_policy(RetryPolicy::new().with_initial_backoff(Duration::from_millis(1)))
+        .build()
+        .expect("Could not create warpgrapher engine");
+
+    let create = "mutation Create($partitionKey: String, $input: ProjectCreateMutationInput!) {
+        ProjectCreate(partitionKey: $partitionKey, input: $input) { name }
+    }";
+    let count = |type_name: &str| -> usize {
+        execute(
+            &engine,
+            &format!("query {{ {} {{ id }} }}", type_name),
+            json!(null),
+        )["data"][type_name]
+            .as_array()
+            .unwrap()
+            .len()
+    };
+
+    let created = execute(&engine, create, json!({"name": "alpha"}));
+    assert_eq!(created["data"]["ProjectCreate"]["name"], "alpha");
+    assert_eq!(backend.writes.load(Ordering::SeqCst), 3);
+    assert_eq!(count("Project"), 1);
+    assert_eq!(count("Activity"), 1);
+
+    backend.writes.store(0, Ordering::SeqCst);
+    backend.fail_at.store(2, Ordering::SeqCst);
+    let failed = execute(&engine, create, json!({"name": "beta"}));
+    assert!(failed["errors"].is_array());
+    assert_eq!(backend.writes.load(Ordering::SeqCst), 2);
+    assert_eq!(count("Project"), 2);
+    assert_eq!(count("Activity"), 1);
+}
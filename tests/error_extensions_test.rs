@@ -0,0 +1,309 @@
+mod setup;
+
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+use serde_json::json;
+#[cfg(feature = "cosmos")]
+use setup::cosmos_test_pool;
+#[cfg(feature = "gremlin")]
+use setup::gremlin_test_pool;
+#[cfg(feature = "in-memory")]
+use setup::memory_test_pool;
+#[cfg(feature = "neo4j")]
+use setup::neo4j_test_pool;
+#[cfg(feature = "postgres")]
+use setup::postgres_test_pool;
+#[cfg(feature = "sqlite")]
+use setup::sqlite_test_pool;
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+use setup::{clear_db, execute, init};
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+use std::convert::TryInto;
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+use std::fs::File;
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+use std::sync::Arc;
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+use warpgrapher::engine::resolvers::{ExecutionResult, ResolverFacade, Resolvers};
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+use warpgrapher::engine::validators::Validators;
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+use warpgrapher::engine::value::Value;
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+use warpgrapher::{Configuration, DatabasePool, Engine, Error};
+
+#[cfg(feature = "cosmos")]
+#[tokio::test]
+async fn error_extensions_test_cosmos() {
+    init();
+    clear_db().await;
+
+    error_extensions_test(cosmos_test_pool().await);
+}
+
+#[cfg(feature = "gremlin")]
+#[tokio::test]
+async fn error_extensions_test_gremlin() {
+    init();
+    clear_db().await;
+
+    error_extensions_test(gremlin_test_pool().await);
+}
+
+#[cfg(feature = "in-memory")]
+#[tokio::test]
+async fn error_extensions_test_memory() {
+    init();
+    clear_db().await;
+
+    error_extensions_test(memory_test_pool().await);
+}
+
+#[cfg(feature = "neo4j")]
+#[tokio::test]
+async fn error_extensions_test_neo4j() {
+    init();
+    clear_db().await;
+
+    error_extensions_test(neo4j_test_pool().await);
+}
+
+#[cfg(feature = "postgres")]
+#[tokio::test]
+async fn error_extensions_test_postgres() {
+    init();
+    clear_db().await;
+
+    error_extensions_test(postgres_test_pool().await);
+}
+
+#[cfg(feature = "sqlite")]
+#[tokio::test]
+async fn error_extensions_test_sqlite() {
+    init();
+    clear_db().await;
+
+    error_extensions_test(sqlite_test_pool().await);
+}
+
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+fn secret(_facade: ResolverFacade<()>) -> ExecutionResult {
+    Err(Error::UserDefined {
+        code: "UNAUTHORIZED".to_string(),
+        message: "Login required.".to_string(),
+        details: Some(json!({"realm": "admin"})),
+    }
+    .into_field_error())
+}
+
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+fn broken(_facade: ResolverFacade<()>) -> ExecutionResult {
+    Err(Error::DatabaseNotFound.into_field_error())
+}
+
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+fn name_length(value: &Value) -> Result<(), Error> {
+    match value {
+        Value::Map(m) => match m.get("name") {
+            Some(Value::String(s)) if s.len() > 5 => Err(Error::ValidationFailed {
+                message: "Name too long.".to_string(),
+            }),
+            _ => Ok(()),
+        },
+        _ => Ok(()),
+    }
+}
+
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+fn role(value: &Value) -> Result<(), Error> {
+    match value {
+        Value::Map(m) => match m.get("role") {
+            Some(Value::String(s)) if s == "root" => Err(Error::UserDefined {
+                code: "FORBIDDEN_ROLE".to_string(),
+                message: "Role not allowed.".to_string(),
+                details: None,
+            }),
+            _ => Ok(()),
+        },
+        _ => Ok(()),
+    }
+}
+
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+fn build_engine(database_pool: DatabasePool, production_mode: bool) -> Engine<()> {
+    let config: Configuration = File::open("./tests/fixtures/error_extensions.yml")
+        .expect("Failed to load config file")
+        .try_into()
+        .unwrap();
+
+    let mut resolvers = Resolvers::<()>::new();
+    resolvers.insert("Secret".to_string(), Arc::new(secret));
+    resolvers.insert("Broken".to_string(), Arc::new(broken));
+
+    let mut validators = Validators::new();
+    validators.insert("NameLength".to_string(), Arc::new(name_length));
+    validators.insert("Role".to_string(), Arc::new(role));
+
+    Engine::<()>::new(config, database_pool)
+        .with_resolvers(resolvers)
+        .with_validators(validators)
+        .with_production_mode(production_mode)
+        .build()
+        .expect("Could not create warpgrapher engine")
+}
+
+/// Passes if errors carry a stable code and details in their extensions, if resolvers and
+/// validators can supply their own codes, and if internal messages are hidden in production mode
+#[allow(dead_code)]
+fn error_extensions_test(database_pool: DatabasePool) {
+    let engine = build_engine(database_pool.clone(), false);
+
+    let create = "mutation Create($partitionKey: String, $input: UserCreateMutationInput!) {
+        UserCreate(partitionKey: $partitionKey, input: $input) { name }
+    }";
+    let invalid = execute(&engine, create, json!({"name": "bartholomew"}));
+    let ext = &invalid["errors"][0]["extensions"];
+    assert_eq!(ext["code"], "VALIDATION_FAILED");
+    assert_eq!(ext["details"]["field"], "name");
+    assert_eq!(invalid["errors"][0]["message"], "Name too long.");
+
+    let forbidden = execute(&engine, create, json!({"name": "bob", "role": "root"}));
+    let ext = &forbidden["errors"][0]["extensions"];
+    assert_eq!(ext["code"], "FORBIDDEN_ROLE");
+    assert_eq!(ext["details"]["field"], "role");
+
+    let secret = execute(&engine, "query { Secret }", json!(null));
+    let ext = &secret["errors"][0]["extensions"];
+    assert_eq!(ext["code"], "UNAUTHORIZED");
+    assert_eq!(ext["details"]["realm"], "admin");
+
+    let broken = execute(&engine, "query { Broken }", json!(null));
+    assert_eq!(
+        broken["errors"][0]["extensions"]["code"],
+        "DATABASE_UNAVAILABLE"
+    );
+    assert_ne!(
+        broken["errors"][0]["message"],
+        "The database is unavailable."
+    );
+
+    let unknown = execute(&engine, "query { Missing }", json!(null));
+    assert_eq!(
+        unknown["errors"][0]["extensions"]["code"],
+        "GRAPHQL_VALIDATION_FAILED"
+    );
+
+    let engine = build_engine(database_pool, true);
+    let broken = execute(&engine, "query { Broken }", json!(null));
+    assert_eq!(
+        broken["errors"][0]["extensions"]["code"],
+        "DATABASE_UNAVAILABLE"
+    );
+    assert_eq!(
+        broken["errors"][0]["message"],
+        "The database is unavailable."
+    );
+    let secret = execute(&engine, "query { Secret }", json!(null));
+    assert_eq!(secret["errors"][0]["message"], "Login required.");
+    assert_eq!(
+        secret["errors"][0]["extensions"]["details"]["realm"],
+        "admin"
+    );
+}
@@ -0,0 +1,361 @@
+mod setup;
+
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+use serde_json::json;
+#[cfg(feature = "cosmos")]
+use setup::cosmos_test_pool;
+#[cfg(feature = "gremlin")]
+use setup::gremlin_test_pool;
+#[cfg(feature = "in-memory")]
+use setup::memory_test_pool;
+#[cfg(feature = "neo4j")]
+use setup::neo4j_test_pool;
+#[cfg(feature = "postgres")]
+use setup::postgres_test_pool;
+#[cfg(feature = "sqlite")]
+use setup::sqlite_test_pool;
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+use setup::{clear_db, execute, init};
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+use std::collections::HashMap;
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+use std::convert::TryInto;
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+use std::fs::File;
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+use std::sync::{Arc, Mutex};
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+use warpgrapher::engine::extensions::{
+    Extension, FieldContext, OperationContext, OperationKind, OperationResult,
+};
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+use warpgrapher::engine::resolvers::ExecutionResult;
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+use warpgrapher::engine::value::Value;
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+use warpgrapher::{Configuration, DatabasePool, Engine};
+
+#[cfg(feature = "cosmos")]
+#[tokio::test]
+async fn extension_hooks_test_cosmos() {
+    init();
+    clear_db().await;
+
+    extension_hooks_test(cosmos_test_pool().await);
+}
+
+#[cfg(feature = "gremlin")]
+#[tokio::test]
+async fn extension_hooks_test_gremlin() {
+    init();
+    clear_db().await;
+
+    extension_hooks_test(gremlin_test_pool().await);
+}
+
+#[cfg(feature = "in-memory")]
+#[tokio::test]
+async fn extension_hooks_test_memory() {
+    init();
+    clear_db().await;
+
+    extension_hooks_test(memory_test_pool().await);
+}
+
+#[cfg(feature = "neo4j")]
+#[tokio::test]
+async fn extension_hooks_test_neo4j() {
+    init();
+    clear_db().await;
+
+    extension_hooks_test(neo4j_test_pool().await);
+}
+
+#[cfg(feature = "postgres")]
+#[tokio::test]
+async fn extension_hooks_test_postgres() {
+    init();
+    clear_db().await;
+
+    extension_hooks_test(postgres_test_pool().await);
+}
+
+#[cfg(feature = "sqlite")]
+#[tokio::test]
+async fn extension_hooks_test_sqlite() {
+    init();
+    clear_db().await;
+
+    extension_hooks_test(sqlite_test_pool().await);
+}
+
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+#[derive(Debug)]
+struct HookExtension {
+    log: Arc<Mutex<Vec<String>>>,
+}
+
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+impl Extension<()> for HookExtension {
+    fn before_operation(
+        &self,
+        operation: &mut OperationContext<()>,
+        input: &mut Option<Value>,
+    ) -> Result<(), Box<dyn std::error::Error + Sync + Send>> {
+        self.log.lock().unwrap().push(format!(
+            "before {:?} {}",
+            operation.kind(),
+            operation.type_name()
+        ));
+
+        match (operation.kind(), input.take()) {
+            (OperationKind::Create, Some(Value::Map(mut m))) => {
+                if let Some(Value::String(name)) = m.get("name") {
+                    if name.trim() == "forbidden" {
+                        return Err("Name is forbidden.".into());
+                    }
+                    let name = Value::String(name.trim().to_lowercase());
+                    m.insert("name".to_string(), name);
+                }
+                m.insert("deleted".to_string(), Value::Bool(false));
+                *input = Some(Value::Map(m));
+            }
+            (OperationKind::Read, i) => {
+                let mut m = match i {
+                    Some(Value::Map(m)) => m,
+                    _ => HashMap::new(),
+                };
+                m.insert("deleted".to_string(), Value::Bool(false));
+                *input = Some(Value::Map(m));
+            }
+            (OperationKind::Delete, Some(Value::Map(mut m))) => {
+                operation.rewrite_delete_as_update()?;
+                let mut soft = HashMap::new();
+                soft.insert("deleted".to_string(), Value::Bool(true));
+                m.remove("$DELETE");
+                m.insert("$SET".to_string(), Value::Map(soft));
+                *input = Some(Value::Map(m));
+            }
+            (_, i) => *input = i,
+        }
+
+        Ok(())
+    }
+
+    fn after_operation(
+        &self,
+        operation: &OperationContext<()>,
+        result: &mut OperationResult,
+    ) -> Result<(), Box<dyn std::error::Error + Sync + Send>> {
+        let count = match result {
+            OperationResult::Nodes(n) => n.len(),
+            OperationResult::Rels(r) => r.len(),
+            OperationResult::Count(c) => *c as usize,
+        };
+        self.log.lock().unwrap().push(format!(
+            "after {:?} {} {}",
+            operation.kind(),
+            operation.type_name(),
+            count
+        ));
+
+        match result {
+            OperationResult::Nodes(n)
+                if operation.kind() == OperationKind::Create
+                    && n.iter().any(|n| {
+                        n.fields().get("name") == Some(&Value::String("audited".to_string()))
+                    }) =>
+            {
+                Err("Audit failed.".into())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn before_field(
+        &self,
+        field: &FieldContext<()>,
+    ) -> Result<(), Box<dyn std::error::Error + Sync + Send>> {
+        if field.field_name() == "secret" {
+            Err("Field is hidden.".into())
+        } else {
+            Ok(())
+        }
+    }
+
+    fn after_field(&self, field: &FieldContext<()>, result: ExecutionResult) -> ExecutionResult {
+        match (field.type_name(), field.field_name(), result) {
+            ("Project", "name", Ok(v)) => Ok(juniper::Value::scalar(
+                v.as_scalar_value::<String>()
+                    .map(|n| n.to_uppercase())
+                    .unwrap_or_default(),
+            )),
+            (_, _, result) => result,
+        }
+    }
+}
+
+/// Passes if extension hooks see each operation and field, and can normalize input, veto
+/// operations and fields, rewrite deletes into soft deletes, and change results, and if the
+/// after operation hooks run once the operation is committed
+#[allow(dead_code)]
+fn extension_hooks_test(database_pool: DatabasePool) {
+    let config: Configuration = File::open("./tests/fixtures/extension_hooks.yml")
+        .expect("Failed to load config file")
+        .try_into()
+        .unwrap();
+
+    let log = Arc::new(Mutex::new(Vec::new()));
+    let engine = Engine::<()>::new(config.clone(), database_pool.clone())
+        .with_extensions(vec![Arc::new(HookExtension { log: log.clone() })])
+        .build()
+        .expect("Could not create warpgrapher engine");
+    let plain = Engine::<()>::new(config, database_pool)
+        .build()
+        .expect("Could not create warpgrapher engine");
+
+    let create = "mutation Create($partitionKey: String, $input: ProjectCreateMutationInput!) {
+        ProjectCreate(partitionKey: $partitionKey, input: $input) { name }
+    }";
+    let alpha = execute(&engine, create, json!({"name": " Alpha "}));
+    assert_eq!(alpha["data"]["ProjectCreate"]["name"], "ALPHA");
+    execute(&engine, create, json!({"name": "beta", "secret": "s3cret"}));
+    let forbidden = execute(&engine, create, json!({"name": "forbidden"}));
+    assert!(forbidden["data"]["ProjectCreate"].is_null());
+    assert!(forbidden["errors"][0]["message"]
+        .as_str()
+        .unwrap()
+        .contains("Name is forbidden."));
+
+    let delete = "mutation Delete($partitionKey: String, $input: ProjectDeleteInput!) {
+        ProjectDelete(partitionKey: $partitionKey, input: $input)
+    }";
+    let deleted = execute(&engine, delete, json!({"$MATCH": {"name": "alpha"}}));
+    assert_eq!(deleted["data"]["ProjectDelete"], 1);
+
+    let read = "query Read($partitionKey: String) {
+        Project(partitionKey: $partitionKey) { name deleted }
+    }";
+    let all = execute(&plain, read, json!(null));
+    assert_eq!(all["data"]["Project"].as_array().unwrap().len(), 2);
+
+    let visible = execute(
+        &engine,
+        "query Read($partitionKey: String) {
+            Project(partitionKey: $partitionKey) { name secret }
+        }",
+        json!(null),
+    );
+    assert_eq!(visible["data"]["Project"].as_array().unwrap().len(), 1);
+    assert_eq!(visible["data"]["Project"][0]["name"], "BETA");
+    assert!(visible["data"]["Project"][0]["secret"].is_null());
+    assert!(visible["errors"][0]["message"]
+        .as_str()
+        .unwrap()
+        .contains("Field is hidden."));
+
+    let audited = execute(&engine, create, json!({"name": "audited"}));
+    assert!(audited["data"]["ProjectCreate"].is_null());
+    assert!(audited["errors"][0]["message"]
+        .as_str()
+        .unwrap()
+        .contains("Audit failed."));
+    let all = execute(&plain, read, json!(null));
+    assert_eq!(all["data"]["Project"].as_array().unwrap().len(), 3);
+
+    let log = log.lock().unwrap();
+    assert!(log.contains(&"before Delete Project".to_string()));
+    assert!(log.contains(&"before Update Project".to_string()));
+    assert!(log.contains(&"after Update Project 1".to_string()));
+    assert!(log.contains(&"after Read Project 1".to_string()));
+    assert_eq!(
+        4,
+        log.iter()
+            .filter(|l| l.as_str() == "before Create Project")
+            .count()
+    );
+}
@@ -0,0 +1,311 @@
+mod setup;
+
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+use serde_json::json;
+#[cfg(feature = "cosmos")]
+use setup::cosmos_test_pool;
+#[cfg(feature = "gremlin")]
+use setup::gremlin_test_pool;
+#[cfg(feature = "in-memory")]
+use setup::memory_test_pool;
+#[cfg(feature = "neo4j")]
+use setup::neo4j_test_pool;
+#[cfg(feature = "postgres")]
+use setup::postgres_test_pool;
+#[cfg(feature = "sqlite")]
+use setup::sqlite_test_pool;
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+use setup::{clear_db, execute, init};
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+use std::convert::{TryFrom, TryInto};
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+use std::fs::File;
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+use std::sync::Arc;
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+use warpgrapher::engine::resolvers::{ExecutionResult, ResolverFacade, Resolvers};
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+use warpgrapher::engine::value::Value;
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+use warpgrapher::{Configuration, DatabasePool, Engine};
+
+#[cfg(feature = "cosmos")]
+#[tokio::test]
+async fn facade_crud_test_cosmos() {
+    init();
+    clear_db().await;
+
+    facade_crud_test(cosmos_test_pool().await);
+}
+
+#[cfg(feature = "gremlin")]
+#[tokio::test]
+async fn facade_crud_test_gremlin() {
+    init();
+    clear_db().await;
+
+    facade_crud_test(gremlin_test_pool().await);
+}
+
+#[cfg(feature = "in-memory")]
+#[tokio::test]
+async fn facade_crud_test_memory() {
+    init();
+    clear_db().await;
+
+    facade_crud_test(memory_test_pool().await);
+}
+
+#[cfg(feature = "neo4j")]
+#[tokio::test]
+async fn facade_crud_test_neo4j() {
+    init();
+    clear_db().await;
+
+    facade_crud_test(neo4j_test_pool().await);
+}
+
+#[cfg(feature = "postgres")]
+#[tokio::test]
+async fn facade_crud_test_postgres() {
+    init();
+    clear_db().await;
+
+    facade_crud_test(postgres_test_pool().await);
+}
+
+#[cfg(feature = "sqlite")]
+#[tokio::test]
+async fn facade_crud_test_sqlite() {
+    init();
+    clear_db().await;
+
+    facade_crud_test(sqlite_test_pool().await);
+}
+
+#[cfg(feature = "in-memory")]
+#[tokio::test]
+async fn facade_rollback_test_memory() {
+    init();
+    clear_db().await;
+
+    facade_rollback_test(memory_test_pool().await);
+}
+
+#[cfg(feature = "neo4j")]
+#[tokio::test]
+async fn facade_rollback_test_neo4j() {
+    init();
+    clear_db().await;
+
+    facade_rollback_test(neo4j_test_pool().await);
+}
+
+#[cfg(feature = "postgres")]
+#[tokio::test]
+async fn facade_rollback_test_postgres() {
+    init();
+    clear_db().await;
+
+    facade_rollback_test(postgres_test_pool().await);
+}
+
+#[cfg(feature = "sqlite")]
+#[tokio::test]
+async fn facade_rollback_test_sqlite() {
+    init();
+    clear_db().await;
+
+    facade_rollback_test(sqlite_test_pool().await);
+}
+
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+fn crud(facade: ResolverFacade<()>) -> ExecutionResult {
+    facade.insert_node(
+        "User",
+        Value::try_from(json!({
+            "name": "alice",
+            "role": "user",
+            "friends": [{"dst": {"User": {"$NEW": {"name": "bob", "role": "user"}}}}]
+        }))?,
+    )?;
+    let updated = facade.update_nodes(
+        "User",
+        Value::try_from(json!({"$MATCH": {"name": "alice"}, "$SET": {"role": "admin"}}))?,
+    )?;
+    let admins = facade.read_nodes("User", Some(Value::try_from(json!({"role": "admin"}))?))?;
+    let friends = facade.read_rels(
+        "User",
+        "friends",
+        Some(Value::try_from(
+            json!({"src": {"User": {"name": "alice"}}}),
+        )?),
+    )?;
+    let deleted =
+        facade.delete_nodes("User", Value::try_from(json!({"$MATCH": {"name": "bob"}}))?)?;
+
+    facade.resolve_scalar(format!(
+        "{} {} {} {}",
+        updated.len(),
+        admins.len(),
+        friends.len(),
+        deleted
+    ))
+}
+
+#[cfg(any(
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+fn create_then_fail(facade: ResolverFacade<()>) -> ExecutionResult {
+    facade.insert_node("User", Value::try_from(json!({"name": "carol"}))?)?;
+
+    Err("rejected".into())
+}
+
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+fn facade_engine(database_pool: DatabasePool) -> Engine<()> {
+    let config: Configuration = File::open("./tests/fixtures/facade.yml")
+        .expect("Failed to load config file")
+        .try_into()
+        .unwrap();
+    let mut resolvers = Resolvers::<()>::new();
+    resolvers.insert("Crud".to_string(), Arc::new(crud));
+    resolvers.insert("CreateThenFail".to_string(), Arc::new(create_then_fail));
+    Engine::<()>::new(config, database_pool)
+        .with_resolvers(resolvers)
+        .build()
+        .expect("Could not create warpgrapher engine")
+}
+
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+fn read_users(engine: &Engine<()>) -> serde_json::Value {
+    execute(
+        engine,
+        "query Read($partitionKey: String, $input: UserQueryInput) {
+            User(partitionKey: $partitionKey, input: $input) { name role friends { id } }
+        }",
+        json!(null),
+    )
+}
+
+/// Passes if a custom resolver can create, update, read, and delete nodes and read
+/// relationships through the resolver facade, without knowledge of the database back-end, and
+/// sees its own writes
+#[allow(dead_code)]
+fn facade_crud_test(database_pool: DatabasePool) {
+    let engine = facade_engine(database_pool);
+
+    let result = execute(
+        &engine,
+        "mutation Crud($partitionKey: String) { Crud(partitionKey: $partitionKey) }",
+        json!(null),
+    );
+    assert_eq!(result["data"]["Crud"], "1 1 1 1");
+
+    let read = read_users(&engine);
+    assert_eq!(read["data"]["User"].as_array().unwrap().len(), 1);
+    assert_eq!(read["data"]["User"][0]["name"], "alice");
+    assert_eq!(read["data"]["User"][0]["role"], "admin");
+    assert!(read["data"]["User"][0]["friends"]
+        .as_array()
+        .unwrap()
+        .is_empty());
+}
+
+/// Passes if the writes made through the resolver facade are rolled back when the custom
+/// resolver returns an error
+#[allow(dead_code)]
+fn facade_rollback_test(database_pool: DatabasePool) {
+    let engine = facade_engine(database_pool);
+
+    let result = execute(
+        &engine,
+        "mutation Fail($partitionKey: String) { CreateThenFail(partitionKey: $partitionKey) }",
+        json!(null),
+    );
+    assert!(result["data"]["CreateThenFail"].is_null());
+    assert_eq!(result["errors"][0]["message"], "rejected");
+
+    let read = read_users(&engine);
+    assert!(read["data"]["User"].as_array().unwrap().is_empty());
+}
@@ -0,0 +1,200 @@
+mod setup;
+
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+use serde_json::json;
+#[cfg(feature = "cosmos")]
+use setup::cosmos_test_pool;
+#[cfg(feature = "gremlin")]
+use setup::gremlin_test_pool;
+#[cfg(feature = "in-memory")]
+use setup::memory_test_pool;
+#[cfg(feature = "neo4j")]
+use setup::neo4j_test_pool;
+#[cfg(feature = "postgres")]
+use setup::postgres_test_pool;
+#[cfg(feature = "sqlite")]
+use setup::sqlite_test_pool;
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+use setup::{clear_db, execute, init};
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+use std::convert::TryInto;
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+use std::fs::File;
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+use std::sync::Arc;
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+use warpgrapher::engine::id_generators::IdGenerators;
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+use warpgrapher::{Configuration, DatabasePool, Engine, Error};
+
+#[cfg(feature = "cosmos")]
+#[tokio::test]
+async fn id_generation_test_cosmos() {
+    init();
+    clear_db().await;
+
+    id_generation_test(cosmos_test_pool().await);
+}
+
+#[cfg(feature = "gremlin")]
+#[tokio::test]
+async fn id_generation_test_gremlin() {
+    init();
+    clear_db().await;
+
+    id_generation_test(gremlin_test_pool().await);
+}
+
+#[cfg(feature = "in-memory")]
+#[tokio::test]
+async fn id_generation_test_memory() {
+    init();
+    clear_db().await;
+
+    id_generation_test(memory_test_pool().await);
+}
+
+#[cfg(feature = "neo4j")]
+#[tokio::test]
+async fn id_generation_test_neo4j() {
+    init();
+    clear_db().await;
+
+    id_generation_test(neo4j_test_pool().await);
+}
+
+#[cfg(feature = "postgres")]
+#[tokio::test]
+async fn id_generation_test_postgres() {
+    init();
+    clear_db().await;
+
+    id_generation_test(postgres_test_pool().await);
+}
+
+#[cfg(feature = "sqlite")]
+#[tokio::test]
+async fn id_generation_test_sqlite() {
+    init();
+    clear_db().await;
+
+    id_generation_test(sqlite_test_pool().await);
+}
+
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+fn order_number(type_name: &str) -> Result<String, Error> {
+    Ok(type_name.to_lowercase() + "-generated")
+}
+
+/// Passes if ids are generated with the strategy configured for each type, a client-supplied id
+/// is used in place of a generated one, and a duplicate client-supplied id is rejected
+#[allow(dead_code)]
+fn id_generation_test(database_pool: DatabasePool) {
+    let config: Configuration = File::open("./tests/fixtures/id_generation.yml")
+        .expect("Failed to load config file")
+        .try_into()
+        .unwrap();
+    let mut id_generators = IdGenerators::new();
+    id_generators.insert("OrderNumber".to_string(), Arc::new(order_number));
+    let engine = Engine::<()>::new(config, database_pool)
+        .with_id_generators(id_generators)
+        .build()
+        .expect("Could not create warpgrapher engine");
+
+    let event = execute(
+        &engine,
+        "mutation Create($partitionKey: String, $input: EventCreateMutationInput!) {
+            EventCreate(partitionKey: $partitionKey, input: $input) { id }
+        }",
+        json!({"name": "launch"}),
+    );
+    let id = event["data"]["EventCreate"]["id"].as_str().unwrap();
+    assert_eq!(36, id.len());
+    assert_eq!("7", &id[14..15]);
+
+    let order_create = "mutation Create($partitionKey: String, $input: OrderCreateMutationInput!) {
+        OrderCreate(partitionKey: $partitionKey, input: $input) { id }
+    }";
+    let generated = execute(&engine, order_create, json!({"name": "first"}));
+    assert_eq!(generated["data"]["OrderCreate"]["id"], "order-generated");
+
+    let supplied = execute(
+        &engine,
+        order_create,
+        json!({"id": "A-1", "name": "second"}),
+    );
+    assert_eq!(supplied["data"]["OrderCreate"]["id"], "A-1");
+
+    let duplicated = execute(&engine, order_create, json!({"id": "A-1", "name": "third"}));
+    assert!(duplicated["data"]["OrderCreate"].is_null());
+    assert!(duplicated["errors"][0]["message"]
+        .as_str()
+        .unwrap()
+        .contains("A-1"));
+
+    let read = execute(
+        &engine,
+        "query Read($partitionKey: String, $input: OrderQueryInput) {
+            Order(partitionKey: $partitionKey, input: $input) { id name }
+        }",
+        json!({"id": "A-1"}),
+    );
+    assert_eq!(read["data"]["Order"].as_array().unwrap().len(), 1);
+    assert_eq!(read["data"]["Order"][0]["name"], "second");
+}
@@ -0,0 +1,379 @@
+mod setup;
+
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+use serde_json::json;
+#[cfg(feature = "cosmos")]
+use setup::cosmos_test_pool;
+#[cfg(feature = "gremlin")]
+use setup::gremlin_test_pool;
+#[cfg(feature = "in-memory")]
+use setup::memory_test_pool;
+#[cfg(feature = "neo4j")]
+use setup::neo4j_test_pool;
+#[cfg(feature = "postgres")]
+use setup::postgres_test_pool;
+#[cfg(feature = "sqlite")]
+use setup::sqlite_test_pool;
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+use setup::{clear_db, execute, init};
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+use std::convert::{TryFrom, TryInto};
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+use std::fs::File;
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+use std::sync::Arc;
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+use warpgrapher::engine::database::NodeRecord;
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+use warpgrapher::engine::triggers::{TriggerContext, Triggers};
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+use warpgrapher::engine::value::Value;
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+use warpgrapher::{Configuration, DatabasePool, Engine, Error};
+
+#[cfg(feature = "cosmos")]
+#[tokio::test]
+async fn lifecycle_triggers_test_cosmos() {
+    init();
+    clear_db().await;
+
+    lifecycle_triggers_test(cosmos_test_pool().await);
+}
+
+#[cfg(feature = "gremlin")]
+#[tokio::test]
+async fn lifecycle_triggers_test_gremlin() {
+    init();
+    clear_db().await;
+
+    lifecycle_triggers_test(gremlin_test_pool().await);
+}
+
+#[cfg(feature = "in-memory")]
+#[tokio::test]
+async fn lifecycle_triggers_test_memory() {
+    init();
+    clear_db().await;
+
+    lifecycle_triggers_test(memory_test_pool().await);
+}
+
+#[cfg(feature = "neo4j")]
+#[tokio::test]
+async fn lifecycle_triggers_test_neo4j() {
+    init();
+    clear_db().await;
+
+    lifecycle_triggers_test(neo4j_test_pool().await);
+}
+
+#[cfg(feature = "postgres")]
+#[tokio::test]
+async fn lifecycle_triggers_test_postgres() {
+    init();
+    clear_db().await;
+
+    lifecycle_triggers_test(postgres_test_pool().await);
+}
+
+#[cfg(feature = "sqlite")]
+#[tokio::test]
+async fn lifecycle_triggers_test_sqlite() {
+    init();
+    clear_db().await;
+
+    lifecycle_triggers_test(sqlite_test_pool().await);
+}
+
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+fn count_issues(context: &mut TriggerContext<()>) -> Result<(), Error> {
+    let id = match context.new_rel().or_else(|| context.old_rel()) {
+        Some(r) => String::try_from(r.src().0.clone())?,
+        None => return Ok(()),
+    };
+    let input = Value::try_from(json!({"src": {"Project": {"id": id}}}))?;
+    let count = context.read_rels("Project", "issues", Some(input))?.len();
+    context.update_nodes(
+        "Project",
+        Value::try_from(json!({"$MATCH": {"id": id}, "$SET": {"issueCount": count}}))?,
+    )?;
+    Ok(())
+}
+
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+fn log_activity(context: &mut TriggerContext<()>) -> Result<(), Error> {
+    let title = |n: Option<&NodeRecord>| match n.and_then(|n| n.fields().get("title")) {
+        Some(Value::String(t)) => t.clone(),
+        _ => "-".to_string(),
+    };
+    let detail = format!(
+        "{} -> {}",
+        title(context.old_node()),
+        title(context.new_node())
+    );
+    let action = format!("{:?}", context.event());
+    context.create_node(
+        "Activity",
+        Value::try_from(json!({"action": action, "detail": detail}))?,
+    )?;
+    Ok(())
+}
+
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+fn freeze(context: &mut TriggerContext<()>) -> Result<(), Error> {
+    match context.old_node().and_then(|n| n.fields().get("title")) {
+        Some(Value::String(t)) if t == "frozen" => Err(Error::ValidationFailed {
+            message: "Issue is frozen.".to_string(),
+        }),
+        _ => Ok(()),
+    }
+}
+
+/// Passes if lifecycle triggers run within the transaction of a mutation, are passed the old
+/// and new state, write through the same transaction, and fail the mutation on error
+#[allow(dead_code)]
+fn lifecycle_triggers_test(database_pool: DatabasePool) {
+    let config: Configuration = File::open("./tests/fixtures/lifecycle_triggers.yml")
+        .expect("Failed to load config file")
+        .try_into()
+        .unwrap();
+
+    let mut triggers = Triggers::<()>::new();
+    triggers.insert("CountIssues".to_string(), Arc::new(count_issues));
+    triggers.insert("LogActivity".to_string(), Arc::new(log_activity));
+    triggers.insert("Freeze".to_string(), Arc::new(freeze));
+
+    let engine = Engine::<()>::new(config, database_pool)
+        .with_triggers(triggers)
+        .build()
+        .expect("Could not create warpgrapher engine");
+
+    execute(
+        &engine,
+        "mutation Create($partitionKey: String, $input: ProjectCreateMutationInput!) {
+            ProjectCreate(partitionKey: $partitionKey, input: $input) { id }
+        }",
+        json!({"name": "alpha"}),
+    );
+    let create_issue = "mutation Create($partitionKey: String, $input: IssueCreateMutationInput!) {
+        IssueCreate(partitionKey: $partitionKey, input: $input) { id }
+    }";
+    execute(&engine, create_issue, json!({"title": "a"}));
+    execute(&engine, create_issue, json!({"title": "frozen"}));
+
+    let add_issue = "mutation Add($partitionKey: String, $input: ProjectIssuesCreateInput!) {
+        ProjectIssuesCreate(partitionKey: $partitionKey, input: $input) { id }
+    }";
+    for title in &["a", "frozen"] {
+        execute(
+            &engine,
+            add_issue,
+            json!({
+                "$MATCH": {"name": "alpha"},
+                "$CREATE": [{"dst": {"Issue": {"$EXISTING": {"title": title}}}}]
+            }),
+        );
+    }
+
+    let project_query = "query Read($partitionKey: String) {
+        Project(partitionKey: $partitionKey) { issueCount }
+    }";
+    let project = execute(&engine, project_query, json!(null));
+    assert_eq!(project["data"]["Project"][0]["issueCount"], 2);
+
+    let update_issue = "mutation Update($partitionKey: String, $input: IssueUpdateInput!) {
+        IssueUpdate(partitionKey: $partitionKey, input: $input) { title }
+    }";
+    let updated = execute(
+        &engine,
+        update_issue,
+        json!({"$MATCH": {"title": "a"}, "$SET": {"title": "b"}}),
+    );
+    assert_eq!(updated["data"]["IssueUpdate"][0]["title"], "b");
+
+    let frozen = execute(
+        &engine,
+        update_issue,
+        json!({"$MATCH": {"title": "frozen"}, "$SET": {"title": "thawed"}}),
+    );
+    assert!(frozen["errors"][0]["message"]
+        .as_str()
+        .unwrap()
+        .contains("Issue is frozen."));
+
+    let removed = execute(
+        &engine,
+        "mutation Remove($partitionKey: String, $input: ProjectIssuesDeleteInput!) {
+            ProjectIssuesDelete(partitionKey: $partitionKey, input: $input)
+        }",
+        json!({"$MATCH": {"dst": {"Issue": {"title": "b"}}}}),
+    );
+    assert_eq!(removed["data"]["ProjectIssuesDelete"], 1);
+    let project = execute(&engine, project_query, json!(null));
+    assert_eq!(project["data"]["Project"][0]["issueCount"], 1);
+
+    let deleted = execute(
+        &engine,
+        "mutation Delete($partitionKey: String, $input: IssueDeleteInput!) {
+            IssueDelete(partitionKey: $partitionKey, input: $input)
+        }",
+        json!({"$MATCH": {"title": "b"}}),
+    );
+    assert_eq!(deleted["data"]["IssueDelete"], 1);
+
+    let activity = execute(
+        &engine,
+        "query Read($partitionKey: String) {
+            Activity(partitionKey: $partitionKey) { action detail }
+        }",
+        json!(null),
+    );
+    let mut activity = activity["data"]["Activity"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|a| {
+            format!(
+                "{} {}",
+                a["action"].as_str().unwrap(),
+                a["detail"].as_str().unwrap()
+            )
+        })
+        .collect::<Vec<_>>();
+    activity.sort();
+    assert_eq!(
+        activity,
+        vec![
+            "AfterCreate - -> a",
+            "AfterCreate - -> frozen",
+            "AfterUpdate a -> b",
+            "BeforeDelete b -> -",
+        ]
+    );
+    let issues = execute(
+        &engine,
+        "query Read($partitionKey: String) {
+            Issue(partitionKey: $partitionKey) { title }
+        }",
+        json!(null),
+    );
+    assert_eq!(issues["data"]["Issue"].as_array().unwrap().len(), 1);
+    assert_eq!(issues["data"]["Issue"][0]["title"], "frozen");
+
+    // Triggers run only for the top-level writes of a mutation, so neither the Issue's nor the
+    // relationship's triggers run for an issue created through nested input.
+    execute(
+        &engine,
+        "mutation Create($partitionKey: String, $input: ProjectCreateMutationInput!) {
+            ProjectCreate(partitionKey: $partitionKey, input: $input) { id }
+        }",
+        json!({"name": "beta", "issues": {"dst": {"Issue": {"$NEW": {"title": "c"}}}}}),
+    );
+    let project = execute(
+        &engine,
+        "query Read($partitionKey: String, $input: ProjectQueryInput) {
+            Project(partitionKey: $partitionKey, input: $input) {
+                issueCount
+                issues { dst { ... on Issue { title } } }
+            }
+        }",
+        json!({"name": "beta"}),
+    );
+    assert_eq!(
+        project["data"]["Project"][0]["issues"][0]["dst"]["title"],
+        "c"
+    );
+    assert!(project["data"]["Project"][0]["issueCount"].is_null());
+    let activity = execute(
+        &engine,
+        "query Read($partitionKey: String) {
+            Activity(partitionKey: $partitionKey) { detail }
+        }",
+        json!(null),
+    );
+    assert_eq!(activity["data"]["Activity"].as_array().unwrap().len(), 4);
+}
@@ -6,10 +6,23 @@ use serde_json::json;
 use setup::cosmos_test_client;
 #[cfg(feature = "gremlin")]
 use setup::gremlin_test_client;
+#[cfg(feature = "in-memory")]
+use setup::memory_test_client;
 #[cfg(feature = "neo4j")]
 use setup::neo4j_test_client;
+#[cfg(feature = "postgres")]
+use setup::postgres_test_client;
+#[cfg(feature = "sqlite")]
+use setup::sqlite_test_client;
 use setup::AppRequestCtx;
-#[cfg(any(feature = "cosmos", feature = "gremlin", feature = "neo4j"))]
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
 use setup::{clear_db, init};
 use warpgrapher::client::Client;
 
@@ -33,6 +46,16 @@ async fn create_mnmt_new_nodes_gremlin() {
     create_mnmt_new_nodes(client).await;
 }
 
+#[cfg(feature = "in-memory")]
+#[tokio::test]
+async fn create_mnmt_new_nodes_memory() {
+    init();
+    clear_db().await;
+
+    let client = memory_test_client("./tests/fixtures/minimal.yml").await;
+    create_mnmt_new_nodes(client).await;
+}
+
 #[cfg(feature = "neo4j")]
 #[tokio::test]
 async fn create_mnmt_new_nodes_neo4j() {
@@ -43,6 +66,26 @@ async fn create_mnmt_new_nodes_neo4j() {
     create_mnmt_new_nodes(client).await;
 }
 
+#[cfg(feature = "postgres")]
+#[tokio::test]
+async fn create_mnmt_new_nodes_postgres() {
+    init();
+    clear_db().await;
+
+    let client = postgres_test_client("./tests/fixtures/minimal.yml").await;
+    create_mnmt_new_nodes(client).await;
+}
+
+#[cfg(feature = "sqlite")]
+#[tokio::test]
+async fn create_mnmt_new_nodes_sqlite() {
+    init();
+    clear_db().await;
+
+    let client = sqlite_test_client("./tests/fixtures/minimal.yml").await;
+    create_mnmt_new_nodes(client).await;
+}
+
 /// Passes if warpgrapher can create a node with a relationship to another new node
 #[allow(clippy::cognitive_complexity, dead_code)]
 async fn create_mnmt_new_nodes(mut client: Client<AppRequestCtx>) {
@@ -214,6 +257,18 @@ async fn create_mnmt_existing_nodes_gremlin() {
     println!("create_mnmt_new_nodes::end");
 }
 
+#[cfg(feature = "in-memory")]
+#[tokio::test]
+async fn create_mnmt_existing_nodes_memory() {
+    init();
+    clear_db().await;
+
+    let client = memory_test_client("./tests/fixtures/minimal.yml").await;
+    create_mnmt_existing_nodes(client).await;
+
+    println!("create_mnmt_new_nodes::end");
+}
+
 #[cfg(feature = "neo4j")]
 #[tokio::test]
 async fn create_mnmt_existing_nodes_neo4j() {
@@ -224,6 +279,30 @@ async fn create_mnmt_existing_nodes_neo4j() {
     create_mnmt_existing_nodes(client).await;
 }
 
+#[cfg(feature = "postgres")]
+#[tokio::test]
+async fn create_mnmt_existing_nodes_postgres() {
+    init();
+    clear_db().await;
+
+    let client = postgres_test_client("./tests/fixtures/minimal.yml").await;
+    create_mnmt_existing_nodes(client).await;
+
+    println!("create_mnmt_new_nodes::end");
+}
+
+#[cfg(feature = "sqlite")]
+#[tokio::test]
+async fn create_mnmt_existing_nodes_sqlite() {
+    init();
+    clear_db().await;
+
+    let client = sqlite_test_client("./tests/fixtures/minimal.yml").await;
+    create_mnmt_existing_nodes(client).await;
+
+    println!("create_mnmt_new_nodes::end");
+}
+
 /// Passes if warpgrapher can create a node with a relationship to an existing node
 #[allow(clippy::cognitive_complexity, dead_code)]
 async fn create_mnmt_existing_nodes(mut client: Client<AppRequestCtx>) {
@@ -349,6 +428,18 @@ async fn read_mnmt_by_rel_props_gremlin() {
     println!("create_mnmt_existing_nodes::end");
 }
 
+#[cfg(feature = "in-memory")]
+#[tokio::test]
+async fn read_mnmt_by_rel_props_memory() {
+    init();
+    clear_db().await;
+
+    let client = memory_test_client("./tests/fixtures/minimal.yml").await;
+    read_mnmt_by_rel_props(client).await;
+
+    println!("create_mnmt_existing_nodes::end");
+}
+
 #[cfg(feature = "neo4j")]
 #[tokio::test]
 async fn read_mnmt_by_rel_props_neo4j() {
@@ -359,6 +450,30 @@ async fn read_mnmt_by_rel_props_neo4j() {
     read_mnmt_by_rel_props(client).await;
 }
 
+#[cfg(feature = "postgres")]
+#[tokio::test]
+async fn read_mnmt_by_rel_props_postgres() {
+    init();
+    clear_db().await;
+
+    let client = postgres_test_client("./tests/fixtures/minimal.yml").await;
+    read_mnmt_by_rel_props(client).await;
+
+    println!("create_mnmt_existing_nodes::end");
+}
+
+#[cfg(feature = "sqlite")]
+#[tokio::test]
+async fn read_mnmt_by_rel_props_sqlite() {
+    init();
+    clear_db().await;
+
+    let client = sqlite_test_client("./tests/fixtures/minimal.yml").await;
+    read_mnmt_by_rel_props(client).await;
+
+    println!("create_mnmt_existing_nodes::end");
+}
+
 /// Passes if warpgrapher can query for a relationship by the properties of a relationship
 #[allow(clippy::cognitive_complexity, dead_code)]
 async fn read_mnmt_by_rel_props(mut client: Client<AppRequestCtx>) {
@@ -440,6 +555,16 @@ async fn read_mnmt_by_dst_props_gremlin() {
     read_mnmt_by_dst_props(client).await;
 }
 
+#[cfg(feature = "in-memory")]
+#[tokio::test]
+async fn read_mnmt_by_dst_props_memory() {
+    init();
+    clear_db().await;
+
+    let client = memory_test_client("./tests/fixtures/minimal.yml").await;
+    read_mnmt_by_dst_props(client).await;
+}
+
 #[cfg(feature = "neo4j")]
 #[tokio::test]
 async fn read_mnmt_by_dst_props_neo4j() {
@@ -450,6 +575,26 @@ async fn read_mnmt_by_dst_props_neo4j() {
     read_mnmt_by_dst_props(client).await;
 }
 
+#[cfg(feature = "postgres")]
+#[tokio::test]
+async fn read_mnmt_by_dst_props_postgres() {
+    init();
+    clear_db().await;
+
+    let client = postgres_test_client("./tests/fixtures/minimal.yml").await;
+    read_mnmt_by_dst_props(client).await;
+}
+
+#[cfg(feature = "sqlite")]
+#[tokio::test]
+async fn read_mnmt_by_dst_props_sqlite() {
+    init();
+    clear_db().await;
+
+    let client = sqlite_test_client("./tests/fixtures/minimal.yml").await;
+    read_mnmt_by_dst_props(client).await;
+}
+
 /// Passes if warpgrapher can query for a relationship by the properties of a destination node
 #[allow(clippy::cognitive_complexity, dead_code)]
 async fn read_mnmt_by_dst_props(mut client: Client<AppRequestCtx>) {
@@ -543,6 +688,16 @@ async fn update_mnmt_new_node_gremlin() {
     update_mnmt_new_node(client).await;
 }
 
+#[cfg(feature = "in-memory")]
+#[tokio::test]
+async fn update_mnmt_new_node_memory() {
+    init();
+    clear_db().await;
+
+    let client = memory_test_client("./tests/fixtures/minimal.yml").await;
+    update_mnmt_new_node(client).await;
+}
+
 #[cfg(feature = "neo4j")]
 #[tokio::test]
 async fn update_mnmt_new_node_neo4j() {
@@ -553,6 +708,26 @@ async fn update_mnmt_new_node_neo4j() {
     update_mnmt_new_node(client).await;
 }
 
+#[cfg(feature = "postgres")]
+#[tokio::test]
+async fn update_mnmt_new_node_postgres() {
+    init();
+    clear_db().await;
+
+    let client = postgres_test_client("./tests/fixtures/minimal.yml").await;
+    update_mnmt_new_node(client).await;
+}
+
+#[cfg(feature = "sqlite")]
+#[tokio::test]
+async fn update_mnmt_new_node_sqlite() {
+    init();
+    clear_db().await;
+
+    let client = sqlite_test_client("./tests/fixtures/minimal.yml").await;
+    update_mnmt_new_node(client).await;
+}
+
 /// Passes if warpgrapher can update a node to add a relationship to a new node
 #[allow(clippy::cognitive_complexity, dead_code)]
 async fn update_mnmt_new_node(mut client: Client<AppRequestCtx>) {
@@ -640,6 +815,16 @@ async fn update_mnmt_existing_nodes_gremlin() {
     update_mnmt_existing_nodes(client).await;
 }
 
+#[cfg(feature = "in-memory")]
+#[tokio::test]
+async fn update_mnmt_existing_nodes_memory() {
+    init();
+    clear_db().await;
+
+    let client = memory_test_client("./tests/fixtures/minimal.yml").await;
+    update_mnmt_existing_nodes(client).await;
+}
+
 #[cfg(feature = "neo4j")]
 #[tokio::test]
 async fn update_mnmt_existing_nodes_neo4j() {
@@ -650,6 +835,26 @@ async fn update_mnmt_existing_nodes_neo4j() {
     update_mnmt_existing_nodes(client).await;
 }
 
+#[cfg(feature = "postgres")]
+#[tokio::test]
+async fn update_mnmt_existing_nodes_postgres() {
+    init();
+    clear_db().await;
+
+    let client = postgres_test_client("./tests/fixtures/minimal.yml").await;
+    update_mnmt_existing_nodes(client).await;
+}
+
+#[cfg(feature = "sqlite")]
+#[tokio::test]
+async fn update_mnmt_existing_nodes_sqlite() {
+    init();
+    clear_db().await;
+
+    let client = sqlite_test_client("./tests/fixtures/minimal.yml").await;
+    update_mnmt_existing_nodes(client).await;
+}
+
 /// Passes if warpgrapher can update a node to add a relationship to an existing node
 #[allow(clippy::cognitive_complexity, dead_code)]
 async fn update_mnmt_existing_nodes(mut client: Client<AppRequestCtx>) {
@@ -749,6 +954,16 @@ async fn update_mnmt_relationship_gremlin() {
     update_mnmt_relationship(client).await;
 }
 
+#[cfg(feature = "in-memory")]
+#[tokio::test]
+async fn update_mnmt_relationship_memory() {
+    init();
+    clear_db().await;
+
+    let client = memory_test_client("./tests/fixtures/minimal.yml").await;
+    update_mnmt_relationship(client).await;
+}
+
 #[cfg(feature = "neo4j")]
 #[tokio::test]
 async fn update_mnmt_relationship_neo4j() {
@@ -759,6 +974,26 @@ async fn update_mnmt_relationship_neo4j() {
     update_mnmt_relationship(client).await;
 }
 
+#[cfg(feature = "postgres")]
+#[tokio::test]
+async fn update_mnmt_relationship_postgres() {
+    init();
+    clear_db().await;
+
+    let client = postgres_test_client("./tests/fixtures/minimal.yml").await;
+    update_mnmt_relationship(client).await;
+}
+
+#[cfg(feature = "sqlite")]
+#[tokio::test]
+async fn update_mnmt_relationship_sqlite() {
+    init();
+    clear_db().await;
+
+    let client = sqlite_test_client("./tests/fixtures/minimal.yml").await;
+    update_mnmt_relationship(client).await;
+}
+
 /// Passes if warpgrapher can update a relationship
 #[allow(clippy::cognitive_complexity, dead_code)]
 async fn update_mnmt_relationship(mut client: Client<AppRequestCtx>) {
@@ -890,6 +1125,16 @@ async fn update_only_correct_mnmt_relationship_gremlin() {
     update_only_correct_mnmt_relationship(client).await;
 }
 
+#[cfg(feature = "in-memory")]
+#[tokio::test]
+async fn update_only_correct_mnmt_relationship_memory() {
+    init();
+    clear_db().await;
+
+    let client = memory_test_client("./tests/fixtures/minimal.yml").await;
+    update_only_correct_mnmt_relationship(client).await;
+}
+
 #[cfg(feature = "neo4j")]
 #[tokio::test]
 async fn update_only_correct_mnmt_relationship_neo4j() {
@@ -900,6 +1145,26 @@ async fn update_only_correct_mnmt_relationship_neo4j() {
     update_only_correct_mnmt_relationship(client).await;
 }
 
+#[cfg(feature = "postgres")]
+#[tokio::test]
+async fn update_only_correct_mnmt_relationship_postgres() {
+    init();
+    clear_db().await;
+
+    let client = postgres_test_client("./tests/fixtures/minimal.yml").await;
+    update_only_correct_mnmt_relationship(client).await;
+}
+
+#[cfg(feature = "sqlite")]
+#[tokio::test]
+async fn update_only_correct_mnmt_relationship_sqlite() {
+    init();
+    clear_db().await;
+
+    let client = sqlite_test_client("./tests/fixtures/minimal.yml").await;
+    update_only_correct_mnmt_relationship(client).await;
+}
+
 /// Passes if warpgrapher only updates the correct matching relationship
 #[allow(clippy::cognitive_complexity, dead_code)]
 async fn update_only_correct_mnmt_relationship(mut client: Client<AppRequestCtx>) {
@@ -1002,6 +1267,16 @@ async fn delete_mnmt_relationship_gremlin() {
     delete_mnmt_relationship(client).await;
 }
 
+#[cfg(feature = "in-memory")]
+#[tokio::test]
+async fn delete_mnmt_relationship_memory() {
+    init();
+    clear_db().await;
+
+    let client = memory_test_client("./tests/fixtures/minimal.yml").await;
+    delete_mnmt_relationship(client).await;
+}
+
 #[cfg(feature = "neo4j")]
 #[tokio::test]
 async fn delete_mnmt_relationship_neo4j() {
@@ -1012,6 +1287,26 @@ async fn delete_mnmt_relationship_neo4j() {
     delete_mnmt_relationship(client).await;
 }
 
+#[cfg(feature = "postgres")]
+#[tokio::test]
+async fn delete_mnmt_relationship_postgres() {
+    init();
+    clear_db().await;
+
+    let client = postgres_test_client("./tests/fixtures/minimal.yml").await;
+    delete_mnmt_relationship(client).await;
+}
+
+#[cfg(feature = "sqlite")]
+#[tokio::test]
+async fn delete_mnmt_relationship_sqlite() {
+    init();
+    clear_db().await;
+
+    let client = sqlite_test_client("./tests/fixtures/minimal.yml").await;
+    delete_mnmt_relationship(client).await;
+}
+
 /// Passes if warpgrapher can update a node to delete a relationship
 #[allow(clippy::cognitive_complexity, dead_code)]
 async fn delete_mnmt_relationship(mut client: Client<AppRequestCtx>) {
@@ -1125,6 +1420,16 @@ async fn delete_node_by_mnmt_rel_property_gremlin() {
     delete_node_by_mnmt_rel_property(client).await;
 }
 
+#[cfg(feature = "in-memory")]
+#[tokio::test]
+async fn delete_node_by_mnmt_rel_property_memory() {
+    init();
+    clear_db().await;
+
+    let client = memory_test_client("./tests/fixtures/minimal.yml").await;
+    delete_node_by_mnmt_rel_property(client).await;
+}
+
 #[cfg(feature = "neo4j")]
 #[tokio::test]
 async fn delete_node_by_mnmt_rel_property_neo4j() {
@@ -1135,6 +1440,26 @@ async fn delete_node_by_mnmt_rel_property_neo4j() {
     delete_node_by_mnmt_rel_property(client).await;
 }
 
+#[cfg(feature = "postgres")]
+#[tokio::test]
+async fn delete_node_by_mnmt_rel_property_postgres() {
+    init();
+    clear_db().await;
+
+    let client = postgres_test_client("./tests/fixtures/minimal.yml").await;
+    delete_node_by_mnmt_rel_property(client).await;
+}
+
+#[cfg(feature = "sqlite")]
+#[tokio::test]
+async fn delete_node_by_mnmt_rel_property_sqlite() {
+    init();
+    clear_db().await;
+
+    let client = sqlite_test_client("./tests/fixtures/minimal.yml").await;
+    delete_node_by_mnmt_rel_property(client).await;
+}
+
 /// Passes if warpgrapher can delete a node based on matching a property on a rel.
 #[allow(clippy::cognitive_complexity, dead_code)]
 async fn delete_node_by_mnmt_rel_property(mut client: Client<AppRequestCtx>) {
@@ -1208,6 +1533,16 @@ async fn delete_node_gremlin() {
     delete_node(client).await;
 }
 
+#[cfg(feature = "in-memory")]
+#[tokio::test]
+async fn delete_node_memory() {
+    init();
+    clear_db().await;
+
+    let client = memory_test_client("./tests/fixtures/minimal.yml").await;
+    delete_node(client).await;
+}
+
 #[cfg(feature = "neo4j")]
 #[tokio::test]
 async fn delete_node_neo4j() {
@@ -1218,6 +1553,26 @@ async fn delete_node_neo4j() {
     delete_node(client).await;
 }
 
+#[cfg(feature = "postgres")]
+#[tokio::test]
+async fn delete_node_postgres() {
+    init();
+    clear_db().await;
+
+    let client = postgres_test_client("./tests/fixtures/minimal.yml").await;
+    delete_node(client).await;
+}
+
+#[cfg(feature = "sqlite")]
+#[tokio::test]
+async fn delete_node_sqlite() {
+    init();
+    clear_db().await;
+
+    let client = sqlite_test_client("./tests/fixtures/minimal.yml").await;
+    delete_node(client).await;
+}
+
 /// Passes if warpgrapher can delete a node
 #[allow(clippy::cognitive_complexity, dead_code)]
 async fn delete_node(mut client: Client<AppRequestCtx>) {
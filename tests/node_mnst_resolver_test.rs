@@ -5,10 +5,23 @@ use serde_json::json;
 use setup::cosmos_test_client;
 #[cfg(feature = "gremlin")]
 use setup::gremlin_test_client;
+#[cfg(feature = "in-memory")]
+use setup::memory_test_client;
 #[cfg(feature = "neo4j")]
 use setup::neo4j_test_client;
+#[cfg(feature = "postgres")]
+use setup::postgres_test_client;
+#[cfg(feature = "sqlite")]
+use setup::sqlite_test_client;
 use setup::AppRequestCtx;
-#[cfg(any(feature = "cosmos", feature = "gremlin", feature = "neo4j"))]
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
 use setup::{clear_db, init};
 use warpgrapher::client::Client;
 
@@ -32,6 +45,16 @@ async fn create_mnst_new_nodes_gremlin() {
     create_mnst_new_nodes(client).await;
 }
 
+#[cfg(feature = "in-memory")]
+#[tokio::test]
+async fn create_mnst_new_nodes_memory() {
+    init();
+    clear_db().await;
+
+    let client = memory_test_client("./tests/fixtures/minimal.yml").await;
+    create_mnst_new_nodes(client).await;
+}
+
 #[cfg(feature = "neo4j")]
 #[tokio::test]
 async fn create_mnst_new_nodes_neo4j() {
@@ -42,6 +65,26 @@ async fn create_mnst_new_nodes_neo4j() {
     create_mnst_new_nodes(client).await;
 }
 
+#[cfg(feature = "postgres")]
+#[tokio::test]
+async fn create_mnst_new_nodes_postgres() {
+    init();
+    clear_db().await;
+
+    let client = postgres_test_client("./tests/fixtures/minimal.yml").await;
+    create_mnst_new_nodes(client).await;
+}
+
+#[cfg(feature = "sqlite")]
+#[tokio::test]
+async fn create_mnst_new_nodes_sqlite() {
+    init();
+    clear_db().await;
+
+    let client = sqlite_test_client("./tests/fixtures/minimal.yml").await;
+    create_mnst_new_nodes(client).await;
+}
+
 /// Passes if warpgrapher can create a node with a relationship to another new node
 #[allow(clippy::cognitive_complexity, dead_code)]
 async fn create_mnst_new_nodes(mut client: Client<AppRequestCtx>) {
@@ -182,6 +225,16 @@ async fn create_mnst_existing_nodes_gremlin() {
     create_mnst_existing_nodes(client).await;
 }
 
+#[cfg(feature = "in-memory")]
+#[tokio::test]
+async fn create_mnst_existing_nodes_memory() {
+    init();
+    clear_db().await;
+
+    let client = memory_test_client("./tests/fixtures/minimal.yml").await;
+    create_mnst_existing_nodes(client).await;
+}
+
 #[cfg(feature = "neo4j")]
 #[tokio::test]
 async fn create_mnst_existing_nodes_neo4j() {
@@ -192,6 +245,26 @@ async fn create_mnst_existing_nodes_neo4j() {
     create_mnst_existing_nodes(client).await;
 }
 
+#[cfg(feature = "postgres")]
+#[tokio::test]
+async fn create_mnst_existing_nodes_postgres() {
+    init();
+    clear_db().await;
+
+    let client = postgres_test_client("./tests/fixtures/minimal.yml").await;
+    create_mnst_existing_nodes(client).await;
+}
+
+#[cfg(feature = "sqlite")]
+#[tokio::test]
+async fn create_mnst_existing_nodes_sqlite() {
+    init();
+    clear_db().await;
+
+    let client = sqlite_test_client("./tests/fixtures/minimal.yml").await;
+    create_mnst_existing_nodes(client).await;
+}
+
 /// Passes if warpgrapher can create a node with a relationship to an existing node
 #[allow(clippy::cognitive_complexity, dead_code)]
 async fn create_mnst_existing_nodes(mut client: Client<AppRequestCtx>) {
@@ -306,6 +379,16 @@ async fn read_mnst_by_rel_props_gremlin() {
     read_mnst_by_rel_props(client).await;
 }
 
+#[cfg(feature = "in-memory")]
+#[tokio::test]
+async fn read_mnst_by_rel_props_memory() {
+    init();
+    clear_db().await;
+
+    let client = memory_test_client("./tests/fixtures/minimal.yml").await;
+    read_mnst_by_rel_props(client).await;
+}
+
 #[cfg(feature = "neo4j")]
 #[tokio::test]
 async fn read_mnst_by_rel_props_neo4j() {
@@ -316,6 +399,26 @@ async fn read_mnst_by_rel_props_neo4j() {
     read_mnst_by_rel_props(client).await;
 }
 
+#[cfg(feature = "postgres")]
+#[tokio::test]
+async fn read_mnst_by_rel_props_postgres() {
+    init();
+    clear_db().await;
+
+    let client = postgres_test_client("./tests/fixtures/minimal.yml").await;
+    read_mnst_by_rel_props(client).await;
+}
+
+#[cfg(feature = "sqlite")]
+#[tokio::test]
+async fn read_mnst_by_rel_props_sqlite() {
+    init();
+    clear_db().await;
+
+    let client = sqlite_test_client("./tests/fixtures/minimal.yml").await;
+    read_mnst_by_rel_props(client).await;
+}
+
 /// Passes if warpgrapher can query for a relationship by the properties of a relationship
 #[allow(clippy::cognitive_complexity, dead_code)]
 async fn read_mnst_by_rel_props(mut client: Client<AppRequestCtx>) {
@@ -388,6 +491,16 @@ async fn read_mnst_by_dst_props_gremlin() {
     read_mnst_by_dst_props(client).await;
 }
 
+#[cfg(feature = "in-memory")]
+#[tokio::test]
+async fn read_mnst_by_dst_props_memory() {
+    init();
+    clear_db().await;
+
+    let client = memory_test_client("./tests/fixtures/minimal.yml").await;
+    read_mnst_by_dst_props(client).await;
+}
+
 #[cfg(feature = "neo4j")]
 #[tokio::test]
 async fn read_mnst_by_dst_props_neo4j() {
@@ -398,6 +511,26 @@ async fn read_mnst_by_dst_props_neo4j() {
     read_mnst_by_dst_props(client).await;
 }
 
+#[cfg(feature = "postgres")]
+#[tokio::test]
+async fn read_mnst_by_dst_props_postgres() {
+    init();
+    clear_db().await;
+
+    let client = postgres_test_client("./tests/fixtures/minimal.yml").await;
+    read_mnst_by_dst_props(client).await;
+}
+
+#[cfg(feature = "sqlite")]
+#[tokio::test]
+async fn read_mnst_by_dst_props_sqlite() {
+    init();
+    clear_db().await;
+
+    let client = sqlite_test_client("./tests/fixtures/minimal.yml").await;
+    read_mnst_by_dst_props(client).await;
+}
+
 /// Passes if warpgrapher can query for a relationship by the properties of a relationship dst object
 #[allow(clippy::cognitive_complexity, dead_code)]
 async fn read_mnst_by_dst_props(mut client: Client<AppRequestCtx>) {
@@ -470,6 +603,16 @@ async fn update_mnst_new_node_gremlin() {
     update_mnst_new_node(client).await;
 }
 
+#[cfg(feature = "in-memory")]
+#[tokio::test]
+async fn update_mnst_new_node_memory() {
+    init();
+    clear_db().await;
+
+    let client = memory_test_client("./tests/fixtures/minimal.yml").await;
+    update_mnst_new_node(client).await;
+}
+
 #[cfg(feature = "neo4j")]
 #[tokio::test]
 async fn update_mnst_new_node_neo4j() {
@@ -480,6 +623,26 @@ async fn update_mnst_new_node_neo4j() {
     update_mnst_new_node(client).await;
 }
 
+#[cfg(feature = "postgres")]
+#[tokio::test]
+async fn update_mnst_new_node_postgres() {
+    init();
+    clear_db().await;
+
+    let client = postgres_test_client("./tests/fixtures/minimal.yml").await;
+    update_mnst_new_node(client).await;
+}
+
+#[cfg(feature = "sqlite")]
+#[tokio::test]
+async fn update_mnst_new_node_sqlite() {
+    init();
+    clear_db().await;
+
+    let client = sqlite_test_client("./tests/fixtures/minimal.yml").await;
+    update_mnst_new_node(client).await;
+}
+
 /// Passes if warpgrapher can query for a relationship by the properties of a relationship
 #[allow(clippy::cognitive_complexity, dead_code)]
 async fn update_mnst_new_node(mut client: Client<AppRequestCtx>) {
@@ -551,6 +714,16 @@ async fn update_mnst_existing_node_gremlin() {
     update_mnst_existing_node(client).await;
 }
 
+#[cfg(feature = "in-memory")]
+#[tokio::test]
+async fn update_mnst_existing_node_memory() {
+    init();
+    clear_db().await;
+
+    let client = memory_test_client("./tests/fixtures/minimal.yml").await;
+    update_mnst_existing_node(client).await;
+}
+
 #[cfg(feature = "neo4j")]
 #[tokio::test]
 async fn update_mnst_existing_node_neo4j() {
@@ -561,6 +734,26 @@ async fn update_mnst_existing_node_neo4j() {
     update_mnst_existing_node(client).await;
 }
 
+#[cfg(feature = "postgres")]
+#[tokio::test]
+async fn update_mnst_existing_node_postgres() {
+    init();
+    clear_db().await;
+
+    let client = postgres_test_client("./tests/fixtures/minimal.yml").await;
+    update_mnst_existing_node(client).await;
+}
+
+#[cfg(feature = "sqlite")]
+#[tokio::test]
+async fn update_mnst_existing_node_sqlite() {
+    init();
+    clear_db().await;
+
+    let client = sqlite_test_client("./tests/fixtures/minimal.yml").await;
+    update_mnst_existing_node(client).await;
+}
+
 /// Passes if warpgrapher can query for a relationship by the properties of a relationship
 #[allow(clippy::cognitive_complexity, dead_code)]
 async fn update_mnst_existing_node(mut client: Client<AppRequestCtx>) {
@@ -642,6 +835,16 @@ async fn update_mnst_relationship_gremlin() {
     update_mnst_relationship(client).await;
 }
 
+#[cfg(feature = "in-memory")]
+#[tokio::test]
+async fn update_mnst_relationship_memory() {
+    init();
+    clear_db().await;
+
+    let client = memory_test_client("./tests/fixtures/minimal.yml").await;
+    update_mnst_relationship(client).await;
+}
+
 #[cfg(feature = "neo4j")]
 #[tokio::test]
 async fn update_mnst_relationship_neo4j() {
@@ -652,6 +855,26 @@ async fn update_mnst_relationship_neo4j() {
     update_mnst_relationship(client).await;
 }
 
+#[cfg(feature = "postgres")]
+#[tokio::test]
+async fn update_mnst_relationship_postgres() {
+    init();
+    clear_db().await;
+
+    let client = postgres_test_client("./tests/fixtures/minimal.yml").await;
+    update_mnst_relationship(client).await;
+}
+
+#[cfg(feature = "sqlite")]
+#[tokio::test]
+async fn update_mnst_relationship_sqlite() {
+    init();
+    clear_db().await;
+
+    let client = sqlite_test_client("./tests/fixtures/minimal.yml").await;
+    update_mnst_relationship(client).await;
+}
+
 /// Passes if warpgrapher can query for a relationship by the properties of a relationship
 #[allow(clippy::cognitive_complexity, dead_code)]
 async fn update_mnst_relationship(mut client: Client<AppRequestCtx>) {
@@ -726,6 +949,16 @@ async fn delete_mnst_relationship_by_rel_props_gremlin() {
     delete_mnst_relationship_by_rel_props(client).await;
 }
 
+#[cfg(feature = "in-memory")]
+#[tokio::test]
+async fn delete_mnst_relationship_by_rel_props_memory() {
+    init();
+    clear_db().await;
+
+    let client = memory_test_client("./tests/fixtures/minimal.yml").await;
+    delete_mnst_relationship_by_rel_props(client).await;
+}
+
 #[cfg(feature = "neo4j")]
 #[tokio::test]
 async fn delete_mnst_relationship_by_rel_props_neo4j() {
@@ -736,6 +969,26 @@ async fn delete_mnst_relationship_by_rel_props_neo4j() {
     delete_mnst_relationship_by_rel_props(client).await;
 }
 
+#[cfg(feature = "postgres")]
+#[tokio::test]
+async fn delete_mnst_relationship_by_rel_props_postgres() {
+    init();
+    clear_db().await;
+
+    let client = postgres_test_client("./tests/fixtures/minimal.yml").await;
+    delete_mnst_relationship_by_rel_props(client).await;
+}
+
+#[cfg(feature = "sqlite")]
+#[tokio::test]
+async fn delete_mnst_relationship_by_rel_props_sqlite() {
+    init();
+    clear_db().await;
+
+    let client = sqlite_test_client("./tests/fixtures/minimal.yml").await;
+    delete_mnst_relationship_by_rel_props(client).await;
+}
+
 /// Passes if warpgrapher can delete a relationship by its properties
 #[allow(clippy::cognitive_complexity, dead_code)]
 async fn delete_mnst_relationship_by_rel_props(mut client: Client<AppRequestCtx>) {
@@ -818,6 +1071,16 @@ async fn delete_mnst_relationship_by_dst_props_gremlin() {
     delete_mnst_relationship_by_dst_props(client).await;
 }
 
+#[cfg(feature = "in-memory")]
+#[tokio::test]
+async fn delete_mnst_relationship_by_dst_props_memory() {
+    init();
+    clear_db().await;
+
+    let client = memory_test_client("./tests/fixtures/minimal.yml").await;
+    delete_mnst_relationship_by_dst_props(client).await;
+}
+
 #[cfg(feature = "neo4j")]
 #[tokio::test]
 async fn delete_mnst_relationship_by_dst_props_neo4j() {
@@ -828,6 +1091,26 @@ async fn delete_mnst_relationship_by_dst_props_neo4j() {
     delete_mnst_relationship_by_dst_props(client).await;
 }
 
+#[cfg(feature = "postgres")]
+#[tokio::test]
+async fn delete_mnst_relationship_by_dst_props_postgres() {
+    init();
+    clear_db().await;
+
+    let client = postgres_test_client("./tests/fixtures/minimal.yml").await;
+    delete_mnst_relationship_by_dst_props(client).await;
+}
+
+#[cfg(feature = "sqlite")]
+#[tokio::test]
+async fn delete_mnst_relationship_by_dst_props_sqlite() {
+    init();
+    clear_db().await;
+
+    let client = sqlite_test_client("./tests/fixtures/minimal.yml").await;
+    delete_mnst_relationship_by_dst_props(client).await;
+}
+
 /// Passes if warpgrapher can delete a relationship by the properties of the dst object
 #[allow(clippy::cognitive_complexity, dead_code)]
 async fn delete_mnst_relationship_by_dst_props(mut client: Client<AppRequestCtx>) {
@@ -910,6 +1193,16 @@ async fn delete_node_by_mnst_rel_property_gremlin() {
     delete_node_by_mnst_rel_property(client).await;
 }
 
+#[cfg(feature = "in-memory")]
+#[tokio::test]
+async fn delete_node_by_mnst_rel_property_memory() {
+    init();
+    clear_db().await;
+
+    let client = memory_test_client("./tests/fixtures/minimal.yml").await;
+    delete_node_by_mnst_rel_property(client).await;
+}
+
 #[cfg(feature = "neo4j")]
 #[tokio::test]
 async fn delete_node_by_mnst_rel_property_neo4j() {
@@ -920,6 +1213,26 @@ async fn delete_node_by_mnst_rel_property_neo4j() {
     delete_node_by_mnst_rel_property(client).await;
 }
 
+#[cfg(feature = "postgres")]
+#[tokio::test]
+async fn delete_node_by_mnst_rel_property_postgres() {
+    init();
+    clear_db().await;
+
+    let client = postgres_test_client("./tests/fixtures/minimal.yml").await;
+    delete_node_by_mnst_rel_property(client).await;
+}
+
+#[cfg(feature = "sqlite")]
+#[tokio::test]
+async fn delete_node_by_mnst_rel_property_sqlite() {
+    init();
+    clear_db().await;
+
+    let client = sqlite_test_client("./tests/fixtures/minimal.yml").await;
+    delete_node_by_mnst_rel_property(client).await;
+}
+
 /// Passes if warpgrapher can delete a node by the properties of a relationship
 #[allow(clippy::cognitive_complexity, dead_code)]
 async fn delete_node_by_mnst_rel_property(mut client: Client<AppRequestCtx>) {
@@ -983,6 +1296,16 @@ async fn delete_node_by_mnst_dst_property_gremlin() {
     delete_node_by_mnst_dst_property(client).await;
 }
 
+#[cfg(feature = "in-memory")]
+#[tokio::test]
+async fn delete_node_by_mnst_dst_property_memory() {
+    init();
+    clear_db().await;
+
+    let client = memory_test_client("./tests/fixtures/minimal.yml").await;
+    delete_node_by_mnst_dst_property(client).await;
+}
+
 #[cfg(feature = "neo4j")]
 #[tokio::test]
 async fn delete_node_by_mnst_dst_property_neo4j() {
@@ -993,6 +1316,26 @@ async fn delete_node_by_mnst_dst_property_neo4j() {
     delete_node_by_mnst_dst_property(client).await;
 }
 
+#[cfg(feature = "postgres")]
+#[tokio::test]
+async fn delete_node_by_mnst_dst_property_postgres() {
+    init();
+    clear_db().await;
+
+    let client = postgres_test_client("./tests/fixtures/minimal.yml").await;
+    delete_node_by_mnst_dst_property(client).await;
+}
+
+#[cfg(feature = "sqlite")]
+#[tokio::test]
+async fn delete_node_by_mnst_dst_property_sqlite() {
+    init();
+    clear_db().await;
+
+    let client = sqlite_test_client("./tests/fixtures/minimal.yml").await;
+    delete_node_by_mnst_dst_property(client).await;
+}
+
 /// Passes if warpgrapher can delete a node by the properties of the dst object at a relationship
 #[allow(clippy::cognitive_complexity, dead_code)]
 async fn delete_node_by_mnst_dst_property(mut client: Client<AppRequestCtx>) {
@@ -8,10 +8,23 @@ use setup::bolt_client;
 use setup::cosmos_test_client;
 #[cfg(feature = "gremlin")]
 use setup::gremlin_test_client;
+#[cfg(feature = "in-memory")]
+use setup::memory_test_client;
 #[cfg(feature = "neo4j")]
 use setup::neo4j_test_client;
+#[cfg(feature = "postgres")]
+use setup::postgres_test_client;
+#[cfg(feature = "sqlite")]
+use setup::sqlite_test_client;
 use setup::AppRequestCtx;
-#[cfg(any(feature = "cosmos", feature = "gremlin", feature = "neo4j"))]
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
 use setup::{clear_db, init};
 #[cfg(feature = "neo4j")]
 use std::iter::FromIterator;
@@ -50,6 +63,36 @@ async fn create_single_node_gremlin() {
     create_single_node(client).await;
 }
 
+#[cfg(feature = "in-memory")]
+#[tokio::test]
+async fn create_single_node_memory() {
+    init();
+    clear_db().await;
+
+    let client = memory_test_client("./tests/fixtures/minimal.yml").await;
+    create_single_node(client).await;
+}
+
+#[cfg(feature = "postgres")]
+#[tokio::test]
+async fn create_single_node_postgres() {
+    init();
+    clear_db().await;
+
+    let client = postgres_test_client("./tests/fixtures/minimal.yml").await;
+    create_single_node(client).await;
+}
+
+#[cfg(feature = "sqlite")]
+#[tokio::test]
+async fn create_single_node_sqlite() {
+    init();
+    clear_db().await;
+
+    let client = sqlite_test_client("./tests/fixtures/minimal.yml").await;
+    create_single_node(client).await;
+}
+
 /// Passes if the create mutation and the read query both succeed.
 #[allow(dead_code)]
 async fn create_single_node(mut client: Client<AppRequestCtx>) {
@@ -128,6 +171,36 @@ async fn read_query_gremlin() {
     read_query(client).await;
 }
 
+#[cfg(feature = "in-memory")]
+#[tokio::test]
+async fn read_query_memory() {
+    init();
+    clear_db().await;
+
+    let client = memory_test_client("./tests/fixtures/minimal.yml").await;
+    read_query(client).await;
+}
+
+#[cfg(feature = "postgres")]
+#[tokio::test]
+async fn read_query_postgres() {
+    init();
+    clear_db().await;
+
+    let client = postgres_test_client("./tests/fixtures/minimal.yml").await;
+    read_query(client).await;
+}
+
+#[cfg(feature = "sqlite")]
+#[tokio::test]
+async fn read_query_sqlite() {
+    init();
+    clear_db().await;
+
+    let client = sqlite_test_client("./tests/fixtures/minimal.yml").await;
+    read_query(client).await;
+}
+
 /// Passes if the create mutation and the read query both succeed.
 #[allow(dead_code)]
 async fn read_query(mut client: Client<AppRequestCtx>) {
@@ -204,6 +277,36 @@ async fn handle_missing_properties_gremlin() {
     handle_missing_properties(client).await;
 }
 
+#[cfg(feature = "in-memory")]
+#[tokio::test]
+async fn handle_missing_properties_memory() {
+    init();
+    clear_db().await;
+
+    let client = memory_test_client("./tests/fixtures/minimal.yml").await;
+    handle_missing_properties(client).await;
+}
+
+#[cfg(feature = "postgres")]
+#[tokio::test]
+async fn handle_missing_properties_postgres() {
+    init();
+    clear_db().await;
+
+    let client = postgres_test_client("./tests/fixtures/minimal.yml").await;
+    handle_missing_properties(client).await;
+}
+
+#[cfg(feature = "sqlite")]
+#[tokio::test]
+async fn handle_missing_properties_sqlite() {
+    init();
+    clear_db().await;
+
+    let client = sqlite_test_client("./tests/fixtures/minimal.yml").await;
+    handle_missing_properties(client).await;
+}
+
 /// Passes if resolvers can handle a shape that reads a property that is not
 /// present on the Neo4J model object.
 #[allow(dead_code)]
@@ -272,6 +375,36 @@ async fn update_mutation_gremlin() {
     update_mutation(client).await;
 }
 
+#[cfg(feature = "in-memory")]
+#[tokio::test]
+async fn update_mutation_memory() {
+    init();
+    clear_db().await;
+
+    let client = memory_test_client("./tests/fixtures/minimal.yml").await;
+    update_mutation(client).await;
+}
+
+#[cfg(feature = "postgres")]
+#[tokio::test]
+async fn update_mutation_postgres() {
+    init();
+    clear_db().await;
+
+    let client = postgres_test_client("./tests/fixtures/minimal.yml").await;
+    update_mutation(client).await;
+}
+
+#[cfg(feature = "sqlite")]
+#[tokio::test]
+async fn update_mutation_sqlite() {
+    init();
+    clear_db().await;
+
+    let client = sqlite_test_client("./tests/fixtures/minimal.yml").await;
+    update_mutation(client).await;
+}
+
 /// Passes if the update mutation succeeds with a target node selected by attribute
 #[allow(dead_code)]
 async fn update_mutation(mut client: Client<AppRequestCtx>) {
@@ -378,6 +511,36 @@ async fn update_mutation_null_query_gremlin() {
     update_mutation_null_query(client).await;
 }
 
+#[cfg(feature = "in-memory")]
+#[tokio::test]
+async fn update_mutation_null_query_memory() {
+    init();
+    clear_db().await;
+
+    let client = memory_test_client("./tests/fixtures/minimal.yml").await;
+    update_mutation_null_query(client).await;
+}
+
+#[cfg(feature = "postgres")]
+#[tokio::test]
+async fn update_mutation_null_query_postgres() {
+    init();
+    clear_db().await;
+
+    let client = postgres_test_client("./tests/fixtures/minimal.yml").await;
+    update_mutation_null_query(client).await;
+}
+
+#[cfg(feature = "sqlite")]
+#[tokio::test]
+async fn update_mutation_null_query_sqlite() {
+    init();
+    clear_db().await;
+
+    let client = sqlite_test_client("./tests/fixtures/minimal.yml").await;
+    update_mutation_null_query(client).await;
+}
+
 /// Passes if the update mutation succeeds with a null match, meaning update all nodes
 #[allow(clippy::cognitive_complexity, dead_code)]
 async fn update_mutation_null_query(mut client: Client<AppRequestCtx>) {
@@ -477,6 +640,36 @@ async fn delete_mutation_gremlin() {
     delete_mutation(client).await;
 }
 
+#[cfg(feature = "in-memory")]
+#[tokio::test]
+async fn delete_mutation_memory() {
+    init();
+    clear_db().await;
+
+    let client = memory_test_client("./tests/fixtures/minimal.yml").await;
+    delete_mutation(client).await;
+}
+
+#[cfg(feature = "postgres")]
+#[tokio::test]
+async fn delete_mutation_postgres() {
+    init();
+    clear_db().await;
+
+    let client = postgres_test_client("./tests/fixtures/minimal.yml").await;
+    delete_mutation(client).await;
+}
+
+#[cfg(feature = "sqlite")]
+#[tokio::test]
+async fn delete_mutation_sqlite() {
+    init();
+    clear_db().await;
+
+    let client = sqlite_test_client("./tests/fixtures/minimal.yml").await;
+    delete_mutation(client).await;
+}
+
 /// Passes if the delete mutation succeeds with a target node selected by attribute
 #[allow(dead_code)]
 async fn delete_mutation(mut client: Client<AppRequestCtx>) {
@@ -571,6 +764,36 @@ async fn delete_mutation_null_query_gremlin() {
     delete_mutation_null_query(client).await;
 }
 
+#[cfg(feature = "in-memory")]
+#[tokio::test]
+async fn delete_mutation_null_query_memory() {
+    init();
+    clear_db().await;
+
+    let client = memory_test_client("./tests/fixtures/minimal.yml").await;
+    delete_mutation_null_query(client).await;
+}
+
+#[cfg(feature = "postgres")]
+#[tokio::test]
+async fn delete_mutation_null_query_postgres() {
+    init();
+    clear_db().await;
+
+    let client = postgres_test_client("./tests/fixtures/minimal.yml").await;
+    delete_mutation_null_query(client).await;
+}
+
+#[cfg(feature = "sqlite")]
+#[tokio::test]
+async fn delete_mutation_null_query_sqlite() {
+    init();
+    clear_db().await;
+
+    let client = sqlite_test_client("./tests/fixtures/minimal.yml").await;
+    delete_mutation_null_query(client).await;
+}
+
 /// Passes if the update mutation succeeds with a null match, meaning delete all nodes
 #[allow(clippy::cognitive_complexity, dead_code)]
 async fn delete_mutation_null_query(mut client: Client<AppRequestCtx>) {
@@ -5,10 +5,23 @@ use serde_json::json;
 use setup::cosmos_test_client;
 #[cfg(feature = "gremlin")]
 use setup::gremlin_test_client;
+#[cfg(feature = "in-memory")]
+use setup::memory_test_client;
 #[cfg(feature = "neo4j")]
 use setup::neo4j_test_client;
+#[cfg(feature = "postgres")]
+use setup::postgres_test_client;
+#[cfg(feature = "sqlite")]
+use setup::sqlite_test_client;
 use setup::AppRequestCtx;
-#[cfg(any(feature = "cosmos", feature = "gremlin", feature = "neo4j"))]
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
 use setup::{clear_db, init};
 use warpgrapher::client::Client;
 
@@ -32,6 +45,16 @@ async fn scalar_lists_test_gremlin() {
     scalar_lists_test(client).await;
 }
 
+#[cfg(feature = "in-memory")]
+#[tokio::test]
+async fn scalar_lists_test_memory() {
+    init();
+    clear_db().await;
+
+    let client = memory_test_client("./tests/fixtures/scalars/scalar_list.yml").await;
+    scalar_lists_test(client).await;
+}
+
 #[cfg(feature = "neo4j")]
 #[tokio::test]
 async fn scalar_lists_test_neo4j() {
@@ -42,6 +65,26 @@ async fn scalar_lists_test_neo4j() {
     scalar_lists_test(client).await;
 }
 
+#[cfg(feature = "postgres")]
+#[tokio::test]
+async fn scalar_lists_test_postgres() {
+    init();
+    clear_db().await;
+
+    let client = postgres_test_client("./tests/fixtures/scalars/scalar_list.yml").await;
+    scalar_lists_test(client).await;
+}
+
+#[cfg(feature = "sqlite")]
+#[tokio::test]
+async fn scalar_lists_test_sqlite() {
+    init();
+    clear_db().await;
+
+    let client = sqlite_test_client("./tests/fixtures/scalars/scalar_list.yml").await;
+    scalar_lists_test(client).await;
+}
+
 /// Passes if the create mutation and the read query both succeed.
 #[allow(clippy::float_cmp, dead_code)]
 async fn scalar_lists_test(mut client: Client<AppRequestCtx>) {
@@ -113,6 +156,16 @@ async fn scalar_lists_no_array_gremlin() {
     scalar_lists_no_array_test(client).await;
 }
 
+#[cfg(feature = "in-memory")]
+#[tokio::test]
+async fn scalar_lists_no_array_memory() {
+    init();
+    clear_db().await;
+
+    let client = memory_test_client("./tests/fixtures/scalars/scalar_list.yml").await;
+    scalar_lists_no_array_test(client).await;
+}
+
 #[cfg(feature = "neo4j")]
 #[tokio::test]
 async fn scalar_lists_no_array_neo4j() {
@@ -123,6 +176,26 @@ async fn scalar_lists_no_array_neo4j() {
     scalar_lists_no_array_test(client).await;
 }
 
+#[cfg(feature = "postgres")]
+#[tokio::test]
+async fn scalar_lists_no_array_postgres() {
+    init();
+    clear_db().await;
+
+    let client = postgres_test_client("./tests/fixtures/scalars/scalar_list.yml").await;
+    scalar_lists_no_array_test(client).await;
+}
+
+#[cfg(feature = "sqlite")]
+#[tokio::test]
+async fn scalar_lists_no_array_sqlite() {
+    init();
+    clear_db().await;
+
+    let client = sqlite_test_client("./tests/fixtures/scalars/scalar_list.yml").await;
+    scalar_lists_no_array_test(client).await;
+}
+
 /// Passes if the create mutation and the read query both succeed.
 #[allow(clippy::float_cmp, dead_code)]
 async fn scalar_lists_no_array_test(mut client: Client<AppRequestCtx>) {
@@ -182,6 +255,16 @@ async fn scalar_no_lists_gremlin() {
     scalar_no_lists_test(client).await;
 }
 
+#[cfg(feature = "in-memory")]
+#[tokio::test]
+async fn scalar_no_lists_memory() {
+    init();
+    clear_db().await;
+
+    let client = memory_test_client("./tests/fixtures/scalars/scalar_no_list.yml").await;
+    scalar_no_lists_test(client).await;
+}
+
 #[cfg(feature = "neo4j")]
 #[tokio::test]
 async fn scalar_no_lists_neo4j() {
@@ -192,6 +275,26 @@ async fn scalar_no_lists_neo4j() {
     scalar_no_lists_test(client).await;
 }
 
+#[cfg(feature = "postgres")]
+#[tokio::test]
+async fn scalar_no_lists_postgres() {
+    init();
+    clear_db().await;
+
+    let client = postgres_test_client("./tests/fixtures/scalars/scalar_no_list.yml").await;
+    scalar_no_lists_test(client).await;
+}
+
+#[cfg(feature = "sqlite")]
+#[tokio::test]
+async fn scalar_no_lists_sqlite() {
+    init();
+    clear_db().await;
+
+    let client = sqlite_test_client("./tests/fixtures/scalars/scalar_no_list.yml").await;
+    scalar_no_lists_test(client).await;
+}
+
 /// Passes if the create mutation and the read query both succeed.
 #[allow(dead_code)]
 async fn scalar_no_lists_test(mut client: Client<AppRequestCtx>) {
@@ -264,6 +367,16 @@ async fn scalar_no_lists_no_array_gremlin() {
     scalar_no_lists_no_array_test(client).await;
 }
 
+#[cfg(feature = "in-memory")]
+#[tokio::test]
+async fn scalar_no_lists_no_array_memory() {
+    init();
+    clear_db().await;
+
+    let client = memory_test_client("./tests/fixtures/scalars/scalar_no_list.yml").await;
+    scalar_no_lists_no_array_test(client).await;
+}
+
 #[cfg(feature = "neo4j")]
 #[tokio::test]
 async fn scalar_no_lists_no_array_neo4j() {
@@ -274,6 +387,26 @@ async fn scalar_no_lists_no_array_neo4j() {
     scalar_no_lists_no_array_test(client).await;
 }
 
+#[cfg(feature = "postgres")]
+#[tokio::test]
+async fn scalar_no_lists_no_array_postgres() {
+    init();
+    clear_db().await;
+
+    let client = postgres_test_client("./tests/fixtures/scalars/scalar_no_list.yml").await;
+    scalar_no_lists_no_array_test(client).await;
+}
+
+#[cfg(feature = "sqlite")]
+#[tokio::test]
+async fn scalar_no_lists_no_array_sqlite() {
+    init();
+    clear_db().await;
+
+    let client = sqlite_test_client("./tests/fixtures/scalars/scalar_no_list.yml").await;
+    scalar_no_lists_no_array_test(client).await;
+}
+
 /// Passes if the create mutation and the read query both succeed.
 #[allow(clippy::float_cmp, dead_code)]
 async fn scalar_no_lists_no_array_test(mut client: Client<AppRequestCtx>) {
@@ -6,10 +6,23 @@ use serde_json::json;
 use setup::cosmos_test_client;
 #[cfg(feature = "gremlin")]
 use setup::gremlin_test_client;
+#[cfg(feature = "in-memory")]
+use setup::memory_test_client;
 #[cfg(feature = "neo4j")]
 use setup::neo4j_test_client;
+#[cfg(feature = "postgres")]
+use setup::postgres_test_client;
+#[cfg(feature = "sqlite")]
+use setup::sqlite_test_client;
 use setup::AppRequestCtx;
-#[cfg(any(feature = "cosmos", feature = "gremlin", feature = "neo4j"))]
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
 use setup::{clear_db, init};
 use warpgrapher::client::Client;
 
@@ -43,6 +56,36 @@ async fn create_node_with_rel_to_new_gremlin() {
     create_node_with_rel_to_new(client).await;
 }
 
+#[cfg(feature = "in-memory")]
+#[tokio::test]
+async fn create_node_with_rel_to_new_memory() {
+    init();
+    clear_db().await;
+
+    let client = memory_test_client("./tests/fixtures/minimal.yml").await;
+    create_node_with_rel_to_new(client).await;
+}
+
+#[cfg(feature = "postgres")]
+#[tokio::test]
+async fn create_node_with_rel_to_new_postgres() {
+    init();
+    clear_db().await;
+
+    let client = postgres_test_client("./tests/fixtures/minimal.yml").await;
+    create_node_with_rel_to_new(client).await;
+}
+
+#[cfg(feature = "sqlite")]
+#[tokio::test]
+async fn create_node_with_rel_to_new_sqlite() {
+    init();
+    clear_db().await;
+
+    let client = sqlite_test_client("./tests/fixtures/minimal.yml").await;
+    create_node_with_rel_to_new(client).await;
+}
+
 /// Passes if a node is created with an SNMT rel to a new node
 #[allow(clippy::cognitive_complexity, dead_code)]
 async fn create_node_with_rel_to_new(mut client: Client<AppRequestCtx>) {
@@ -178,6 +221,36 @@ async fn create_node_with_rel_to_existing_gremlin() {
     create_node_with_rel_to_existing(client).await;
 }
 
+#[cfg(feature = "in-memory")]
+#[tokio::test]
+async fn create_node_with_rel_to_existing_memory() {
+    init();
+    clear_db().await;
+
+    let client = memory_test_client("./tests/fixtures/minimal.yml").await;
+    create_node_with_rel_to_existing(client).await;
+}
+
+#[cfg(feature = "postgres")]
+#[tokio::test]
+async fn create_node_with_rel_to_existing_postgres() {
+    init();
+    clear_db().await;
+
+    let client = postgres_test_client("./tests/fixtures/minimal.yml").await;
+    create_node_with_rel_to_existing(client).await;
+}
+
+#[cfg(feature = "sqlite")]
+#[tokio::test]
+async fn create_node_with_rel_to_existing_sqlite() {
+    init();
+    clear_db().await;
+
+    let client = sqlite_test_client("./tests/fixtures/minimal.yml").await;
+    create_node_with_rel_to_existing(client).await;
+}
+
 /// Passes if a node is created with an SNMT rel to existing node
 #[allow(clippy::cognitive_complexity, dead_code)]
 async fn create_node_with_rel_to_existing(mut client: Client<AppRequestCtx>) {
@@ -300,6 +373,36 @@ async fn read_multiple_nodes_with_multiple_rels_gremlin() {
     read_multiple_nodes_with_multiple_rels(client).await;
 }
 
+#[cfg(feature = "in-memory")]
+#[tokio::test]
+async fn read_multiple_nodes_with_multiple_rels_memory() {
+    init();
+    clear_db().await;
+
+    let client = memory_test_client("./tests/fixtures/minimal.yml").await;
+    read_multiple_nodes_with_multiple_rels(client).await;
+}
+
+#[cfg(feature = "postgres")]
+#[tokio::test]
+async fn read_multiple_nodes_with_multiple_rels_postgres() {
+    init();
+    clear_db().await;
+
+    let client = postgres_test_client("./tests/fixtures/minimal.yml").await;
+    read_multiple_nodes_with_multiple_rels(client).await;
+}
+
+#[cfg(feature = "sqlite")]
+#[tokio::test]
+async fn read_multiple_nodes_with_multiple_rels_sqlite() {
+    init();
+    clear_db().await;
+
+    let client = sqlite_test_client("./tests/fixtures/minimal.yml").await;
+    read_multiple_nodes_with_multiple_rels(client).await;
+}
+
 /// Passes if multiple nodes with multiple rels are read and
 /// the relationships associate correctly
 #[allow(clippy::cognitive_complexity, dead_code)]
@@ -466,6 +569,36 @@ async fn read_node_with_matching_props_on_rel_gremlin() {
     read_node_with_matching_props_on_rel(client).await;
 }
 
+#[cfg(feature = "in-memory")]
+#[tokio::test]
+async fn read_node_with_matching_props_on_rel_memory() {
+    init();
+    clear_db().await;
+
+    let client = memory_test_client("./tests/fixtures/minimal.yml").await;
+    read_node_with_matching_props_on_rel(client).await;
+}
+
+#[cfg(feature = "postgres")]
+#[tokio::test]
+async fn read_node_with_matching_props_on_rel_postgres() {
+    init();
+    clear_db().await;
+
+    let client = postgres_test_client("./tests/fixtures/minimal.yml").await;
+    read_node_with_matching_props_on_rel(client).await;
+}
+
+#[cfg(feature = "sqlite")]
+#[tokio::test]
+async fn read_node_with_matching_props_on_rel_sqlite() {
+    init();
+    clear_db().await;
+
+    let client = sqlite_test_client("./tests/fixtures/minimal.yml").await;
+    read_node_with_matching_props_on_rel(client).await;
+}
+
 /// Passes if nodes matching props on a relationship are returned
 #[allow(clippy::cognitive_complexity, dead_code)]
 async fn read_node_with_matching_props_on_rel(mut client: Client<AppRequestCtx>) {
@@ -633,6 +766,36 @@ async fn read_node_with_matching_props_on_rel_dst_node_gremlin() {
     read_node_with_matching_props_on_rel_dst_node(client).await;
 }
 
+#[cfg(feature = "in-memory")]
+#[tokio::test]
+async fn read_node_with_matching_props_on_rel_dst_node_memory() {
+    init();
+    clear_db().await;
+
+    let client = memory_test_client("./tests/fixtures/minimal.yml").await;
+    read_node_with_matching_props_on_rel_dst_node(client).await;
+}
+
+#[cfg(feature = "postgres")]
+#[tokio::test]
+async fn read_node_with_matching_props_on_rel_dst_node_postgres() {
+    init();
+    clear_db().await;
+
+    let client = postgres_test_client("./tests/fixtures/minimal.yml").await;
+    read_node_with_matching_props_on_rel_dst_node(client).await;
+}
+
+#[cfg(feature = "sqlite")]
+#[tokio::test]
+async fn read_node_with_matching_props_on_rel_dst_node_sqlite() {
+    init();
+    clear_db().await;
+
+    let client = sqlite_test_client("./tests/fixtures/minimal.yml").await;
+    read_node_with_matching_props_on_rel_dst_node(client).await;
+}
+
 /// Passes if it returns nodes with relationship dst nodes
 /// with matching props
 #[allow(clippy::cognitive_complexity, dead_code)]
@@ -760,6 +923,36 @@ async fn update_existing_node_with_rel_to_new_node_gremlin() {
     update_existing_node_with_rel_to_new_node(client).await;
 }
 
+#[cfg(feature = "in-memory")]
+#[tokio::test]
+async fn update_existing_node_with_rel_to_new_node_memory() {
+    init();
+    clear_db().await;
+
+    let client = memory_test_client("./tests/fixtures/minimal.yml").await;
+    update_existing_node_with_rel_to_new_node(client).await;
+}
+
+#[cfg(feature = "postgres")]
+#[tokio::test]
+async fn update_existing_node_with_rel_to_new_node_postgres() {
+    init();
+    clear_db().await;
+
+    let client = postgres_test_client("./tests/fixtures/minimal.yml").await;
+    update_existing_node_with_rel_to_new_node(client).await;
+}
+
+#[cfg(feature = "sqlite")]
+#[tokio::test]
+async fn update_existing_node_with_rel_to_new_node_sqlite() {
+    init();
+    clear_db().await;
+
+    let client = sqlite_test_client("./tests/fixtures/minimal.yml").await;
+    update_existing_node_with_rel_to_new_node(client).await;
+}
+
 #[allow(clippy::cognitive_complexity, dead_code)]
 async fn update_existing_node_with_rel_to_new_node(mut client: Client<AppRequestCtx>) {
     // create project node
@@ -884,6 +1077,36 @@ async fn update_existing_node_with_rel_to_existing_node_gremlin() {
     update_existing_node_with_rel_to_existing_node(client).await;
 }
 
+#[cfg(feature = "in-memory")]
+#[tokio::test]
+async fn update_existing_node_with_rel_to_existing_node_memory() {
+    init();
+    clear_db().await;
+
+    let client = memory_test_client("./tests/fixtures/minimal.yml").await;
+    update_existing_node_with_rel_to_existing_node(client).await;
+}
+
+#[cfg(feature = "postgres")]
+#[tokio::test]
+async fn update_existing_node_with_rel_to_existing_node_postgres() {
+    init();
+    clear_db().await;
+
+    let client = postgres_test_client("./tests/fixtures/minimal.yml").await;
+    update_existing_node_with_rel_to_existing_node(client).await;
+}
+
+#[cfg(feature = "sqlite")]
+#[tokio::test]
+async fn update_existing_node_with_rel_to_existing_node_sqlite() {
+    init();
+    clear_db().await;
+
+    let client = sqlite_test_client("./tests/fixtures/minimal.yml").await;
+    update_existing_node_with_rel_to_existing_node(client).await;
+}
+
 #[allow(clippy::cognitive_complexity, dead_code)]
 async fn update_existing_node_with_rel_to_existing_node(mut client: Client<AppRequestCtx>) {
     // create project node
@@ -1007,6 +1230,36 @@ async fn delete_node_with_matching_props_on_rel_dst_node_gremlin() {
     delete_node_with_matching_props_on_rel_dst_node(client).await;
 }
 
+#[cfg(feature = "in-memory")]
+#[tokio::test]
+async fn delete_node_with_matching_props_on_rel_dst_node_memory() {
+    init();
+    clear_db().await;
+
+    let client = memory_test_client("./tests/fixtures/minimal.yml").await;
+    delete_node_with_matching_props_on_rel_dst_node(client).await;
+}
+
+#[cfg(feature = "postgres")]
+#[tokio::test]
+async fn delete_node_with_matching_props_on_rel_dst_node_postgres() {
+    init();
+    clear_db().await;
+
+    let client = postgres_test_client("./tests/fixtures/minimal.yml").await;
+    delete_node_with_matching_props_on_rel_dst_node(client).await;
+}
+
+#[cfg(feature = "sqlite")]
+#[tokio::test]
+async fn delete_node_with_matching_props_on_rel_dst_node_sqlite() {
+    init();
+    clear_db().await;
+
+    let client = sqlite_test_client("./tests/fixtures/minimal.yml").await;
+    delete_node_with_matching_props_on_rel_dst_node(client).await;
+}
+
 #[allow(clippy::cognitive_complexity, dead_code)]
 async fn delete_node_with_matching_props_on_rel_dst_node(mut client: Client<AppRequestCtx>) {
     // create project nodes
@@ -1092,6 +1345,36 @@ async fn delete_node_gremlin() {
     delete_node(client).await;
 }
 
+#[cfg(feature = "in-memory")]
+#[tokio::test]
+async fn delete_node_memory() {
+    init();
+    clear_db().await;
+
+    let client = memory_test_client("./tests/fixtures/minimal.yml").await;
+    delete_node(client).await;
+}
+
+#[cfg(feature = "postgres")]
+#[tokio::test]
+async fn delete_node_postgres() {
+    init();
+    clear_db().await;
+
+    let client = postgres_test_client("./tests/fixtures/minimal.yml").await;
+    delete_node(client).await;
+}
+
+#[cfg(feature = "sqlite")]
+#[tokio::test]
+async fn delete_node_sqlite() {
+    init();
+    clear_db().await;
+
+    let client = sqlite_test_client("./tests/fixtures/minimal.yml").await;
+    delete_node(client).await;
+}
+
 #[allow(clippy::cognitive_complexity, dead_code)]
 async fn delete_node(mut client: Client<AppRequestCtx>) {
     // create project nodes
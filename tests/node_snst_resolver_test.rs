@@ -5,10 +5,23 @@ use serde_json::json;
 use setup::cosmos_test_client;
 #[cfg(feature = "gremlin")]
 use setup::gremlin_test_client;
+#[cfg(feature = "in-memory")]
+use setup::memory_test_client;
 #[cfg(feature = "neo4j")]
 use setup::neo4j_test_client;
+#[cfg(feature = "postgres")]
+use setup::postgres_test_client;
+#[cfg(feature = "sqlite")]
+use setup::sqlite_test_client;
 use setup::AppRequestCtx;
-#[cfg(any(feature = "cosmos", feature = "gremlin", feature = "neo4j"))]
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
 use setup::{clear_db, init};
 use warpgrapher::client::Client;
 
@@ -42,6 +55,36 @@ async fn create_snst_new_node_gremlin() {
     create_snst_new_node(client).await;
 }
 
+#[cfg(feature = "in-memory")]
+#[tokio::test]
+async fn create_snst_new_node_memory() {
+    init();
+    clear_db().await;
+
+    let client = memory_test_client("./tests/fixtures/minimal.yml").await;
+    create_snst_new_node(client).await;
+}
+
+#[cfg(feature = "postgres")]
+#[tokio::test]
+async fn create_snst_new_node_postgres() {
+    init();
+    clear_db().await;
+
+    let client = postgres_test_client("./tests/fixtures/minimal.yml").await;
+    create_snst_new_node(client).await;
+}
+
+#[cfg(feature = "sqlite")]
+#[tokio::test]
+async fn create_snst_new_node_sqlite() {
+    init();
+    clear_db().await;
+
+    let client = sqlite_test_client("./tests/fixtures/minimal.yml").await;
+    create_snst_new_node(client).await;
+}
+
 /// Passes if warpgrapher can create a node with a relationship to another new node
 #[allow(clippy::cognitive_complexity, dead_code)]
 async fn create_snst_new_node(mut client: Client<AppRequestCtx>) {
@@ -161,6 +204,36 @@ async fn create_node_with_rel_to_existing_gremlin() {
     create_node_with_rel_to_existing(client).await;
 }
 
+#[cfg(feature = "in-memory")]
+#[tokio::test]
+async fn create_node_with_rel_to_existing_memory() {
+    init();
+    clear_db().await;
+
+    let client = memory_test_client("./tests/fixtures/minimal.yml").await;
+    create_node_with_rel_to_existing(client).await;
+}
+
+#[cfg(feature = "postgres")]
+#[tokio::test]
+async fn create_node_with_rel_to_existing_postgres() {
+    init();
+    clear_db().await;
+
+    let client = postgres_test_client("./tests/fixtures/minimal.yml").await;
+    create_node_with_rel_to_existing(client).await;
+}
+
+#[cfg(feature = "sqlite")]
+#[tokio::test]
+async fn create_node_with_rel_to_existing_sqlite() {
+    init();
+    clear_db().await;
+
+    let client = sqlite_test_client("./tests/fixtures/minimal.yml").await;
+    create_node_with_rel_to_existing(client).await;
+}
+
 #[allow(clippy::cognitive_complexity, dead_code)]
 async fn create_node_with_rel_to_existing(mut client: Client<AppRequestCtx>) {
     let _u0 = client
@@ -283,6 +356,36 @@ async fn read_multiple_snst_node_with_rel_gremlin() {
     read_multiple_snst_node_with_rel(client).await;
 }
 
+#[cfg(feature = "in-memory")]
+#[tokio::test]
+async fn read_multiple_snst_node_with_rel_memory() {
+    init();
+    clear_db().await;
+
+    let client = memory_test_client("./tests/fixtures/minimal.yml").await;
+    read_multiple_snst_node_with_rel(client).await;
+}
+
+#[cfg(feature = "postgres")]
+#[tokio::test]
+async fn read_multiple_snst_node_with_rel_postgres() {
+    init();
+    clear_db().await;
+
+    let client = postgres_test_client("./tests/fixtures/minimal.yml").await;
+    read_multiple_snst_node_with_rel(client).await;
+}
+
+#[cfg(feature = "sqlite")]
+#[tokio::test]
+async fn read_multiple_snst_node_with_rel_sqlite() {
+    init();
+    clear_db().await;
+
+    let client = sqlite_test_client("./tests/fixtures/minimal.yml").await;
+    read_multiple_snst_node_with_rel(client).await;
+}
+
 #[allow(clippy::cognitive_complexity, dead_code)]
 async fn read_multiple_snst_node_with_rel(mut client: Client<AppRequestCtx>) {
     let _u0 = client
@@ -468,6 +571,36 @@ async fn read_snst_node_by_rel_props_gremlin() {
     read_snst_node_by_rel_props(client).await;
 }
 
+#[cfg(feature = "in-memory")]
+#[tokio::test]
+async fn read_snst_node_by_rel_props_memory() {
+    init();
+    clear_db().await;
+
+    let client = memory_test_client("./tests/fixtures/minimal.yml").await;
+    read_snst_node_by_rel_props(client).await;
+}
+
+#[cfg(feature = "postgres")]
+#[tokio::test]
+async fn read_snst_node_by_rel_props_postgres() {
+    init();
+    clear_db().await;
+
+    let client = postgres_test_client("./tests/fixtures/minimal.yml").await;
+    read_snst_node_by_rel_props(client).await;
+}
+
+#[cfg(feature = "sqlite")]
+#[tokio::test]
+async fn read_snst_node_by_rel_props_sqlite() {
+    init();
+    clear_db().await;
+
+    let client = sqlite_test_client("./tests/fixtures/minimal.yml").await;
+    read_snst_node_by_rel_props(client).await;
+}
+
 #[allow(clippy::cognitive_complexity, dead_code)]
 async fn read_snst_node_by_rel_props(mut client: Client<AppRequestCtx>) {
     let _u0 = client
@@ -580,6 +713,36 @@ async fn read_snst_node_by_dst_props_gremlin() {
     read_snst_node_by_dst_props(client).await;
 }
 
+#[cfg(feature = "in-memory")]
+#[tokio::test]
+async fn read_snst_node_by_dst_props_memory() {
+    init();
+    clear_db().await;
+
+    let client = memory_test_client("./tests/fixtures/minimal.yml").await;
+    read_snst_node_by_dst_props(client).await;
+}
+
+#[cfg(feature = "postgres")]
+#[tokio::test]
+async fn read_snst_node_by_dst_props_postgres() {
+    init();
+    clear_db().await;
+
+    let client = postgres_test_client("./tests/fixtures/minimal.yml").await;
+    read_snst_node_by_dst_props(client).await;
+}
+
+#[cfg(feature = "sqlite")]
+#[tokio::test]
+async fn read_snst_node_by_dst_props_sqlite() {
+    init();
+    clear_db().await;
+
+    let client = sqlite_test_client("./tests/fixtures/minimal.yml").await;
+    read_snst_node_by_dst_props(client).await;
+}
+
 #[allow(clippy::cognitive_complexity, dead_code)]
 async fn read_snst_node_by_dst_props(mut client: Client<AppRequestCtx>) {
     let _u0 = client
@@ -694,6 +857,36 @@ async fn update_snst_node_with_new_rel_gremlin() {
     update_snst_node_with_new_rel(client).await;
 }
 
+#[cfg(feature = "in-memory")]
+#[tokio::test]
+async fn update_snst_node_with_new_rel_memory() {
+    init();
+    clear_db().await;
+
+    let client = memory_test_client("./tests/fixtures/minimal.yml").await;
+    update_snst_node_with_new_rel(client).await;
+}
+
+#[cfg(feature = "postgres")]
+#[tokio::test]
+async fn update_snst_node_with_new_rel_postgres() {
+    init();
+    clear_db().await;
+
+    let client = postgres_test_client("./tests/fixtures/minimal.yml").await;
+    update_snst_node_with_new_rel(client).await;
+}
+
+#[cfg(feature = "sqlite")]
+#[tokio::test]
+async fn update_snst_node_with_new_rel_sqlite() {
+    init();
+    clear_db().await;
+
+    let client = sqlite_test_client("./tests/fixtures/minimal.yml").await;
+    update_snst_node_with_new_rel(client).await;
+}
+
 #[allow(clippy::cognitive_complexity, dead_code)]
 async fn update_snst_node_with_new_rel(mut client: Client<AppRequestCtx>) {
     let _u0 = client
@@ -833,6 +1026,36 @@ async fn update_snst_node_with_existing_rel_gremlin() {
     update_snst_node_with_existing_rel(client).await;
 }
 
+#[cfg(feature = "in-memory")]
+#[tokio::test]
+async fn update_snst_node_with_existing_rel_memory() {
+    init();
+    clear_db().await;
+
+    let client = memory_test_client("./tests/fixtures/minimal.yml").await;
+    update_snst_node_with_existing_rel(client).await;
+}
+
+#[cfg(feature = "postgres")]
+#[tokio::test]
+async fn update_snst_node_with_existing_rel_postgres() {
+    init();
+    clear_db().await;
+
+    let client = postgres_test_client("./tests/fixtures/minimal.yml").await;
+    update_snst_node_with_existing_rel(client).await;
+}
+
+#[cfg(feature = "sqlite")]
+#[tokio::test]
+async fn update_snst_node_with_existing_rel_sqlite() {
+    init();
+    clear_db().await;
+
+    let client = sqlite_test_client("./tests/fixtures/minimal.yml").await;
+    update_snst_node_with_existing_rel(client).await;
+}
+
 #[allow(clippy::cognitive_complexity, dead_code)]
 async fn update_snst_node_with_existing_rel(mut client: Client<AppRequestCtx>) {
     let _u0 = client
@@ -980,6 +1203,36 @@ async fn delete_snst_rel_by_dst_props_gremlin() {
     delete_snst_rel_by_dst_props(client).await;
 }
 
+#[cfg(feature = "in-memory")]
+#[tokio::test]
+async fn delete_snst_rel_by_dst_props_memory() {
+    init();
+    clear_db().await;
+
+    let client = memory_test_client("./tests/fixtures/minimal.yml").await;
+    delete_snst_rel_by_dst_props(client).await;
+}
+
+#[cfg(feature = "postgres")]
+#[tokio::test]
+async fn delete_snst_rel_by_dst_props_postgres() {
+    init();
+    clear_db().await;
+
+    let client = postgres_test_client("./tests/fixtures/minimal.yml").await;
+    delete_snst_rel_by_dst_props(client).await;
+}
+
+#[cfg(feature = "sqlite")]
+#[tokio::test]
+async fn delete_snst_rel_by_dst_props_sqlite() {
+    init();
+    clear_db().await;
+
+    let client = sqlite_test_client("./tests/fixtures/minimal.yml").await;
+    delete_snst_rel_by_dst_props(client).await;
+}
+
 #[allow(clippy::cognitive_complexity, dead_code)]
 async fn delete_snst_rel_by_dst_props(mut client: Client<AppRequestCtx>) {
     let _u0 = client
@@ -1118,6 +1371,36 @@ async fn delete_snst_rel_by_rel_props_gremlin() {
     delete_snst_rel_by_rel_props(client).await;
 }
 
+#[cfg(feature = "in-memory")]
+#[tokio::test]
+async fn delete_snst_rel_by_rel_props_memory() {
+    init();
+    clear_db().await;
+
+    let client = memory_test_client("./tests/fixtures/minimal.yml").await;
+    delete_snst_rel_by_rel_props(client).await;
+}
+
+#[cfg(feature = "postgres")]
+#[tokio::test]
+async fn delete_snst_rel_by_rel_props_postgres() {
+    init();
+    clear_db().await;
+
+    let client = postgres_test_client("./tests/fixtures/minimal.yml").await;
+    delete_snst_rel_by_rel_props(client).await;
+}
+
+#[cfg(feature = "sqlite")]
+#[tokio::test]
+async fn delete_snst_rel_by_rel_props_sqlite() {
+    init();
+    clear_db().await;
+
+    let client = sqlite_test_client("./tests/fixtures/minimal.yml").await;
+    delete_snst_rel_by_rel_props(client).await;
+}
+
 #[allow(clippy::cognitive_complexity, dead_code)]
 async fn delete_snst_rel_by_rel_props(mut client: Client<AppRequestCtx>) {
     let _u0 = client
@@ -1254,6 +1537,36 @@ async fn delete_snst_node_by_dst_prop_gremlin() {
     delete_snst_node_by_dst_prop(client).await;
 }
 
+#[cfg(feature = "in-memory")]
+#[tokio::test]
+async fn delete_snst_node_by_dst_prop_memory() {
+    init();
+    clear_db().await;
+
+    let client = memory_test_client("./tests/fixtures/minimal.yml").await;
+    delete_snst_node_by_dst_prop(client).await;
+}
+
+#[cfg(feature = "postgres")]
+#[tokio::test]
+async fn delete_snst_node_by_dst_prop_postgres() {
+    init();
+    clear_db().await;
+
+    let client = postgres_test_client("./tests/fixtures/minimal.yml").await;
+    delete_snst_node_by_dst_prop(client).await;
+}
+
+#[cfg(feature = "sqlite")]
+#[tokio::test]
+async fn delete_snst_node_by_dst_prop_sqlite() {
+    init();
+    clear_db().await;
+
+    let client = sqlite_test_client("./tests/fixtures/minimal.yml").await;
+    delete_snst_node_by_dst_prop(client).await;
+}
+
 #[allow(clippy::cognitive_complexity, dead_code)]
 async fn delete_snst_node_by_dst_prop(mut client: Client<AppRequestCtx>) {
     let _u0 = client
@@ -1391,6 +1704,36 @@ async fn delete_snst_node_by_rel_prop_gremlin() {
     delete_snst_node_by_rel_prop(client).await;
 }
 
+#[cfg(feature = "in-memory")]
+#[tokio::test]
+async fn delete_snst_node_by_rel_prop_memory() {
+    init();
+    clear_db().await;
+
+    let client = memory_test_client("./tests/fixtures/minimal.yml").await;
+    delete_snst_node_by_rel_prop(client).await;
+}
+
+#[cfg(feature = "postgres")]
+#[tokio::test]
+async fn delete_snst_node_by_rel_prop_postgres() {
+    init();
+    clear_db().await;
+
+    let client = postgres_test_client("./tests/fixtures/minimal.yml").await;
+    delete_snst_node_by_rel_prop(client).await;
+}
+
+#[cfg(feature = "sqlite")]
+#[tokio::test]
+async fn delete_snst_node_by_rel_prop_sqlite() {
+    init();
+    clear_db().await;
+
+    let client = sqlite_test_client("./tests/fixtures/minimal.yml").await;
+    delete_snst_node_by_rel_prop(client).await;
+}
+
 #[allow(clippy::cognitive_complexity, dead_code)]
 async fn delete_snst_node_by_rel_prop(mut client: Client<AppRequestCtx>) {
     let _u0 = client
@@ -1526,6 +1869,36 @@ async fn detach_snst_rel_by_dst_delete_gremlin() {
     detach_snst_rel_by_dst_delete(client).await;
 }
 
+#[cfg(feature = "in-memory")]
+#[tokio::test]
+async fn detach_snst_rel_by_dst_delete_memory() {
+    init();
+    clear_db().await;
+
+    let client = memory_test_client("./tests/fixtures/minimal.yml").await;
+    detach_snst_rel_by_dst_delete(client).await;
+}
+
+#[cfg(feature = "postgres")]
+#[tokio::test]
+async fn detach_snst_rel_by_dst_delete_postgres() {
+    init();
+    clear_db().await;
+
+    let client = postgres_test_client("./tests/fixtures/minimal.yml").await;
+    detach_snst_rel_by_dst_delete(client).await;
+}
+
+#[cfg(feature = "sqlite")]
+#[tokio::test]
+async fn detach_snst_rel_by_dst_delete_sqlite() {
+    init();
+    clear_db().await;
+
+    let client = sqlite_test_client("./tests/fixtures/minimal.yml").await;
+    detach_snst_rel_by_dst_delete(client).await;
+}
+
 #[allow(clippy::cognitive_complexity, dead_code)]
 async fn detach_snst_rel_by_dst_delete(mut client: Client<AppRequestCtx>) {
     let _u0 = client
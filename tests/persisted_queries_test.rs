@@ -0,0 +1,247 @@
+mod setup;
+
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+use serde_json::json;
+#[cfg(feature = "cosmos")]
+use setup::cosmos_test_pool;
+#[cfg(feature = "gremlin")]
+use setup::gremlin_test_pool;
+#[cfg(feature = "in-memory")]
+use setup::memory_test_pool;
+#[cfg(feature = "neo4j")]
+use setup::neo4j_test_pool;
+#[cfg(feature = "postgres")]
+use setup::postgres_test_pool;
+#[cfg(feature = "sqlite")]
+use setup::sqlite_test_pool;
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+use setup::{clear_db, execute, init};
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+use sha2::{Digest, Sha256};
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+use std::collections::HashMap;
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+use std::convert::TryInto;
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+use std::fs::File;
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+use warpgrapher::engine::persisted::{PersistedQueries, PersistedRequest};
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+use warpgrapher::{Configuration, DatabasePool, Engine};
+
+#[cfg(feature = "cosmos")]
+#[tokio::test]
+async fn persisted_queries_test_cosmos() {
+    init();
+    clear_db().await;
+
+    persisted_queries_test(cosmos_test_pool().await);
+}
+
+#[cfg(feature = "gremlin")]
+#[tokio::test]
+async fn persisted_queries_test_gremlin() {
+    init();
+    clear_db().await;
+
+    persisted_queries_test(gremlin_test_pool().await);
+}
+
+#[cfg(feature = "in-memory")]
+#[tokio::test]
+async fn persisted_queries_test_memory() {
+    init();
+    clear_db().await;
+
+    persisted_queries_test(memory_test_pool().await);
+}
+
+#[cfg(feature = "neo4j")]
+#[tokio::test]
+async fn persisted_queries_test_neo4j() {
+    init();
+    clear_db().await;
+
+    persisted_queries_test(neo4j_test_pool().await);
+}
+
+#[cfg(feature = "postgres")]
+#[tokio::test]
+async fn persisted_queries_test_postgres() {
+    init();
+    clear_db().await;
+
+    persisted_queries_test(postgres_test_pool().await);
+}
+
+#[cfg(feature = "sqlite")]
+#[tokio::test]
+async fn persisted_queries_test_sqlite() {
+    init();
+    clear_db().await;
+
+    persisted_queries_test(sqlite_test_pool().await);
+}
+
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+fn execute_persisted(engine: &Engine<()>, req: PersistedRequest) -> serde_json::Value {
+    let engine = engine.clone();
+    std::thread::spawn(move || {
+        engine
+            .execute_persisted(&req, &HashMap::new())
+            .expect("Expected successful execution.")
+    })
+    .join()
+    .expect("Thread panicked")
+}
+
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+fn apq_request(query: Option<&str>, hash: &str) -> PersistedRequest {
+    serde_json::from_value(json!({
+        "query": query,
+        "variables": {"partitionKey": "1234"},
+        "extensions": {"persistedQuery": {"version": 1, "sha256Hash": hash}}
+    }))
+    .unwrap()
+}
+
+/// Passes if persisted queries are run by name, automatic persisted queries are registered by
+/// hash, and other queries are rejected in persisted only mode
+#[allow(dead_code)]
+fn persisted_queries_test(database_pool: DatabasePool) {
+    let config: Configuration = File::open("./tests/fixtures/persisted_queries.yml")
+        .expect("Failed to load config file")
+        .try_into()
+        .unwrap();
+
+    let pq = PersistedQueries::from_dir("./tests/fixtures/persisted-queries")
+        .unwrap()
+        .with_persisted_only(true);
+    let engine = Engine::<()>::new(config.clone(), database_pool.clone())
+        .with_persisted_queries(pq)
+        .build()
+        .expect("Could not create warpgrapher engine");
+
+    let created = execute_persisted(
+        &engine,
+        PersistedRequest::with_id("CreateProject")
+            .with_variables(json!({"partitionKey": "1234", "input": {"name": "alpha"}})),
+    );
+    assert_eq!(created["data"]["ProjectCreate"]["name"], "alpha");
+    let projects = execute_persisted(
+        &engine,
+        PersistedRequest::with_id("Projects").with_variables(json!({"partitionKey": "1234"})),
+    );
+    assert_eq!(projects["data"]["Project"][0]["name"], "alpha");
+
+    let missing = execute_persisted(&engine, PersistedRequest::with_id("Issues"));
+    assert_eq!(missing["errors"][0]["message"], "PersistedQueryNotFound");
+    assert_eq!(
+        missing["errors"][0]["extensions"]["code"],
+        "PERSISTED_QUERY_NOT_FOUND"
+    );
+    let adhoc = execute(
+        &engine,
+        "query Read($partitionKey: String) { Project(partitionKey: $partitionKey) { name } }",
+        json!(null),
+    );
+    assert_eq!(
+        adhoc["errors"][0]["extensions"]["code"],
+        "PERSISTED_QUERY_REQUIRED"
+    );
+    assert!(adhoc.get("data").is_none());
+
+    let engine = Engine::<()>::new(config, database_pool)
+        .with_persisted_queries(PersistedQueries::new())
+        .build()
+        .expect("Could not create warpgrapher engine");
+    let query =
+        "query Read($partitionKey: String) { Project(partitionKey: $partitionKey) { name } }";
+    let wrong_hash = "5d29b3f7a5a6c5c9d86d1b2cbd6e6d54d1e87a41b7e6f5b1d39b2da2a7b5b3f0";
+    let not_found = execute_persisted(&engine, apq_request(None, wrong_hash));
+    assert_eq!(not_found["errors"][0]["message"], "PersistedQueryNotFound");
+    let mismatched = execute_persisted(&engine, apq_request(Some(query), wrong_hash));
+    assert_eq!(
+        mismatched["errors"][0]["extensions"]["code"],
+        "BAD_USER_INPUT"
+    );
+
+    let hash: String = Sha256::digest(query.as_bytes())
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect();
+    let registered = execute_persisted(&engine, apq_request(Some(query), &hash));
+    assert_eq!(registered["data"]["Project"][0]["name"], "alpha");
+    let by_hash = execute_persisted(&engine, apq_request(None, &hash));
+    assert_eq!(by_hash["data"]["Project"][0]["name"], "alpha");
+}
@@ -0,0 +1,133 @@
+#[cfg(feature = "postgres")]
+mod setup;
+
+#[cfg(feature = "postgres")]
+use serde_json::json;
+#[cfg(feature = "postgres")]
+use std::convert::TryInto;
+#[cfg(feature = "postgres")]
+use std::fs::File;
+#[cfg(feature = "postgres")]
+use uuid::Uuid;
+#[cfg(feature = "postgres")]
+use warpgrapher::engine::config::Configuration;
+#[cfg(feature = "postgres")]
+use warpgrapher::engine::database::postgres::PostgresEndpoint;
+#[cfg(feature = "postgres")]
+use warpgrapher::engine::database::{DatabaseEndpoint, DatabasePool};
+#[cfg(feature = "postgres")]
+use warpgrapher::{Client, Engine};
+
+#[cfg(feature = "postgres")]
+fn count(pool: &DatabasePool, table: &str) -> i64 {
+    pool.postgres()
+        .unwrap()
+        .pool()
+        .get()
+        .unwrap()
+        .query_one(&*("SELECT COUNT(*) FROM ".to_string() + table), &[])
+        .unwrap()
+        .get(0)
+}
+
+#[cfg(feature = "postgres")]
+fn drop_tables(pool: &DatabasePool, nodes: &str, edges: &str) {
+    pool.postgres()
+        .unwrap()
+        .pool()
+        .get()
+        .unwrap()
+        .batch_execute(&format!("DROP TABLE {}; DROP TABLE {};", nodes, edges))
+        .unwrap();
+}
+
+/// Passes if a graph is stored in the tables named for the endpoint, and reads filtered on
+/// string, integer, boolean, and float properties, and on relationship properties, return the
+/// matching nodes and relationships
+#[cfg(feature = "postgres")]
+#[tokio::test]
+async fn table_names_and_filters_postgres() {
+    setup::init();
+
+    let suffix = Uuid::new_v4().to_simple().to_string();
+    let nodes = "wg_nodes_".to_string() + &suffix;
+    let edges = "wg_edges_".to_string() + &suffix;
+    let pool = PostgresEndpoint::from_env()
+        .unwrap()
+        .with_table_names(nodes.clone(), edges.clone())
+        .pool()
+        .await
+        .unwrap();
+
+    let config: Configuration = File::open("./tests/fixtures/minimal.yml")
+        .unwrap()
+        .try_into()
+        .unwrap();
+    let engine = Engine::<()>::new(config, pool.clone()).build().unwrap();
+    let mut client = Client::new_with_engine(engine, None);
+
+    client
+        .create_node(
+            "Project",
+            "id",
+            None,
+            &json!({
+                "name": "alpha",
+                "priority": 1,
+                "estimate": 1.5,
+                "active": true,
+                "owner": {"props": {"since": "2020"}, "dst": {"User": {"$NEW": {"name": "alice"}}}}
+            }),
+        )
+        .await
+        .unwrap();
+    client
+        .create_node(
+            "Project",
+            "id",
+            None,
+            &json!({
+                "name": "beta",
+                "priority": 2,
+                "estimate": 2.5,
+                "active": false,
+                "owner": {"props": {"since": "2021"}, "dst": {"User": {"$NEW": {"name": "bob"}}}}
+            }),
+        )
+        .await
+        .unwrap();
+    assert_eq!(count(&pool, &nodes), 4);
+    assert_eq!(count(&pool, &edges), 2);
+
+    for input in &[
+        json!({"name": "beta"}),
+        json!({"priority": 2}),
+        json!({"active": false}),
+        json!({"estimate": 2.5}),
+        json!({"owner": {"props": {"since": "2021"}}}),
+        json!({"owner": {"dst": {"User": {"name": "bob"}}}}),
+    ] {
+        let projects = client
+            .read_node("Project", "name", None, Some(input))
+            .await
+            .unwrap();
+        let projects = projects.as_array().unwrap();
+        assert_eq!(projects.len(), 1, "input: {}", input);
+        assert_eq!(projects[0]["name"], "beta", "input: {}", input);
+    }
+
+    let owners = client
+        .read_rel(
+            "Project",
+            "owner",
+            "props { since }",
+            None,
+            Some(&json!({"props": {"since": "2020"}})),
+        )
+        .await
+        .unwrap();
+    assert_eq!(owners.as_array().unwrap().len(), 1);
+    assert_eq!(owners[0]["props"]["since"], "2020");
+
+    drop_tables(&pool, &nodes, &edges);
+}
@@ -0,0 +1,229 @@
+mod setup;
+
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+use serde_json::json;
+#[cfg(feature = "cosmos")]
+use setup::cosmos_test_pool;
+#[cfg(feature = "gremlin")]
+use setup::gremlin_test_pool;
+#[cfg(feature = "in-memory")]
+use setup::memory_test_pool;
+#[cfg(feature = "neo4j")]
+use setup::neo4j_test_pool;
+#[cfg(feature = "postgres")]
+use setup::postgres_test_pool;
+#[cfg(feature = "sqlite")]
+use setup::sqlite_test_pool;
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+use setup::{clear_db, execute, init};
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+use std::convert::TryInto;
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+use std::fs::File;
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+use std::sync::Arc;
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+use warpgrapher::engine::limits::QueryLimits;
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+use warpgrapher::engine::resolvers::{ExecutionResult, ResolverFacade, Resolvers};
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+use warpgrapher::{Configuration, DatabasePool, Engine, Error};
+
+#[cfg(feature = "cosmos")]
+#[tokio::test]
+async fn query_limits_test_cosmos() {
+    init();
+    clear_db().await;
+
+    query_limits_test(cosmos_test_pool().await);
+}
+
+#[cfg(feature = "gremlin")]
+#[tokio::test]
+async fn query_limits_test_gremlin() {
+    init();
+    clear_db().await;
+
+    query_limits_test(gremlin_test_pool().await);
+}
+
+#[cfg(feature = "in-memory")]
+#[tokio::test]
+async fn query_limits_test_memory() {
+    init();
+    clear_db().await;
+
+    query_limits_test(memory_test_pool().await);
+}
+
+#[cfg(feature = "neo4j")]
+#[tokio::test]
+async fn query_limits_test_neo4j() {
+    init();
+    clear_db().await;
+
+    query_limits_test(neo4j_test_pool().await);
+}
+
+#[cfg(feature = "postgres")]
+#[tokio::test]
+async fn query_limits_test_postgres() {
+    init();
+    clear_db().await;
+
+    query_limits_test(postgres_test_pool().await);
+}
+
+#[cfg(feature = "sqlite")]
+#[tokio::test]
+async fn query_limits_test_sqlite() {
+    init();
+    clear_db().await;
+
+    query_limits_test(sqlite_test_pool().await);
+}
+
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+fn count_projects(facade: ResolverFacade<()>) -> ExecutionResult {
+    let projects = facade
+        .read_nodes("Project", None)
+        .map_err(Error::into_field_error)?;
+    facade.resolve_scalar(projects.len() as i32)
+}
+
+/// Passes if queries that are too deep, too costly, or cannot be parsed are rejected before they
+/// run, and if a query that reads too many nodes fails, whether the nodes are read by a
+/// generated resolver or by a custom resolver
+#[allow(dead_code)]
+fn query_limits_test(database_pool: DatabasePool) {
+    let config: Configuration = File::open("./tests/fixtures/query_limits.yml")
+        .expect("Failed to load config file")
+        .try_into()
+        .unwrap();
+
+    let mut resolvers = Resolvers::<()>::new();
+    resolvers.insert("CountProjects".to_string(), Arc::new(count_projects));
+
+    let limits = QueryLimits::new()
+        .with_max_depth(3)
+        .with_max_cost(200)
+        .with_max_nodes(2);
+    let engine = Engine::<()>::new(config, database_pool)
+        .with_resolvers(resolvers)
+        .with_query_limits(limits)
+        .build()
+        .expect("Could not create warpgrapher engine");
+
+    let create = "mutation Create($partitionKey: String, $input: ProjectCreateMutationInput!) {
+        ProjectCreate(partitionKey: $partitionKey, input: $input) { id }
+    }";
+    execute(&engine, create, json!({"name": "alpha"}));
+    execute(&engine, create, json!({"name": "beta"}));
+
+    let read = "query Read($partitionKey: String) {
+        Project(partitionKey: $partitionKey) { name }
+    }";
+    let projects = execute(&engine, read, json!(null));
+    assert_eq!(projects["data"]["Project"].as_array().unwrap().len(), 2);
+    let count = execute(&engine, "query { CountProjects }", json!(null));
+    assert_eq!(count["data"]["CountProjects"], 2);
+
+    let deep = execute(
+        &engine,
+        "query { Project { issues { dst { ... on Issue { id } } props { id } } } }",
+        json!(null),
+    );
+    assert_eq!(deep["errors"][0]["extensions"]["code"], "QUERY_TOO_DEEP");
+    assert_eq!(deep["errors"][0]["extensions"]["details"]["max"], 3);
+    assert!(deep.get("data").is_none());
+
+    let costly = execute(
+        &engine,
+        "query { a: Project { issues { id } } b: Project { issues { id } } }",
+        json!(null),
+    );
+    assert_eq!(
+        costly["errors"][0]["extensions"]["code"],
+        "QUERY_TOO_COMPLEX"
+    );
+    assert_eq!(costly["errors"][0]["extensions"]["details"]["cost"], 222);
+
+    let unparsed = execute(&engine, "query { Project { name }", json!(null));
+    assert_eq!(
+        unparsed["errors"][0]["extensions"]["code"],
+        "GRAPHQL_VALIDATION_FAILED"
+    );
+    assert!(unparsed.get("data").is_none());
+
+    execute(&engine, create, json!({"name": "gamma"}));
+    let large = execute(&engine, read, json!(null));
+    assert_eq!(large["errors"][0]["extensions"]["code"], "RESULT_TOO_LARGE");
+    assert!(large["data"]["Project"].is_null());
+
+    let count = execute(&engine, "query { CountProjects }", json!(null));
+    assert_eq!(count["errors"][0]["extensions"]["code"], "RESULT_TOO_LARGE");
+    assert!(count["data"]["CountProjects"].is_null());
+}
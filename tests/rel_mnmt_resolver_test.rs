@@ -5,10 +5,23 @@ use serde_json::json;
 use setup::cosmos_test_client;
 #[cfg(feature = "gremlin")]
 use setup::gremlin_test_client;
+#[cfg(feature = "in-memory")]
+use setup::memory_test_client;
 #[cfg(feature = "neo4j")]
 use setup::neo4j_test_client;
+#[cfg(feature = "postgres")]
+use setup::postgres_test_client;
+#[cfg(feature = "sqlite")]
+use setup::sqlite_test_client;
 use setup::AppRequestCtx;
-#[cfg(any(feature = "cosmos", feature = "gremlin", feature = "neo4j"))]
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
 use setup::{clear_db, init};
 use warpgrapher::client::Client;
 
@@ -42,6 +55,36 @@ async fn create_mnmt_new_rel_gremlin() {
     create_mnmt_new_rel(client).await;
 }
 
+#[cfg(feature = "in-memory")]
+#[tokio::test]
+async fn create_mnmt_new_rel_memory() {
+    init();
+    clear_db().await;
+
+    let client = memory_test_client("./tests/fixtures/minimal.yml").await;
+    create_mnmt_new_rel(client).await;
+}
+
+#[cfg(feature = "postgres")]
+#[tokio::test]
+async fn create_mnmt_new_rel_postgres() {
+    init();
+    clear_db().await;
+
+    let client = postgres_test_client("./tests/fixtures/minimal.yml").await;
+    create_mnmt_new_rel(client).await;
+}
+
+#[cfg(feature = "sqlite")]
+#[tokio::test]
+async fn create_mnmt_new_rel_sqlite() {
+    init();
+    clear_db().await;
+
+    let client = sqlite_test_client("./tests/fixtures/minimal.yml").await;
+    create_mnmt_new_rel(client).await;
+}
+
 /// Passes if warpgrapher can create a node with a relationship to another new node
 #[allow(clippy::cognitive_complexity, dead_code)]
 async fn create_mnmt_new_rel(mut client: Client<AppRequestCtx>) {
@@ -160,6 +203,36 @@ async fn create_mnmt_rel_existing_node_gremlin() {
     create_mnmt_rel_existing_node(client).await;
 }
 
+#[cfg(feature = "in-memory")]
+#[tokio::test]
+async fn create_mnmt_rel_existing_node_memory() {
+    init();
+    clear_db().await;
+
+    let client = memory_test_client("./tests/fixtures/minimal.yml").await;
+    create_mnmt_rel_existing_node(client).await;
+}
+
+#[cfg(feature = "postgres")]
+#[tokio::test]
+async fn create_mnmt_rel_existing_node_postgres() {
+    init();
+    clear_db().await;
+
+    let client = postgres_test_client("./tests/fixtures/minimal.yml").await;
+    create_mnmt_rel_existing_node(client).await;
+}
+
+#[cfg(feature = "sqlite")]
+#[tokio::test]
+async fn create_mnmt_rel_existing_node_sqlite() {
+    init();
+    clear_db().await;
+
+    let client = sqlite_test_client("./tests/fixtures/minimal.yml").await;
+    create_mnmt_rel_existing_node(client).await;
+}
+
 #[allow(clippy::cognitive_complexity, dead_code)]
 async fn create_mnmt_rel_existing_node(mut client: Client<AppRequestCtx>) {
     let _p0 = client
@@ -306,6 +379,36 @@ async fn read_mnmt_rel_by_rel_props_gremlin() {
     read_mnmt_rel_by_rel_props(client).await;
 }
 
+#[cfg(feature = "in-memory")]
+#[tokio::test]
+async fn read_mnmt_rel_by_rel_props_memory() {
+    init();
+    clear_db().await;
+
+    let client = memory_test_client("./tests/fixtures/minimal.yml").await;
+    read_mnmt_rel_by_rel_props(client).await;
+}
+
+#[cfg(feature = "postgres")]
+#[tokio::test]
+async fn read_mnmt_rel_by_rel_props_postgres() {
+    init();
+    clear_db().await;
+
+    let client = postgres_test_client("./tests/fixtures/minimal.yml").await;
+    read_mnmt_rel_by_rel_props(client).await;
+}
+
+#[cfg(feature = "sqlite")]
+#[tokio::test]
+async fn read_mnmt_rel_by_rel_props_sqlite() {
+    init();
+    clear_db().await;
+
+    let client = sqlite_test_client("./tests/fixtures/minimal.yml").await;
+    read_mnmt_rel_by_rel_props(client).await;
+}
+
 #[allow(clippy::cognitive_complexity, dead_code)]
 async fn read_mnmt_rel_by_rel_props(mut client: Client<AppRequestCtx>) {
     let _p0 = client
@@ -388,6 +491,36 @@ async fn read_mnmt_rel_by_src_props_gremlin() {
     read_mnmt_rel_by_src_props(client).await;
 }
 
+#[cfg(feature = "in-memory")]
+#[tokio::test]
+async fn read_mnmt_rel_by_src_props_memory() {
+    init();
+    clear_db().await;
+
+    let client = memory_test_client("./tests/fixtures/minimal.yml").await;
+    read_mnmt_rel_by_src_props(client).await;
+}
+
+#[cfg(feature = "postgres")]
+#[tokio::test]
+async fn read_mnmt_rel_by_src_props_postgres() {
+    init();
+    clear_db().await;
+
+    let client = postgres_test_client("./tests/fixtures/minimal.yml").await;
+    read_mnmt_rel_by_src_props(client).await;
+}
+
+#[cfg(feature = "sqlite")]
+#[tokio::test]
+async fn read_mnmt_rel_by_src_props_sqlite() {
+    init();
+    clear_db().await;
+
+    let client = sqlite_test_client("./tests/fixtures/minimal.yml").await;
+    read_mnmt_rel_by_src_props(client).await;
+}
+
 #[allow(clippy::cognitive_complexity, dead_code)]
 async fn read_mnmt_rel_by_src_props(mut client: Client<AppRequestCtx>) {
     let _p0 = client
@@ -469,6 +602,36 @@ async fn read_mnmt_rel_by_dst_props_gremlin() {
     read_mnmt_rel_by_dst_props(client).await;
 }
 
+#[cfg(feature = "in-memory")]
+#[tokio::test]
+async fn read_mnmt_rel_by_dst_props_memory() {
+    init();
+    clear_db().await;
+
+    let client = memory_test_client("./tests/fixtures/minimal.yml").await;
+    read_mnmt_rel_by_dst_props(client).await;
+}
+
+#[cfg(feature = "postgres")]
+#[tokio::test]
+async fn read_mnmt_rel_by_dst_props_postgres() {
+    init();
+    clear_db().await;
+
+    let client = postgres_test_client("./tests/fixtures/minimal.yml").await;
+    read_mnmt_rel_by_dst_props(client).await;
+}
+
+#[cfg(feature = "sqlite")]
+#[tokio::test]
+async fn read_mnmt_rel_by_dst_props_sqlite() {
+    init();
+    clear_db().await;
+
+    let client = sqlite_test_client("./tests/fixtures/minimal.yml").await;
+    read_mnmt_rel_by_dst_props(client).await;
+}
+
 #[allow(clippy::cognitive_complexity, dead_code)]
 async fn read_mnmt_rel_by_dst_props(mut client: Client<AppRequestCtx>) {
     let _p0 = client
@@ -586,6 +749,36 @@ async fn update_mnmt_rel_by_rel_prop_gremlin() {
     update_mnmt_rel_by_rel_prop(client).await;
 }
 
+#[cfg(feature = "in-memory")]
+#[tokio::test]
+async fn update_mnmt_rel_by_rel_prop_memory() {
+    init();
+    clear_db().await;
+
+    let client = memory_test_client("./tests/fixtures/minimal.yml").await;
+    update_mnmt_rel_by_rel_prop(client).await;
+}
+
+#[cfg(feature = "postgres")]
+#[tokio::test]
+async fn update_mnmt_rel_by_rel_prop_postgres() {
+    init();
+    clear_db().await;
+
+    let client = postgres_test_client("./tests/fixtures/minimal.yml").await;
+    update_mnmt_rel_by_rel_prop(client).await;
+}
+
+#[cfg(feature = "sqlite")]
+#[tokio::test]
+async fn update_mnmt_rel_by_rel_prop_sqlite() {
+    init();
+    clear_db().await;
+
+    let client = sqlite_test_client("./tests/fixtures/minimal.yml").await;
+    update_mnmt_rel_by_rel_prop(client).await;
+}
+
 #[allow(clippy::cognitive_complexity, dead_code)]
 async fn update_mnmt_rel_by_rel_prop(mut client: Client<AppRequestCtx>) {
     let _p0 = client
@@ -733,6 +926,36 @@ async fn update_mnmt_rel_by_src_prop_gremlin() {
     update_mnmt_rel_by_src_prop(client).await;
 }
 
+#[cfg(feature = "in-memory")]
+#[tokio::test]
+async fn update_mnmt_rel_by_src_prop_memory() {
+    init();
+    clear_db().await;
+
+    let client = memory_test_client("./tests/fixtures/minimal.yml").await;
+    update_mnmt_rel_by_src_prop(client).await;
+}
+
+#[cfg(feature = "postgres")]
+#[tokio::test]
+async fn update_mnmt_rel_by_src_prop_postgres() {
+    init();
+    clear_db().await;
+
+    let client = postgres_test_client("./tests/fixtures/minimal.yml").await;
+    update_mnmt_rel_by_src_prop(client).await;
+}
+
+#[cfg(feature = "sqlite")]
+#[tokio::test]
+async fn update_mnmt_rel_by_src_prop_sqlite() {
+    init();
+    clear_db().await;
+
+    let client = sqlite_test_client("./tests/fixtures/minimal.yml").await;
+    update_mnmt_rel_by_src_prop(client).await;
+}
+
 #[allow(clippy::cognitive_complexity, dead_code)]
 async fn update_mnmt_rel_by_src_prop(mut client: Client<AppRequestCtx>) {
     let _p0 = client
@@ -828,6 +1051,36 @@ async fn update_mnmt_rel_by_dst_prop_gremlin() {
     update_mnmt_rel_by_dst_prop(client).await;
 }
 
+#[cfg(feature = "in-memory")]
+#[tokio::test]
+async fn update_mnmt_rel_by_dst_prop_memory() {
+    init();
+    clear_db().await;
+
+    let client = memory_test_client("./tests/fixtures/minimal.yml").await;
+    update_mnmt_rel_by_dst_prop(client).await;
+}
+
+#[cfg(feature = "postgres")]
+#[tokio::test]
+async fn update_mnmt_rel_by_dst_prop_postgres() {
+    init();
+    clear_db().await;
+
+    let client = postgres_test_client("./tests/fixtures/minimal.yml").await;
+    update_mnmt_rel_by_dst_prop(client).await;
+}
+
+#[cfg(feature = "sqlite")]
+#[tokio::test]
+async fn update_mnmt_rel_by_dst_prop_sqlite() {
+    init();
+    clear_db().await;
+
+    let client = sqlite_test_client("./tests/fixtures/minimal.yml").await;
+    update_mnmt_rel_by_dst_prop(client).await;
+}
+
 #[allow(clippy::cognitive_complexity, dead_code)]
 async fn update_mnmt_rel_by_dst_prop(mut client: Client<AppRequestCtx>) {
     let _p0 = client
@@ -975,6 +1228,36 @@ async fn delete_mnmt_rel_by_rel_prop_gremlin() {
     delete_mnmt_rel_by_rel_prop(client).await;
 }
 
+#[cfg(feature = "in-memory")]
+#[tokio::test]
+async fn delete_mnmt_rel_by_rel_prop_memory() {
+    init();
+    clear_db().await;
+
+    let client = memory_test_client("./tests/fixtures/minimal.yml").await;
+    delete_mnmt_rel_by_rel_prop(client).await;
+}
+
+#[cfg(feature = "postgres")]
+#[tokio::test]
+async fn delete_mnmt_rel_by_rel_prop_postgres() {
+    init();
+    clear_db().await;
+
+    let client = postgres_test_client("./tests/fixtures/minimal.yml").await;
+    delete_mnmt_rel_by_rel_prop(client).await;
+}
+
+#[cfg(feature = "sqlite")]
+#[tokio::test]
+async fn delete_mnmt_rel_by_rel_prop_sqlite() {
+    init();
+    clear_db().await;
+
+    let client = sqlite_test_client("./tests/fixtures/minimal.yml").await;
+    delete_mnmt_rel_by_rel_prop(client).await;
+}
+
 #[allow(clippy::cognitive_complexity, dead_code)]
 async fn delete_mnmt_rel_by_rel_prop(mut client: Client<AppRequestCtx>) {
     let _p0 = client
@@ -1100,6 +1383,36 @@ async fn delete_mnmt_rel_by_dst_prop_gremlin() {
     delete_mnmt_rel_by_dst_prop(client).await;
 }
 
+#[cfg(feature = "in-memory")]
+#[tokio::test]
+async fn delete_mnmt_rel_by_dst_prop_memory() {
+    init();
+    clear_db().await;
+
+    let client = memory_test_client("./tests/fixtures/minimal.yml").await;
+    delete_mnmt_rel_by_dst_prop(client).await;
+}
+
+#[cfg(feature = "postgres")]
+#[tokio::test]
+async fn delete_mnmt_rel_by_dst_prop_postgres() {
+    init();
+    clear_db().await;
+
+    let client = postgres_test_client("./tests/fixtures/minimal.yml").await;
+    delete_mnmt_rel_by_dst_prop(client).await;
+}
+
+#[cfg(feature = "sqlite")]
+#[tokio::test]
+async fn delete_mnmt_rel_by_dst_prop_sqlite() {
+    init();
+    clear_db().await;
+
+    let client = sqlite_test_client("./tests/fixtures/minimal.yml").await;
+    delete_mnmt_rel_by_dst_prop(client).await;
+}
+
 #[allow(clippy::cognitive_complexity, dead_code)]
 async fn delete_mnmt_rel_by_dst_prop(mut client: Client<AppRequestCtx>) {
     let _p0 = client
@@ -1225,6 +1538,36 @@ async fn delete_mnmt_rel_by_src_prop_gremlin() {
     delete_mnmt_rel_by_src_prop(client).await;
 }
 
+#[cfg(feature = "in-memory")]
+#[tokio::test]
+async fn delete_mnmt_rel_by_src_prop_memory() {
+    init();
+    clear_db().await;
+
+    let client = memory_test_client("./tests/fixtures/minimal.yml").await;
+    delete_mnmt_rel_by_src_prop(client).await;
+}
+
+#[cfg(feature = "postgres")]
+#[tokio::test]
+async fn delete_mnmt_rel_by_src_prop_postgres() {
+    init();
+    clear_db().await;
+
+    let client = postgres_test_client("./tests/fixtures/minimal.yml").await;
+    delete_mnmt_rel_by_src_prop(client).await;
+}
+
+#[cfg(feature = "sqlite")]
+#[tokio::test]
+async fn delete_mnmt_rel_by_src_prop_sqlite() {
+    init();
+    clear_db().await;
+
+    let client = sqlite_test_client("./tests/fixtures/minimal.yml").await;
+    delete_mnmt_rel_by_src_prop(client).await;
+}
+
 #[allow(clippy::cognitive_complexity, dead_code)]
 async fn delete_mnmt_rel_by_src_prop(mut client: Client<AppRequestCtx>) {
     let _p0 = client
@@ -1356,6 +1699,16 @@ async fn delete_mnmt_rel_by_src_and_dst_prop_gremlin() {
     delete_mnmt_rel_by_src_and_dst_prop(client).await;
 }
 
+#[cfg(feature = "in-memory")]
+#[tokio::test]
+async fn delete_mnmt_rel_by_src_and_dst_prop_memory() {
+    init();
+    clear_db().await;
+
+    let client = memory_test_client("./tests/fixtures/minimal.yml").await;
+    delete_mnmt_rel_by_src_and_dst_prop(client).await;
+}
+
 #[cfg(feature = "neo4j")]
 #[tokio::test]
 async fn delete_mnmt_rel_by_src_and_dst_prop_neo4j() {
@@ -1366,6 +1719,26 @@ async fn delete_mnmt_rel_by_src_and_dst_prop_neo4j() {
     delete_mnmt_rel_by_src_and_dst_prop(client).await;
 }
 
+#[cfg(feature = "postgres")]
+#[tokio::test]
+async fn delete_mnmt_rel_by_src_and_dst_prop_postgres() {
+    init();
+    clear_db().await;
+
+    let client = postgres_test_client("./tests/fixtures/minimal.yml").await;
+    delete_mnmt_rel_by_src_and_dst_prop(client).await;
+}
+
+#[cfg(feature = "sqlite")]
+#[tokio::test]
+async fn delete_mnmt_rel_by_src_and_dst_prop_sqlite() {
+    init();
+    clear_db().await;
+
+    let client = sqlite_test_client("./tests/fixtures/minimal.yml").await;
+    delete_mnmt_rel_by_src_and_dst_prop(client).await;
+}
+
 #[allow(clippy::cognitive_complexity, dead_code)]
 async fn delete_mnmt_rel_by_src_and_dst_prop(mut client: Client<AppRequestCtx>) {
     let _p0 = client
@@ -5,10 +5,23 @@ use serde_json::json;
 use setup::cosmos_test_client;
 #[cfg(feature = "gremlin")]
 use setup::gremlin_test_client;
+#[cfg(feature = "in-memory")]
+use setup::memory_test_client;
 #[cfg(feature = "neo4j")]
 use setup::neo4j_test_client;
+#[cfg(feature = "postgres")]
+use setup::postgres_test_client;
+#[cfg(feature = "sqlite")]
+use setup::sqlite_test_client;
 use setup::AppRequestCtx;
-#[cfg(any(feature = "cosmos", feature = "gremlin", feature = "neo4j"))]
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
 use setup::{clear_db, init};
 use warpgrapher::client::Client;
 
@@ -42,6 +55,36 @@ async fn create_mnst_new_rel_gremlin() {
     create_mnst_new_rel(client).await;
 }
 
+#[cfg(feature = "in-memory")]
+#[tokio::test]
+async fn create_mnst_new_rel_memory() {
+    init();
+    clear_db().await;
+
+    let client = memory_test_client("./tests/fixtures/minimal.yml").await;
+    create_mnst_new_rel(client).await;
+}
+
+#[cfg(feature = "postgres")]
+#[tokio::test]
+async fn create_mnst_new_rel_postgres() {
+    init();
+    clear_db().await;
+
+    let client = postgres_test_client("./tests/fixtures/minimal.yml").await;
+    create_mnst_new_rel(client).await;
+}
+
+#[cfg(feature = "sqlite")]
+#[tokio::test]
+async fn create_mnst_new_rel_sqlite() {
+    init();
+    clear_db().await;
+
+    let client = sqlite_test_client("./tests/fixtures/minimal.yml").await;
+    create_mnst_new_rel(client).await;
+}
+
 /// Passes if warpgrapher can create a node with a relationship to another new node
 #[allow(clippy::cognitive_complexity, dead_code)]
 async fn create_mnst_new_rel(mut client: Client<AppRequestCtx>) {
@@ -155,6 +198,36 @@ async fn create_mnst_rel_existing_node_gremlin() {
     create_mnst_rel_existing_node(client).await;
 }
 
+#[cfg(feature = "in-memory")]
+#[tokio::test]
+async fn create_mnst_rel_existing_node_memory() {
+    init();
+    clear_db().await;
+
+    let client = memory_test_client("./tests/fixtures/minimal.yml").await;
+    create_mnst_rel_existing_node(client).await;
+}
+
+#[cfg(feature = "postgres")]
+#[tokio::test]
+async fn create_mnst_rel_existing_node_postgres() {
+    init();
+    clear_db().await;
+
+    let client = postgres_test_client("./tests/fixtures/minimal.yml").await;
+    create_mnst_rel_existing_node(client).await;
+}
+
+#[cfg(feature = "sqlite")]
+#[tokio::test]
+async fn create_mnst_rel_existing_node_sqlite() {
+    init();
+    clear_db().await;
+
+    let client = sqlite_test_client("./tests/fixtures/minimal.yml").await;
+    create_mnst_rel_existing_node(client).await;
+}
+
 #[allow(clippy::cognitive_complexity, dead_code)]
 async fn create_mnst_rel_existing_node(mut client: Client<AppRequestCtx>) {
     let _p0 = client
@@ -292,6 +365,36 @@ async fn read_mnst_rel_by_rel_props_gremlin() {
     read_mnst_rel_by_rel_props(client).await;
 }
 
+#[cfg(feature = "in-memory")]
+#[tokio::test]
+async fn read_mnst_rel_by_rel_props_memory() {
+    init();
+    clear_db().await;
+
+    let client = memory_test_client("./tests/fixtures/minimal.yml").await;
+    read_mnst_rel_by_rel_props(client).await;
+}
+
+#[cfg(feature = "postgres")]
+#[tokio::test]
+async fn read_mnst_rel_by_rel_props_postgres() {
+    init();
+    clear_db().await;
+
+    let client = postgres_test_client("./tests/fixtures/minimal.yml").await;
+    read_mnst_rel_by_rel_props(client).await;
+}
+
+#[cfg(feature = "sqlite")]
+#[tokio::test]
+async fn read_mnst_rel_by_rel_props_sqlite() {
+    init();
+    clear_db().await;
+
+    let client = sqlite_test_client("./tests/fixtures/minimal.yml").await;
+    read_mnst_rel_by_rel_props(client).await;
+}
+
 #[allow(clippy::cognitive_complexity, dead_code)]
 async fn read_mnst_rel_by_rel_props(mut client: Client<AppRequestCtx>) {
     let _p0 = client
@@ -375,6 +478,36 @@ async fn read_mnst_rel_by_src_props_gremlin() {
     read_mnst_rel_by_src_props(client).await;
 }
 
+#[cfg(feature = "in-memory")]
+#[tokio::test]
+async fn read_mnst_rel_by_src_props_memory() {
+    init();
+    clear_db().await;
+
+    let client = memory_test_client("./tests/fixtures/minimal.yml").await;
+    read_mnst_rel_by_src_props(client).await;
+}
+
+#[cfg(feature = "postgres")]
+#[tokio::test]
+async fn read_mnst_rel_by_src_props_postgres() {
+    init();
+    clear_db().await;
+
+    let client = postgres_test_client("./tests/fixtures/minimal.yml").await;
+    read_mnst_rel_by_src_props(client).await;
+}
+
+#[cfg(feature = "sqlite")]
+#[tokio::test]
+async fn read_mnst_rel_by_src_props_sqlite() {
+    init();
+    clear_db().await;
+
+    let client = sqlite_test_client("./tests/fixtures/minimal.yml").await;
+    read_mnst_rel_by_src_props(client).await;
+}
+
 #[allow(clippy::cognitive_complexity, dead_code)]
 async fn read_mnst_rel_by_src_props(mut client: Client<AppRequestCtx>) {
     let _p0 = client
@@ -464,6 +597,36 @@ async fn read_mnst_rel_by_dst_props_gremlin() {
     read_mnst_rel_by_dst_props(client).await;
 }
 
+#[cfg(feature = "in-memory")]
+#[tokio::test]
+async fn read_mnst_rel_by_dst_props_memory() {
+    init();
+    clear_db().await;
+
+    let client = memory_test_client("./tests/fixtures/minimal.yml").await;
+    read_mnst_rel_by_dst_props(client).await;
+}
+
+#[cfg(feature = "postgres")]
+#[tokio::test]
+async fn read_mnst_rel_by_dst_props_postgres() {
+    init();
+    clear_db().await;
+
+    let client = postgres_test_client("./tests/fixtures/minimal.yml").await;
+    read_mnst_rel_by_dst_props(client).await;
+}
+
+#[cfg(feature = "sqlite")]
+#[tokio::test]
+async fn read_mnst_rel_by_dst_props_sqlite() {
+    init();
+    clear_db().await;
+
+    let client = sqlite_test_client("./tests/fixtures/minimal.yml").await;
+    read_mnst_rel_by_dst_props(client).await;
+}
+
 #[allow(clippy::cognitive_complexity, dead_code)]
 async fn read_mnst_rel_by_dst_props(mut client: Client<AppRequestCtx>) {
     let _p0 = client
@@ -547,6 +710,36 @@ async fn update_mnst_rel_by_rel_prop_gremlin() {
     update_mnst_rel_by_rel_prop(client).await;
 }
 
+#[cfg(feature = "in-memory")]
+#[tokio::test]
+async fn update_mnst_rel_by_rel_prop_memory() {
+    init();
+    clear_db().await;
+
+    let client = memory_test_client("./tests/fixtures/minimal.yml").await;
+    update_mnst_rel_by_rel_prop(client).await;
+}
+
+#[cfg(feature = "postgres")]
+#[tokio::test]
+async fn update_mnst_rel_by_rel_prop_postgres() {
+    init();
+    clear_db().await;
+
+    let client = postgres_test_client("./tests/fixtures/minimal.yml").await;
+    update_mnst_rel_by_rel_prop(client).await;
+}
+
+#[cfg(feature = "sqlite")]
+#[tokio::test]
+async fn update_mnst_rel_by_rel_prop_sqlite() {
+    init();
+    clear_db().await;
+
+    let client = sqlite_test_client("./tests/fixtures/minimal.yml").await;
+    update_mnst_rel_by_rel_prop(client).await;
+}
+
 #[allow(clippy::cognitive_complexity, dead_code)]
 async fn update_mnst_rel_by_rel_prop(mut client: Client<AppRequestCtx>) {
     let _p0 = client
@@ -673,6 +866,36 @@ async fn update_mnst_rel_by_src_prop_gremlin() {
     update_mnst_rel_by_src_prop(client).await;
 }
 
+#[cfg(feature = "in-memory")]
+#[tokio::test]
+async fn update_mnst_rel_by_src_prop_memory() {
+    init();
+    clear_db().await;
+
+    let client = memory_test_client("./tests/fixtures/minimal.yml").await;
+    update_mnst_rel_by_src_prop(client).await;
+}
+
+#[cfg(feature = "postgres")]
+#[tokio::test]
+async fn update_mnst_rel_by_src_prop_postgres() {
+    init();
+    clear_db().await;
+
+    let client = postgres_test_client("./tests/fixtures/minimal.yml").await;
+    update_mnst_rel_by_src_prop(client).await;
+}
+
+#[cfg(feature = "sqlite")]
+#[tokio::test]
+async fn update_mnst_rel_by_src_prop_sqlite() {
+    init();
+    clear_db().await;
+
+    let client = sqlite_test_client("./tests/fixtures/minimal.yml").await;
+    update_mnst_rel_by_src_prop(client).await;
+}
+
 #[allow(clippy::cognitive_complexity, dead_code)]
 async fn update_mnst_rel_by_src_prop(mut client: Client<AppRequestCtx>) {
     let _p0 = client
@@ -766,6 +989,36 @@ async fn update_mnst_rel_by_dst_prop_gremlin() {
     update_mnst_rel_by_dst_prop(client).await;
 }
 
+#[cfg(feature = "in-memory")]
+#[tokio::test]
+async fn update_mnst_rel_by_dst_prop_memory() {
+    init();
+    clear_db().await;
+
+    let client = memory_test_client("./tests/fixtures/minimal.yml").await;
+    update_mnst_rel_by_dst_prop(client).await;
+}
+
+#[cfg(feature = "postgres")]
+#[tokio::test]
+async fn update_mnst_rel_by_dst_prop_postgres() {
+    init();
+    clear_db().await;
+
+    let client = postgres_test_client("./tests/fixtures/minimal.yml").await;
+    update_mnst_rel_by_dst_prop(client).await;
+}
+
+#[cfg(feature = "sqlite")]
+#[tokio::test]
+async fn update_mnst_rel_by_dst_prop_sqlite() {
+    init();
+    clear_db().await;
+
+    let client = sqlite_test_client("./tests/fixtures/minimal.yml").await;
+    update_mnst_rel_by_dst_prop(client).await;
+}
+
 #[allow(clippy::cognitive_complexity, dead_code)]
 async fn update_mnst_rel_by_dst_prop(mut client: Client<AppRequestCtx>) {
     let _p0 = client
@@ -892,6 +1145,36 @@ async fn delete_mnst_rel_by_rel_prop_gremlin() {
     delete_mnst_rel_by_rel_prop(client).await;
 }
 
+#[cfg(feature = "in-memory")]
+#[tokio::test]
+async fn delete_mnst_rel_by_rel_prop_memory() {
+    init();
+    clear_db().await;
+
+    let client = memory_test_client("./tests/fixtures/minimal.yml").await;
+    delete_mnst_rel_by_rel_prop(client).await;
+}
+
+#[cfg(feature = "postgres")]
+#[tokio::test]
+async fn delete_mnst_rel_by_rel_prop_postgres() {
+    init();
+    clear_db().await;
+
+    let client = postgres_test_client("./tests/fixtures/minimal.yml").await;
+    delete_mnst_rel_by_rel_prop(client).await;
+}
+
+#[cfg(feature = "sqlite")]
+#[tokio::test]
+async fn delete_mnst_rel_by_rel_prop_sqlite() {
+    init();
+    clear_db().await;
+
+    let client = sqlite_test_client("./tests/fixtures/minimal.yml").await;
+    delete_mnst_rel_by_rel_prop(client).await;
+}
+
 #[allow(clippy::cognitive_complexity, dead_code)]
 async fn delete_mnst_rel_by_rel_prop(mut client: Client<AppRequestCtx>) {
     let _p0 = client
@@ -995,6 +1278,36 @@ async fn delete_mnst_rel_by_dst_prop_gremlin() {
     delete_mnst_rel_by_dst_prop(client).await;
 }
 
+#[cfg(feature = "in-memory")]
+#[tokio::test]
+async fn delete_mnst_rel_by_dst_prop_memory() {
+    init();
+    clear_db().await;
+
+    let client = memory_test_client("./tests/fixtures/minimal.yml").await;
+    delete_mnst_rel_by_dst_prop(client).await;
+}
+
+#[cfg(feature = "postgres")]
+#[tokio::test]
+async fn delete_mnst_rel_by_dst_prop_postgres() {
+    init();
+    clear_db().await;
+
+    let client = postgres_test_client("./tests/fixtures/minimal.yml").await;
+    delete_mnst_rel_by_dst_prop(client).await;
+}
+
+#[cfg(feature = "sqlite")]
+#[tokio::test]
+async fn delete_mnst_rel_by_dst_prop_sqlite() {
+    init();
+    clear_db().await;
+
+    let client = sqlite_test_client("./tests/fixtures/minimal.yml").await;
+    delete_mnst_rel_by_dst_prop(client).await;
+}
+
 #[allow(clippy::cognitive_complexity, dead_code)]
 async fn delete_mnst_rel_by_dst_prop(mut client: Client<AppRequestCtx>) {
     let _p0 = client
@@ -1098,6 +1411,36 @@ async fn delete_mnst_rel_by_src_prop_gremlin() {
     delete_mnst_rel_by_src_prop(client).await;
 }
 
+#[cfg(feature = "in-memory")]
+#[tokio::test]
+async fn delete_mnst_rel_by_src_prop_memory() {
+    init();
+    clear_db().await;
+
+    let client = memory_test_client("./tests/fixtures/minimal.yml").await;
+    delete_mnst_rel_by_src_prop(client).await;
+}
+
+#[cfg(feature = "postgres")]
+#[tokio::test]
+async fn delete_mnst_rel_by_src_prop_postgres() {
+    init();
+    clear_db().await;
+
+    let client = postgres_test_client("./tests/fixtures/minimal.yml").await;
+    delete_mnst_rel_by_src_prop(client).await;
+}
+
+#[cfg(feature = "sqlite")]
+#[tokio::test]
+async fn delete_mnst_rel_by_src_prop_sqlite() {
+    init();
+    clear_db().await;
+
+    let client = sqlite_test_client("./tests/fixtures/minimal.yml").await;
+    delete_mnst_rel_by_src_prop(client).await;
+}
+
 #[allow(clippy::cognitive_complexity, dead_code)]
 async fn delete_mnst_rel_by_src_prop(mut client: Client<AppRequestCtx>) {
     let _p0 = client
@@ -5,10 +5,23 @@ use serde_json::json;
 use setup::cosmos_test_client;
 #[cfg(feature = "gremlin")]
 use setup::gremlin_test_client;
+#[cfg(feature = "in-memory")]
+use setup::memory_test_client;
 #[cfg(feature = "neo4j")]
 use setup::neo4j_test_client;
+#[cfg(feature = "postgres")]
+use setup::postgres_test_client;
+#[cfg(feature = "sqlite")]
+use setup::sqlite_test_client;
 use setup::AppRequestCtx;
-#[cfg(any(feature = "cosmos", feature = "gremlin", feature = "neo4j"))]
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
 use setup::{clear_db, init};
 use warpgrapher::client::Client;
 
@@ -42,6 +55,36 @@ async fn create_snst_new_rel_gremlin() {
     create_snst_new_rel(client).await;
 }
 
+#[cfg(feature = "in-memory")]
+#[tokio::test]
+async fn create_snst_new_rel_memory() {
+    init();
+    clear_db().await;
+
+    let client = memory_test_client("./tests/fixtures/minimal.yml").await;
+    create_snst_new_rel(client).await;
+}
+
+#[cfg(feature = "postgres")]
+#[tokio::test]
+async fn create_snst_new_rel_postgres() {
+    init();
+    clear_db().await;
+
+    let client = postgres_test_client("./tests/fixtures/minimal.yml").await;
+    create_snst_new_rel(client).await;
+}
+
+#[cfg(feature = "sqlite")]
+#[tokio::test]
+async fn create_snst_new_rel_sqlite() {
+    init();
+    clear_db().await;
+
+    let client = sqlite_test_client("./tests/fixtures/minimal.yml").await;
+    create_snst_new_rel(client).await;
+}
+
 /// Passes if warpgrapher can create a node with a relationship to another new node
 #[allow(clippy::cognitive_complexity, dead_code)]
 async fn create_snst_new_rel(mut client: Client<AppRequestCtx>) {
@@ -127,6 +170,36 @@ async fn snst_without_src_no_new_dst_gremlin() {
     snst_without_src_no_new_dst(client).await;
 }
 
+#[cfg(feature = "in-memory")]
+#[tokio::test]
+async fn snst_without_src_no_new_dst_memory() {
+    init();
+    clear_db().await;
+
+    let client = memory_test_client("./tests/fixtures/minimal.yml").await;
+    snst_without_src_no_new_dst(client).await;
+}
+
+#[cfg(feature = "postgres")]
+#[tokio::test]
+async fn snst_without_src_no_new_dst_postgres() {
+    init();
+    clear_db().await;
+
+    let client = postgres_test_client("./tests/fixtures/minimal.yml").await;
+    snst_without_src_no_new_dst(client).await;
+}
+
+#[cfg(feature = "sqlite")]
+#[tokio::test]
+async fn snst_without_src_no_new_dst_sqlite() {
+    init();
+    clear_db().await;
+
+    let client = sqlite_test_client("./tests/fixtures/minimal.yml").await;
+    snst_without_src_no_new_dst(client).await;
+}
+
 /// Passes if warpgrapher does not create the destination node if it can't find any source nodes
 #[allow(clippy::cognitive_complexity, dead_code)]
 async fn snst_without_src_no_new_dst(mut client: Client<AppRequestCtx>) {
@@ -188,6 +261,36 @@ async fn create_snst_rel_existing_node_gremlin() {
     create_snst_rel_existing_node(client).await;
 }
 
+#[cfg(feature = "in-memory")]
+#[tokio::test]
+async fn create_snst_rel_existing_node_memory() {
+    init();
+    clear_db().await;
+
+    let client = memory_test_client("./tests/fixtures/minimal.yml").await;
+    create_snst_rel_existing_node(client).await;
+}
+
+#[cfg(feature = "postgres")]
+#[tokio::test]
+async fn create_snst_rel_existing_node_postgres() {
+    init();
+    clear_db().await;
+
+    let client = postgres_test_client("./tests/fixtures/minimal.yml").await;
+    create_snst_rel_existing_node(client).await;
+}
+
+#[cfg(feature = "sqlite")]
+#[tokio::test]
+async fn create_snst_rel_existing_node_sqlite() {
+    init();
+    clear_db().await;
+
+    let client = sqlite_test_client("./tests/fixtures/minimal.yml").await;
+    create_snst_rel_existing_node(client).await;
+}
+
 #[allow(clippy::cognitive_complexity, dead_code)]
 async fn create_snst_rel_existing_node(mut client: Client<AppRequestCtx>) {
     let _p0 = client
@@ -284,6 +387,36 @@ async fn create_snst_rel_by_rel_props_gremlin() {
     read_snst_rel_by_rel_props(client).await;
 }
 
+#[cfg(feature = "in-memory")]
+#[tokio::test]
+async fn create_snst_rel_by_rel_props_memory() {
+    init();
+    clear_db().await;
+
+    let client = memory_test_client("./tests/fixtures/minimal.yml").await;
+    read_snst_rel_by_rel_props(client).await;
+}
+
+#[cfg(feature = "postgres")]
+#[tokio::test]
+async fn create_snst_rel_by_rel_props_postgres() {
+    init();
+    clear_db().await;
+
+    let client = postgres_test_client("./tests/fixtures/minimal.yml").await;
+    read_snst_rel_by_rel_props(client).await;
+}
+
+#[cfg(feature = "sqlite")]
+#[tokio::test]
+async fn create_snst_rel_by_rel_props_sqlite() {
+    init();
+    clear_db().await;
+
+    let client = sqlite_test_client("./tests/fixtures/minimal.yml").await;
+    read_snst_rel_by_rel_props(client).await;
+}
+
 #[allow(clippy::cognitive_complexity, dead_code)]
 async fn read_snst_rel_by_rel_props(mut client: Client<AppRequestCtx>) {
     let _p0 = client
@@ -361,6 +494,36 @@ async fn read_snst_rel_by_src_props_gremlin() {
     read_snst_rel_by_src_props(client).await;
 }
 
+#[cfg(feature = "in-memory")]
+#[tokio::test]
+async fn read_snst_rel_by_src_props_memory() {
+    init();
+    clear_db().await;
+
+    let client = memory_test_client("./tests/fixtures/minimal.yml").await;
+    read_snst_rel_by_src_props(client).await;
+}
+
+#[cfg(feature = "postgres")]
+#[tokio::test]
+async fn read_snst_rel_by_src_props_postgres() {
+    init();
+    clear_db().await;
+
+    let client = postgres_test_client("./tests/fixtures/minimal.yml").await;
+    read_snst_rel_by_src_props(client).await;
+}
+
+#[cfg(feature = "sqlite")]
+#[tokio::test]
+async fn read_snst_rel_by_src_props_sqlite() {
+    init();
+    clear_db().await;
+
+    let client = sqlite_test_client("./tests/fixtures/minimal.yml").await;
+    read_snst_rel_by_src_props(client).await;
+}
+
 #[allow(clippy::cognitive_complexity, dead_code)]
 async fn read_snst_rel_by_src_props(mut client: Client<AppRequestCtx>) {
     let _p0 = client
@@ -438,6 +601,36 @@ async fn read_snst_rel_by_dst_props_gremlin() {
     read_snst_rel_by_dst_props(client).await;
 }
 
+#[cfg(feature = "in-memory")]
+#[tokio::test]
+async fn read_snst_rel_by_dst_props_memory() {
+    init();
+    clear_db().await;
+
+    let client = memory_test_client("./tests/fixtures/minimal.yml").await;
+    read_snst_rel_by_dst_props(client).await;
+}
+
+#[cfg(feature = "postgres")]
+#[tokio::test]
+async fn read_snst_rel_by_dst_props_postgres() {
+    init();
+    clear_db().await;
+
+    let client = postgres_test_client("./tests/fixtures/minimal.yml").await;
+    read_snst_rel_by_dst_props(client).await;
+}
+
+#[cfg(feature = "sqlite")]
+#[tokio::test]
+async fn read_snst_rel_by_dst_props_sqlite() {
+    init();
+    clear_db().await;
+
+    let client = sqlite_test_client("./tests/fixtures/minimal.yml").await;
+    read_snst_rel_by_dst_props(client).await;
+}
+
 #[allow(clippy::cognitive_complexity, dead_code)]
 async fn read_snst_rel_by_dst_props(mut client: Client<AppRequestCtx>) {
     let _p0 = client
@@ -515,6 +708,36 @@ async fn create_snst_rel_by_rel_prop_gremlin() {
     update_snst_rel_by_rel_prop(client).await;
 }
 
+#[cfg(feature = "in-memory")]
+#[tokio::test]
+async fn create_snst_rel_by_rel_prop_memory() {
+    init();
+    clear_db().await;
+
+    let client = memory_test_client("./tests/fixtures/minimal.yml").await;
+    update_snst_rel_by_rel_prop(client).await;
+}
+
+#[cfg(feature = "postgres")]
+#[tokio::test]
+async fn create_snst_rel_by_rel_prop_postgres() {
+    init();
+    clear_db().await;
+
+    let client = postgres_test_client("./tests/fixtures/minimal.yml").await;
+    update_snst_rel_by_rel_prop(client).await;
+}
+
+#[cfg(feature = "sqlite")]
+#[tokio::test]
+async fn create_snst_rel_by_rel_prop_sqlite() {
+    init();
+    clear_db().await;
+
+    let client = sqlite_test_client("./tests/fixtures/minimal.yml").await;
+    update_snst_rel_by_rel_prop(client).await;
+}
+
 #[allow(clippy::cognitive_complexity, dead_code)]
 async fn update_snst_rel_by_rel_prop(mut client: Client<AppRequestCtx>) {
     let _p0 = client
@@ -618,6 +841,36 @@ async fn update_snst_rel_by_src_prop_gremlin() {
     update_snst_rel_by_src_prop(client).await;
 }
 
+#[cfg(feature = "in-memory")]
+#[tokio::test]
+async fn update_snst_rel_by_src_prop_memory() {
+    init();
+    clear_db().await;
+
+    let client = memory_test_client("./tests/fixtures/minimal.yml").await;
+    update_snst_rel_by_src_prop(client).await;
+}
+
+#[cfg(feature = "postgres")]
+#[tokio::test]
+async fn update_snst_rel_by_src_prop_postgres() {
+    init();
+    clear_db().await;
+
+    let client = postgres_test_client("./tests/fixtures/minimal.yml").await;
+    update_snst_rel_by_src_prop(client).await;
+}
+
+#[cfg(feature = "sqlite")]
+#[tokio::test]
+async fn update_snst_rel_by_src_prop_sqlite() {
+    init();
+    clear_db().await;
+
+    let client = sqlite_test_client("./tests/fixtures/minimal.yml").await;
+    update_snst_rel_by_src_prop(client).await;
+}
+
 #[allow(clippy::cognitive_complexity, dead_code)]
 async fn update_snst_rel_by_src_prop(mut client: Client<AppRequestCtx>) {
     let _p0 = client
@@ -721,6 +974,36 @@ async fn update_snst_rel_by_dst_prop_gremlin() {
     update_snst_rel_by_dst_prop(client).await;
 }
 
+#[cfg(feature = "in-memory")]
+#[tokio::test]
+async fn update_snst_rel_by_dst_prop_memory() {
+    init();
+    clear_db().await;
+
+    let client = memory_test_client("./tests/fixtures/minimal.yml").await;
+    update_snst_rel_by_dst_prop(client).await;
+}
+
+#[cfg(feature = "postgres")]
+#[tokio::test]
+async fn update_snst_rel_by_dst_prop_postgres() {
+    init();
+    clear_db().await;
+
+    let client = postgres_test_client("./tests/fixtures/minimal.yml").await;
+    update_snst_rel_by_dst_prop(client).await;
+}
+
+#[cfg(feature = "sqlite")]
+#[tokio::test]
+async fn update_snst_rel_by_dst_prop_sqlite() {
+    init();
+    clear_db().await;
+
+    let client = sqlite_test_client("./tests/fixtures/minimal.yml").await;
+    update_snst_rel_by_dst_prop(client).await;
+}
+
 #[allow(clippy::cognitive_complexity, dead_code)]
 async fn update_snst_rel_by_dst_prop(mut client: Client<AppRequestCtx>) {
     let _p0 = client
@@ -824,6 +1107,36 @@ async fn delete_snst_rel_by_del_prop_gremlin() {
     delete_snst_rel_by_rel_prop(client).await;
 }
 
+#[cfg(feature = "in-memory")]
+#[tokio::test]
+async fn delete_snst_rel_by_del_prop_memory() {
+    init();
+    clear_db().await;
+
+    let client = memory_test_client("./tests/fixtures/minimal.yml").await;
+    delete_snst_rel_by_rel_prop(client).await;
+}
+
+#[cfg(feature = "postgres")]
+#[tokio::test]
+async fn delete_snst_rel_by_del_prop_postgres() {
+    init();
+    clear_db().await;
+
+    let client = postgres_test_client("./tests/fixtures/minimal.yml").await;
+    delete_snst_rel_by_rel_prop(client).await;
+}
+
+#[cfg(feature = "sqlite")]
+#[tokio::test]
+async fn delete_snst_rel_by_del_prop_sqlite() {
+    init();
+    clear_db().await;
+
+    let client = sqlite_test_client("./tests/fixtures/minimal.yml").await;
+    delete_snst_rel_by_rel_prop(client).await;
+}
+
 #[allow(clippy::cognitive_complexity, dead_code)]
 async fn delete_snst_rel_by_rel_prop(mut client: Client<AppRequestCtx>) {
     let _p0 = client
@@ -905,6 +1218,36 @@ async fn delete_snst_rel_by_dst_prop_gremlin() {
     delete_snst_rel_by_dst_prop(client).await;
 }
 
+#[cfg(feature = "in-memory")]
+#[tokio::test]
+async fn delete_snst_rel_by_dst_prop_memory() {
+    init();
+    clear_db().await;
+
+    let client = memory_test_client("./tests/fixtures/minimal.yml").await;
+    delete_snst_rel_by_dst_prop(client).await;
+}
+
+#[cfg(feature = "postgres")]
+#[tokio::test]
+async fn delete_snst_rel_by_dst_prop_postgres() {
+    init();
+    clear_db().await;
+
+    let client = postgres_test_client("./tests/fixtures/minimal.yml").await;
+    delete_snst_rel_by_dst_prop(client).await;
+}
+
+#[cfg(feature = "sqlite")]
+#[tokio::test]
+async fn delete_snst_rel_by_dst_prop_sqlite() {
+    init();
+    clear_db().await;
+
+    let client = sqlite_test_client("./tests/fixtures/minimal.yml").await;
+    delete_snst_rel_by_dst_prop(client).await;
+}
+
 #[allow(clippy::cognitive_complexity, dead_code)]
 async fn delete_snst_rel_by_dst_prop(mut client: Client<AppRequestCtx>) {
     let _p0 = client
@@ -986,6 +1329,36 @@ async fn delete_snst_rel_by_src_prop_gremlin() {
     delete_snst_rel_by_src_prop(client).await;
 }
 
+#[cfg(feature = "in-memory")]
+#[tokio::test]
+async fn delete_snst_rel_by_src_prop_memory() {
+    init();
+    clear_db().await;
+
+    let client = memory_test_client("./tests/fixtures/minimal.yml").await;
+    delete_snst_rel_by_src_prop(client).await;
+}
+
+#[cfg(feature = "postgres")]
+#[tokio::test]
+async fn delete_snst_rel_by_src_prop_postgres() {
+    init();
+    clear_db().await;
+
+    let client = postgres_test_client("./tests/fixtures/minimal.yml").await;
+    delete_snst_rel_by_src_prop(client).await;
+}
+
+#[cfg(feature = "sqlite")]
+#[tokio::test]
+async fn delete_snst_rel_by_src_prop_sqlite() {
+    init();
+    clear_db().await;
+
+    let client = sqlite_test_client("./tests/fixtures/minimal.yml").await;
+    delete_snst_rel_by_src_prop(client).await;
+}
+
 #[allow(clippy::cognitive_complexity, dead_code)]
 async fn delete_snst_rel_by_src_prop(mut client: Client<AppRequestCtx>) {
     let _p0 = client
@@ -0,0 +1,214 @@
+mod setup;
+
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+use serde_json::json;
+#[cfg(feature = "cosmos")]
+use setup::cosmos_test_pool;
+#[cfg(feature = "gremlin")]
+use setup::gremlin_test_pool;
+#[cfg(feature = "in-memory")]
+use setup::memory_test_pool;
+#[cfg(feature = "neo4j")]
+use setup::neo4j_test_pool;
+#[cfg(feature = "postgres")]
+use setup::postgres_test_pool;
+#[cfg(feature = "sqlite")]
+use setup::sqlite_test_pool;
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+use setup::{clear_db, execute, init};
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+use std::convert::TryInto;
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+use std::fs::File;
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+use std::sync::Arc;
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+use warpgrapher::engine::resolvers::{ResolverFacade, Resolvers};
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+use warpgrapher::engine::value::Value;
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+use warpgrapher::{Configuration, DatabasePool, Engine};
+
+#[cfg(feature = "cosmos")]
+#[tokio::test]
+async fn resolver_arguments_test_cosmos() {
+    init();
+    clear_db().await;
+
+    resolver_arguments_test(cosmos_test_pool().await);
+}
+
+#[cfg(feature = "gremlin")]
+#[tokio::test]
+async fn resolver_arguments_test_gremlin() {
+    init();
+    clear_db().await;
+
+    resolver_arguments_test(gremlin_test_pool().await);
+}
+
+#[cfg(feature = "in-memory")]
+#[tokio::test]
+async fn resolver_arguments_test_memory() {
+    init();
+    clear_db().await;
+
+    resolver_arguments_test(memory_test_pool().await);
+}
+
+#[cfg(feature = "neo4j")]
+#[tokio::test]
+async fn resolver_arguments_test_neo4j() {
+    init();
+    clear_db().await;
+
+    resolver_arguments_test(neo4j_test_pool().await);
+}
+
+#[cfg(feature = "postgres")]
+#[tokio::test]
+async fn resolver_arguments_test_postgres() {
+    init();
+    clear_db().await;
+
+    resolver_arguments_test(postgres_test_pool().await);
+}
+
+#[cfg(feature = "sqlite")]
+#[tokio::test]
+async fn resolver_arguments_test_sqlite() {
+    init();
+    clear_db().await;
+
+    resolver_arguments_test(sqlite_test_pool().await);
+}
+
+/// Passes if named arguments to custom endpoints and dynamic properties reach the resolvers,
+/// with configured defaults applied when the client omits them
+#[allow(dead_code)]
+fn resolver_arguments_test(database_pool: DatabasePool) {
+    let config: Configuration = File::open("./tests/fixtures/resolver_arguments.yml")
+        .expect("Failed to load config file")
+        .try_into()
+        .unwrap();
+
+    let mut resolvers = Resolvers::<()>::new();
+    resolvers.insert(
+        "SearchIssues".to_string(),
+        Arc::new(|facade: ResolverFacade<()>| {
+            let text: String = facade.arg("text")?;
+            let limit: i32 = facade.arg("limit")?;
+            let labels: Option<Vec<String>> = facade.arg_opt("labels")?;
+            facade.resolve_scalar(format!(
+                "{} {} {}",
+                text,
+                limit,
+                labels.map(|l| l.join(",")).unwrap_or_default()
+            ))
+        }),
+    );
+    resolvers.insert(
+        "Shout".to_string(),
+        Arc::new(|facade: ResolverFacade<()>| {
+            let times: i32 = facade.arg("times")?;
+            let title = match facade.parent_node()?.fields().get("title") {
+                Some(Value::String(t)) => t.to_uppercase(),
+                _ => String::new(),
+            };
+            facade.resolve_scalar(title.repeat(times as usize))
+        }),
+    );
+
+    let engine = Engine::<()>::new(config, database_pool)
+        .with_resolvers(resolvers)
+        .build()
+        .expect("Could not create warpgrapher engine");
+
+    let defaulted = execute(
+        &engine,
+        "query { SearchIssues(text: \"bug\") }",
+        json!(null),
+    );
+    assert_eq!(defaulted["data"]["SearchIssues"], "bug 20 ");
+    let explicit = execute(
+        &engine,
+        "query { SearchIssues(text: \"bug\", limit: 5, labels: [\"ui\", \"db\"]) }",
+        json!(null),
+    );
+    assert_eq!(explicit["data"]["SearchIssues"], "bug 5 ui,db");
+    let missing = execute(&engine, "query { SearchIssues(limit: 5) }", json!(null));
+    assert!(missing["data"].is_null());
+
+    execute(
+        &engine,
+        "mutation Create($partitionKey: String, $input: IssueCreateMutationInput!) {
+            IssueCreate(partitionKey: $partitionKey, input: $input) { id }
+        }",
+        json!({"title": "hi"}),
+    );
+    let shout = execute(
+        &engine,
+        "query Read($partitionKey: String) {
+            Issue(partitionKey: $partitionKey) { a: shout b: shout(times: 3) }
+        }",
+        json!(null),
+    );
+    assert_eq!(shout["data"]["Issue"][0]["a"], "HIHI");
+    assert_eq!(shout["data"]["Issue"][0]["b"], "HIHIHI");
+}
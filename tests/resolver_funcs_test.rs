@@ -0,0 +1,276 @@
+mod setup;
+
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+use serde_json::json;
+#[cfg(feature = "cosmos")]
+use setup::cosmos_test_pool;
+#[cfg(feature = "gremlin")]
+use setup::gremlin_test_pool;
+#[cfg(feature = "in-memory")]
+use setup::memory_test_pool;
+#[cfg(feature = "neo4j")]
+use setup::neo4j_test_pool;
+#[cfg(feature = "postgres")]
+use setup::postgres_test_pool;
+#[cfg(feature = "sqlite")]
+use setup::sqlite_test_pool;
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+use setup::{clear_db, execute, init};
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+use std::convert::TryInto;
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+use std::fs::File;
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+use std::sync::atomic::{AtomicUsize, Ordering};
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+use std::sync::Arc;
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+use warpgrapher::engine::resolvers::{async_resolver, ExecutionResult, ResolverFacade, Resolvers};
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+use warpgrapher::engine::validators::{async_validator, Validators};
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+use warpgrapher::engine::value::Value;
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+use warpgrapher::{Configuration, DatabasePool, Engine, Error};
+
+#[cfg(feature = "cosmos")]
+#[tokio::test]
+async fn resolver_funcs_test_cosmos() {
+    init();
+    clear_db().await;
+
+    resolver_funcs_test(cosmos_test_pool().await);
+}
+
+#[cfg(feature = "gremlin")]
+#[tokio::test]
+async fn resolver_funcs_test_gremlin() {
+    init();
+    clear_db().await;
+
+    resolver_funcs_test(gremlin_test_pool().await);
+}
+
+#[cfg(feature = "in-memory")]
+#[tokio::test]
+async fn resolver_funcs_test_memory() {
+    init();
+    clear_db().await;
+
+    resolver_funcs_test(memory_test_pool().await);
+}
+
+#[cfg(feature = "neo4j")]
+#[tokio::test]
+async fn resolver_funcs_test_neo4j() {
+    init();
+    clear_db().await;
+
+    resolver_funcs_test(neo4j_test_pool().await);
+}
+
+#[cfg(feature = "postgres")]
+#[tokio::test]
+async fn resolver_funcs_test_postgres() {
+    init();
+    clear_db().await;
+
+    resolver_funcs_test(postgres_test_pool().await);
+}
+
+#[cfg(feature = "sqlite")]
+#[tokio::test]
+async fn resolver_funcs_test_sqlite() {
+    init();
+    clear_db().await;
+
+    resolver_funcs_test(sqlite_test_pool().await);
+}
+
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+async fn user_count(facade: ResolverFacade<'_, ()>) -> ExecutionResult {
+    let users = facade.read_nodes("User", None)?;
+    facade.resolve_scalar(users.len() as i32)
+}
+
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+async fn known_role(value: &Value) -> Result<(), Error> {
+    match value {
+        Value::Map(m) => match m.get("role") {
+            Some(Value::String(s)) if s == "root" => Err(Error::ValidationFailed {
+                message: "Unknown role.".to_string(),
+            }),
+            _ => Ok(()),
+        },
+        _ => Ok(()),
+    }
+}
+
+/// Passes if closures that capture state, and asynchronous functions, can be used as custom
+/// resolvers and validators, if that state is shared across requests, and if the collections of
+/// resolvers and validators can be cloned to build another engine
+#[allow(dead_code)]
+fn resolver_funcs_test(database_pool: DatabasePool) {
+    let config: Configuration = File::open("./tests/fixtures/resolver_funcs.yml")
+        .expect("Failed to load config file")
+        .try_into()
+        .unwrap();
+
+    let greeting = "hello".to_string();
+    let calls = Arc::new(AtomicUsize::new(0));
+    let counter = calls.clone();
+    let mut resolvers = Resolvers::<()>::new();
+    resolvers.insert(
+        "Greeting".to_string(),
+        Arc::new(move |facade: ResolverFacade<()>| {
+            let n = counter.fetch_add(1, Ordering::SeqCst) + 1;
+            facade.resolve_scalar(format!("{} {}", greeting, n))
+        }),
+    );
+    resolvers.insert(
+        "UserCount".to_string(),
+        async_resolver(|facade| Box::pin(user_count(facade))),
+    );
+
+    let max_len = 5;
+    let mut validators = Validators::new();
+    validators.insert(
+        "NameLength".to_string(),
+        Arc::new(move |value: &Value| match value {
+            Value::Map(m) => match m.get("name") {
+                Some(Value::String(s)) if s.len() > max_len => Err(Error::ValidationFailed {
+                    message: "Name too long.".to_string(),
+                }),
+                _ => Ok(()),
+            },
+            _ => Ok(()),
+        }),
+    );
+    validators.insert(
+        "KnownRole".to_string(),
+        async_validator(|value| Box::pin(known_role(value))),
+    );
+
+    let engine = Engine::<()>::new(config.clone(), database_pool.clone())
+        .with_resolvers(resolvers.clone())
+        .with_validators(validators.clone())
+        .build()
+        .expect("Could not create warpgrapher engine");
+    let other = Engine::<()>::new(config, database_pool)
+        .with_resolvers(resolvers)
+        .with_validators(validators)
+        .build()
+        .expect("Could not create warpgrapher engine");
+
+    let greet = "query Greet($partitionKey: String) { Greeting(partitionKey: $partitionKey) }";
+    let first = execute(&engine, greet, json!(null));
+    assert_eq!(first["data"]["Greeting"], "hello 1");
+    let second = execute(&other, greet, json!(null));
+    assert_eq!(second["data"]["Greeting"], "hello 2");
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+
+    let create = "mutation Create($partitionKey: String, $input: UserCreateMutationInput!) {
+        UserCreate(partitionKey: $partitionKey, input: $input) { name }
+    }";
+    let valid = execute(&engine, create, json!({"name": "alice", "role": "admin"}));
+    assert_eq!(valid["data"]["UserCreate"]["name"], "alice");
+    let too_long = execute(
+        &engine,
+        create,
+        json!({"name": "bartholomew", "role": "admin"}),
+    );
+    assert!(too_long["data"]["UserCreate"].is_null());
+    let unknown = execute(&engine, create, json!({"name": "carol", "role": "root"}));
+    assert!(unknown["data"]["UserCreate"].is_null());
+
+    let count = execute(
+        &engine,
+        "query Count($partitionKey: String) { UserCount(partitionKey: $partitionKey) }",
+        json!(null),
+    );
+    assert_eq!(count["data"]["UserCount"], 1);
+}
@@ -0,0 +1,247 @@
+mod setup;
+
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+use serde_json::json;
+#[cfg(feature = "cosmos")]
+use setup::cosmos_test_pool;
+#[cfg(feature = "gremlin")]
+use setup::gremlin_test_pool;
+#[cfg(feature = "in-memory")]
+use setup::memory_test_pool;
+#[cfg(feature = "neo4j")]
+use setup::neo4j_test_pool;
+#[cfg(feature = "postgres")]
+use setup::postgres_test_pool;
+#[cfg(feature = "sqlite")]
+use setup::sqlite_test_pool;
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+use setup::{clear_db, execute, init};
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+use std::convert::{TryFrom, TryInto};
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+use std::fs::File;
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+use std::sync::Arc;
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+use warpgrapher::engine::cache::MemoryCacheStore;
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+use warpgrapher::engine::resolvers::{ExecutionResult, ResolverFacade, Resolvers};
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+use warpgrapher::engine::value::Value;
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+use warpgrapher::{Configuration, DatabasePool, Engine, Error};
+
+#[cfg(feature = "cosmos")]
+#[tokio::test]
+async fn response_cache_test_cosmos() {
+    init();
+    clear_db().await;
+
+    response_cache_test(cosmos_test_pool().await);
+}
+
+#[cfg(feature = "gremlin")]
+#[tokio::test]
+async fn response_cache_test_gremlin() {
+    init();
+    clear_db().await;
+
+    response_cache_test(gremlin_test_pool().await);
+}
+
+#[cfg(feature = "in-memory")]
+#[tokio::test]
+async fn response_cache_test_memory() {
+    init();
+    clear_db().await;
+
+    response_cache_test(memory_test_pool().await);
+}
+
+#[cfg(feature = "neo4j")]
+#[tokio::test]
+async fn response_cache_test_neo4j() {
+    init();
+    clear_db().await;
+
+    response_cache_test(neo4j_test_pool().await);
+}
+
+#[cfg(feature = "postgres")]
+#[tokio::test]
+async fn response_cache_test_postgres() {
+    init();
+    clear_db().await;
+
+    response_cache_test(postgres_test_pool().await);
+}
+
+#[cfg(feature = "sqlite")]
+#[tokio::test]
+async fn response_cache_test_sqlite() {
+    init();
+    clear_db().await;
+
+    response_cache_test(sqlite_test_pool().await);
+}
+
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+fn log_note(facade: ResolverFacade<()>) -> ExecutionResult {
+    facade
+        .insert_node(
+            "Note",
+            Value::try_from(json!({"text": "logged"})).map_err(Error::into_field_error)?,
+        )
+        .map_err(Error::into_field_error)?;
+    facade.resolve_scalar(1)
+}
+
+/// Passes if read queries are served from the cache until a mutation, or a custom resolver
+/// writing through the resolver facade, writes to a connected type, and responses to other
+/// types and other variables are kept apart
+#[allow(dead_code)]
+fn response_cache_test(database_pool: DatabasePool) {
+    let config: Configuration = File::open("./tests/fixtures/response_cache.yml")
+        .expect("Failed to load config file")
+        .try_into()
+        .unwrap();
+
+    let mut resolvers = Resolvers::<()>::new();
+    resolvers.insert("LogNote".to_string(), Arc::new(log_note));
+
+    let store = Arc::new(MemoryCacheStore::new());
+    let engine = Engine::<()>::new(config.clone(), database_pool.clone())
+        .with_resolvers(resolvers.clone())
+        .with_cache_store(store.clone())
+        .build()
+        .expect("Could not create warpgrapher engine");
+    let uncached = Engine::<()>::new(config, database_pool)
+        .with_resolvers(resolvers)
+        .build()
+        .expect("Could not create warpgrapher engine");
+
+    let create_project =
+        "mutation Create($partitionKey: String, $input: ProjectCreateMutationInput!) {
+        ProjectCreate(partitionKey: $partitionKey, input: $input) { id }
+    }";
+    let create_note = "mutation Create($partitionKey: String, $input: NoteCreateMutationInput!) {
+        NoteCreate(partitionKey: $partitionKey, input: $input) { id }
+    }";
+    execute(&engine, create_project, json!({"name": "alpha"}));
+    execute(&engine, create_note, json!({"text": "first"}));
+
+    let projects = "query Read($partitionKey: String) {
+        Project(partitionKey: $partitionKey) { name }
+    }";
+    let notes = "query Read($partitionKey: String) {
+        Note(partitionKey: $partitionKey) { text }
+    }";
+    let count = |query: &str, type_name: &str| -> usize {
+        execute(&engine, query, json!(null))["data"][type_name]
+            .as_array()
+            .unwrap()
+            .len()
+    };
+    assert_eq!(count(projects, "Project"), 1);
+    assert_eq!(count(notes, "Note"), 1);
+    assert_eq!(store.len(), 2);
+
+    execute(&uncached, create_project, json!({"name": "beta"}));
+    execute(&uncached, create_note, json!({"text": "second"}));
+    assert_eq!(count(projects, "Project"), 1);
+    assert_eq!(count(notes, "Note"), 1);
+
+    execute(
+        &engine,
+        "mutation Create($partitionKey: String, $input: IssueCreateMutationInput!) {
+            IssueCreate(partitionKey: $partitionKey, input: $input) { id }
+        }",
+        json!({"title": "bug"}),
+    );
+    assert_eq!(count(projects, "Project"), 2);
+    assert_eq!(count(notes, "Note"), 1);
+
+    let logged = execute(&engine, "query { LogNote }", json!(null));
+    assert_eq!(logged["data"]["LogNote"], 1);
+    assert_eq!(count(notes, "Note"), 3);
+
+    let filtered = execute(
+        &engine,
+        "query Read($partitionKey: String, $input: ProjectQueryInput) {
+            Project(partitionKey: $partitionKey, input: $input) { name }
+        }",
+        json!({"name": "beta"}),
+    );
+    assert_eq!(filtered["data"]["Project"], json!([{"name": "beta"}]));
+    assert_eq!(store.len(), 3);
+}
@@ -15,7 +15,14 @@ use log::trace;
 use std::collections::HashMap;
 #[cfg(feature = "neo4j")]
 use std::convert::TryFrom;
-#[cfg(any(feature = "cosmos", feature = "gremlin", feature = "neo4j"))]
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
 use std::convert::TryInto;
 use std::env::var_os;
 use std::fs::File;
@@ -26,16 +33,38 @@ use std::iter::FromIterator;
 use std::sync::Arc;
 #[cfg(feature = "neo4j")]
 use tokio::runtime::Runtime;
+#[cfg(feature = "sqlite")]
+use uuid::Uuid;
 use warpgrapher::engine::context::RequestContext;
 #[cfg(feature = "cosmos")]
 use warpgrapher::engine::database::gremlin::CosmosEndpoint;
 #[cfg(feature = "gremlin")]
 use warpgrapher::engine::database::gremlin::GremlinEndpoint;
+#[cfg(feature = "in-memory")]
+use warpgrapher::engine::database::memory::MemoryEndpoint;
 #[cfg(feature = "neo4j")]
 use warpgrapher::engine::database::neo4j::Neo4jEndpoint;
-#[cfg(any(feature = "cosmos", feature = "gremlin", feature = "neo4j"))]
+#[cfg(feature = "postgres")]
+use warpgrapher::engine::database::postgres::PostgresEndpoint;
+#[cfg(feature = "sqlite")]
+use warpgrapher::engine::database::sqlite::SqliteEndpoint;
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
 use warpgrapher::engine::database::DatabaseEndpoint;
-#[cfg(feature = "neo4j")]
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
 use warpgrapher::engine::database::DatabasePool;
 #[cfg(feature = "neo4j")]
 use warpgrapher::engine::extensions::Extensions;
@@ -48,7 +77,23 @@ use warpgrapher::engine::resolvers::Resolvers;
 #[cfg(feature = "neo4j")]
 use warpgrapher::engine::validators::Validators;
 use warpgrapher::engine::value::Value;
-#[cfg(any(feature = "cosmos", feature = "gremlin", feature = "neo4j"))]
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+use warpgrapher::juniper::http::GraphQLRequest;
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
 use warpgrapher::{Client, Engine};
 use warpgrapher::{Configuration, Error};
 
@@ -174,6 +219,52 @@ pub(crate) fn neo4j_pass() -> String {
         .to_owned()
 }
 
+#[cfg(feature = "postgres")]
+fn postgres_host() -> String {
+    var_os("WG_POSTGRES_HOST")
+        .expect("Expected WG_POSTGRES_HOST to be set.")
+        .to_str()
+        .expect("Expected WG_POSTGRES_HOST to be a string.")
+        .to_owned()
+}
+
+#[cfg(feature = "postgres")]
+fn postgres_port() -> u16 {
+    var_os("WG_POSTGRES_PORT")
+        .expect("Expected WG_POSTGRES_PORT to be set.")
+        .to_str()
+        .expect("Expected WG_POSTGRES_PORT to be a string.")
+        .parse::<u16>()
+        .expect("Expected WG_POSTGRES_PORT to be a u16.")
+}
+
+#[cfg(feature = "postgres")]
+fn postgres_user() -> String {
+    var_os("WG_POSTGRES_USER")
+        .expect("Expected WG_POSTGRES_USER to be set.")
+        .to_str()
+        .expect("Expected WG_POSTGRES_USER to be a string.")
+        .to_owned()
+}
+
+#[cfg(feature = "postgres")]
+fn postgres_pass() -> String {
+    var_os("WG_POSTGRES_PASS")
+        .expect("Expected WG_POSTGRES_PASS to be set.")
+        .to_str()
+        .expect("Expected WG_POSTGRES_PASS to be a string.")
+        .to_owned()
+}
+
+#[cfg(feature = "postgres")]
+fn postgres_db() -> String {
+    var_os("WG_POSTGRES_DB")
+        .expect("Expected WG_POSTGRES_DB to be set.")
+        .to_str()
+        .expect("Expected WG_POSTGRES_DB to be a string.")
+        .to_owned()
+}
+
 #[allow(dead_code)]
 #[cfg(feature = "neo4j")]
 pub(crate) async fn bolt_client() -> bolt_client::Client {
@@ -207,6 +298,84 @@ fn load_config(config: &str) -> Configuration {
     serde_yaml::from_reader(cr).expect("Could not deserialize configuration file.")
 }
 
+/// Returns a pool for the Neo4j database configured in the environment
+#[allow(dead_code)]
+#[cfg(feature = "neo4j")]
+pub(crate) async fn neo4j_test_pool() -> DatabasePool {
+    Neo4jEndpoint::from_env().unwrap().pool().await.unwrap()
+}
+
+/// Returns a pool for the Cosmos DB database configured in the environment
+#[allow(dead_code)]
+#[cfg(feature = "cosmos")]
+pub(crate) async fn cosmos_test_pool() -> DatabasePool {
+    CosmosEndpoint::from_env().unwrap().pool().await.unwrap()
+}
+
+/// Returns a pool for the Gremlin database configured in the environment
+#[allow(dead_code)]
+#[cfg(feature = "gremlin")]
+pub(crate) async fn gremlin_test_pool() -> DatabasePool {
+    GremlinEndpoint::from_env().unwrap().pool().await.unwrap()
+}
+
+/// Returns a pool for a new, empty in-memory graph
+#[allow(dead_code)]
+#[cfg(feature = "in-memory")]
+pub(crate) async fn memory_test_pool() -> DatabasePool {
+    MemoryEndpoint::new().pool().await.unwrap()
+}
+
+/// Returns a pool for the PostgreSQL database configured in the environment
+#[allow(dead_code)]
+#[cfg(feature = "postgres")]
+pub(crate) async fn postgres_test_pool() -> DatabasePool {
+    PostgresEndpoint::from_env().unwrap().pool().await.unwrap()
+}
+
+/// Returns a pool for a new, empty SQLite database file in the temporary directory
+#[allow(dead_code)]
+#[cfg(feature = "sqlite")]
+pub(crate) async fn sqlite_test_pool() -> DatabasePool {
+    let path = std::env::temp_dir().join(Uuid::new_v4().to_string() + ".db");
+    SqliteEndpoint::new(path.to_string_lossy().into_owned())
+        .pool()
+        .await
+        .unwrap()
+}
+
+/// Executes a request against the engine on a thread of its own, as the local client does, and
+/// returns the whole response, including any errors, rather than only the data. The request
+/// variables are the input and a partition key of `1234`, for the back-ends that require one.
+#[allow(dead_code)]
+#[cfg(any(
+    feature = "cosmos",
+    feature = "gremlin",
+    feature = "in-memory",
+    feature = "neo4j",
+    feature = "postgres",
+    feature = "sqlite"
+))]
+pub(crate) fn execute<RequestCtx: RequestContext>(
+    engine: &Engine<RequestCtx>,
+    query: &str,
+    input: serde_json::Value,
+) -> serde_json::Value {
+    let req: GraphQLRequest = serde_json::from_value(serde_json::json!({
+        "query": query,
+        "variables": { "partitionKey": "1234", "input": input }
+    }))
+    .unwrap();
+    let engine = engine.clone();
+    std::thread::spawn(move || {
+        engine
+            .execute(&req, &std::collections::HashMap::new())
+            .expect("Expected successful execution.")
+    })
+    .join()
+    .expect("Thread panicked")
+}
+
 #[allow(dead_code)]
 #[cfg(feature = "neo4j")]
 pub(crate) async fn neo4j_test_client(config_path: &str) -> Client<AppRequestCtx> {
@@ -216,21 +385,21 @@ pub(crate) async fn neo4j_test_client(config_path: &str) -> Client<AppRequestCtx
         .try_into()
         .unwrap();
 
-    let database_pool = Neo4jEndpoint::from_env().unwrap().pool().await.unwrap();
+    let database_pool = neo4j_test_pool().await;
 
     // load resolvers
     let mut resolvers: Resolvers<AppRequestCtx> = Resolvers::new();
-    resolvers.insert("GlobalTopDev".to_owned(), Box::new(global_top_dev));
-    resolvers.insert("GlobalTopTags".to_owned(), Box::new(global_top_tags));
-    resolvers.insert("ProjectCount".to_owned(), Box::new(project_count));
-    resolvers.insert("ProjectPoints".to_string(), Box::new(project_points));
-    resolvers.insert("ProjectTopDev".to_string(), Box::new(project_top_dev));
-    resolvers.insert("ProjectTopIssues".to_string(), Box::new(project_top_issues));
-    resolvers.insert("ProjectTopTags".to_string(), Box::new(project_top_tags));
+    resolvers.insert("GlobalTopDev".to_owned(), Arc::new(global_top_dev));
+    resolvers.insert("GlobalTopTags".to_owned(), Arc::new(global_top_tags));
+    resolvers.insert("ProjectCount".to_owned(), Arc::new(project_count));
+    resolvers.insert("ProjectPoints".to_string(), Arc::new(project_points));
+    resolvers.insert("ProjectTopDev".to_string(), Arc::new(project_top_dev));
+    resolvers.insert("ProjectTopIssues".to_string(), Arc::new(project_top_issues));
+    resolvers.insert("ProjectTopTags".to_string(), Arc::new(project_top_tags));
 
     // load validators
     let mut validators: Validators = Validators::new();
-    validators.insert("NameValidator".to_string(), Box::new(name_validator));
+    validators.insert("NameValidator".to_string(), Arc::new(name_validator));
 
     // initialize extensions
     let metadata_extension: MetadataExtension<AppRequestCtx> = MetadataExtension::new();
@@ -257,7 +426,7 @@ pub(crate) async fn cosmos_test_client(config_path: &str) -> Client<AppRequestCt
         .try_into()
         .unwrap();
 
-    let database_pool = CosmosEndpoint::from_env().unwrap().pool().await.unwrap();
+    let database_pool = cosmos_test_pool().await;
 
     let engine = Engine::<AppRequestCtx>::new(config, database_pool)
         .with_version("1.0".to_string())
@@ -296,7 +465,65 @@ pub(crate) async fn gremlin_test_client(config_path: &str) -> Client<AppRequestC
         .try_into()
         .unwrap();
 
-    let database_pool = GremlinEndpoint::from_env().unwrap().pool().await.unwrap();
+    let database_pool = gremlin_test_pool().await;
+
+    let engine = Engine::<AppRequestCtx>::new(config, database_pool)
+        .with_version("1.0".to_string())
+        .build()
+        .expect("Could not create warpgrapher engine");
+
+    Client::new_with_engine(engine, None)
+}
+
+/// Returns a client for an engine backed by a new, empty in-memory graph, so there is no
+/// database to clear between tests
+#[allow(dead_code)]
+#[cfg(feature = "in-memory")]
+pub(crate) async fn memory_test_client(config_path: &str) -> Client<AppRequestCtx> {
+    let config: Configuration = File::open(config_path)
+        .expect("Failed to load config file")
+        .try_into()
+        .unwrap();
+
+    let database_pool = memory_test_pool().await;
+
+    let engine = Engine::<AppRequestCtx>::new(config, database_pool)
+        .with_version("1.0".to_string())
+        .build()
+        .expect("Could not create warpgrapher engine");
+
+    Client::new_with_engine(engine, None)
+}
+
+#[allow(dead_code)]
+#[cfg(feature = "postgres")]
+pub(crate) async fn postgres_test_client(config_path: &str) -> Client<AppRequestCtx> {
+    let config: Configuration = File::open(config_path)
+        .expect("Failed to load config file")
+        .try_into()
+        .unwrap();
+
+    let database_pool = postgres_test_pool().await;
+
+    let engine = Engine::<AppRequestCtx>::new(config, database_pool)
+        .with_version("1.0".to_string())
+        .build()
+        .expect("Could not create warpgrapher engine");
+
+    Client::new_with_engine(engine, None)
+}
+
+/// Returns a client for an engine backed by a new, empty SQLite database file in the temporary
+/// directory, so there is no database to clear between tests
+#[allow(dead_code)]
+#[cfg(feature = "sqlite")]
+pub(crate) async fn sqlite_test_client(config_path: &str) -> Client<AppRequestCtx> {
+    let config: Configuration = File::open(config_path)
+        .expect("Failed to load config file")
+        .try_into()
+        .unwrap();
+
+    let database_pool = sqlite_test_pool().await;
 
     let engine = Engine::<AppRequestCtx>::new(config, database_pool)
         .with_version("1.0".to_string())
@@ -344,6 +571,29 @@ async fn clear_neo4j_db() {
         .expect("Expected pull to succeed.");
 }
 
+#[cfg(feature = "postgres")]
+#[allow(dead_code)]
+fn clear_postgres_db() {
+    let mut client = postgres_client::Config::new()
+        .host(&postgres_host())
+        .port(postgres_port())
+        .user(&postgres_user())
+        .password(postgres_pass())
+        .dbname(&postgres_db())
+        .connect(postgres_client::NoTls)
+        .expect("Expected successful postgres client creation.");
+
+    // The tables do not exist until the first pool is created
+    client
+        .batch_execute(
+            "DO $$ BEGIN
+                IF to_regclass('edges') IS NOT NULL THEN TRUNCATE edges; END IF;
+                IF to_regclass('nodes') IS NOT NULL THEN TRUNCATE nodes; END IF;
+            END $$;",
+        )
+        .expect("Expected successful truncation.");
+}
+
 #[allow(dead_code)]
 pub(crate) async fn clear_db() {
     #[cfg(feature = "cosmos")]
@@ -354,6 +604,9 @@ pub(crate) async fn clear_db() {
 
     #[cfg(feature = "neo4j")]
     clear_neo4j_db().await;
+
+    #[cfg(feature = "postgres")]
+    clear_postgres_db();
 }
 
 #[derive(Clone, Debug)]
@@ -459,7 +712,7 @@ pub(crate) fn global_top_dev(facade: ResolverFacade<AppRequestCtx>) -> Execution
     trace!("global_top_dev called");
     let mut hm = HashMap::new();
     hm.insert("name".to_string(), Value::String("Joe".to_string()));
-    facade.resolve_node(&facade.create_node("User", hm))
+    facade.resolve_node(&facade.node("User", hm))
 }
 
 /// custom field returning scalar